@@ -14,6 +14,12 @@ pub enum Alignment {
 
     /// Fill the entire axis.
     Fill,
+
+    /// Align the baselines of the text of the contents.
+    ///
+    /// It is only meaningful in the cross axis of a horizontal container,
+    /// like a row. Anywhere else, it behaves like [`Alignment::Start`].
+    Baseline,
 }
 
 impl From<Horizontal> for Alignment {
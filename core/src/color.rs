@@ -89,6 +89,16 @@ impl Color {
         }
     }
 
+    /// Converts the [`Color`] into its RGBA8 equivalent.
+    pub fn into_rgba8(self) -> [u8; 4] {
+        [
+            (self.r * 255.0).round() as u8,
+            (self.g * 255.0).round() as u8,
+            (self.b * 255.0).round() as u8,
+            (self.a * 255.0).round() as u8,
+        ]
+    }
+
     /// Converts the [`Color`] into its linear values.
     pub fn into_linear(self) -> [f32; 4] {
         // As described in:
@@ -109,6 +119,17 @@ impl Color {
         ]
     }
 
+    /// Converts the [`Color`] into its linear values, with the color
+    /// components multiplied by the alpha value.
+    ///
+    /// This is the representation expected by the compositors of most
+    /// platforms when drawing transparent windows.
+    pub fn into_linear_premultiplied(self) -> [f32; 4] {
+        let [r, g, b, a] = self.into_linear();
+
+        [r * a, g * a, b * a, a]
+    }
+
     /// Inverts the [`Color`] in-place.
     pub fn invert(&mut self) {
         self.r = 1.0f32 - self.r;
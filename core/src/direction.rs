@@ -0,0 +1,34 @@
+use crate::alignment;
+
+/// The direction in which content flows horizontally.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Direction {
+    /// Content flows from left to right, like in English.
+    #[default]
+    LeftToRight,
+
+    /// Content flows from right to left, like in Arabic or Hebrew.
+    RightToLeft,
+}
+
+impl Direction {
+    /// Returns the given horizontal alignment as it should be applied when
+    /// content flows in the current [`Direction`].
+    ///
+    /// Left and right alignments are swapped when the [`Direction`] is
+    /// [`Direction::RightToLeft`].
+    pub fn align_x(
+        self,
+        alignment: alignment::Horizontal,
+    ) -> alignment::Horizontal {
+        match (self, alignment) {
+            (Direction::RightToLeft, alignment::Horizontal::Left) => {
+                alignment::Horizontal::Right
+            }
+            (Direction::RightToLeft, alignment::Horizontal::Right) => {
+                alignment::Horizontal::Left
+            }
+            _ => alignment,
+        }
+    }
+}
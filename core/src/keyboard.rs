@@ -3,6 +3,9 @@ mod event;
 mod key_code;
 mod modifiers;
 
+pub mod shortcut;
+
 pub use event::Event;
 pub use key_code::KeyCode;
 pub use modifiers::Modifiers;
+pub use shortcut::{Shortcut, Shortcuts};
@@ -0,0 +1,362 @@
+//! Declare keyboard shortcuts and the messages they produce.
+use crate::keyboard::{KeyCode, Modifiers};
+
+use std::fmt;
+use std::str::FromStr;
+
+/// A combination of [`Modifiers`] and a [`KeyCode`] that triggers an action.
+///
+/// A [`Shortcut`] can be parsed from a string like `"Ctrl+Shift+P"`. The
+/// `Cmd` modifier stands for [`Modifiers::COMMAND`], which is the logo key on
+/// macOS and the control key everywhere else.
+///
+/// # Example
+/// ```
+/// # use iced_core::keyboard::{KeyCode, Modifiers, Shortcut};
+/// let shortcut: Shortcut = "Ctrl+Shift+P".parse().unwrap();
+///
+/// assert_eq!(shortcut.key_code, KeyCode::P);
+/// assert_eq!(shortcut.modifiers, Modifiers::CTRL | Modifiers::SHIFT);
+/// assert_eq!(shortcut.to_string(), "Ctrl+Shift+P");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Shortcut {
+    /// The [`Modifiers`] that must be pressed.
+    pub modifiers: Modifiers,
+
+    /// The [`KeyCode`] of the key that must be pressed.
+    pub key_code: KeyCode,
+}
+
+impl Shortcut {
+    /// Creates a new [`Shortcut`] from some [`Modifiers`] and a [`KeyCode`].
+    pub fn new(modifiers: Modifiers, key_code: KeyCode) -> Self {
+        Self {
+            modifiers,
+            key_code,
+        }
+    }
+
+    /// Returns true if pressing the given key with the given [`Modifiers`]
+    /// triggers the [`Shortcut`].
+    pub fn matches(&self, key_code: KeyCode, modifiers: Modifiers) -> bool {
+        self.key_code == key_code && self.modifiers == modifiers
+    }
+}
+
+impl fmt::Display for Shortcut {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let logo = if cfg!(target_os = "macos") {
+            "Cmd"
+        } else {
+            "Super"
+        };
+
+        for (modifier, name) in [
+            (Modifiers::CTRL, "Ctrl"),
+            (Modifiers::ALT, "Alt"),
+            (Modifiers::SHIFT, "Shift"),
+            (Modifiers::LOGO, logo),
+        ] {
+            if self.modifiers.contains(modifier) {
+                write!(f, "{}+", name)?;
+            }
+        }
+
+        match KEYS.iter().find(|(_, key_code)| *key_code == self.key_code) {
+            Some((name, _)) => write!(f, "{}", name),
+            None => write!(f, "{:?}", self.key_code),
+        }
+    }
+}
+
+/// An error produced when parsing an invalid [`Shortcut`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError(String);
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid shortcut: {}", self.0)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+impl FromStr for Shortcut {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut modifiers = Modifiers::empty();
+        let mut parts: Vec<&str> = s.split('+').map(str::trim).collect();
+
+        // A trailing empty part means the key itself is the plus sign
+        let key = match parts.pop() {
+            Some("") if s.ends_with("++") || s == "+" => {
+                let _ = parts.pop();
+                "Plus"
+            }
+            Some(key) if !key.is_empty() => key,
+            _ => return Err(ParseError(s.to_owned())),
+        };
+
+        for part in parts {
+            modifiers |= match part.to_ascii_lowercase().as_str() {
+                "ctrl" | "control" => Modifiers::CTRL,
+                "shift" => Modifiers::SHIFT,
+                "alt" | "option" => Modifiers::ALT,
+                "super" | "logo" | "meta" | "win" => Modifiers::LOGO,
+                "cmd" | "command" | "cmdorctrl" => Modifiers::COMMAND,
+                _ => return Err(ParseError(s.to_owned())),
+            };
+        }
+
+        let key_code = KEYS
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(key))
+            .map(|(_, key_code)| *key_code)
+            .ok_or_else(|| ParseError(s.to_owned()))?;
+
+        Ok(Self::new(modifiers, key_code))
+    }
+}
+
+/// A set of [`Shortcut`]s, each bound to a message.
+///
+/// When the same [`Shortcut`] is bound more than once, the first binding wins
+/// and the rest are reported by [`Shortcuts::conflicts`].
+#[derive(Debug)]
+pub struct Shortcuts<Message> {
+    bindings: Vec<Binding<Message>>,
+}
+
+#[derive(Debug)]
+struct Binding<Message> {
+    shortcut: Shortcut,
+    message: Message,
+    produce: fn(&Message) -> Message,
+}
+
+/// A [`Shortcut`] bound more than once in some [`Shortcuts`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Conflict {
+    /// The [`Shortcut`] bound more than once.
+    pub shortcut: Shortcut,
+
+    /// The amount of bindings of the [`Shortcut`].
+    pub bindings: usize,
+}
+
+impl fmt::Display for Conflict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "shortcut {} is bound {} times, only the first binding is used",
+            self.shortcut, self.bindings
+        )
+    }
+}
+
+impl<Message> Shortcuts<Message> {
+    /// Creates an empty set of [`Shortcuts`].
+    pub fn new() -> Self {
+        Self {
+            bindings: Vec::new(),
+        }
+    }
+
+    /// Binds the given [`Shortcut`] to a message.
+    pub fn bind(mut self, shortcut: Shortcut, message: Message) -> Self
+    where
+        Message: Clone,
+    {
+        self.bindings.push(Binding {
+            shortcut,
+            message,
+            produce: Message::clone,
+        });
+
+        self
+    }
+
+    /// Returns true if no [`Shortcut`] is bound.
+    pub fn is_empty(&self) -> bool {
+        self.bindings.is_empty()
+    }
+
+    /// Produces the message bound to the [`Shortcut`] triggered by pressing
+    /// the given key with the given [`Modifiers`], if any.
+    pub fn find(
+        &self,
+        key_code: KeyCode,
+        modifiers: Modifiers,
+    ) -> Option<Message> {
+        self.bindings
+            .iter()
+            .find(|binding| binding.shortcut.matches(key_code, modifiers))
+            .map(|binding| (binding.produce)(&binding.message))
+    }
+
+    /// Returns the [`Shortcut`] bound to the given message, if any.
+    ///
+    /// This is useful to display the [`Shortcut`] of an action next to it,
+    /// like in a menu.
+    pub fn shortcut(&self, message: &Message) -> Option<Shortcut>
+    where
+        Message: PartialEq,
+    {
+        self.bindings
+            .iter()
+            .find(|binding| &binding.message == message)
+            .map(|binding| binding.shortcut)
+    }
+
+    /// Returns the [`Shortcut`]s that are bound more than once.
+    pub fn conflicts(&self) -> Vec<Conflict> {
+        let mut conflicts: Vec<Conflict> = Vec::new();
+
+        for (i, binding) in self.bindings.iter().enumerate() {
+            let is_reported = conflicts
+                .iter()
+                .any(|conflict| conflict.shortcut == binding.shortcut);
+
+            if is_reported {
+                continue;
+            }
+
+            let bindings = 1 + self.bindings[i + 1..]
+                .iter()
+                .filter(|other| other.shortcut == binding.shortcut)
+                .count();
+
+            if bindings > 1 {
+                conflicts.push(Conflict {
+                    shortcut: binding.shortcut,
+                    bindings,
+                });
+            }
+        }
+
+        conflicts
+    }
+
+    /// Appends the bindings of other [`Shortcuts`], which take lower priority.
+    pub fn extend(mut self, other: Self) -> Self {
+        self.bindings.extend(other.bindings);
+        self
+    }
+}
+
+// Every binding knows how to produce its message, so [`Shortcuts`] can be
+// cloned for any kind of message
+impl<Message> Clone for Shortcuts<Message> {
+    fn clone(&self) -> Self {
+        Self {
+            bindings: self
+                .bindings
+                .iter()
+                .map(|binding| Binding {
+                    shortcut: binding.shortcut,
+                    message: (binding.produce)(&binding.message),
+                    produce: binding.produce,
+                })
+                .collect(),
+        }
+    }
+}
+
+impl<Message> Default for Shortcuts<Message> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The names of the keys that a [`Shortcut`] can be parsed from.
+const KEYS: &[(&str, KeyCode)] = &[
+    ("A", KeyCode::A),
+    ("B", KeyCode::B),
+    ("C", KeyCode::C),
+    ("D", KeyCode::D),
+    ("E", KeyCode::E),
+    ("F", KeyCode::F),
+    ("G", KeyCode::G),
+    ("H", KeyCode::H),
+    ("I", KeyCode::I),
+    ("J", KeyCode::J),
+    ("K", KeyCode::K),
+    ("L", KeyCode::L),
+    ("M", KeyCode::M),
+    ("N", KeyCode::N),
+    ("O", KeyCode::O),
+    ("P", KeyCode::P),
+    ("Q", KeyCode::Q),
+    ("R", KeyCode::R),
+    ("S", KeyCode::S),
+    ("T", KeyCode::T),
+    ("U", KeyCode::U),
+    ("V", KeyCode::V),
+    ("W", KeyCode::W),
+    ("X", KeyCode::X),
+    ("Y", KeyCode::Y),
+    ("Z", KeyCode::Z),
+    ("0", KeyCode::Key0),
+    ("1", KeyCode::Key1),
+    ("2", KeyCode::Key2),
+    ("3", KeyCode::Key3),
+    ("4", KeyCode::Key4),
+    ("5", KeyCode::Key5),
+    ("6", KeyCode::Key6),
+    ("7", KeyCode::Key7),
+    ("8", KeyCode::Key8),
+    ("9", KeyCode::Key9),
+    ("F1", KeyCode::F1),
+    ("F2", KeyCode::F2),
+    ("F3", KeyCode::F3),
+    ("F4", KeyCode::F4),
+    ("F5", KeyCode::F5),
+    ("F6", KeyCode::F6),
+    ("F7", KeyCode::F7),
+    ("F8", KeyCode::F8),
+    ("F9", KeyCode::F9),
+    ("F10", KeyCode::F10),
+    ("F11", KeyCode::F11),
+    ("F12", KeyCode::F12),
+    ("F13", KeyCode::F13),
+    ("F14", KeyCode::F14),
+    ("F15", KeyCode::F15),
+    ("F16", KeyCode::F16),
+    ("F17", KeyCode::F17),
+    ("F18", KeyCode::F18),
+    ("F19", KeyCode::F19),
+    ("F20", KeyCode::F20),
+    ("F21", KeyCode::F21),
+    ("F22", KeyCode::F22),
+    ("F23", KeyCode::F23),
+    ("F24", KeyCode::F24),
+    ("Escape", KeyCode::Escape),
+    ("Tab", KeyCode::Tab),
+    ("Space", KeyCode::Space),
+    ("Enter", KeyCode::Enter),
+    ("Backspace", KeyCode::Backspace),
+    ("Delete", KeyCode::Delete),
+    ("Insert", KeyCode::Insert),
+    ("Home", KeyCode::Home),
+    ("End", KeyCode::End),
+    ("PageUp", KeyCode::PageUp),
+    ("PageDown", KeyCode::PageDown),
+    ("Up", KeyCode::Up),
+    ("Down", KeyCode::Down),
+    ("Left", KeyCode::Left),
+    ("Right", KeyCode::Right),
+    ("Plus", KeyCode::Plus),
+    ("-", KeyCode::Minus),
+    ("=", KeyCode::Equals),
+    (",", KeyCode::Comma),
+    (".", KeyCode::Period),
+    ("/", KeyCode::Slash),
+    ("\\", KeyCode::Backslash),
+    (";", KeyCode::Semicolon),
+    ("'", KeyCode::Apostrophe),
+    ("`", KeyCode::Grave),
+    ("[", KeyCode::LBracket),
+    ("]", KeyCode::RBracket),
+];
@@ -20,6 +20,7 @@ pub mod mouse;
 
 mod background;
 mod color;
+mod direction;
 mod font;
 mod length;
 mod padding;
@@ -31,6 +32,7 @@ mod vector;
 pub use alignment::Alignment;
 pub use background::Background;
 pub use color::Color;
+pub use direction::Direction;
 pub use font::Font;
 pub use length::Length;
 pub use padding::Padding;
@@ -1,4 +1,4 @@
-use crate::Point;
+use crate::{Point, Vector};
 
 use super::Button;
 
@@ -22,6 +22,17 @@ pub enum Event {
         position: Point,
     },
 
+    /// The mouse was moved while the pointer is locked.
+    ///
+    /// While locked, the cursor is hidden and stays in place, so no
+    /// [`Event::CursorMoved`] events are produced. Instead, the relative
+    /// movement of the mouse is reported, which is not limited by the edges
+    /// of the window or the screen.
+    Motion {
+        /// The relative movement of the mouse, in unaccelerated device units
+        delta: Vector,
+    },
+
     /// A mouse button was pressed.
     ButtonPressed(Button),
 
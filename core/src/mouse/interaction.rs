@@ -11,6 +11,18 @@ pub enum Interaction {
     Grabbing,
     ResizingHorizontally,
     ResizingVertically,
+    ResizingDiagonallyUp,
+    ResizingDiagonallyDown,
+    Move,
+    NotAllowed,
+    Help,
+    ContextMenu,
+    Copy,
+    Alias,
+    Cell,
+    ZoomIn,
+    ZoomOut,
+    Hidden,
 }
 
 impl Default for Interaction {
@@ -31,7 +31,7 @@
 /// let widget = Widget::new().padding([10, 20]);        // top/bottom, left/right
 /// let widget = Widget::new().padding([5, 10, 15, 20]); // top, right, bottom, left
 /// ```
-#[derive(Debug, Hash, Copy, Clone)]
+#[derive(Debug, Hash, Copy, Clone, PartialEq, Eq)]
 pub struct Padding {
     /// Top padding
     pub top: u16,
@@ -4,10 +4,9 @@ mod scene;
 use controls::Controls;
 use scene::Scene;
 
-use iced_wgpu::{wgpu, Backend, Renderer, Settings, Viewport};
+use iced_wgpu::{wgpu, Engine, Settings, Viewport};
 use iced_winit::{conversion, futures, program, winit, Clipboard, Debug, Size};
 
-use futures::task::SpawnExt;
 use winit::{
     dpi::PhysicalPosition,
     event::{Event, ModifiersState, WindowEvent},
@@ -78,18 +77,14 @@ pub fn main() {
     };
     let mut resized = false;
 
-    // Initialize staging belt and local pool
-    let mut staging_belt = wgpu::util::StagingBelt::new(5 * 1024);
-    let mut local_pool = futures::executor::LocalPool::new();
-
     // Initialize scene and GUI controls
     let scene = Scene::new(&mut device);
     let controls = Controls::new();
 
     // Initialize iced
     let mut debug = Debug::new();
-    let mut renderer =
-        Renderer::new(Backend::new(&mut device, Settings::default(), format));
+    let mut engine = Engine::new(format);
+    let mut renderer = engine.create_renderer(&device, Settings::default());
 
     let mut state = program::State::new(
         controls,
@@ -195,20 +190,15 @@ pub fn main() {
                         }
 
                         // And then iced on top
-                        renderer.with_primitives(|backend, primitive| {
-                            backend.present(
-                                &mut device,
-                                &mut staging_belt,
-                                &mut encoder,
-                                &view,
-                                primitive,
-                                &viewport,
-                                &debug.overlay(),
-                            );
-                        });
+                        engine.render(
+                            &device,
+                            &mut renderer,
+                            &view,
+                            &viewport,
+                            &mut encoder,
+                        );
 
                         // Then we submit the work
-                        staging_belt.finish();
                         queue.submit(Some(encoder.finish()));
                         frame.present();
 
@@ -218,14 +208,6 @@ pub fn main() {
                                  state.mouse_interaction(),
                              ),
                          );
-
-                        // And recall staging buffers
-                        local_pool
-                            .spawner()
-                            .spawn(staging_belt.recall())
-                            .expect("Recall staging buffers");
-
-                        local_pool.run_until_stalled();
                     }
                     Err(error) => match error {
                         wgpu::SurfaceError::OutOfMemory => {
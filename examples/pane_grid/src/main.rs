@@ -1,7 +1,7 @@
 use iced::{
     alignment, button, executor, keyboard, pane_grid, scrollable, Alignment,
     Application, Button, Color, Column, Command, Container, Element, Length,
-    PaneGrid, Row, Scrollable, Settings, Subscription, Text,
+    PaneGrid, Rectangle, Row, Scrollable, Settings, Subscription, Text,
 };
 use iced_native::{event, subscription, Event};
 
@@ -23,6 +23,9 @@ enum Message {
     Clicked(pane_grid::Pane),
     Dragged(pane_grid::DragEvent),
     Resized(pane_grid::ResizeEvent),
+    Floated(pane_grid::FloatEvent),
+    ToggleFloat(pane_grid::Pane),
+    ToggleMaximize(pane_grid::Pane),
     TogglePin(pane_grid::Pane),
     Close(pane_grid::Pane),
     CloseFocused,
@@ -102,6 +105,43 @@ impl Application for Example {
                 self.panes.swap(&pane, &target);
             }
             Message::Dragged(_) => {}
+            Message::Floated(pane_grid::FloatEvent { pane, bounds }) => {
+                self.panes.place(&pane, bounds);
+            }
+            Message::ToggleFloat(pane) => {
+                if self.panes.floating(&pane).is_some() {
+                    let target = self
+                        .panes
+                        .iter()
+                        .map(|(pane, _)| *pane)
+                        .find(|pane| self.panes.floating(pane).is_none());
+
+                    if let Some(target) = target {
+                        let _ = self.panes.dock(
+                            &pane,
+                            &target,
+                            pane_grid::Axis::Vertical,
+                        );
+                    }
+                } else {
+                    let _ = self.panes.float(
+                        &pane,
+                        Rectangle {
+                            x: 50.0,
+                            y: 50.0,
+                            width: 300.0,
+                            height: 250.0,
+                        },
+                    );
+                }
+            }
+            Message::ToggleMaximize(pane) => {
+                if self.panes.maximized() == Some(pane) {
+                    self.panes.restore();
+                } else {
+                    self.panes.maximize(&pane);
+                }
+            }
             Message::TogglePin(pane) => {
                 if let Some(Pane { is_pinned, .. }) = self.panes.get_mut(&pane)
                 {
@@ -151,8 +191,16 @@ impl Application for Example {
         let focus = self.focus;
         let total_panes = self.panes.len();
 
+        let floating: Vec<_> = self
+            .panes
+            .iter()
+            .map(|(pane, _)| *pane)
+            .filter(|pane| self.panes.floating(pane).is_some())
+            .collect();
+
         let pane_grid = PaneGrid::new(&mut self.panes, |id, pane| {
             let is_focused = focus == Some(id);
+            let is_floating = floating.contains(&id);
 
             let text = if pane.is_pinned { "Unpin" } else { "Pin" };
             let pin_button =
@@ -187,6 +235,7 @@ impl Application for Example {
                 id,
                 total_panes,
                 pane.is_pinned,
+                is_floating,
             ))
             .title_bar(title_bar)
             .style(if is_focused {
@@ -200,7 +249,9 @@ impl Application for Example {
         .spacing(10)
         .on_click(Message::Clicked)
         .on_drag(Message::Dragged)
-        .on_resize(10, Message::Resized);
+        .on_resize(10, Message::Resized)
+        .on_float(Message::Floated)
+        .on_maximize(Message::ToggleMaximize);
 
         Container::new(pane_grid)
             .width(Length::Fill)
@@ -253,6 +304,7 @@ struct Content {
     scroll: scrollable::State,
     split_horizontally: button::State,
     split_vertically: button::State,
+    float: button::State,
     close: button::State,
 }
 
@@ -278,6 +330,7 @@ impl Content {
             scroll: scrollable::State::new(),
             split_horizontally: button::State::new(),
             split_vertically: button::State::new(),
+            float: button::State::new(),
             close: button::State::new(),
         }
     }
@@ -286,11 +339,13 @@ impl Content {
         pane: pane_grid::Pane,
         total_panes: usize,
         is_pinned: bool,
+        is_floating: bool,
     ) -> Element<Message> {
         let Content {
             scroll,
             split_horizontally,
             split_vertically,
+            float,
             close,
             ..
         } = self;
@@ -325,6 +380,15 @@ impl Content {
                 style::Button::Primary,
             ));
 
+        if total_panes > 1 {
+            controls = controls.push(button(
+                float,
+                if is_floating { "Dock" } else { "Float" },
+                Message::ToggleFloat(pane),
+                style::Button::Primary,
+            ));
+        }
+
         if total_panes > 1 && !is_pinned {
             controls = controls.push(button(
                 close,
@@ -41,14 +41,16 @@ impl Sandbox for ScrollableDemo {
             Message::ThemeChanged(theme) => self.theme = theme,
             Message::ScrollToTop(i) => {
                 if let Some(variant) = self.variants.get_mut(i) {
-                    variant.scrollable.snap_to(0.0);
+                    variant
+                        .scrollable
+                        .snap_to(scrollable::RelativeOffset::START);
 
                     variant.latest_offset = 0.0;
                 }
             }
             Message::ScrollToBottom(i) => {
                 if let Some(variant) = self.variants.get_mut(i) {
-                    variant.scrollable.snap_to(1.0);
+                    variant.scrollable.snap_to(scrollable::RelativeOffset::END);
 
                     variant.latest_offset = 1.0;
                 }
@@ -92,8 +94,11 @@ impl Sandbox for ScrollableDemo {
                             .spacing(10)
                             .width(Length::Fill)
                             .height(Length::Fill)
-                            .on_scroll(move |offset| {
-                                Message::Scrolled(i, offset)
+                            .on_scroll(move |viewport| {
+                                Message::Scrolled(
+                                    i,
+                                    viewport.relative_offset().y,
+                                )
                             })
                             .style(*theme)
                             .push(Text::new(variant.title))
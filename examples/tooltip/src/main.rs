@@ -3,6 +3,7 @@ use iced::{
     alignment, button, Alignment, Button, Column, Container, Element, Length,
     Row, Sandbox, Settings, Text,
 };
+use std::time::Duration;
 
 pub fn main() {
     Example::run(Settings::default()).unwrap()
@@ -10,11 +11,11 @@ pub fn main() {
 
 #[derive(Default)]
 struct Example {
-    top: button::State,
-    bottom: button::State,
-    right: button::State,
-    left: button::State,
-    follow_cursor: button::State,
+    top: (button::State, tooltip::State),
+    bottom: (button::State, tooltip::State),
+    right: (button::State, tooltip::State),
+    left: (button::State, tooltip::State),
+    follow_cursor: (button::State, tooltip::State),
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -94,10 +95,19 @@ impl Sandbox for Example {
 
 fn tooltip<'a>(
     label: &str,
-    button_state: &'a mut button::State,
+    (button_state, tooltip_state): &'a mut (button::State, tooltip::State),
     position: tooltip::Position,
 ) -> Element<'a, Message> {
+    let tooltip = Column::new()
+        .spacing(5)
+        .push(Text::new("Tooltip").size(20))
+        .push(Text::new(
+            "It is shown after a short delay and it lingers for a bit \
+            once the cursor leaves the button.",
+        ));
+
     Tooltip::new(
+        tooltip_state,
         Button::new(
             button_state,
             Text::new(label)
@@ -110,11 +120,14 @@ fn tooltip<'a>(
         .on_press(Message)
         .width(Length::Fill)
         .height(Length::Fill),
-        "Tooltip",
+        tooltip,
         position,
     )
     .gap(5)
     .padding(10)
+    .max_width(200)
+    .show_delay(Duration::from_millis(500))
+    .hide_delay(Duration::from_millis(300))
     .style(style::Tooltip)
     .into()
 }
@@ -48,6 +48,19 @@ pub trait Executor: Sized {
     where
         Self: Sized;
 
+    /// Creates a new [`Executor`] with the given [`Settings`].
+    ///
+    /// By default, the [`Settings`] are ignored and [`Executor::new`] is
+    /// used.
+    fn with_settings(settings: Settings) -> Result<Self, futures::io::Error>
+    where
+        Self: Sized,
+    {
+        let _ = settings;
+
+        Self::new()
+    }
+
     /// Spawns a future in the [`Executor`].
     #[cfg(not(target_arch = "wasm32"))]
     fn spawn(&self, future: impl Future<Output = ()> + Send + 'static);
@@ -56,6 +69,30 @@ pub trait Executor: Sized {
     #[cfg(target_arch = "wasm32")]
     fn spawn(&self, future: impl Future<Output = ()> + 'static);
 
+    /// Spawns a future in the background lane of the [`Executor`].
+    ///
+    /// The background lane is meant for heavy, non-urgent tasks, which
+    /// should not starve the futures spawned with [`Executor::spawn`].
+    ///
+    /// By default, the future runs to completion in its own thread.
+    #[cfg(not(target_arch = "wasm32"))]
+    fn spawn_background(
+        &self,
+        future: impl Future<Output = ()> + Send + 'static,
+    ) {
+        let _ = std::thread::Builder::new()
+            .name(String::from("iced background"))
+            .spawn(move || futures::executor::block_on(future));
+    }
+
+    /// Spawns a local future in the background lane of the [`Executor`].
+    ///
+    /// By default, it is the same as [`Executor::spawn`].
+    #[cfg(target_arch = "wasm32")]
+    fn spawn_background(&self, future: impl Future<Output = ()> + 'static) {
+        self.spawn(future);
+    }
+
     /// Runs the given closure inside the [`Executor`].
     ///
     /// Some executors, like `tokio`, require some global state to be in place
@@ -66,3 +103,14 @@ pub trait Executor: Sized {
         f()
     }
 }
+
+/// The settings of an [`Executor`].
+///
+/// Not every [`Executor`] can honor every setting.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Settings {
+    /// The amount of threads that run the futures of the [`Executor`].
+    ///
+    /// If `None`, the [`Executor`] chooses; usually, one thread per core.
+    pub threads: Option<usize>,
+}
@@ -1,4 +1,4 @@
-use crate::Executor;
+use crate::executor::{Executor, Settings};
 
 use futures::Future;
 
@@ -11,6 +11,16 @@ impl Executor for futures::executor::ThreadPool {
         futures::executor::ThreadPool::new()
     }
 
+    fn with_settings(settings: Settings) -> Result<Self, futures::io::Error> {
+        let mut builder = futures::executor::ThreadPool::builder();
+
+        if let Some(threads) = settings.threads {
+            let _ = builder.pool_size(threads);
+        }
+
+        builder.create()
+    }
+
     fn spawn(&self, future: impl Future<Output = ()> + Send + 'static) {
         self.spawn_ok(future);
     }
@@ -1,4 +1,4 @@
-use crate::Executor;
+use crate::executor::{Executor, Settings};
 
 use futures::Future;
 
@@ -11,10 +11,31 @@ impl Executor for Tokio {
         tokio::runtime::Runtime::new()
     }
 
+    fn with_settings(settings: Settings) -> Result<Self, futures::io::Error> {
+        let mut builder = tokio::runtime::Builder::new_multi_thread();
+
+        if let Some(threads) = settings.threads {
+            let _ = builder.worker_threads(threads);
+        }
+
+        builder.enable_all().build()
+    }
+
     fn spawn(&self, future: impl Future<Output = ()> + Send + 'static) {
         let _ = tokio::runtime::Runtime::spawn(self, future);
     }
 
+    fn spawn_background(
+        &self,
+        future: impl Future<Output = ()> + Send + 'static,
+    ) {
+        let handle = self.handle().clone();
+
+        let _ = tokio::runtime::Runtime::spawn_blocking(self, move || {
+            handle.block_on(future)
+        });
+    }
+
     fn enter<R>(&self, f: impl FnOnce() -> R) -> R {
         let _guard = tokio::runtime::Runtime::enter(self);
         f()
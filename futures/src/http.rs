@@ -0,0 +1,170 @@
+//! Perform HTTP requests and track their progress.
+//!
+//! The requests are performed with [`reqwest`], which needs the `tokio`
+//! executor.
+//!
+//! [`reqwest`]: https://docs.rs/reqwest
+use crate::progress::{self, Progress};
+use crate::BoxStream;
+
+use futures::channel::mpsc;
+use futures::stream::{self, StreamExt};
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// The minimum time between the [`Progress`] updates of a request.
+const PROGRESS_INTERVAL: Duration = Duration::from_millis(50);
+
+/// The size of the chunks of the body of an upload.
+const CHUNK_SIZE: usize = 64 * 1024;
+
+/// Downloads the resource at the given URL, producing its [`Progress`] and
+/// finally its bytes.
+pub fn download(
+    url: impl Into<String>,
+) -> BoxStream<Progress<Result<Vec<u8>, Error>>> {
+    enum State {
+        Ready(String),
+        Downloading {
+            response: reqwest::Response,
+            bytes: Vec<u8>,
+            total: Option<u64>,
+        },
+        Finished,
+    }
+
+    let progress =
+        stream::unfold(State::Ready(url.into()), |state| async move {
+            match state {
+                State::Ready(url) => {
+                    match reqwest::get(&url)
+                        .await
+                        .and_then(reqwest::Response::error_for_status)
+                    {
+                        Ok(response) => {
+                            let total = response.content_length();
+
+                            Some((
+                                Progress::Advanced { done: 0, total },
+                                State::Downloading {
+                                    response,
+                                    bytes: Vec::new(),
+                                    total,
+                                },
+                            ))
+                        }
+                        Err(error) => Some((
+                            Progress::Finished(Err(error.into())),
+                            State::Finished,
+                        )),
+                    }
+                }
+                State::Downloading {
+                    mut response,
+                    mut bytes,
+                    total,
+                } => match response.chunk().await {
+                    Ok(Some(chunk)) => {
+                        bytes.extend_from_slice(&chunk);
+
+                        Some((
+                            Progress::Advanced {
+                                done: bytes.len() as u64,
+                                total,
+                            },
+                            State::Downloading {
+                                response,
+                                bytes,
+                                total,
+                            },
+                        ))
+                    }
+                    Ok(None) => {
+                        Some((Progress::Finished(Ok(bytes)), State::Finished))
+                    }
+                    Err(error) => Some((
+                        Progress::Finished(Err(error.into())),
+                        State::Finished,
+                    )),
+                },
+                State::Finished => None,
+            }
+        });
+
+    progress::throttle(progress, PROGRESS_INTERVAL).boxed()
+}
+
+/// Uploads the given bytes to the given URL with a `POST` request, producing
+/// the [`Progress`] of the upload and finally the bytes of the response.
+pub fn upload(
+    url: impl Into<String>,
+    body: Vec<u8>,
+) -> BoxStream<Progress<Result<Vec<u8>, Error>>> {
+    let url = url.into();
+    let total = body.len() as u64;
+    let (sender, receiver) = mpsc::unbounded();
+
+    let chunks: Vec<Vec<u8>> =
+        body.chunks(CHUNK_SIZE).map(<[u8]>::to_vec).collect();
+
+    let mut done = 0;
+
+    let body = stream::iter(chunks).map(move |chunk| {
+        done += chunk.len() as u64;
+
+        let _ = sender.unbounded_send(Progress::Advanced {
+            done,
+            total: Some(total),
+        });
+
+        Ok::<_, std::io::Error>(chunk)
+    });
+
+    let response = async move {
+        let response = reqwest::Client::new()
+            .post(&url)
+            .body(reqwest::Body::wrap_stream(body))
+            .send()
+            .await
+            .and_then(reqwest::Response::error_for_status);
+
+        let bytes = match response {
+            Ok(response) => response.bytes().await.map(|bytes| bytes.to_vec()),
+            Err(error) => Err(error),
+        };
+
+        Progress::Finished(bytes.map_err(Error::from))
+    };
+
+    let progress = stream::select(
+        stream::once(async move {
+            Progress::Advanced {
+                done: 0,
+                total: Some(total),
+            }
+        })
+        .chain(receiver),
+        stream::once(response),
+    );
+
+    progress::throttle(progress, PROGRESS_INTERVAL).boxed()
+}
+
+/// An error that occurred while performing an HTTP request.
+#[derive(Debug, Clone)]
+pub struct Error(Arc<reqwest::Error>);
+
+impl From<reqwest::Error> for Error {
+    fn from(error: reqwest::Error) -> Self {
+        Error(Arc::new(error))
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl std::error::Error for Error {}
@@ -17,8 +17,13 @@ mod command;
 mod runtime;
 
 pub mod executor;
+pub mod progress;
 pub mod subscription;
 
+#[cfg(all(feature = "http", not(target_arch = "wasm32")))]
+#[cfg_attr(docsrs, doc(cfg(feature = "http")))]
+pub mod http;
+
 #[cfg(all(
     any(
         feature = "tokio",
@@ -0,0 +1,88 @@
+//! Track the progress of long-running tasks.
+use futures::stream::{Stream, StreamExt};
+
+/// The progress of a long-running task that eventually produces a `T`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Progress<T> {
+    /// The task has advanced.
+    Advanced {
+        /// The amount of work done so far; usually, a number of bytes.
+        done: u64,
+
+        /// The total amount of work of the task, if known.
+        total: Option<u64>,
+    },
+
+    /// The task has finished with the given output.
+    Finished(T),
+}
+
+impl<T> Progress<T> {
+    /// Returns the percentage of the task that is done, between `0.0` and
+    /// `100.0`, if known.
+    pub fn percentage(&self) -> Option<f32> {
+        match self {
+            Progress::Advanced { done, total } => {
+                let total = (*total).filter(|total| *total > 0)?;
+
+                Some((*done as f32 / total as f32 * 100.0).min(100.0))
+            }
+            Progress::Finished(_) => Some(100.0),
+        }
+    }
+
+    /// Returns true if the task has finished.
+    pub fn is_finished(&self) -> bool {
+        matches!(self, Progress::Finished(_))
+    }
+}
+
+/// Throttles a stream of [`Progress`], so that it advances at most once every
+/// `interval`.
+///
+/// The first and the [`Progress::Finished`] items are always produced. The
+/// stream ends after the latter.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn throttle<T>(
+    stream: impl Stream<Item = Progress<T>>,
+    interval: std::time::Duration,
+) -> impl Stream<Item = Progress<T>> {
+    use std::time::Instant;
+
+    stream
+        .scan(
+            (None::<Instant>, false),
+            move |(last_advanced, is_finished), progress| {
+                if *is_finished {
+                    return futures::future::ready(None);
+                }
+
+                let progress = match progress {
+                    Progress::Advanced { .. } => {
+                        let now = Instant::now();
+
+                        match last_advanced {
+                            Some(last)
+                                if now.duration_since(*last) < interval =>
+                            {
+                                None
+                            }
+                            _ => {
+                                *last_advanced = Some(now);
+
+                                Some(progress)
+                            }
+                        }
+                    }
+                    Progress::Finished(_) => {
+                        *is_finished = true;
+
+                        Some(progress)
+                    }
+                };
+
+                futures::future::ready(Some(progress))
+            },
+        )
+        .filter_map(futures::future::ready)
+}
@@ -1,6 +1,6 @@
 //! Run commands and keep track of subscriptions.
-use crate::BoxFuture;
 use crate::{subscription, Executor, Subscription};
+use crate::{BoxFuture, BoxStream};
 
 use futures::{channel::mpsc, Sink};
 use std::marker::PhantomData;
@@ -59,10 +59,39 @@ where
 
         let future = future.then(|message| async move {
             let _ = sender.send(message).await;
+        });
+
+        self.executor.spawn(future);
+    }
+
+    /// Spawns a [`Command`] in the background lane of the [`Runtime`].
+    ///
+    /// See [`Executor::spawn_background`] to learn more.
+    pub fn spawn_background(&mut self, future: BoxFuture<Message>) {
+        use futures::{FutureExt, SinkExt};
+
+        let mut sender = self.sender.clone();
 
-            ()
+        let future = future.then(|message| async move {
+            let _ = sender.send(message).await;
         });
 
+        self.executor.spawn_background(future);
+    }
+
+    /// Runs a [`Stream`] in the [`Runtime`].
+    ///
+    /// Every `Message` produced by the [`Stream`] will be forwarded to the
+    /// `Sender` of the [`Runtime`].
+    ///
+    /// [`Stream`]: futures::Stream
+    pub fn run(&mut self, stream: BoxStream<Message>) {
+        use futures::{FutureExt, StreamExt};
+
+        let sender = self.sender.clone();
+
+        let future = stream.map(Ok).forward(sender).map(|_| ());
+
         self.executor.spawn(future);
     }
 
@@ -1,8 +1,19 @@
 //! Listen to external events in your application.
 mod tracker;
 
+#[cfg(all(feature = "watch", not(target_arch = "wasm32")))]
+#[cfg_attr(docsrs, doc(cfg(feature = "watch")))]
+pub mod watch;
+
+#[cfg(all(feature = "websocket", not(target_arch = "wasm32")))]
+#[cfg_attr(docsrs, doc(cfg(feature = "websocket")))]
+pub mod websocket;
+
 pub use tracker::Tracker;
 
+#[cfg(all(feature = "watch", not(target_arch = "wasm32")))]
+pub use watch::{watch, watch_debounced};
+
 use crate::BoxStream;
 
 /// A request to listen to external events.
@@ -44,6 +55,21 @@ where
         }
     }
 
+    /// Creates a [`Subscription`] that runs the given stream, identified by
+    /// the given `id`.
+    ///
+    /// The stream keeps running as long as a [`Subscription`] with the same
+    /// `id` is requested. The stream provided afterwards is discarded, so
+    /// the `id` must change to restart it; for instance, when its parameters
+    /// change.
+    pub fn run_with_id<I>(id: I, stream: BoxStream<O>) -> Self
+    where
+        I: std::hash::Hash + 'static,
+        O: 'static,
+    {
+        Self::from_recipe(Run { id, stream })
+    }
+
     /// Batches all the provided subscriptions and returns the resulting
     /// [`Subscription`].
     pub fn batch(
@@ -84,6 +110,33 @@ where
         }
     }
 
+    /// Adds a key to the identity of the [`Subscription`], without changing
+    /// its output.
+    ///
+    /// The [`Subscription`] is restarted whenever the key changes. This is
+    /// useful to restart a [`Subscription`] when a parameter that its
+    /// [`Recipe`] does not hash changes.
+    pub fn keyed<K>(mut self, key: K) -> Self
+    where
+        H: 'static,
+        E: 'static,
+        O: 'static,
+        K: std::hash::Hash + Clone + 'static,
+    {
+        Subscription {
+            recipes: self
+                .recipes
+                .drain(..)
+                .map(|recipe| {
+                    Box::new(Keyed {
+                        recipe,
+                        key: key.clone(),
+                    }) as Box<dyn Recipe<H, E, Output = O>>
+                })
+                .collect(),
+        }
+    }
+
     /// Transforms the [`Subscription`] output with the given function.
     pub fn map<A>(mut self, f: fn(O) -> A) -> Subscription<H, E, A>
     where
@@ -191,6 +244,56 @@ where
     }
 }
 
+struct Run<I, O> {
+    id: I,
+    stream: BoxStream<O>,
+}
+
+impl<H, E, I, O> Recipe<H, E> for Run<I, O>
+where
+    H: std::hash::Hasher,
+    I: std::hash::Hash + 'static,
+    O: 'static,
+{
+    type Output = O;
+
+    fn hash(&self, state: &mut H) {
+        use std::hash::Hash;
+
+        std::any::TypeId::of::<Self>().hash(state);
+        self.id.hash(state);
+    }
+
+    fn stream(self: Box<Self>, _input: BoxStream<E>) -> BoxStream<O> {
+        self.stream
+    }
+}
+
+struct Keyed<Hasher, Event, Output, K> {
+    recipe: Box<dyn Recipe<Hasher, Event, Output = Output>>,
+    key: K,
+}
+
+impl<H, E, O, K> Recipe<H, E> for Keyed<H, E, O, K>
+where
+    H: std::hash::Hasher,
+    K: std::hash::Hash + 'static,
+{
+    type Output = O;
+
+    fn hash(&self, state: &mut H) {
+        use std::hash::Hash;
+
+        std::any::TypeId::of::<K>().hash(state);
+        self.key.hash(state);
+        self.recipe.hash(state);
+    }
+
+    fn stream(self: Box<Self>, input: BoxStream<E>) -> BoxStream<O> {
+        self.recipe.stream(input)
+    }
+}
+
 struct With<Hasher, Event, A, B> {
     recipe: Box<dyn Recipe<Hasher, Event, Output = A>>,
     value: B,
@@ -0,0 +1,152 @@
+//! Watch the file system for changes.
+use crate::subscription::{Recipe, Subscription};
+use crate::BoxStream;
+
+use futures::channel::mpsc;
+use futures::stream::{self, StreamExt};
+use notify::Watcher;
+use std::path::PathBuf;
+use std::sync::mpsc as std_mpsc;
+use std::time::Duration;
+
+/// A change in the file system.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Event {
+    /// A file or directory was created.
+    Created(PathBuf),
+
+    /// A file was written to, or its metadata changed.
+    Modified(PathBuf),
+
+    /// A file or directory was removed.
+    Removed(PathBuf),
+
+    /// A file or directory was renamed.
+    Renamed {
+        /// The previous path of the file or directory.
+        from: PathBuf,
+
+        /// The new path of the file or directory.
+        to: PathBuf,
+    },
+
+    /// The file system could not be watched.
+    Error(String),
+}
+
+/// Returns a [`Subscription`] that produces an [`Event`] whenever the file or
+/// directory at the given path changes.
+///
+/// Directories are watched recursively. Bursts of changes to the same file
+/// are debounced for 100 milliseconds.
+pub fn watch<H, E>(path: impl Into<PathBuf>) -> Subscription<H, E, Event>
+where
+    H: std::hash::Hasher,
+{
+    watch_debounced(path, Duration::from_millis(100))
+}
+
+/// Returns a [`Subscription`] that produces an [`Event`] whenever the file or
+/// directory at the given path changes, debouncing bursts of changes to the
+/// same file for the given `delay`.
+///
+/// Directories are watched recursively.
+pub fn watch_debounced<H, E>(
+    path: impl Into<PathBuf>,
+    delay: Duration,
+) -> Subscription<H, E, Event>
+where
+    H: std::hash::Hasher,
+{
+    Subscription::from_recipe(Watch {
+        path: path.into(),
+        delay,
+    })
+}
+
+struct Watch {
+    path: PathBuf,
+    delay: Duration,
+}
+
+impl<H, E> Recipe<H, E> for Watch
+where
+    H: std::hash::Hasher,
+{
+    type Output = Event;
+
+    fn hash(&self, state: &mut H) {
+        use std::hash::Hash;
+
+        std::any::TypeId::of::<Self>().hash(state);
+        self.path.hash(state);
+        self.delay.hash(state);
+    }
+
+    fn stream(self: Box<Self>, _input: BoxStream<E>) -> BoxStream<Event> {
+        let (sender, receiver) = mpsc::unbounded();
+        let (notify_sender, notify_receiver) = std_mpsc::channel();
+
+        let mut watcher = match notify::watcher(notify_sender, self.delay) {
+            Ok(watcher) => watcher,
+            Err(error) => {
+                return stream::once(
+                    async move { Event::Error(error.to_string()) },
+                )
+                .boxed();
+            }
+        };
+
+        if let Err(error) =
+            watcher.watch(&self.path, notify::RecursiveMode::Recursive)
+        {
+            return stream::once(
+                async move { Event::Error(error.to_string()) },
+            )
+            .boxed();
+        }
+
+        // The watcher lives in its own thread until the subscription is
+        // dropped and the receiving end of the channel is closed
+        let _ = std::thread::spawn(move || {
+            let _watcher = watcher;
+
+            while !sender.is_closed() {
+                let event = match notify_receiver
+                    .recv_timeout(Duration::from_millis(500))
+                {
+                    Ok(event) => event,
+                    Err(std_mpsc::RecvTimeoutError::Timeout) => continue,
+                    Err(std_mpsc::RecvTimeoutError::Disconnected) => break,
+                };
+
+                if let Some(event) = convert(event) {
+                    if sender.unbounded_send(event).is_err() {
+                        break;
+                    }
+                }
+            }
+        });
+
+        receiver.boxed()
+    }
+}
+
+fn convert(event: notify::DebouncedEvent) -> Option<Event> {
+    use notify::DebouncedEvent;
+
+    match event {
+        DebouncedEvent::Create(path) => Some(Event::Created(path)),
+        DebouncedEvent::Write(path) | DebouncedEvent::Chmod(path) => {
+            Some(Event::Modified(path))
+        }
+        DebouncedEvent::Remove(path) => Some(Event::Removed(path)),
+        DebouncedEvent::Rename(from, to) => Some(Event::Renamed { from, to }),
+        DebouncedEvent::Error(error, _) => {
+            Some(Event::Error(error.to_string()))
+        }
+        DebouncedEvent::NoticeWrite(_)
+        | DebouncedEvent::NoticeRemove(_)
+        | DebouncedEvent::Rescan => None,
+    }
+}
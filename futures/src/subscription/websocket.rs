@@ -0,0 +1,212 @@
+//! Maintain a WebSocket connection.
+use crate::subscription::{Recipe, Subscription};
+use crate::BoxStream;
+
+use async_tungstenite::async_std::{connect_async, ConnectStream};
+use async_tungstenite::tungstenite;
+use async_tungstenite::WebSocketStream;
+use futures::channel::mpsc;
+use futures::stream::{self, SplitSink, SplitStream, StreamExt};
+use futures::{FutureExt, SinkExt};
+use std::time::Duration;
+
+/// The delay before the first attempt to reconnect.
+const INITIAL_BACKOFF: Duration = Duration::from_secs(1);
+
+/// The maximum delay between attempts to reconnect.
+const MAXIMUM_BACKOFF: Duration = Duration::from_secs(30);
+
+/// An event of a WebSocket connection.
+#[derive(Debug, Clone)]
+pub enum Event {
+    /// The connection was established.
+    ///
+    /// Keep the [`Connection`] around to send messages to the server.
+    Connected(Connection),
+
+    /// The connection was lost, or could not be established.
+    ///
+    /// The [`Subscription`] keeps trying to reconnect, waiting longer after
+    /// every failed attempt.
+    Disconnected,
+
+    /// A [`Message`] was received from the server.
+    Received(Message),
+}
+
+/// A message sent through a WebSocket connection.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Message {
+    /// A text message.
+    Text(String),
+
+    /// A binary message.
+    Binary(Vec<u8>),
+}
+
+/// A handle to send messages through an established WebSocket connection.
+#[derive(Debug, Clone)]
+pub struct Connection(mpsc::UnboundedSender<Message>);
+
+impl Connection {
+    /// Sends a [`Message`] to the server.
+    ///
+    /// The [`Message`] is dropped if the connection has been lost since.
+    pub fn send(&self, message: Message) {
+        if self.0.unbounded_send(message).is_err() {
+            log::warn!("WebSocket message dropped: the connection is closed");
+        }
+    }
+}
+
+/// Returns a [`Subscription`] that maintains a WebSocket connection to the
+/// given URL, reconnecting with an exponential backoff whenever it is lost.
+pub fn connect<H, E>(url: impl Into<String>) -> Subscription<H, E, Event>
+where
+    H: std::hash::Hasher,
+{
+    Subscription::from_recipe(Connect { url: url.into() })
+}
+
+struct Connect {
+    url: String,
+}
+
+enum State {
+    Disconnected {
+        backoff: Option<Duration>,
+    },
+    Connected {
+        sink: SplitSink<WebSocketStream<ConnectStream>, tungstenite::Message>,
+        stream: Box<SplitStream<WebSocketStream<ConnectStream>>>,
+        outgoing: mpsc::UnboundedReceiver<Message>,
+    },
+}
+
+impl<H, E> Recipe<H, E> for Connect
+where
+    H: std::hash::Hasher,
+{
+    type Output = Event;
+
+    fn hash(&self, state: &mut H) {
+        use std::hash::Hash;
+
+        std::any::TypeId::of::<Self>().hash(state);
+        self.url.hash(state);
+    }
+
+    fn stream(self: Box<Self>, _input: BoxStream<E>) -> BoxStream<Event> {
+        let url = self.url;
+
+        stream::unfold(State::Disconnected { backoff: None }, move |state| {
+            let url = url.clone();
+
+            async move { Some(next(&url, state).await) }
+        })
+        .boxed()
+    }
+}
+
+async fn next(url: &str, mut state: State) -> (Event, State) {
+    loop {
+        state = match state {
+            State::Disconnected { backoff } => {
+                if let Some(backoff) = backoff {
+                    futures_timer::Delay::new(backoff).await;
+                }
+
+                return match connect_async(url).await {
+                    Ok((websocket, _)) => {
+                        let (sender, outgoing) = mpsc::unbounded();
+                        let (sink, stream) = websocket.split();
+
+                        (
+                            Event::Connected(Connection(sender)),
+                            State::Connected {
+                                sink,
+                                stream: Box::new(stream),
+                                outgoing,
+                            },
+                        )
+                    }
+                    Err(error) => {
+                        log::warn!("WebSocket connection failed: {}", error);
+
+                        let backoff = backoff
+                            .map(|backoff| (backoff * 2).min(MAXIMUM_BACKOFF))
+                            .unwrap_or(INITIAL_BACKOFF);
+
+                        (
+                            Event::Disconnected,
+                            State::Disconnected {
+                                backoff: Some(backoff),
+                            },
+                        )
+                    }
+                };
+            }
+            State::Connected {
+                mut sink,
+                mut stream,
+                mut outgoing,
+            } => {
+                let disconnected = (
+                    Event::Disconnected,
+                    State::Disconnected {
+                        backoff: Some(INITIAL_BACKOFF),
+                    },
+                );
+
+                futures::select! {
+                    received = stream.next().fuse() => {
+                        let message = match received {
+                            Some(Ok(tungstenite::Message::Text(text))) => {
+                                Some(Message::Text(text))
+                            }
+                            Some(Ok(tungstenite::Message::Binary(bytes))) => {
+                                Some(Message::Binary(bytes))
+                            }
+                            Some(Ok(_)) => None,
+                            Some(Err(_)) | None => return disconnected,
+                        };
+
+                        let state = State::Connected {
+                            sink,
+                            stream,
+                            outgoing,
+                        };
+
+                        match message {
+                            Some(message) => {
+                                return (Event::Received(message), state);
+                            }
+                            None => state,
+                        }
+                    }
+                    message = outgoing.select_next_some() => {
+                        let message = match message {
+                            Message::Text(text) => {
+                                tungstenite::Message::Text(text)
+                            }
+                            Message::Binary(bytes) => {
+                                tungstenite::Message::Binary(bytes)
+                            }
+                        };
+
+                        if sink.send(message).await.is_err() {
+                            return disconnected;
+                        }
+
+                        State::Connected {
+                            sink,
+                            stream,
+                            outgoing,
+                        }
+                    }
+                    complete => return disconnected,
+                }
+            }
+        };
+    }
+}
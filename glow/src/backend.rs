@@ -5,6 +5,7 @@ use crate::{Settings, Transformation, Viewport};
 
 use iced_graphics::backend;
 use iced_graphics::font;
+use iced_graphics::layer;
 use iced_graphics::{Layer, Primitive};
 use iced_native::alignment;
 use iced_native::{Font, Size};
@@ -56,15 +57,26 @@ impl Backend {
         let scale_factor = viewport.scale_factor() as f32;
         let projection = viewport.projection();
 
-        let mut layers = Layer::generate(primitives, viewport);
-        layers.push(Layer::overlay(overlay_text, viewport));
+        let layers = Layer::generate(primitives, viewport);
 
-        for layer in layers {
+        let overlay: Vec<String> = if overlay_text.is_empty() {
+            Vec::new()
+        } else {
+            overlay_text
+                .iter()
+                .map(|line| String::from(line.as_ref()))
+                .chain(layer::Stats::of(&layers).overlay())
+                .collect()
+        };
+
+        for layer in layers.iter().chain(&[Layer::overlay(&overlay, viewport)])
+        {
             self.flush(
                 gl,
+                viewport,
                 scale_factor,
                 projection,
-                &layer,
+                layer,
                 viewport_size.height,
             );
         }
@@ -73,17 +85,21 @@ impl Backend {
     fn flush(
         &mut self,
         gl: &glow::Context,
+        viewport: &Viewport,
         scale_factor: f32,
         transformation: Transformation,
         layer: &Layer<'_>,
         target_height: u32,
     ) {
-        let mut bounds = (layer.bounds * scale_factor).snap();
+        let bounds = (layer.bounds * scale_factor).snap();
 
         if bounds.width < 1 || bounds.height < 1 {
             return;
         }
 
+        // Scissor rectangles are in the coordinates of the target
+        let mut bounds = viewport.rotate_bounds(bounds);
+
         bounds.height = bounds.height.min(target_height);
 
         if !layer.quads.is_empty() {
@@ -105,7 +121,7 @@ impl Backend {
                 gl,
                 target_height,
                 scaled,
-                scale_factor,
+                viewport,
                 &layer.meshes,
             );
         }
@@ -215,6 +231,10 @@ impl backend::Text for Backend {
         self.text_pipeline.measure(contents, size, font, bounds)
     }
 
+    fn baseline(&self, size: f32, font: Font) -> f32 {
+        self.text_pipeline.baseline(size, font)
+    }
+
     fn hit_test(
         &self,
         contents: &str,
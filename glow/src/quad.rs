@@ -13,10 +13,8 @@ pub struct Pipeline {
     instances: <glow::Context as HasContext>::Buffer,
     transform_location: <glow::Context as HasContext>::UniformLocation,
     scale_location: <glow::Context as HasContext>::UniformLocation,
-    screen_height_location: <glow::Context as HasContext>::UniformLocation,
     current_transform: Transformation,
     current_scale: f32,
-    current_target_height: u32,
 }
 
 impl Pipeline {
@@ -39,10 +37,6 @@ impl Pipeline {
             unsafe { gl.get_uniform_location(program, "u_Scale") }
                 .expect("Get scale location");
 
-        let screen_height_location =
-            unsafe { gl.get_uniform_location(program, "u_ScreenHeight") }
-                .expect("Get target height location");
-
         unsafe {
             gl.use_program(Some(program));
 
@@ -54,7 +48,6 @@ impl Pipeline {
             );
 
             gl.uniform_1_f32(Some(&scale_location), 1.0);
-            gl.uniform_1_f32(Some(&screen_height_location), 0.0);
 
             gl.use_program(None);
         }
@@ -68,10 +61,8 @@ impl Pipeline {
             instances,
             transform_location,
             scale_location,
-            screen_height_location,
             current_transform: Transformation::identity(),
             current_scale: 1.0,
-            current_target_height: 0,
         }
     }
 
@@ -84,6 +75,9 @@ impl Pipeline {
         scale: f32,
         bounds: Rectangle<u32>,
     ) {
+        #[cfg(feature = "tracing")]
+        let _span = iced_graphics::tracing::info_span!("quads").entered();
+
         unsafe {
             gl.enable(glow::SCISSOR_TEST);
             gl.scissor(
@@ -119,17 +113,6 @@ impl Pipeline {
             self.current_scale = scale;
         }
 
-        if target_height != self.current_target_height {
-            unsafe {
-                gl.uniform_1_f32(
-                    Some(&self.screen_height_location),
-                    target_height as f32,
-                );
-            }
-
-            self.current_target_height = target_height;
-        }
-
         let mut i = 0;
         let total = instances.len();
 
@@ -1,13 +1,12 @@
 #version 330
 
-uniform float u_ScreenHeight;
-
 in vec4 v_Color;
 in vec4 v_BorderColor;
 in vec2 v_Pos;
 in vec2 v_Scale;
 in float v_BorderRadius;
 in float v_BorderWidth;
+in vec2 v_FragPos;
 
 out vec4 o_Color;
 
@@ -32,7 +31,7 @@ float distance(in vec2 frag_coord, in vec2 position, in vec2 size, float radius)
 void main() {
     vec4 mixed_color;
 
-    vec2 fragCoord = vec2(gl_FragCoord.x, u_ScreenHeight - gl_FragCoord.y);
+    vec2 fragCoord = v_FragPos;
 
     // TODO: Remove branching (?)
     if(v_BorderWidth > 0) {
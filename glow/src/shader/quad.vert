@@ -16,6 +16,7 @@ out vec2 v_Pos;
 out vec2 v_Scale;
 out float v_BorderRadius;
 out float v_BorderWidth;
+out vec2 v_FragPos;
 
 const vec2 positions[4] = vec2[](
     vec2(0.0, 0.0),
@@ -47,6 +48,7 @@ void main() {
     v_Scale = p_Scale;
     v_BorderRadius = i_BorderRadius * u_Scale;
     v_BorderWidth = i_BorderWidth * u_Scale;
+    v_FragPos = (i_Transform * vec4(q_Pos, 0.0, 1.0)).xy;
 
     gl_Position = u_Transform * i_Transform * vec4(q_Pos, 0.0, 1.0);
 }
@@ -1,6 +1,6 @@
 //! Draw meshes of triangles.
 use crate::program;
-use crate::Transformation;
+use crate::{Transformation, Viewport};
 use glow::HasContext;
 use iced_graphics::layer;
 use std::marker::PhantomData;
@@ -111,9 +111,12 @@ impl Pipeline {
         gl: &glow::Context,
         target_height: u32,
         transformation: Transformation,
-        scale_factor: f32,
+        viewport: &Viewport,
         meshes: &[layer::Mesh<'_>],
     ) {
+        #[cfg(feature = "tracing")]
+        let _span = iced_graphics::tracing::info_span!("triangles").entered();
+
         unsafe {
             gl.enable(glow::MULTISAMPLE);
             gl.enable(glow::SCISSOR_TEST);
@@ -176,7 +179,9 @@ impl Pipeline {
             let transform =
                 transformation * Transformation::translate(origin.x, origin.y);
 
-            let clip_bounds = (*clip_bounds * scale_factor).snap();
+            let clip_bounds = viewport.rotate_bounds(
+                (*clip_bounds * viewport.scale_factor() as f32).snap(),
+            );
 
             unsafe {
                 if self.current_transform != transform {
@@ -1,7 +1,7 @@
 //! Decorate content and apply alignment.
 use crate::Renderer;
 
-pub use iced_graphics::container::{Style, StyleSheet};
+pub use iced_graphics::container::{Phase, Style, StyleSheet};
 
 /// An element decorating some content.
 ///
@@ -0,0 +1,12 @@
+//! Disable the interaction of a whole subtree of widgets.
+use crate::Renderer;
+
+pub use iced_native::widget::disabled::{disabled, Style, StyleSheet};
+
+/// A widget that disables the interaction of its content and all of its
+/// children.
+///
+/// This is an alias of an `iced_native` disabled widget with an
+/// `iced_glow::Renderer`.
+pub type Disabled<'a, Message> =
+    iced_native::widget::Disabled<'a, Message, Renderer>;
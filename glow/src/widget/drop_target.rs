@@ -0,0 +1,14 @@
+//! Accept data dropped by the user.
+//!
+//! A [`DropTarget`] has some local [`State`].
+use crate::Renderer;
+
+pub use iced_graphics::drop_target::{Style, StyleSheet};
+pub use iced_native::widget::drop_target::State;
+
+/// A widget that accepts payloads of type `T` dropped on top of its content.
+///
+/// This is an alias of an `iced_native` drop target with an
+/// `iced_glow::Renderer`.
+pub type DropTarget<'a, T, Message> =
+    iced_native::widget::DropTarget<'a, T, Message, Renderer>;
@@ -0,0 +1,14 @@
+//! Coordinate the inputs of a form, validating them and submitting them
+//! together.
+//!
+//! The value of each input of a [`Form`] is kept in a [`Field`].
+use crate::Renderer;
+
+pub use iced_native::widget::form::Field;
+
+/// A widget that submits the inputs inside of its content when the enter
+/// key is pressed.
+///
+/// This is an alias of an `iced_native` form with an
+/// `iced_glow::Renderer`.
+pub type Form<'a, Message> = iced_native::widget::Form<'a, Message, Renderer>;
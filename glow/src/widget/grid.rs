@@ -0,0 +1,11 @@
+//! Distribute content in rows and columns.
+use crate::Renderer;
+
+/// A container that places its contents in the cells of a grid.
+///
+/// This is an alias of an `iced_native` grid with an `iced_glow::Renderer`.
+pub type Grid<'a, Message> = iced_native::widget::Grid<'a, Message, Renderer>;
+
+/// An element placed in a [`Grid`], spanning one or more rows and columns.
+pub type Cell<'a, Message> =
+    iced_native::widget::grid::Cell<'a, Message, Renderer>;
@@ -0,0 +1,13 @@
+//! Reuse the widgets of some content as long as its data does not change.
+//!
+//! A [`Lazy`] has some local [`State`].
+use crate::Renderer;
+
+/// The local state of a [`Lazy`].
+pub type State<Message> = iced_native::widget::lazy::State<Message, Renderer>;
+
+/// A widget that only builds its content when the data it depends on changes.
+///
+/// This is an alias of an `iced_native` lazy with an
+/// `iced_glow::Renderer`.
+pub type Lazy<'a, Message> = iced_native::widget::Lazy<'a, Message, Renderer>;
@@ -0,0 +1,13 @@
+//! Navigate through a collection split in pages.
+//!
+//! A [`Pagination`] has some local [`State`].
+use crate::Renderer;
+
+pub use iced_graphics::pagination::{Style, StyleSheet};
+pub use iced_native::widget::pagination::State;
+
+/// A control to navigate through a collection split in pages.
+///
+/// This is an alias of an `iced_native` pagination with an `iced_glow::Renderer`.
+pub type Pagination<'a, Message> =
+    iced_native::widget::Pagination<'a, Message, Renderer>;
@@ -0,0 +1,13 @@
+//! Build content that adapts to the space available to it.
+//!
+//! A [`Responsive`] has some local [`State`].
+use crate::Renderer;
+
+pub use iced_native::widget::responsive::State;
+
+/// A widget that produces its content from the space available to it.
+///
+/// This is an alias of an `iced_native` responsive with an
+/// `iced_glow::Renderer`.
+pub type Responsive<'a, Message> =
+    iced_native::widget::Responsive<'a, Message, Renderer>;
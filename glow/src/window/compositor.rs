@@ -67,7 +67,7 @@ impl iced_graphics::window::GLCompositor for Compositor {
     ) {
         let gl = &self.gl;
 
-        let [r, g, b, a] = color.into_linear();
+        let [r, g, b, a] = color.into_linear_premultiplied();
 
         unsafe {
             gl.clear_color(r, g, b, a);
@@ -78,4 +78,37 @@ impl iced_graphics::window::GLCompositor for Compositor {
             backend.present(gl, primitive, viewport, overlay);
         });
     }
+
+    fn screenshot<T: AsRef<str>>(
+        &mut self,
+        renderer: &mut Self::Renderer,
+        viewport: &Viewport,
+        color: Color,
+        overlay: &[T],
+    ) -> Vec<u8> {
+        self.present(renderer, viewport, color, overlay);
+
+        let size = viewport.physical_size();
+        let row = 4 * size.width as usize;
+        let mut pixels = vec![0; row * size.height as usize];
+
+        unsafe {
+            self.gl.read_pixels(
+                0,
+                0,
+                size.width as i32,
+                size.height as i32,
+                glow::RGBA,
+                glow::UNSIGNED_BYTE,
+                &mut pixels,
+            );
+        }
+
+        // OpenGL reads the rows from bottom to top
+        pixels
+            .chunks_exact(row)
+            .rev()
+            .flat_map(|row| row.iter().copied())
+            .collect()
+    }
 }
@@ -1,18 +1,22 @@
 //! Create interactive, native cross-platform applications.
-use crate::mouse;
 use crate::{Error, Executor, Runtime};
 
 pub use iced_winit::Application;
 
 use iced_graphics::window;
 use iced_winit::application;
-use iced_winit::conversion;
 use iced_winit::futures;
 use iced_winit::futures::channel::mpsc;
-use iced_winit::{Cache, Clipboard, Debug, Proxy, Settings};
+use iced_winit::renderer;
+use iced_winit::text;
+use iced_winit::widget;
+use iced_winit::{Cache, Clipboard, Debug, NativeMenu, Proxy, Settings};
 
+use glutin::event_loop::ControlFlow;
 use glutin::window::Window;
+use iced_native::window::{RedrawRequest, Screenshot};
 use std::mem::ManuallyDrop;
+use std::time::Instant;
 
 /// Runs an [`Application`] with an executor, compositor, and the provided
 /// settings.
@@ -22,6 +26,7 @@ pub fn run<A, E, C>(
 ) -> Result<(), Error>
 where
     A: Application + 'static,
+    A::Renderer: text::Renderer,
     E: Executor + 'static,
     C: window::GLCompositor<Renderer = A::Renderer> + 'static,
 {
@@ -38,13 +43,14 @@ where
     let mut proxy = event_loop.create_proxy();
 
     let mut runtime = {
-        let executor = E::new().map_err(Error::ExecutorCreationFailed)?;
+        let executor = E::with_settings(settings.executor)
+            .map_err(Error::ExecutorCreationFailed)?;
         let proxy = Proxy::new(event_loop.create_proxy());
 
         Runtime::new(executor, proxy)
     };
 
-    let (application, init_command) = {
+    let (mut application, init_command) = {
         let flags = settings.flags;
 
         runtime.enter(|| A::new(flags))
@@ -89,17 +95,33 @@ where
     };
 
     let mut clipboard = Clipboard::connect(context.window());
+    let mut operations = Vec::new();
+    let mut screenshots = Vec::new();
+    let mut frame_pacing =
+        application::FramePacing::new(settings.frame_rate_cap);
 
-    application::run_command(
+    let close_requested = application::run_command(
         init_command,
         &mut runtime,
         &mut clipboard,
         &mut proxy,
+        &mut operations,
+        &mut screenshots,
+        &mut frame_pacing,
         context.window(),
+        &mut application::DragResize::new(),
     );
+
+    if close_requested {
+        application::run_shutdown(&mut application, &runtime);
+
+        return Ok(());
+    }
+
     runtime.track(subscription);
 
     let (mut sender, receiver) = mpsc::unbounded();
+    let (control_sender, control_receiver) = std::sync::mpsc::channel();
 
     let mut instance = Box::pin(run_instance::<A, E, C>(
         application,
@@ -108,17 +130,20 @@ where
         runtime,
         clipboard,
         proxy,
+        operations,
+        screenshots,
+        frame_pacing,
         debug,
         receiver,
+        control_sender,
         context,
         settings.exit_on_close_request,
+        settings.keyboard_zoom,
     ));
 
     let mut context = task::Context::from_waker(task::noop_waker_ref());
 
     event_loop.run_return(move |event, _, control_flow| {
-        use glutin::event_loop::ControlFlow;
-
         if let ControlFlow::Exit = control_flow {
             return;
         }
@@ -143,10 +168,18 @@ where
 
             let poll = instance.as_mut().poll(&mut context);
 
-            *control_flow = match poll {
-                task::Poll::Pending => ControlFlow::Wait,
-                task::Poll::Ready(_) => ControlFlow::Exit,
-            };
+            match poll {
+                task::Poll::Pending => {
+                    // The instance reports how long it can wait for new
+                    // events, as widgets may be animating
+                    while let Ok(flow) = control_receiver.try_recv() {
+                        *control_flow = flow;
+                    }
+                }
+                task::Poll::Ready(_) => {
+                    *control_flow = ControlFlow::Exit;
+                }
+            }
         }
     });
 
@@ -160,12 +193,18 @@ async fn run_instance<A, E, C>(
     mut runtime: Runtime<E, Proxy<A::Message>, A::Message>,
     mut clipboard: Clipboard,
     mut proxy: glutin::event_loop::EventLoopProxy<A::Message>,
+    mut operations: Vec<Box<dyn widget::Operation<A::Message>>>,
+    mut screenshots: Vec<Box<dyn Fn(Screenshot) -> A::Message>>,
+    mut frame_pacing: application::FramePacing,
     mut debug: Debug,
     mut receiver: mpsc::UnboundedReceiver<glutin::event::Event<'_, A::Message>>,
+    control_sender: std::sync::mpsc::Sender<ControlFlow>,
     mut context: glutin::ContextWrapper<glutin::PossiblyCurrent, Window>,
     exit_on_close_request: bool,
+    keyboard_zoom: bool,
 ) where
     A: Application + 'static,
+    A::Renderer: text::Renderer,
     E: Executor + 'static,
     C: window::GLCompositor<Renderer = A::Renderer> + 'static,
 {
@@ -174,90 +213,195 @@ async fn run_instance<A, E, C>(
 
     let mut state = application::State::new(&application, context.window());
     let mut viewport_version = state.viewport_version();
+
+    let mut native_menu =
+        NativeMenu::new(application.menu_bar(), context.window(), &proxy);
+
+    let mut shortcuts = application.shortcuts().extend(native_menu.shortcuts());
+    application::report_shortcut_conflicts(&shortcuts);
+
     let mut user_interface =
         ManuallyDrop::new(application::build_user_interface(
             &mut application,
+            &mut native_menu,
             Cache::default(),
             &mut renderer,
             state.logical_size(),
             &mut debug,
         ));
 
-    let mut mouse_interaction = mouse::Interaction::default();
-    let mut events = Vec::new();
+    application::run_operations(
+        &mut user_interface,
+        &mut operations,
+        &mut proxy,
+    );
+
+    let mut input = application::Input::new(&state);
     let mut messages = Vec::new();
+    let mut redraw_request = None;
+    let mut last_frame = None;
 
     debug.startup_finished();
 
     while let Some(event) = receiver.next().await {
         match event {
             event::Event::MainEventsCleared => {
-                if events.is_empty() && messages.is_empty() {
-                    continue;
+                let now = Instant::now();
+
+                let is_redraw_due = match redraw_request {
+                    Some(RedrawRequest::NextFrame) => true,
+                    Some(RedrawRequest::At(at)) => at <= now,
+                    None => false,
+                };
+
+                if is_redraw_due {
+                    redraw_request = None;
+
+                    input.push(iced_native::Event::Window(
+                        iced_native::window::Event::RedrawRequested(now),
+                    ));
                 }
 
-                debug.event_processing_started();
+                input.recognize_gestures(now);
 
-                let statuses = user_interface.update(
-                    &events,
-                    state.cursor_position(),
+                if input.is_empty() && messages.is_empty() {
+                    control_sender
+                        .send(control_flow(redraw_request))
+                        .expect("Send control flow");
+
+                    continue;
+                }
+
+                let (events, request) = input.process(
+                    &mut user_interface,
+                    &mut state,
+                    context.window(),
                     &mut renderer,
                     &mut clipboard,
+                    &shortcuts,
+                    keyboard_zoom,
                     &mut messages,
+                    &mut operations,
+                    &mut debug,
                 );
 
-                debug.event_processing_finished();
+                for request in [request, state.redraw_request()] {
+                    redraw_request = match (redraw_request, request) {
+                        (Some(current), Some(new)) => Some(current.min(new)),
+                        (current, new) => current.or(new),
+                    };
+                }
+
+                control_sender
+                    .send(control_flow(redraw_request))
+                    .expect("Send control flow");
+
+                application::run_operations(
+                    &mut user_interface,
+                    &mut operations,
+                    &mut proxy,
+                );
 
-                for event in events.drain(..).zip(statuses.into_iter()) {
-                    runtime.broadcast(event);
+                for (event, status) in events {
+                    runtime.broadcast((
+                        iced_native::window::Id::MAIN,
+                        event,
+                        status,
+                    ));
                 }
 
-                if !messages.is_empty() {
+                if !messages.is_empty() || user_interface.is_outdated() {
                     let cache =
                         ManuallyDrop::into_inner(user_interface).into_cache();
 
                     // Update application
-                    application::update(
+                    let close_requested = application::update(
                         &mut application,
                         &mut runtime,
                         &mut clipboard,
                         &mut proxy,
+                        &mut operations,
+                        &mut screenshots,
+                        &mut frame_pacing,
                         &mut debug,
                         &mut messages,
                         context.window(),
+                        input.drag_resize(),
                     );
 
                     // Update window
                     state.synchronize(&application, context.window());
 
-                    let should_exit = application.should_exit();
+                    native_menu.update(application.menu_bar());
+                    shortcuts =
+                        application.shortcuts().extend(native_menu.shortcuts());
+
+                    let should_exit =
+                        close_requested || application.should_exit();
 
                     user_interface =
                         ManuallyDrop::new(application::build_user_interface(
                             &mut application,
+                            &mut native_menu,
                             cache,
                             &mut renderer,
                             state.logical_size(),
                             &mut debug,
                         ));
 
+                    application::run_operations(
+                        &mut user_interface,
+                        &mut operations,
+                        &mut proxy,
+                    );
+
+                    // Let the new widgets request redraws, so animations
+                    // started by the application get their first frame
+                    let _ = user_interface.update(
+                        &[iced_native::Event::Window(
+                            iced_native::window::Event::RedrawRequested(
+                                Instant::now(),
+                            ),
+                        )],
+                        state.cursor_position(),
+                        &mut renderer,
+                        &mut clipboard,
+                        &mut messages,
+                    );
+
+                    for request in [
+                        state.redraw_request(),
+                        user_interface.redraw_request(),
+                    ] {
+                        redraw_request = match (redraw_request, request) {
+                            (Some(current), Some(new)) => {
+                                Some(current.min(new))
+                            }
+                            (current, new) => current.or(new),
+                        };
+                    }
+
+                    control_sender
+                        .send(control_flow(redraw_request))
+                        .expect("Send control flow");
+
                     if should_exit {
                         break;
                     }
                 }
 
                 debug.draw_started();
-                let new_mouse_interaction =
-                    user_interface.draw(&mut renderer, state.cursor_position());
+                let new_mouse_interaction = user_interface.draw(
+                    &mut renderer,
+                    &renderer::Style::from(state.theme()),
+                    state.cursor_position(),
+                );
                 debug.draw_finished();
 
-                if new_mouse_interaction != mouse_interaction {
-                    context.window().set_cursor_icon(
-                        conversion::mouse_interaction(new_mouse_interaction),
-                    );
-
-                    mouse_interaction = new_mouse_interaction;
-                }
+                input.set_mouse_interaction(
+                    context.window(),
+                    new_mouse_interaction,
+                );
 
                 context.window().request_redraw();
             }
@@ -265,7 +409,7 @@ async fn run_instance<A, E, C>(
                 event::MacOS::ReceivedUrl(url),
             )) => {
                 use iced_native::event;
-                events.push(iced_native::Event::PlatformSpecific(
+                input.push(iced_native::Event::PlatformSpecific(
                     event::PlatformSpecific::MacOS(event::MacOS::ReceivedUrl(
                         url,
                     )),
@@ -274,7 +418,36 @@ async fn run_instance<A, E, C>(
             event::Event::UserEvent(message) => {
                 messages.push(message);
             }
+            event::Event::Suspended | event::Event::Resumed => {
+                input.push(iced_native::Event::Window(
+                    iced_native::window::Event::Occluded(matches!(
+                        event,
+                        event::Event::Suspended
+                    )),
+                ));
+            }
             event::Event::RedrawRequested(_) => {
+                if let Some(next_frame) =
+                    frame_pacing.delay(last_frame, Instant::now())
+                {
+                    redraw_request = Some(match redraw_request {
+                        Some(current) => {
+                            current.min(RedrawRequest::At(next_frame))
+                        }
+                        None => RedrawRequest::At(next_frame),
+                    });
+
+                    control_sender
+                        .send(control_flow(redraw_request))
+                        .expect("Send control flow");
+
+                    continue;
+                }
+
+                // The swap interval of the OpenGL context cannot be changed
+                // at runtime
+                let _ = frame_pacing.take_present_mode();
+
                 debug.render_started();
 
                 #[allow(unsafe_code)]
@@ -300,19 +473,17 @@ async fn run_instance<A, E, C>(
                     debug.layout_finished();
 
                     debug.draw_started();
-                    let new_mouse_interaction = user_interface
-                        .draw(&mut renderer, state.cursor_position());
+                    let new_mouse_interaction = user_interface.draw(
+                        &mut renderer,
+                        &renderer::Style::from(state.theme()),
+                        state.cursor_position(),
+                    );
                     debug.draw_finished();
 
-                    if new_mouse_interaction != mouse_interaction {
-                        context.window().set_cursor_icon(
-                            conversion::mouse_interaction(
-                                new_mouse_interaction,
-                            ),
-                        );
-
-                        mouse_interaction = new_mouse_interaction;
-                    }
+                    input.set_mouse_interaction(
+                        context.window(),
+                        new_mouse_interaction,
+                    );
 
                     context.resize(glutin::dpi::PhysicalSize::new(
                         physical_size.width,
@@ -324,14 +495,53 @@ async fn run_instance<A, E, C>(
                     viewport_version = current_viewport_version;
                 }
 
-                compositor.present(
-                    &mut renderer,
-                    state.viewport(),
-                    state.background_color(),
-                    &debug.overlay(),
-                );
+                debug.present_started();
+                if screenshots.is_empty() {
+                    compositor.present(
+                        &mut renderer,
+                        state.viewport(),
+                        state.background_color(),
+                        &debug.overlay(),
+                    );
+                } else {
+                    let screenshot = Screenshot::new(
+                        compositor.screenshot(
+                            &mut renderer,
+                            state.viewport(),
+                            state.background_color(),
+                            &debug.overlay(),
+                        ),
+                        state.viewport().physical_size(),
+                    );
+
+                    for tag in screenshots.drain(..) {
+                        proxy
+                            .send_event(tag(screenshot.clone()))
+                            .expect("Send message to event loop");
+                    }
+                }
 
                 context.swap_buffers().expect("Swap buffers");
+                debug.present_finished();
+
+                let now = Instant::now();
+                last_frame = Some(now);
+
+                if frame_pacing.is_continuous(iced_native::window::Id::MAIN) {
+                    redraw_request = Some(RedrawRequest::NextFrame);
+
+                    control_sender
+                        .send(control_flow(redraw_request))
+                        .expect("Send control flow");
+                }
+
+                runtime.broadcast((
+                    iced_native::window::Id::MAIN,
+                    iced_native::Event::Window(
+                        iced_native::window::Event::FramePresented(now),
+                    ),
+                    iced_native::event::Status::Ignored,
+                ));
 
                 debug.render_finished();
 
@@ -348,15 +558,18 @@ async fn run_instance<A, E, C>(
                     break;
                 }
 
-                state.update(context.window(), &window_event, &mut debug);
-
-                if let Some(event) = conversion::window_event(
+                input.handle_window_event(
+                    &mut state,
+                    context.window(),
                     &window_event,
-                    state.scale_factor(),
-                    state.modifiers(),
-                ) {
-                    events.push(event);
-                }
+                    &mut debug,
+                );
+            }
+            event::Event::DeviceEvent {
+                event: glutin::event::DeviceEvent::MouseMotion { delta },
+                ..
+            } => {
+                input.handle_mouse_motion(&state, delta);
             }
             _ => {}
         }
@@ -364,4 +577,14 @@ async fn run_instance<A, E, C>(
 
     // Manually drop the user interface
     drop(ManuallyDrop::into_inner(user_interface));
+
+    application::run_shutdown(&mut application, &runtime);
+}
+
+fn control_flow(redraw_request: Option<RedrawRequest>) -> ControlFlow {
+    match redraw_request {
+        Some(RedrawRequest::NextFrame) => ControlFlow::Poll,
+        Some(RedrawRequest::At(at)) => ControlFlow::WaitUntil(at),
+        None => ControlFlow::Wait,
+    }
 }
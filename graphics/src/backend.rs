@@ -1,4 +1,11 @@
 //! Write a graphics backend.
+//!
+//! A backend implements [`Backend`] and the traits of the primitives it
+//! supports, and draws the layers generated by [`Layer::generate`].
+//! `iced_software` is a small reference implementation built on `tiny-skia`
+//! that runs on the CPU.
+//!
+//! [`Layer::generate`]: crate::Layer::generate
 use iced_native::image;
 use iced_native::svg;
 use iced_native::text;
@@ -45,6 +52,10 @@ pub trait Text {
         bounds: Size,
     ) -> (f32, f32);
 
+    /// Returns the distance from the top of a line of text with the given
+    /// size and font to its baseline.
+    fn baseline(&self, size: f32, font: Font) -> f32;
+
     /// Tests whether the provided point is within the boundaries of [`Text`]
     /// laid out with the given parameters, returning information about
     /// the nearest character.
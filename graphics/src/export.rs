@@ -0,0 +1,365 @@
+//! Export primitives to vector formats, for printing or reporting.
+//!
+//! The primitives drawn by a [`Renderer`] already carry the layout of the
+//! widget tree, so exporting them produces the same picture shown on the
+//! screen:
+//!
+//! ```
+//! use iced_graphics::export;
+//! use iced_graphics::{Background, Color, Primitive, Rectangle, Size};
+//!
+//! let quad = Primitive::Quad {
+//!     bounds: Rectangle {
+//!         x: 10.0,
+//!         y: 10.0,
+//!         width: 80.0,
+//!         height: 30.0,
+//!     },
+//!     background: Background::Color(Color::BLACK),
+//!     border_radius: 5.0,
+//!     border_width: 0.0,
+//!     border_color: Color::TRANSPARENT,
+//! };
+//!
+//! let svg = export::svg(&[quad], Size::new(100.0, 50.0));
+//!
+//! assert!(svg.contains("<rect"));
+//! ```
+//!
+//! [`Renderer`]: crate::Renderer
+use crate::alignment;
+use crate::triangle;
+use crate::{Background, Color, Font, Primitive, Rectangle, Size};
+
+use iced_native::{image, svg};
+
+use std::fmt::Write;
+
+/// Converts the given primitives into an SVG document of the given logical
+/// [`Size`].
+///
+/// Text is exported as SVG text, so it is rendered with the fonts available
+/// to the viewer. Images made of raw pixels are not exported.
+pub fn svg(primitives: &[Primitive], size: Size) -> String {
+    let mut exporter = Exporter {
+        output: String::new(),
+        clips: 0,
+    };
+
+    let _ = write!(
+        exporter.output,
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" \
+         xmlns:xlink=\"http://www.w3.org/1999/xlink\" \
+         width=\"{width}\" height=\"{height}\" \
+         viewBox=\"0 0 {width} {height}\">",
+        width = size.width,
+        height = size.height,
+    );
+
+    for primitive in primitives {
+        exporter.primitive(primitive);
+    }
+
+    exporter.output.push_str("</svg>");
+    exporter.output
+}
+
+struct Exporter {
+    output: String,
+    clips: usize,
+}
+
+impl Exporter {
+    fn primitive(&mut self, primitive: &Primitive) {
+        match primitive {
+            Primitive::None => {}
+            Primitive::Group { primitives } => {
+                for primitive in primitives {
+                    self.primitive(primitive);
+                }
+            }
+            Primitive::Text {
+                content,
+                bounds,
+                color,
+                size,
+                font,
+                horizontal_alignment,
+                vertical_alignment,
+            } => {
+                let anchor = match horizontal_alignment {
+                    alignment::Horizontal::Left => "start",
+                    alignment::Horizontal::Center => "middle",
+                    alignment::Horizontal::Right => "end",
+                };
+
+                let baseline = match vertical_alignment {
+                    alignment::Vertical::Top => "text-before-edge",
+                    alignment::Vertical::Center => "central",
+                    alignment::Vertical::Bottom => "text-after-edge",
+                };
+
+                let family = match font {
+                    Font::Default => "sans-serif",
+                    Font::External { name, .. } => name,
+                };
+
+                let _ = write!(
+                    self.output,
+                    "<text x=\"{}\" y=\"{}\" font-size=\"{}\" \
+                     font-family=\"{}\" text-anchor=\"{}\" \
+                     dominant-baseline=\"{}\"{}>",
+                    bounds.x,
+                    bounds.y,
+                    size,
+                    escape(family),
+                    anchor,
+                    baseline,
+                    fill(*color),
+                );
+
+                for (i, line) in content.lines().enumerate() {
+                    let _ = write!(
+                        self.output,
+                        "<tspan x=\"{}\" dy=\"{}\">{}</tspan>",
+                        bounds.x,
+                        if i == 0 { 0.0 } else { *size },
+                        escape(line),
+                    );
+                }
+
+                self.output.push_str("</text>");
+            }
+            Primitive::Quad {
+                bounds,
+                background,
+                border_radius,
+                border_width,
+                border_color,
+            } => {
+                let Background::Color(background) = background;
+
+                let _ = write!(
+                    self.output,
+                    "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" \
+                     rx=\"{}\"{}",
+                    bounds.x,
+                    bounds.y,
+                    bounds.width,
+                    bounds.height,
+                    border_radius,
+                    fill(*background),
+                );
+
+                if *border_width > 0.0 {
+                    let (hex, alpha) = rgb(*border_color);
+
+                    // Borders are drawn inside the bounds of a quad
+                    let _ = write!(
+                        self.output,
+                        "/><rect x=\"{}\" y=\"{}\" width=\"{}\" \
+                         height=\"{}\" rx=\"{}\" fill=\"none\" \
+                         stroke=\"{}\" stroke-opacity=\"{}\" \
+                         stroke-width=\"{}\"",
+                        bounds.x + border_width / 2.0,
+                        bounds.y + border_width / 2.0,
+                        (bounds.width - border_width).max(0.0),
+                        (bounds.height - border_width).max(0.0),
+                        (border_radius - border_width / 2.0).max(0.0),
+                        hex,
+                        alpha,
+                        border_width,
+                    );
+                }
+
+                self.output.push_str("/>");
+            }
+            Primitive::Image { handle, bounds } => {
+                let href = match handle.data() {
+                    image::Data::Path(path) => escape(&path.to_string_lossy()),
+                    image::Data::Bytes(bytes) => {
+                        data_uri(image_mime_type(bytes), bytes)
+                    }
+                    image::Data::Pixels { .. } => return,
+                };
+
+                self.image(&href, *bounds);
+            }
+            Primitive::Svg { handle, bounds, .. } => {
+                let href = match handle.data() {
+                    svg::Data::Path(path) => escape(&path.to_string_lossy()),
+                    svg::Data::Bytes(bytes) => data_uri("image/svg+xml", bytes),
+                };
+
+                self.image(&href, *bounds);
+            }
+            Primitive::Clip { bounds, content } => {
+                let id = self.clip(*bounds);
+
+                let _ =
+                    write!(self.output, "<g clip-path=\"url(#clip{})\">", id);
+                self.primitive(content);
+                self.output.push_str("</g>");
+            }
+            Primitive::Translate {
+                translation,
+                content,
+            } => {
+                let _ = write!(
+                    self.output,
+                    "<g transform=\"translate({} {})\">",
+                    translation.x, translation.y
+                );
+                self.primitive(content);
+                self.output.push_str("</g>");
+            }
+            Primitive::Mesh2D { buffers, size } => {
+                let id = self.clip(Rectangle::with_size(*size));
+
+                let _ =
+                    write!(self.output, "<g clip-path=\"url(#clip{})\">", id);
+                self.mesh(buffers);
+                self.output.push_str("</g>");
+            }
+            Primitive::Cached { cache } => {
+                self.primitive(cache);
+            }
+        }
+    }
+
+    fn image(&mut self, href: &str, bounds: Rectangle) {
+        let _ = write!(
+            self.output,
+            "<image x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" \
+             preserveAspectRatio=\"none\" xlink:href=\"{}\"/>",
+            bounds.x, bounds.y, bounds.width, bounds.height, href,
+        );
+    }
+
+    fn clip(&mut self, bounds: Rectangle) -> usize {
+        let id = self.clips;
+        self.clips += 1;
+
+        let _ = write!(
+            self.output,
+            "<clipPath id=\"clip{}\"><rect x=\"{}\" y=\"{}\" width=\"{}\" \
+             height=\"{}\"/></clipPath>",
+            id, bounds.x, bounds.y, bounds.width, bounds.height,
+        );
+
+        id
+    }
+
+    fn mesh(&mut self, mesh: &triangle::Mesh2D) {
+        // SVG cannot interpolate colors between vertices, so every triangle
+        // is filled with the average color of its vertices
+        for triangle in mesh.indices.chunks_exact(3) {
+            let vertices = [
+                mesh.vertices[triangle[0] as usize],
+                mesh.vertices[triangle[1] as usize],
+                mesh.vertices[triangle[2] as usize],
+            ];
+
+            let mut color = [0.0; 4];
+
+            for vertex in &vertices {
+                for (channel, value) in color.iter_mut().zip(&vertex.color) {
+                    *channel += value / 3.0;
+                }
+            }
+
+            let [r, g, b, a] = color;
+
+            let _ = write!(
+                self.output,
+                "<path d=\"M{} {}L{} {}L{} {}Z\"{}/>",
+                vertices[0].position[0],
+                vertices[0].position[1],
+                vertices[1].position[0],
+                vertices[1].position[1],
+                vertices[2].position[0],
+                vertices[2].position[1],
+                fill(Color::new(to_srgb(r), to_srgb(g), to_srgb(b), a)),
+            );
+        }
+    }
+}
+
+fn rgb(color: Color) -> (String, f32) {
+    let [r, g, b, a] = color.into_rgba8();
+
+    (
+        format!("#{:02x}{:02x}{:02x}", r, g, b),
+        f32::from(a) / 255.0,
+    )
+}
+
+fn fill(color: Color) -> String {
+    let (hex, alpha) = rgb(color);
+
+    format!(" fill=\"{}\" fill-opacity=\"{}\"", hex, alpha)
+}
+
+fn to_srgb(linear: f32) -> f32 {
+    if linear <= 0.003_130_8 {
+        linear * 12.92
+    } else {
+        1.055 * linear.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            _ => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+fn image_mime_type(bytes: &[u8]) -> &'static str {
+    if bytes.starts_with(b"\x89PNG") {
+        "image/png"
+    } else if bytes.starts_with(b"\xff\xd8\xff") {
+        "image/jpeg"
+    } else if bytes.starts_with(b"GIF8") {
+        "image/gif"
+    } else if bytes.starts_with(b"BM") {
+        "image/bmp"
+    } else {
+        "application/octet-stream"
+    }
+}
+
+fn data_uri(mime_type: &str, bytes: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] =
+        b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+    let mut uri = format!("data:{};base64,", mime_type);
+
+    for chunk in bytes.chunks(3) {
+        let n = chunk
+            .iter()
+            .enumerate()
+            .fold(0u32, |n, (i, byte)| n | u32::from(*byte) << (16 - 8 * i));
+
+        for i in 0..4 {
+            if i <= chunk.len() {
+                let index = (n >> (18 - 6 * i)) & 0x3f;
+                uri.push(char::from(ALPHABET[index as usize]));
+            } else {
+                uri.push('=');
+            }
+        }
+    }
+
+    uri
+}
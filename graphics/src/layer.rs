@@ -4,7 +4,8 @@ use crate::image;
 use crate::svg;
 use crate::triangle;
 use crate::{
-    Background, Font, Point, Primitive, Rectangle, Size, Vector, Viewport,
+    Background, Color, Font, Point, Primitive, Rectangle, Size, Vector,
+    Viewport,
 };
 
 /// A group of primitives that should be clipped together.
@@ -41,17 +42,36 @@ impl<'a> Layer<'a> {
     /// Creates a new [`Layer`] for the provided overlay text.
     ///
     /// This can be useful for displaying debug information.
+    ///
+    /// Any line made only of the block elements from `▁` to `█` is drawn as a
+    /// bar graph, with a bar for every character.
     pub fn overlay(lines: &'a [impl AsRef<str>], viewport: &Viewport) -> Self {
         let mut overlay =
             Layer::new(Rectangle::with_size(viewport.logical_size()));
 
         for (i, line) in lines.iter().enumerate() {
+            let top = 11.0 + 25.0 * i as f32;
+
+            if let Some(levels) = graph_levels(line.as_ref()) {
+                for (j, level) in levels.enumerate() {
+                    let height = 20.0 * f32::from(level) / 8.0;
+
+                    overlay.quads.push(Quad {
+                        position: [11.0 + 4.0 * j as f32, top + 20.0 - height],
+                        size: [3.0, height],
+                        color: [0.9, 0.9, 0.9, 1.0],
+                        border_color: [0.0, 0.0, 0.0, 1.0],
+                        border_radius: 0.0,
+                        border_width: 0.5,
+                    });
+                }
+
+                continue;
+            }
+
             let text = Text {
                 content: line.as_ref(),
-                bounds: Rectangle::new(
-                    Point::new(11.0, 11.0 + 25.0 * i as f32),
-                    Size::INFINITY,
-                ),
+                bounds: Rectangle::new(Point::new(11.0, top), Size::INFINITY),
                 color: [0.9, 0.9, 0.9, 1.0],
                 size: 20.0,
                 font: Font::Default,
@@ -77,6 +97,9 @@ impl<'a> Layer<'a> {
         primitives: &'a [Primitive],
         viewport: &Viewport,
     ) -> Vec<Self> {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::info_span!("layers").entered();
+
         let first_layer =
             Layer::new(Rectangle::with_size(viewport.logical_size()));
 
@@ -221,11 +244,16 @@ impl<'a> Layer<'a> {
                     bounds: *bounds + translation,
                 });
             }
-            Primitive::Svg { handle, bounds } => {
+            Primitive::Svg {
+                handle,
+                color,
+                bounds,
+            } => {
                 let layer = &mut layers[current_layer];
 
                 layer.images.push(Image::Vector {
                     handle: handle.clone(),
+                    color: *color,
                     bounds: *bounds + translation,
                 });
             }
@@ -233,6 +261,77 @@ impl<'a> Layer<'a> {
     }
 }
 
+/// The amount of primitives in a list of layers.
+///
+/// This can be useful for displaying debug information.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Stats {
+    /// The amount of layers.
+    pub layers: usize,
+
+    /// The amount of quads.
+    pub quads: usize,
+
+    /// The amount of triangle meshes.
+    pub meshes: usize,
+
+    /// The amount of text sections.
+    pub text: usize,
+
+    /// The amount of images.
+    pub images: usize,
+
+    /// The amount of draw calls, which is the amount of pipelines flushed for
+    /// every layer.
+    pub draw_calls: usize,
+}
+
+impl Stats {
+    /// Counts the primitives in the given layers.
+    pub fn of(layers: &[Layer<'_>]) -> Self {
+        layers.iter().fold(Self::default(), |stats, layer| {
+            let pipelines = [
+                layer.quads.len(),
+                layer.meshes.len(),
+                layer.text.len(),
+                layer.images.len(),
+            ];
+
+            Self {
+                layers: stats.layers + 1,
+                quads: stats.quads + layer.quads.len(),
+                meshes: stats.meshes + layer.meshes.len(),
+                text: stats.text + layer.text.len(),
+                images: stats.images + layer.images.len(),
+                draw_calls: stats.draw_calls
+                    + pipelines.iter().filter(|amount| **amount > 0).count(),
+            }
+        })
+    }
+
+    /// Returns the lines of text that describe the [`Stats`] in an overlay.
+    pub fn overlay(&self) -> Vec<String> {
+        vec![
+            format!(
+                "Primitives: {} quads, {} meshes, {} text, {} images",
+                self.quads, self.meshes, self.text, self.images
+            ),
+            format!("Layers: {} ({} draw calls)", self.layers, self.draw_calls),
+        ]
+    }
+}
+
+fn graph_levels(line: &str) -> Option<impl Iterator<Item = u8> + '_> {
+    let is_graph = !line.is_empty()
+        && line.chars().all(|c| ('\u{2581}'..='\u{2588}').contains(&c));
+
+    if is_graph {
+        Some(line.chars().map(|c| (c as u32 - 0x2580) as u8))
+    } else {
+        None
+    }
+}
+
 /// A colored rectangle with a border.
 ///
 /// This type can be directly uploaded to GPU memory.
@@ -312,6 +411,9 @@ pub enum Image {
         /// The handle of a vector image.
         handle: svg::Handle,
 
+        /// The color `currentColor` resolves to, if any.
+        color: Option<Color>,
+
         /// The bounds of the image.
         bounds: Rectangle,
     },
@@ -20,6 +20,7 @@ mod transformation;
 mod viewport;
 
 pub mod backend;
+pub mod export;
 pub mod font;
 pub mod layer;
 pub mod overlay;
@@ -38,9 +39,13 @@ pub use layer::Layer;
 pub use primitive::Primitive;
 pub use renderer::Renderer;
 pub use transformation::Transformation;
-pub use viewport::Viewport;
+pub use viewport::{Rotation, Viewport};
+
+#[cfg(feature = "tracing")]
+#[doc(hidden)]
+pub use tracing;
 
 pub use iced_native::alignment;
 pub use iced_native::{
-    Alignment, Background, Color, Font, Point, Rectangle, Size, Vector,
+    Alignment, Background, Color, Font, Padding, Point, Rectangle, Size, Vector,
 };
@@ -1,6 +1,6 @@
 use iced_native::image;
 use iced_native::svg;
-use iced_native::{Background, Color, Font, Rectangle, Size, Vector};
+use iced_native::{Background, Color, Font, Point, Rectangle, Size, Vector};
 
 use crate::alignment;
 use crate::triangle;
@@ -59,6 +59,9 @@ pub enum Primitive {
         /// The path of the SVG file
         handle: svg::Handle,
 
+        /// The color `currentColor` resolves to, if any
+        color: Option<Color>,
+
         /// The bounds of the viewport
         bounds: Rectangle,
     },
@@ -104,3 +107,130 @@ impl Default for Primitive {
         Primitive::None
     }
 }
+
+impl Primitive {
+    /// Multiplies the opacity of the [`Primitive`] by the given factor.
+    ///
+    /// Images cannot be faded and are left untouched.
+    pub fn fade(&mut self, opacity: f32) {
+        match self {
+            Primitive::None | Primitive::Image { .. } => {}
+            Primitive::Group { primitives } => {
+                for primitive in primitives {
+                    primitive.fade(opacity);
+                }
+            }
+            Primitive::Text { color, .. } => {
+                color.a *= opacity;
+            }
+            Primitive::Quad {
+                background,
+                border_color,
+                ..
+            } => {
+                match background {
+                    Background::Color(color) => color.a *= opacity,
+                }
+
+                border_color.a *= opacity;
+            }
+            Primitive::Svg { color, .. } => {
+                if let Some(color) = color {
+                    color.a *= opacity;
+                }
+            }
+            Primitive::Clip { content, .. }
+            | Primitive::Translate { content, .. } => {
+                content.fade(opacity);
+            }
+            Primitive::Mesh2D { buffers, .. } => {
+                for vertex in &mut buffers.vertices {
+                    vertex.color[3] *= opacity;
+                }
+            }
+            Primitive::Cached { cache } => {
+                let mut primitive = Primitive::clone(cache);
+                primitive.fade(opacity);
+
+                *self = primitive;
+            }
+        }
+    }
+
+    /// Scales the [`Primitive`] by the given factor, around the given
+    /// `origin`.
+    pub fn scale(&mut self, origin: Point, factor: f32) {
+        let scale_point = |point: Point| origin + (point - origin) * factor;
+
+        let scale_rectangle = |bounds: Rectangle| {
+            let position = scale_point(Point::new(bounds.x, bounds.y));
+
+            Rectangle {
+                x: position.x,
+                y: position.y,
+                width: bounds.width * factor,
+                height: bounds.height * factor,
+            }
+        };
+
+        match self {
+            Primitive::None => {}
+            Primitive::Group { primitives } => {
+                for primitive in primitives {
+                    primitive.scale(origin, factor);
+                }
+            }
+            Primitive::Text { bounds, size, .. } => {
+                *bounds = scale_rectangle(*bounds);
+                *size *= factor;
+            }
+            Primitive::Quad {
+                bounds,
+                border_radius,
+                border_width,
+                ..
+            } => {
+                *bounds = scale_rectangle(*bounds);
+                *border_radius *= factor;
+                *border_width *= factor;
+            }
+            Primitive::Image { bounds, .. } | Primitive::Svg { bounds, .. } => {
+                *bounds = scale_rectangle(*bounds);
+            }
+            Primitive::Clip { bounds, content } => {
+                *bounds = scale_rectangle(*bounds);
+                content.scale(origin, factor);
+            }
+            Primitive::Translate {
+                translation,
+                content,
+            } => {
+                *translation = *translation * factor;
+                content.scale(origin, factor);
+            }
+            Primitive::Mesh2D { buffers, size } => {
+                // The drawable region of a mesh starts at its origin, so the
+                // mesh is scaled around it and translated afterwards
+                let mut buffers = buffers.clone();
+
+                for vertex in &mut buffers.vertices {
+                    vertex.position[0] *= factor;
+                    vertex.position[1] *= factor;
+                }
+
+                let size = Size::new(size.width * factor, size.height * factor);
+
+                *self = Primitive::Translate {
+                    translation: scale_point(Point::ORIGIN) - Point::ORIGIN,
+                    content: Box::new(Primitive::Mesh2D { buffers, size }),
+                };
+            }
+            Primitive::Cached { cache } => {
+                let mut primitive = Primitive::clone(cache);
+                primitive.scale(origin, factor);
+
+                *self = primitive;
+            }
+        }
+    }
+}
@@ -6,6 +6,8 @@ use iced_native::renderer;
 use iced_native::text::{self, Text};
 use iced_native::{Background, Element, Font, Point, Rectangle, Size};
 
+use std::sync::Arc;
+
 pub use iced_native::renderer::Style;
 
 /// A backend-agnostic renderer that supports all the built-in widgets.
@@ -93,6 +95,75 @@ where
         });
     }
 
+    fn with_cache(
+        &mut self,
+        cache: &renderer::Cache,
+        key: u64,
+        f: impl FnOnce(&mut Self),
+    ) {
+        let primitive = match cache.get::<Arc<Primitive>>(key) {
+            Some(primitive) => primitive,
+            None => {
+                let current_primitives = std::mem::take(&mut self.primitives);
+
+                f(self);
+
+                let primitives =
+                    std::mem::replace(&mut self.primitives, current_primitives);
+
+                let primitive = Arc::new(Primitive::Group { primitives });
+                cache.store(key, primitive.clone());
+
+                primitive
+            }
+        };
+
+        self.primitives.push(Primitive::Cached { cache: primitive });
+    }
+
+    fn with_opacity(&mut self, opacity: f32, f: impl FnOnce(&mut Self)) {
+        let current_primitives = std::mem::take(&mut self.primitives);
+
+        f(self);
+
+        let mut layer_primitives =
+            std::mem::replace(&mut self.primitives, current_primitives);
+
+        if opacity < 1.0 {
+            for primitive in &mut layer_primitives {
+                primitive.fade(opacity);
+            }
+        }
+
+        self.primitives.push(Primitive::Group {
+            primitives: layer_primitives,
+        });
+    }
+
+    fn with_scale(
+        &mut self,
+        origin: Point,
+        scale: f32,
+        f: impl FnOnce(&mut Self),
+    ) {
+        let current_primitives = std::mem::take(&mut self.primitives);
+
+        f(self);
+
+        let mut layer_primitives =
+            std::mem::replace(&mut self.primitives, current_primitives);
+
+        if (scale - 1.0).abs() > f32::EPSILON {
+            for primitive in &mut layer_primitives {
+                primitive.scale(origin, scale);
+            }
+        }
+
+        self.primitives.push(Primitive::Group {
+            primitives: layer_primitives,
+        });
+    }
+
     fn fill_quad(
         &mut self,
         quad: renderer::Quad,
@@ -137,6 +208,10 @@ where
             .measure(content, f32::from(size), font, bounds)
     }
 
+    fn baseline(&self, size: u16, font: Font) -> f32 {
+        self.backend().baseline(f32::from(size), font)
+    }
+
     fn hit_test(
         &self,
         content: &str,
@@ -1,4 +1,4 @@
-use crate::{Size, Transformation};
+use crate::{Padding, Point, Rectangle, Size, Transformation};
 
 /// A viewing region for displaying computer graphics.
 #[derive(Debug, Clone)]
@@ -6,6 +6,8 @@ pub struct Viewport {
     physical_size: Size<u32>,
     logical_size: Size<f32>,
     scale_factor: f64,
+    rotation: Rotation,
+    padding: Padding,
     projection: Transformation,
 }
 
@@ -15,12 +17,71 @@ impl Viewport {
     pub fn with_physical_size(size: Size<u32>, scale_factor: f64) -> Viewport {
         Viewport {
             physical_size: size,
-            logical_size: Size::new(
-                (size.width as f64 / scale_factor) as f32,
-                (size.height as f64 / scale_factor) as f32,
-            ),
+            logical_size: Size::ZERO,
             scale_factor,
-            projection: Transformation::orthographic(size.width, size.height),
+            rotation: Rotation::None,
+            padding: Padding::ZERO,
+            projection: Transformation::identity(),
+        }
+        .project()
+    }
+
+    /// Rotates the contents of the [`Viewport`] clockwise by the given
+    /// [`Rotation`].
+    ///
+    /// The physical size of the [`Viewport`] stays the same, while its
+    /// logical size is swapped for quarter turns.
+    pub fn with_rotation(self, rotation: Rotation) -> Viewport {
+        Viewport { rotation, ..self }.project()
+    }
+
+    /// Insets the contents of the [`Viewport`] by the given logical
+    /// [`Padding`], like the safe area of the screen of a phone.
+    ///
+    /// The [`Padding`] is applied to the sides of the target, before the
+    /// contents are rotated.
+    pub fn with_padding(self, padding: Padding) -> Viewport {
+        Viewport { padding, ..self }.project()
+    }
+
+    fn project(self) -> Viewport {
+        let bounds = self.physical_bounds();
+        let Rectangle { width, height, .. } = bounds;
+
+        let logical_size = Size::new(
+            (width as f64 / self.scale_factor) as f32,
+            (height as f64 / self.scale_factor) as f32,
+        );
+
+        let (logical_size, rotate) = match self.rotation {
+            Rotation::None => (logical_size, Transformation::identity()),
+            Rotation::Clockwise90 => (
+                Size::new(logical_size.height, logical_size.width),
+                Transformation::translate(width as f32, 0.0)
+                    * Transformation::rotate(std::f32::consts::FRAC_PI_2),
+            ),
+            Rotation::Clockwise180 => (
+                logical_size,
+                Transformation::translate(width as f32, height as f32)
+                    * Transformation::rotate(std::f32::consts::PI),
+            ),
+            Rotation::Clockwise270 => (
+                Size::new(logical_size.height, logical_size.width),
+                Transformation::translate(0.0, height as f32)
+                    * Transformation::rotate(3.0 * std::f32::consts::FRAC_PI_2),
+            ),
+        };
+
+        Viewport {
+            logical_size,
+            projection: Transformation::orthographic(
+                self.physical_size.width,
+                self.physical_size.height,
+            ) * Transformation::translate(
+                bounds.x as f32,
+                bounds.y as f32,
+            ) * rotate,
+            ..self
         }
     }
 
@@ -49,8 +110,130 @@ impl Viewport {
         self.scale_factor
     }
 
+    /// Returns the [`Rotation`] of the [`Viewport`].
+    pub fn rotation(&self) -> Rotation {
+        self.rotation
+    }
+
+    /// Returns the logical [`Padding`] of the [`Viewport`].
+    pub fn padding(&self) -> Padding {
+        self.padding
+    }
+
+    /// Returns the physical bounds of the contents of the [`Viewport`] in its
+    /// target; that is, its physical size without its [`Padding`].
+    pub fn physical_bounds(&self) -> Rectangle<u32> {
+        let Size { width, height } = self.physical_size;
+        let physical =
+            |inset: u16| (f64::from(inset) * self.scale_factor).round() as u32;
+
+        let x = physical(self.padding.left).min(width);
+        let y = physical(self.padding.top).min(height);
+
+        Rectangle {
+            x,
+            y,
+            width: (width - x).saturating_sub(physical(self.padding.right)),
+            height: (height - y).saturating_sub(physical(self.padding.bottom)),
+        }
+    }
+
     /// Returns the projection transformation of the [`Viewport`].
     pub fn projection(&self) -> Transformation {
         self.projection
     }
+
+    /// Converts physical bounds in the rotated contents of the [`Viewport`]
+    /// into physical bounds of its target.
+    ///
+    /// This is useful to compute scissor rectangles.
+    pub fn rotate_bounds(&self, bounds: Rectangle<u32>) -> Rectangle<u32> {
+        let Rectangle {
+            x,
+            y,
+            width,
+            height,
+        } = self.physical_bounds();
+
+        let rotated = match self.rotation {
+            Rotation::None => bounds,
+            Rotation::Clockwise90 => Rectangle {
+                x: width.saturating_sub(bounds.y + bounds.height),
+                y: bounds.x,
+                width: bounds.height,
+                height: bounds.width,
+            },
+            Rotation::Clockwise180 => Rectangle {
+                x: width.saturating_sub(bounds.x + bounds.width),
+                y: height.saturating_sub(bounds.y + bounds.height),
+                ..bounds
+            },
+            Rotation::Clockwise270 => Rectangle {
+                x: bounds.y,
+                y: height.saturating_sub(bounds.x + bounds.width),
+                width: bounds.height,
+                height: bounds.width,
+            },
+        };
+
+        Rectangle {
+            x: rotated.x + x,
+            y: rotated.y + y,
+            ..rotated
+        }
+    }
+
+    /// Converts a logical position in the target of the [`Viewport`], like
+    /// the position of the cursor in a window, into a logical position in
+    /// its rotated contents.
+    pub fn rotate_position(&self, position: Point) -> Point {
+        let bounds = self.physical_bounds();
+        let logical = |value: u32| (value as f64 / self.scale_factor) as f32;
+
+        let position = Point::new(
+            position.x - logical(bounds.x),
+            position.y - logical(bounds.y),
+        );
+        let width = logical(bounds.width);
+        let height = logical(bounds.height);
+
+        match self.rotation {
+            Rotation::None => position,
+            Rotation::Clockwise90 => Point::new(position.y, width - position.x),
+            Rotation::Clockwise180 => {
+                Point::new(width - position.x, height - position.y)
+            }
+            Rotation::Clockwise270 => {
+                Point::new(height - position.y, position.x)
+            }
+        }
+    }
+}
+
+/// The clockwise rotation of the contents of a [`Viewport`].
+///
+/// It can be used to drive displays mounted in a different orientation than
+/// their native one, like the rotated panels of embedded devices and kiosks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Rotation {
+    /// The contents are not rotated.
+    #[default]
+    None,
+
+    /// The contents are rotated by 90 degrees.
+    Clockwise90,
+
+    /// The contents are rotated by 180 degrees.
+    Clockwise180,
+
+    /// The contents are rotated by 270 degrees.
+    Clockwise270,
+}
+
+impl Rotation {
+    /// Returns true if the [`Rotation`] swaps the width and the height of
+    /// the contents.
+    pub fn is_quarter_turn(self) -> bool {
+        matches!(self, Rotation::Clockwise90 | Rotation::Clockwise270)
+    }
 }
@@ -8,33 +8,76 @@
 //! use iced_graphics::{button, Button};
 //! ```
 pub mod button;
+pub mod cached;
 pub mod checkbox;
 pub mod container;
+pub mod disabled;
+pub mod draggable;
+pub mod drop_target;
+pub mod error_boundary;
+pub mod form;
+pub mod grid;
 pub mod image;
+pub mod inspector;
+pub mod lazy;
+pub mod mouse_area;
+pub mod pagination;
 pub mod pane_grid;
 pub mod pick_list;
 pub mod progress_bar;
 pub mod radio;
+pub mod reorderable_column;
+pub mod responsive;
 pub mod rule;
 pub mod scrollable;
 pub mod slider;
+pub mod stack;
 pub mod svg;
 pub mod text_input;
 pub mod toggler;
 pub mod tooltip;
 
+mod animated;
+mod aspect_ratio;
 mod column;
+mod directional;
+mod reveal;
 mod row;
+mod shortcut_scope;
 mod space;
 mod text;
+mod window_handle;
+mod wrap;
 
 #[doc(no_inline)]
 pub use button::Button;
 #[doc(no_inline)]
+pub use cached::Cached;
+#[doc(no_inline)]
 pub use checkbox::Checkbox;
 #[doc(no_inline)]
 pub use container::Container;
 #[doc(no_inline)]
+pub use disabled::{disabled, Disabled};
+#[doc(no_inline)]
+pub use draggable::Draggable;
+#[doc(no_inline)]
+pub use drop_target::DropTarget;
+#[doc(no_inline)]
+pub use error_boundary::ErrorBoundary;
+#[doc(no_inline)]
+pub use form::Form;
+#[doc(no_inline)]
+pub use grid::Grid;
+#[doc(no_inline)]
+pub use inspector::Inspector;
+#[doc(no_inline)]
+pub use lazy::Lazy;
+#[doc(no_inline)]
+pub use mouse_area::MouseArea;
+#[doc(no_inline)]
+pub use pagination::Pagination;
+#[doc(no_inline)]
 pub use pane_grid::PaneGrid;
 #[doc(no_inline)]
 pub use pick_list::PickList;
@@ -43,24 +86,37 @@ pub use progress_bar::ProgressBar;
 #[doc(no_inline)]
 pub use radio::Radio;
 #[doc(no_inline)]
+pub use reorderable_column::ReorderableColumn;
+#[doc(no_inline)]
+pub use responsive::Responsive;
+#[doc(no_inline)]
 pub use rule::Rule;
 #[doc(no_inline)]
 pub use scrollable::Scrollable;
 #[doc(no_inline)]
 pub use slider::Slider;
 #[doc(no_inline)]
+pub use stack::Stack;
+#[doc(no_inline)]
 pub use text_input::TextInput;
 #[doc(no_inline)]
 pub use toggler::Toggler;
 #[doc(no_inline)]
 pub use tooltip::Tooltip;
 
+pub use animated::Animated;
+pub use aspect_ratio::AspectRatio;
 pub use column::Column;
+pub use directional::Directional;
 pub use image::Image;
+pub use reveal::Reveal;
 pub use row::Row;
+pub use shortcut_scope::ShortcutScope;
 pub use space::Space;
 pub use svg::Svg;
 pub use text::Text;
+pub use window_handle::WindowHandle;
+pub use wrap::Wrap;
 
 #[cfg(feature = "canvas")]
 #[cfg_attr(docsrs, doc(cfg(feature = "canvas")))]
@@ -0,0 +1,6 @@
+//! Keep redrawing some content while it is animated.
+use crate::Renderer;
+
+/// A widget that keeps redrawing its content while some animation is running.
+pub type Animated<'a, Message, Backend> =
+    iced_native::widget::Animated<'a, Message, Renderer<Backend>>;
@@ -0,0 +1,5 @@
+use crate::Renderer;
+
+/// A container that sizes its content to a fixed aspect ratio.
+pub type AspectRatio<'a, Message, Backend> =
+    iced_native::widget::AspectRatio<'a, Message, Renderer<Backend>>;
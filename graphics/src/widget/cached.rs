@@ -0,0 +1,14 @@
+//! Reuse the primitives drawn by some content as long as it does not change.
+//!
+//! A [`Cached`] has some local [`State`].
+use crate::Renderer;
+
+pub use iced_native::widget::cached::State;
+
+/// A widget that reuses the primitives drawn by its content as long as the
+/// data it depends on and its bounds do not change.
+///
+/// This is an alias of an `iced_native` cached widget with an
+/// `iced_graphics::Renderer`.
+pub type Cached<'a, Message, Backend> =
+    iced_native::widget::Cached<'a, Message, Renderer<Backend>>;
@@ -174,7 +174,7 @@ where
 
         let cursor = Cursor::from_window_position(cursor_position);
 
-        if let Some(canvas_event) = canvas_event {
+        let event_status = if let Some(canvas_event) = canvas_event {
             let (event_status, message) =
                 self.program.update(canvas_event, bounds, cursor);
 
@@ -182,10 +182,16 @@ where
                 shell.publish(message);
             }
 
-            return event_status;
+            event_status
+        } else {
+            event::Status::Ignored
+        };
+
+        if self.program.wants_pointer_lock() {
+            shell.request_pointer_lock();
         }
 
-        event::Status::Ignored
+        event_status
     }
 
     fn mouse_interaction(
@@ -6,6 +6,12 @@ use crate::{
 };
 
 use lyon::tessellation;
+use std::borrow::Cow;
+
+/// The amount of tessellations performed by a single thread when tessellating
+/// in parallel.
+#[cfg(feature = "rayon")]
+const CHUNK_SIZE: usize = 32;
 
 /// The frame of a [`Canvas`].
 ///
@@ -16,8 +22,12 @@ pub struct Frame {
     buffers: lyon::tessellation::VertexBuffers<triangle::Vertex2D, u32>,
     primitives: Vec<Primitive>,
     transforms: Transforms,
+    #[cfg(not(feature = "rayon"))]
     fill_tessellator: tessellation::FillTessellator,
+    #[cfg(not(feature = "rayon"))]
     stroke_tessellator: tessellation::StrokeTessellator,
+    #[cfg(feature = "rayon")]
+    pending: Vec<Tessellation<'static>>,
 }
 
 #[derive(Debug)]
@@ -49,8 +59,12 @@ impl Frame {
                     is_identity: true,
                 },
             },
+            #[cfg(not(feature = "rayon"))]
             fill_tessellator: tessellation::FillTessellator::new(),
+            #[cfg(not(feature = "rayon"))]
             stroke_tessellator: tessellation::StrokeTessellator::new(),
+            #[cfg(feature = "rayon")]
+            pending: Vec::new(),
         }
     }
 
@@ -83,31 +97,14 @@ impl Frame {
     pub fn fill(&mut self, path: &Path, fill: impl Into<Fill>) {
         let Fill { color, rule } = fill.into();
 
-        let mut buffers = tessellation::BuffersBuilder::new(
-            &mut self.buffers,
-            FillVertex(color.into_linear()),
-        );
-
         let options =
             tessellation::FillOptions::default().with_fill_rule(rule.into());
 
-        let result = if self.transforms.current.is_identity {
-            self.fill_tessellator.tessellate_path(
-                path.raw(),
-                &options,
-                &mut buffers,
-            )
-        } else {
-            let path = path.transformed(&self.transforms.current.raw);
-
-            self.fill_tessellator.tessellate_path(
-                path.raw(),
-                &options,
-                &mut buffers,
-            )
-        };
-
-        let _ = result.expect("Tessellate path");
+        self.tessellate(Tessellation::Fill {
+            path: self.transform(path),
+            options,
+            color: color.into_linear(),
+        });
     }
 
     /// Draws an axis-aligned rectangle given its top-left corner coordinate and
@@ -120,11 +117,6 @@ impl Frame {
     ) {
         let Fill { color, rule } = fill.into();
 
-        let mut buffers = tessellation::BuffersBuilder::new(
-            &mut self.buffers,
-            FillVertex(color.into_linear()),
-        );
-
         let top_left =
             self.transforms.current.raw.transform_point(
                 lyon::math::Point::new(top_left.x, top_left.y),
@@ -138,14 +130,11 @@ impl Frame {
         let options =
             tessellation::FillOptions::default().with_fill_rule(rule.into());
 
-        let _ = self
-            .fill_tessellator
-            .tessellate_rectangle(
-                &lyon::math::Rect::new(top_left, size.into()),
-                &options,
-                &mut buffers,
-            )
-            .expect("Fill rectangle");
+        self.tessellate(Tessellation::Rectangle {
+            rectangle: lyon::math::Rect::new(top_left, size.into()),
+            options,
+            color: color.into_linear(),
+        });
     }
 
     /// Draws the stroke of the given [`Path`] on the [`Frame`] with the
@@ -153,34 +142,17 @@ impl Frame {
     pub fn stroke(&mut self, path: &Path, stroke: impl Into<Stroke>) {
         let stroke = stroke.into();
 
-        let mut buffers = tessellation::BuffersBuilder::new(
-            &mut self.buffers,
-            StrokeVertex(stroke.color.into_linear()),
-        );
-
         let mut options = tessellation::StrokeOptions::default();
         options.line_width = stroke.width;
         options.start_cap = stroke.line_cap.into();
         options.end_cap = stroke.line_cap.into();
         options.line_join = stroke.line_join.into();
 
-        let result = if self.transforms.current.is_identity {
-            self.stroke_tessellator.tessellate_path(
-                path.raw(),
-                &options,
-                &mut buffers,
-            )
-        } else {
-            let path = path.transformed(&self.transforms.current.raw);
-
-            self.stroke_tessellator.tessellate_path(
-                path.raw(),
-                &options,
-                &mut buffers,
-            )
-        };
-
-        let _ = result.expect("Stroke path");
+        self.tessellate(Tessellation::Stroke {
+            path: self.transform(path),
+            options,
+            color: stroke.color.into_linear(),
+        });
     }
 
     /// Draws the characters of the given [`Text`] on the [`Frame`], filling
@@ -279,6 +251,9 @@ impl Frame {
 
     /// Produces the [`Geometry`] representing everything drawn on the [`Frame`].
     pub fn into_geometry(mut self) -> Geometry {
+        #[cfg(feature = "rayon")]
+        self.tessellate_pending();
+
         if !self.buffers.indices.is_empty() {
             self.primitives.push(Primitive::Mesh2D {
                 buffers: triangle::Mesh2D {
@@ -293,6 +268,182 @@ impl Frame {
             primitives: self.primitives,
         })
     }
+
+    fn transform<'a>(&self, path: &'a Path) -> Cow<'a, Path> {
+        if self.transforms.current.is_identity {
+            Cow::Borrowed(path)
+        } else {
+            Cow::Owned(path.transformed(&self.transforms.current.raw))
+        }
+    }
+
+    #[cfg(not(feature = "rayon"))]
+    fn tessellate(&mut self, tessellation: Tessellation<'_>) {
+        tessellation.run(
+            &mut self.fill_tessellator,
+            &mut self.stroke_tessellator,
+            &mut self.buffers,
+        );
+    }
+
+    #[cfg(feature = "rayon")]
+    fn tessellate(&mut self, tessellation: Tessellation<'_>) {
+        self.pending.push(tessellation.into_owned());
+    }
+
+    /// Tessellates the pending paths across threads, appending the results
+    /// in the order they were drawn.
+    #[cfg(feature = "rayon")]
+    fn tessellate_pending(&mut self) {
+        use rayon::prelude::*;
+
+        let chunks: Vec<_> = self
+            .pending
+            .par_chunks(CHUNK_SIZE)
+            .map(|chunk| {
+                let mut fill_tessellator = tessellation::FillTessellator::new();
+                let mut stroke_tessellator =
+                    tessellation::StrokeTessellator::new();
+                let mut buffers = tessellation::VertexBuffers::new();
+
+                for tessellation in chunk {
+                    tessellation.run(
+                        &mut fill_tessellator,
+                        &mut stroke_tessellator,
+                        &mut buffers,
+                    );
+                }
+
+                buffers
+            })
+            .collect();
+
+        self.pending.clear();
+
+        for chunk in chunks {
+            let offset = self.buffers.vertices.len() as u32;
+
+            self.buffers.vertices.extend(chunk.vertices);
+            self.buffers
+                .indices
+                .extend(chunk.indices.into_iter().map(|index| index + offset));
+        }
+    }
+}
+
+/// A shape drawn on a [`Frame`] that has yet to be tessellated.
+enum Tessellation<'a> {
+    Fill {
+        path: Cow<'a, Path>,
+        options: tessellation::FillOptions,
+        color: [f32; 4],
+    },
+    Rectangle {
+        rectangle: lyon::math::Rect,
+        options: tessellation::FillOptions,
+        color: [f32; 4],
+    },
+    Stroke {
+        path: Cow<'a, Path>,
+        options: tessellation::StrokeOptions,
+        color: [f32; 4],
+    },
+}
+
+impl<'a> Tessellation<'a> {
+    fn run(
+        &self,
+        fill_tessellator: &mut tessellation::FillTessellator,
+        stroke_tessellator: &mut tessellation::StrokeTessellator,
+        buffers: &mut tessellation::VertexBuffers<triangle::Vertex2D, u32>,
+    ) {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::trace_span!("tessellate").entered();
+
+        match self {
+            Tessellation::Fill {
+                path,
+                options,
+                color,
+            } => {
+                let _ = fill_tessellator
+                    .tessellate_path(
+                        path.raw(),
+                        options,
+                        &mut tessellation::BuffersBuilder::new(
+                            buffers,
+                            FillVertex(*color),
+                        ),
+                    )
+                    .expect("Tessellate path");
+            }
+            Tessellation::Rectangle {
+                rectangle,
+                options,
+                color,
+            } => {
+                let _ = fill_tessellator
+                    .tessellate_rectangle(
+                        rectangle,
+                        options,
+                        &mut tessellation::BuffersBuilder::new(
+                            buffers,
+                            FillVertex(*color),
+                        ),
+                    )
+                    .expect("Fill rectangle");
+            }
+            Tessellation::Stroke {
+                path,
+                options,
+                color,
+            } => {
+                let _ = stroke_tessellator
+                    .tessellate_path(
+                        path.raw(),
+                        options,
+                        &mut tessellation::BuffersBuilder::new(
+                            buffers,
+                            StrokeVertex(*color),
+                        ),
+                    )
+                    .expect("Stroke path");
+            }
+        }
+    }
+
+    #[cfg(feature = "rayon")]
+    fn into_owned(self) -> Tessellation<'static> {
+        match self {
+            Tessellation::Fill {
+                path,
+                options,
+                color,
+            } => Tessellation::Fill {
+                path: Cow::Owned(path.into_owned()),
+                options,
+                color,
+            },
+            Tessellation::Rectangle {
+                rectangle,
+                options,
+                color,
+            } => Tessellation::Rectangle {
+                rectangle,
+                options,
+                color,
+            },
+            Tessellation::Stroke {
+                path,
+                options,
+                color,
+            } => Tessellation::Stroke {
+                path: Cow::Owned(path.into_owned()),
+                options,
+                color,
+            },
+        }
+    }
 }
 
 struct FillVertex([f32; 4]);
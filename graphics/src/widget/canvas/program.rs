@@ -51,6 +51,17 @@ pub trait Program<Message> {
     ) -> mouse::Interaction {
         mouse::Interaction::default()
     }
+
+    /// Returns whether the [`Program`] wants the pointer to be locked.
+    ///
+    /// While locked, the cursor is hidden and the [`Program`] receives the
+    /// relative movement of the mouse as [`mouse::Event::Motion`] events.
+    /// This is useful to orbit a 3D viewport, for instance.
+    ///
+    /// By default, it returns `false`.
+    fn wants_pointer_lock(&self) -> bool {
+        false
+    }
 }
 
 impl<T, Message> Program<Message> for &mut T
@@ -77,4 +88,8 @@ where
     ) -> mouse::Interaction {
         T::mouse_interaction(self, bounds, cursor)
     }
+
+    fn wants_pointer_lock(&self) -> bool {
+        T::wants_pointer_lock(self)
+    }
 }
@@ -1,6 +1,7 @@
 //! Decorate content and apply alignment.
 use crate::Renderer;
 
+pub use iced_native::widget::container::Phase;
 pub use iced_style::container::{Style, StyleSheet};
 
 /// An element decorating some content.
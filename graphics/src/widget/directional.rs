@@ -0,0 +1,5 @@
+use crate::Renderer;
+
+/// A widget that sets the direction in which its content flows.
+pub type Directional<'a, Message, Backend> =
+    iced_native::widget::Directional<'a, Message, Renderer<Backend>>;
@@ -0,0 +1,14 @@
+//! Let users drag data out of a widget.
+//!
+//! A [`Draggable`] has some local [`State`].
+use crate::Renderer;
+
+pub use iced_native::widget::draggable::State;
+
+/// A widget that starts a drag carrying some payload when its content is
+/// pressed and moved.
+///
+/// This is an alias of an `iced_native` draggable with an
+/// `iced_graphics::Renderer`.
+pub type Draggable<'a, Message, Backend> =
+    iced_native::widget::Draggable<'a, Message, Renderer<Backend>>;
@@ -0,0 +1,14 @@
+//! Accept data dropped by the user.
+//!
+//! A [`DropTarget`] has some local [`State`].
+use crate::Renderer;
+
+pub use iced_native::widget::drop_target::State;
+pub use iced_style::drop_target::{Style, StyleSheet};
+
+/// A widget that accepts payloads of type `T` dropped on top of its content.
+///
+/// This is an alias of an `iced_native` drop target with an
+/// `iced_graphics::Renderer`.
+pub type DropTarget<'a, T, Message, Backend> =
+    iced_native::widget::DropTarget<'a, T, Message, Renderer<Backend>>;
@@ -0,0 +1,14 @@
+//! Keep a panicking widget from bringing down the whole application.
+//!
+//! An [`ErrorBoundary`] has some local [`State`].
+use crate::Renderer;
+
+pub use iced_native::widget::error_boundary::State;
+
+/// A widget that catches the panics of its content and displays a fallback
+/// instead.
+///
+/// This is an alias of an `iced_native` error boundary with an
+/// `iced_graphics::Renderer`.
+pub type ErrorBoundary<'a, Message, Backend> =
+    iced_native::widget::ErrorBoundary<'a, Message, Renderer<Backend>>;
@@ -0,0 +1,12 @@
+//! Distribute content in rows and columns.
+use crate::Renderer;
+
+/// A container that places its contents in the cells of a grid.
+///
+/// This is an alias of an `iced_native` grid with an `iced_graphics::Renderer`.
+pub type Grid<'a, Message, Backend> =
+    iced_native::widget::Grid<'a, Message, Renderer<Backend>>;
+
+/// An element placed in a [`Grid`], spanning one or more rows and columns.
+pub type Cell<'a, Message, Backend> =
+    iced_native::widget::grid::Cell<'a, Message, Renderer<Backend>>;
@@ -0,0 +1,15 @@
+//! Reuse the widgets of some content as long as its data does not change.
+//!
+//! A [`Lazy`] has some local [`State`].
+use crate::Renderer;
+
+/// The local state of a [`Lazy`].
+pub type State<Message, Backend> =
+    iced_native::widget::lazy::State<Message, Renderer<Backend>>;
+
+/// A widget that only builds its content when the data it depends on changes.
+///
+/// This is an alias of an `iced_native` lazy with an
+/// `iced_graphics::Renderer`.
+pub type Lazy<'a, Message, Backend> =
+    iced_native::widget::Lazy<'a, Message, Renderer<Backend>>;
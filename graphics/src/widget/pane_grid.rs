@@ -10,7 +10,7 @@
 use crate::Renderer;
 
 pub use iced_native::widget::pane_grid::{
-    Axis, Configuration, Content, Direction, DragEvent, Node, Pane,
+    Axis, Configuration, Content, Direction, DragEvent, FloatEvent, Node, Pane,
     ResizeEvent, Split, State, TitleBar,
 };
 
@@ -0,0 +1,15 @@
+//! Let users reorder a vertical list by dragging its rows.
+//!
+//! A [`ReorderableColumn`] has some local [`State`].
+use crate::Renderer;
+
+pub use iced_native::widget::reorderable_column::State;
+pub use iced_style::reorderable_column::{Style, StyleSheet};
+
+/// A container that distributes its contents vertically and lets users
+/// reorder them by dragging the handle next to each row.
+///
+/// This is an alias of an `iced_native` reorderable column with an
+/// `iced_graphics::Renderer`.
+pub type ReorderableColumn<'a, Message, Backend> =
+    iced_native::widget::ReorderableColumn<'a, Message, Renderer<Backend>>;
@@ -0,0 +1,6 @@
+//! Animate some content when it enters or exits the view.
+use crate::Renderer;
+
+/// A widget that animates its content when it enters or exits the view.
+pub type Reveal<'a, Message, Backend> =
+    iced_native::widget::Reveal<'a, Message, Renderer<Backend>>;
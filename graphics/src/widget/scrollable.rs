@@ -1,11 +1,14 @@
-//! Navigate an endless amount of content with a scrollbar.
+//! Navigate an endless amount of content with scrollbars.
 use crate::Renderer;
 
-pub use iced_native::widget::scrollable::State;
+pub use iced_native::widget::scrollable::{
+    scroll_to, snap_to, AbsoluteOffset, Anchor, Axes, RelativeOffset, State,
+    Viewport,
+};
 pub use iced_style::scrollable::{Scrollbar, Scroller, StyleSheet};
 
-/// A widget that can vertically display an infinite amount of content
-/// with a scrollbar.
+/// A widget that can display an infinite amount of content with
+/// scrollbars.
 ///
 /// This is an alias of an `iced_native` scrollable with a default
 /// `Renderer`.
@@ -0,0 +1,6 @@
+use crate::Renderer;
+
+/// A widget that triggers some keyboard shortcuts while a widget inside of its
+/// content is focused.
+pub type ShortcutScope<'a, Message, Backend> =
+    iced_native::widget::ShortcutScope<'a, Message, Renderer<Backend>>;
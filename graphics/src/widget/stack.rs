@@ -0,0 +1,13 @@
+//! Display content on top of other content.
+use crate::Renderer;
+
+/// A container that displays its contents on top of each other, in the same
+/// bounds.
+///
+/// This is an alias of an `iced_native` stack with an `iced_graphics::Renderer`.
+pub type Stack<'a, Message, Backend> =
+    iced_native::widget::Stack<'a, Message, Renderer<Backend>>;
+
+/// An element of a [`Stack`].
+pub type Item<'a, Message, Backend> =
+    iced_native::widget::stack::Item<'a, Message, Renderer<Backend>>;
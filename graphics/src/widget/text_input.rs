@@ -3,7 +3,10 @@
 //! A [`TextInput`] has some local [`State`].
 use crate::Renderer;
 
-pub use iced_native::widget::text_input::State;
+pub use iced_native::widget::text_input::{
+    clear_history, focus, snapshot, Icon, Mask, Selection, Side, State,
+    Validation,
+};
 pub use iced_style::text_input::{Style, StyleSheet};
 
 /// A field that can be filled with text.
@@ -8,4 +8,4 @@ use crate::Renderer;
 pub type Tooltip<'a, Message, Backend> =
     iced_native::widget::Tooltip<'a, Message, Renderer<Backend>>;
 
-pub use iced_native::widget::tooltip::Position;
+pub use iced_native::widget::tooltip::{Position, State};
@@ -0,0 +1,5 @@
+use crate::Renderer;
+
+/// A widget that moves or resizes the window when its content is dragged.
+pub type WindowHandle<'a, Message, Backend> =
+    iced_native::widget::WindowHandle<'a, Message, Renderer<Backend>>;
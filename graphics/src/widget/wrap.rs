@@ -0,0 +1,6 @@
+use crate::Renderer;
+
+/// A container that distributes its contents horizontally, wrapping them into
+/// multiple lines.
+pub type Wrap<'a, Message, Backend> =
+    iced_native::widget::Wrap<'a, Message, Renderer<Backend>>;
@@ -1,5 +1,7 @@
 use crate::{Color, Error, Viewport};
 
+use iced_native::window::PresentMode;
+
 use raw_window_handle::HasRawWindowHandle;
 use thiserror::Error;
 
@@ -38,6 +40,12 @@ pub trait Compositor: Sized {
         height: u32,
     );
 
+    /// Changes the [`PresentMode`] used by the surfaces configured from now
+    /// on.
+    ///
+    /// By default, the present mode cannot be changed and it is ignored.
+    fn set_present_mode(&mut self, _present_mode: PresentMode) {}
+
     /// Presents the [`Renderer`] primitives to the next frame of the given [`Surface`].
     ///
     /// [`SwapChain`]: Self::SwapChain
@@ -49,6 +57,20 @@ pub trait Compositor: Sized {
         background_color: Color,
         overlay: &[T],
     ) -> Result<(), SurfaceError>;
+
+    /// Draws the [`Renderer`] primitives like [`Compositor::present`] does,
+    /// but returns the resulting pixels instead of presenting them.
+    ///
+    /// The pixels are in RGBA order, row by row, with the physical size of
+    /// the given [`Viewport`].
+    fn screenshot<T: AsRef<str>>(
+        &mut self,
+        renderer: &mut Self::Renderer,
+        surface: &mut Self::Surface,
+        viewport: &Viewport,
+        background_color: Color,
+        overlay: &[T],
+    ) -> Vec<u8>;
 }
 
 /// Result of an unsuccessful call to [`Compositor::draw`].
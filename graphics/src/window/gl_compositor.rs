@@ -59,4 +59,19 @@ pub trait GLCompositor: Sized {
         background_color: Color,
         overlay: &[T],
     );
+
+    /// Draws the primitives of the [`Renderer`] like
+    /// [`GLCompositor::present`] does, and reads back the resulting pixels.
+    ///
+    /// The pixels are in RGBA order, row by row, from top to bottom, with the
+    /// physical size of the given [`Viewport`].
+    ///
+    /// [`Renderer`]: crate::Renderer
+    fn screenshot<T: AsRef<str>>(
+        &mut self,
+        renderer: &mut Self::Renderer,
+        viewport: &Viewport,
+        background_color: Color,
+        overlay: &[T],
+    ) -> Vec<u8>;
 }
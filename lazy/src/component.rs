@@ -3,6 +3,7 @@ use iced_native::layout::{self, Layout};
 use iced_native::mouse;
 use iced_native::overlay;
 use iced_native::renderer;
+use iced_native::widget;
 use iced_native::{
     Clipboard, Element, Hasher, Length, Point, Rectangle, Shell, Size, Widget,
 };
@@ -149,7 +150,7 @@ where
             )
         });
 
-        local_shell.with_invalid_layout(|| shell.invalidate_layout());
+        shell.forward(&mut local_shell);
 
         if !local_messages.is_empty() {
             let mut component = self
@@ -209,6 +210,66 @@ where
         });
     }
 
+    fn operate(
+        &mut self,
+        layout: Layout<'_>,
+        operation: &mut dyn widget::Operation<Message>,
+    ) {
+        struct MapOperation<'a, B> {
+            operation: &'a mut dyn widget::Operation<B>,
+        }
+
+        impl<'a, T, B> widget::Operation<T> for MapOperation<'a, B> {
+            fn container(
+                &mut self,
+                id: Option<&widget::Id>,
+                operate_on_children: &mut dyn FnMut(
+                    &mut dyn widget::Operation<T>,
+                ),
+            ) {
+                self.operation.container(id, &mut |operation| {
+                    operate_on_children(&mut MapOperation { operation });
+                });
+            }
+
+            fn focusable(
+                &mut self,
+                state: &mut dyn widget::operation::Focusable,
+                id: Option<&widget::Id>,
+            ) {
+                self.operation.focusable(state, id);
+            }
+
+            fn scrollable(
+                &mut self,
+                state: &mut dyn widget::operation::Scrollable,
+                id: Option<&widget::Id>,
+            ) {
+                self.operation.scrollable(state, id);
+            }
+
+            fn text_input(
+                &mut self,
+                state: &mut dyn widget::operation::TextInput,
+                id: Option<&widget::Id>,
+            ) {
+                self.operation.text_input(state, id);
+            }
+
+            fn accessible(
+                &mut self,
+                node: iced_native::accessibility::Node,
+                id: Option<&widget::Id>,
+            ) {
+                self.operation.accessible(node, id);
+            }
+        }
+
+        self.with_element_mut(|element| {
+            element.operate(layout, &mut MapOperation { operation });
+        });
+    }
+
     fn mouse_interaction(
         &self,
         layout: Layout<'_>,
@@ -223,6 +284,7 @@ where
     fn overlay(
         &mut self,
         layout: Layout<'_>,
+        viewport: &Rectangle,
     ) -> Option<overlay::Element<'_, Message, Renderer>> {
         let has_overlay = self
             .state
@@ -236,7 +298,9 @@ where
                     CacheBuilder {
                         element,
                         message: PhantomData,
-                        overlay_builder: |element| element.overlay(layout),
+                        overlay_builder: |element| {
+                            element.overlay(layout, viewport)
+                        },
                     }
                     .build(),
                 );
@@ -247,7 +311,10 @@ where
         has_overlay.then(|| {
             overlay::Element::new(
                 layout.position(),
-                Box::new(Overlay { instance: self }),
+                Box::new(Overlay {
+                    instance: self,
+                    viewport: *viewport,
+                }),
             )
         })
     }
@@ -255,6 +322,7 @@ where
 
 struct Overlay<'a, 'b, Message, Renderer, Event> {
     instance: &'b mut Instance<'a, Message, Renderer, Event>,
+    viewport: Rectangle,
 }
 
 impl<'a, 'b, Message, Renderer, Event>
@@ -342,14 +410,21 @@ where
         struct Marker;
         std::any::TypeId::of::<Marker>().hash(state);
 
-        (position.x as u32).hash(state);
-        (position.y as u32).hash(state);
+        position.x.to_bits().hash(state);
+        position.y.to_bits().hash(state);
 
         self.with_overlay_maybe(|overlay| {
             overlay.hash_layout(state);
         });
     }
 
+    fn is_over(&self, layout: Layout<'_>, cursor_position: Point) -> bool {
+        self.with_overlay_maybe(|overlay| {
+            overlay.is_over(layout, cursor_position)
+        })
+        .unwrap_or(false)
+    }
+
     fn on_event(
         &mut self,
         event: iced_native::Event,
@@ -375,7 +450,7 @@ where
             })
             .unwrap_or_else(|| iced_native::event::Status::Ignored);
 
-        local_shell.with_invalid_layout(|| shell.invalidate_layout());
+        shell.forward(&mut local_shell);
 
         if !local_messages.is_empty() {
             let mut component =
@@ -388,6 +463,8 @@ where
                 shell.publish(message);
             }
 
+            let viewport = self.viewport;
+
             self.instance.state = RefCell::new(Some(
                 StateBuilder {
                     component,
@@ -397,7 +474,7 @@ where
                                 element: state.view(),
                                 message: PhantomData,
                                 overlay_builder: |element| {
-                                    element.overlay(layout)
+                                    element.overlay(layout, &viewport)
                                 },
                             }
                             .build(),
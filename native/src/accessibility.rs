@@ -0,0 +1,240 @@
+//! Describe the widgets of a user interface to assistive technologies.
+//!
+//! Widgets publish a [`Node`] for every element a screen reader should know
+//! about with [`Operation::accessible`]. A [`Tree`] collects these nodes in
+//! layout order, and the actions requested by assistive technologies are
+//! routed back to the widgets as an [`Event::Accessibility`] carrying a
+//! [`Request`].
+//!
+//! [`Event::Accessibility`]: crate::Event::Accessibility
+use crate::widget::{Id, Operation};
+use crate::{Event, Rectangle};
+
+/// The description of a widget for assistive technologies.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Node {
+    /// The [`Role`] of the widget.
+    pub role: Role,
+
+    /// The text that names the widget, if any.
+    pub label: Option<String>,
+
+    /// The current [`Value`] of the widget, if any.
+    pub value: Option<Value>,
+
+    /// The bounds of the widget.
+    pub bounds: Rectangle,
+
+    /// Whether the widget is focused or not.
+    pub is_focused: bool,
+
+    /// Whether the widget is disabled or not.
+    pub is_disabled: bool,
+
+    /// Whether the widget can be focused with [`Action::Focus`].
+    pub is_focusable: bool,
+
+    /// Whether the widget can be activated with [`Action::Invoke`].
+    pub is_invokable: bool,
+
+    /// Whether the [`Value`] of the widget can be changed with
+    /// [`Action::SetValue`] or [`Action::SetNumericValue`].
+    pub is_editable: bool,
+}
+
+impl Node {
+    /// Creates a new [`Node`] with the given [`Role`] and bounds.
+    pub fn new(role: Role, bounds: Rectangle) -> Self {
+        Self {
+            role,
+            label: None,
+            value: None,
+            bounds,
+            is_focused: false,
+            is_disabled: false,
+            is_focusable: false,
+            is_invokable: false,
+            is_editable: false,
+        }
+    }
+
+    /// Sets the label of the [`Node`].
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Sets the [`Value`] of the [`Node`].
+    pub fn value(mut self, value: Value) -> Self {
+        self.value = Some(value);
+        self
+    }
+
+    /// Sets whether the [`Node`] is focused or not.
+    pub fn focused(mut self, is_focused: bool) -> Self {
+        self.is_focused = is_focused;
+        self
+    }
+
+    /// Sets whether the [`Node`] is disabled or not.
+    pub fn disabled(mut self, is_disabled: bool) -> Self {
+        self.is_disabled = is_disabled;
+        self
+    }
+
+    /// Sets whether the [`Node`] can be focused or not.
+    pub fn focusable(mut self, is_focusable: bool) -> Self {
+        self.is_focusable = is_focusable;
+        self
+    }
+
+    /// Sets whether the [`Node`] can be invoked or not.
+    pub fn invokable(mut self, is_invokable: bool) -> Self {
+        self.is_invokable = is_invokable;
+        self
+    }
+
+    /// Sets whether the [`Value`] of the [`Node`] can be changed or not.
+    pub fn editable(mut self, is_editable: bool) -> Self {
+        self.is_editable = is_editable;
+        self
+    }
+}
+
+/// The kind of a widget, as understood by assistive technologies.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Role {
+    /// A button.
+    Button,
+
+    /// A checkbox.
+    CheckBox,
+
+    /// A piece of static text.
+    Label,
+
+    /// A container that can be scrolled.
+    ScrollView,
+
+    /// A slider that selects a number in a range.
+    Slider,
+
+    /// A field of editable text.
+    TextInput,
+}
+
+/// The value of a widget.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    /// Some text.
+    Text(String),
+
+    /// A number in a range.
+    Number {
+        /// The current number.
+        value: f64,
+        /// The minimum number.
+        min: f64,
+        /// The maximum number.
+        max: f64,
+    },
+
+    /// A state that can be toggled.
+    Toggled(bool),
+}
+
+/// An action requested by an assistive technology.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Action {
+    /// Focus the widget.
+    Focus,
+
+    /// Activate the widget, like clicking a button.
+    Invoke,
+
+    /// Replace the text of the widget.
+    SetValue(String),
+
+    /// Replace the number of the widget.
+    SetNumericValue(f64),
+
+    /// Scroll the widget into view.
+    ScrollIntoView,
+}
+
+/// An [`Action`] targeting a specific widget.
+///
+/// The target is identified by the bounds of its [`Node`]. Widgets compare
+/// them with the bounds of their layout to decide whether they should handle
+/// the [`Request`] or not.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Request {
+    /// The bounds of the target widget.
+    pub target: Rectangle,
+
+    /// The requested [`Action`].
+    pub action: Action,
+}
+
+/// The nodes of a user interface, in layout order.
+///
+/// A [`Tree`] is an [`Operation`] that collects every [`Node`] published by
+/// the widgets it traverses.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Tree {
+    nodes: Vec<Node>,
+}
+
+impl Tree {
+    /// Creates an empty [`Tree`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the nodes of the [`Tree`].
+    pub fn nodes(&self) -> &[Node] {
+        &self.nodes
+    }
+
+    /// Returns the index of the focused [`Node`], if any.
+    pub fn focus(&self) -> Option<usize> {
+        self.nodes.iter().position(|node| node.is_focused)
+    }
+
+    /// Produces the [`Event`] that routes the given [`Action`] to the
+    /// [`Node`] at the given index.
+    pub fn request(&self, index: usize, action: Action) -> Option<Event> {
+        let node = self.nodes.get(index)?;
+
+        Some(Event::Accessibility(Request {
+            target: node.bounds,
+            action,
+        }))
+    }
+}
+
+impl<T> Operation<T> for Tree {
+    fn container(
+        &mut self,
+        _id: Option<&Id>,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+    ) {
+        operate_on_children(self)
+    }
+
+    fn accessible(&mut self, node: Node, _id: Option<&Id>) {
+        // The text inside of a button becomes its label
+        if let Some(last) = self.nodes.last_mut() {
+            if node.role == Role::Label
+                && last.role == Role::Button
+                && last.label.is_none()
+                && last.bounds.contains(node.bounds.center())
+            {
+                last.label = node.label;
+                return;
+            }
+        }
+
+        self.nodes.push(node);
+    }
+}
@@ -0,0 +1,488 @@
+//! Animate the appearance of widgets and the values of an application.
+use crate::window;
+use crate::{Background, Color, Point, Size, Vector};
+
+use std::time::{Duration, Instant};
+
+/// A curve that maps the linear progress of an animation to its eased
+/// progress.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Easing {
+    /// A constant speed.
+    Linear,
+    /// Starts slowly and speeds up.
+    EaseIn,
+    /// Starts quickly and slows down.
+    #[default]
+    EaseOut,
+    /// Starts and ends slowly.
+    EaseInOut,
+}
+
+impl Easing {
+    /// Applies the [`Easing`] to the given linear progress, between `0.0` and
+    /// `1.0`.
+    pub fn apply(self, progress: f32) -> f32 {
+        let t = progress.clamp(0.0, 1.0);
+
+        match self {
+            Easing::Linear => t,
+            Easing::EaseIn => t * t * t,
+            Easing::EaseOut => 1.0 - (1.0 - t).powi(3),
+            Easing::EaseInOut => {
+                if t < 0.5 {
+                    4.0 * t * t * t
+                } else {
+                    1.0 - (-2.0 * t + 2.0).powi(3) / 2.0
+                }
+            }
+        }
+    }
+}
+
+/// A value that can be interpolated towards another one.
+pub trait Interpolate {
+    /// Interpolates the value towards `other` by the given factor, where
+    /// `0.0` produces the value itself and `1.0` produces `other`.
+    fn interpolate(&self, other: &Self, factor: f32) -> Self;
+}
+
+impl Interpolate for f32 {
+    fn interpolate(&self, other: &Self, factor: f32) -> Self {
+        self + (other - self) * factor
+    }
+}
+
+impl Interpolate for Color {
+    fn interpolate(&self, other: &Self, factor: f32) -> Self {
+        Color {
+            r: self.r.interpolate(&other.r, factor),
+            g: self.g.interpolate(&other.g, factor),
+            b: self.b.interpolate(&other.b, factor),
+            a: self.a.interpolate(&other.a, factor),
+        }
+    }
+}
+
+impl Interpolate for Point {
+    fn interpolate(&self, other: &Self, factor: f32) -> Self {
+        Point::new(
+            self.x.interpolate(&other.x, factor),
+            self.y.interpolate(&other.y, factor),
+        )
+    }
+}
+
+impl Interpolate for Vector {
+    fn interpolate(&self, other: &Self, factor: f32) -> Self {
+        Vector::new(
+            self.x.interpolate(&other.x, factor),
+            self.y.interpolate(&other.y, factor),
+        )
+    }
+}
+
+impl Interpolate for Size {
+    fn interpolate(&self, other: &Self, factor: f32) -> Self {
+        Size::new(
+            self.width.interpolate(&other.width, factor),
+            self.height.interpolate(&other.height, factor),
+        )
+    }
+}
+
+impl Interpolate for Background {
+    fn interpolate(&self, other: &Self, factor: f32) -> Self {
+        match (self, other) {
+            (Background::Color(a), Background::Color(b)) => {
+                Background::Color(a.interpolate(b, factor))
+            }
+        }
+    }
+}
+
+impl Interpolate for Option<Background> {
+    fn interpolate(&self, other: &Self, factor: f32) -> Self {
+        let transparent = |background: &Background| match background {
+            Background::Color(color) => {
+                Background::Color(Color { a: 0.0, ..*color })
+            }
+        };
+
+        match (self, other) {
+            (Some(a), Some(b)) => Some(a.interpolate(b, factor)),
+            (Some(a), None) => Some(a.interpolate(&transparent(a), factor)),
+            (None, Some(b)) => Some(transparent(b).interpolate(b, factor)),
+            (None, None) => None,
+        }
+    }
+}
+
+/// The timing of the animated changes of the appearance of a widget.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Transition {
+    /// The duration of the [`Transition`].
+    pub duration: Duration,
+    /// The [`Easing`] of the [`Transition`].
+    pub easing: Easing,
+}
+
+impl Transition {
+    /// Creates a new [`Transition`] with the given duration and the default
+    /// [`Easing`].
+    pub fn new(duration: Duration) -> Self {
+        Self {
+            duration,
+            easing: Easing::default(),
+        }
+    }
+
+    /// Sets the [`Easing`] of the [`Transition`].
+    pub fn easing(mut self, easing: Easing) -> Self {
+        self.easing = easing;
+        self
+    }
+}
+
+impl Default for Transition {
+    fn default() -> Self {
+        Self::new(Duration::from_millis(150))
+    }
+}
+
+/// Tracks the interaction status of a widget to animate the changes of its
+/// appearance.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Tracker<S> {
+    current: Option<S>,
+    previous: Option<S>,
+    changed_at: Option<Instant>,
+}
+
+impl<S> Default for Tracker<S> {
+    fn default() -> Self {
+        Self {
+            current: None,
+            previous: None,
+            changed_at: None,
+        }
+    }
+}
+
+impl<S> Tracker<S>
+where
+    S: Copy + PartialEq,
+{
+    /// Creates a new [`Tracker`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Updates the current status tracked by the [`Tracker`].
+    pub fn update(&mut self, status: S, now: Instant) {
+        if self.current != Some(status) {
+            self.previous = self.current;
+            self.current = Some(status);
+            self.changed_at = Some(now);
+        }
+    }
+
+    /// Returns the previous status and the eased progress of the given
+    /// [`Transition`] towards the current status, if it is still running.
+    pub fn progress(
+        &self,
+        transition: Transition,
+        now: Instant,
+    ) -> Option<(S, f32)> {
+        let previous = self.previous?;
+        let elapsed = now.saturating_duration_since(self.changed_at?);
+
+        if elapsed >= transition.duration {
+            return None;
+        }
+
+        let progress =
+            elapsed.as_secs_f32() / transition.duration.as_secs_f32();
+
+        Some((previous, transition.easing.apply(progress)))
+    }
+}
+
+/// A value animated over time.
+///
+/// An [`Animation`] starts at some value and goes through a sequence of
+/// steps, each one interpolating towards a new value during some time with
+/// some [`Easing`]. It can be delayed, which is useful to stagger the
+/// animations of a list of items.
+///
+/// An [`Animation`] is stored in the state of an application and queried in
+/// its `view`. Wrap the animated widgets in an [`Animated`] widget to keep
+/// redrawing them while the [`Animation`] is running.
+///
+/// [`Animated`]: crate::widget::Animated
+#[derive(Debug, Clone, PartialEq)]
+pub struct Animation<T> {
+    initial: T,
+    steps: Vec<Step<T>>,
+    delay: Duration,
+    started_at: Option<Instant>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Step<T> {
+    value: T,
+    duration: Duration,
+    easing: Easing,
+}
+
+impl<T> Animation<T>
+where
+    T: Interpolate + Clone,
+{
+    /// Creates a new [`Animation`] resting at the given value.
+    pub fn new(value: T) -> Self {
+        Self {
+            initial: value,
+            steps: Vec::new(),
+            delay: Duration::ZERO,
+            started_at: None,
+        }
+    }
+
+    /// Adds a step to the [`Animation`] that interpolates towards the given
+    /// value during the given duration.
+    ///
+    /// Steps run one after the other.
+    pub fn then(
+        mut self,
+        value: T,
+        duration: Duration,
+        easing: Easing,
+    ) -> Self {
+        self.steps.push(Step {
+            value,
+            duration,
+            easing,
+        });
+        self
+    }
+
+    /// Delays the start of the [`Animation`] by the given duration.
+    pub fn delay(mut self, delay: Duration) -> Self {
+        self.delay += delay;
+        self
+    }
+
+    /// Delays the start of the [`Animation`] by the given step times the
+    /// given index, in order to stagger the animations of a list of items.
+    pub fn stagger(self, index: usize, step: Duration) -> Self {
+        self.delay(step * index as u32)
+    }
+
+    /// Starts the [`Animation`] at the given [`Instant`].
+    pub fn start(&mut self, now: Instant) {
+        self.started_at = Some(now);
+    }
+
+    /// Animates from the current value towards the given one, replacing any
+    /// steps left and starting at the given [`Instant`].
+    ///
+    /// This is useful to retarget an [`Animation`] that may still be running.
+    pub fn go_to(
+        &mut self,
+        value: T,
+        duration: Duration,
+        easing: Easing,
+        now: Instant,
+    ) {
+        self.initial = self.value(now);
+        self.steps = vec![Step {
+            value,
+            duration,
+            easing,
+        }];
+        self.delay = Duration::ZERO;
+        self.started_at = Some(now);
+    }
+
+    /// Returns the total duration of the [`Animation`], including its delay.
+    ///
+    /// Delaying an [`Animation`] by the duration of another one sequences
+    /// them.
+    pub fn duration(&self) -> Duration {
+        self.steps
+            .iter()
+            .fold(self.delay, |duration, step| duration + step.duration)
+    }
+
+    /// Returns the value of the [`Animation`] at the given [`Instant`].
+    pub fn value(&self, now: Instant) -> T {
+        let started_at = match self.started_at {
+            Some(started_at) => started_at,
+            None => return self.initial.clone(),
+        };
+
+        let mut elapsed = now
+            .saturating_duration_since(started_at)
+            .saturating_sub(self.delay);
+
+        let mut from = &self.initial;
+
+        for step in &self.steps {
+            if elapsed < step.duration {
+                let progress =
+                    elapsed.as_secs_f32() / step.duration.as_secs_f32();
+
+                return from
+                    .interpolate(&step.value, step.easing.apply(progress));
+            }
+
+            elapsed -= step.duration;
+            from = &step.value;
+        }
+
+        from.clone()
+    }
+
+    /// Returns true if the [`Animation`] has started and not finished yet at
+    /// the given [`Instant`].
+    pub fn is_running(&self, now: Instant) -> bool {
+        match self.started_at {
+            Some(started_at) => {
+                now.saturating_duration_since(started_at) < self.duration()
+            }
+            None => false,
+        }
+    }
+
+    /// Returns the [`window::RedrawRequest`] needed to keep the [`Animation`]
+    /// running at the given [`Instant`], if any.
+    pub fn redraw_request(
+        &self,
+        now: Instant,
+    ) -> Option<window::RedrawRequest> {
+        let started_at = self.started_at?;
+
+        if !self.is_running(now) {
+            return None;
+        }
+
+        let starts_at = started_at + self.delay;
+
+        Some(if now < starts_at {
+            window::RedrawRequest::At(starts_at)
+        } else {
+            window::RedrawRequest::NextFrame
+        })
+    }
+}
+
+/// The presence of some content that is animated when it enters or exits the
+/// view.
+///
+/// A [`Presence`] is stored in the state of an application, next to the
+/// state of the content it animates. The content must keep being displayed
+/// while the [`Presence`] is present, so its exit can be animated before its
+/// state is dropped. Use a [`Reveal`] widget to apply the animation.
+///
+/// [`Reveal`]: crate::widget::Reveal
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Presence {
+    is_visible: bool,
+    changed_at: Option<Instant>,
+    transition: Transition,
+}
+
+impl Presence {
+    /// Creates a new [`Presence`] that is visible or not, without animating
+    /// it.
+    pub fn new(is_visible: bool) -> Self {
+        Self {
+            is_visible,
+            changed_at: None,
+            transition: Transition::new(Duration::from_millis(200)),
+        }
+    }
+
+    /// Sets the [`Transition`] of the [`Presence`].
+    pub fn transition(mut self, transition: Transition) -> Self {
+        self.transition = transition;
+        self
+    }
+
+    /// Animates the entrance of the content at the given [`Instant`].
+    pub fn show(&mut self, now: Instant) {
+        self.set_visible(true, now);
+    }
+
+    /// Animates the exit of the content at the given [`Instant`].
+    pub fn hide(&mut self, now: Instant) {
+        self.set_visible(false, now);
+    }
+
+    /// Animates the entrance or the exit of the content at the given
+    /// [`Instant`].
+    ///
+    /// A running animation is reversed from its current progress.
+    pub fn set_visible(&mut self, is_visible: bool, now: Instant) {
+        if self.is_visible == is_visible {
+            return;
+        }
+
+        let remaining = self
+            .transition
+            .duration
+            .mul_f32(1.0 - self.linear_progress(now));
+
+        self.is_visible = is_visible;
+        self.changed_at = Some(now.checked_sub(remaining).unwrap_or(now));
+    }
+
+    /// Returns true if the content is visible or entering the view.
+    pub fn is_visible(&self) -> bool {
+        self.is_visible
+    }
+
+    /// Returns true if the content must be displayed at the given
+    /// [`Instant`]; that is, if it is visible or still exiting the view.
+    pub fn is_present(&self, now: Instant) -> bool {
+        self.is_visible || self.linear_progress(now) < 1.0
+    }
+
+    /// Returns the eased visibility of the content at the given [`Instant`],
+    /// from `0.0` when hidden to `1.0` when fully visible.
+    pub fn progress(&self, now: Instant) -> f32 {
+        let progress = self.transition.easing.apply(self.linear_progress(now));
+
+        if self.is_visible {
+            progress
+        } else {
+            1.0 - progress
+        }
+    }
+
+    /// Returns the [`window::RedrawRequest`] needed to keep the [`Presence`]
+    /// animating at the given [`Instant`], if any.
+    pub fn redraw_request(
+        &self,
+        now: Instant,
+    ) -> Option<window::RedrawRequest> {
+        if self.linear_progress(now) < 1.0 {
+            Some(window::RedrawRequest::NextFrame)
+        } else {
+            None
+        }
+    }
+
+    fn linear_progress(&self, now: Instant) -> f32 {
+        match self.changed_at {
+            Some(changed_at) if !self.transition.duration.is_zero() => {
+                let elapsed = now.saturating_duration_since(changed_at);
+
+                (elapsed.as_secs_f32() / self.transition.duration.as_secs_f32())
+                    .min(1.0)
+            }
+            _ => 1.0,
+        }
+    }
+}
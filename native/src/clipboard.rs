@@ -1,5 +1,8 @@
 //! Access the clipboard.
+use crate::image;
+
 use std::fmt;
+use std::path::PathBuf;
 
 /// A buffer for short-term storage and transfer within and between
 /// applications.
@@ -9,6 +12,61 @@ pub trait Clipboard {
 
     /// Writes the given text contents to the [`Clipboard`].
     fn write(&mut self, contents: String);
+
+    /// Reads the current content of the primary selection as text.
+    ///
+    /// The primary selection holds the text selected last, and it is
+    /// usually pasted with the middle mouse button. It is only available on
+    /// Linux.
+    ///
+    /// By default, it returns `None`.
+    fn read_primary(&self) -> Option<String> {
+        None
+    }
+
+    /// Writes the given text contents to the primary selection.
+    ///
+    /// By default, it does nothing.
+    fn write_primary(&mut self, _contents: String) {}
+
+    /// Reads the current content of the [`Clipboard`] as an image.
+    ///
+    /// By default, it returns `None`.
+    fn read_image(&self) -> Option<image::Handle> {
+        None
+    }
+
+    /// Writes the given image to the [`Clipboard`].
+    ///
+    /// By default, it does nothing.
+    fn write_image(&mut self, _image: image::Handle) {}
+
+    /// Reads the current content of the [`Clipboard`] as HTML.
+    ///
+    /// By default, it returns `None`.
+    fn read_html(&self) -> Option<String> {
+        None
+    }
+
+    /// Writes the given HTML to the [`Clipboard`], along with an alternative
+    /// text for the applications that cannot paste HTML.
+    ///
+    /// By default, it writes the alternative text.
+    fn write_html(&mut self, _html: String, alt_text: String) {
+        self.write(alt_text);
+    }
+
+    /// Reads the current content of the [`Clipboard`] as a list of files.
+    ///
+    /// By default, it returns `None`.
+    fn read_files(&self) -> Option<Vec<PathBuf>> {
+        None
+    }
+
+    /// Writes the given list of files to the [`Clipboard`].
+    ///
+    /// By default, it does nothing.
+    fn write_files(&mut self, _files: Vec<PathBuf>) {}
 }
 
 /// A null implementation of the [`Clipboard`] trait.
@@ -32,6 +90,36 @@ pub enum Action<T> {
 
     /// Write the given contents to the clipboard.
     Write(String),
+
+    /// Read the primary selection and produce `T` with the result.
+    ReadPrimary(Box<dyn Fn(Option<String>) -> T>),
+
+    /// Write the given contents to the primary selection.
+    WritePrimary(String),
+
+    /// Read an image from the clipboard and produce `T` with the result.
+    ReadImage(Box<dyn Fn(Option<image::Handle>) -> T>),
+
+    /// Write the given image to the clipboard.
+    WriteImage(image::Handle),
+
+    /// Read HTML from the clipboard and produce `T` with the result.
+    ReadHtml(Box<dyn Fn(Option<String>) -> T>),
+
+    /// Write the given HTML to the clipboard.
+    WriteHtml {
+        /// The HTML to write
+        html: String,
+        /// The text to paste in applications that cannot paste HTML
+        alt_text: String,
+    },
+
+    /// Read a list of files from the clipboard and produce `T` with the
+    /// result.
+    ReadFiles(Box<dyn Fn(Option<Vec<PathBuf>>) -> T>),
+
+    /// Write the given list of files to the clipboard.
+    WriteFiles(Vec<PathBuf>),
 }
 
 impl<T> Action<T> {
@@ -43,6 +131,24 @@ impl<T> Action<T> {
         match self {
             Self::Read(o) => Action::Read(Box::new(move |s| f(o(s)))),
             Self::Write(content) => Action::Write(content),
+            Self::ReadPrimary(o) => {
+                Action::ReadPrimary(Box::new(move |s| f(o(s))))
+            }
+            Self::WritePrimary(content) => Action::WritePrimary(content),
+            Self::ReadImage(o) => {
+                Action::ReadImage(Box::new(move |image| f(o(image))))
+            }
+            Self::WriteImage(image) => Action::WriteImage(image),
+            Self::ReadHtml(o) => {
+                Action::ReadHtml(Box::new(move |html| f(o(html))))
+            }
+            Self::WriteHtml { html, alt_text } => {
+                Action::WriteHtml { html, alt_text }
+            }
+            Self::ReadFiles(o) => {
+                Action::ReadFiles(Box::new(move |files| f(o(files))))
+            }
+            Self::WriteFiles(files) => Action::WriteFiles(files),
         }
     }
 }
@@ -52,6 +158,14 @@ impl<T> fmt::Debug for Action<T> {
         match self {
             Self::Read(_) => write!(f, "Action::Read"),
             Self::Write(_) => write!(f, "Action::Write"),
+            Self::ReadPrimary(_) => write!(f, "Action::ReadPrimary"),
+            Self::WritePrimary(_) => write!(f, "Action::WritePrimary"),
+            Self::ReadImage(_) => write!(f, "Action::ReadImage"),
+            Self::WriteImage(_) => write!(f, "Action::WriteImage"),
+            Self::ReadHtml(_) => write!(f, "Action::ReadHtml"),
+            Self::WriteHtml { .. } => write!(f, "Action::WriteHtml"),
+            Self::ReadFiles(_) => write!(f, "Action::ReadFiles"),
+            Self::WriteFiles(_) => write!(f, "Action::WriteFiles"),
         }
     }
 }
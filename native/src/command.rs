@@ -1,8 +1,13 @@
 //! Run asynchronous actions.
 mod action;
+mod proxy;
 
 pub use action::Action;
+pub use proxy::{Closed, Proxy};
 
+use crate::widget;
+
+use iced_futures::futures::Stream;
 use std::fmt;
 use std::future::Future;
 
@@ -22,6 +27,21 @@ impl<T> Command<T> {
         Self(iced_futures::Command::single(action))
     }
 
+    /// Creates a [`Command`] that runs the given [`widget::Operation`] on the
+    /// widgets of the application.
+    pub fn widget(operation: impl widget::Operation<T> + 'static) -> Self {
+        Self::single(Action::Widget(Box::new(operation)))
+    }
+
+    /// Creates a [`Command`] that produces a message with a [`Proxy`] of the
+    /// runtime.
+    ///
+    /// The [`Proxy`] can be stored and used afterwards to send messages to the
+    /// application from any thread.
+    pub fn proxy(f: impl Fn(Proxy<T>) -> T + 'static) -> Self {
+        Self::single(Action::FetchProxy(Box::new(f)))
+    }
+
     /// Creates a [`Command`] that performs the action of the given future.
     pub fn perform<A>(
         future: impl Future<Output = T> + 'static + Send,
@@ -32,6 +52,35 @@ impl<T> Command<T> {
         Command::single(Action::Future(Box::pin(future.map(f))))
     }
 
+    /// Creates a [`Command`] that performs the action of the given future in
+    /// the background lane of the runtime.
+    ///
+    /// Use it for heavy tasks that are not urgent, so they do not starve the
+    /// rest of the commands.
+    pub fn perform_in_background<A>(
+        future: impl Future<Output = T> + 'static + Send,
+        f: impl Fn(T) -> A + 'static + Send,
+    ) -> Command<A> {
+        use iced_futures::futures::FutureExt;
+
+        Command::single(Action::Background(Box::pin(future.map(f))))
+    }
+
+    /// Creates a [`Command`] that runs the given stream to completion,
+    /// producing a message for every item.
+    ///
+    /// This is useful to report the [`Progress`] of a long-running task.
+    ///
+    /// [`Progress`]: iced_futures::progress::Progress
+    pub fn run<A>(
+        stream: impl Stream<Item = T> + 'static + Send,
+        f: impl Fn(T) -> A + 'static + Send,
+    ) -> Command<A> {
+        use iced_futures::futures::StreamExt;
+
+        Command::single(Action::Stream(Box::pin(stream.map(f))))
+    }
+
     /// Creates a [`Command`] that performs the actions of all the given
     /// commands.
     ///
@@ -49,6 +98,7 @@ impl<T> Command<T> {
     ) -> Command<A>
     where
         T: 'static,
+        A: 'static,
     {
         let Command(command) = self;
 
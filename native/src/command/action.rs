@@ -1,7 +1,12 @@
 use crate::clipboard;
+use crate::command::Proxy;
+use crate::dnd;
+use crate::tray;
+use crate::widget;
 use crate::window;
 
 use std::fmt;
+use std::sync::Arc;
 
 /// An action that a [`Command`] can perform.
 ///
@@ -10,11 +15,47 @@ pub enum Action<T> {
     /// Run a [`Future`] to completion.
     Future(iced_futures::BoxFuture<T>),
 
+    /// Run a [`Future`] to completion in the background lane of the runtime.
+    ///
+    /// [`Future`]: iced_futures::futures::Future
+    Background(iced_futures::BoxFuture<T>),
+
+    /// Run a [`Stream`] to completion, producing a `T` for every item.
+    ///
+    /// [`Stream`]: iced_futures::futures::Stream
+    Stream(iced_futures::BoxStream<T>),
+
     /// Run a clipboard action.
     Clipboard(clipboard::Action<T>),
 
     /// Run a window action.
     Window(window::Action),
+
+    /// Run a system tray action.
+    Tray(tray::Action<T>),
+
+    /// Fetch the monitors connected to the system and produce `T` with them.
+    FetchMonitors(Box<dyn Fn(Vec<window::Monitor>) -> T>),
+
+    /// Fetch the color scheme preferred by the user of the operating system
+    /// and produce `T` with it.
+    FetchColorScheme(Box<dyn Fn(Option<window::ColorScheme>) -> T>),
+
+    /// Capture the contents of the window with the given [`window::Id`] and
+    /// produce `T` with them.
+    Screenshot(window::Id, Box<dyn Fn(window::Screenshot) -> T>),
+
+    /// Start dragging some [`dnd::Data`] out of the main window, into other
+    /// applications, and produce `T` with whether the drag could be started.
+    ///
+    /// The drag follows the cursor until the mouse button is released.
+    StartDrag(dnd::Data, Box<dyn Fn(Result<(), dnd::Error>) -> T>),
+
+    /// Fetch a [`Proxy`] of the runtime and produce `T` with it.
+    FetchProxy(Box<dyn Fn(Proxy<T>) -> T>),
+
+    /// Run a widget operation.
+    Widget(Box<dyn widget::Operation<T>>),
 }
 
 impl<T> Action<T> {
@@ -22,13 +63,48 @@ impl<T> Action<T> {
     pub fn map<A>(self, f: impl Fn(T) -> A + 'static + Send + Sync) -> Action<A>
     where
         T: 'static,
+        A: 'static,
     {
-        use iced_futures::futures::FutureExt;
+        use iced_futures::futures::{FutureExt, StreamExt};
 
         match self {
             Self::Future(future) => Action::Future(Box::pin(future.map(f))),
+            Self::Background(future) => {
+                Action::Background(Box::pin(future.map(f)))
+            }
+            Self::Stream(stream) => Action::Stream(Box::pin(stream.map(f))),
             Self::Clipboard(action) => Action::Clipboard(action.map(f)),
             Self::Window(window) => Action::Window(window),
+            Self::Tray(action) => Action::Tray(action.map(f)),
+            Self::FetchMonitors(tag) => {
+                Action::FetchMonitors(Box::new(move |monitors| {
+                    f(tag(monitors))
+                }))
+            }
+            Self::FetchColorScheme(tag) => {
+                Action::FetchColorScheme(Box::new(move |color_scheme| {
+                    f(tag(color_scheme))
+                }))
+            }
+            Self::Screenshot(id, tag) => Action::Screenshot(
+                id,
+                Box::new(move |screenshot| f(tag(screenshot))),
+            ),
+            Self::StartDrag(data, tag) => {
+                Action::StartDrag(data, Box::new(move |result| f(tag(result))))
+            }
+            Self::FetchProxy(tag) => {
+                let f = Arc::new(f);
+
+                Action::FetchProxy(Box::new(move |proxy: Proxy<A>| {
+                    let map = f.clone();
+
+                    f(tag(proxy.map(move |message| map(message))))
+                }))
+            }
+            Self::Widget(operation) => {
+                Action::Widget(Box::new(widget::operation::map(operation, f)))
+            }
         }
     }
 }
@@ -37,10 +113,23 @@ impl<T> fmt::Debug for Action<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::Future(_) => write!(f, "Action::Future"),
+            Self::Background(_) => write!(f, "Action::Background"),
+            Self::Stream(_) => write!(f, "Action::Stream"),
             Self::Clipboard(action) => {
                 write!(f, "Action::Clipboard({:?})", action)
             }
             Self::Window(action) => write!(f, "Action::Window({:?})", action),
+            Self::Tray(action) => write!(f, "Action::Tray({:?})", action),
+            Self::FetchMonitors(_) => write!(f, "Action::FetchMonitors"),
+            Self::FetchColorScheme(_) => write!(f, "Action::FetchColorScheme"),
+            Self::Screenshot(id, _) => {
+                write!(f, "Action::Screenshot({:?})", id)
+            }
+            Self::StartDrag(data, _) => {
+                write!(f, "Action::StartDrag({:?})", data)
+            }
+            Self::FetchProxy(_) => write!(f, "Action::FetchProxy"),
+            Self::Widget(_) => write!(f, "Action::Widget"),
         }
     }
 }
@@ -0,0 +1,66 @@
+use std::fmt;
+use std::sync::Arc;
+
+/// A handle that sends messages to the runtime of an application and wakes
+/// up its event loop.
+///
+/// A [`Proxy`] can be cloned cheaply and sent to other threads, so it can be
+/// used to integrate foreign event sources, like hardware callbacks.
+pub struct Proxy<T> {
+    send: Arc<dyn Fn(T) -> Result<(), Closed> + Send + Sync>,
+}
+
+impl<T> Proxy<T> {
+    /// Creates a new [`Proxy`] from a function that sends a message to a
+    /// runtime.
+    pub fn new(
+        send: impl Fn(T) -> Result<(), Closed> + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            send: Arc::new(send),
+        }
+    }
+
+    /// Sends a message to the runtime.
+    ///
+    /// Fails if the runtime has already exited.
+    pub fn send(&self, message: T) -> Result<(), Closed> {
+        (self.send)(message)
+    }
+
+    /// Creates a [`Proxy`] that sends messages of a different type,
+    /// transforming them with the given function.
+    pub fn map<A>(self, f: impl Fn(A) -> T + Send + Sync + 'static) -> Proxy<A>
+    where
+        T: 'static,
+    {
+        Proxy::new(move |message| self.send(f(message)))
+    }
+}
+
+impl<T> Clone for Proxy<T> {
+    fn clone(&self) -> Self {
+        Self {
+            send: self.send.clone(),
+        }
+    }
+}
+
+impl<T> fmt::Debug for Proxy<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Proxy").finish()
+    }
+}
+
+/// The error of sending a message with a [`Proxy`] to a runtime that has
+/// already exited.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Closed;
+
+impl fmt::Display for Closed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "the runtime has exited")
+    }
+}
+
+impl std::error::Error for Closed {}
@@ -1,6 +1,13 @@
 #![allow(missing_docs)]
 use std::{collections::VecDeque, time};
 
+#[cfg(feature = "tracing")]
+#[path = "spans.rs"]
+mod spans;
+
+#[cfg(feature = "tracing")]
+use spans::{Spans, Stage};
+
 /// A bunch of time measurements for debugging purposes.
 #[derive(Debug)]
 pub struct Debug {
@@ -27,8 +34,16 @@ pub struct Debug {
     render_start: time::Instant,
     render_durations: TimeBuffer,
 
+    present_start: time::Instant,
+    present_durations: TimeBuffer,
+
+    frames: VecDeque<time::Instant>,
+
     message_count: usize,
     last_messages: VecDeque<String>,
+
+    #[cfg(feature = "tracing")]
+    spans: Spans,
 }
 
 impl Debug {
@@ -59,8 +74,16 @@ impl Debug {
             render_start: now,
             render_durations: TimeBuffer::new(50),
 
+            present_start: now,
+            present_durations: TimeBuffer::new(50),
+
+            frames: VecDeque::new(),
+
             message_count: 0,
             last_messages: VecDeque::new(),
+
+            #[cfg(feature = "tracing")]
+            spans: Spans::default(),
         }
     }
 
@@ -68,66 +91,138 @@ impl Debug {
         self.is_enabled = !self.is_enabled;
     }
 
+    pub fn is_enabled(&self) -> bool {
+        self.is_enabled
+    }
+
     pub fn startup_started(&mut self) {
+        #[cfg(feature = "tracing")]
+        self.spans.enter(Stage::Startup);
+
         self.startup_start = time::Instant::now();
     }
 
     pub fn startup_finished(&mut self) {
+        #[cfg(feature = "tracing")]
+        self.spans.exit(Stage::Startup);
+
         self.startup_duration = time::Instant::now() - self.startup_start;
     }
 
     pub fn update_started(&mut self) {
+        #[cfg(feature = "tracing")]
+        self.spans.enter(Stage::Update);
+
         self.update_start = time::Instant::now();
     }
 
     pub fn update_finished(&mut self) {
+        #[cfg(feature = "tracing")]
+        self.spans.exit(Stage::Update);
+
         self.update_durations
             .push(time::Instant::now() - self.update_start);
     }
 
     pub fn view_started(&mut self) {
+        #[cfg(feature = "tracing")]
+        self.spans.enter(Stage::View);
+
         self.view_start = time::Instant::now();
     }
 
     pub fn view_finished(&mut self) {
+        #[cfg(feature = "tracing")]
+        self.spans.exit(Stage::View);
+
         self.view_durations
             .push(time::Instant::now() - self.view_start);
     }
 
     pub fn layout_started(&mut self) {
+        #[cfg(feature = "tracing")]
+        self.spans.enter(Stage::Layout);
+
         self.layout_start = time::Instant::now();
     }
 
     pub fn layout_finished(&mut self) {
+        #[cfg(feature = "tracing")]
+        self.spans.exit(Stage::Layout);
+
         self.layout_durations
             .push(time::Instant::now() - self.layout_start);
     }
 
     pub fn event_processing_started(&mut self) {
+        #[cfg(feature = "tracing")]
+        self.spans.enter(Stage::Event);
+
         self.event_start = time::Instant::now();
     }
 
     pub fn event_processing_finished(&mut self) {
+        #[cfg(feature = "tracing")]
+        self.spans.exit(Stage::Event);
+
         self.event_durations
             .push(time::Instant::now() - self.event_start);
     }
 
     pub fn draw_started(&mut self) {
+        #[cfg(feature = "tracing")]
+        self.spans.enter(Stage::Draw);
+
         self.draw_start = time::Instant::now();
     }
 
     pub fn draw_finished(&mut self) {
+        #[cfg(feature = "tracing")]
+        self.spans.exit(Stage::Draw);
+
         self.draw_durations
             .push(time::Instant::now() - self.draw_start);
     }
 
     pub fn render_started(&mut self) {
+        #[cfg(feature = "tracing")]
+        self.spans.enter(Stage::Render);
+
         self.render_start = time::Instant::now();
     }
 
     pub fn render_finished(&mut self) {
-        self.render_durations
-            .push(time::Instant::now() - self.render_start);
+        #[cfg(feature = "tracing")]
+        self.spans.exit(Stage::Render);
+
+        let now = time::Instant::now();
+
+        self.render_durations.push(now - self.render_start);
+
+        self.frames.push_back(now);
+
+        while let Some(frame) = self.frames.front() {
+            if now - *frame <= time::Duration::from_secs(1) {
+                break;
+            }
+
+            let _ = self.frames.pop_front();
+        }
+    }
+
+    pub fn present_started(&mut self) {
+        #[cfg(feature = "tracing")]
+        self.spans.enter(Stage::Present);
+
+        self.present_start = time::Instant::now();
+    }
+
+    pub fn present_finished(&mut self) {
+        #[cfg(feature = "tracing")]
+        self.spans.exit(Stage::Present);
+
+        self.present_durations
+            .push(time::Instant::now() - self.present_start);
     }
 
     pub fn log_message<Message: std::fmt::Debug>(&mut self, message: &Message) {
@@ -158,6 +253,16 @@ impl Debug {
             env!("CARGO_PKG_REPOSITORY"),
         ));
         lines.push(key_value("Startup:", self.startup_duration));
+        lines.push(format!(
+            "Frame: {:?} ({} FPS)",
+            self.render_durations.average(),
+            self.frames.len()
+        ));
+        lines.push(format!(
+            "Frame time (up to {:?}):",
+            self.render_durations.max().max(FRAME_BUDGET)
+        ));
+        lines.push(self.render_durations.graph(FRAME_BUDGET));
         lines.push(key_value("Update:", self.update_durations.average()));
         lines.push(key_value("View:", self.view_durations.average()));
         lines.push(key_value("Layout:", self.layout_durations.average()));
@@ -169,7 +274,7 @@ impl Debug {
             "Primitive generation:",
             self.draw_durations.average(),
         ));
-        lines.push(key_value("Render:", self.render_durations.average()));
+        lines.push(key_value("Present:", self.present_durations.average()));
         lines.push(key_value("Message count:", self.message_count));
         lines.push(String::from("Last messages:"));
         lines.extend(self.last_messages.iter().map(|msg| {
@@ -184,6 +289,9 @@ impl Debug {
     }
 }
 
+/// The time available to render a frame at 60 FPS.
+const FRAME_BUDGET: time::Duration = time::Duration::from_micros(16_667);
+
 #[derive(Debug)]
 struct TimeBuffer {
     head: usize,
@@ -215,4 +323,33 @@ impl TimeBuffer {
 
         sum / self.size.max(1) as u32
     }
+
+    fn max(&self) -> time::Duration {
+        self.contents[..self.size]
+            .iter()
+            .copied()
+            .max()
+            .unwrap_or_default()
+    }
+
+    /// Draws the durations in the [`TimeBuffer`], from oldest to newest, as a
+    /// bar graph made of block elements.
+    ///
+    /// The graph is scaled to fit the largest duration, but never below the
+    /// given minimum.
+    fn graph(&self, minimum: time::Duration) -> String {
+        const BARS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+        let scale = self.max().max(minimum).as_secs_f32();
+        let len = self.contents.len();
+
+        (0..self.size)
+            .map(|i| self.contents[(self.head + len + 1 - self.size + i) % len])
+            .map(|duration| {
+                let level = (duration.as_secs_f32() / scale * 7.0).round();
+
+                BARS[(level as usize).min(7)]
+            })
+            .collect()
+    }
 }
@@ -1,39 +1,108 @@
 #![allow(missing_docs)]
+#[cfg(feature = "tracing")]
+#[path = "spans.rs"]
+mod spans;
+
+#[cfg(feature = "tracing")]
+use spans::{Spans, Stage};
+
 #[derive(Debug)]
-pub struct Debug;
+pub struct Debug {
+    #[cfg(feature = "tracing")]
+    spans: Spans,
+}
 
 impl Debug {
     pub fn new() -> Self {
-        Self
+        Self {
+            #[cfg(feature = "tracing")]
+            spans: Spans::default(),
+        }
     }
 
-    pub fn startup_started(&mut self) {}
+    pub fn is_enabled(&self) -> bool {
+        false
+    }
 
-    pub fn startup_finished(&mut self) {}
+    pub fn startup_started(&mut self) {
+        #[cfg(feature = "tracing")]
+        self.spans.enter(Stage::Startup);
+    }
 
-    pub fn update_started(&mut self) {}
+    pub fn startup_finished(&mut self) {
+        #[cfg(feature = "tracing")]
+        self.spans.exit(Stage::Startup);
+    }
 
-    pub fn update_finished(&mut self) {}
+    pub fn update_started(&mut self) {
+        #[cfg(feature = "tracing")]
+        self.spans.enter(Stage::Update);
+    }
 
-    pub fn view_started(&mut self) {}
+    pub fn update_finished(&mut self) {
+        #[cfg(feature = "tracing")]
+        self.spans.exit(Stage::Update);
+    }
 
-    pub fn view_finished(&mut self) {}
+    pub fn view_started(&mut self) {
+        #[cfg(feature = "tracing")]
+        self.spans.enter(Stage::View);
+    }
 
-    pub fn layout_started(&mut self) {}
+    pub fn view_finished(&mut self) {
+        #[cfg(feature = "tracing")]
+        self.spans.exit(Stage::View);
+    }
 
-    pub fn layout_finished(&mut self) {}
+    pub fn layout_started(&mut self) {
+        #[cfg(feature = "tracing")]
+        self.spans.enter(Stage::Layout);
+    }
 
-    pub fn event_processing_started(&mut self) {}
+    pub fn layout_finished(&mut self) {
+        #[cfg(feature = "tracing")]
+        self.spans.exit(Stage::Layout);
+    }
 
-    pub fn event_processing_finished(&mut self) {}
+    pub fn event_processing_started(&mut self) {
+        #[cfg(feature = "tracing")]
+        self.spans.enter(Stage::Event);
+    }
+
+    pub fn event_processing_finished(&mut self) {
+        #[cfg(feature = "tracing")]
+        self.spans.exit(Stage::Event);
+    }
 
-    pub fn draw_started(&mut self) {}
+    pub fn draw_started(&mut self) {
+        #[cfg(feature = "tracing")]
+        self.spans.enter(Stage::Draw);
+    }
 
-    pub fn draw_finished(&mut self) {}
+    pub fn draw_finished(&mut self) {
+        #[cfg(feature = "tracing")]
+        self.spans.exit(Stage::Draw);
+    }
 
-    pub fn render_started(&mut self) {}
+    pub fn render_started(&mut self) {
+        #[cfg(feature = "tracing")]
+        self.spans.enter(Stage::Render);
+    }
 
-    pub fn render_finished(&mut self) {}
+    pub fn render_finished(&mut self) {
+        #[cfg(feature = "tracing")]
+        self.spans.exit(Stage::Render);
+    }
+
+    pub fn present_started(&mut self) {
+        #[cfg(feature = "tracing")]
+        self.spans.enter(Stage::Present);
+    }
+
+    pub fn present_finished(&mut self) {
+        #[cfg(feature = "tracing")]
+        self.spans.exit(Stage::Present);
+    }
 
     pub fn log_message<Message: std::fmt::Debug>(
         &mut self,
@@ -0,0 +1,43 @@
+use tracing::span::EnteredSpan;
+
+/// A stage of the lifecycle of an application, traced with its own span.
+#[derive(Debug, Clone, Copy)]
+pub enum Stage {
+    Startup,
+    Update,
+    View,
+    Layout,
+    Event,
+    Draw,
+    Render,
+    Present,
+}
+
+/// The `tracing` spans of the stages that are currently running.
+#[derive(Debug, Default)]
+pub struct Spans {
+    entered: [Option<EnteredSpan>; 8],
+}
+
+impl Spans {
+    /// Enters the span of the given [`Stage`].
+    pub fn enter(&mut self, stage: Stage) {
+        let span = match stage {
+            Stage::Startup => tracing::info_span!("startup"),
+            Stage::Update => tracing::info_span!("update"),
+            Stage::View => tracing::info_span!("view"),
+            Stage::Layout => tracing::info_span!("layout"),
+            Stage::Event => tracing::info_span!("event"),
+            Stage::Draw => tracing::info_span!("draw"),
+            Stage::Render => tracing::info_span!("render"),
+            Stage::Present => tracing::info_span!("present"),
+        };
+
+        self.entered[stage as usize] = Some(span.entered());
+    }
+
+    /// Exits the span of the given [`Stage`], if it was entered.
+    pub fn exit(&mut self, stage: Stage) {
+        self.entered[stage as usize] = None;
+    }
+}
@@ -0,0 +1,149 @@
+//! Drag and drop data between widgets.
+//!
+//! A drag is started by a widget calling [`Shell::start_drag`] with some
+//! [`Payload`]. From that moment on, the [`UserInterface`] notifies every
+//! widget of the progress of the drag with an [`Event`] until the payload is
+//! dropped or the drag is cancelled.
+//!
+//! Files dragged from other applications are also notified as a drag
+//! carrying [`Files`]. Dragging [`Data`] out of an application can be
+//! achieved with a [`command::Action::StartDrag`].
+//!
+//! [`command::Action::StartDrag`]: crate::command::Action::StartDrag
+//! [`Shell::start_drag`]: crate::Shell::start_drag
+//! [`UserInterface`]: crate::UserInterface
+use std::any::Any;
+use std::fmt;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+/// The data carried by an on-going drag.
+#[derive(Clone)]
+pub struct Payload(Arc<dyn Any + Send + Sync>);
+
+impl Payload {
+    /// Creates a new [`Payload`] carrying the given data.
+    pub fn new<T>(data: T) -> Self
+    where
+        T: Any + Send + Sync,
+    {
+        Self(Arc::new(data))
+    }
+
+    /// Returns true if the [`Payload`] carries data of type `T`.
+    pub fn is<T: Any>(&self) -> bool {
+        self.0.is::<T>()
+    }
+
+    /// Returns a reference to the data of the [`Payload`], if it has type
+    /// `T`.
+    pub fn downcast_ref<T: Any>(&self) -> Option<&T> {
+        self.0.downcast_ref()
+    }
+}
+
+impl fmt::Debug for Payload {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Payload").finish()
+    }
+}
+
+impl PartialEq for Payload {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+/// A drag and drop interaction.
+///
+/// These events are produced alongside the mouse and touch events driving
+/// the drag, so widgets can use the current cursor position to find out
+/// whether the [`Payload`] is over them.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Event {
+    /// The [`Payload`] was moved.
+    Moved(Payload),
+
+    /// The [`Payload`] was dropped.
+    Dropped(Payload),
+
+    /// The drag was cancelled.
+    Cancelled(Payload),
+}
+
+/// The payload of a drag of files coming from other applications.
+///
+/// A [`DropTarget`] accepting [`Files`] can be used to let users drop files
+/// in a specific area of your application.
+///
+/// _**Note:** Most platforms do not report cursor movement while files are
+/// being dragged over a window. In that case, the last known cursor position
+/// is used to find the widgets the files are hovering._
+///
+/// [`DropTarget`]: crate::widget::DropTarget
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Files {
+    paths: Vec<PathBuf>,
+}
+
+impl Files {
+    /// Creates some new [`Files`] with the given paths.
+    pub fn new(paths: Vec<PathBuf>) -> Self {
+        Self { paths }
+    }
+
+    /// Returns the paths of the [`Files`].
+    pub fn paths(&self) -> &[PathBuf] {
+        &self.paths
+    }
+}
+
+/// The data dragged out of an application, into other applications.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Data {
+    /// Some files, like an attachment dragged into a file manager.
+    Files(Vec<PathBuf>),
+
+    /// Some text.
+    Text(String),
+
+    /// An image.
+    Image {
+        /// The width of the image.
+        width: u32,
+
+        /// The height of the image.
+        height: u32,
+
+        /// The pixels of the image, in RGBA order.
+        pixels: Vec<u8>,
+    },
+}
+
+/// An error that prevented [`Data`] from being dragged out of an
+/// application.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// The platform does not support dragging data out of an application.
+    Unsupported,
+
+    /// The drag could not be started.
+    Failed(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Unsupported => write!(
+                f,
+                "dragging data out of the window is not supported by the \
+                platform"
+            ),
+            Error::Failed(error) => {
+                write!(f, "the drag could not be started: {}", error)
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
@@ -3,10 +3,15 @@ use crate::layout;
 use crate::mouse;
 use crate::overlay;
 use crate::renderer;
+use crate::widget::inspector;
+use crate::widget::operation::{self, Operation};
 use crate::{
     Clipboard, Color, Hasher, Layout, Length, Point, Rectangle, Shell, Widget,
 };
 
+use std::cell::Cell;
+use std::hash::{Hash, Hasher as _};
+
 /// A generic [`Widget`].
 ///
 /// It is useful to build composable user interfaces that do not leak
@@ -19,6 +24,7 @@ use crate::{
 #[allow(missing_debug_implementations)]
 pub struct Element<'a, Message, Renderer> {
     pub(crate) widget: Box<dyn Widget<Message, Renderer> + 'a>,
+    hash: Cell<Option<u64>>,
 }
 
 impl<'a, Message, Renderer> Element<'a, Message, Renderer>
@@ -31,6 +37,7 @@ where
     ) -> Element<'a, Message, Renderer> {
         Element {
             widget: Box::new(widget),
+            hash: Cell::new(None),
         }
     }
 
@@ -177,6 +184,7 @@ where
     {
         Element {
             widget: Box::new(Map::new(self.widget, f)),
+            hash: Cell::new(None),
         }
     }
 
@@ -196,6 +204,7 @@ where
     {
         Element {
             widget: Box::new(Explain::new(self, color.into())),
+            hash: Cell::new(None),
         }
     }
 
@@ -217,7 +226,14 @@ where
         renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
-        self.widget.layout(renderer, limits)
+        // The layout of an already hashed subtree can be reused as long as
+        // its limits do not change
+        match self.hash.get() {
+            Some(hash) => layout::cache::fetch(hash, limits, || {
+                self.widget.layout(renderer, limits)
+            }),
+            None => self.widget.layout(renderer, limits),
+        }
     }
 
     /// Processes a runtime [`Event`].
@@ -265,16 +281,45 @@ where
     }
 
     /// Computes the _layout_ hash of the [`Element`].
+    ///
+    /// The hash of the subtree is remembered, so its layout can be cached
+    /// and reused by the runtime.
     pub fn hash_layout(&self, state: &mut Hasher) {
-        self.widget.hash_layout(state);
+        let hash = {
+            let hasher = &mut Hasher::default();
+            self.widget.hash_layout(hasher);
+
+            hasher.finish()
+        };
+
+        self.hash.set(Some(hash));
+
+        hash.hash(state);
+    }
+
+    /// Applies an [`Operation`] to the [`Element`].
+    pub fn operate(
+        &mut self,
+        layout: Layout<'_>,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        self.widget.operate(layout, operation);
+    }
+
+    /// Describes the [`Element`] and its children to an [`Inspector`].
+    ///
+    /// [`Inspector`]: crate::widget::Inspector
+    pub fn inspect(&self, layout: Layout<'_>) -> inspector::Node {
+        self.widget.inspect(layout)
     }
 
     /// Returns the overlay of the [`Element`], if there is any.
     pub fn overlay<'b>(
         &'b mut self,
         layout: Layout<'_>,
+        viewport: &Rectangle,
     ) -> Option<overlay::Element<'b, Message, Renderer>> {
-        self.widget.overlay(layout)
+        self.widget.overlay(layout, viewport)
     }
 }
 
@@ -372,14 +417,28 @@ where
         self.widget.hash_layout(state);
     }
 
+    fn operate(
+        &mut self,
+        layout: Layout<'_>,
+        operation: &mut dyn Operation<B>,
+    ) {
+        self.widget
+            .operate(layout, &mut operation::MapRef { operation });
+    }
+
+    fn inspect(&self, layout: Layout<'_>) -> inspector::Node {
+        self.widget.inspect(layout)
+    }
+
     fn overlay(
         &mut self,
         layout: Layout<'_>,
+        viewport: &Rectangle,
     ) -> Option<overlay::Element<'_, B, Renderer>> {
         let mapper = &self.mapper;
 
         self.widget
-            .overlay(layout)
+            .overlay(layout, viewport)
             .map(move |overlay| overlay.map(mapper))
     }
 }
@@ -492,10 +551,23 @@ where
         self.element.widget.hash_layout(state);
     }
 
+    fn operate(
+        &mut self,
+        layout: Layout<'_>,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        self.element.operate(layout, operation);
+    }
+
+    fn inspect(&self, layout: Layout<'_>) -> inspector::Node {
+        self.element.inspect(layout)
+    }
+
     fn overlay(
         &mut self,
         layout: Layout<'_>,
+        viewport: &Rectangle,
     ) -> Option<overlay::Element<'_, Message, Renderer>> {
-        self.element.overlay(layout)
+        self.element.overlay(layout, viewport)
     }
 }
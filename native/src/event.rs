@@ -1,6 +1,10 @@
 //! Handle events of a user interface.
+use crate::accessibility;
+use crate::dnd;
+use crate::input_method;
 use crate::keyboard;
 use crate::mouse;
+use crate::pen;
 use crate::touch;
 use crate::window;
 
@@ -24,6 +28,21 @@ pub enum Event {
     /// A touch event
     Touch(touch::Event),
 
+    /// A touch gesture
+    Gesture(touch::gesture::Event),
+
+    /// A pen event
+    Pen(pen::Event),
+
+    /// A drag and drop event
+    DragAndDrop(dnd::Event),
+
+    /// An input method event
+    InputMethod(input_method::Event),
+
+    /// An action requested by an assistive technology
+    Accessibility(accessibility::Request),
+
     /// A platform specific event
     PlatformSpecific(PlatformSpecific),
 }
@@ -76,3 +95,74 @@ impl Status {
         }
     }
 }
+
+/// Pushes an [`Event`] to a queue, coalescing it with a previous movement of
+/// the same pointer.
+///
+/// A movement of the mouse, a finger, or a pen replaces the latest pending
+/// movement of the same pointer, as long as only other movements were queued
+/// after it. Therefore, presses, releases, and any other events keep their
+/// order with respect to the movements around them.
+///
+/// ```
+/// use iced_native::event::{self, Event};
+/// use iced_native::mouse;
+/// use iced_native::Point;
+///
+/// let moved = |x| {
+///     Event::Mouse(mouse::Event::CursorMoved {
+///         position: Point::new(x, 0.0),
+///     })
+/// };
+///
+/// let pressed =
+///     Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left));
+///
+/// let mut events = Vec::new();
+///
+/// event::coalesce(&mut events, moved(1.0));
+/// event::coalesce(&mut events, moved(2.0));
+/// event::coalesce(&mut events, pressed.clone());
+/// event::coalesce(&mut events, moved(3.0));
+///
+/// assert_eq!(events, vec![moved(2.0), pressed, moved(3.0)]);
+/// ```
+pub fn coalesce(events: &mut Vec<Event>, event: Event) {
+    if let Some(pointer) = Pointer::of(&event) {
+        let previous = events
+            .iter_mut()
+            .rev()
+            .map_while(|queued| Some((Pointer::of(queued)?, queued)))
+            .find(|(queued, _)| *queued == pointer);
+
+        if let Some((_, queued)) = previous {
+            *queued = event;
+
+            return;
+        }
+    }
+
+    events.push(event);
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Pointer {
+    Mouse,
+    Finger(touch::Finger),
+    Pen,
+}
+
+impl Pointer {
+    fn of(event: &Event) -> Option<Pointer> {
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                Some(Pointer::Mouse)
+            }
+            Event::Touch(touch::Event::FingerMoved { id, .. }) => {
+                Some(Pointer::Finger(*id))
+            }
+            Event::Pen(pen::Event::Moved(_)) => Some(Pointer::Pen),
+            _ => None,
+        }
+    }
+}
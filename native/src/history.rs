@@ -0,0 +1,313 @@
+//! Undo and redo the changes of an application.
+//!
+//! A [`History`] keeps the [`Edit`] operations performed on some state of an
+//! application, so they can be reverted and reapplied later. The
+//! [`Action`] of an undo or redo can be produced by any widget, menu item or
+//! shortcut and handled in the `update` logic of the application.
+
+/// An undoable change to some state.
+pub trait Edit {
+    /// The type of state changed by the [`Edit`].
+    type Target;
+
+    /// Applies the [`Edit`] to the target.
+    fn apply(&self, target: &mut Self::Target);
+
+    /// Reverts the [`Edit`], leaving the target as it was before it was
+    /// applied.
+    fn revert(&self, target: &mut Self::Target);
+
+    /// Tries to merge the `next` edit into this one, so both can be undone in
+    /// a single step. Returns whether the edits were merged.
+    ///
+    /// This is useful to coalesce bursts of similar edits, like the
+    /// keystrokes of a word or the steps of a drag. By default, edits are
+    /// never merged.
+    fn merge(&mut self, _next: &Self) -> bool {
+        false
+    }
+}
+
+/// An action on a [`History`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Action {
+    /// Revert the last step.
+    Undo,
+    /// Reapply the last reverted step.
+    Redo,
+}
+
+/// The undo and redo stacks of some state.
+#[derive(Debug, Clone)]
+pub struct History<E> {
+    undo: Vec<E>,
+    redo: Vec<E>,
+    limit: Option<usize>,
+    is_sealed: bool,
+}
+
+impl<E> History<E>
+where
+    E: Edit,
+{
+    /// Creates an empty [`History`] that keeps every step.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates an empty [`History`] that keeps, at most, the given amount of
+    /// undo steps, forgetting the oldest ones first.
+    pub fn with_limit(limit: usize) -> Self {
+        Self {
+            limit: Some(limit),
+            ..Self::default()
+        }
+    }
+
+    /// Applies the [`Edit`] to the target and records it.
+    pub fn perform(&mut self, edit: E, target: &mut E::Target) {
+        edit.apply(target);
+
+        self.record(edit);
+    }
+
+    /// Records an [`Edit`] that has already been applied.
+    ///
+    /// The [`Edit`] is merged into the last step, if possible, unless the
+    /// [`History`] has been sealed since. Recording an [`Edit`] forgets every
+    /// step that can be redone.
+    pub fn record(&mut self, edit: E) {
+        self.redo.clear();
+
+        let is_merged = match self.undo.last_mut() {
+            Some(last) if !self.is_sealed => last.merge(&edit),
+            _ => false,
+        };
+
+        if !is_merged {
+            self.undo.push(edit);
+
+            if let Some(limit) = self.limit {
+                if self.undo.len() > limit {
+                    let _ = self.undo.remove(0);
+                }
+            }
+        }
+
+        self.is_sealed = false;
+    }
+
+    /// Reverts the last step on the target, returning whether there was
+    /// any step to revert.
+    pub fn undo(&mut self, target: &mut E::Target) -> bool {
+        match self.undo.pop() {
+            Some(edit) => {
+                edit.revert(target);
+
+                self.redo.push(edit);
+                self.is_sealed = true;
+
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Reapplies the last reverted step on the target, returning whether
+    /// there was any step to reapply.
+    pub fn redo(&mut self, target: &mut E::Target) -> bool {
+        match self.redo.pop() {
+            Some(edit) => {
+                edit.apply(target);
+
+                self.undo.push(edit);
+                self.is_sealed = true;
+
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Performs the given [`Action`] on the target, returning whether the
+    /// target changed.
+    pub fn update(&mut self, action: Action, target: &mut E::Target) -> bool {
+        match action {
+            Action::Undo => self.undo(target),
+            Action::Redo => self.redo(target),
+        }
+    }
+
+    /// Returns whether there is any step that can be undone.
+    ///
+    /// This is useful to enable or disable an "Undo" menu item.
+    pub fn can_undo(&self) -> bool {
+        !self.undo.is_empty()
+    }
+
+    /// Returns whether there is any step that can be redone.
+    ///
+    /// This is useful to enable or disable a "Redo" menu item.
+    pub fn can_redo(&self) -> bool {
+        !self.redo.is_empty()
+    }
+
+    /// Returns the step that would be reverted by an undo, if any.
+    ///
+    /// This is useful to describe the step in a menu item, like
+    /// "Undo Typing".
+    pub fn next_undo(&self) -> Option<&E> {
+        self.undo.last()
+    }
+
+    /// Returns the step that would be reapplied by a redo, if any.
+    pub fn next_redo(&self) -> Option<&E> {
+        self.redo.last()
+    }
+
+    /// Closes the step that is currently open, so the next recorded
+    /// [`Edit`] starts a new one.
+    pub fn seal(&mut self) {
+        self.is_sealed = true;
+    }
+
+    /// Forgets every recorded step.
+    pub fn clear(&mut self) {
+        self.undo.clear();
+        self.redo.clear();
+        self.is_sealed = false;
+    }
+}
+
+impl<E> Default for History<E> {
+    fn default() -> Self {
+        Self {
+            undo: Vec::new(),
+            redo: Vec::new(),
+            limit: None,
+            is_sealed: false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Appends some text to a `String`, merging consecutive letters.
+    #[derive(Debug, Clone, PartialEq)]
+    struct Append(String);
+
+    impl Edit for Append {
+        type Target = String;
+
+        fn apply(&self, target: &mut String) {
+            target.push_str(&self.0);
+        }
+
+        fn revert(&self, target: &mut String) {
+            target.truncate(target.len() - self.0.len());
+        }
+
+        fn merge(&mut self, next: &Self) -> bool {
+            let is_word = |text: &str| text.chars().all(char::is_alphabetic);
+
+            if is_word(&self.0) && is_word(&next.0) {
+                self.0.push_str(&next.0);
+
+                true
+            } else {
+                false
+            }
+        }
+    }
+
+    fn append(text: &str) -> Append {
+        Append(String::from(text))
+    }
+
+    #[test]
+    fn undo_and_redo_steps() {
+        let mut history = History::new();
+        let mut text = String::new();
+
+        history.perform(append("a"), &mut text);
+        history.perform(append(" "), &mut text);
+
+        assert!(history.undo(&mut text));
+        assert_eq!(text, "a");
+
+        assert!(history.undo(&mut text));
+        assert_eq!(text, "");
+        assert!(!history.undo(&mut text));
+
+        assert!(history.update(Action::Redo, &mut text));
+        assert!(history.update(Action::Redo, &mut text));
+        assert_eq!(text, "a ");
+        assert!(!history.can_redo());
+    }
+
+    #[test]
+    fn new_edits_truncate_redo() {
+        let mut history = History::new();
+        let mut text = String::new();
+
+        history.perform(append("a"), &mut text);
+        history.perform(append(" "), &mut text);
+        history.perform(append("b"), &mut text);
+
+        assert!(history.undo(&mut text));
+        assert!(history.undo(&mut text));
+        assert_eq!(history.next_redo(), Some(&append(" ")));
+
+        history.perform(append("!"), &mut text);
+
+        assert_eq!(text, "a!");
+        assert!(!history.can_redo());
+        assert!(!history.redo(&mut text));
+
+        assert!(history.undo(&mut text));
+        assert_eq!(text, "a");
+    }
+
+    #[test]
+    fn merges_edits_until_sealed() {
+        let mut history = History::new();
+        let mut text = String::new();
+
+        history.perform(append("a"), &mut text);
+        history.perform(append("b"), &mut text);
+        history.seal();
+        history.perform(append("c"), &mut text);
+
+        assert_eq!(history.next_undo(), Some(&append("c")));
+
+        assert!(history.undo(&mut text));
+        assert_eq!(text, "ab");
+
+        assert!(history.undo(&mut text));
+        assert_eq!(text, "");
+
+        // Redoing seals the history, so a new edit does not merge into it
+        assert!(history.redo(&mut text));
+        history.perform(append("d"), &mut text);
+
+        assert!(history.undo(&mut text));
+        assert_eq!(text, "ab");
+    }
+
+    #[test]
+    fn forgets_the_oldest_steps_over_the_limit() {
+        let mut history = History::with_limit(2);
+        let mut text = String::new();
+
+        for edit in &["a", " ", "b"] {
+            history.perform(append(edit), &mut text);
+        }
+
+        assert!(history.undo(&mut text));
+        assert!(history.undo(&mut text));
+        assert!(!history.undo(&mut text));
+        assert_eq!(text, "a");
+    }
+}
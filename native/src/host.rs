@@ -0,0 +1,39 @@
+//! Run iced inside the event loop of a host application.
+use crate::mouse;
+use crate::window;
+use crate::{Clipboard, Event};
+
+/// The windowing system an iced [`Program`] runs in.
+///
+/// A [`Host`] translates the native events of a windowing library into iced
+/// events, gives access to its clipboard, and controls its window. By
+/// implementing it, iced can be driven by any event loop, like the one of an
+/// SDL2 application or of a game engine that already owns its window, with
+/// [`program::State::process`].
+///
+/// The graphics compositors of iced only need a window implementing
+/// `HasRawWindowHandle` to draw into it, so a [`Host`] wrapping such a window
+/// is enough to embed a whole iced user interface.
+///
+/// [`Program`]: crate::Program
+/// [`program::State::process`]: crate::program::State::process
+pub trait Host {
+    /// The native event type of the [`Host`], which may borrow from its
+    /// event loop.
+    type Event<'a>;
+
+    /// Translates a native event of the [`Host`] into the iced [`Event`]s it
+    /// produces, if iced cares about it.
+    fn translate(&mut self, event: &Self::Event<'_>) -> Vec<Event>;
+
+    /// Returns the [`Clipboard`] of the [`Host`].
+    fn clipboard(&mut self) -> &mut dyn Clipboard;
+
+    /// Runs a [`window::Action`] requested by a widget on the window of the
+    /// [`Host`].
+    fn run_window_action(&mut self, action: window::Action);
+
+    /// Changes the cursor of the window of the [`Host`] to match the given
+    /// [`mouse::Interaction`].
+    fn set_mouse_interaction(&mut self, interaction: mouse::Interaction);
+}
@@ -1,4 +1,10 @@
 //! Load and draw raster graphics.
+#[cfg(all(feature = "image_fetch", not(target_arch = "wasm32")))]
+pub mod fetch;
+
+#[cfg(all(feature = "image_fetch", not(target_arch = "wasm32")))]
+pub use fetch::fetch;
+
 use crate::{Hasher, Rectangle};
 
 use std::hash::{Hash, Hasher as _};
@@ -0,0 +1,155 @@
+//! Fetch images over HTTP.
+use crate::image::Handle;
+use crate::Command;
+
+use iced_futures::futures::channel::oneshot;
+use iced_futures::futures::StreamExt;
+use iced_futures::http;
+use iced_futures::progress::Progress;
+
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::{Arc, Mutex};
+
+/// Returns a [`Command`] that fetches the image at the given URL and
+/// produces its [`Handle`].
+///
+/// The image is decoded in a separate thread, so it can be drawn right away.
+pub fn fetch<Message>(
+    url: impl Into<String>,
+    f: impl Fn(Result<Handle, Error>) -> Message + 'static + Send,
+) -> Command<Message> {
+    Command::perform(load(url.into()), f)
+}
+
+/// A cache of the images fetched over HTTP, keyed by URL.
+///
+/// A [`Cache`] can be cloned cheaply; its clones share the same images.
+#[derive(Debug, Clone, Default)]
+pub struct Cache {
+    handles: Arc<Mutex<HashMap<String, Handle>>>,
+}
+
+impl Cache {
+    /// Creates an empty [`Cache`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns a [`Command`] that produces the [`Handle`] of the image at the
+    /// given URL.
+    ///
+    /// The image is only fetched if it is not in the [`Cache`] already.
+    pub fn fetch<Message>(
+        &self,
+        url: impl Into<String>,
+        f: impl Fn(Result<Handle, Error>) -> Message + 'static + Send,
+    ) -> Command<Message> {
+        let url = url.into();
+
+        if let Some(handle) = self.get(&url) {
+            return Command::perform(async move { Ok(handle) }, f);
+        }
+
+        let handles = self.handles.clone();
+
+        Command::perform(
+            async move {
+                let handle = load(url.clone()).await?;
+
+                let _ = handles
+                    .lock()
+                    .expect("Lock image cache")
+                    .insert(url, handle.clone());
+
+                Ok(handle)
+            },
+            f,
+        )
+    }
+
+    /// Returns the [`Handle`] of the image at the given URL, if it has
+    /// already been fetched.
+    pub fn get(&self, url: &str) -> Option<Handle> {
+        self.handles
+            .lock()
+            .expect("Lock image cache")
+            .get(url)
+            .cloned()
+    }
+
+    /// Removes the image at the given URL from the [`Cache`].
+    pub fn remove(&self, url: &str) {
+        let _ = self.handles.lock().expect("Lock image cache").remove(url);
+    }
+
+    /// Removes all the images from the [`Cache`].
+    pub fn clear(&self) {
+        self.handles.lock().expect("Lock image cache").clear();
+    }
+}
+
+async fn load(url: String) -> Result<Handle, Error> {
+    let mut download = http::download(url);
+
+    let bytes = loop {
+        match download.next().await {
+            Some(Progress::Finished(result)) => break result?,
+            Some(Progress::Advanced { .. }) => {}
+            None => return Err(Error::Interrupted),
+        }
+    };
+
+    let (sender, receiver) = oneshot::channel();
+
+    let _ = std::thread::spawn(move || {
+        let _ = sender.send(decode(&bytes));
+    });
+
+    receiver.await.map_err(|_| Error::Interrupted)?
+}
+
+fn decode(bytes: &[u8]) -> Result<Handle, Error> {
+    let image = image_rs::load_from_memory(bytes)
+        .map_err(|error| Error::Decoding(Arc::new(error)))?
+        .to_bgra8();
+
+    let (width, height) = image.dimensions();
+
+    Ok(Handle::from_pixels(width, height, image.into_raw()))
+}
+
+/// An error that occurred while fetching an image.
+#[derive(Debug, Clone)]
+pub enum Error {
+    /// The image could not be downloaded.
+    Http(http::Error),
+
+    /// The image could not be decoded.
+    Decoding(Arc<image_rs::ImageError>),
+
+    /// The image was dropped before finishing.
+    Interrupted,
+}
+
+impl From<http::Error> for Error {
+    fn from(error: http::Error) -> Self {
+        Error::Http(error)
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Http(error) => {
+                write!(f, "the image could not be downloaded: {}", error)
+            }
+            Error::Decoding(error) => {
+                write!(f, "the image could not be decoded: {}", error)
+            }
+            Error::Interrupted => write!(f, "the image fetch was interrupted"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
@@ -0,0 +1,45 @@
+//! Compose text with an input method editor.
+//!
+//! Input methods let users type text that cannot be entered with a single
+//! key press, like CJK characters. While composing, the text being typed is
+//! reported as a preedit string, which a focused widget displays in place
+//! until it is committed.
+//!
+//! A focused widget reports the position of its caret with
+//! [`Shell::request_input_method`], so the shell can place the candidate
+//! window of the input method next to it.
+//!
+//! _**Note:** The version of `winit` used by `iced_winit` does not report
+//! composition events, so input methods draw the composition themselves
+//! there and the committed text is received as characters. The SDL2 host of
+//! `iced_sdl2` reports them, and other shells can feed these events to a
+//! [`UserInterface`] directly._
+//!
+//! [`Shell::request_input_method`]: crate::Shell::request_input_method
+//! [`UserInterface`]: crate::UserInterface
+use std::ops::Range;
+
+/// An input method event.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Event {
+    /// The input method was enabled.
+    Opened,
+
+    /// The preedit string of the current composition changed.
+    ///
+    /// An empty string means the composition was cleared.
+    Preedit {
+        /// The text being composed.
+        content: String,
+
+        /// The byte range of the content that is selected by the input
+        /// method, if any.
+        selection: Option<Range<usize>>,
+    },
+
+    /// The composition finished, producing the given text.
+    Commit(String),
+
+    /// The input method was disabled.
+    Closed,
+}
@@ -0,0 +1,297 @@
+//! Localize an application using [Fluent].
+//!
+//! A [`Localization`] holds the translations of an application for a set of
+//! locales and formats its messages in the current one. Switching the
+//! locale of the [`Localization`] in the `update` logic of an application
+//! localizes its whole user interface the next time it is rebuilt.
+//!
+//! The [`Direction`] of the current locale can be returned from the
+//! `direction` method of an `Application` to mirror its user interface for
+//! right-to-left languages.
+//!
+//! [Fluent]: https://projectfluent.org
+use crate::event::{self, Event};
+use crate::subscription::{EventStream, Recipe};
+use crate::widget::Text;
+use crate::window;
+use crate::{text, Direction, Hasher, Subscription};
+
+use fluent_bundle::concurrent::FluentBundle;
+use fluent_bundle::FluentResource;
+use iced_futures::futures::channel::mpsc;
+use iced_futures::BoxStream;
+use std::fmt;
+use std::time::Duration;
+
+pub use fluent_bundle::{FluentArgs as Args, FluentValue as Value};
+pub use unic_langid::LanguageIdentifier as Locale;
+
+/// An error that occurred while loading some translations.
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum Error {
+    /// The locale is not a valid language identifier.
+    #[error("the locale {0:?} is invalid")]
+    InvalidLocale(String),
+
+    /// The source is not a valid Fluent resource.
+    #[error("the resource for {locale} is invalid: {errors:?}")]
+    InvalidResource {
+        /// The locale of the resource.
+        locale: Locale,
+        /// The syntax errors of the resource.
+        errors: Vec<String>,
+    },
+
+    /// The resource defines a message that was already defined.
+    #[error("the resource for {locale} overrides messages: {errors:?}")]
+    Overriding {
+        /// The locale of the resource.
+        locale: Locale,
+        /// The messages that were defined twice.
+        errors: Vec<String>,
+    },
+}
+
+/// The translations of an application, together with its current locale.
+pub struct Localization {
+    bundles: Vec<FluentBundle<FluentResource>>,
+    current: usize,
+}
+
+impl Localization {
+    /// Creates an empty [`Localization`].
+    ///
+    /// The first locale with any translations becomes the current locale and
+    /// the fallback of the rest.
+    pub fn new() -> Self {
+        Self {
+            bundles: Vec::new(),
+            current: 0,
+        }
+    }
+
+    /// Adds the translations in the given Fluent source to the given locale.
+    pub fn add_resource(
+        &mut self,
+        locale: &str,
+        source: impl Into<String>,
+    ) -> Result<(), Error> {
+        let locale = parse(locale)?;
+
+        let resource =
+            FluentResource::try_new(source.into()).map_err(|(_, errors)| {
+                Error::InvalidResource {
+                    locale: locale.clone(),
+                    errors: errors
+                        .iter()
+                        .map(|error| format!("{:?}", error.kind))
+                        .collect(),
+                }
+            })?;
+
+        let bundle = match self
+            .bundles
+            .iter()
+            .position(|bundle| bundle.locales[0] == locale)
+        {
+            Some(index) => &mut self.bundles[index],
+            None => {
+                let mut bundle =
+                    FluentBundle::new_concurrent(vec![locale.clone()]);
+
+                // Isolation marks are not supported by the text renderers
+                bundle.set_use_isolating(false);
+
+                self.bundles.push(bundle);
+                self.bundles.last_mut().expect("Get new bundle")
+            }
+        };
+
+        bundle
+            .add_resource(resource)
+            .map_err(|errors| Error::Overriding {
+                locale,
+                errors: errors.iter().map(ToString::to_string).collect(),
+            })
+    }
+
+    /// Adds the translations in the given Fluent source to the given locale,
+    /// returning the [`Localization`].
+    pub fn with_resource(
+        mut self,
+        locale: &str,
+        source: impl Into<String>,
+    ) -> Result<Self, Error> {
+        self.add_resource(locale, source)?;
+
+        Ok(self)
+    }
+
+    /// Returns the locales with translations in the [`Localization`].
+    pub fn locales(&self) -> impl Iterator<Item = &Locale> {
+        self.bundles.iter().map(|bundle| &bundle.locales[0])
+    }
+
+    /// Returns the current locale of the [`Localization`], if it has any
+    /// translations.
+    pub fn locale(&self) -> Option<&Locale> {
+        self.bundles
+            .get(self.current)
+            .map(|bundle| &bundle.locales[0])
+    }
+
+    /// Switches to the locale that best matches the given one, returning
+    /// whether the current locale changed.
+    ///
+    /// A locale without translations is matched by the first locale of the
+    /// same language, if any. Otherwise, the current locale is kept.
+    pub fn set_locale(&mut self, locale: &str) -> bool {
+        let locale = match parse(locale) {
+            Ok(locale) => locale,
+            Err(_) => return false,
+        };
+
+        let best = self
+            .bundles
+            .iter()
+            .position(|bundle| bundle.locales[0] == locale)
+            .or_else(|| {
+                self.bundles.iter().position(|bundle| {
+                    bundle.locales[0].language == locale.language
+                })
+            });
+
+        match best {
+            Some(index) if index != self.current => {
+                self.current = index;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns the [`Direction`] in which text flows in the current locale.
+    pub fn direction(&self) -> Direction {
+        use unic_langid::CharacterDirection;
+
+        match self.locale().map(Locale::character_direction) {
+            Some(CharacterDirection::RTL) => Direction::RightToLeft,
+            _ => Direction::LeftToRight,
+        }
+    }
+
+    /// Formats the message with the given key in the current locale, using
+    /// the given arguments.
+    ///
+    /// Messages missing in the current locale are formatted in the fallback
+    /// locale. If the message does not exist at all, the key is returned.
+    pub fn format(&self, key: &str, args: Option<&Args<'_>>) -> String {
+        let bundles = self
+            .bundles
+            .get(self.current)
+            .into_iter()
+            .chain(self.bundles.first());
+
+        for bundle in bundles {
+            let pattern = match bundle
+                .get_message(key)
+                .and_then(|message| message.value())
+            {
+                Some(pattern) => pattern,
+                None => continue,
+            };
+
+            // Fluent replaces the parts that cannot be formatted, like
+            // missing arguments, with their names
+            let mut errors = Vec::new();
+
+            return bundle
+                .format_pattern(pattern, args, &mut errors)
+                .into_owned();
+        }
+
+        String::from(key)
+    }
+}
+
+impl Default for Localization {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for Localization {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Localization")
+            .field("locales", &self.locales().collect::<Vec<_>>())
+            .field("locale", &self.locale())
+            .finish()
+    }
+}
+
+/// Creates a [`Text`] widget with the message with the given key, formatted
+/// in the current locale of the [`Localization`].
+pub fn text_l10n<Renderer>(
+    localization: &Localization,
+    key: &str,
+    args: Option<&Args<'_>>,
+) -> Text<Renderer>
+where
+    Renderer: text::Renderer,
+{
+    Text::new(localization.format(key, args))
+}
+
+/// Returns a [`Subscription`] that produces the locale preferred by the user
+/// of the operating system, once when started and again every time it
+/// changes.
+///
+/// Passing it to [`Localization::set_locale`] keeps the application in the
+/// language of the user. The locale is checked for changes every two
+/// seconds.
+pub fn system_locale() -> Subscription<String> {
+    Subscription::from_recipe(SystemLocale)
+}
+
+struct SystemLocale;
+
+impl Recipe<Hasher, (window::Id, Event, event::Status)> for SystemLocale {
+    type Output = String;
+
+    fn hash(&self, state: &mut Hasher) {
+        use std::hash::Hash;
+
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+    }
+
+    fn stream(self: Box<Self>, _input: EventStream) -> BoxStream<Self::Output> {
+        let (sender, receiver) = mpsc::unbounded();
+
+        let _ = std::thread::spawn(move || {
+            let mut last_locale = None;
+
+            while !sender.is_closed() {
+                let locale = sys_locale::get_locale();
+
+                if locale != last_locale {
+                    if let Some(locale) = &locale {
+                        let _ = sender.unbounded_send(locale.clone());
+                    }
+
+                    last_locale = locale;
+                }
+
+                std::thread::sleep(Duration::from_secs(2));
+            }
+        });
+
+        iced_futures::boxed_stream(receiver)
+    }
+}
+
+fn parse(locale: &str) -> Result<Locale, Error> {
+    locale
+        .parse()
+        .map_err(|_| Error::InvalidLocale(String::from(locale)))
+}
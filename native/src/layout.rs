@@ -2,18 +2,21 @@
 mod limits;
 mod node;
 
+pub(crate) mod cache;
+
 pub mod flex;
 
 pub use limits::Limits;
 pub use node::Node;
 
-use crate::{Point, Rectangle, Vector};
+use crate::{Direction, Point, Rectangle, Vector};
 
 /// The bounds of a [`Node`] and its children, using absolute coordinates.
 #[derive(Debug, Clone, Copy)]
 pub struct Layout<'a> {
     position: Point,
     node: &'a Node,
+    direction: Direction,
 }
 
 impl<'a> Layout<'a> {
@@ -30,6 +33,7 @@ impl<'a> Layout<'a> {
         Self {
             position: Point::new(bounds.x, bounds.y) + offset,
             node,
+            direction: node.direction().unwrap_or_default(),
         }
     }
 
@@ -38,6 +42,14 @@ impl<'a> Layout<'a> {
         self.position
     }
 
+    /// Returns the [`Direction`] of the [`Layout`].
+    ///
+    /// The [`Direction`] is inherited from the parent [`Layout`], unless the
+    /// [`Node`] overrides it.
+    pub fn direction(&self) -> Direction {
+        self.direction
+    }
+
     /// Returns the bounds of the [`Layout`].
     ///
     /// The returned [`Rectangle`] describes the position and size of a
@@ -55,8 +67,9 @@ impl<'a> Layout<'a> {
 
     /// Returns an iterator over the [`Layout`] of the children of a [`Node`].
     pub fn children(self) -> impl Iterator<Item = Layout<'a>> {
-        self.node.children().iter().map(move |node| {
-            Layout::with_offset(
+        self.node.children().iter().map(move |node| Layout {
+            direction: node.direction().unwrap_or(self.direction),
+            ..Layout::with_offset(
                 Vector::new(self.position.x, self.position.y),
                 node,
             )
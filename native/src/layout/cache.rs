@@ -0,0 +1,256 @@
+use crate::layout::{Limits, Node};
+use crate::Direction;
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+thread_local! {
+    static ACTIVE: RefCell<Option<Cache>> = const { RefCell::new(None) };
+}
+
+/// The layouts of the subtrees of a user interface, keyed by their layout hash
+/// and the [`Limits`] they were computed with.
+///
+/// Only the layouts needed by the last layout pass are kept around.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Cache {
+    previous: HashMap<Key, Node>,
+    current: HashMap<Key, Node>,
+}
+
+impl Cache {
+    /// Runs the given layout pass, reusing and recording the layouts of any
+    /// hashed subtree.
+    pub(crate) fn run<T>(&mut self, f: impl FnOnce() -> T) -> T {
+        let outer =
+            ACTIVE.with(|active| active.replace(Some(std::mem::take(self))));
+
+        let result = f();
+
+        let mut cache = ACTIVE
+            .with(|active| active.replace(outer))
+            .unwrap_or_default();
+
+        // Forget the layouts that were not needed by this pass
+        cache.previous = std::mem::take(&mut cache.current);
+        *self = cache;
+
+        result
+    }
+}
+
+/// Returns the cached layout of the subtree with the given hash and [`Limits`]
+/// or computes it, if a layout pass is running.
+pub(crate) fn fetch(
+    hash: u64,
+    limits: &Limits,
+    layout: impl FnOnce() -> Node,
+) -> Node {
+    let key = Key::new(hash, limits);
+
+    let cached = ACTIVE.with(|active| {
+        let mut active = active.borrow_mut();
+        let cache = active.as_mut()?;
+
+        if let Some(node) = cache.current.get(&key) {
+            return Some(Some(node.clone()));
+        }
+
+        let node = cache.previous.remove(&key);
+
+        if let Some(node) = &node {
+            let _ = cache.current.insert(key, node.clone());
+        }
+
+        Some(node)
+    });
+
+    match cached {
+        Some(Some(node)) => node,
+        Some(None) => {
+            let node = layout();
+
+            ACTIVE.with(|active| {
+                if let Some(cache) = active.borrow_mut().as_mut() {
+                    let _ = cache.current.insert(key, node.clone());
+                }
+            });
+
+            node
+        }
+        None => layout(),
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct Key {
+    hash: u64,
+    limits: [u32; 6],
+    direction: Direction,
+}
+
+impl Key {
+    fn new(hash: u64, limits: &Limits) -> Self {
+        let (min, max, fill) = (limits.min(), limits.max(), limits.fill());
+
+        Key {
+            hash,
+            limits: [
+                min.width.to_bits(),
+                min.height.to_bits(),
+                max.width.to_bits(),
+                max.height.to_bits(),
+                fill.width.to_bits(),
+                fill.height.to_bits(),
+            ],
+            direction: limits.direction(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::renderer::{self, Null};
+    use crate::{
+        Element, Hasher, Layout, Length, Point, Rectangle, Size, Widget,
+    };
+
+    use std::cell::Cell;
+    use std::hash::Hash;
+
+    fn limits(width: f32) -> Limits {
+        Limits::new(Size::ZERO, Size::new(width, 100.0))
+    }
+
+    fn fetch_counting(
+        computed: &Cell<usize>,
+        hash: u64,
+        limits: &Limits,
+    ) -> Node {
+        fetch(hash, limits, || {
+            computed.set(computed.get() + 1);
+
+            Node::new(limits.max())
+        })
+    }
+
+    #[test]
+    fn reuses_layouts_of_unchanged_subtrees() {
+        let mut cache = Cache::default();
+        let computed = Cell::new(0);
+
+        let first = cache.run(|| fetch_counting(&computed, 1, &limits(50.0)));
+        let second = cache.run(|| fetch_counting(&computed, 1, &limits(50.0)));
+
+        assert_eq!(computed.get(), 1);
+        assert_eq!(first.size(), second.size());
+    }
+
+    #[test]
+    fn computes_layouts_of_changed_subtrees() {
+        let mut cache = Cache::default();
+        let computed = Cell::new(0);
+
+        let _ = cache.run(|| fetch_counting(&computed, 1, &limits(50.0)));
+        let _ = cache.run(|| fetch_counting(&computed, 2, &limits(50.0)));
+
+        assert_eq!(computed.get(), 2);
+
+        let resized = cache.run(|| fetch_counting(&computed, 2, &limits(80.0)));
+
+        assert_eq!(computed.get(), 3);
+        assert_eq!(resized.size(), Size::new(80.0, 100.0));
+    }
+
+    #[test]
+    fn forgets_layouts_not_needed_by_the_last_pass() {
+        let mut cache = Cache::default();
+        let computed = Cell::new(0);
+
+        cache.run(|| {
+            let _ = fetch_counting(&computed, 1, &limits(50.0));
+            let _ = fetch_counting(&computed, 2, &limits(50.0));
+        });
+
+        let _ = cache.run(|| fetch_counting(&computed, 2, &limits(50.0)));
+        let _ = cache.run(|| fetch_counting(&computed, 1, &limits(50.0)));
+
+        assert_eq!(computed.get(), 3);
+    }
+
+    #[test]
+    fn computes_layouts_outside_of_a_pass() {
+        let computed = Cell::new(0);
+
+        let _ = fetch_counting(&computed, 1, &limits(50.0));
+        let _ = fetch_counting(&computed, 1, &limits(50.0));
+
+        assert_eq!(computed.get(), 2);
+    }
+
+    struct Counted<'a> {
+        hash: u64,
+        layouts: &'a Cell<usize>,
+    }
+
+    impl<'a> Widget<(), Null> for Counted<'a> {
+        fn width(&self) -> Length {
+            Length::Fill
+        }
+
+        fn height(&self) -> Length {
+            Length::Fill
+        }
+
+        fn layout(&self, _renderer: &Null, limits: &Limits) -> Node {
+            self.layouts.set(self.layouts.get() + 1);
+
+            Node::new(limits.max())
+        }
+
+        fn draw(
+            &self,
+            _renderer: &mut Null,
+            _style: &renderer::Style,
+            _layout: Layout<'_>,
+            _cursor_position: Point,
+            _viewport: &Rectangle,
+        ) {
+        }
+
+        fn hash_layout(&self, state: &mut Hasher) {
+            self.hash.hash(state);
+        }
+    }
+
+    #[test]
+    fn elements_reuse_layouts_once_hashed() {
+        let mut cache = Cache::default();
+        let layouts = Cell::new(0);
+
+        let mut layout = |hash, is_hashed| {
+            let element: Element<'_, (), Null> = Element::new(Counted {
+                hash,
+                layouts: &layouts,
+            });
+
+            if is_hashed {
+                element.hash_layout(&mut Hasher::default());
+            }
+
+            let _ = cache.run(|| element.layout(&Null::new(), &limits(50.0)));
+        };
+
+        layout(1, false);
+        layout(1, false);
+        assert_eq!(layouts.get(), 2);
+
+        layout(1, true);
+        layout(1, true);
+        assert_eq!(layouts.get(), 3);
+
+        layout(2, true);
+        assert_eq!(layouts.get(), 4);
+    }
+}
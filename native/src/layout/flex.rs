@@ -55,7 +55,16 @@ impl Axis {
 /// Computes the flex layout with the given axis and limits, applying spacing,
 /// padding and alignment to the items as needed.
 ///
+/// [`Alignment::Baseline`] aligns the baselines of the items of a horizontal
+/// layout, as reported by their [`Node`]. Items without a baseline are aligned
+/// by their bottom edge.
+///
+/// The items are mirrored horizontally if the [`Direction`] of the limits is
+/// right to left.
+///
 /// It returns a new layout [`Node`].
+///
+/// [`Direction`]: crate::Direction
 pub fn resolve<Message, Renderer>(
     axis: Axis,
     renderer: &Renderer,
@@ -93,7 +102,8 @@ where
                 let (max_width, max_height) = axis.pack(available, max_cross);
 
                 let child_limits =
-                    Limits::new(Size::ZERO, Size::new(max_width, max_height));
+                    Limits::new(Size::ZERO, Size::new(max_width, max_height))
+                        .with_direction(limits.direction());
 
                 let layout = child.layout(renderer, &child_limits);
                 let size = layout.size();
@@ -128,7 +138,8 @@ where
             let child_limits = Limits::new(
                 Size::new(min_width, min_height),
                 Size::new(max_width, max_height),
-            );
+            )
+            .with_direction(limits.direction());
 
             let layout = child.layout(renderer, &child_limits);
             let size = layout.size();
@@ -177,7 +188,8 @@ where
             let child_limits = Limits::new(
                 Size::new(min_width, min_height),
                 Size::new(max_width, max_height),
-            );
+            )
+            .with_direction(limits.direction());
 
             let layout = child.layout(renderer, &child_limits);
 
@@ -192,6 +204,16 @@ where
     let pad = axis.pack(padding.left as f32, padding.top as f32);
     let mut main = pad.0;
 
+    // Items without a baseline are aligned by their bottom edge
+    let ascent = |node: &Node| node.baseline().unwrap_or(node.size().height);
+
+    let max_ascent = match axis {
+        Axis::Horizontal if align_items == Alignment::Baseline => {
+            nodes.iter().map(ascent).fold(0.0, f32::max)
+        }
+        _ => 0.0,
+    };
+
     for (i, node) in nodes.iter_mut().enumerate() {
         if i > 0 {
             main += spacing;
@@ -202,6 +224,13 @@ where
         node.move_to(Point::new(x, y));
 
         match axis {
+            Axis::Horizontal if align_items == Alignment::Baseline => {
+                let offset = max_ascent - ascent(node);
+
+                node.move_to(Point::new(x, y + offset));
+
+                cross = cross.max(offset + node.size().height);
+            }
             Axis::Horizontal => {
                 node.align(
                     Alignment::Start,
@@ -226,5 +255,8 @@ where
     let (width, height) = axis.pack(main - pad.0, cross);
     let size = limits.resolve(Size::new(width, height));
 
-    Node::with_children(size.pad(padding), nodes)
+    let mut node = Node::with_children(size.pad(padding), nodes);
+    node.mirror(limits.direction());
+
+    node
 }
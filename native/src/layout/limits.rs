@@ -1,4 +1,4 @@
-use crate::{Length, Padding, Size};
+use crate::{Direction, Length, Padding, Size};
 
 /// A set of size constraints for layouting.
 #[derive(Debug, Clone, Copy)]
@@ -6,6 +6,7 @@ pub struct Limits {
     min: Size,
     max: Size,
     fill: Size,
+    direction: Direction,
 }
 
 impl Limits {
@@ -14,6 +15,7 @@ impl Limits {
         min: Size::ZERO,
         max: Size::INFINITY,
         fill: Size::INFINITY,
+        direction: Direction::LeftToRight,
     };
 
     /// Creates new [`Limits`] with the given minimum and maximum [`Size`].
@@ -22,6 +24,7 @@ impl Limits {
             min,
             max,
             fill: Size::INFINITY,
+            direction: Direction::LeftToRight,
         }
     }
 
@@ -40,6 +43,19 @@ impl Limits {
         self.fill
     }
 
+    /// Returns the [`Direction`] in which the content of the [`Limits`] should
+    /// flow.
+    pub fn direction(&self) -> Direction {
+        self.direction
+    }
+
+    /// Sets the [`Direction`] in which the content of the [`Limits`] should
+    /// flow.
+    pub fn with_direction(mut self, direction: Direction) -> Limits {
+        self.direction = direction;
+        self
+    }
+
     /// Applies a width constraint to the current [`Limits`].
     pub fn width(mut self, width: Length) -> Limits {
         match width {
@@ -141,7 +157,12 @@ impl Limits {
             (self.fill.height - size.height).max(0.0),
         );
 
-        Limits { min, max, fill }
+        Limits {
+            min,
+            max,
+            fill,
+            direction: self.direction,
+        }
     }
 
     /// Removes the minimum width constraint for the current [`Limits`].
@@ -150,6 +171,7 @@ impl Limits {
             min: Size::ZERO,
             max: self.max,
             fill: self.fill,
+            direction: self.direction,
         }
     }
 
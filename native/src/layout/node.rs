@@ -1,10 +1,12 @@
-use crate::{Alignment, Point, Rectangle, Size, Vector};
+use crate::{Alignment, Direction, Point, Rectangle, Size, Vector};
 
 /// The bounds of an element and its children.
 #[derive(Debug, Clone, Default)]
 pub struct Node {
     bounds: Rectangle,
     children: Vec<Node>,
+    direction: Option<Direction>,
+    baseline: Option<f32>,
 }
 
 impl Node {
@@ -23,9 +25,25 @@ impl Node {
                 height: size.height,
             },
             children,
+            direction: None,
+            baseline: None,
         }
     }
 
+    /// Sets the [`Direction`] of the [`Node`] and its children, overriding
+    /// the one of its parent.
+    pub fn with_direction(mut self, direction: Direction) -> Self {
+        self.direction = Some(direction);
+        self
+    }
+
+    /// Sets the baseline of the [`Node`]; that is, the distance from its top
+    /// to the baseline of its text.
+    pub fn with_baseline(mut self, baseline: f32) -> Self {
+        self.baseline = Some(baseline);
+        self
+    }
+
     /// Returns the [`Size`] of the [`Node`].
     pub fn size(&self) -> Size {
         Size::new(self.bounds.width, self.bounds.height)
@@ -41,6 +59,39 @@ impl Node {
         &self.children
     }
 
+    /// Returns the baseline of the [`Node`], relative to its top, if it
+    /// contains any text.
+    ///
+    /// If the [`Node`] does not have a baseline of its own, the baseline of its
+    /// first child with one is used.
+    pub fn baseline(&self) -> Option<f32> {
+        self.baseline.or_else(|| {
+            self.children.iter().find_map(|child| {
+                child.baseline().map(|baseline| child.bounds.y + baseline)
+            })
+        })
+    }
+
+    /// Returns the [`Direction`] of the [`Node`], if it overrides the one of
+    /// its parent.
+    pub fn direction(&self) -> Option<Direction> {
+        self.direction
+    }
+
+    /// Mirrors the horizontal position of the children of the [`Node`] if the
+    /// given [`Direction`] is [`Direction::RightToLeft`].
+    ///
+    /// This allows a widget to compute the layout of its children from left
+    /// to right, and then flip it to follow the [`Direction`] of its content.
+    pub fn mirror(&mut self, direction: Direction) {
+        if direction == Direction::RightToLeft {
+            for child in &mut self.children {
+                child.bounds.x =
+                    self.bounds.width - child.bounds.x - child.bounds.width;
+            }
+        }
+    }
+
     /// Aligns the [`Node`] in the given space.
     pub fn align(
         &mut self,
@@ -49,7 +100,7 @@ impl Node {
         space: Size,
     ) {
         match horizontal_alignment {
-            Alignment::Start => {}
+            Alignment::Start | Alignment::Baseline => {}
             Alignment::Center => {
                 self.bounds.x += (space.width - self.bounds.width) / 2.0;
             }
@@ -62,7 +113,7 @@ impl Node {
         }
 
         match vertical_alignment {
-            Alignment::Start => {}
+            Alignment::Start | Alignment::Baseline => {}
             Alignment::Center => {
                 self.bounds.y += (space.height - self.bounds.height) / 2.0;
             }
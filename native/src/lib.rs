@@ -35,21 +35,36 @@
 #![deny(missing_debug_implementations)]
 #![deny(unused_results)]
 #![forbid(unsafe_code)]
-#![forbid(rust_2018_idioms)]
+#![deny(rust_2018_idioms)]
+pub mod accessibility;
+pub mod animation;
 pub mod clipboard;
 pub mod command;
+pub mod dnd;
 pub mod event;
+pub mod history;
+pub mod host;
 pub mod image;
+pub mod input_method;
 pub mod keyboard;
+#[cfg(feature = "l10n")]
+pub mod l10n;
 pub mod layout;
+pub mod menu_bar;
 pub mod mouse;
 pub mod overlay;
+pub mod pen;
+#[cfg(feature = "persistence")]
+pub mod persistence;
 pub mod program;
 pub mod renderer;
+pub mod simulator;
 pub mod subscription;
 pub mod svg;
 pub mod text;
+pub mod theme;
 pub mod touch;
+pub mod tray;
 pub mod widget;
 pub mod window;
 
@@ -70,8 +85,8 @@ mod debug;
 
 pub use iced_core::alignment;
 pub use iced_core::{
-    Alignment, Background, Color, Font, Length, Padding, Point, Rectangle,
-    Size, Vector,
+    Alignment, Background, Color, Direction, Font, Length, Padding, Point,
+    Rectangle, Size, Vector,
 };
 pub use iced_futures::{executor, futures};
 
@@ -84,6 +99,7 @@ pub use debug::Debug;
 pub use element::Element;
 pub use event::Event;
 pub use hasher::Hasher;
+pub use host::Host;
 pub use layout::Layout;
 pub use overlay::Overlay;
 pub use program::Program;
@@ -91,5 +107,6 @@ pub use renderer::Renderer;
 pub use runtime::Runtime;
 pub use shell::Shell;
 pub use subscription::Subscription;
+pub use theme::Theme;
 pub use user_interface::{Cache, UserInterface};
 pub use widget::Widget;
@@ -0,0 +1,429 @@
+//! Declare the menu bar of an application, rendered by the operating system.
+//!
+//! The [`Shortcut`] of an [`Item`] is shown next to it and becomes part of
+//! the keyboard shortcuts of the application, so it works even where the
+//! menu bar cannot be shown.
+//!
+//! _**Note:** The operating system only shows the menu bar with the
+//! `menu_bar` feature on macOS, and on Linux desktops with a global menu
+//! running on X11, like KDE Plasma or Unity. On macOS, the first [`Menu`] is
+//! the application menu and its title is replaced by the name of the
+//! application. Everywhere else, including Wayland sessions, the menu bar is
+//! drawn at the top of the window instead, as a row of pick lists built with
+//! [`MenuBar::view`]._
+use crate::keyboard::{Shortcut, Shortcuts};
+use crate::text;
+use crate::widget::{pick_list, PickList, Row};
+use crate::{Element, Length};
+
+use std::fmt;
+use std::rc::Rc;
+
+/// The menu bar of an application.
+///
+/// Its menus identify the message of every [`Item`] by its index, so they
+/// can be sent to the operating system and compared cheaply.
+#[derive(Debug)]
+pub struct MenuBar<Message> {
+    menus: Vec<Menu<usize>>,
+    messages: Vec<Message>,
+    shortcuts: Shortcuts<Message>,
+    produce: fn(&Message) -> Message,
+}
+
+impl<Message> MenuBar<Message> {
+    /// Creates an empty [`MenuBar`].
+    pub fn new() -> Self {
+        Self {
+            menus: Vec::new(),
+            messages: Vec::new(),
+            shortcuts: Shortcuts::new(),
+            produce: |_| unreachable!("an empty menu bar has no messages"),
+        }
+    }
+
+    /// Adds a [`Menu`] to the [`MenuBar`].
+    pub fn menu(mut self, menu: Menu<Message>) -> Self
+    where
+        Message: Clone,
+    {
+        self.produce = Message::clone;
+
+        let menu = self.index(menu);
+        self.menus.push(menu);
+
+        self
+    }
+
+    /// Returns true if the [`MenuBar`] has no menus.
+    pub fn is_empty(&self) -> bool {
+        self.menus.is_empty()
+    }
+
+    /// Returns the menus of the [`MenuBar`], with the message of every
+    /// [`Item`] replaced by its index.
+    pub fn menus(&self) -> &[Menu<usize>] {
+        &self.menus
+    }
+
+    /// Produces the message with the given index, if any.
+    pub fn message(&self, index: usize) -> Option<Message> {
+        self.messages.get(index).map(self.produce)
+    }
+
+    /// Returns the [`Shortcuts`] of the items of the [`MenuBar`].
+    pub fn shortcuts(&self) -> Shortcuts<Message> {
+        self.shortcuts.clone()
+    }
+
+    /// Draws the [`MenuBar`] inside of a window, as a row of pick lists.
+    ///
+    /// Every [`Menu`] is flattened into a single list, and its disabled items
+    /// and separators are left out.
+    pub fn view<'a, Renderer>(
+        &self,
+        state: &'a mut State,
+    ) -> Element<'a, Message, Renderer>
+    where
+        Message: 'static,
+        Renderer: text::Renderer + 'a,
+    {
+        let messages: Rc<Vec<Message>> =
+            Rc::new(self.messages.iter().map(self.produce).collect());
+        let produce = self.produce;
+
+        state.menus.resize_with(self.menus.len(), Default::default);
+
+        self.menus
+            .iter()
+            .zip(state.menus.iter_mut())
+            .fold(
+                Row::new().spacing(5).padding(5).width(Length::Fill),
+                |row, (menu, state)| {
+                    let messages = messages.clone();
+
+                    row.push(
+                        PickList::new(
+                            state,
+                            entries(menu),
+                            None,
+                            move |entry: Entry| produce(&messages[entry.index]),
+                        )
+                        .placeholder(menu.title.clone()),
+                    )
+                },
+            )
+            .into()
+    }
+
+    fn index(&mut self, menu: Menu<Message>) -> Menu<usize>
+    where
+        Message: Clone,
+    {
+        Menu {
+            title: menu.title,
+            items: menu
+                .items
+                .into_iter()
+                .map(|item| match item {
+                    Item::Button {
+                        label,
+                        on_press,
+                        shortcut,
+                    } => Item::Button {
+                        label,
+                        on_press: on_press
+                            .map(|message| self.push(message, shortcut)),
+                        shortcut,
+                    },
+                    Item::Checkbox {
+                        label,
+                        is_checked,
+                        on_toggle,
+                        shortcut,
+                    } => Item::Checkbox {
+                        label,
+                        is_checked,
+                        on_toggle: on_toggle
+                            .map(|message| self.push(message, shortcut)),
+                        shortcut,
+                    },
+                    Item::Separator => Item::Separator,
+                    Item::Submenu(menu) => Item::Submenu(self.index(menu)),
+                })
+                .collect(),
+        }
+    }
+
+    fn push(&mut self, message: Message, shortcut: Option<Shortcut>) -> usize
+    where
+        Message: Clone,
+    {
+        if let Some(shortcut) = shortcut {
+            self.shortcuts = std::mem::take(&mut self.shortcuts)
+                .bind(shortcut, message.clone());
+        }
+
+        self.messages.push(message);
+        self.messages.len() - 1
+    }
+}
+
+impl<Message> Default for MenuBar<Message> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The state of a [`MenuBar`] drawn inside of a window.
+#[derive(Debug, Clone, Default)]
+pub struct State {
+    menus: Vec<pick_list::State<Entry>>,
+}
+
+impl State {
+    /// Creates a new [`State`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+/// An enabled item of a [`Menu`] drawn inside of a window.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Entry {
+    label: String,
+    index: usize,
+}
+
+impl fmt::Display for Entry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.label)
+    }
+}
+
+fn entries(menu: &Menu<usize>) -> Vec<Entry> {
+    let mut flattened = Vec::new();
+
+    for item in &menu.items {
+        let (label, index, shortcut) = match item {
+            Item::Button {
+                label,
+                on_press: Some(index),
+                shortcut,
+            } => (label.clone(), *index, shortcut),
+            Item::Checkbox {
+                label,
+                is_checked,
+                on_toggle: Some(index),
+                shortcut,
+            } => (
+                format!("[{}] {}", if *is_checked { "x" } else { " " }, label),
+                *index,
+                shortcut,
+            ),
+            Item::Submenu(submenu) => {
+                flattened.extend(entries(submenu).into_iter().map(|entry| {
+                    Entry {
+                        label: format!("{} > {}", submenu.title, entry.label),
+                        index: entry.index,
+                    }
+                }));
+
+                continue;
+            }
+            _ => continue,
+        };
+
+        let label = match shortcut {
+            Some(shortcut) => format!("{}  ({})", label, shortcut),
+            None => label,
+        };
+
+        flattened.push(Entry { label, index });
+    }
+
+    flattened
+}
+
+/// A menu with a title, in a [`MenuBar`] or nested in another [`Menu`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Menu<Message> {
+    /// The title of the [`Menu`]
+    pub title: String,
+    /// The items of the [`Menu`]
+    pub items: Vec<Item<Message>>,
+}
+
+impl<Message> Menu<Message> {
+    /// Creates a new [`Menu`] with the given title and items.
+    pub fn new(title: impl Into<String>, items: Vec<Item<Message>>) -> Self {
+        Self {
+            title: title.into(),
+            items,
+        }
+    }
+}
+
+/// An item of a [`Menu`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum Item<Message> {
+    /// A button that produces a message when pressed.
+    ///
+    /// It is disabled if it has no message.
+    Button {
+        /// The label of the button
+        label: String,
+        /// The message produced when the button is pressed
+        on_press: Option<Message>,
+        /// The [`Shortcut`] that presses the button
+        shortcut: Option<Shortcut>,
+    },
+    /// A button with a check mark that produces a message when toggled.
+    Checkbox {
+        /// The label of the checkbox
+        label: String,
+        /// Whether the checkbox is checked or not
+        is_checked: bool,
+        /// The message produced when the checkbox is toggled
+        on_toggle: Option<Message>,
+        /// The [`Shortcut`] that toggles the checkbox
+        shortcut: Option<Shortcut>,
+    },
+    /// A line separating groups of items.
+    Separator,
+    /// A nested [`Menu`].
+    Submenu(Menu<Message>),
+}
+
+impl<Message> Item<Message> {
+    /// Creates a button that produces the given message when pressed.
+    pub fn button(label: impl Into<String>, on_press: Message) -> Self {
+        Item::Button {
+            label: label.into(),
+            on_press: Some(on_press),
+            shortcut: None,
+        }
+    }
+
+    /// Creates a checkbox that produces the given message when toggled.
+    pub fn checkbox(
+        label: impl Into<String>,
+        is_checked: bool,
+        on_toggle: Message,
+    ) -> Self {
+        Item::Checkbox {
+            label: label.into(),
+            is_checked,
+            on_toggle: Some(on_toggle),
+            shortcut: None,
+        }
+    }
+
+    /// Creates a nested [`Menu`] with the given title and items.
+    pub fn submenu(
+        title: impl Into<String>,
+        items: Vec<Item<Message>>,
+    ) -> Self {
+        Item::Submenu(Menu::new(title, items))
+    }
+
+    /// Sets the [`Shortcut`] of a button or a checkbox.
+    pub fn shortcut(mut self, new_shortcut: Shortcut) -> Self {
+        match &mut self {
+            Item::Button { shortcut, .. } | Item::Checkbox { shortcut, .. } => {
+                *shortcut = Some(new_shortcut);
+            }
+            Item::Separator | Item::Submenu(_) => {}
+        }
+
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::keyboard::{KeyCode, Modifiers};
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    enum Message {
+        Open,
+        Save,
+        ToggleGrid,
+        Quit,
+    }
+
+    #[test]
+    fn indexes_the_messages_and_binds_the_shortcuts_of_items() {
+        let save = Shortcut::new(Modifiers::CTRL, KeyCode::S);
+
+        let menu_bar = MenuBar::new()
+            .menu(Menu::new(
+                "File",
+                vec![
+                    Item::button("Open", Message::Open),
+                    Item::button("Save", Message::Save).shortcut(save),
+                    Item::Separator,
+                    Item::submenu(
+                        "View",
+                        vec![Item::checkbox("Grid", true, Message::ToggleGrid)],
+                    ),
+                ],
+            ))
+            .menu(Menu::new(
+                "Application",
+                vec![Item::button("Quit", Message::Quit)],
+            ));
+
+        assert_eq!(
+            menu_bar.menus()[0].items[3],
+            Item::submenu("View", vec![Item::checkbox("Grid", true, 2)])
+        );
+        assert_eq!(menu_bar.menus()[1].items, vec![Item::button("Quit", 3)]);
+
+        assert_eq!(menu_bar.message(1), Some(Message::Save));
+        assert_eq!(menu_bar.message(3), Some(Message::Quit));
+        assert_eq!(menu_bar.message(4), None);
+
+        let shortcuts = menu_bar.shortcuts();
+
+        assert_eq!(
+            shortcuts.find(KeyCode::S, Modifiers::CTRL),
+            Some(Message::Save)
+        );
+        assert_eq!(shortcuts.shortcut(&Message::Open), None);
+    }
+
+    #[test]
+    fn flattens_the_enabled_items_of_menus_drawn_in_the_window() {
+        let save = Shortcut::new(Modifiers::CTRL, KeyCode::S);
+
+        let menu = Menu::new(
+            "File",
+            vec![
+                Item::Button {
+                    label: String::from("Open"),
+                    on_press: None,
+                    shortcut: None,
+                },
+                Item::button("Save", 0).shortcut(save),
+                Item::Separator,
+                Item::submenu("View", vec![Item::checkbox("Grid", true, 1)]),
+            ],
+        );
+
+        assert_eq!(
+            entries(&menu),
+            vec![
+                Entry {
+                    label: format!("Save  ({})", save),
+                    index: 0,
+                },
+                Entry {
+                    label: String::from("View > [x] Grid"),
+                    index: 1,
+                },
+            ]
+        );
+    }
+}
@@ -1,9 +1,13 @@
 //! Display interactive elements on top of other widgets.
 mod element;
+mod group;
 
 pub mod menu;
 
+pub(crate) mod nested;
+
 pub use element::Element;
+pub use group::Group;
 pub use menu::Menu;
 
 use crate::event::{self, Event};
@@ -87,4 +91,44 @@ where
     ) -> mouse::Interaction {
         mouse::Interaction::Idle
     }
+
+    /// Returns whether the [`Overlay`] is under the given cursor position.
+    ///
+    /// Overlays below it will not see the cursor. By default, it checks
+    /// the bounds of the [`Layout`].
+    fn is_over(&self, layout: Layout<'_>, cursor_position: Point) -> bool {
+        layout.bounds().contains(cursor_position)
+    }
+
+    /// Returns the nested overlay of the [`Overlay`], if there is any.
+    ///
+    /// A nested overlay is displayed on top of its parent and receives
+    /// events before it. For instance, a tooltip inside an open menu.
+    ///
+    /// By default, it returns `None`.
+    fn overlay(
+        &mut self,
+        _layout: Layout<'_>,
+    ) -> Option<Element<'_, Message, Renderer>> {
+        None
+    }
+}
+
+/// Combines the overlays of multiple children into a single [`Element`].
+///
+/// Overlays that come later are displayed on top of the previous ones.
+pub fn from_children<'a, Message, Renderer>(
+    children: impl IntoIterator<Item = Element<'a, Message, Renderer>>,
+) -> Option<Element<'a, Message, Renderer>>
+where
+    Message: 'a,
+    Renderer: crate::Renderer + 'a,
+{
+    let mut children: Vec<_> = children.into_iter().collect();
+
+    if children.len() > 1 {
+        Some(Group::with_children(children).overlay())
+    } else {
+        children.pop()
+    }
 }
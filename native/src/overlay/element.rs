@@ -9,8 +9,8 @@ use crate::{Clipboard, Hasher, Layout, Point, Rectangle, Shell, Size, Vector};
 /// A generic [`Overlay`].
 #[allow(missing_debug_implementations)]
 pub struct Element<'a, Message, Renderer> {
-    position: Point,
-    overlay: Box<dyn Overlay<Message, Renderer> + 'a>,
+    pub(super) position: Point,
+    pub(super) overlay: Box<dyn Overlay<Message, Renderer> + 'a>,
 }
 
 impl<'a, Message, Renderer> Element<'a, Message, Renderer>
@@ -100,6 +100,19 @@ where
     pub fn hash_layout(&self, state: &mut Hasher) {
         self.overlay.hash_layout(state, self.position);
     }
+
+    /// Returns whether the [`Element`] is under the given cursor position.
+    pub fn is_over(&self, layout: Layout<'_>, cursor_position: Point) -> bool {
+        self.overlay.is_over(layout, cursor_position)
+    }
+
+    /// Returns the nested overlay of the [`Element`], if there is any.
+    pub fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<Element<'_, Message, Renderer>> {
+        self.overlay.overlay(layout)
+    }
 }
 
 struct Map<'a, A, B, Renderer> {
@@ -178,4 +191,20 @@ where
     fn hash_layout(&self, state: &mut Hasher, position: Point) {
         self.content.hash_layout(state, position);
     }
+
+    fn is_over(&self, layout: Layout<'_>, cursor_position: Point) -> bool {
+        self.content.is_over(layout, cursor_position)
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<Element<'_, B, Renderer>> {
+        let mapper = self.mapper;
+
+        self.content.overlay(layout).map(|nested| Element {
+            position: nested.position,
+            overlay: Box::new(Map::new(nested.overlay, mapper)),
+        })
+    }
 }
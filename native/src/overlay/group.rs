@@ -0,0 +1,216 @@
+use crate::event::{self, Event};
+use crate::layout;
+use crate::mouse;
+use crate::overlay::{Element, Overlay};
+use crate::renderer;
+use crate::{Clipboard, Hasher, Layout, Point, Rectangle, Shell, Size};
+
+/// A collection of overlays displayed on top of each other.
+///
+/// Overlays that come later are displayed on top of the previous ones and
+/// receive events first.
+#[allow(missing_debug_implementations)]
+pub struct Group<'a, Message, Renderer> {
+    children: Vec<Element<'a, Message, Renderer>>,
+}
+
+impl<'a, Message, Renderer> Group<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: crate::Renderer + 'a,
+{
+    /// Creates an empty [`Group`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a [`Group`] with the given elements.
+    pub fn with_children(
+        children: Vec<Element<'a, Message, Renderer>>,
+    ) -> Self {
+        Group { children }
+    }
+
+    /// Adds an [`Element`] on top of the [`Group`].
+    pub fn push(mut self, child: Element<'a, Message, Renderer>) -> Self {
+        self.children.push(child);
+        self
+    }
+
+    /// Turns the [`Group`] into an overlay [`Element`].
+    pub fn overlay(self) -> Element<'a, Message, Renderer> {
+        Element::new(Point::ORIGIN, Box::new(self))
+    }
+}
+
+impl<'a, Message, Renderer> Default for Group<'a, Message, Renderer> {
+    fn default() -> Self {
+        Group {
+            children: Vec::new(),
+        }
+    }
+}
+
+impl<'a, Message, Renderer> Overlay<Message, Renderer>
+    for Group<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+{
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        bounds: Size,
+        position: Point,
+    ) -> layout::Node {
+        let translation = position - Point::ORIGIN;
+
+        layout::Node::with_children(
+            bounds,
+            self.children
+                .iter()
+                .map(|child| {
+                    child.overlay.layout(
+                        renderer,
+                        bounds,
+                        child.position + translation,
+                    )
+                })
+                .collect(),
+        )
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) {
+        let topmost = topmost(&self.children, layout, cursor_position);
+
+        for (i, (child, layout)) in
+            self.children.iter().zip(layout.children()).enumerate()
+        {
+            // Only the topmost overlay under the cursor can be hovered
+            let cursor_position = match topmost {
+                Some(topmost) if topmost > i => Point::new(-1.0, -1.0),
+                _ => cursor_position,
+            };
+
+            renderer.with_layer(layout.bounds(), |renderer| {
+                child.draw(renderer, style, layout, cursor_position);
+            });
+        }
+    }
+
+    fn hash_layout(&self, state: &mut Hasher, position: Point) {
+        use std::hash::Hash;
+
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        let translation = position - Point::ORIGIN;
+
+        for child in &self.children {
+            child
+                .overlay
+                .hash_layout(state, child.position + translation);
+        }
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let layouts: Vec<_> = layout.children().collect();
+        let mut is_covered = false;
+
+        // The topmost overlays are the first to process events, and the
+        // overlays below never see an event captured on top of them
+        for (child, layout) in self.children.iter_mut().zip(layouts).rev() {
+            let child_cursor = if is_covered {
+                Point::new(-1.0, -1.0)
+            } else {
+                cursor_position
+            };
+
+            is_covered = is_covered || child.is_over(layout, cursor_position);
+
+            let status = child.on_event(
+                event.clone(),
+                layout,
+                child_cursor,
+                renderer,
+                clipboard,
+                shell,
+            );
+
+            if status == event::Status::Captured {
+                return event::Status::Captured;
+            }
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) -> mouse::Interaction {
+        topmost(&self.children, layout, cursor_position)
+            .and_then(|i| {
+                let child = &self.children[i];
+                let layout = layout.children().nth(i)?;
+
+                Some(child.mouse_interaction(layout, cursor_position, viewport))
+            })
+            .unwrap_or_default()
+    }
+
+    fn is_over(&self, layout: Layout<'_>, cursor_position: Point) -> bool {
+        topmost(&self.children, layout, cursor_position).is_some()
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<Element<'_, Message, Renderer>> {
+        let children: Vec<_> = self
+            .children
+            .iter_mut()
+            .zip(layout.children())
+            .filter_map(|(child, layout)| child.overlay(layout))
+            .collect();
+
+        if children.is_empty() {
+            None
+        } else {
+            Some(Element::new(Point::ORIGIN, Box::new(Group { children })))
+        }
+    }
+}
+
+/// Returns the index of the topmost child under the cursor, if any.
+fn topmost<Message, Renderer>(
+    children: &[Element<'_, Message, Renderer>],
+    layout: Layout<'_>,
+    cursor_position: Point,
+) -> Option<usize>
+where
+    Renderer: crate::Renderer,
+{
+    children
+        .iter()
+        .zip(layout.children())
+        .enumerate()
+        .filter(|(_, (child, layout))| child.is_over(*layout, cursor_position))
+        .map(|(i, _)| i)
+        .last()
+}
@@ -1,11 +1,14 @@
 //! Build and show dropdown menus.
+use crate::accessibility;
 use crate::alignment;
 use crate::event::{self, Event};
+use crate::keyboard;
 use crate::layout;
 use crate::mouse;
 use crate::overlay;
 use crate::renderer;
 use crate::text::{self, Text};
+use crate::theme;
 use crate::touch;
 use crate::widget::scrollable::{self, Scrollable};
 use crate::widget::Container;
@@ -14,9 +17,19 @@ use crate::{
     Rectangle, Shell, Size, Vector, Widget,
 };
 
+use std::cell::Cell;
+use std::time::{Duration, Instant};
+
 pub use iced_style::menu::Style;
 
+/// A function that may produce the tooltip of an option.
+type TooltipFn<'a, T> = dyn Fn(&T) -> Option<String> + 'a;
+
 /// A list of selectable options.
+///
+/// The options of a [`Menu`] can be navigated with the arrow keys and the
+/// `Home` and `End` keys, or by typing the start of their labels. The
+/// `Enter` key selects the hovered option.
 #[allow(missing_debug_implementations)]
 pub struct Menu<'a, T, Renderer: text::Renderer> {
     state: &'a mut State,
@@ -27,7 +40,9 @@ pub struct Menu<'a, T, Renderer: text::Renderer> {
     padding: Padding,
     text_size: Option<u16>,
     font: Renderer::Font,
-    style: Style,
+    style: Option<Style>,
+    class: Option<&'a str>,
+    tooltip: Option<&'a TooltipFn<'a, T>>,
 }
 
 impl<'a, T, Renderer> Menu<'a, T, Renderer>
@@ -52,7 +67,9 @@ where
             padding: Padding::ZERO,
             text_size: None,
             font: Default::default(),
-            style: Default::default(),
+            style: None,
+            class: None,
+            tooltip: None,
         }
     }
 
@@ -81,8 +98,35 @@ where
     }
 
     /// Sets the style of the [`Menu`].
+    ///
+    /// By default, the [`Menu`] is styled by the current [`Theme`].
+    ///
+    /// [`Theme`]: crate::Theme
     pub fn style(mut self, style: impl Into<Style>) -> Self {
-        self.style = style.into();
+        self.style = Some(style.into());
+        self
+    }
+
+    /// Sets the style class of the [`Menu`].
+    ///
+    /// The class is resolved through the current [`Theme`] when the
+    /// [`Menu`] does not have a custom style.
+    ///
+    /// [`Theme`]: crate::Theme
+    pub fn class(mut self, class: &'a str) -> Self {
+        self.class = Some(class);
+        self
+    }
+
+    /// Sets the function that produces the tooltip of the hovered option of
+    /// the [`Menu`], if any.
+    ///
+    /// The tooltip is displayed next to the option, on top of the [`Menu`].
+    pub fn tooltip(
+        mut self,
+        tooltip: &'a dyn Fn(&T) -> Option<String>,
+    ) -> Self {
+        self.tooltip = Some(tooltip);
         self
     }
 
@@ -108,6 +152,8 @@ where
 #[derive(Debug, Clone, Default)]
 pub struct State {
     scrollable: scrollable::State,
+    type_ahead: TypeAhead,
+    revealed_option: Cell<Option<Rectangle>>,
 }
 
 impl State {
@@ -119,9 +165,11 @@ impl State {
 
 struct Overlay<'a, Message, Renderer: text::Renderer> {
     container: Container<'a, Message, Renderer>,
+    revealed_option: &'a Cell<Option<Rectangle>>,
     width: u16,
     target_height: f32,
-    style: Style,
+    style: Option<Style>,
+    class: Option<&'a str>,
 }
 
 impl<'a, Message, Renderer: text::Renderer> Overlay<'a, Message, Renderer>
@@ -143,25 +191,41 @@ where
             font,
             text_size,
             style,
+            class,
+            tooltip,
         } = menu;
 
+        let State {
+            scrollable,
+            type_ahead,
+            revealed_option,
+        } = state;
+
+        let revealed_option = &*revealed_option;
+
         let container =
-            Container::new(Scrollable::new(&mut state.scrollable).push(List {
+            Container::new(Scrollable::new(scrollable).push(List {
                 options,
                 hovered_option,
                 last_selection,
+                type_ahead,
+                revealed_option,
                 font,
                 text_size,
                 padding,
-                style: style.clone(),
+                style,
+                class,
+                tooltip,
             }))
             .padding(1);
 
         Self {
             container,
+            revealed_option,
             width: width,
             target_height,
             style: style,
+            class,
         }
     }
 }
@@ -210,8 +274,8 @@ where
         struct Marker;
         std::any::TypeId::of::<Marker>().hash(state);
 
-        (position.x as u32).hash(state);
-        (position.y as u32).hash(state);
+        position.x.to_bits().hash(state);
+        position.y.to_bits().hash(state);
         self.container.hash_layout(state);
     }
 
@@ -224,14 +288,31 @@ where
         clipboard: &mut dyn Clipboard,
         shell: &mut Shell<'_, Message>,
     ) -> event::Status {
-        self.container.on_event(
+        let event_status = self.container.on_event(
             event.clone(),
             layout,
             cursor_position,
             renderer,
             clipboard,
             shell,
-        )
+        );
+
+        // Options hovered with the keyboard may be out of view
+        if let Some(target) = self.revealed_option.take() {
+            let _ = self.container.on_event(
+                Event::Accessibility(accessibility::Request {
+                    target,
+                    action: accessibility::Action::ScrollIntoView,
+                }),
+                layout,
+                cursor_position,
+                renderer,
+                clipboard,
+                shell,
+            );
+        }
+
+        event_status
     }
 
     fn mouse_interaction(
@@ -252,30 +333,66 @@ where
         cursor_position: Point,
     ) {
         let bounds = layout.bounds();
+        let menu_style = self.style.unwrap_or_else(|| {
+            Style::from(&style.theme.resolve(theme::Widget::Menu, self.class))
+        });
 
         renderer.fill_quad(
             renderer::Quad {
                 bounds,
-                border_color: self.style.border_color,
-                border_width: self.style.border_width,
+                border_color: menu_style.border_color,
+                border_width: menu_style.border_width,
                 border_radius: 0.0,
             },
-            self.style.background,
+            menu_style.background,
         );
 
         self.container
             .draw(renderer, style, layout, cursor_position, &bounds);
     }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        let bounds = layout.bounds();
+
+        self.container.overlay(layout, &bounds)
+    }
 }
 
 struct List<'a, T, Renderer: text::Renderer> {
     options: &'a [T],
     hovered_option: &'a mut Option<usize>,
     last_selection: &'a mut Option<T>,
+    type_ahead: &'a mut TypeAhead,
+    revealed_option: &'a Cell<Option<Rectangle>>,
     padding: Padding,
     text_size: Option<u16>,
     font: Renderer::Font,
-    style: Style,
+    style: Option<Style>,
+    class: Option<&'a str>,
+    tooltip: Option<&'a TooltipFn<'a, T>>,
+}
+
+impl<'a, T, Renderer> List<'a, T, Renderer>
+where
+    T: ToString,
+    Renderer: text::Renderer,
+{
+    /// Hovers the option with the given index, scrolling it into view.
+    fn hover(&mut self, index: usize, bounds: Rectangle, renderer: &Renderer) {
+        let text_size = self.text_size.unwrap_or(renderer.default_size());
+        let option_height = f32::from(text_size + self.padding.vertical());
+
+        *self.hovered_option = Some(index);
+
+        self.revealed_option.set(Some(Rectangle {
+            y: bounds.y + option_height * index as f32,
+            height: option_height,
+            ..bounds
+        }));
+    }
 }
 
 impl<'a, T, Message, Renderer> Widget<Message, Renderer>
@@ -381,6 +498,54 @@ where
                     }
                 }
             }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code, ..
+            }) => {
+                let last = match self.options.len().checked_sub(1) {
+                    Some(last) => last,
+                    None => return event::Status::Ignored,
+                };
+
+                let hovered = self.hovered_option.map(|index| index.min(last));
+
+                let index = match key_code {
+                    keyboard::KeyCode::Up => {
+                        hovered.map_or(last, |index| index.saturating_sub(1))
+                    }
+                    keyboard::KeyCode::Down => {
+                        hovered.map_or(0, |index| (index + 1).min(last))
+                    }
+                    keyboard::KeyCode::Home => 0,
+                    keyboard::KeyCode::End => last,
+                    keyboard::KeyCode::Enter
+                    | keyboard::KeyCode::NumpadEnter => {
+                        if let Some(index) = hovered {
+                            *self.last_selection =
+                                Some(self.options[index].clone());
+                        }
+
+                        return event::Status::Captured;
+                    }
+                    _ => return event::Status::Ignored,
+                };
+
+                self.hover(index, layout.bounds(), renderer);
+
+                return event::Status::Captured;
+            }
+            Event::Keyboard(keyboard::Event::CharacterReceived(c))
+                if !c.is_control() =>
+            {
+                let hovered = *self.hovered_option;
+
+                if let Some(index) =
+                    self.type_ahead.find(c, self.options, hovered)
+                {
+                    self.hover(index, layout.bounds(), renderer);
+                }
+
+                return event::Status::Captured;
+            }
             _ => {}
         }
 
@@ -405,12 +570,15 @@ where
     fn draw(
         &self,
         renderer: &mut Renderer,
-        _style: &renderer::Style,
+        style: &renderer::Style,
         layout: Layout<'_>,
         _cursor_position: Point,
         viewport: &Rectangle,
     ) {
         let bounds = layout.bounds();
+        let menu_style = self.style.unwrap_or_else(|| {
+            Style::from(&style.theme.resolve(theme::Widget::Menu, self.class))
+        });
 
         let text_size = self.text_size.unwrap_or(renderer.default_size());
         let option_height = (text_size + self.padding.vertical()) as usize;
@@ -441,7 +609,7 @@ where
                         border_width: 0.0,
                         border_radius: 0.0,
                     },
-                    self.style.selected_background,
+                    menu_style.selected_background,
                 );
             }
 
@@ -456,15 +624,220 @@ where
                 size: f32::from(text_size),
                 font: self.font.clone(),
                 color: if is_selected {
-                    self.style.selected_text_color
+                    menu_style.selected_text_color
                 } else {
-                    self.style.text_color
+                    menu_style.text_color
                 },
                 horizontal_alignment: alignment::Horizontal::Left,
                 vertical_alignment: alignment::Vertical::Center,
             });
         }
     }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+        viewport: &Rectangle,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        let tooltip = self.tooltip?;
+        let index = (*self.hovered_option)?;
+        let content = tooltip(self.options.get(index)?)?;
+
+        let bounds = layout.bounds();
+        let option_height = bounds.height / self.options.len() as f32;
+
+        let option = Rectangle {
+            y: bounds.y + option_height * index as f32,
+            height: option_height,
+            ..bounds
+        };
+
+        // The tooltip of an option scrolled out of view is hidden
+        let _ = option.intersection(viewport)?;
+
+        Some(overlay::Element::new(
+            option.position(),
+            Box::new(Tooltip {
+                content,
+                target: option.size(),
+                padding: self.padding,
+                text_size: self.text_size,
+                font: self.font.clone(),
+                style: self.style,
+                class: self.class,
+            }),
+        ))
+    }
+}
+
+/// The tooltip of the hovered option of a [`Menu`].
+struct Tooltip<'a, Renderer: text::Renderer> {
+    content: String,
+    target: Size,
+    padding: Padding,
+    text_size: Option<u16>,
+    font: Renderer::Font,
+    style: Option<Style>,
+    class: Option<&'a str>,
+}
+
+impl<'a, Message, Renderer> crate::Overlay<Message, Renderer>
+    for Tooltip<'a, Renderer>
+where
+    Renderer: text::Renderer,
+{
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        bounds: Size,
+        position: Point,
+    ) -> layout::Node {
+        let text_size = self.text_size.unwrap_or(renderer.default_size());
+
+        let (width, height) = renderer.measure(
+            &self.content,
+            text_size,
+            self.font.clone(),
+            Size::INFINITY,
+        );
+
+        let size = Size::new(
+            width + f32::from(self.padding.horizontal()),
+            height + f32::from(self.padding.vertical()),
+        );
+
+        // The tooltip is displayed after the option, or before it when there
+        // is not enough space
+        let x = if position.x + self.target.width + size.width <= bounds.width {
+            position.x + self.target.width
+        } else {
+            (position.x - size.width).max(0.0)
+        };
+
+        let y = position.y.min(bounds.height - size.height).max(0.0);
+
+        let mut node = layout::Node::new(size);
+        node.move_to(Point::new(x, y));
+
+        node
+    }
+
+    fn hash_layout(&self, state: &mut Hasher, position: Point) {
+        use std::hash::Hash;
+
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        position.x.to_bits().hash(state);
+        position.y.to_bits().hash(state);
+        self.target.width.to_bits().hash(state);
+        self.content.hash(state);
+        self.text_size.hash(state);
+        self.padding.hash(state);
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor_position: Point,
+    ) {
+        let bounds = layout.bounds();
+        let menu_style = self.style.unwrap_or_else(|| {
+            Style::from(&style.theme.resolve(theme::Widget::Menu, self.class))
+        });
+
+        let text_size = self.text_size.unwrap_or(renderer.default_size());
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border_color: menu_style.border_color,
+                border_width: menu_style.border_width,
+                border_radius: 0.0,
+            },
+            menu_style.background,
+        );
+
+        renderer.fill_text(Text {
+            content: &self.content,
+            bounds: Rectangle {
+                x: bounds.x + f32::from(self.padding.left),
+                y: bounds.y + f32::from(self.padding.top),
+                ..bounds
+            },
+            size: f32::from(text_size),
+            font: self.font.clone(),
+            color: menu_style.text_color,
+            horizontal_alignment: alignment::Horizontal::Left,
+            vertical_alignment: alignment::Vertical::Top,
+        });
+    }
+
+    fn is_over(&self, _layout: Layout<'_>, _cursor_position: Point) -> bool {
+        // A tooltip never takes the cursor from the options below it
+        false
+    }
+}
+
+/// The characters typed in a [`Menu`] to find an option.
+#[derive(Debug, Clone, Default)]
+struct TypeAhead {
+    query: String,
+    typed_at: Option<Instant>,
+}
+
+impl TypeAhead {
+    /// The time after which a new query starts.
+    const TIMEOUT: Duration = Duration::from_secs(1);
+
+    /// Adds a character to the query, returning the index of the first
+    /// option after the hovered one whose label starts with it.
+    ///
+    /// Typing the same character repeatedly cycles through the options that
+    /// start with it.
+    fn find<T: ToString>(
+        &mut self,
+        c: char,
+        options: &[T],
+        hovered: Option<usize>,
+    ) -> Option<usize> {
+        let now = Instant::now();
+
+        let is_expired = match self.typed_at {
+            Some(typed_at) => now - typed_at > Self::TIMEOUT,
+            None => true,
+        };
+
+        if is_expired {
+            self.query.clear();
+        }
+
+        self.typed_at = Some(now);
+        self.query.extend(c.to_lowercase());
+
+        let mut chars = self.query.chars();
+        let first = chars.next()?;
+
+        // A longer query keeps the hovered option if it still matches
+        let (prefix, skip) = if chars.all(|c| c == first) {
+            (first.to_string(), 1)
+        } else {
+            (self.query.clone(), 0)
+        };
+
+        let start = hovered.map_or(0, |index| index + skip);
+
+        (0..options.len())
+            .map(|i| (start + i) % options.len())
+            .find(|&index| {
+                options[index]
+                    .to_string()
+                    .to_lowercase()
+                    .starts_with(&prefix)
+            })
+    }
 }
 
 impl<'a, T, Message, Renderer> Into<Element<'a, Message, Renderer>>
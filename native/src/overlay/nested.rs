@@ -0,0 +1,434 @@
+use crate::event::{self, Event};
+use crate::layout;
+use crate::mouse;
+use crate::overlay;
+use crate::renderer;
+use crate::{Clipboard, Hasher, Layout, Point, Rectangle, Shell, Size};
+
+use std::hash::Hash;
+
+/// An overlay [`Element`] together with the overlays nested inside of it.
+///
+/// The layout of a [`Nested`] overlay has the layout of the [`Element`] as
+/// its first child and, if present, the layout of the nested overlays as its
+/// second child.
+///
+/// [`Element`]: overlay::Element
+#[allow(missing_debug_implementations)]
+pub(crate) struct Nested<'a, Message, Renderer> {
+    overlay: overlay::Element<'a, Message, Renderer>,
+}
+
+impl<'a, Message, Renderer> Nested<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+{
+    /// Creates a [`Nested`] overlay from the given [`Element`].
+    ///
+    /// [`Element`]: overlay::Element
+    pub fn new(overlay: overlay::Element<'a, Message, Renderer>) -> Self {
+        Self { overlay }
+    }
+
+    /// Computes the layout of the overlay and its nested overlays.
+    pub fn layout(
+        &mut self,
+        renderer: &Renderer,
+        bounds: Size,
+    ) -> layout::Node {
+        fn recurse<Message, Renderer>(
+            element: &mut overlay::Element<'_, Message, Renderer>,
+            renderer: &Renderer,
+            bounds: Size,
+        ) -> layout::Node
+        where
+            Renderer: crate::Renderer,
+        {
+            let node = element.layout(renderer, bounds);
+
+            let nested = element
+                .overlay(Layout::new(&node))
+                .map(|mut nested| recurse(&mut nested, renderer, bounds));
+
+            layout::Node::with_children(
+                bounds,
+                std::iter::once(node).chain(nested).collect(),
+            )
+        }
+
+        recurse(&mut self.overlay, renderer, bounds)
+    }
+
+    /// Computes the _layout_ hash of the overlay and its nested overlays.
+    ///
+    /// Nested overlays are obtained using the given cached layout, if any.
+    pub fn hash_layout(
+        &mut self,
+        state: &mut Hasher,
+        cached: Option<Layout<'_>>,
+    ) {
+        fn recurse<Message, Renderer>(
+            element: &mut overlay::Element<'_, Message, Renderer>,
+            state: &mut Hasher,
+            layout: Option<Layout<'_>>,
+        ) where
+            Renderer: crate::Renderer,
+        {
+            element.hash_layout(state);
+
+            let (own, nested) = split(layout);
+
+            if let Some(own) = own {
+                match element.overlay(own) {
+                    Some(mut overlay) => {
+                        true.hash(state);
+                        recurse(&mut overlay, state, nested);
+                    }
+                    None => false.hash(state),
+                }
+            }
+        }
+
+        recurse(&mut self.overlay, state, cached)
+    }
+
+    /// Draws the overlay and its nested overlays on top of it.
+    pub fn draw(
+        &mut self,
+        renderer: &mut Renderer,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) {
+        fn recurse<Message, Renderer>(
+            element: &mut overlay::Element<'_, Message, Renderer>,
+            layout: Layout<'_>,
+            renderer: &mut Renderer,
+            style: &renderer::Style,
+            cursor_position: Point,
+        ) where
+            Renderer: crate::Renderer,
+        {
+            let (own, nested) = split(Some(layout));
+
+            let own = match own {
+                Some(own) => own,
+                None => return,
+            };
+
+            let is_covered = match (element.overlay(own), nested) {
+                (Some(mut overlay), Some(nested)) => {
+                    is_over(&mut overlay, nested, cursor_position)
+                }
+                _ => false,
+            };
+
+            renderer.with_layer(own.bounds(), |renderer| {
+                element.draw(
+                    renderer,
+                    style,
+                    own,
+                    if is_covered {
+                        Point::new(-1.0, -1.0)
+                    } else {
+                        cursor_position
+                    },
+                );
+            });
+
+            if let (Some(mut overlay), Some(nested)) =
+                (element.overlay(own), nested)
+            {
+                recurse(&mut overlay, nested, renderer, style, cursor_position);
+            }
+        }
+
+        recurse(&mut self.overlay, layout, renderer, style, cursor_position)
+    }
+
+    /// Processes a runtime [`Event`], giving the nested overlays the chance
+    /// to handle it first.
+    ///
+    /// Once an overlay captures the [`Event`], the overlays below it do not
+    /// receive it.
+    pub fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        fn recurse<Message, Renderer>(
+            element: &mut overlay::Element<'_, Message, Renderer>,
+            event: Event,
+            layout: Layout<'_>,
+            cursor_position: Point,
+            renderer: &Renderer,
+            clipboard: &mut dyn Clipboard,
+            shell: &mut Shell<'_, Message>,
+        ) -> (event::Status, bool)
+        where
+            Renderer: crate::Renderer,
+        {
+            let (own, nested) = split(Some(layout));
+
+            let own = match own {
+                Some(own) => own,
+                None => return (event::Status::Ignored, false),
+            };
+
+            let (nested_status, is_covered) =
+                match (element.overlay(own), nested) {
+                    (Some(mut overlay), Some(nested)) => recurse(
+                        &mut overlay,
+                        event.clone(),
+                        nested,
+                        cursor_position,
+                        renderer,
+                        clipboard,
+                        shell,
+                    ),
+                    _ => (event::Status::Ignored, false),
+                };
+
+            // The overlays below never see an event captured on top of them
+            if nested_status == event::Status::Captured {
+                return (event::Status::Captured, true);
+            }
+
+            let status = element.on_event(
+                event,
+                own,
+                if is_covered {
+                    Point::new(-1.0, -1.0)
+                } else {
+                    cursor_position
+                },
+                renderer,
+                clipboard,
+                shell,
+            );
+
+            (status, is_covered || element.is_over(own, cursor_position))
+        }
+
+        let (status, _) = recurse(
+            &mut self.overlay,
+            event,
+            layout,
+            cursor_position,
+            renderer,
+            clipboard,
+            shell,
+        );
+
+        status
+    }
+
+    /// Returns the [`mouse::Interaction`] of the topmost overlay under the
+    /// cursor, if any.
+    pub fn mouse_interaction(
+        &mut self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) -> Option<mouse::Interaction> {
+        fn recurse<Message, Renderer>(
+            element: &mut overlay::Element<'_, Message, Renderer>,
+            layout: Layout<'_>,
+            cursor_position: Point,
+            viewport: &Rectangle,
+        ) -> Option<mouse::Interaction>
+        where
+            Renderer: crate::Renderer,
+        {
+            let (own, nested) = split(Some(layout));
+            let own = own?;
+
+            let nested_interaction = match (element.overlay(own), nested) {
+                (Some(mut overlay), Some(nested)) => {
+                    recurse(&mut overlay, nested, cursor_position, viewport)
+                }
+                _ => None,
+            };
+
+            nested_interaction.or_else(|| {
+                if element.is_over(own, cursor_position) {
+                    Some(element.mouse_interaction(
+                        own,
+                        cursor_position,
+                        viewport,
+                    ))
+                } else {
+                    None
+                }
+            })
+        }
+
+        recurse(&mut self.overlay, layout, cursor_position, viewport)
+    }
+
+    /// Returns whether the overlay or any of its nested overlays is under the
+    /// given cursor position.
+    pub fn is_over(
+        &mut self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> bool {
+        is_over(&mut self.overlay, layout, cursor_position)
+    }
+}
+
+fn is_over<Message, Renderer>(
+    element: &mut overlay::Element<'_, Message, Renderer>,
+    layout: Layout<'_>,
+    cursor_position: Point,
+) -> bool
+where
+    Renderer: crate::Renderer,
+{
+    let (own, nested) = split(Some(layout));
+
+    let own = match own {
+        Some(own) => own,
+        None => return false,
+    };
+
+    element.is_over(own, cursor_position)
+        || match (element.overlay(own), nested) {
+            (Some(mut overlay), Some(nested)) => {
+                is_over(&mut overlay, nested, cursor_position)
+            }
+            _ => false,
+        }
+}
+
+/// Splits the layout of a [`Nested`] overlay into the layout of the overlay
+/// itself and the layout of its nested overlays.
+fn split(
+    layout: Option<Layout<'_>>,
+) -> (Option<Layout<'_>>, Option<Layout<'_>>) {
+    let mut children = layout.into_iter().flat_map(Layout::children);
+
+    (children.next(), children.next())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::renderer::Null;
+
+    use std::cell::Cell;
+
+    /// An overlay that fills its bounds, counts the events it receives and
+    /// may have another [`Probe`] nested inside of it.
+    struct Probe<'a> {
+        events: &'a Cell<usize>,
+        status: event::Status,
+        nested: Option<(&'a Cell<usize>, event::Status)>,
+    }
+
+    impl<'a> Probe<'a> {
+        fn overlay(self) -> overlay::Element<'a, (), Null> {
+            overlay::Element::new(Point::ORIGIN, Box::new(self))
+        }
+    }
+
+    impl<'a> overlay::Overlay<(), Null> for Probe<'a> {
+        fn layout(
+            &self,
+            _renderer: &Null,
+            bounds: Size,
+            position: Point,
+        ) -> layout::Node {
+            let mut node = layout::Node::new(bounds);
+            node.move_to(position);
+
+            node
+        }
+
+        fn draw(
+            &self,
+            _renderer: &mut Null,
+            _style: &renderer::Style,
+            _layout: Layout<'_>,
+            _cursor_position: Point,
+        ) {
+        }
+
+        fn hash_layout(&self, _state: &mut Hasher, _position: Point) {}
+
+        fn on_event(
+            &mut self,
+            _event: Event,
+            _layout: Layout<'_>,
+            _cursor_position: Point,
+            _renderer: &Null,
+            _clipboard: &mut dyn Clipboard,
+            _shell: &mut Shell<'_, ()>,
+        ) -> event::Status {
+            self.events.set(self.events.get() + 1);
+
+            self.status
+        }
+
+        fn overlay(
+            &mut self,
+            _layout: Layout<'_>,
+        ) -> Option<overlay::Element<'_, (), Null>> {
+            self.nested.map(|(events, status)| {
+                Probe {
+                    events,
+                    status,
+                    nested: None,
+                }
+                .overlay()
+            })
+        }
+    }
+
+    fn press(upper: event::Status) -> (usize, usize, event::Status) {
+        let (lower_events, upper_events) = (Cell::new(0), Cell::new(0));
+
+        let mut nested = Nested::new(
+            Probe {
+                events: &lower_events,
+                status: event::Status::Ignored,
+                nested: Some((&upper_events, upper)),
+            }
+            .overlay(),
+        );
+
+        let layout = nested.layout(&Null, Size::new(100.0, 100.0));
+        let mut messages = Vec::new();
+
+        let status = nested.on_event(
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+            Layout::new(&layout),
+            Point::new(50.0, 50.0),
+            &Null,
+            &mut crate::clipboard::Null,
+            &mut Shell::new(&mut messages),
+        );
+
+        (lower_events.get(), upper_events.get(), status)
+    }
+
+    #[test]
+    fn events_captured_on_top_do_not_reach_the_overlays_below() {
+        assert_eq!(
+            press(event::Status::Captured),
+            (0, 1, event::Status::Captured)
+        );
+    }
+
+    #[test]
+    fn events_ignored_on_top_reach_the_overlays_below() {
+        assert_eq!(
+            press(event::Status::Ignored),
+            (1, 1, event::Status::Ignored)
+        );
+    }
+}
@@ -0,0 +1,83 @@
+//! Handle pen and stylus input.
+use crate::Point;
+
+/// A pen interaction.
+///
+/// A pen touching a screen also produces touch events, so widgets that do
+/// not care about pressure or tilt keep working with a pen.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Event {
+    /// The pen touched the surface.
+    Pressed(Sample),
+
+    /// The pen moved while touching the surface.
+    Moved(Sample),
+
+    /// The pen was lifted from the surface.
+    Lifted(Sample),
+
+    /// The pen interaction was canceled.
+    Lost,
+
+    /// A button of the pen was pressed.
+    ButtonPressed(Button),
+
+    /// A button of the pen was released.
+    ButtonReleased(Button),
+}
+
+/// The state of a pen at some point of an interaction.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Sample {
+    /// The position of the tip of the pen.
+    pub position: Point,
+
+    /// The pressure applied by the pen, from `0.0` to `1.0`.
+    pub pressure: f32,
+
+    /// The [`Tilt`] of the pen, if the device reports it.
+    pub tilt: Option<Tilt>,
+
+    /// The [`Tool`] touching the surface.
+    pub tool: Tool,
+}
+
+/// The orientation of a pen relative to the surface.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Tilt {
+    /// The angle between the pen and the surface, in radians.
+    ///
+    /// It is `0.0` when the pen is parallel to the surface and `π / 2` when
+    /// it is perpendicular to it.
+    pub altitude: f32,
+
+    /// The direction the pen is pointing at on the surface, in radians, if
+    /// the device reports it.
+    ///
+    /// It is `0.0` when the pen points to the right, and it grows
+    /// clockwise.
+    pub azimuth: Option<f32>,
+}
+
+/// The end of the pen touching the surface.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Tool {
+    /// The tip of the pen.
+    Pen,
+
+    /// The eraser at the back of the pen.
+    Eraser,
+}
+
+/// A button of a pen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Button {
+    /// The button closest to the tip.
+    Primary,
+
+    /// The button furthest from the tip.
+    Secondary,
+
+    /// Some other button.
+    Other(u8),
+}
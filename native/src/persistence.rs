@@ -0,0 +1,272 @@
+//! Save and restore the state of an application between runs.
+//!
+//! A [`Snapshot`] records the geometry of a window, the split ratios of some
+//! [`PaneGrid`] layouts and the offsets of some [`Scrollable`] widgets, each
+//! one under a key chosen by the application. It implements `serde`'s
+//! `Serialize` and `Deserialize`, so it can be written to any [`Store`] in
+//! any format.
+//!
+//! [`PaneGrid`]: crate::widget::PaneGrid
+//! [`Scrollable`]: crate::widget::Scrollable
+use crate::command::{self, Command};
+use crate::widget::pane_grid::{self, Axis};
+use crate::widget::scrollable;
+use crate::window;
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// A place where a [`Snapshot`] can be saved to and loaded from.
+///
+/// The [`Store`] decides how a [`Snapshot`] is serialized: a JSON file in the
+/// configuration directory of the user, an entry in a database, etc.
+pub trait Store: Send + 'static {
+    /// The type of error produced when saving or loading fails.
+    type Error: Send + 'static;
+
+    /// Saves the given [`Snapshot`], replacing the previous one.
+    fn save(&mut self, snapshot: &Snapshot) -> Result<(), Self::Error>;
+
+    /// Loads the last saved [`Snapshot`], if any.
+    fn load(&mut self) -> Result<Option<Snapshot>, Self::Error>;
+}
+
+/// Returns a [`Command`] that saves the [`Snapshot`] to the given [`Store`]
+/// and produces a message with the result.
+pub fn save<S, Message>(
+    mut store: S,
+    snapshot: Snapshot,
+    f: impl Fn(Result<(), S::Error>) -> Message + 'static + Send,
+) -> Command<Message>
+where
+    S: Store,
+{
+    Command::perform(async move { store.save(&snapshot) }, f)
+}
+
+/// Returns a [`Command`] that loads a [`Snapshot`] from the given [`Store`]
+/// and produces a message with the result.
+pub fn load<S, Message>(
+    mut store: S,
+    f: impl Fn(Result<Option<Snapshot>, S::Error>) -> Message + 'static + Send,
+) -> Command<Message>
+where
+    S: Store,
+{
+    Command::perform(async move { store.load() }, f)
+}
+
+/// The persisted state of an application.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct Snapshot {
+    /// The geometry of the window of the application.
+    #[serde(default)]
+    pub window: Window,
+
+    #[serde(default)]
+    pane_grids: BTreeMap<String, Layout>,
+
+    #[serde(default)]
+    scrollables: BTreeMap<String, Scroll>,
+}
+
+impl Snapshot {
+    /// Creates an empty [`Snapshot`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records the split ratios of the [`pane_grid::State`] under the given
+    /// key.
+    pub fn record_pane_grid<T>(
+        &mut self,
+        key: impl Into<String>,
+        state: &pane_grid::State<T>,
+    ) {
+        let _ = self
+            .pane_grids
+            .insert(key.into(), Layout::from(state.layout()));
+    }
+
+    /// Restores the split ratios recorded under the given key into the
+    /// [`pane_grid::State`].
+    ///
+    /// Only the splits that have the same position and [`Axis`] in both
+    /// layouts are resized. Returns whether any ratio was recorded under the
+    /// key.
+    pub fn restore_pane_grid<T>(
+        &self,
+        key: &str,
+        state: &mut pane_grid::State<T>,
+    ) -> bool {
+        let layout = match self.pane_grids.get(key) {
+            Some(layout) => layout,
+            None => return false,
+        };
+
+        let mut ratios = Vec::new();
+        layout.ratios(state.layout(), &mut ratios);
+
+        for (split, ratio) in ratios {
+            state.resize(&split, ratio);
+        }
+
+        true
+    }
+
+    /// Records the scroll position of the [`scrollable::State`] under the
+    /// given key.
+    pub fn record_scrollable(
+        &mut self,
+        key: impl Into<String>,
+        state: &scrollable::State,
+    ) {
+        let _ = self.scrollables.insert(key.into(), state.snapshot());
+    }
+
+    /// Restores the scroll position recorded under the given key into the
+    /// [`scrollable::State`].
+    ///
+    /// Returns whether any scroll position was recorded under the key.
+    pub fn restore_scrollable(
+        &self,
+        key: &str,
+        state: &mut scrollable::State,
+    ) -> bool {
+        match self.scrollables.get(key) {
+            Some(scroll) => {
+                state.restore(*scroll);
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+/// The geometry of a window, in logical pixels.
+///
+/// Keep it up to date by calling [`Window::update`] with every
+/// [`window::Event`] of the application.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct Window {
+    /// The size of the window, if known.
+    pub size: Option<(u32, u32)>,
+
+    /// The position of the window, if known.
+    ///
+    /// Some platforms, like Wayland, never report it.
+    pub position: Option<(i32, i32)>,
+}
+
+impl Window {
+    /// Updates the [`Window`] with the given [`window::Event`].
+    pub fn update(&mut self, event: &window::Event) {
+        match *event {
+            window::Event::Resized { width, height } => {
+                self.size = Some((width, height));
+            }
+            window::Event::Moved { x, y } => {
+                self.position = Some((x, y));
+            }
+            _ => {}
+        }
+    }
+
+    /// Applies the known geometry of the [`Window`] to the [`window::Settings`]
+    /// used to create a window.
+    pub fn apply(&self, settings: &mut window::Settings) {
+        if let Some(size) = self.size {
+            settings.size = size;
+        }
+
+        if let Some((x, y)) = self.position {
+            settings.position = window::Position::Specific(x, y);
+        }
+    }
+
+    /// Returns a [`Command`] that resizes and moves an open window to the
+    /// known geometry of the [`Window`].
+    pub fn restore<Message>(&self) -> Command<Message> {
+        let resize = self.size.map(|(width, height)| {
+            Command::single(command::Action::Window(window::Action::Resize {
+                width,
+                height,
+            }))
+        });
+
+        let move_to = self.position.map(|(x, y)| {
+            Command::single(command::Action::Window(window::Action::Move {
+                x,
+                y,
+            }))
+        });
+
+        Command::batch(resize.into_iter().chain(move_to))
+    }
+}
+
+/// The scroll position of a [`Scrollable`] along both of its axes.
+///
+/// [`Scrollable`]: crate::widget::Scrollable
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub(crate) struct Scroll {
+    pub(crate) x: scrollable::Offset,
+    pub(crate) y: scrollable::Offset,
+}
+
+/// The split ratios of a [`pane_grid::Node`], together with its structure.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+enum Layout {
+    Split {
+        axis: Axis,
+        ratio: f32,
+        a: Box<Layout>,
+        b: Box<Layout>,
+    },
+    Pane,
+}
+
+impl Layout {
+    /// Collects the ratio of every [`pane_grid::Split`] of the given node that
+    /// matches a split of the [`Layout`].
+    fn ratios(
+        &self,
+        node: &pane_grid::Node,
+        ratios: &mut Vec<(pane_grid::Split, f32)>,
+    ) {
+        if let (
+            Layout::Split { axis, ratio, a, b },
+            pane_grid::Node::Split {
+                id,
+                axis: node_axis,
+                a: node_a,
+                b: node_b,
+                ..
+            },
+        ) = (self, node)
+        {
+            if axis == node_axis {
+                ratios.push((*id, *ratio));
+
+                a.ratios(node_a, ratios);
+                b.ratios(node_b, ratios);
+            }
+        }
+    }
+}
+
+impl From<&pane_grid::Node> for Layout {
+    fn from(node: &pane_grid::Node) -> Self {
+        match node {
+            pane_grid::Node::Split {
+                axis, ratio, a, b, ..
+            } => Layout::Split {
+                axis: *axis,
+                ratio: *ratio,
+                a: Box::new(Layout::from(a.as_ref())),
+                b: Box::new(Layout::from(b.as_ref())),
+            },
+            pane_grid::Node::Pane(_) => Layout::Pane,
+        }
+    }
+}
@@ -1,6 +1,8 @@
 use crate::mouse;
+use crate::renderer;
+use crate::window;
 use crate::{
-    Cache, Clipboard, Command, Debug, Event, Point, Program, Size,
+    Cache, Clipboard, Command, Debug, Event, Host, Point, Program, Size,
     UserInterface,
 };
 
@@ -16,6 +18,7 @@ where
     queued_events: Vec<Event>,
     queued_messages: Vec<P::Message>,
     mouse_interaction: mouse::Interaction,
+    window_actions: Vec<window::Action>,
 }
 
 impl<P> State<P>
@@ -46,6 +49,7 @@ where
             queued_events: Vec::new(),
             queued_messages: Vec::new(),
             mouse_interaction: mouse::Interaction::Idle,
+            window_actions: Vec::new(),
         }
     }
 
@@ -78,6 +82,46 @@ where
         self.mouse_interaction
     }
 
+    /// Translates a native event of the given [`Host`] and queues the events
+    /// it produces, if iced cares about it.
+    pub fn queue_host_event<H: Host>(
+        &mut self,
+        host: &mut H,
+        event: &H::Event<'_>,
+    ) {
+        self.queued_events.extend(host.translate(event));
+    }
+
+    /// Processes all the queued events and messages like [`State::update`],
+    /// using the clipboard of the given [`Host`].
+    ///
+    /// Afterwards, the window actions requested by the widgets are run on
+    /// the [`Host`] and the cursor of its window is updated.
+    pub fn process<H: Host>(
+        &mut self,
+        host: &mut H,
+        bounds: Size,
+        cursor_position: Point,
+        renderer: &mut P::Renderer,
+        debug: &mut Debug,
+    ) -> Option<Command<P::Message>> {
+        let command = self.update(
+            bounds,
+            cursor_position,
+            renderer,
+            host.clipboard(),
+            debug,
+        );
+
+        for action in self.window_actions.drain(..) {
+            host.run_window_action(action);
+        }
+
+        host.set_mouse_interaction(self.mouse_interaction);
+
+        command
+    }
+
     /// Processes all the queued events and messages, rebuilding and redrawing
     /// the widgets of the linked [`Program`] if necessary.
     ///
@@ -110,14 +154,19 @@ where
             &mut messages,
         );
 
+        self.window_actions = user_interface.take_window_actions();
+
         messages.extend(self.queued_messages.drain(..));
         self.queued_events.clear();
         debug.event_processing_finished();
 
         if messages.is_empty() {
             debug.draw_started();
-            self.mouse_interaction =
-                user_interface.draw(renderer, cursor_position);
+            self.mouse_interaction = user_interface.draw(
+                renderer,
+                &renderer::Style::default(),
+                cursor_position,
+            );
             debug.draw_finished();
 
             self.cache = Some(user_interface.into_cache());
@@ -148,8 +197,11 @@ where
             );
 
             debug.draw_started();
-            self.mouse_interaction =
-                user_interface.draw(renderer, cursor_position);
+            self.mouse_interaction = user_interface.draw(
+                renderer,
+                &renderer::Style::default(),
+                cursor_position,
+            );
             debug.draw_finished();
 
             self.cache = Some(user_interface.into_cache());
@@ -176,3 +228,98 @@ fn build_user_interface<'a, P: Program>(
 
     user_interface
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clipboard;
+    use crate::renderer::Null;
+    use crate::widget::{Space, WindowHandle};
+    use crate::{Element, Length};
+
+    /// A program that drags the window when it is pressed anywhere.
+    struct TitleBar;
+
+    impl Program for TitleBar {
+        type Renderer = Null;
+        type Message = ();
+
+        fn update(&mut self, _message: ()) -> Command<()> {
+            Command::none()
+        }
+
+        fn view(&mut self) -> Element<'_, (), Null> {
+            WindowHandle::new(Space::new(Length::Fill, Length::Fill)).into()
+        }
+    }
+
+    /// A host that queues its events as is and records what happens to its
+    /// window.
+    struct Recorder {
+        clipboard: clipboard::Null,
+        actions: Vec<window::Action>,
+        interaction: Option<mouse::Interaction>,
+    }
+
+    impl Host for Recorder {
+        type Event<'a> = Event;
+
+        fn translate(&mut self, event: &Event) -> Vec<Event> {
+            vec![event.clone()]
+        }
+
+        fn clipboard(&mut self) -> &mut dyn Clipboard {
+            &mut self.clipboard
+        }
+
+        fn run_window_action(&mut self, action: window::Action) {
+            self.actions.push(action);
+        }
+
+        fn set_mouse_interaction(&mut self, interaction: mouse::Interaction) {
+            self.interaction = Some(interaction);
+        }
+    }
+
+    #[test]
+    fn runs_the_window_actions_of_the_widgets_on_the_host() {
+        let bounds = Size::new(100.0, 100.0);
+        let mut renderer = Null;
+        let mut debug = Debug::new();
+
+        let mut state = State::new(TitleBar, bounds, &mut renderer, &mut debug);
+
+        let mut host = Recorder {
+            clipboard: clipboard::Null,
+            actions: Vec::new(),
+            interaction: None,
+        };
+
+        state.queue_host_event(
+            &mut host,
+            &Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+        );
+
+        let _ = state.process(
+            &mut host,
+            bounds,
+            Point::new(50.0, 50.0),
+            &mut renderer,
+            &mut debug,
+        );
+
+        assert!(matches!(host.actions.as_slice(), [window::Action::Drag]));
+        assert_eq!(host.interaction, Some(mouse::Interaction::Idle));
+
+        // The actions are only run once
+        let _ = state.process(
+            &mut host,
+            bounds,
+            Point::new(50.0, 50.0),
+            &mut renderer,
+            &mut debug,
+        );
+
+        assert_eq!(host.actions.len(), 1);
+    }
+}
@@ -19,13 +19,16 @@
 //! [`text::Renderer`]: crate::widget::text::Renderer
 //! [`Checkbox`]: crate::widget::Checkbox
 //! [`checkbox::Renderer`]: crate::widget::checkbox::Renderer
+mod cache;
 #[cfg(debug_assertions)]
 mod null;
+
+pub use cache::Cache;
 #[cfg(debug_assertions)]
 pub use null::Null;
 
 use crate::layout;
-use crate::{Background, Color, Element, Rectangle, Vector};
+use crate::{Background, Color, Element, Point, Rectangle, Theme, Vector};
 
 /// A component that can take the state of a user interface and produce an
 /// output for its users.
@@ -54,6 +57,33 @@ pub trait Renderer: Sized {
         f: impl FnOnce(&mut Self),
     );
 
+    /// Multiplies the opacity of the primitives recorded in the given closure
+    /// by the given factor, between `0.0` and `1.0`.
+    fn with_opacity(&mut self, opacity: f32, f: impl FnOnce(&mut Self));
+
+    /// Scales the primitives recorded in the given closure by the given
+    /// factor, around the given `origin`.
+    fn with_scale(
+        &mut self,
+        origin: Point,
+        scale: f32,
+        f: impl FnOnce(&mut Self),
+    );
+
+    /// Draws the primitives recorded in the given closure, reusing the ones
+    /// stored in the [`Cache`] instead if they were recorded with the same
+    /// `key`.
+    ///
+    /// By default, the closure is always run and nothing is cached.
+    fn with_cache(
+        &mut self,
+        _cache: &Cache,
+        _key: u64,
+        f: impl FnOnce(&mut Self),
+    ) {
+        f(self)
+    }
+
     /// Clears all of the recorded primitives in the [`Renderer`].
     fn clear(&mut self);
 
@@ -78,16 +108,27 @@ pub struct Quad {
 }
 
 /// The styling attributes of a [`Renderer`].
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Style {
     /// The text color
     pub text_color: Color,
+
+    /// The [`Theme`] used to style the widgets that do not have a custom
+    /// style sheet.
+    pub theme: Theme,
 }
 
 impl Default for Style {
     fn default() -> Self {
+        Style::from(Theme::default())
+    }
+}
+
+impl From<Theme> for Style {
+    fn from(theme: Theme) -> Self {
         Style {
-            text_color: Color::BLACK,
+            text_color: theme.palette().text,
+            theme,
         }
     }
 }
@@ -0,0 +1,60 @@
+use std::any::Any;
+use std::cell::RefCell;
+use std::fmt;
+
+/// A storage for the primitives recorded by a [`Renderer`], so they can be
+/// drawn again without running the drawing logic that produced them.
+///
+/// The contents of a [`Cache`] are opaque to widgets and are only understood
+/// by the [`Renderer`] that stored them.
+///
+/// [`Renderer`]: crate::Renderer
+#[derive(Default)]
+pub struct Cache {
+    entry: RefCell<Option<Entry>>,
+}
+
+struct Entry {
+    key: u64,
+    recording: Box<dyn Any>,
+}
+
+impl Cache {
+    /// Creates a new empty [`Cache`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the recording stored in the [`Cache`] for the given key, if
+    /// any.
+    pub fn get<T: Clone + 'static>(&self, key: u64) -> Option<T> {
+        self.entry
+            .borrow()
+            .as_ref()
+            .filter(|entry| entry.key == key)
+            .and_then(|entry| entry.recording.downcast_ref::<T>())
+            .cloned()
+    }
+
+    /// Stores a recording in the [`Cache`] for the given key, replacing the
+    /// previous one.
+    pub fn store<T: 'static>(&self, key: u64, recording: T) {
+        *self.entry.borrow_mut() = Some(Entry {
+            key,
+            recording: Box::new(recording),
+        });
+    }
+
+    /// Removes the recording stored in the [`Cache`], if any.
+    pub fn clear(&self) {
+        *self.entry.borrow_mut() = None;
+    }
+}
+
+impl fmt::Debug for Cache {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Cache")
+            .field("key", &self.entry.borrow().as_ref().map(|entry| entry.key))
+            .finish()
+    }
+}
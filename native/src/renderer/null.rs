@@ -5,7 +5,7 @@ use crate::{Background, Font, Point, Rectangle, Size, Vector};
 /// A renderer that does nothing.
 ///
 /// It can be useful if you are writing tests!
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, Default)]
 pub struct Null;
 
 impl Null {
@@ -25,6 +25,16 @@ impl Renderer for Null {
     ) {
     }
 
+    fn with_opacity(&mut self, _opacity: f32, _f: impl FnOnce(&mut Self)) {}
+
+    fn with_scale(
+        &mut self,
+        _origin: Point,
+        _scale: f32,
+        _f: impl FnOnce(&mut Self),
+    ) {
+    }
+
     fn clear(&mut self) {}
 
     fn fill_quad(
@@ -56,6 +66,10 @@ impl text::Renderer for Null {
         (0.0, 20.0)
     }
 
+    fn baseline(&self, size: u16, _font: Font) -> f32 {
+        f32::from(size)
+    }
+
     fn hit_test(
         &self,
         _contents: &str,
@@ -1,13 +1,25 @@
+use crate::dnd;
+use crate::window;
+use crate::Rectangle;
+
 /// A connection to the state of a shell.
 ///
 /// A [`Widget`] can leverage a [`Shell`] to trigger changes in an application,
-/// like publishing messages or invalidating the current layout.
+/// like publishing messages, invalidating the current layout or asking for the
+/// widgets to be rebuilt.
 ///
 /// [`Widget`]: crate::Widget
 #[derive(Debug)]
 pub struct Shell<'a, Message> {
     messages: &'a mut Vec<Message>,
     is_layout_invalid: bool,
+    are_widgets_invalid: bool,
+    drag: Option<dnd::Payload>,
+    window_actions: Vec<window::Action>,
+    redraw_request: Option<window::RedrawRequest>,
+    input_method: Option<Rectangle>,
+    raw_events: bool,
+    pointer_lock: bool,
 }
 
 impl<'a, Message> Shell<'a, Message> {
@@ -16,6 +28,13 @@ impl<'a, Message> Shell<'a, Message> {
         Self {
             messages,
             is_layout_invalid: false,
+            are_widgets_invalid: false,
+            drag: None,
+            window_actions: Vec::new(),
+            redraw_request: None,
+            input_method: None,
+            raw_events: false,
+            pointer_lock: false,
         }
     }
 
@@ -29,6 +48,16 @@ impl<'a, Message> Shell<'a, Message> {
         }
     }
 
+    /// Triggers the given function if the widgets are invalid, cleaning them in
+    /// the process.
+    pub fn with_invalid_widgets(&mut self, f: impl FnOnce()) {
+        if self.are_widgets_invalid {
+            self.are_widgets_invalid = false;
+
+            f()
+        }
+    }
+
     /// Publish the given `Message` for an application to process it.
     pub fn publish(&mut self, message: Message) {
         self.messages.push(message);
@@ -41,14 +70,197 @@ impl<'a, Message> Shell<'a, Message> {
         self.is_layout_invalid = true;
     }
 
+    /// Invalidates the current application widgets.
+    ///
+    /// The shell will rebuild the widgets of the application by calling its
+    /// `view` logic again, even if no messages were produced.
+    pub fn invalidate_widgets(&mut self) {
+        self.are_widgets_invalid = true;
+    }
+
+    /// Starts dragging the given [`dnd::Payload`].
+    ///
+    /// Widgets will be notified of the progress of the drag with
+    /// [`dnd::Event`]s until the payload is dropped.
+    pub fn start_drag(&mut self, payload: dnd::Payload) {
+        self.drag = Some(payload);
+    }
+
+    /// Takes the [`dnd::Payload`] of the drag started while processing the
+    /// current event, if any.
+    pub fn take_drag(&mut self) -> Option<dnd::Payload> {
+        self.drag.take()
+    }
+
+    /// Requests the given [`window::Action`] to be performed on the window
+    /// of the application.
+    ///
+    /// This is useful for widgets that control the window directly, like a
+    /// custom title bar that moves it.
+    ///
+    /// The actions are performed in the order they were requested.
+    pub fn request_window_action(&mut self, action: window::Action) {
+        self.window_actions.push(action);
+    }
+
+    /// Takes the [`window::Action`]s requested while processing the current
+    /// event.
+    pub fn take_window_actions(&mut self) -> Vec<window::Action> {
+        std::mem::take(&mut self.window_actions)
+    }
+
+    /// Requests a redraw of the window with the given [`window::RedrawRequest`].
+    ///
+    /// Only the earliest request is kept. When it is due, widgets receive a
+    /// [`window::Event::RedrawRequested`] event.
+    pub fn request_redraw(&mut self, request: window::RedrawRequest) {
+        self.redraw_request = Some(match self.redraw_request {
+            Some(current) => current.min(request),
+            None => request,
+        });
+    }
+
+    /// Returns the earliest [`window::RedrawRequest`] made by the widgets, if
+    /// any.
+    pub fn redraw_request(&self) -> Option<window::RedrawRequest> {
+        self.redraw_request
+    }
+
+    /// Enables the input method, placing its candidate window next to the
+    /// given caret bounds.
+    ///
+    /// A focused widget that accepts text should call this method while
+    /// processing every event, since the input method is disabled when no
+    /// widget requests it.
+    pub fn request_input_method(&mut self, caret: Rectangle) {
+        self.input_method = Some(caret);
+    }
+
+    /// Returns the caret bounds of the input method requested by the
+    /// widgets, if any.
+    pub fn input_method(&self) -> Option<Rectangle> {
+        self.input_method
+    }
+
+    /// Asks the shell to stop coalescing movement events, so every position
+    /// reported by the mouse, fingers, or pens is delivered.
+    ///
+    /// Like [`Shell::request_input_method`], a widget that needs this precision
+    /// (e.g. a drawing canvas) should call this method while processing every
+    /// event, since movements are coalesced again when no widget requests it.
+    pub fn request_raw_events(&mut self) {
+        self.raw_events = true;
+    }
+
+    /// Returns whether a widget asked for raw movement events.
+    pub fn wants_raw_events(&self) -> bool {
+        self.raw_events
+    }
+
+    /// Locks the pointer, hiding the cursor and keeping it in place.
+    ///
+    /// While locked, the movement of the mouse is delivered as
+    /// [`mouse::Event::Motion`] deltas instead of absolute positions. This
+    /// is useful for controls that track unbounded movement, like orbiting
+    /// a 3D viewport or scrubbing a value.
+    ///
+    /// Like [`Shell::request_input_method`], a widget should call this method
+    /// while processing every event, since the pointer is released when no
+    /// widget requests it.
+    ///
+    /// [`mouse::Event::Motion`]: crate::mouse::Event::Motion
+    pub fn request_pointer_lock(&mut self) {
+        self.pointer_lock = true;
+    }
+
+    /// Returns whether a widget asked for the pointer to be locked.
+    pub fn wants_pointer_lock(&self) -> bool {
+        self.pointer_lock
+    }
+
     /// Merges the current [`Shell`] with another one by applying the given
     /// function to the messages of the latter.
     ///
     /// This method is useful for composition.
-    pub fn merge<B>(&mut self, other: Shell<'_, B>, f: impl Fn(B) -> Message) {
+    pub fn merge<B>(
+        &mut self,
+        mut other: Shell<'_, B>,
+        f: impl Fn(B) -> Message,
+    ) {
         self.messages.extend(other.messages.drain(..).map(f));
 
+        self.forward(&mut other);
+    }
+
+    /// Forwards everything but the messages of another [`Shell`] to the
+    /// current one.
+    ///
+    /// This includes the invalidations, the started drag, the window actions
+    /// and every request made by the widgets of the other [`Shell`].
+    ///
+    /// A widget that handles the messages of its children by itself (e.g. a
+    /// component) should call this method, so the children can still start
+    /// drags or lock the pointer.
+    pub fn forward<B>(&mut self, other: &mut Shell<'_, B>) {
         self.is_layout_invalid =
             self.is_layout_invalid || other.is_layout_invalid;
+
+        self.are_widgets_invalid =
+            self.are_widgets_invalid || other.are_widgets_invalid;
+
+        if let Some(payload) = other.drag.take() {
+            self.drag = Some(payload);
+        }
+
+        self.window_actions.append(&mut other.window_actions);
+
+        if let Some(request) = other.redraw_request {
+            self.request_redraw(request);
+        }
+
+        if other.input_method.is_some() {
+            self.input_method = other.input_method;
+        }
+
+        self.raw_events = self.raw_events || other.raw_events;
+        self.pointer_lock = self.pointer_lock || other.pointer_lock;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn forward_keeps_messages_and_moves_requests() {
+        let mut messages: Vec<i32> = Vec::new();
+        let mut shell = Shell::new(&mut messages);
+
+        let mut local_messages = vec![42];
+        let mut local_shell = Shell::new(&mut local_messages);
+
+        local_shell.start_drag(dnd::Payload::new("text"));
+        local_shell.request_window_action(window::Action::Minimize);
+        local_shell.request_window_action(window::Action::Drag);
+        local_shell.request_pointer_lock();
+        local_shell.invalidate_layout();
+
+        shell.forward(&mut local_shell);
+
+        assert!(local_shell.take_drag().is_none());
+        assert_eq!(local_messages, vec![42]);
+
+        assert!(shell.take_drag().is_some());
+        assert!(shell.wants_pointer_lock());
+        assert!(matches!(
+            shell.take_window_actions().as_slice(),
+            [window::Action::Minimize, window::Action::Drag]
+        ));
+
+        let mut relayout = false;
+        shell.with_invalid_layout(|| relayout = true);
+
+        assert!(relayout);
+        assert!(messages.is_empty());
     }
 }
@@ -0,0 +1,244 @@
+//! Simulate user interactions with a user interface.
+//!
+//! A [`Simulator`] lays out a widget tree at a given size and lets you inject
+//! synthetic mouse, keyboard and touch events, collecting the messages that
+//! the widgets produce. It is useful to test widgets without a window.
+//!
+//! ```
+//! use iced_native::renderer::Null;
+//! use iced_native::simulator::Simulator;
+//! use iced_native::widget::{button, Button, Text};
+//! use iced_native::{Point, Size};
+//!
+//! #[derive(Debug, Clone, PartialEq)]
+//! enum Message {
+//!     Pressed,
+//! }
+//!
+//! let mut state = button::State::new();
+//!
+//! let mut simulator = Simulator::<_, Null>::new(
+//!     Button::new(&mut state, Text::new("Press me"))
+//!         .on_press(Message::Pressed),
+//!     Size::new(100.0, 100.0),
+//! );
+//!
+//! simulator.click(Point::new(5.0, 5.0));
+//!
+//! assert_eq!(simulator.into_messages(), vec![Message::Pressed]);
+//! ```
+use crate::clipboard::Clipboard;
+use crate::event::{self, Event};
+use crate::keyboard;
+use crate::mouse;
+use crate::renderer;
+use crate::touch;
+use crate::user_interface::{self, UserInterface};
+use crate::{Element, Point, Size};
+
+/// A user interface that receives synthetic events.
+#[allow(missing_debug_implementations)]
+pub struct Simulator<'a, Message, Renderer: crate::Renderer> {
+    user_interface: UserInterface<'a, Message, Renderer>,
+    renderer: Renderer,
+    clipboard: Memory,
+    cursor_position: Point,
+    messages: Vec<Message>,
+}
+
+impl<'a, Message, Renderer> Simulator<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+{
+    /// Creates a new [`Simulator`] that lays out the given root widget at the
+    /// given [`Size`] with a default `Renderer`.
+    pub fn new(
+        root: impl Into<Element<'a, Message, Renderer>>,
+        size: Size,
+    ) -> Self
+    where
+        Renderer: Default,
+    {
+        Self::with_renderer(root, size, Renderer::default())
+    }
+
+    /// Creates a new [`Simulator`] that lays out the given root widget at the
+    /// given [`Size`] with the given `Renderer`.
+    pub fn with_renderer(
+        root: impl Into<Element<'a, Message, Renderer>>,
+        size: Size,
+        mut renderer: Renderer,
+    ) -> Self {
+        let user_interface = UserInterface::build(
+            root,
+            size,
+            user_interface::Cache::default(),
+            &mut renderer,
+        );
+
+        Self {
+            user_interface,
+            renderer,
+            clipboard: Memory::default(),
+            cursor_position: Point::new(-1.0, -1.0),
+            messages: Vec::new(),
+        }
+    }
+
+    /// Injects the given events and returns their [`event::Status`].
+    pub fn simulate(
+        &mut self,
+        events: impl IntoIterator<Item = Event>,
+    ) -> Vec<event::Status> {
+        let events: Vec<Event> = events.into_iter().collect();
+
+        for event in &events {
+            if let Event::Mouse(mouse::Event::CursorMoved { position })
+            | Event::Touch(touch::Event::FingerPressed {
+                position, ..
+            })
+            | Event::Touch(touch::Event::FingerMoved {
+                position, ..
+            }) = event
+            {
+                self.cursor_position = *position;
+            }
+        }
+
+        self.user_interface.update(
+            &events,
+            self.cursor_position,
+            &mut self.renderer,
+            &mut self.clipboard,
+            &mut self.messages,
+        )
+    }
+
+    /// Moves the mouse cursor to the given position.
+    pub fn point_at(&mut self, position: Point) -> event::Status {
+        self.simulate([Event::Mouse(mouse::Event::CursorMoved { position })])[0]
+    }
+
+    /// Clicks the left mouse button at the given position.
+    pub fn click(&mut self, position: Point) -> event::Status {
+        self.simulate([
+            Event::Mouse(mouse::Event::CursorMoved { position }),
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)),
+        ])
+        .into_iter()
+        .fold(event::Status::Ignored, event::Status::merge)
+    }
+
+    /// Taps the given position with a finger.
+    pub fn tap(&mut self, position: Point) -> event::Status {
+        let id = touch::Finger(0);
+
+        self.simulate([
+            Event::Touch(touch::Event::FingerPressed { id, position }),
+            Event::Touch(touch::Event::FingerLifted { id, position }),
+        ])
+        .into_iter()
+        .fold(event::Status::Ignored, event::Status::merge)
+    }
+
+    /// Presses and releases the given key with the given
+    /// [`keyboard::Modifiers`].
+    pub fn press_key(
+        &mut self,
+        key_code: keyboard::KeyCode,
+        modifiers: keyboard::Modifiers,
+    ) -> event::Status {
+        self.simulate([
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code,
+                modifiers,
+            }),
+            Event::Keyboard(keyboard::Event::KeyReleased {
+                key_code,
+                modifiers,
+            }),
+        ])
+        .into_iter()
+        .fold(event::Status::Ignored, event::Status::merge)
+    }
+
+    /// Types the given text, one character at a time.
+    pub fn type_text(&mut self, text: &str) -> event::Status {
+        self.simulate(
+            text.chars().map(|c| {
+                Event::Keyboard(keyboard::Event::CharacterReceived(c))
+            }),
+        )
+        .into_iter()
+        .fold(event::Status::Ignored, event::Status::merge)
+    }
+
+    /// Draws the user interface and returns the resulting
+    /// [`mouse::Interaction`].
+    pub fn draw(&mut self) -> mouse::Interaction {
+        self.user_interface.draw(
+            &mut self.renderer,
+            &renderer::Style::default(),
+            self.cursor_position,
+        )
+    }
+
+    /// Returns the current contents of the clipboard.
+    pub fn clipboard(&self) -> Option<&str> {
+        self.clipboard.contents.as_deref()
+    }
+
+    /// Returns the messages produced so far.
+    pub fn messages(&self) -> &[Message] {
+        &self.messages
+    }
+
+    /// Consumes the [`Simulator`] and returns the messages produced.
+    ///
+    /// Dropping the [`Simulator`] releases the state of the widgets, so it can
+    /// be inspected afterwards.
+    pub fn into_messages(self) -> Vec<Message> {
+        self.messages
+    }
+}
+
+/// A clipboard kept in memory.
+#[derive(Debug, Default)]
+struct Memory {
+    contents: Option<String>,
+}
+
+impl Clipboard for Memory {
+    fn read(&self) -> Option<String> {
+        self.contents.clone()
+    }
+
+    fn write(&mut self, contents: String) {
+        self.contents = Some(contents);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::renderer::Null;
+    use crate::widget::{text_input, TextInput};
+
+    #[test]
+    fn text_input_produces_messages_when_typing() {
+        let mut state = text_input::State::focused();
+
+        let mut simulator = Simulator::<_, Null>::new(
+            TextInput::new(&mut state, "Placeholder", "", String::from),
+            Size::new(200.0, 50.0),
+        );
+
+        let _ = simulator.type_text("ab");
+
+        assert_eq!(
+            simulator.into_messages(),
+            vec![String::from("a"), String::from("ab")]
+        );
+    }
+}
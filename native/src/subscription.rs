@@ -1,5 +1,6 @@
 //! Listen to external events in your application.
 use crate::event::{self, Event};
+use crate::window;
 use crate::Hasher;
 use iced_futures::BoxStream;
 
@@ -16,22 +17,25 @@ use iced_futures::BoxStream;
 ///
 /// [`Command`]: crate::Command
 pub type Subscription<T> =
-    iced_futures::Subscription<Hasher, (Event, event::Status), T>;
+    iced_futures::Subscription<Hasher, (window::Id, Event, event::Status), T>;
 
-/// A stream of runtime events.
+/// A stream of runtime events, together with the [`window::Id`] of the window
+/// they originated from.
 ///
 /// It is the input of a [`Subscription`] in the native runtime.
-pub type EventStream = BoxStream<(Event, event::Status)>;
+pub type EventStream = BoxStream<(window::Id, Event, event::Status)>;
 
 /// A native [`Subscription`] tracker.
-pub type Tracker =
-    iced_futures::subscription::Tracker<Hasher, (Event, event::Status)>;
+pub type Tracker = iced_futures::subscription::Tracker<
+    Hasher,
+    (window::Id, Event, event::Status),
+>;
 
 pub use iced_futures::subscription::Recipe;
 
 mod events;
 
-use events::Events;
+use events::{Events, WindowEvents};
 
 /// Returns a [`Subscription`] to all the runtime events.
 ///
@@ -62,3 +66,17 @@ where
 {
     Subscription::from_recipe(Events { f })
 }
+
+/// Returns a [`Subscription`] that filters all the runtime events with the
+/// provided function, producing messages accordingly.
+///
+/// Unlike [`events_with`], the provided function also receives the
+/// [`window::Id`] of the window every [`Event`] originated from.
+pub fn events_with_window<Message>(
+    f: fn(window::Id, Event, event::Status) -> Option<Message>,
+) -> Subscription<Message>
+where
+    Message: 'static + Send,
+{
+    Subscription::from_recipe(WindowEvents { f })
+}
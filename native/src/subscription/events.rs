@@ -1,5 +1,6 @@
 use crate::event::{self, Event};
 use crate::subscription::{EventStream, Recipe};
+use crate::window;
 use crate::Hasher;
 use iced_futures::futures::future;
 use iced_futures::futures::StreamExt;
@@ -9,7 +10,8 @@ pub struct Events<Message> {
     pub(super) f: fn(Event, event::Status) -> Option<Message>,
 }
 
-impl<Message> Recipe<Hasher, (Event, event::Status)> for Events<Message>
+impl<Message> Recipe<Hasher, (window::Id, Event, event::Status)>
+    for Events<Message>
 where
     Message: 'static + Send,
 {
@@ -27,9 +29,39 @@ where
         self: Box<Self>,
         event_stream: EventStream,
     ) -> BoxStream<Self::Output> {
-        let stream = event_stream.filter_map(move |(event, status)| {
+        let stream = event_stream.filter_map(move |(_, event, status)| {
             future::ready((self.f)(event, status))
         });
         iced_futures::boxed_stream(stream)
     }
 }
+
+pub struct WindowEvents<Message> {
+    pub(super) f: fn(window::Id, Event, event::Status) -> Option<Message>,
+}
+
+impl<Message> Recipe<Hasher, (window::Id, Event, event::Status)>
+    for WindowEvents<Message>
+where
+    Message: 'static + Send,
+{
+    type Output = Message;
+
+    fn hash(&self, state: &mut Hasher) {
+        use std::hash::Hash;
+
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+        self.f.hash(state);
+    }
+
+    fn stream(
+        self: Box<Self>,
+        event_stream: EventStream,
+    ) -> BoxStream<Self::Output> {
+        let stream = event_stream.filter_map(move |(id, event, status)| {
+            future::ready((self.f)(id, event, status))
+        });
+        iced_futures::boxed_stream(stream)
+    }
+}
@@ -85,6 +85,10 @@ pub trait Renderer: crate::Renderer {
         bounds: Size,
     ) -> (f32, f32);
 
+    /// Returns the distance from the top of a line of text with the given size
+    /// and font to its baseline.
+    fn baseline(&self, size: u16, font: Self::Font) -> f32;
+
     /// Measures the width of the text as if it were laid out in a single line.
     fn measure_width(&self, content: &str, size: u16, font: Self::Font) -> f32 {
         let (width, _) = self.measure(content, size, font, Size::INFINITY);
@@ -0,0 +1,8 @@
+//! Use the built-in theme of Iced.
+pub use iced_style::theme::*;
+
+#[cfg(feature = "theme_file")]
+mod watch;
+
+#[cfg(feature = "theme_file")]
+pub use watch::watch;
@@ -0,0 +1,61 @@
+use crate::event::{self, Event};
+use crate::subscription::{EventStream, Recipe};
+use crate::theme::{Error, Theme};
+use crate::window;
+use crate::{Hasher, Subscription};
+
+use iced_futures::futures::channel::mpsc;
+use iced_futures::BoxStream;
+
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime};
+
+/// Returns a [`Subscription`] that loads the [`Theme`] file at the given path
+/// and loads it again every time the file changes.
+///
+/// This is useful to iterate on the colors of a [`Theme`] without
+/// recompiling the application. The file is checked for changes twice every
+/// second.
+pub fn watch(path: impl Into<PathBuf>) -> Subscription<Result<Theme, Error>> {
+    Subscription::from_recipe(Watch { path: path.into() })
+}
+
+struct Watch {
+    path: PathBuf,
+}
+
+impl Recipe<Hasher, (window::Id, Event, event::Status)> for Watch {
+    type Output = Result<Theme, Error>;
+
+    fn hash(&self, state: &mut Hasher) {
+        use std::hash::Hash;
+
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+        self.path.hash(state);
+    }
+
+    fn stream(self: Box<Self>, _input: EventStream) -> BoxStream<Self::Output> {
+        let (sender, receiver) = mpsc::unbounded();
+
+        let _ = std::thread::spawn(move || {
+            let mut last_modified: Option<Option<SystemTime>> = None;
+
+            while !sender.is_closed() {
+                let modified = std::fs::metadata(&self.path)
+                    .and_then(|metadata| metadata.modified())
+                    .ok();
+
+                if last_modified != Some(modified) {
+                    last_modified = Some(modified);
+
+                    let _ = sender.unbounded_send(Theme::from_path(&self.path));
+                }
+
+                std::thread::sleep(Duration::from_millis(500));
+            }
+        });
+
+        iced_futures::boxed_stream(receiver)
+    }
+}
@@ -1,4 +1,6 @@
 //! Build touch events.
+pub mod gesture;
+
 use crate::Point;
 
 /// A touch interaction.
@@ -0,0 +1,487 @@
+//! Recognize gestures from raw touch events.
+use crate::event;
+use crate::touch::{self, Finger};
+use crate::window::RedrawRequest;
+use crate::Point;
+
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+/// The amount of time a finger needs to be held down to trigger a long press.
+const LONG_PRESS_DURATION: Duration = Duration::from_millis(500);
+
+/// The maximum distance a finger can travel during a long press.
+const LONG_PRESS_SLOP: f32 = 10.0;
+
+/// The minimum distance a finger needs to travel to trigger a swipe.
+const SWIPE_DISTANCE: f32 = 50.0;
+
+/// The maximum amount of time a swipe can take.
+const SWIPE_DURATION: Duration = Duration::from_millis(500);
+
+/// A gesture recognized from touch events.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Event {
+    /// Two fingers are pinching or rotating.
+    Pinched {
+        /// The point between both fingers.
+        center: Point,
+
+        /// The distance between both fingers relative to the distance when
+        /// the pinch started.
+        scale: f32,
+
+        /// The rotation of both fingers since the pinch started, in radians.
+        rotation: f32,
+    },
+
+    /// A pinch ended.
+    PinchEnded,
+
+    /// A finger was held down without moving.
+    LongPressed {
+        /// The position of the finger.
+        position: Point,
+    },
+
+    /// A finger moved quickly in a direction and was lifted.
+    Swiped {
+        /// The [`Direction`] of the swipe.
+        direction: Direction,
+
+        /// The speed of the swipe, in logical pixels per second.
+        velocity: f32,
+    },
+}
+
+/// The direction of a swipe.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    /// Towards the left.
+    Left,
+
+    /// Towards the right.
+    Right,
+
+    /// Towards the top.
+    Up,
+
+    /// Towards the bottom.
+    Down,
+}
+
+/// Turns touch events into gesture events.
+///
+/// Touches are only part of a gesture while no widget captures them. For
+/// instance, a finger scrolling a scrollable cannot produce a swipe or a
+/// long press.
+///
+/// The recognized gestures are queued, so they can be delivered with the
+/// next batch of events.
+#[derive(Debug, Default)]
+pub struct Recognizer {
+    tracks: HashMap<Finger, Track>,
+    pinch: Option<Pinch>,
+    queue: Vec<Event>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Track {
+    start: Point,
+    position: Point,
+    pressed_at: Instant,
+    is_moved: bool,
+    is_claimed: bool,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Pinch {
+    fingers: (Finger, Finger),
+    distance: f32,
+    angle: f32,
+}
+
+impl Recognizer {
+    /// Creates a new [`Recognizer`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Processes a batch of events, given the [`event::Status`] they had
+    /// after being handled by the widgets.
+    pub fn update(
+        &mut self,
+        events: &[crate::Event],
+        statuses: &[event::Status],
+        now: Instant,
+    ) {
+        for (event, status) in events.iter().zip(statuses) {
+            if let crate::Event::Touch(event) = event {
+                self.process(*event, *status, now);
+            }
+        }
+    }
+
+    /// Returns the recognized gestures, including any long press that is due
+    /// at the given time.
+    pub fn drain(&mut self, now: Instant) -> Vec<Event> {
+        for track in self.tracks.values_mut() {
+            if !track.is_claimed
+                && !track.is_moved
+                && now.duration_since(track.pressed_at) >= LONG_PRESS_DURATION
+            {
+                track.is_claimed = true;
+
+                self.queue.push(Event::LongPressed {
+                    position: track.position,
+                });
+            }
+        }
+
+        std::mem::take(&mut self.queue)
+    }
+
+    /// Returns when the [`Recognizer`] needs to be drained again.
+    pub fn redraw_request(&self) -> Option<RedrawRequest> {
+        if !self.queue.is_empty() {
+            return Some(RedrawRequest::NextFrame);
+        }
+
+        self.tracks
+            .values()
+            .filter(|track| !track.is_claimed && !track.is_moved)
+            .map(|track| track.pressed_at + LONG_PRESS_DURATION)
+            .min()
+            .map(RedrawRequest::At)
+    }
+
+    fn process(
+        &mut self,
+        event: touch::Event,
+        status: event::Status,
+        now: Instant,
+    ) {
+        let is_captured = status == event::Status::Captured;
+
+        match event {
+            touch::Event::FingerPressed { id, position } => {
+                let _ = self.tracks.insert(
+                    id,
+                    Track {
+                        start: position,
+                        position,
+                        pressed_at: now,
+                        is_moved: false,
+                        is_claimed: is_captured,
+                    },
+                );
+
+                self.start_pinch();
+            }
+            touch::Event::FingerMoved { id, position } => {
+                if let Some(track) = self.tracks.get_mut(&id) {
+                    track.position = position;
+
+                    track.is_moved |=
+                        track.start.distance(position) > LONG_PRESS_SLOP;
+                    track.is_claimed |= is_captured;
+                }
+
+                self.update_pinch(id);
+            }
+            touch::Event::FingerLifted { id, position } => {
+                let track = self.tracks.remove(&id);
+
+                if self.end_pinch(id) {
+                    return;
+                }
+
+                if let Some(track) = track.filter(|track| !track.is_claimed) {
+                    self.recognize_swipe(track, position, now);
+                }
+            }
+            touch::Event::FingerLost { id, .. } => {
+                let _ = self.tracks.remove(&id);
+                let _ = self.end_pinch(id);
+            }
+        }
+    }
+
+    fn start_pinch(&mut self) {
+        if self.pinch.is_some() {
+            return;
+        }
+
+        let mut available = self
+            .tracks
+            .iter()
+            .filter(|(_, track)| !track.is_claimed)
+            .map(|(finger, _)| *finger);
+
+        if let (Some(a), Some(b)) = (available.next(), available.next()) {
+            let (distance, angle) = self.measure(a, b);
+
+            for finger in [a, b].iter() {
+                if let Some(track) = self.tracks.get_mut(finger) {
+                    track.is_claimed = true;
+                }
+            }
+
+            self.pinch = Some(Pinch {
+                fingers: (a, b),
+                distance,
+                angle,
+            });
+        }
+    }
+
+    fn update_pinch(&mut self, finger: Finger) {
+        let pinch = match self.pinch {
+            Some(pinch) if pinch.fingers.0 == finger => pinch,
+            Some(pinch) if pinch.fingers.1 == finger => pinch,
+            _ => return,
+        };
+
+        let (a, b) = pinch.fingers;
+        let (distance, angle) = self.measure(a, b);
+
+        let center = match (self.tracks.get(&a), self.tracks.get(&b)) {
+            (Some(a), Some(b)) => Point::new(
+                (a.position.x + b.position.x) / 2.0,
+                (a.position.y + b.position.y) / 2.0,
+            ),
+            _ => return,
+        };
+
+        self.queue.push(Event::Pinched {
+            center,
+            scale: if pinch.distance > 0.0 {
+                distance / pinch.distance
+            } else {
+                1.0
+            },
+            rotation: angle - pinch.angle,
+        });
+    }
+
+    /// Ends the pinch the given finger is part of, if any.
+    fn end_pinch(&mut self, finger: Finger) -> bool {
+        match self.pinch {
+            Some(Pinch {
+                fingers: (a, b), ..
+            }) if a == finger || b == finger => {
+                self.pinch = None;
+                self.queue.push(Event::PinchEnded);
+
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn recognize_swipe(&mut self, track: Track, position: Point, now: Instant) {
+        let elapsed = now.duration_since(track.pressed_at);
+        let distance = track.start.distance(position);
+
+        if distance < SWIPE_DISTANCE || elapsed > SWIPE_DURATION {
+            return;
+        }
+
+        let dx = position.x - track.start.x;
+        let dy = position.y - track.start.y;
+
+        let direction = if dx.abs() > dy.abs() {
+            if dx > 0.0 {
+                Direction::Right
+            } else {
+                Direction::Left
+            }
+        } else if dy > 0.0 {
+            Direction::Down
+        } else {
+            Direction::Up
+        };
+
+        self.queue.push(Event::Swiped {
+            direction,
+            velocity: distance / elapsed.as_secs_f32().max(f32::EPSILON),
+        });
+    }
+
+    fn measure(&self, a: Finger, b: Finger) -> (f32, f32) {
+        match (self.tracks.get(&a), self.tracks.get(&b)) {
+            (Some(a), Some(b)) => {
+                let dx = b.position.x - a.position.x;
+                let dy = b.position.y - a.position.y;
+
+                (dx.hypot(dy), dy.atan2(dx))
+            }
+            _ => (0.0, 0.0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn touch(
+        recognizer: &mut Recognizer,
+        event: touch::Event,
+        status: event::Status,
+        now: Instant,
+    ) {
+        recognizer.update(&[crate::Event::Touch(event)], &[status], now);
+    }
+
+    fn press(recognizer: &mut Recognizer, id: u64, x: f32, now: Instant) {
+        touch(
+            recognizer,
+            touch::Event::FingerPressed {
+                id: Finger(id),
+                position: Point::new(x, 0.0),
+            },
+            event::Status::Ignored,
+            now,
+        );
+    }
+
+    fn slide(recognizer: &mut Recognizer, id: u64, x: f32, now: Instant) {
+        touch(
+            recognizer,
+            touch::Event::FingerMoved {
+                id: Finger(id),
+                position: Point::new(x, 0.0),
+            },
+            event::Status::Ignored,
+            now,
+        );
+    }
+
+    fn lift(recognizer: &mut Recognizer, id: u64, x: f32, now: Instant) {
+        touch(
+            recognizer,
+            touch::Event::FingerLifted {
+                id: Finger(id),
+                position: Point::new(x, 0.0),
+            },
+            event::Status::Ignored,
+            now,
+        );
+    }
+
+    fn after(start: Instant, millis: u64) -> Instant {
+        start + Duration::from_millis(millis)
+    }
+
+    #[test]
+    fn long_press_is_recognized_once_due() {
+        let mut recognizer = Recognizer::new();
+        let start = Instant::now();
+
+        press(&mut recognizer, 0, 10.0, start);
+
+        assert_eq!(recognizer.drain(after(start, 100)), vec![]);
+        assert_eq!(
+            recognizer.redraw_request(),
+            Some(RedrawRequest::At(after(start, 500)))
+        );
+
+        assert_eq!(
+            recognizer.drain(after(start, 500)),
+            vec![Event::LongPressed {
+                position: Point::new(10.0, 0.0)
+            }]
+        );
+
+        assert_eq!(recognizer.drain(after(start, 900)), vec![]);
+        assert_eq!(recognizer.redraw_request(), None);
+    }
+
+    #[test]
+    fn moving_cancels_long_press() {
+        let mut recognizer = Recognizer::new();
+        let start = Instant::now();
+
+        press(&mut recognizer, 0, 0.0, start);
+        slide(&mut recognizer, 0, 20.0, after(start, 100));
+
+        assert_eq!(recognizer.redraw_request(), None);
+        assert_eq!(recognizer.drain(after(start, 600)), vec![]);
+    }
+
+    #[test]
+    fn fast_lift_after_moving_is_a_swipe() {
+        let mut recognizer = Recognizer::new();
+        let start = Instant::now();
+
+        press(&mut recognizer, 0, 100.0, start);
+        lift(&mut recognizer, 0, 0.0, after(start, 200));
+
+        assert_eq!(
+            recognizer.drain(after(start, 200)),
+            vec![Event::Swiped {
+                direction: Direction::Left,
+                velocity: 500.0,
+            }]
+        );
+
+        press(&mut recognizer, 1, 0.0, start);
+        lift(&mut recognizer, 1, 30.0, after(start, 100));
+
+        press(&mut recognizer, 2, 0.0, start);
+        lift(&mut recognizer, 2, 100.0, after(start, 800));
+
+        assert_eq!(recognizer.drain(after(start, 800)), vec![]);
+    }
+
+    #[test]
+    fn captured_touches_are_ignored() {
+        let mut recognizer = Recognizer::new();
+        let start = Instant::now();
+
+        touch(
+            &mut recognizer,
+            touch::Event::FingerPressed {
+                id: Finger(0),
+                position: Point::ORIGIN,
+            },
+            event::Status::Captured,
+            start,
+        );
+
+        assert_eq!(recognizer.drain(after(start, 600)), vec![]);
+
+        lift(&mut recognizer, 0, 100.0, after(start, 100));
+
+        assert_eq!(recognizer.drain(after(start, 600)), vec![]);
+    }
+
+    #[test]
+    fn two_fingers_pinch() {
+        let mut recognizer = Recognizer::new();
+        let start = Instant::now();
+
+        press(&mut recognizer, 0, 0.0, start);
+        press(&mut recognizer, 1, 100.0, start);
+        slide(&mut recognizer, 1, 200.0, after(start, 50));
+
+        assert_eq!(
+            recognizer.drain(after(start, 50)),
+            vec![Event::Pinched {
+                center: Point::new(100.0, 0.0),
+                scale: 2.0,
+                rotation: 0.0,
+            }]
+        );
+
+        // Neither finger can trigger a long press or a swipe afterwards
+        lift(&mut recognizer, 1, 400.0, after(start, 100));
+        lift(&mut recognizer, 0, 0.0, after(start, 600));
+
+        assert_eq!(
+            recognizer.drain(after(start, 600)),
+            vec![Event::PinchEnded]
+        );
+    }
+}
@@ -0,0 +1,390 @@
+//! Show an icon with a menu in the system tray.
+//!
+//! A [`Tray`] is useful for utilities that keep running in the background,
+//! with their windows hidden. The activations of its menu items arrive to
+//! the application as messages.
+//!
+//! _**Note:** The system tray is only available in native platforms with the
+//! `tray` feature. On Linux, it needs a desktop that implements the
+//! `StatusNotifierItem` specification, like KDE Plasma or GNOME with the
+//! AppIndicator extension._
+use crate::command::{self, Command};
+use crate::image;
+
+use std::fmt;
+use std::sync::Arc;
+
+/// An icon in the system tray, with a menu.
+pub struct Tray<T> {
+    /// The icon of the [`Tray`]
+    pub icon: image::Handle,
+    /// The tooltip shown when hovering the icon of the [`Tray`]
+    pub tooltip: String,
+    /// The items of the menu of the [`Tray`]
+    pub menu: Vec<Item<T>>,
+    /// The message produced when the icon of the [`Tray`] is clicked
+    pub on_activate: Option<Message<T>>,
+}
+
+impl<T> Tray<T> {
+    /// Creates a new [`Tray`] with the given icon and tooltip, and an empty
+    /// menu.
+    pub fn new(icon: image::Handle, tooltip: impl Into<String>) -> Self {
+        Self {
+            icon,
+            tooltip: tooltip.into(),
+            menu: Vec::new(),
+            on_activate: None,
+        }
+    }
+
+    /// Sets the [`Item`]s of the menu of the [`Tray`].
+    pub fn menu(mut self, menu: Vec<Item<T>>) -> Self {
+        self.menu = menu;
+        self
+    }
+
+    /// Sets the message produced when the icon of the [`Tray`] is clicked.
+    ///
+    /// When set, the menu is only opened with a right click.
+    pub fn on_activate(mut self, message: T) -> Self
+    where
+        T: Clone + Send + Sync + 'static,
+    {
+        self.on_activate = Some(Message::new(message));
+        self
+    }
+
+    /// Applies a transformation to the messages of the [`Tray`].
+    pub fn map<A>(self, f: impl Fn(T) -> A + 'static + Send + Sync) -> Tray<A>
+    where
+        T: 'static,
+        A: 'static,
+    {
+        let f: Arc<dyn Fn(T) -> A + Send + Sync> = Arc::new(f);
+
+        Tray {
+            icon: self.icon,
+            tooltip: self.tooltip,
+            menu: map_items(self.menu, &f),
+            on_activate: self.on_activate.map(|message| message.map(&f)),
+        }
+    }
+}
+
+impl<T> fmt::Debug for Tray<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Tray")
+            .field("icon", &self.icon)
+            .field("tooltip", &self.tooltip)
+            .field("menu", &self.menu)
+            .finish()
+    }
+}
+
+/// An item of the menu of a [`Tray`].
+pub enum Item<T> {
+    /// A button that produces a message when pressed.
+    ///
+    /// It is disabled if it has no message.
+    Button {
+        /// The label of the button
+        label: String,
+        /// The message produced when the button is pressed
+        on_press: Option<Message<T>>,
+    },
+    /// A button with a check mark that produces a message when toggled.
+    Checkbox {
+        /// The label of the checkbox
+        label: String,
+        /// Whether the checkbox is checked or not
+        is_checked: bool,
+        /// The message produced when the checkbox is toggled
+        on_toggle: Option<Message<T>>,
+    },
+    /// A line separating groups of items.
+    Separator,
+    /// A nested menu.
+    Submenu {
+        /// The label of the submenu
+        label: String,
+        /// The items of the submenu
+        items: Vec<Item<T>>,
+    },
+}
+
+impl<T> Item<T> {
+    /// Creates a button that produces the given message when pressed.
+    pub fn button(label: impl Into<String>, on_press: T) -> Self
+    where
+        T: Clone + Send + Sync + 'static,
+    {
+        Item::Button {
+            label: label.into(),
+            on_press: Some(Message::new(on_press)),
+        }
+    }
+
+    /// Creates a checkbox that produces the given message when toggled.
+    pub fn checkbox(
+        label: impl Into<String>,
+        is_checked: bool,
+        on_toggle: T,
+    ) -> Self
+    where
+        T: Clone + Send + Sync + 'static,
+    {
+        Item::Checkbox {
+            label: label.into(),
+            is_checked,
+            on_toggle: Some(Message::new(on_toggle)),
+        }
+    }
+
+    /// Creates a submenu with the given items.
+    pub fn submenu(label: impl Into<String>, items: Vec<Item<T>>) -> Self {
+        Item::Submenu {
+            label: label.into(),
+            items,
+        }
+    }
+
+    /// Applies a transformation to the messages of the [`Item`].
+    pub fn map<A>(self, f: impl Fn(T) -> A + 'static + Send + Sync) -> Item<A>
+    where
+        T: 'static,
+        A: 'static,
+    {
+        let f: Arc<dyn Fn(T) -> A + Send + Sync> = Arc::new(f);
+
+        map_item(self, &f)
+    }
+}
+
+impl<T> fmt::Debug for Item<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Item::Button { label, on_press } => f
+                .debug_struct("Button")
+                .field("label", label)
+                .field("is_enabled", &on_press.is_some())
+                .finish(),
+            Item::Checkbox {
+                label,
+                is_checked,
+                on_toggle,
+            } => f
+                .debug_struct("Checkbox")
+                .field("label", label)
+                .field("is_checked", is_checked)
+                .field("is_enabled", &on_toggle.is_some())
+                .finish(),
+            Item::Separator => write!(f, "Separator"),
+            Item::Submenu { label, items } => f
+                .debug_struct("Submenu")
+                .field("label", label)
+                .field("items", items)
+                .finish(),
+        }
+    }
+}
+
+/// The message of a [`Tray`] or one of its [`Item`]s, which can be produced
+/// any number of times from any thread.
+pub struct Message<T>(Arc<dyn Fn() -> T + Send + Sync>);
+
+impl<T> Message<T> {
+    /// Creates a new [`Message`] that produces clones of the given message.
+    pub fn new(message: T) -> Self
+    where
+        T: Clone + Send + Sync + 'static,
+    {
+        Self(Arc::new(move || message.clone()))
+    }
+
+    /// Produces the message.
+    pub fn produce(&self) -> T {
+        (self.0)()
+    }
+
+    fn map<A>(self, f: &Arc<dyn Fn(T) -> A + Send + Sync>) -> Message<A>
+    where
+        T: 'static,
+        A: 'static,
+    {
+        let f = f.clone();
+
+        Message(Arc::new(move || f((self.0)())))
+    }
+}
+
+impl<T> Clone for Message<T> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<T> fmt::Debug for Message<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Message")
+    }
+}
+
+fn map_items<T: 'static, A: 'static>(
+    items: Vec<Item<T>>,
+    f: &Arc<dyn Fn(T) -> A + Send + Sync>,
+) -> Vec<Item<A>> {
+    items.into_iter().map(|item| map_item(item, f)).collect()
+}
+
+fn map_item<T: 'static, A: 'static>(
+    item: Item<T>,
+    f: &Arc<dyn Fn(T) -> A + Send + Sync>,
+) -> Item<A> {
+    match item {
+        Item::Button { label, on_press } => Item::Button {
+            label,
+            on_press: on_press.map(|message| message.map(f)),
+        },
+        Item::Checkbox {
+            label,
+            is_checked,
+            on_toggle,
+        } => Item::Checkbox {
+            label,
+            is_checked,
+            on_toggle: on_toggle.map(|message| message.map(f)),
+        },
+        Item::Separator => Item::Separator,
+        Item::Submenu { label, items } => Item::Submenu {
+            label,
+            items: map_items(items, f),
+        },
+    }
+}
+
+/// A system tray action to be performed by some [`Command`].
+///
+/// [`Command`]: crate::Command
+pub enum Action<T> {
+    /// Show the given [`Tray`], replacing the current one.
+    Show(Tray<T>),
+    /// Change the icon of the current [`Tray`].
+    SetIcon(image::Handle),
+    /// Change the tooltip of the current [`Tray`].
+    SetTooltip(String),
+    /// Replace the menu of the current [`Tray`].
+    SetMenu(Vec<Item<T>>),
+    /// Remove the current [`Tray`] from the system tray.
+    Hide,
+}
+
+impl<T> Action<T> {
+    /// Maps the messages of a tray [`Action`] using the provided closure.
+    pub fn map<A>(self, f: impl Fn(T) -> A + 'static + Send + Sync) -> Action<A>
+    where
+        T: 'static,
+        A: 'static,
+    {
+        match self {
+            Self::Show(tray) => Action::Show(tray.map(f)),
+            Self::SetIcon(icon) => Action::SetIcon(icon),
+            Self::SetTooltip(tooltip) => Action::SetTooltip(tooltip),
+            Self::SetMenu(menu) => {
+                let f: Arc<dyn Fn(T) -> A + Send + Sync> = Arc::new(f);
+
+                Action::SetMenu(map_items(menu, &f))
+            }
+            Self::Hide => Action::Hide,
+        }
+    }
+}
+
+impl<T> fmt::Debug for Action<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Show(tray) => write!(f, "Action::Show({:?})", tray),
+            Self::SetIcon(icon) => write!(f, "Action::SetIcon({:?})", icon),
+            Self::SetTooltip(tooltip) => {
+                write!(f, "Action::SetTooltip({:?})", tooltip)
+            }
+            Self::SetMenu(menu) => write!(f, "Action::SetMenu({:?})", menu),
+            Self::Hide => write!(f, "Action::Hide"),
+        }
+    }
+}
+
+/// Shows the given [`Tray`] in the system tray, replacing the current one.
+pub fn show<Message>(tray: Tray<Message>) -> Command<Message> {
+    Command::single(command::Action::Tray(Action::Show(tray)))
+}
+
+/// Changes the icon of the current [`Tray`].
+pub fn set_icon<Message>(icon: image::Handle) -> Command<Message> {
+    Command::single(command::Action::Tray(Action::SetIcon(icon)))
+}
+
+/// Changes the tooltip of the current [`Tray`].
+pub fn set_tooltip<Message>(tooltip: impl Into<String>) -> Command<Message> {
+    Command::single(command::Action::Tray(Action::SetTooltip(tooltip.into())))
+}
+
+/// Replaces the menu of the current [`Tray`].
+pub fn set_menu<Message>(menu: Vec<Item<Message>>) -> Command<Message> {
+    Command::single(command::Action::Tray(Action::SetMenu(menu)))
+}
+
+/// Removes the current [`Tray`] from the system tray.
+pub fn hide<Message>() -> Command<Message> {
+    Command::single(command::Action::Tray(Action::Hide))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[test]
+    fn maps_the_messages_of_nested_items() {
+        static MAPPED: AtomicUsize = AtomicUsize::new(0);
+
+        let tray = Tray::new(image::Handle::from_pixels(1, 1, vec![0; 4]), "")
+            .on_activate(1)
+            .menu(vec![
+                Item::button("One", 2),
+                Item::Separator,
+                Item::submenu("More", vec![Item::checkbox("Three", true, 3)]),
+            ])
+            .map(|number: u32| {
+                let _ = MAPPED.fetch_add(1, Ordering::SeqCst);
+
+                number * 10
+            });
+
+        assert_eq!(MAPPED.load(Ordering::SeqCst), 0);
+        assert_eq!(tray.on_activate.map(|message| message.produce()), Some(10));
+
+        match tray.menu.as_slice() {
+            [Item::Button {
+                on_press: Some(on_press),
+                ..
+            }, Item::Separator, Item::Submenu { items, .. }] => {
+                assert_eq!(on_press.produce(), 20);
+                assert_eq!(on_press.produce(), 20);
+
+                match items.as_slice() {
+                    [Item::Checkbox {
+                        on_toggle: Some(on_toggle),
+                        is_checked: true,
+                        ..
+                    }] => assert_eq!(on_toggle.produce(), 30),
+                    items => panic!("unexpected submenu: {:?}", items),
+                }
+            }
+            items => panic!("unexpected menu: {:?}", items),
+        }
+
+        assert_eq!(MAPPED.load(Ordering::SeqCst), 4);
+    }
+}
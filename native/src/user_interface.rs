@@ -1,8 +1,14 @@
+use crate::accessibility;
+use crate::dnd;
 use crate::event::{self, Event};
+use crate::keyboard;
 use crate::layout;
 use crate::mouse;
-use crate::overlay;
+use crate::overlay::nested::Nested;
 use crate::renderer;
+use crate::touch;
+use crate::widget;
+use crate::window;
 use crate::{Clipboard, Element, Layout, Point, Rectangle, Shell, Size};
 
 use std::hash::Hasher;
@@ -25,6 +31,14 @@ pub struct UserInterface<'a, Message, Renderer> {
     base: Layer,
     overlay: Option<Layer>,
     bounds: Size,
+    drag: Option<Drag>,
+    subtrees: layout::cache::Cache,
+    is_outdated: bool,
+    redraw_request: Option<window::RedrawRequest>,
+    input_method: Option<Rectangle>,
+    raw_events: bool,
+    pointer_lock: bool,
+    window_actions: Vec<window::Action>,
 }
 
 impl<'a, Message, Renderer> UserInterface<'a, Message, Renderer>
@@ -91,6 +105,7 @@ where
         renderer: &mut Renderer,
     ) -> Self {
         let root = root.into();
+        let mut subtrees = cache.subtrees;
 
         let (base, overlay) = {
             let hash = {
@@ -107,10 +122,12 @@ where
                 (cache.base.layout, cache.overlay)
             } else {
                 (
-                    renderer.layout(
-                        &root,
-                        &layout::Limits::new(Size::ZERO, bounds),
-                    ),
+                    subtrees.run(|| {
+                        renderer.layout(
+                            &root,
+                            &layout::Limits::new(Size::ZERO, bounds),
+                        )
+                    }),
                     None,
                 )
             };
@@ -123,6 +140,14 @@ where
             base,
             overlay,
             bounds,
+            drag: cache.drag,
+            subtrees,
+            is_outdated: false,
+            redraw_request: None,
+            input_method: None,
+            raw_events: cache.raw_events,
+            pointer_lock: cache.pointer_lock,
+            window_actions: Vec::new(),
         }
     }
 
@@ -200,10 +225,24 @@ where
         clipboard: &mut dyn Clipboard,
         messages: &mut Vec<Message>,
     ) -> Vec<event::Status> {
-        let (base_cursor, overlay_statuses) = if let Some(mut overlay) =
-            self.root.overlay(Layout::new(&self.base.layout))
+        let mut statuses = vec![event::Status::Ignored; events.len()];
+
+        let (origins, events) = self.interleave_drag_events(events);
+        let mut started_drag = None;
+        let mut is_outdated = false;
+        let mut redraw_request = None;
+        let mut input_method = None;
+        let mut raw_events = false;
+        let mut pointer_lock = false;
+        let mut window_actions = Vec::new();
+
+        let viewport = Rectangle::with_size(self.bounds);
+
+        let (base_cursor, overlay_statuses) = if let Some(overlay) =
+            self.root.overlay(Layout::new(&self.base.layout), &viewport)
         {
             let bounds = self.bounds;
+            let mut overlay = Nested::new(overlay);
 
             let mut layer = Self::overlay_layer(
                 self.overlay.take(),
@@ -227,6 +266,23 @@ where
                         &mut shell,
                     );
 
+                    if let Some(payload) = shell.take_drag() {
+                        started_drag = Some(payload);
+                    }
+
+                    window_actions.extend(shell.take_window_actions());
+
+                    redraw_request =
+                        min_request(redraw_request, shell.redraw_request());
+
+                    if let Some(caret) = shell.input_method() {
+                        input_method = Some(caret);
+                    }
+
+                    raw_events = raw_events || shell.wants_raw_events();
+                    pointer_lock = pointer_lock || shell.wants_pointer_lock();
+
+                    shell.with_invalid_widgets(|| is_outdated = true);
                     shell.with_invalid_layout(|| {
                         layer = Self::overlay_layer(
                             None,
@@ -240,7 +296,8 @@ where
                 })
                 .collect();
 
-            let base_cursor = if layer.layout.bounds().contains(cursor_position)
+            let base_cursor = if overlay
+                .is_over(Layout::new(&layer.layout), cursor_position)
             {
                 // TODO: Type-safe cursor availability
                 Point::new(-1.0, -1.0)
@@ -255,7 +312,7 @@ where
             (cursor_position, vec![event::Status::Ignored; events.len()])
         };
 
-        events
+        let event_statuses: Vec<event::Status> = events
             .iter()
             .cloned()
             .zip(overlay_statuses.into_iter())
@@ -271,6 +328,23 @@ where
                     &mut shell,
                 );
 
+                if let Some(payload) = shell.take_drag() {
+                    started_drag = Some(payload);
+                }
+
+                window_actions.extend(shell.take_window_actions());
+
+                redraw_request =
+                    min_request(redraw_request, shell.redraw_request());
+
+                if let Some(caret) = shell.input_method() {
+                    input_method = Some(caret);
+                }
+
+                raw_events = raw_events || shell.wants_raw_events();
+                pointer_lock = pointer_lock || shell.wants_pointer_lock();
+
+                shell.with_invalid_widgets(|| is_outdated = true);
                 shell.with_invalid_layout(|| {
                     let hash = {
                         let hasher = &mut crate::Hasher::default();
@@ -279,21 +353,237 @@ where
                         hasher.finish()
                     };
 
-                    let layout = renderer.layout(
-                        &self.root,
-                        &layout::Limits::new(Size::ZERO, self.bounds),
-                    );
+                    // Only the subtrees that changed are laid out again
+                    let mut subtrees = std::mem::take(&mut self.subtrees);
+
+                    let layout = subtrees.run(|| {
+                        renderer.layout(
+                            &self.root,
+                            &layout::Limits::new(Size::ZERO, self.bounds),
+                        )
+                    });
 
+                    self.subtrees = subtrees;
                     self.base = Layer { layout, hash };
                     self.overlay = None;
                 });
 
                 event_status.merge(overlay_status)
             })
-            .collect()
+            .collect();
+
+        if let Some(payload) = started_drag {
+            self.drag = Some(Drag {
+                payload,
+                is_external: false,
+            });
+        }
+
+        self.is_outdated = is_outdated;
+        self.redraw_request = redraw_request;
+        self.window_actions.extend(window_actions);
+
+        if !events.is_empty() {
+            self.input_method = input_method;
+            self.raw_events = raw_events;
+            self.pointer_lock = pointer_lock;
+        }
+
+        for (origin, status) in origins.into_iter().zip(event_statuses) {
+            statuses[origin] = statuses[origin].merge(status);
+        }
+
+        statuses
+    }
+
+    /// Returns whether a widget asked for the [`UserInterface`] to be rebuilt
+    /// during the last call to [`UserInterface::update`].
+    ///
+    /// An outdated [`UserInterface`] should be rebuilt by calling the `view`
+    /// logic again, even if no messages were produced.
+    pub fn is_outdated(&self) -> bool {
+        self.is_outdated
+    }
+
+    /// Returns the earliest [`window::RedrawRequest`] made by a widget during
+    /// the last call to [`UserInterface::update`], if any.
+    ///
+    /// When the request is due, the shell should redraw the window and feed a
+    /// [`window::Event::RedrawRequested`] event to the [`UserInterface`].
+    pub fn redraw_request(&self) -> Option<window::RedrawRequest> {
+        self.redraw_request
+    }
+
+    /// Returns the caret bounds of the input method requested by a widget
+    /// during the last call to [`UserInterface::update`], if any.
+    ///
+    /// The shell should enable the input method of the window while it is
+    /// requested, placing its candidate window next to the caret.
+    pub fn input_method(&self) -> Option<Rectangle> {
+        self.input_method
+    }
+
+    /// Returns whether a widget asked for raw movement events during the last
+    /// call to [`UserInterface::update`].
+    ///
+    /// Unless requested, the shell may coalesce the movement events of every
+    /// pointer with [`event::coalesce`] before feeding them to the
+    /// [`UserInterface`].
+    pub fn wants_raw_events(&self) -> bool {
+        self.raw_events
+    }
+
+    /// Returns whether a widget asked for the pointer to be locked during the
+    /// last call to [`UserInterface::update`].
+    ///
+    /// While locked, the shell should hide the cursor, keep it in place, and
+    /// feed the relative movement of the mouse as [`mouse::Event::Motion`]
+    /// events instead of [`mouse::Event::CursorMoved`].
+    pub fn wants_pointer_lock(&self) -> bool {
+        self.pointer_lock
+    }
+
+    /// Takes the [`window::Action`]s requested by the widgets during the
+    /// previous calls to [`UserInterface::update`].
+    ///
+    /// The shell should perform these actions on the window of the
+    /// [`UserInterface`].
+    pub fn take_window_actions(&mut self) -> Vec<window::Action> {
+        std::mem::take(&mut self.window_actions)
+    }
+
+    /// Produces the [`dnd::Event`]s caused by the given events during an
+    /// on-going drag, placing each one right after its cause.
+    ///
+    /// It returns the index of the original event of every produced event.
+    fn interleave_drag_events(
+        &mut self,
+        events: &[Event],
+    ) -> (Vec<usize>, Vec<Event>) {
+        let mut origins = Vec::with_capacity(events.len());
+        let mut interleaved = Vec::with_capacity(events.len());
+
+        let mut dropped_files = Vec::new();
+
+        for (origin, event) in events.iter().enumerate() {
+            origins.push(origin);
+            interleaved.push(event.clone());
+
+            let drag_event = match (event, &self.drag) {
+                (
+                    Event::Window(window::Event::FileHovered(path)),
+                    None
+                    | Some(Drag {
+                        is_external: true, ..
+                    }),
+                ) => {
+                    let mut paths = self
+                        .drag
+                        .as_ref()
+                        .and_then(|drag| {
+                            drag.payload.downcast_ref::<dnd::Files>()
+                        })
+                        .map(|files| files.paths().to_vec())
+                        .unwrap_or_default();
+
+                    paths.push(path.clone());
+
+                    let payload = dnd::Payload::new(dnd::Files::new(paths));
+
+                    self.drag = Some(Drag {
+                        payload: payload.clone(),
+                        is_external: true,
+                    });
+
+                    dnd::Event::Moved(payload)
+                }
+                (
+                    Event::Window(window::Event::FileDropped(path)),
+                    None
+                    | Some(Drag {
+                        is_external: true, ..
+                    }),
+                ) => {
+                    self.drag = None;
+                    dropped_files.push(path.clone());
+
+                    // Files dropped at once are delivered in a row, so we
+                    // coalesce them into a single payload.
+                    if let Some(Event::Window(window::Event::FileDropped(_))) =
+                        events.get(origin + 1)
+                    {
+                        continue;
+                    }
+
+                    dnd::Event::Dropped(dnd::Payload::new(dnd::Files::new(
+                        std::mem::take(&mut dropped_files),
+                    )))
+                }
+                (
+                    Event::Window(window::Event::FilesHoveredLeft),
+                    Some(Drag {
+                        payload,
+                        is_external: true,
+                    }),
+                ) => {
+                    let payload = payload.clone();
+                    self.drag = None;
+
+                    dnd::Event::Cancelled(payload)
+                }
+                (
+                    Event::Mouse(mouse::Event::CursorMoved { .. })
+                    | Event::Touch(touch::Event::FingerMoved { .. }),
+                    Some(Drag { payload, .. }),
+                ) => dnd::Event::Moved(payload.clone()),
+                (
+                    Event::Mouse(mouse::Event::ButtonReleased(
+                        mouse::Button::Left,
+                    ))
+                    | Event::Touch(touch::Event::FingerLifted { .. }),
+                    Some(Drag {
+                        payload,
+                        is_external: false,
+                    }),
+                ) => {
+                    let payload = payload.clone();
+                    self.drag = None;
+
+                    dnd::Event::Dropped(payload)
+                }
+                (
+                    Event::Touch(touch::Event::FingerLost { .. })
+                    | Event::Keyboard(keyboard::Event::KeyPressed {
+                        key_code: keyboard::KeyCode::Escape,
+                        ..
+                    }),
+                    Some(Drag {
+                        payload,
+                        is_external: false,
+                    }),
+                ) => {
+                    let payload = payload.clone();
+                    self.drag = None;
+
+                    dnd::Event::Cancelled(payload)
+                }
+                _ => continue,
+            };
+
+            origins.push(origin);
+            interleaved.push(Event::DragAndDrop(drag_event));
+        }
+
+        (origins, interleaved)
     }
 
-    /// Draws the [`UserInterface`] with the provided [`Renderer`].
+    /// Draws the [`UserInterface`] with the provided [`Renderer`] and
+    /// [`renderer::Style`].
+    ///
+    /// The [`Theme`] of the [`renderer::Style`] styles every widget that does
+    /// not have a custom style sheet.
+    ///
+    /// [`Theme`]: crate::Theme
     ///
     /// It returns the some [`Renderer::Output`]. You should update the icon of
     /// the mouse cursor accordingly in your system.
@@ -306,7 +596,7 @@ where
     /// [completing the last example](#example-1):
     ///
     /// ```no_run
-    /// use iced_native::{clipboard, UserInterface, Cache, Size, Point};
+    /// use iced_native::{clipboard, renderer, UserInterface, Cache, Size, Point};
     /// use iced_wgpu::Renderer;
     ///
     /// # mod iced_wgpu {
@@ -332,6 +622,7 @@ where
     /// let mut clipboard = clipboard::Null;
     /// let mut events = Vec::new();
     /// let mut messages = Vec::new();
+    /// let style = renderer::Style::default();
     ///
     /// loop {
     ///     // Obtain system events...
@@ -353,7 +644,8 @@ where
     ///     );
     ///
     ///     // Draw the user interface
-    ///     let mouse_cursor = user_interface.draw(&mut renderer, cursor_position);
+    ///     let mouse_cursor =
+    ///         user_interface.draw(&mut renderer, &style, cursor_position);
     ///
     ///     cache = user_interface.into_cache();
     ///
@@ -368,6 +660,7 @@ where
     pub fn draw(
         &mut self,
         renderer: &mut Renderer,
+        style: &renderer::Style,
         cursor_position: Point,
     ) -> mouse::Interaction {
         // TODO: Move to shell level (?)
@@ -375,9 +668,14 @@ where
 
         let viewport = Rectangle::with_size(self.bounds);
 
-        if let Some(mut overlay) =
-            self.root.overlay(Layout::new(&self.base.layout))
+        let base_cursor = if let Some(overlay) =
+            self.root.overlay(Layout::new(&self.base.layout), &viewport)
         {
+            let mut overlay = Nested::new(overlay);
+
+            // The cached layout is dropped whenever the base layout or the
+            // bounds change, and the positions of the overlays are part of
+            // its hash, so it still follows anchors that move or scroll.
             let layer = Self::overlay_layer(
                 self.overlay.take(),
                 self.bounds,
@@ -385,34 +683,30 @@ where
                 renderer,
             );
 
+            let is_over =
+                overlay.is_over(Layout::new(&layer.layout), cursor_position);
+
             self.overlay = Some(layer);
-        };
 
-        if let Some(layer) = &self.overlay {
-            let base_cursor = if layer.layout.bounds().contains(cursor_position)
-            {
+            if is_over {
                 Point::new(-1.0, -1.0)
             } else {
                 cursor_position
-            };
-
-            self.root.widget.draw(
-                renderer,
-                &renderer::Style::default(),
-                Layout::new(&self.base.layout),
-                base_cursor,
-                &viewport,
-            );
+            }
         } else {
-            self.root.widget.draw(
-                renderer,
-                &renderer::Style::default(),
-                Layout::new(&self.base.layout),
-                cursor_position,
-                &viewport,
-            );
+            self.overlay = None;
+
+            cursor_position
         };
 
+        self.root.widget.draw(
+            renderer,
+            style,
+            Layout::new(&self.base.layout),
+            base_cursor,
+            &viewport,
+        );
+
         let base_interaction = self.root.widget.mouse_interaction(
             Layout::new(&self.base.layout),
             cursor_position,
@@ -423,6 +717,7 @@ where
             overlay,
             root,
             base,
+            pointer_lock,
             ..
         } = self;
 
@@ -431,35 +726,53 @@ where
         //
         // Once we have a proper persistent widget tree, we should be able to
         // avoid this additional call.
-        overlay
+        let interaction = overlay
             .as_ref()
             .and_then(|layer| {
-                root.overlay(Layout::new(&base.layout)).map(|overlay| {
-                    let overlay_interaction = overlay.mouse_interaction(
-                        Layout::new(&layer.layout),
-                        cursor_position,
-                        &viewport,
-                    );
+                root.overlay(Layout::new(&base.layout), &viewport).map(
+                    |overlay| {
+                        let mut overlay = Nested::new(overlay);
 
-                    let overlay_bounds = layer.layout.bounds();
+                        let overlay_interaction = overlay.mouse_interaction(
+                            Layout::new(&layer.layout),
+                            cursor_position,
+                            &viewport,
+                        );
 
-                    renderer.with_layer(overlay_bounds, |renderer| {
                         overlay.draw(
                             renderer,
-                            &renderer::Style::default(),
+                            style,
                             Layout::new(&layer.layout),
                             cursor_position,
                         );
-                    });
 
-                    if overlay_bounds.contains(cursor_position) {
-                        overlay_interaction
-                    } else {
-                        base_interaction
-                    }
-                })
+                        overlay_interaction.unwrap_or(base_interaction)
+                    },
+                )
             })
-            .unwrap_or(base_interaction)
+            .unwrap_or(base_interaction);
+
+        // The cursor is hidden while the pointer is locked
+        if *pointer_lock {
+            mouse::Interaction::Hidden
+        } else {
+            interaction
+        }
+    }
+
+    /// Applies a [`widget::Operation`] to the widgets of the [`UserInterface`].
+    pub fn operate(&mut self, operation: &mut dyn widget::Operation<Message>) {
+        self.root.operate(Layout::new(&self.base.layout), operation);
+    }
+
+    /// Describes the widgets of the [`UserInterface`] to assistive
+    /// technologies.
+    pub fn accessibility(&mut self) -> accessibility::Tree {
+        let mut tree = accessibility::Tree::new();
+
+        self.operate(&mut tree);
+
+        tree
     }
 
     /// Relayouts and returns a new  [`UserInterface`] using the provided
@@ -472,6 +785,10 @@ where
                 base: self.base,
                 overlay: self.overlay,
                 bounds: self.bounds,
+                drag: self.drag,
+                subtrees: self.subtrees,
+                raw_events: self.raw_events,
+                pointer_lock: self.pointer_lock,
             },
             renderer,
         )
@@ -484,18 +801,25 @@ where
             base: self.base,
             overlay: self.overlay,
             bounds: self.bounds,
+            drag: self.drag,
+            subtrees: self.subtrees,
+            raw_events: self.raw_events,
+            pointer_lock: self.pointer_lock,
         }
     }
 
     fn overlay_layer(
         cache: Option<Layer>,
         bounds: Size,
-        overlay: &mut overlay::Element<'_, Message, Renderer>,
+        overlay: &mut Nested<'_, Message, Renderer>,
         renderer: &Renderer,
     ) -> Layer {
         let new_hash = {
             let hasher = &mut crate::Hasher::default();
-            overlay.hash_layout(hasher);
+            overlay.hash_layout(
+                hasher,
+                cache.as_ref().map(|layer| Layout::new(&layer.layout)),
+            );
 
             hasher.finish()
         };
@@ -512,6 +836,12 @@ where
     }
 }
 
+#[derive(Debug, Clone)]
+struct Drag {
+    payload: dnd::Payload,
+    is_external: bool,
+}
+
 #[derive(Debug, Clone)]
 struct Layer {
     layout: layout::Node,
@@ -524,6 +854,10 @@ pub struct Cache {
     base: Layer,
     overlay: Option<Layer>,
     bounds: Size,
+    drag: Option<Drag>,
+    subtrees: layout::cache::Cache,
+    raw_events: bool,
+    pointer_lock: bool,
 }
 
 impl Cache {
@@ -539,6 +873,10 @@ impl Cache {
             },
             overlay: None,
             bounds: Size::ZERO,
+            drag: None,
+            subtrees: layout::cache::Cache::default(),
+            raw_events: false,
+            pointer_lock: false,
         }
     }
 }
@@ -548,3 +886,13 @@ impl Default for Cache {
         Cache::new()
     }
 }
+
+fn min_request(
+    a: Option<window::RedrawRequest>,
+    b: Option<window::RedrawRequest>,
+) -> Option<window::RedrawRequest> {
+    match (a, b) {
+        (Some(a), Some(b)) => Some(a.min(b)),
+        (a, b) => a.or(b),
+    }
+}
@@ -11,37 +11,89 @@
 //! source of inspiration.
 //!
 //! [renderer]: crate::renderer
+pub mod animated;
+pub mod aspect_ratio;
 pub mod button;
+pub mod cached;
 pub mod checkbox;
 pub mod column;
 pub mod container;
+pub mod directional;
+pub mod disabled;
+pub mod draggable;
+pub mod drop_target;
+pub mod error_boundary;
+pub mod form;
+pub mod grid;
 pub mod image;
+pub mod inspector;
+pub mod lazy;
+pub mod mouse_area;
+pub mod operation;
+pub mod pagination;
 pub mod pane_grid;
 pub mod pick_list;
 pub mod progress_bar;
 pub mod radio;
+pub mod reorderable_column;
+pub mod responsive;
+pub mod reveal;
 pub mod row;
 pub mod rule;
 pub mod scrollable;
+pub mod shortcut_scope;
 pub mod slider;
 pub mod space;
+pub mod stack;
 pub mod svg;
 pub mod text;
 pub mod text_input;
 pub mod toggler;
 pub mod tooltip;
+pub mod window_handle;
+pub mod wrap;
 
+mod id;
+
+#[doc(no_inline)]
+pub use animated::Animated;
+#[doc(no_inline)]
+pub use aspect_ratio::AspectRatio;
 #[doc(no_inline)]
 pub use button::Button;
 #[doc(no_inline)]
+pub use cached::Cached;
+#[doc(no_inline)]
 pub use checkbox::Checkbox;
 #[doc(no_inline)]
 pub use column::Column;
 #[doc(no_inline)]
 pub use container::Container;
 #[doc(no_inline)]
+pub use directional::Directional;
+#[doc(no_inline)]
+pub use disabled::{disabled, Disabled};
+#[doc(no_inline)]
+pub use draggable::Draggable;
+#[doc(no_inline)]
+pub use drop_target::DropTarget;
+#[doc(no_inline)]
+pub use error_boundary::ErrorBoundary;
+#[doc(no_inline)]
+pub use form::Form;
+#[doc(no_inline)]
+pub use grid::Grid;
+#[doc(no_inline)]
 pub use image::Image;
 #[doc(no_inline)]
+pub use inspector::Inspector;
+#[doc(no_inline)]
+pub use lazy::Lazy;
+#[doc(no_inline)]
+pub use mouse_area::MouseArea;
+#[doc(no_inline)]
+pub use pagination::Pagination;
+#[doc(no_inline)]
 pub use pane_grid::PaneGrid;
 #[doc(no_inline)]
 pub use pick_list::PickList;
@@ -50,16 +102,26 @@ pub use progress_bar::ProgressBar;
 #[doc(no_inline)]
 pub use radio::Radio;
 #[doc(no_inline)]
+pub use reorderable_column::ReorderableColumn;
+#[doc(no_inline)]
+pub use responsive::Responsive;
+#[doc(no_inline)]
+pub use reveal::Reveal;
+#[doc(no_inline)]
 pub use row::Row;
 #[doc(no_inline)]
 pub use rule::Rule;
 #[doc(no_inline)]
 pub use scrollable::Scrollable;
 #[doc(no_inline)]
+pub use shortcut_scope::ShortcutScope;
+#[doc(no_inline)]
 pub use slider::Slider;
 #[doc(no_inline)]
 pub use space::Space;
 #[doc(no_inline)]
+pub use stack::Stack;
+#[doc(no_inline)]
 pub use svg::Svg;
 #[doc(no_inline)]
 pub use text::Text;
@@ -69,13 +131,22 @@ pub use text_input::TextInput;
 pub use toggler::Toggler;
 #[doc(no_inline)]
 pub use tooltip::Tooltip;
+#[doc(no_inline)]
+pub use window_handle::WindowHandle;
+#[doc(no_inline)]
+pub use wrap::Wrap;
+
+pub use id::Id;
+pub use operation::Operation;
 
 use crate::event::{self, Event};
 use crate::layout;
 use crate::mouse;
 use crate::overlay;
 use crate::renderer;
-use crate::{Clipboard, Hasher, Layout, Length, Point, Rectangle, Shell};
+use crate::{
+    Clipboard, Command, Hasher, Layout, Length, Point, Rectangle, Shell,
+};
 
 /// A component that displays information and allows interaction.
 ///
@@ -141,6 +212,11 @@ where
     /// For example, the [`Text`] widget does not hash its color property, as
     /// its value cannot affect the overall [`Layout`] of the user interface.
     ///
+    /// The runtime also reuses the layout of any [`Element`] whose hash and
+    /// limits did not change. Hence, a [`Widget`] should hash every property
+    /// that affects its layout.
+    ///
+    /// [`Element`]: crate::Element
     /// [`Text`]: crate::widget::Text
     fn hash_layout(&self, state: &mut Hasher);
 
@@ -180,11 +256,66 @@ where
         mouse::Interaction::Idle
     }
 
+    /// Applies an [`Operation`] to the [`Widget`] and its children, if any.
+    ///
+    /// Widgets with internal state that can be queried or updated, like a
+    /// [`TextInput`] or a [`Scrollable`], should expose it to the [`Operation`]
+    /// together with their [`Id`].
+    fn operate(
+        &mut self,
+        _layout: Layout<'_>,
+        _operation: &mut dyn Operation<Message>,
+    ) {
+    }
+
+    /// Describes the [`Widget`] and its children to an [`Inspector`].
+    ///
+    /// By default, the children are only described by their [`Layout`].
+    /// Widgets with children should describe them with their own
+    /// [`inspector::Node`].
+    fn inspect(&self, layout: Layout<'_>) -> inspector::Node {
+        inspector::Node::new(
+            std::any::type_name::<Self>(),
+            self.width(),
+            self.height(),
+            layout,
+        )
+        .with_children(
+            layout
+                .children()
+                .map(inspector::Node::from_layout)
+                .collect(),
+        )
+    }
+
     /// Returns the overlay of the [`Widget`], if there is any.
+    ///
+    /// The `viewport` is the visible area of the [`Widget`]. Widgets that
+    /// anchor an overlay may use it to close the overlay once they are no
+    /// longer visible.
     fn overlay(
         &mut self,
         _layout: Layout<'_>,
+        _viewport: &Rectangle,
     ) -> Option<overlay::Element<'_, Message, Renderer>> {
         None
     }
 }
+
+/// Produces a [`Command`] that focuses the widget with the given [`Id`],
+/// unfocusing any other widget.
+pub fn focus<Message: 'static>(id: Id) -> Command<Message> {
+    Command::widget(operation::focusable::focus(id))
+}
+
+/// Produces a [`Command`] that moves the focus to the next widget in the
+/// focus traversal order.
+pub fn focus_next<Message: 'static>() -> Command<Message> {
+    Command::widget(operation::focusable::focus_next())
+}
+
+/// Produces a [`Command`] that moves the focus to the previous widget in the
+/// focus traversal order.
+pub fn focus_previous<Message: 'static>() -> Command<Message> {
+    Command::widget(operation::focusable::focus_previous())
+}
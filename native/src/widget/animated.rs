@@ -0,0 +1,168 @@
+//! Keep redrawing some content while it is animated.
+use std::hash::Hash;
+
+use crate::event::{self, Event};
+use crate::layout;
+use crate::mouse;
+use crate::overlay;
+use crate::renderer;
+use crate::widget::Operation;
+use crate::window;
+use crate::{
+    Clipboard, Element, Hasher, Layout, Length, Point, Rectangle, Shell, Widget,
+};
+
+/// A widget that keeps redrawing its content while some [`Animation`] is
+/// running.
+///
+/// When a redraw is due, the view of the application is built again, so the
+/// content can query the current value of its [`Animation`]. This way, no
+/// time subscription is needed to animate an application.
+///
+/// [`Animation`]: crate::animation::Animation
+#[allow(missing_debug_implementations)]
+pub struct Animated<'a, Message, Renderer> {
+    content: Element<'a, Message, Renderer>,
+    redraw_request: Option<window::RedrawRequest>,
+}
+
+impl<'a, Message, Renderer> Animated<'a, Message, Renderer> {
+    /// Creates a new [`Animated`] widget with the given content.
+    pub fn new(content: impl Into<Element<'a, Message, Renderer>>) -> Self {
+        Animated {
+            content: content.into(),
+            redraw_request: None,
+        }
+    }
+
+    /// Adds the [`window::RedrawRequest`] of an [`Animation`] to the
+    /// [`Animated`] widget.
+    ///
+    /// The earliest request is kept.
+    ///
+    /// [`Animation`]: crate::animation::Animation
+    pub fn redraw_request(
+        mut self,
+        request: Option<window::RedrawRequest>,
+    ) -> Self {
+        self.redraw_request = match (self.redraw_request, request) {
+            (Some(current), Some(new)) => Some(current.min(new)),
+            (current, new) => current.or(new),
+        };
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Animated<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+{
+    fn width(&self) -> Length {
+        self.content.width()
+    }
+
+    fn height(&self) -> Length {
+        self.content.height()
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content.layout(renderer, limits)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        if let Some(request) = self.redraw_request {
+            if let Event::Window(window::Event::RedrawRequested(now)) = event {
+                let is_due = match request {
+                    window::RedrawRequest::NextFrame => true,
+                    window::RedrawRequest::At(at) => at <= now,
+                };
+
+                if is_due {
+                    shell.invalidate_widgets();
+                }
+            }
+
+            shell.request_redraw(request);
+        }
+
+        self.content.on_event(
+            event,
+            layout,
+            cursor_position,
+            renderer,
+            clipboard,
+            shell,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) -> mouse::Interaction {
+        self.content
+            .mouse_interaction(layout, cursor_position, viewport)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        self.content
+            .draw(renderer, style, layout, cursor_position, viewport);
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.content.hash_layout(state);
+    }
+
+    fn operate(
+        &mut self,
+        layout: Layout<'_>,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        self.content.operate(layout, operation);
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+        viewport: &Rectangle,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        self.content.overlay(layout, viewport)
+    }
+}
+
+impl<'a, Message, Renderer> From<Animated<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + crate::Renderer,
+{
+    fn from(
+        animated: Animated<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(animated)
+    }
+}
@@ -0,0 +1,222 @@
+//! Keep the proportions of some content.
+use std::hash::Hash;
+
+use crate::alignment::{self, Alignment};
+use crate::event::{self, Event};
+use crate::layout;
+use crate::mouse;
+use crate::overlay;
+use crate::renderer;
+use crate::widget::Operation;
+use crate::{
+    Clipboard, Element, Hasher, Layout, Length, Point, Rectangle, Shell, Size,
+    Widget,
+};
+
+/// A container that sizes its content to a fixed aspect ratio.
+///
+/// The content takes as much of the available space as possible while
+/// keeping its ratio. Any space left is kept empty around it, like the
+/// letterboxing of a video.
+#[allow(missing_debug_implementations)]
+pub struct AspectRatio<'a, Message, Renderer> {
+    ratio: f32,
+    width: Length,
+    height: Length,
+    horizontal_alignment: alignment::Horizontal,
+    vertical_alignment: alignment::Vertical,
+    content: Element<'a, Message, Renderer>,
+}
+
+impl<'a, Message, Renderer> AspectRatio<'a, Message, Renderer> {
+    /// Creates a new [`AspectRatio`] with the given ratio, in terms of width
+    /// divided by height, and content.
+    pub fn new<T>(ratio: f32, content: T) -> Self
+    where
+        T: Into<Element<'a, Message, Renderer>>,
+    {
+        AspectRatio {
+            ratio,
+            width: Length::Fill,
+            height: Length::Shrink,
+            horizontal_alignment: alignment::Horizontal::Center,
+            vertical_alignment: alignment::Vertical::Center,
+            content: content.into(),
+        }
+    }
+
+    /// Sets the width of the [`AspectRatio`].
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`AspectRatio`].
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the horizontal alignment of the content in the space left by its
+    /// ratio.
+    pub fn align_x(mut self, alignment: alignment::Horizontal) -> Self {
+        self.horizontal_alignment = alignment;
+        self
+    }
+
+    /// Sets the vertical alignment of the content in the space left by its
+    /// ratio.
+    pub fn align_y(mut self, alignment: alignment::Vertical) -> Self {
+        self.vertical_alignment = alignment;
+        self
+    }
+
+    /// Returns the biggest [`Size`] with the ratio of the [`AspectRatio`]
+    /// that fits in the given space.
+    fn fit(&self, space: Size) -> Size {
+        let ratio = self.ratio.max(f32::EPSILON);
+
+        if !space.width.is_finite() && !space.height.is_finite() {
+            return Size::ZERO;
+        }
+
+        if !space.height.is_finite() || space.width / space.height < ratio {
+            Size::new(space.width, space.width / ratio)
+        } else {
+            Size::new(space.height * ratio, space.height)
+        }
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for AspectRatio<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        let size = limits.resolve(self.fit(limits.max()));
+        let content_size = self.fit(size);
+
+        let mut content = self
+            .content
+            .layout(renderer, &layout::Limits::new(content_size, content_size));
+
+        content.move_to(Point::ORIGIN);
+        content.align(
+            Alignment::from(self.horizontal_alignment),
+            Alignment::from(self.vertical_alignment),
+            size,
+        );
+
+        layout::Node::with_children(size, vec![content])
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        self.content.widget.on_event(
+            event,
+            layout.children().next().unwrap(),
+            cursor_position,
+            renderer,
+            clipboard,
+            shell,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) -> mouse::Interaction {
+        self.content.widget.mouse_interaction(
+            layout.children().next().unwrap(),
+            cursor_position,
+            viewport,
+        )
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        self.content.draw(
+            renderer,
+            style,
+            layout.children().next().unwrap(),
+            cursor_position,
+            viewport,
+        );
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.ratio.to_bits().hash(state);
+        self.width.hash(state);
+        self.height.hash(state);
+        self.horizontal_alignment.hash(state);
+        self.vertical_alignment.hash(state);
+
+        self.content.hash_layout(state);
+    }
+
+    fn operate(
+        &mut self,
+        layout: Layout<'_>,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        operation.container(None, &mut |operation| {
+            self.content
+                .operate(layout.children().next().unwrap(), operation);
+        });
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+        viewport: &Rectangle,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        self.content
+            .overlay(layout.children().next().unwrap(), viewport)
+    }
+}
+
+impl<'a, Message, Renderer> From<AspectRatio<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + crate::Renderer,
+    Message: 'a,
+{
+    fn from(
+        aspect_ratio: AspectRatio<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(aspect_ratio)
+    }
+}
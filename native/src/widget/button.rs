@@ -1,21 +1,34 @@
 //! Allow your users to perform actions by pressing a button.
 //!
 //! A [`Button`] has some local [`State`].
+use crate::accessibility;
+use crate::animation::{Interpolate, Tracker, Transition};
 use crate::event::{self, Event};
+use crate::keyboard;
 use crate::layout;
 use crate::mouse;
 use crate::overlay;
 use crate::renderer;
+use crate::theme;
 use crate::touch;
+use crate::widget::inspector;
+use crate::widget::operation::{self, Operation};
+use crate::widget::Id;
+use crate::window;
 use crate::{
     Background, Clipboard, Color, Element, Hasher, Layout, Length, Padding,
     Point, Rectangle, Shell, Vector, Widget,
 };
 
 use std::hash::Hash;
+use std::time::{Duration, Instant};
 
 pub use iced_style::button::{Style, StyleSheet};
 
+/// The time a [`Button`] with [`Button::on_press_repeat`] has to be held
+/// down before its message starts repeating.
+pub const REPEAT_DELAY: Duration = Duration::from_millis(500);
+
 /// A generic widget that produces a message when pressed.
 ///
 /// ```
@@ -59,14 +72,20 @@ pub use iced_style::button::{Style, StyleSheet};
 #[allow(missing_debug_implementations)]
 pub struct Button<'a, Message, Renderer> {
     state: &'a mut State,
+    id: Option<Id>,
+    tab_index: i32,
     content: Element<'a, Message, Renderer>,
     on_press: Option<Message>,
+    long_press: Option<Duration>,
+    repeat: Option<Duration>,
     width: Length,
     height: Length,
     min_width: u32,
     min_height: u32,
     padding: Padding,
-    style_sheet: Box<dyn StyleSheet + 'a>,
+    style_sheet: Option<Box<dyn StyleSheet + 'a>>,
+    class: Option<String>,
+    transition: Option<Transition>,
 }
 
 impl<'a, Message, Renderer> Button<'a, Message, Renderer>
@@ -82,17 +101,37 @@ where
     {
         Button {
             state,
+            id: None,
+            tab_index: 0,
             content: content.into(),
             on_press: None,
+            long_press: None,
+            repeat: None,
             width: Length::Shrink,
             height: Length::Shrink,
             min_width: 0,
             min_height: 0,
             padding: Padding::new(5),
-            style_sheet: Default::default(),
+            style_sheet: None,
+            class: None,
+            transition: None,
         }
     }
 
+    /// Sets the [`Id`] of the [`Button`].
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Sets the position of the [`Button`] in the focus traversal order.
+    ///
+    /// See [`operation::Focusable::tab_index`].
+    pub fn tab_index(mut self, tab_index: i32) -> Self {
+        self.tab_index = tab_index;
+        self
+    }
+
     /// Sets the width of the [`Button`].
     pub fn width(mut self, width: Length) -> Self {
         self.width = width;
@@ -130,20 +169,262 @@ where
         self
     }
 
+    /// Makes the [`Button`] produce its [`Button::on_press`] message once it
+    /// has been held down for the given duration, instead of when released.
+    ///
+    /// Releasing the [`Button`] earlier, or moving the cursor out of it,
+    /// cancels the press. This is useful for actions that need to be
+    /// confirmed, like deleting something.
+    ///
+    /// The keyboard and assistive technologies still press the [`Button`]
+    /// instantly.
+    pub fn on_long_press(mut self, duration: Duration) -> Self {
+        self.long_press = Some(duration);
+        self
+    }
+
+    /// Makes the [`Button`] produce its [`Button::on_press`] message as soon
+    /// as it is held down, and then repeatedly with the given interval until
+    /// it is released.
+    ///
+    /// The message starts repeating after [`REPEAT_DELAY`], or after the
+    /// interval if longer. This is useful for the arrows of a spinner or a
+    /// scrollbar.
+    pub fn on_press_repeat(mut self, interval: Duration) -> Self {
+        self.repeat = Some(interval);
+        self
+    }
+
     /// Sets the style of the [`Button`].
     pub fn style(
         mut self,
         style_sheet: impl Into<Box<dyn StyleSheet + 'a>>,
     ) -> Self {
-        self.style_sheet = style_sheet.into();
+        self.style_sheet = Some(style_sheet.into());
         self
     }
+
+    /// Sets the style class of the [`Button`].
+    ///
+    /// The class is resolved through the current [`Theme`] when the
+    /// [`Button`] does not have a custom style sheet.
+    ///
+    /// [`Theme`]: crate::Theme
+    pub fn class(mut self, class: impl Into<String>) -> Self {
+        self.class = Some(class.into());
+        self
+    }
+
+    /// Sets the [`Transition`] used to animate the changes of the appearance
+    /// of the [`Button`] when it is hovered, pressed, or focused.
+    ///
+    /// By default, the appearance of a [`Button`] changes instantly.
+    pub fn transition(mut self, transition: Transition) -> Self {
+        self.transition = Some(transition);
+        self
+    }
+
+    fn update(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        if let event::Status::Captured = self.content.on_event(
+            event.clone(),
+            layout.children().next().unwrap(),
+            cursor_position,
+            renderer,
+            clipboard,
+            shell,
+        ) {
+            return event::Status::Captured;
+        }
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                self.state.is_focused = false;
+
+                if self.on_press.is_some() {
+                    let bounds = layout.bounds();
+
+                    if bounds.contains(cursor_position) {
+                        self.state.is_pressed = true;
+
+                        if self.is_held() {
+                            let now = Instant::now();
+
+                            self.state.next_press =
+                                Some(now + self.long_press.unwrap_or_default());
+                            self.state.is_repeating = false;
+
+                            self.hold(now, true, shell);
+                        }
+
+                        return event::Status::Captured;
+                    }
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. }) => {
+                if let Some(on_press) = self.on_press.clone() {
+                    let bounds = layout.bounds();
+
+                    if self.state.is_pressed {
+                        self.state.is_pressed = false;
+                        self.state.next_press = None;
+
+                        // Held buttons produce their message while pressed
+                        if !self.is_held() && bounds.contains(cursor_position) {
+                            shell.publish(on_press);
+                        }
+
+                        return event::Status::Captured;
+                    }
+                }
+            }
+            Event::Touch(touch::Event::FingerLost { .. }) => {
+                self.state.is_pressed = false;
+                self.state.next_press = None;
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+            | Event::Touch(touch::Event::FingerMoved { .. })
+            | Event::Window(window::Event::RedrawRequested(_))
+                if self.state.next_press.is_some() =>
+            {
+                let is_mouse_over = layout.bounds().contains(cursor_position);
+
+                self.hold(Instant::now(), is_mouse_over, shell);
+            }
+            Event::Accessibility(accessibility::Request { target, action }) => {
+                let is_target = target == layout.bounds();
+
+                match action {
+                    accessibility::Action::Focus => {
+                        self.state.is_focused =
+                            is_target && self.on_press.is_some();
+                    }
+                    accessibility::Action::Invoke if is_target => {
+                        if let Some(on_press) = self.on_press.clone() {
+                            shell.publish(on_press);
+
+                            return event::Status::Captured;
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code: keyboard::KeyCode::Enter | keyboard::KeyCode::Space,
+                ..
+            }) if self.state.is_focused => {
+                if let Some(on_press) = self.on_press.clone() {
+                    shell.publish(on_press);
+
+                    return event::Status::Captured;
+                }
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    /// Returns whether the [`Button`] produces its message while held down,
+    /// instead of when released.
+    fn is_held(&self) -> bool {
+        self.long_press.is_some() || self.repeat.is_some()
+    }
+
+    /// Produces the message of a [`Button`] held down, if it is due, and
+    /// schedules the next one.
+    fn hold(
+        &mut self,
+        now: Instant,
+        is_mouse_over: bool,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        if !is_mouse_over {
+            self.state.next_press = None;
+            return;
+        }
+
+        let next_press = match self.state.next_press {
+            Some(next_press) => next_press,
+            None => return,
+        };
+
+        if next_press <= now {
+            if let Some(on_press) = self.on_press.clone() {
+                shell.publish(on_press);
+            }
+
+            self.state.next_press = self.repeat.map(|interval| {
+                if self.state.is_repeating {
+                    now + interval
+                } else {
+                    now + interval.max(REPEAT_DELAY)
+                }
+            });
+            self.state.is_repeating = true;
+        }
+
+        if let Some(next_press) = self.state.next_press {
+            shell.request_redraw(window::RedrawRequest::At(next_press));
+        }
+    }
+
+    fn status(&self, bounds: Rectangle, cursor_position: Point) -> Status {
+        let is_mouse_over = bounds.contains(cursor_position);
+
+        if self.on_press.is_none() {
+            Status::Disabled
+        } else if is_mouse_over && self.state.is_pressed {
+            Status::Pressed
+        } else if self.state.is_focused {
+            Status::Focused
+        } else if is_mouse_over {
+            Status::Hovered
+        } else {
+            Status::Active
+        }
+    }
 }
 
 /// The local state of a [`Button`].
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub struct State {
     is_pressed: bool,
+    is_focused: bool,
+    next_press: Option<Instant>,
+    is_repeating: bool,
+    status: Tracker<Status>,
+}
+
+/// The interaction status of a [`Button`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Status {
+    Active,
+    Hovered,
+    Pressed,
+    Focused,
+    Disabled,
+}
+
+impl Status {
+    fn style(self, style_sheet: &dyn StyleSheet) -> Style {
+        match self {
+            Status::Active => style_sheet.active(),
+            Status::Hovered => style_sheet.hovered(),
+            Status::Pressed => style_sheet.pressed(),
+            Status::Focused => style_sheet.focused(),
+            Status::Disabled => style_sheet.disabled(),
+        }
+    }
 }
 
 impl State {
@@ -151,6 +432,35 @@ impl State {
     pub fn new() -> State {
         State::default()
     }
+
+    /// Returns whether the [`Button`] is currently focused or not.
+    pub fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    /// Focuses the [`Button`].
+    pub fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    /// Unfocuses the [`Button`].
+    pub fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+}
+
+impl operation::Focusable for State {
+    fn is_focused(&self) -> bool {
+        State::is_focused(self)
+    }
+
+    fn focus(&mut self) {
+        State::focus(self)
+    }
+
+    fn unfocus(&mut self) {
+        State::unfocus(self)
+    }
 }
 
 impl<'a, Message, Renderer> Widget<Message, Renderer>
@@ -199,53 +509,28 @@ where
         clipboard: &mut dyn Clipboard,
         shell: &mut Shell<'_, Message>,
     ) -> event::Status {
-        if let event::Status::Captured = self.content.on_event(
-            event.clone(),
-            layout.children().next().unwrap(),
+        let status = self.update(
+            event,
+            layout,
             cursor_position,
             renderer,
             clipboard,
             shell,
-        ) {
-            return event::Status::Captured;
-        }
-
-        match event {
-            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
-            | Event::Touch(touch::Event::FingerPressed { .. }) => {
-                if self.on_press.is_some() {
-                    let bounds = layout.bounds();
-
-                    if bounds.contains(cursor_position) {
-                        self.state.is_pressed = true;
-
-                        return event::Status::Captured;
-                    }
-                }
-            }
-            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
-            | Event::Touch(touch::Event::FingerLifted { .. }) => {
-                if let Some(on_press) = self.on_press.clone() {
-                    let bounds = layout.bounds();
+        );
 
-                    if self.state.is_pressed {
-                        self.state.is_pressed = false;
+        if let Some(transition) = self.transition {
+            let now = Instant::now();
 
-                        if bounds.contains(cursor_position) {
-                            shell.publish(on_press);
-                        }
+            self.state
+                .status
+                .update(self.status(layout.bounds(), cursor_position), now);
 
-                        return event::Status::Captured;
-                    }
-                }
-            }
-            Event::Touch(touch::Event::FingerLost { .. }) => {
-                self.state.is_pressed = false;
+            if self.state.status.progress(transition, now).is_some() {
+                shell.request_redraw(window::RedrawRequest::NextFrame);
             }
-            _ => {}
         }
 
-        event::Status::Ignored
+        status
     }
 
     fn mouse_interaction(
@@ -267,27 +552,28 @@ where
     fn draw(
         &self,
         renderer: &mut Renderer,
-        _style: &renderer::Style,
+        style: &renderer::Style,
         layout: Layout<'_>,
         cursor_position: Point,
         _viewport: &Rectangle,
     ) {
+        let theme = style
+            .theme
+            .resolve(theme::Widget::Button, self.class.as_deref());
+        let style_sheet = self.style_sheet.as_deref().unwrap_or(&theme);
+
         let bounds = layout.bounds();
         let content_layout = layout.children().next().unwrap();
 
-        let is_mouse_over = bounds.contains(cursor_position);
-        let is_disabled = self.on_press.is_none();
+        let styling = self.status(bounds, cursor_position).style(style_sheet);
 
-        let styling = if is_disabled {
-            self.style_sheet.disabled()
-        } else if is_mouse_over {
-            if self.state.is_pressed {
-                self.style_sheet.pressed()
-            } else {
-                self.style_sheet.hovered()
+        let styling = match self.transition.and_then(|transition| {
+            self.state.status.progress(transition, Instant::now())
+        }) {
+            Some((previous, progress)) => {
+                previous.style(style_sheet).interpolate(&styling, progress)
             }
-        } else {
-            self.style_sheet.active()
+            None => styling,
         };
 
         if styling.background.is_some() || styling.border_width > 0.0 {
@@ -325,6 +611,7 @@ where
             renderer,
             &renderer::Style {
                 text_color: styling.text_color,
+                theme: style.theme.clone(),
             },
             content_layout,
             cursor_position,
@@ -340,11 +627,61 @@ where
         self.content.hash_layout(state);
     }
 
+    fn operate(
+        &mut self,
+        layout: Layout<'_>,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let is_enabled = self.on_press.is_some();
+
+        operation.accessible(
+            accessibility::Node::new(
+                accessibility::Role::Button,
+                layout.bounds(),
+            )
+            .focused(self.state.is_focused)
+            .disabled(!is_enabled)
+            .focusable(is_enabled)
+            .invokable(is_enabled),
+            self.id.as_ref(),
+        );
+
+        if is_enabled {
+            operation.focusable(
+                &mut operation::focusable::WithTabIndex::new(
+                    self.state,
+                    self.tab_index,
+                ),
+                self.id.as_ref(),
+            );
+        }
+
+        operation.container(None, &mut |operation| {
+            self.content
+                .operate(layout.children().next().unwrap(), operation);
+        });
+    }
+
+    fn inspect(&self, layout: Layout<'_>) -> inspector::Node {
+        inspector::Node::new(
+            std::any::type_name::<Self>(),
+            self.width,
+            self.height,
+            layout,
+        )
+        .with_id(self.id.clone())
+        .with_children(vec![self
+            .content
+            .inspect(layout.children().next().unwrap())])
+    }
+
     fn overlay(
         &mut self,
         layout: Layout<'_>,
+        viewport: &Rectangle,
     ) -> Option<overlay::Element<'_, Message, Renderer>> {
-        self.content.overlay(layout.children().next().unwrap())
+        self.content
+            .overlay(layout.children().next().unwrap(), viewport)
     }
 }
 
@@ -360,3 +697,24 @@ where
         Element::new(button)
     }
 }
+
+impl Interpolate for Style {
+    fn interpolate(&self, other: &Self, factor: f32) -> Self {
+        Style {
+            shadow_offset: self
+                .shadow_offset
+                .interpolate(&other.shadow_offset, factor),
+            background: self.background.interpolate(&other.background, factor),
+            border_radius: self
+                .border_radius
+                .interpolate(&other.border_radius, factor),
+            border_width: self
+                .border_width
+                .interpolate(&other.border_width, factor),
+            border_color: self
+                .border_color
+                .interpolate(&other.border_color, factor),
+            text_color: self.text_color.interpolate(&other.text_color, factor),
+        }
+    }
+}
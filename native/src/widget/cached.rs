@@ -0,0 +1,250 @@
+//! Reuse the primitives drawn by some content as long as it does not change.
+//!
+//! A [`Cached`] has some local [`State`].
+use std::hash::{Hash, Hasher as _};
+
+use crate::event::{self, Event};
+use crate::layout;
+use crate::mouse;
+use crate::overlay;
+use crate::renderer;
+use crate::widget::inspector;
+use crate::widget::Operation;
+use crate::window;
+use crate::{
+    Clipboard, Element, Hasher, Layout, Length, Point, Rectangle, Shell, Widget,
+};
+
+/// A widget that reuses the primitives drawn by its content as long as the
+/// data it depends on and its bounds do not change.
+///
+/// A [`Cached`] is identified by a key, normally the data its content depends
+/// on. As long as the key and the bounds of the content stay the same, the
+/// content is not drawn again and the primitives recorded previously are
+/// reused instead. This way, static regions of a user interface, like headers
+/// and sidebars, skip both their draw logic and any tessellation.
+///
+/// The layout hash of the content is part of the key too. While the cursor
+/// is over the content or the content keeps requesting redraws (e.g. to
+/// animate), it is drawn normally, since its widgets may be changing. Other
+/// changes to the state of the widgets or to the [`renderer::Style`] are not
+/// detected, so anything that affects the appearance of the content, like the
+/// current theme, should be part of the key.
+///
+/// # Example
+/// ```
+/// # use iced_native::widget::{cached, Cached, Column, Text};
+/// # use iced_native::renderer::Null as Renderer;
+/// #
+/// let items = vec![String::from("Apples"), String::from("Oranges")];
+/// let mut state = cached::State::new();
+///
+/// let content = items.iter().fold(Column::new(), |column, item| {
+///     column.push(Text::new(item.clone()))
+/// });
+///
+/// let cached: Cached<'_, (), Renderer> =
+///     Cached::new(&mut state, &items, content);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Cached<'a, Message, Renderer> {
+    state: &'a mut State,
+    key: u64,
+    content: Element<'a, Message, Renderer>,
+}
+
+impl<'a, Message, Renderer> Cached<'a, Message, Renderer> {
+    /// Creates a new [`Cached`] with the given [`State`], key and content.
+    pub fn new<K, E>(state: &'a mut State, key: K, content: E) -> Self
+    where
+        K: Hash,
+        E: Into<Element<'a, Message, Renderer>>,
+    {
+        let key = {
+            let hasher = &mut Hasher::default();
+            key.hash(hasher);
+
+            hasher.finish()
+        };
+
+        Cached {
+            state,
+            key,
+            content: content.into(),
+        }
+    }
+}
+
+/// The local state of a [`Cached`].
+#[derive(Debug, Default)]
+pub struct State {
+    cache: renderer::Cache,
+    is_animating: bool,
+}
+
+impl State {
+    /// Creates a new [`State`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Discards the primitives stored in the [`State`], forcing the content
+    /// of the [`Cached`] to be drawn again.
+    pub fn clear(&mut self) {
+        self.cache.clear();
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Cached<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+{
+    fn width(&self) -> Length {
+        self.content.width()
+    }
+
+    fn height(&self) -> Length {
+        self.content.height()
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content.layout(renderer, limits)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let mut messages = Vec::new();
+        let mut local_shell = Shell::new(&mut messages);
+
+        let is_redraw =
+            matches!(event, Event::Window(window::Event::RedrawRequested(_)));
+
+        let status = self.content.on_event(
+            event,
+            layout,
+            cursor_position,
+            renderer,
+            clipboard,
+            &mut local_shell,
+        );
+
+        // An animation requests a new redraw every time it is redrawn
+        if local_shell.redraw_request().is_some() {
+            self.state.is_animating = true;
+        } else if is_redraw {
+            self.state.is_animating = false;
+        }
+
+        shell.merge(local_shell, std::convert::identity);
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) -> mouse::Interaction {
+        self.content
+            .mouse_interaction(layout, cursor_position, viewport)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+
+        if bounds.contains(cursor_position) || self.state.is_animating {
+            self.content.draw(
+                renderer,
+                style,
+                layout,
+                cursor_position,
+                viewport,
+            );
+
+            return;
+        }
+
+        let key = {
+            let hasher = &mut Hasher::default();
+            self.key.hash(hasher);
+            self.content.hash_layout(hasher);
+
+            // Widgets may skip drawing what falls out of the viewport
+            for rectangle in &[bounds, *viewport] {
+                rectangle.x.to_bits().hash(hasher);
+                rectangle.y.to_bits().hash(hasher);
+                rectangle.width.to_bits().hash(hasher);
+                rectangle.height.to_bits().hash(hasher);
+            }
+
+            hasher.finish()
+        };
+
+        renderer.with_cache(&self.state.cache, key, |renderer| {
+            self.content.draw(
+                renderer,
+                style,
+                layout,
+                cursor_position,
+                viewport,
+            );
+        });
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        self.content.hash_layout(state);
+    }
+
+    fn operate(
+        &mut self,
+        layout: Layout<'_>,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        self.content.operate(layout, operation);
+    }
+
+    fn inspect(&self, layout: Layout<'_>) -> inspector::Node {
+        self.content.inspect(layout)
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+        viewport: &Rectangle,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        self.content.overlay(layout, viewport)
+    }
+}
+
+impl<'a, Message, Renderer> From<Cached<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + crate::Renderer,
+    Message: 'a,
+{
+    fn from(
+        cached: Cached<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(cached)
+    }
+}
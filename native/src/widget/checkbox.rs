@@ -1,14 +1,16 @@
 //! Show toggle controls using checkboxes.
 use std::hash::Hash;
 
+use crate::accessibility;
 use crate::alignment;
 use crate::event::{self, Event};
 use crate::layout;
 use crate::mouse;
 use crate::renderer;
 use crate::text;
+use crate::theme;
 use crate::touch;
-use crate::widget::{self, Row, Text};
+use crate::widget::{self, Operation, Row, Text};
 use crate::{
     Alignment, Clipboard, Color, Element, Hasher, Layout, Length, Point,
     Rectangle, Shell, Widget,
@@ -44,7 +46,8 @@ pub struct Checkbox<'a, Message, Renderer: text::Renderer> {
     text_size: Option<u16>,
     font: Renderer::Font,
     text_color: Option<Color>,
-    style_sheet: Box<dyn StyleSheet + 'a>,
+    style_sheet: Option<Box<dyn StyleSheet + 'a>>,
+    class: Option<String>,
 }
 
 impl<'a, Message, Renderer: text::Renderer> Checkbox<'a, Message, Renderer> {
@@ -76,7 +79,8 @@ impl<'a, Message, Renderer: text::Renderer> Checkbox<'a, Message, Renderer> {
             text_size: None,
             font: Renderer::Font::default(),
             text_color: None,
-            style_sheet: Default::default(),
+            style_sheet: None,
+            class: None,
         }
     }
 
@@ -123,7 +127,18 @@ impl<'a, Message, Renderer: text::Renderer> Checkbox<'a, Message, Renderer> {
         mut self,
         style_sheet: impl Into<Box<dyn StyleSheet + 'a>>,
     ) -> Self {
-        self.style_sheet = style_sheet.into();
+        self.style_sheet = Some(style_sheet.into());
+        self
+    }
+
+    /// Sets the style class of the [`Checkbox`].
+    ///
+    /// The class is resolved through the current [`Theme`] when the
+    /// [`Checkbox`] does not have a custom style sheet.
+    ///
+    /// [`Theme`]: crate::Theme
+    pub fn class(mut self, class: impl Into<String>) -> Self {
+        self.class = Some(class.into());
         self
     }
 }
@@ -184,6 +199,14 @@ where
                     return event::Status::Captured;
                 }
             }
+            Event::Accessibility(accessibility::Request {
+                target,
+                action: accessibility::Action::Invoke,
+            }) if target == layout.bounds() => {
+                shell.publish((self.on_toggle)(!self.is_checked));
+
+                return event::Status::Captured;
+            }
             _ => {}
         }
 
@@ -211,15 +234,20 @@ where
         cursor_position: Point,
         _viewport: &Rectangle,
     ) {
+        let theme = style
+            .theme
+            .resolve(theme::Widget::Checkbox, self.class.as_deref());
+        let style_sheet = self.style_sheet.as_deref().unwrap_or(&theme);
+
         let bounds = layout.bounds();
         let is_mouse_over = bounds.contains(cursor_position);
 
         let mut children = layout.children();
 
         let custom_style = if is_mouse_over {
-            self.style_sheet.hovered(self.is_checked)
+            style_sheet.hovered(self.is_checked)
         } else {
-            self.style_sheet.active(self.is_checked)
+            style_sheet.active(self.is_checked)
         };
 
         {
@@ -276,6 +304,23 @@ where
 
         self.label.hash(state);
     }
+
+    fn operate(
+        &mut self,
+        layout: Layout<'_>,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        operation.accessible(
+            accessibility::Node::new(
+                accessibility::Role::CheckBox,
+                layout.bounds(),
+            )
+            .label(&self.label)
+            .value(accessibility::Value::Toggled(self.is_checked))
+            .invokable(true),
+            None,
+        );
+    }
 }
 
 impl<'a, Message, Renderer> From<Checkbox<'a, Message, Renderer>>
@@ -6,6 +6,8 @@ use crate::layout;
 use crate::mouse;
 use crate::overlay;
 use crate::renderer;
+use crate::widget::inspector;
+use crate::widget::Operation;
 use crate::{
     Alignment, Clipboard, Element, Hasher, Layout, Length, Padding, Point,
     Rectangle, Shell, Widget,
@@ -214,15 +216,46 @@ where
         }
     }
 
+    fn operate(
+        &mut self,
+        layout: Layout<'_>,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        operation.container(None, &mut |operation| {
+            self.children.iter_mut().zip(layout.children()).for_each(
+                |(child, layout)| {
+                    child.operate(layout, operation);
+                },
+            )
+        });
+    }
+
+    fn inspect(&self, layout: Layout<'_>) -> inspector::Node {
+        inspector::Node::new(
+            std::any::type_name::<Self>(),
+            self.width,
+            self.height,
+            layout,
+        )
+        .with_children(
+            self.children
+                .iter()
+                .zip(layout.children())
+                .map(|(child, layout)| child.inspect(layout))
+                .collect(),
+        )
+    }
+
     fn overlay(
         &mut self,
         layout: Layout<'_>,
+        viewport: &Rectangle,
     ) -> Option<overlay::Element<'_, Message, Renderer>> {
-        self.children
-            .iter_mut()
-            .zip(layout.children())
-            .filter_map(|(child, layout)| child.widget.overlay(layout))
-            .next()
+        overlay::from_children(
+            self.children.iter_mut().zip(layout.children()).filter_map(
+                |(child, layout)| child.widget.overlay(layout, viewport),
+            ),
+        )
     }
 }
 
@@ -3,10 +3,14 @@ use std::hash::Hash;
 
 use crate::alignment::{self, Alignment};
 use crate::event::{self, Event};
+use crate::keyboard;
 use crate::layout;
 use crate::mouse;
 use crate::overlay;
 use crate::renderer;
+use crate::widget::inspector;
+use crate::widget::operation::{self, Operation};
+use crate::widget::Id;
 use crate::{
     Background, Clipboard, Color, Element, Hasher, Layout, Length, Padding,
     Point, Rectangle, Shell, Widget,
@@ -16,11 +20,29 @@ use std::u32;
 
 pub use iced_style::container::{Style, StyleSheet};
 
+/// A function that may produce a message for a key of the keyboard.
+type KeyHandler<'a, Message> =
+    Box<dyn Fn(keyboard::KeyCode, keyboard::Modifiers) -> Option<Message> + 'a>;
+
+/// The moment the key handlers of a [`Container`] run, relative to its
+/// content.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Phase {
+    /// The handlers run before the content, which does not receive the
+    /// keyboard events they handle.
+    Capture,
+
+    /// The handlers run after the content, only for the keyboard events it
+    /// ignores.
+    Bubble,
+}
+
 /// An element decorating some content.
 ///
 /// It is normally used for alignment purposes.
 #[allow(missing_debug_implementations)]
 pub struct Container<'a, Message, Renderer> {
+    id: Option<Id>,
     padding: Padding,
     width: Length,
     height: Length,
@@ -28,7 +50,10 @@ pub struct Container<'a, Message, Renderer> {
     max_height: u32,
     horizontal_alignment: alignment::Horizontal,
     vertical_alignment: alignment::Vertical,
-    style_sheet: Box<dyn StyleSheet + 'a>,
+    style_sheet: Option<Box<dyn StyleSheet + 'a>>,
+    on_key_press: Option<KeyHandler<'a, Message>>,
+    on_key_release: Option<KeyHandler<'a, Message>>,
+    key_phase: Phase,
     content: Element<'a, Message, Renderer>,
 }
 
@@ -42,6 +67,7 @@ where
         T: Into<Element<'a, Message, Renderer>>,
     {
         Container {
+            id: None,
             padding: Padding::ZERO,
             width: Length::Shrink,
             height: Length::Shrink,
@@ -49,11 +75,20 @@ where
             max_height: u32::MAX,
             horizontal_alignment: alignment::Horizontal::Left,
             vertical_alignment: alignment::Vertical::Top,
-            style_sheet: Default::default(),
+            style_sheet: None,
+            on_key_press: None,
+            on_key_release: None,
+            key_phase: Phase::Bubble,
             content: content.into(),
         }
     }
 
+    /// Sets the [`Id`] of the [`Container`].
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
     /// Sets the [`Padding`] of the [`Container`].
     pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
         self.padding = padding.into();
@@ -113,9 +148,59 @@ where
         mut self,
         style_sheet: impl Into<Box<dyn StyleSheet + 'a>>,
     ) -> Self {
-        self.style_sheet = style_sheet.into();
+        self.style_sheet = Some(style_sheet.into());
+        self
+    }
+
+    /// Sets the function that may produce a message when a key is pressed
+    /// while a widget inside of the [`Container`] is focused.
+    pub fn on_key_press<F>(mut self, f: F) -> Self
+    where
+        F: 'a + Fn(keyboard::KeyCode, keyboard::Modifiers) -> Option<Message>,
+    {
+        self.on_key_press = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the function that may produce a message when a key is released
+    /// while a widget inside of the [`Container`] is focused.
+    pub fn on_key_release<F>(mut self, f: F) -> Self
+    where
+        F: 'a + Fn(keyboard::KeyCode, keyboard::Modifiers) -> Option<Message>,
+    {
+        self.on_key_release = Some(Box::new(f));
         self
     }
+
+    /// Sets the [`Phase`] of the key handlers of the [`Container`].
+    ///
+    /// By default, they run in the [`Phase::Bubble`].
+    pub fn key_phase(mut self, phase: Phase) -> Self {
+        self.key_phase = phase;
+        self
+    }
+
+    /// Produces the message of the key handlers of the [`Container`] for the
+    /// given event, if any.
+    fn on_key(&mut self, event: &Event, layout: Layout<'_>) -> Option<Message> {
+        let message = match *event {
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code,
+                modifiers,
+            }) => (self.on_key_press.as_ref()?)(key_code, modifiers)?,
+            Event::Keyboard(keyboard::Event::KeyReleased {
+                key_code,
+                modifiers,
+            }) => (self.on_key_release.as_ref()?)(key_code, modifiers)?,
+            _ => return None,
+        };
+
+        if operation::focusable::has_focus(&mut self.content, layout) {
+            Some(message)
+        } else {
+            None
+        }
+    }
 }
 
 impl<'a, Message, Renderer> Widget<Message, Renderer>
@@ -157,7 +242,11 @@ where
             size,
         );
 
-        layout::Node::with_children(size.pad(self.padding), vec![content])
+        let mut node =
+            layout::Node::with_children(size.pad(self.padding), vec![content]);
+        node.mirror(limits.direction());
+
+        node
     }
 
     fn on_event(
@@ -169,14 +258,34 @@ where
         clipboard: &mut dyn Clipboard,
         shell: &mut Shell<'_, Message>,
     ) -> event::Status {
-        self.content.widget.on_event(
-            event,
-            layout.children().next().unwrap(),
+        let content_layout = layout.children().next().unwrap();
+
+        if self.key_phase == Phase::Capture {
+            if let Some(message) = self.on_key(&event, content_layout) {
+                shell.publish(message);
+
+                return event::Status::Captured;
+            }
+        }
+
+        let status = self.content.widget.on_event(
+            event.clone(),
+            content_layout,
             cursor_position,
             renderer,
             clipboard,
             shell,
-        )
+        );
+
+        if status == event::Status::Ignored && self.key_phase == Phase::Bubble {
+            if let Some(message) = self.on_key(&event, content_layout) {
+                shell.publish(message);
+
+                return event::Status::Captured;
+            }
+        }
+
+        status
     }
 
     fn mouse_interaction(
@@ -200,7 +309,10 @@ where
         cursor_position: Point,
         viewport: &Rectangle,
     ) {
-        let style = self.style_sheet.style();
+        let style_sheet =
+            self.style_sheet.as_deref().unwrap_or(&renderer_style.theme);
+
+        let style = style_sheet.style();
 
         draw_background(renderer, &style, layout.bounds());
 
@@ -210,6 +322,7 @@ where
                 text_color: style
                     .text_color
                     .unwrap_or(renderer_style.text_color),
+                theme: renderer_style.theme.clone(),
             },
             layout.children().next().unwrap(),
             cursor_position,
@@ -232,11 +345,38 @@ where
         self.content.hash_layout(state);
     }
 
+    fn operate(
+        &mut self,
+        layout: Layout<'_>,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let Self { id, content, .. } = self;
+
+        operation.container(id.as_ref(), &mut |operation| {
+            content.operate(layout.children().next().unwrap(), operation);
+        });
+    }
+
+    fn inspect(&self, layout: Layout<'_>) -> inspector::Node {
+        inspector::Node::new(
+            std::any::type_name::<Self>(),
+            self.width,
+            self.height,
+            layout,
+        )
+        .with_id(self.id.clone())
+        .with_children(vec![self
+            .content
+            .inspect(layout.children().next().unwrap())])
+    }
+
     fn overlay(
         &mut self,
         layout: Layout<'_>,
+        viewport: &Rectangle,
     ) -> Option<overlay::Element<'_, Message, Renderer>> {
-        self.content.overlay(layout.children().next().unwrap())
+        self.content
+            .overlay(layout.children().next().unwrap(), viewport)
     }
 }
 
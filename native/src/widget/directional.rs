@@ -0,0 +1,157 @@
+//! Choose the direction in which some content flows.
+use std::hash::Hash;
+
+use crate::event::{self, Event};
+use crate::layout;
+use crate::mouse;
+use crate::overlay;
+use crate::renderer;
+use crate::widget::Operation;
+use crate::{
+    Clipboard, Direction, Element, Hasher, Layout, Length, Point, Rectangle,
+    Shell, Widget,
+};
+
+/// A widget that sets the [`Direction`] in which its content flows.
+///
+/// Rows, containers, scrollables and text follow the [`Direction`] of their
+/// closest [`Directional`] ancestor, mirroring their layout when it is
+/// [`Direction::RightToLeft`]. This way, you can switch the direction of a
+/// part of your user interface; like a text field with a Hebrew translation
+/// in an otherwise English application.
+#[allow(missing_debug_implementations)]
+pub struct Directional<'a, Message, Renderer> {
+    direction: Direction,
+    content: Element<'a, Message, Renderer>,
+}
+
+impl<'a, Message, Renderer> Directional<'a, Message, Renderer> {
+    /// Creates a new [`Directional`] with the given [`Direction`] and
+    /// content.
+    pub fn new<T>(direction: Direction, content: T) -> Self
+    where
+        T: Into<Element<'a, Message, Renderer>>,
+    {
+        Directional {
+            direction,
+            content: content.into(),
+        }
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Directional<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+{
+    fn width(&self) -> Length {
+        self.content.width()
+    }
+
+    fn height(&self) -> Length {
+        self.content.height()
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let content = self
+            .content
+            .layout(renderer, &limits.with_direction(self.direction));
+
+        layout::Node::with_children(content.size(), vec![content])
+            .with_direction(self.direction)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        self.content.widget.on_event(
+            event,
+            layout.children().next().unwrap(),
+            cursor_position,
+            renderer,
+            clipboard,
+            shell,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) -> mouse::Interaction {
+        self.content.widget.mouse_interaction(
+            layout.children().next().unwrap(),
+            cursor_position,
+            viewport,
+        )
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        self.content.draw(
+            renderer,
+            style,
+            layout.children().next().unwrap(),
+            cursor_position,
+            viewport,
+        );
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.direction.hash(state);
+        self.content.hash_layout(state);
+    }
+
+    fn operate(
+        &mut self,
+        layout: Layout<'_>,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        operation.container(None, &mut |operation| {
+            self.content
+                .operate(layout.children().next().unwrap(), operation);
+        });
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+        viewport: &Rectangle,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        self.content
+            .overlay(layout.children().next().unwrap(), viewport)
+    }
+}
+
+impl<'a, Message, Renderer> From<Directional<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + crate::Renderer,
+    Message: 'a,
+{
+    fn from(
+        directional: Directional<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(directional)
+    }
+}
@@ -0,0 +1,265 @@
+//! Disable the interaction of a whole subtree of widgets.
+use crate::accessibility;
+use crate::event::{self, Event};
+use crate::layout;
+use crate::mouse;
+use crate::overlay;
+use crate::renderer;
+use crate::widget::operation::{Focusable, Operation, Scrollable, TextInput};
+use crate::widget::Id;
+use crate::{
+    Clipboard, Element, Hasher, Layout, Length, Point, Rectangle, Shell, Widget,
+};
+
+use std::hash::Hash;
+
+pub use iced_style::disabled::{Style, StyleSheet};
+
+/// Disables the interaction of the given content and all of its children.
+///
+/// This is a shorthand for [`Disabled::new`].
+pub fn disabled<'a, Message, Renderer>(
+    content: impl Into<Element<'a, Message, Renderer>>,
+) -> Disabled<'a, Message, Renderer> {
+    Disabled::new(content)
+}
+
+/// A widget that disables the interaction of its content and all of its
+/// children, no matter if they have a disabled state of their own.
+///
+/// The content of a [`Disabled`] widget:
+///
+/// - ignores the mouse, the keyboard and touches, and is never hovered,
+/// - is skipped by the focus traversal order, losing its focus if any,
+/// - is reported as disabled to assistive technologies,
+/// - does not display any overlay, and
+/// - is dimmed, following its [`StyleSheet`].
+#[allow(missing_debug_implementations)]
+pub struct Disabled<'a, Message, Renderer> {
+    content: Element<'a, Message, Renderer>,
+    is_disabled: bool,
+    style_sheet: Option<Box<dyn StyleSheet + 'a>>,
+}
+
+impl<'a, Message, Renderer> Disabled<'a, Message, Renderer> {
+    /// Creates a new [`Disabled`] widget with the given content.
+    pub fn new(content: impl Into<Element<'a, Message, Renderer>>) -> Self {
+        Disabled {
+            content: content.into(),
+            is_disabled: true,
+            style_sheet: None,
+        }
+    }
+
+    /// Sets whether the content of the [`Disabled`] widget is disabled.
+    ///
+    /// This is useful to disable some content conditionally, like a form
+    /// while it is being submitted. By default, it is disabled.
+    pub fn disabled(mut self, is_disabled: bool) -> Self {
+        self.is_disabled = is_disabled;
+        self
+    }
+
+    /// Sets the style of the [`Disabled`] widget.
+    pub fn style(
+        mut self,
+        style_sheet: impl Into<Box<dyn StyleSheet + 'a>>,
+    ) -> Self {
+        self.style_sheet = Some(style_sheet.into());
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Disabled<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+{
+    fn width(&self) -> Length {
+        self.content.width()
+    }
+
+    fn height(&self) -> Length {
+        self.content.height()
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content.layout(renderer, limits)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        if !self.is_disabled {
+            return self.content.on_event(
+                event,
+                layout,
+                cursor_position,
+                renderer,
+                clipboard,
+                shell,
+            );
+        }
+
+        // The content keeps animating, but it is never hovered
+        if let Event::Window(_)
+        | Event::Mouse(mouse::Event::CursorMoved { .. })
+        | Event::Mouse(mouse::Event::CursorLeft) = event
+        {
+            let _ = self.content.on_event(
+                event,
+                layout,
+                // TODO: Type-safe cursor availability
+                Point::new(-1.0, -1.0),
+                renderer,
+                clipboard,
+                shell,
+            );
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) -> mouse::Interaction {
+        if self.is_disabled {
+            return mouse::Interaction::default();
+        }
+
+        self.content
+            .mouse_interaction(layout, cursor_position, viewport)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        if !self.is_disabled {
+            return self.content.draw(
+                renderer,
+                style,
+                layout,
+                cursor_position,
+                viewport,
+            );
+        }
+
+        let style_sheet = self.style_sheet.as_deref().unwrap_or(&style.theme);
+
+        renderer.with_opacity(style_sheet.style().opacity, |renderer| {
+            self.content.draw(
+                renderer,
+                style,
+                layout,
+                Point::new(-1.0, -1.0),
+                viewport,
+            );
+        });
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.content.hash_layout(state);
+    }
+
+    fn operate(
+        &mut self,
+        layout: Layout<'_>,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let is_disabled = self.is_disabled;
+
+        operation.container(None, &mut |operation| {
+            if is_disabled {
+                self.content.operate(layout, &mut Disable { operation });
+            } else {
+                self.content.operate(layout, operation);
+            }
+        });
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+        viewport: &Rectangle,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        if self.is_disabled {
+            return None;
+        }
+
+        self.content.overlay(layout, viewport)
+    }
+}
+
+impl<'a, Message, Renderer> From<Disabled<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + crate::Renderer,
+{
+    fn from(
+        disabled: Disabled<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(disabled)
+    }
+}
+
+/// An [`Operation`] that hides the widgets that can be focused and marks
+/// every widget as disabled.
+struct Disable<'a, T> {
+    operation: &'a mut dyn Operation<T>,
+}
+
+impl<'a, T> Operation<T> for Disable<'a, T> {
+    fn container(
+        &mut self,
+        id: Option<&Id>,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+    ) {
+        self.operation.container(id, &mut |operation| {
+            operate_on_children(&mut Disable { operation });
+        });
+    }
+
+    fn focusable(&mut self, state: &mut dyn Focusable, _id: Option<&Id>) {
+        state.unfocus();
+    }
+
+    fn scrollable(&mut self, state: &mut dyn Scrollable, id: Option<&Id>) {
+        self.operation.scrollable(state, id);
+    }
+
+    fn text_input(&mut self, state: &mut dyn TextInput, id: Option<&Id>) {
+        self.operation.text_input(state, id);
+    }
+
+    fn accessible(&mut self, node: accessibility::Node, id: Option<&Id>) {
+        self.operation.accessible(
+            node.disabled(true)
+                .focusable(false)
+                .invokable(false)
+                .editable(false),
+            id,
+        );
+    }
+}
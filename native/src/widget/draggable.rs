@@ -0,0 +1,314 @@
+//! Let users drag data out of a widget.
+//!
+//! A [`Draggable`] has some local [`State`].
+use std::any::Any;
+use std::hash::Hash;
+
+use crate::dnd;
+use crate::event::{self, Event};
+use crate::layout;
+use crate::mouse;
+use crate::overlay;
+use crate::renderer;
+use crate::touch;
+use crate::widget::Operation;
+use crate::{
+    Clipboard, Element, Hasher, Layout, Length, Overlay, Point, Rectangle,
+    Shell, Size, Vector, Widget,
+};
+
+/// The distance the cursor needs to travel while pressed to start a drag.
+const DRAG_THRESHOLD: f32 = 4.0;
+
+/// A widget that starts a drag carrying some [`dnd::Payload`] when its
+/// content is pressed and moved.
+///
+/// While the drag is in progress, a ghost of the content follows the cursor.
+/// The payload can be received by a [`DropTarget`].
+///
+/// [`DropTarget`]: crate::widget::DropTarget
+#[allow(missing_debug_implementations)]
+pub struct Draggable<'a, Message, Renderer> {
+    state: &'a mut State,
+    content: Element<'a, Message, Renderer>,
+    ghost: Option<Element<'a, Message, Renderer>>,
+    payload: dnd::Payload,
+}
+
+impl<'a, Message, Renderer> Draggable<'a, Message, Renderer> {
+    /// Creates a new [`Draggable`] with the given [`State`], content, and the
+    /// data to carry when dragged.
+    pub fn new<T>(
+        state: &'a mut State,
+        content: impl Into<Element<'a, Message, Renderer>>,
+        payload: T,
+    ) -> Self
+    where
+        T: Any + Send + Sync,
+    {
+        Draggable {
+            state,
+            content: content.into(),
+            ghost: None,
+            payload: dnd::Payload::new(payload),
+        }
+    }
+
+    /// Sets the element that follows the cursor while the [`Draggable`] is
+    /// being dragged.
+    ///
+    /// By default, the content of the [`Draggable`] is used.
+    pub fn ghost(
+        mut self,
+        ghost: impl Into<Element<'a, Message, Renderer>>,
+    ) -> Self {
+        self.ghost = Some(ghost.into());
+        self
+    }
+}
+
+/// The local state of a [`Draggable`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct State {
+    pressed_at: Option<Point>,
+    grabbed_at: Option<Vector>,
+}
+
+impl State {
+    /// Creates a new [`State`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns whether the [`Draggable`] is currently being dragged.
+    pub fn is_dragging(&self) -> bool {
+        self.grabbed_at.is_some()
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Draggable<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+{
+    fn width(&self) -> Length {
+        self.content.width()
+    }
+
+    fn height(&self) -> Length {
+        self.content.height()
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content.layout(renderer, limits)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        if let event::Status::Captured = self.content.on_event(
+            event.clone(),
+            layout,
+            cursor_position,
+            renderer,
+            clipboard,
+            shell,
+        ) {
+            return event::Status::Captured;
+        }
+
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. })
+                if bounds.contains(cursor_position) =>
+            {
+                self.state.pressed_at = Some(cursor_position);
+
+                return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+            | Event::Touch(touch::Event::FingerMoved { .. }) => {
+                if let (Some(pressed_at), None) =
+                    (self.state.pressed_at, self.state.grabbed_at)
+                {
+                    if pressed_at.distance(cursor_position) > DRAG_THRESHOLD {
+                        self.state.grabbed_at =
+                            Some(pressed_at - bounds.position());
+
+                        shell.start_drag(self.payload.clone());
+
+                        return event::Status::Captured;
+                    }
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+            | Event::Touch(touch::Event::FingerLost { .. })
+            | Event::DragAndDrop(dnd::Event::Cancelled(_)) => {
+                self.state.pressed_at = None;
+                self.state.grabbed_at = None;
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) -> mouse::Interaction {
+        if self.state.is_dragging() {
+            return mouse::Interaction::Grabbing;
+        }
+
+        self.content
+            .mouse_interaction(layout, cursor_position, viewport)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        self.content
+            .draw(renderer, style, layout, cursor_position, viewport);
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.content.hash_layout(state);
+    }
+
+    fn operate(
+        &mut self,
+        layout: Layout<'_>,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        operation.container(None, &mut |operation| {
+            self.content.operate(layout, operation);
+        });
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+        viewport: &Rectangle,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        match self.state.grabbed_at {
+            Some(grabbed_at) => {
+                let ghost = self.ghost.as_ref().unwrap_or(&self.content);
+
+                Some(overlay::Element::new(
+                    layout.position(),
+                    Box::new(Ghost {
+                        element: ghost,
+                        size: layout.bounds().size(),
+                        grabbed_at,
+                    }),
+                ))
+            }
+            None => self.content.overlay(layout, viewport),
+        }
+    }
+}
+
+/// The element following the cursor while a [`Draggable`] is dragged.
+struct Ghost<'a, 'b, Message, Renderer> {
+    element: &'b Element<'a, Message, Renderer>,
+    size: Size,
+    grabbed_at: Vector,
+}
+
+impl<'a, 'b, Message, Renderer> Overlay<Message, Renderer>
+    for Ghost<'a, 'b, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+{
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        _bounds: Size,
+        _position: Point,
+    ) -> layout::Node {
+        let limits = layout::Limits::new(Size::ZERO, self.size);
+
+        // The ghost never takes any space, so it does not hide any
+        // interaction from the widgets below it.
+        layout::Node::with_children(
+            Size::ZERO,
+            vec![self.element.layout(renderer, &limits)],
+        )
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) {
+        let layout = match layout.children().next() {
+            Some(layout) => layout,
+            None => return,
+        };
+
+        let bounds = layout.bounds();
+
+        renderer.with_translation(
+            cursor_position - Point::ORIGIN - self.grabbed_at,
+            |renderer| {
+                renderer.with_layer(bounds, |renderer| {
+                    self.element.draw(
+                        renderer,
+                        style,
+                        layout,
+                        // TODO: Remove once cursor availability is encoded
+                        // in the type system
+                        Point::new(-1.0, -1.0),
+                        &bounds,
+                    );
+                });
+            },
+        );
+    }
+
+    fn hash_layout(&self, state: &mut Hasher, _position: Point) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.element.hash_layout(state);
+    }
+}
+
+impl<'a, Message, Renderer> From<Draggable<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + crate::Renderer,
+    Message: 'a,
+{
+    fn from(
+        draggable: Draggable<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(draggable)
+    }
+}
@@ -0,0 +1,317 @@
+//! Accept data dropped by the user.
+//!
+//! A [`DropTarget`] has some local [`State`].
+use std::any::Any;
+use std::hash::Hash;
+
+use crate::dnd;
+use crate::event::{self, Event};
+use crate::layout;
+use crate::mouse;
+use crate::overlay;
+use crate::renderer;
+use crate::theme;
+use crate::widget::Operation;
+use crate::{
+    Background, Clipboard, Color, Element, Hasher, Layout, Length, Point,
+    Rectangle, Shell, Widget,
+};
+
+pub use iced_style::drop_target::{Style, StyleSheet};
+
+/// A widget that accepts payloads of type `T` dropped on top of its content.
+///
+/// Payloads are produced by a [`Draggable`], or by other applications when
+/// dragging [`dnd::Files`] into the window. Payloads of any other type are
+/// ignored.
+///
+/// Nested drop targets accepting the same type of payload take precedence
+/// over their parents.
+///
+/// [`Draggable`]: crate::widget::Draggable
+#[allow(missing_debug_implementations)]
+pub struct DropTarget<'a, T, Message, Renderer> {
+    state: &'a mut State,
+    content: Element<'a, Message, Renderer>,
+    on_drop: Box<dyn Fn(T) -> Message + 'a>,
+    on_enter: Option<Box<dyn Fn(T) -> Message + 'a>>,
+    on_leave: Option<Message>,
+    style_sheet: Option<Box<dyn StyleSheet + 'a>>,
+    class: Option<String>,
+}
+
+impl<'a, T, Message, Renderer> DropTarget<'a, T, Message, Renderer>
+where
+    T: Any + Clone,
+    Message: Clone,
+{
+    /// Creates a new [`DropTarget`] with the given [`State`], content, and the
+    /// message to produce when a payload is dropped on it.
+    pub fn new<F>(
+        state: &'a mut State,
+        content: impl Into<Element<'a, Message, Renderer>>,
+        on_drop: F,
+    ) -> Self
+    where
+        F: 'a + Fn(T) -> Message,
+    {
+        DropTarget {
+            state,
+            content: content.into(),
+            on_drop: Box::new(on_drop),
+            on_enter: None,
+            on_leave: None,
+            style_sheet: None,
+            class: None,
+        }
+    }
+
+    /// Sets the message to produce when a payload is dragged into the
+    /// [`DropTarget`].
+    pub fn on_enter<F>(mut self, on_enter: F) -> Self
+    where
+        F: 'a + Fn(T) -> Message,
+    {
+        self.on_enter = Some(Box::new(on_enter));
+        self
+    }
+
+    /// Sets the message to produce when a payload is dragged out of the
+    /// [`DropTarget`] without being dropped.
+    pub fn on_leave(mut self, message: Message) -> Self {
+        self.on_leave = Some(message);
+        self
+    }
+
+    /// Sets the style of the [`DropTarget`].
+    pub fn style(
+        mut self,
+        style_sheet: impl Into<Box<dyn StyleSheet + 'a>>,
+    ) -> Self {
+        self.style_sheet = Some(style_sheet.into());
+        self
+    }
+
+    /// Sets the style class of the [`DropTarget`].
+    ///
+    /// The class is resolved through the current [`Theme`] when the
+    /// [`DropTarget`] does not have a custom style sheet.
+    ///
+    /// [`Theme`]: crate::Theme
+    pub fn class(mut self, class: impl Into<String>) -> Self {
+        self.class = Some(class.into());
+        self
+    }
+
+    fn leave(&mut self, shell: &mut Shell<'_, Message>) {
+        if self.state.is_hovered {
+            if let Some(on_leave) = &self.on_leave {
+                shell.publish(on_leave.clone());
+            }
+        }
+
+        self.state.is_hovered = false;
+    }
+}
+
+/// The local state of a [`DropTarget`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct State {
+    is_expecting: bool,
+    is_hovered: bool,
+}
+
+impl State {
+    /// Creates a new [`State`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns whether an acceptable payload is being dragged over the
+    /// [`DropTarget`].
+    pub fn is_hovered(&self) -> bool {
+        self.is_hovered
+    }
+}
+
+impl<'a, T, Message, Renderer> Widget<Message, Renderer>
+    for DropTarget<'a, T, Message, Renderer>
+where
+    T: Any + Clone,
+    Message: Clone,
+    Renderer: crate::Renderer,
+{
+    fn width(&self) -> Length {
+        self.content.width()
+    }
+
+    fn height(&self) -> Length {
+        self.content.height()
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content.layout(renderer, limits)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let content_status = self.content.on_event(
+            event.clone(),
+            layout,
+            cursor_position,
+            renderer,
+            clipboard,
+            shell,
+        );
+
+        let payload = match &event {
+            Event::DragAndDrop(dnd::Event::Moved(payload))
+            | Event::DragAndDrop(dnd::Event::Dropped(payload))
+            | Event::DragAndDrop(dnd::Event::Cancelled(payload)) => payload,
+            _ => return content_status,
+        };
+
+        let value = match payload.downcast_ref::<T>() {
+            Some(value) => value,
+            None => return content_status,
+        };
+
+        let is_mouse_over = layout.bounds().contains(cursor_position)
+            && content_status == event::Status::Ignored;
+
+        match event {
+            Event::DragAndDrop(dnd::Event::Moved(_)) => {
+                self.state.is_expecting = true;
+
+                if is_mouse_over {
+                    if !self.state.is_hovered {
+                        self.state.is_hovered = true;
+
+                        if let Some(on_enter) = &self.on_enter {
+                            shell.publish(on_enter(value.clone()));
+                        }
+                    }
+
+                    return event::Status::Captured;
+                }
+
+                self.leave(shell);
+            }
+            Event::DragAndDrop(dnd::Event::Dropped(_)) => {
+                self.state.is_expecting = false;
+                self.state.is_hovered = false;
+
+                if is_mouse_over {
+                    shell.publish((self.on_drop)(value.clone()));
+
+                    return event::Status::Captured;
+                }
+            }
+            _ => {
+                self.state.is_expecting = false;
+                self.leave(shell);
+            }
+        }
+
+        content_status
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) -> mouse::Interaction {
+        self.content
+            .mouse_interaction(layout, cursor_position, viewport)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        let theme = style
+            .theme
+            .resolve(theme::Widget::DropTarget, self.class.as_deref());
+        let style_sheet = self.style_sheet.as_deref().unwrap_or(&theme);
+
+        let style_sheet = if self.state.is_hovered {
+            style_sheet.hovered()
+        } else if self.state.is_expecting {
+            style_sheet.expecting()
+        } else {
+            style_sheet.active()
+        };
+
+        if style_sheet.background.is_some() || style_sheet.border_width > 0.0 {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: layout.bounds(),
+                    border_radius: style_sheet.border_radius,
+                    border_width: style_sheet.border_width,
+                    border_color: style_sheet.border_color,
+                },
+                style_sheet
+                    .background
+                    .unwrap_or(Background::Color(Color::TRANSPARENT)),
+            );
+        }
+
+        self.content
+            .draw(renderer, style, layout, cursor_position, viewport);
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.content.hash_layout(state);
+    }
+
+    fn operate(
+        &mut self,
+        layout: Layout<'_>,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        operation.container(None, &mut |operation| {
+            self.content.operate(layout, operation);
+        });
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+        viewport: &Rectangle,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        self.content.overlay(layout, viewport)
+    }
+}
+
+impl<'a, T, Message, Renderer> From<DropTarget<'a, T, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    T: 'a + Any + Clone,
+    Message: 'a + Clone,
+    Renderer: 'a + crate::Renderer,
+{
+    fn from(
+        drop_target: DropTarget<'a, T, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(drop_target)
+    }
+}
@@ -0,0 +1,572 @@
+//! Keep a panicking widget from bringing down the whole application.
+//!
+//! An [`ErrorBoundary`] has some local [`State`].
+use crate::event::{self, Event};
+use crate::layout;
+use crate::mouse;
+use crate::overlay;
+use crate::renderer;
+use crate::widget::Operation;
+use crate::{
+    Clipboard, Element, Hasher, Layout, Length, Point, Rectangle, Shell, Size,
+    Widget,
+};
+
+use std::any::Any;
+use std::cell::{Cell, RefCell};
+use std::hash::Hash;
+use std::panic::{self, AssertUnwindSafe};
+
+/// A widget that catches the panics of its content while it is laid out,
+/// drawn or handling events, and displays a fallback instead.
+///
+/// Once its content panics, the [`ErrorBoundary`] keeps displaying the
+/// fallback until its [`State`] is reset.
+#[allow(missing_debug_implementations)]
+pub struct ErrorBoundary<'a, Message, Renderer> {
+    state: &'a State,
+    content: Element<'a, Message, Renderer>,
+    fallback: Element<'a, Message, Renderer>,
+    on_error: Option<Box<dyn Fn(String) -> Message + 'a>>,
+}
+
+impl<'a, Message, Renderer> ErrorBoundary<'a, Message, Renderer> {
+    /// Creates a new [`ErrorBoundary`] with the given [`State`], content and
+    /// fallback.
+    pub fn new(
+        state: &'a State,
+        content: impl Into<Element<'a, Message, Renderer>>,
+        fallback: impl Into<Element<'a, Message, Renderer>>,
+    ) -> Self {
+        ErrorBoundary {
+            state,
+            content: content.into(),
+            fallback: fallback.into(),
+            on_error: None,
+        }
+    }
+
+    /// Sets the message that should be produced with the description of the
+    /// panic when the content of the [`ErrorBoundary`] panics.
+    pub fn on_error(
+        mut self,
+        on_error: impl Fn(String) -> Message + 'a,
+    ) -> Self {
+        self.on_error = Some(Box::new(on_error));
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for ErrorBoundary<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+{
+    fn width(&self) -> Length {
+        if self.state.is_failed() {
+            self.fallback.width()
+        } else {
+            self.content.width()
+        }
+    }
+
+    fn height(&self) -> Length {
+        if self.state.is_failed() {
+            self.fallback.height()
+        } else {
+            self.content.height()
+        }
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        if !self.state.is_failed() {
+            let content =
+                self.state.catch(|| self.content.layout(renderer, limits));
+
+            if let Some(content) = content {
+                return layout::Node::with_children(
+                    content.size(),
+                    vec![content],
+                );
+            }
+        }
+
+        // The layout of the fallback has an empty node in place of the
+        // content, so both layouts can be told apart when cached
+        let fallback = self.fallback.layout(renderer, limits);
+
+        layout::Node::with_children(
+            fallback.size(),
+            vec![layout::Node::new(Size::ZERO), fallback],
+        )
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let status = match Child::of(layout) {
+            Child::Content(layout) if !self.state.is_failed() => {
+                let content = &mut self.content;
+
+                self.state
+                    .catch(|| {
+                        content.on_event(
+                            event,
+                            layout,
+                            cursor_position,
+                            renderer,
+                            clipboard,
+                            shell,
+                        )
+                    })
+                    .unwrap_or(event::Status::Ignored)
+            }
+            Child::Fallback(layout) => self.fallback.on_event(
+                event,
+                layout,
+                cursor_position,
+                renderer,
+                clipboard,
+                shell,
+            ),
+            _ => event::Status::Ignored,
+        };
+
+        if let Some(panic) = self.state.unreported() {
+            if let Some(on_error) = &self.on_error {
+                shell.publish(on_error(panic));
+            }
+
+            // The fallback needs to be laid out
+            shell.invalidate_layout();
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) -> mouse::Interaction {
+        match Child::of(layout) {
+            Child::Content(layout) if !self.state.is_failed() => self
+                .state
+                .catch(|| {
+                    self.content.mouse_interaction(
+                        layout,
+                        cursor_position,
+                        viewport,
+                    )
+                })
+                .unwrap_or_default(),
+            Child::Fallback(layout) => self.fallback.mouse_interaction(
+                layout,
+                cursor_position,
+                viewport,
+            ),
+            _ => mouse::Interaction::default(),
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        match Child::of(layout) {
+            Child::Content(layout) if !self.state.is_failed() => {
+                renderer.with_layer(layout.bounds(), |renderer| {
+                    let result = self.state.catch(|| {
+                        self.content.draw(
+                            renderer,
+                            style,
+                            layout,
+                            cursor_position,
+                            viewport,
+                        )
+                    });
+
+                    // Drop whatever the content managed to draw before
+                    // panicking, since its layers may be left unbalanced
+                    if result.is_none() {
+                        renderer.clear();
+                    }
+                });
+            }
+            Child::Fallback(layout) => {
+                self.fallback.draw(
+                    renderer,
+                    style,
+                    layout,
+                    cursor_position,
+                    viewport,
+                );
+            }
+            _ => {}
+        }
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.state.is_failed().hash(state);
+
+        if self.state.is_failed() {
+            self.fallback.hash_layout(state);
+        } else {
+            self.content.hash_layout(state);
+        }
+    }
+
+    fn operate(
+        &mut self,
+        layout: Layout<'_>,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        match Child::of(layout) {
+            Child::Content(layout) if !self.state.is_failed() => {
+                let content = &mut self.content;
+
+                let _ = self.state.catch(|| {
+                    operation.container(None, &mut |operation| {
+                        content.operate(layout, operation);
+                    });
+                });
+            }
+            Child::Fallback(layout) => {
+                operation.container(None, &mut |operation| {
+                    self.fallback.operate(layout, operation);
+                });
+            }
+            _ => {}
+        }
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+        viewport: &Rectangle,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        match Child::of(layout) {
+            Child::Content(layout) if !self.state.is_failed() => {
+                self.content.overlay(layout, viewport)
+            }
+            Child::Fallback(layout) => self.fallback.overlay(layout, viewport),
+            _ => None,
+        }
+    }
+}
+
+impl<'a, Message, Renderer> From<ErrorBoundary<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + crate::Renderer,
+{
+    fn from(
+        error_boundary: ErrorBoundary<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(error_boundary)
+    }
+}
+
+/// The local state of an [`ErrorBoundary`].
+#[derive(Debug, Default)]
+pub struct State {
+    panic: RefCell<Option<String>>,
+    is_reported: Cell<bool>,
+}
+
+impl State {
+    /// Creates a new [`State`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the description of the panic caught by the [`ErrorBoundary`],
+    /// if any.
+    pub fn panic(&self) -> Option<String> {
+        self.panic.borrow().clone()
+    }
+
+    /// Forgets the panic caught by the [`ErrorBoundary`], so it tries to
+    /// display its content again.
+    pub fn reset(&mut self) {
+        *self.panic.get_mut() = None;
+        self.is_reported.set(false);
+    }
+
+    fn is_failed(&self) -> bool {
+        self.panic.borrow().is_some()
+    }
+
+    fn catch<T>(&self, f: impl FnOnce() -> T) -> Option<T> {
+        match panic::catch_unwind(AssertUnwindSafe(f)) {
+            Ok(value) => Some(value),
+            Err(payload) => {
+                let mut panic = self.panic.borrow_mut();
+
+                if panic.is_none() {
+                    *panic = Some(describe(payload.as_ref()));
+                }
+
+                None
+            }
+        }
+    }
+
+    fn unreported(&self) -> Option<String> {
+        if self.is_reported.get() {
+            return None;
+        }
+
+        let panic = self.panic()?;
+        self.is_reported.set(true);
+
+        Some(panic)
+    }
+}
+
+/// The child of an [`ErrorBoundary`] that was laid out.
+enum Child<'a> {
+    Content(Layout<'a>),
+    Fallback(Layout<'a>),
+}
+
+impl<'a> Child<'a> {
+    fn of(layout: Layout<'a>) -> Self {
+        let mut children = layout.children();
+        let first = children.next().expect("Get child layout");
+
+        match children.next() {
+            Some(fallback) => Child::Fallback(fallback),
+            None => Child::Content(first),
+        }
+    }
+}
+
+fn describe(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        String::from(*message)
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        String::from("unknown panic")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::simulator::Simulator;
+    use crate::{Background, Vector};
+
+    /// A renderer that draws nothing, but runs the draw logic of every layer,
+    /// unlike `renderer::Null`.
+    #[derive(Debug, Default)]
+    struct Layered;
+
+    impl crate::Renderer for Layered {
+        fn with_layer(
+            &mut self,
+            _bounds: Rectangle,
+            f: impl FnOnce(&mut Self),
+        ) {
+            f(self)
+        }
+
+        fn with_translation(
+            &mut self,
+            _translation: Vector,
+            f: impl FnOnce(&mut Self),
+        ) {
+            f(self)
+        }
+
+        fn with_opacity(&mut self, _opacity: f32, f: impl FnOnce(&mut Self)) {
+            f(self)
+        }
+
+        fn with_scale(
+            &mut self,
+            _origin: Point,
+            _scale: f32,
+            f: impl FnOnce(&mut Self),
+        ) {
+            f(self)
+        }
+
+        fn clear(&mut self) {}
+
+        fn fill_quad(
+            &mut self,
+            _quad: renderer::Quad,
+            _background: impl Into<Background>,
+        ) {
+        }
+    }
+
+    /// A widget that counts how many times it is drawn and panics at the
+    /// given stage, if any.
+    struct Probe<'a> {
+        draws: &'a Cell<usize>,
+        panic_on: Option<&'static str>,
+    }
+
+    impl<'a> Probe<'a> {
+        fn check(&self, stage: &'static str) {
+            if self.panic_on == Some(stage) {
+                panic!("{}", stage);
+            }
+        }
+    }
+
+    impl<'a> Widget<String, Layered> for Probe<'a> {
+        fn width(&self) -> Length {
+            Length::Fill
+        }
+
+        fn height(&self) -> Length {
+            Length::Fill
+        }
+
+        fn layout(
+            &self,
+            _renderer: &Layered,
+            limits: &layout::Limits,
+        ) -> layout::Node {
+            self.check("layout");
+
+            layout::Node::new(limits.max())
+        }
+
+        fn on_event(
+            &mut self,
+            _event: Event,
+            _layout: Layout<'_>,
+            _cursor_position: Point,
+            _renderer: &Layered,
+            _clipboard: &mut dyn Clipboard,
+            _shell: &mut Shell<'_, String>,
+        ) -> event::Status {
+            self.check("event");
+
+            event::Status::Ignored
+        }
+
+        fn draw(
+            &self,
+            _renderer: &mut Layered,
+            _style: &renderer::Style,
+            _layout: Layout<'_>,
+            _cursor_position: Point,
+            _viewport: &Rectangle,
+        ) {
+            self.check("draw");
+
+            self.draws.set(self.draws.get() + 1);
+        }
+
+        fn hash_layout(&self, state: &mut Hasher) {
+            self.panic_on.hash(state);
+        }
+    }
+
+    fn simulator<'a>(
+        state: &'a State,
+        content: &'a Cell<usize>,
+        fallback: &'a Cell<usize>,
+        panic_on: Option<&'static str>,
+    ) -> Simulator<'a, String, Layered> {
+        Simulator::new(
+            ErrorBoundary::new(
+                state,
+                Element::new(Probe {
+                    draws: content,
+                    panic_on,
+                }),
+                Element::new(Probe {
+                    draws: fallback,
+                    panic_on: None,
+                }),
+            )
+            .on_error(|panic| panic),
+            Size::new(100.0, 100.0),
+        )
+    }
+
+    #[test]
+    fn draws_the_content_until_it_panics() {
+        let state = State::new();
+        let (content, fallback) = (Cell::new(0), Cell::new(0));
+
+        let mut simulator = simulator(&state, &content, &fallback, None);
+        let _ = simulator.point_at(Point::new(10.0, 10.0));
+        let _ = simulator.draw();
+
+        assert_eq!((content.get(), fallback.get()), (1, 0));
+        assert!(simulator.messages().is_empty());
+        assert_eq!(state.panic(), None);
+    }
+
+    #[test]
+    fn draws_the_fallback_when_the_layout_panics() {
+        let state = State::new();
+        let (content, fallback) = (Cell::new(0), Cell::new(0));
+
+        let mut simulator =
+            simulator(&state, &content, &fallback, Some("layout"));
+        let _ = simulator.draw();
+
+        assert_eq!((content.get(), fallback.get()), (0, 1));
+        assert_eq!(state.panic().as_deref(), Some("layout"));
+    }
+
+    #[test]
+    fn reports_panics_while_handling_events() {
+        let state = State::new();
+        let (content, fallback) = (Cell::new(0), Cell::new(0));
+
+        let mut simulator =
+            simulator(&state, &content, &fallback, Some("event"));
+        let _ = simulator.point_at(Point::new(10.0, 10.0));
+        let _ = simulator.point_at(Point::new(20.0, 20.0));
+        let _ = simulator.draw();
+
+        assert_eq!((content.get(), fallback.get()), (0, 1));
+        assert_eq!(simulator.into_messages(), vec![String::from("event")]);
+    }
+
+    #[test]
+    fn draws_the_content_again_once_reset() {
+        let mut state = State::new();
+        let (content, fallback) = (Cell::new(0), Cell::new(0));
+
+        let _ = simulator(&state, &content, &fallback, Some("draw")).draw();
+
+        assert_eq!(state.panic().as_deref(), Some("draw"));
+
+        state.reset();
+
+        let _ = simulator(&state, &content, &fallback, None).draw();
+
+        assert_eq!((content.get(), fallback.get()), (1, 0));
+        assert_eq!(state.panic(), None);
+    }
+}
@@ -0,0 +1,332 @@
+//! Coordinate the inputs of a form, validating them and submitting them
+//! together.
+//!
+//! The value of each input of a form is kept in a [`Field`], which parses
+//! it, runs its validators, and tracks whether it has been changed and
+//! touched by the user. The error of a [`Field`] can be displayed next to
+//! its input as soon as the user touches it.
+//!
+//! A [`Form`] wraps the inputs and produces a message when the enter key is
+//! pressed while any of them is focused. When handling it, calling
+//! [`Field::submit`] on every field marks all of them as touched and
+//! returns their typed values, which can be combined into a single output
+//! once all of them are valid.
+use crate::event::{self, Event};
+use crate::keyboard;
+use crate::layout;
+use crate::mouse;
+use crate::overlay;
+use crate::renderer;
+use crate::widget::operation::{self, Operation};
+use crate::{
+    Clipboard, Element, Hasher, Layout, Length, Point, Rectangle, Shell, Widget,
+};
+
+use std::fmt;
+use std::hash::Hash;
+use std::str::FromStr;
+
+/// A widget that submits the inputs inside of its content when the enter
+/// key is pressed.
+///
+/// Inputs that handle the enter key on their own, like a [`TextInput`] with
+/// an `on_submit` message, take precedence over the [`Form`].
+///
+/// [`TextInput`]: crate::widget::TextInput
+#[allow(missing_debug_implementations)]
+pub struct Form<'a, Message, Renderer> {
+    content: Element<'a, Message, Renderer>,
+    on_submit: Option<Message>,
+}
+
+impl<'a, Message, Renderer> Form<'a, Message, Renderer> {
+    /// Creates a new [`Form`] with the given content.
+    pub fn new(content: impl Into<Element<'a, Message, Renderer>>) -> Self {
+        Form {
+            content: content.into(),
+            on_submit: None,
+        }
+    }
+
+    /// Sets the message that should be produced when the [`Form`] is
+    /// submitted.
+    ///
+    /// If this is never called, the [`Form`] ignores the enter key.
+    pub fn on_submit(mut self, message: Message) -> Self {
+        self.on_submit = Some(message);
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Form<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: crate::Renderer,
+{
+    fn width(&self) -> Length {
+        self.content.width()
+    }
+
+    fn height(&self) -> Length {
+        self.content.height()
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content.layout(renderer, limits)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let status = self.content.on_event(
+            event.clone(),
+            layout,
+            cursor_position,
+            renderer,
+            clipboard,
+            shell,
+        );
+
+        if status == event::Status::Captured {
+            return status;
+        }
+
+        if let Event::Keyboard(keyboard::Event::KeyPressed {
+            key_code: keyboard::KeyCode::Enter | keyboard::KeyCode::NumpadEnter,
+            ..
+        }) = event
+        {
+            if let Some(on_submit) = self.on_submit.clone() {
+                if operation::focusable::has_focus(&mut self.content, layout) {
+                    shell.publish(on_submit);
+
+                    return event::Status::Captured;
+                }
+            }
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) -> mouse::Interaction {
+        self.content
+            .mouse_interaction(layout, cursor_position, viewport)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        self.content
+            .draw(renderer, style, layout, cursor_position, viewport);
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.content.hash_layout(state);
+    }
+
+    fn operate(
+        &mut self,
+        layout: Layout<'_>,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        operation.container(None, &mut |operation| {
+            self.content.operate(layout, operation);
+        });
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+        viewport: &Rectangle,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        self.content.overlay(layout, viewport)
+    }
+}
+
+impl<'a, Message, Renderer> From<Form<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + crate::Renderer,
+{
+    fn from(
+        form: Form<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(form)
+    }
+}
+
+type Parser<T> = dyn Fn(&str) -> Result<T, String>;
+type Validator<T> = dyn Fn(&T) -> Result<(), String>;
+
+/// The value of an input of a [`Form`], parsed into a `T`.
+pub struct Field<T> {
+    input: String,
+    initial: String,
+    parser: Box<Parser<T>>,
+    validators: Vec<Box<Validator<T>>>,
+    is_touched: bool,
+}
+
+impl<T> Field<T> {
+    /// Creates a new [`Field`] with the given initial input, parsed with
+    /// the [`FromStr`] implementation of `T`.
+    pub fn new(initial: impl Into<String>) -> Self
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        Self::with_parser(initial, |input| {
+            input.parse().map_err(|error: T::Err| error.to_string())
+        })
+    }
+
+    /// Creates a new [`Field`] with the given initial input and parser.
+    ///
+    /// The parser returns the message to display when the input is invalid.
+    pub fn with_parser(
+        initial: impl Into<String>,
+        parser: impl Fn(&str) -> Result<T, String> + 'static,
+    ) -> Self {
+        let initial = initial.into();
+
+        Field {
+            input: initial.clone(),
+            initial,
+            parser: Box::new(parser),
+            validators: Vec::new(),
+            is_touched: false,
+        }
+    }
+
+    /// Adds a validator to the [`Field`].
+    ///
+    /// The validator runs on the parsed value and returns the message to
+    /// display when it is invalid. Validators run in the order they were
+    /// added, and the first error is displayed.
+    pub fn validator(
+        mut self,
+        validator: impl Fn(&T) -> Result<(), String> + 'static,
+    ) -> Self {
+        self.validators.push(Box::new(validator));
+        self
+    }
+
+    /// Returns the current input of the [`Field`].
+    pub fn input(&self) -> &str {
+        &self.input
+    }
+
+    /// Replaces the input of the [`Field`], marking it as touched.
+    ///
+    /// Call it when the input widget of the [`Field`] changes.
+    pub fn set_input(&mut self, input: impl Into<String>) {
+        self.input = input.into();
+        self.is_touched = true;
+    }
+
+    /// Marks the [`Field`] as touched, so its error is displayed.
+    ///
+    /// This is useful when the user leaves the input of the [`Field`]
+    /// without changing it.
+    pub fn touch(&mut self) {
+        self.is_touched = true;
+    }
+
+    /// Returns whether the user has touched the [`Field`].
+    pub fn is_touched(&self) -> bool {
+        self.is_touched
+    }
+
+    /// Returns whether the input of the [`Field`] differs from its initial
+    /// input.
+    pub fn is_dirty(&self) -> bool {
+        self.input != self.initial
+    }
+
+    /// Parses and validates the input of the [`Field`].
+    pub fn validate(&self) -> Result<T, String> {
+        let value = (self.parser)(&self.input)?;
+
+        for validator in &self.validators {
+            validator(&value)?;
+        }
+
+        Ok(value)
+    }
+
+    /// Returns whether the input of the [`Field`] is valid.
+    pub fn is_valid(&self) -> bool {
+        self.validate().is_ok()
+    }
+
+    /// Returns the error to display for the [`Field`], if any.
+    ///
+    /// Errors are only displayed once the [`Field`] has been touched.
+    pub fn error(&self) -> Option<String> {
+        if self.is_touched {
+            self.validate().err()
+        } else {
+            None
+        }
+    }
+
+    /// Marks the [`Field`] as touched and returns its value, if valid.
+    ///
+    /// Call it for every [`Field`] of a [`Form`] when it is submitted, so
+    /// the errors of all the invalid fields are displayed at once.
+    pub fn submit(&mut self) -> Option<T> {
+        self.is_touched = true;
+        self.validate().ok()
+    }
+
+    /// Makes the current input of the [`Field`] its initial input, so it is
+    /// no longer dirty nor touched.
+    ///
+    /// This is useful after a successful submission.
+    pub fn commit(&mut self) {
+        self.initial = self.input.clone();
+        self.is_touched = false;
+    }
+
+    /// Restores the initial input of the [`Field`], so it is no longer dirty
+    /// nor touched.
+    pub fn reset(&mut self) {
+        self.input = self.initial.clone();
+        self.is_touched = false;
+    }
+}
+
+impl<T> fmt::Debug for Field<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Field")
+            .field("input", &self.input)
+            .field("initial", &self.initial)
+            .field("is_touched", &self.is_touched)
+            .finish()
+    }
+}
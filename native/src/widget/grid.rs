@@ -0,0 +1,504 @@
+//! Distribute content in rows and columns.
+use std::hash::Hash;
+
+use crate::event::{self, Event};
+use crate::layout;
+use crate::mouse;
+use crate::overlay;
+use crate::renderer;
+use crate::widget::Operation;
+use crate::{
+    Clipboard, Element, Hasher, Layout, Length, Padding, Point, Rectangle,
+    Shell, Size, Widget,
+};
+
+/// A container that places its contents in the cells of a grid.
+///
+/// The size of every row and column of the [`Grid`] is given by a [`Length`]:
+///
+/// - [`Length::Units`] tracks have a fixed size.
+/// - [`Length::Shrink`] tracks fit the contents of their cells.
+/// - [`Length::Fill`] and [`Length::FillPortion`] tracks share the
+///   remaining space proportionally.
+///
+/// Rows and columns without an explicit size shrink to fit their contents.
+#[allow(missing_debug_implementations)]
+pub struct Grid<'a, Message, Renderer> {
+    rows: Vec<Length>,
+    columns: Vec<Length>,
+    row_spacing: u16,
+    column_spacing: u16,
+    padding: Padding,
+    width: Length,
+    height: Length,
+    cells: Vec<Cell<'a, Message, Renderer>>,
+}
+
+impl<'a, Message, Renderer> Grid<'a, Message, Renderer> {
+    /// Creates an empty [`Grid`].
+    pub fn new() -> Self {
+        Self::with_cells(Vec::new())
+    }
+
+    /// Creates a [`Grid`] with the given cells.
+    pub fn with_cells(cells: Vec<Cell<'a, Message, Renderer>>) -> Self {
+        Grid {
+            rows: Vec::new(),
+            columns: Vec::new(),
+            row_spacing: 0,
+            column_spacing: 0,
+            padding: Padding::ZERO,
+            width: Length::Shrink,
+            height: Length::Shrink,
+            cells,
+        }
+    }
+
+    /// Sets the sizes of the rows of the [`Grid`].
+    pub fn rows(mut self, rows: Vec<Length>) -> Self {
+        self.rows = rows;
+        self
+    }
+
+    /// Sets the sizes of the columns of the [`Grid`].
+    pub fn columns(mut self, columns: Vec<Length>) -> Self {
+        self.columns = columns;
+        self
+    }
+
+    /// Sets the spacing _between_ both the rows and the columns of the
+    /// [`Grid`].
+    pub fn spacing(mut self, units: u16) -> Self {
+        self.row_spacing = units;
+        self.column_spacing = units;
+        self
+    }
+
+    /// Sets the vertical spacing _between_ the rows of the [`Grid`].
+    pub fn row_spacing(mut self, units: u16) -> Self {
+        self.row_spacing = units;
+        self
+    }
+
+    /// Sets the horizontal spacing _between_ the columns of the [`Grid`].
+    pub fn column_spacing(mut self, units: u16) -> Self {
+        self.column_spacing = units;
+        self
+    }
+
+    /// Sets the [`Padding`] of the [`Grid`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the width of the [`Grid`].
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`Grid`].
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Adds a [`Cell`] to the [`Grid`].
+    pub fn push(mut self, cell: Cell<'a, Message, Renderer>) -> Self {
+        self.cells.push(cell);
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Default for Grid<'a, Message, Renderer> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// An element placed in a [`Grid`], spanning one or more rows and columns.
+#[allow(missing_debug_implementations)]
+pub struct Cell<'a, Message, Renderer> {
+    row: usize,
+    column: usize,
+    row_span: usize,
+    column_span: usize,
+    content: Element<'a, Message, Renderer>,
+}
+
+impl<'a, Message, Renderer> Cell<'a, Message, Renderer> {
+    /// Creates a new [`Cell`] placing the given content in the given row and
+    /// column.
+    pub fn new(
+        row: usize,
+        column: usize,
+        content: impl Into<Element<'a, Message, Renderer>>,
+    ) -> Self {
+        Cell {
+            row,
+            column,
+            row_span: 1,
+            column_span: 1,
+            content: content.into(),
+        }
+    }
+
+    /// Sets the amount of rows the [`Cell`] spans.
+    pub fn row_span(mut self, rows: usize) -> Self {
+        self.row_span = rows.max(1);
+        self
+    }
+
+    /// Sets the amount of columns the [`Cell`] spans.
+    pub fn column_span(mut self, columns: usize) -> Self {
+        self.column_span = columns.max(1);
+        self
+    }
+}
+
+/// Computes the size of every track of a [`Grid`] along a single axis.
+///
+/// `spans` contains the first track, the amount of tracks and the size of the
+/// contents of every cell.
+fn resolve_tracks(
+    tracks: &[Length],
+    count: usize,
+    spacing: f32,
+    available: f32,
+    spans: &[(usize, usize, f32)],
+) -> Vec<f32> {
+    let track = |i: usize| tracks.get(i).copied().unwrap_or(Length::Shrink);
+
+    let mut sizes: Vec<f32> = (0..count)
+        .map(|i| match track(i) {
+            Length::Units(units) => f32::from(units),
+            _ => 0.0,
+        })
+        .collect();
+
+    let fits_content = |i: usize| {
+        track(i) == Length::Shrink
+            || (track(i).fill_factor() > 0 && !available.is_finite())
+    };
+
+    // Fit single tracks first, so contents spanning many tracks only grow
+    // them when strictly necessary.
+    let mut spans: Vec<_> = spans.to_vec();
+    spans.sort_by_key(|(_, span, _)| *span);
+
+    for (start, span, size) in spans {
+        let range = start..start + span;
+
+        let current = sizes[range.clone()].iter().sum::<f32>()
+            + spacing * (span - 1) as f32;
+
+        let growable: Vec<usize> = range.filter(|i| fits_content(*i)).collect();
+
+        if size > current && !growable.is_empty() {
+            let extra = (size - current) / growable.len() as f32;
+
+            for i in growable {
+                sizes[i] += extra;
+            }
+        }
+    }
+
+    let portions: u16 = (0..count)
+        .filter(|i| !fits_content(*i))
+        .map(|i| track(i).fill_factor())
+        .sum();
+
+    if portions > 0 {
+        let used = sizes.iter().sum::<f32>()
+            + spacing * count.saturating_sub(1) as f32;
+        let remaining = (available - used).max(0.0);
+
+        for (i, size) in sizes.iter_mut().enumerate() {
+            if !fits_content(i) {
+                *size = remaining * f32::from(track(i).fill_factor())
+                    / f32::from(portions);
+            }
+        }
+    }
+
+    sizes
+}
+
+/// Returns the offset and length of the given range of tracks.
+fn span_bounds(
+    sizes: &[f32],
+    spacing: f32,
+    start: usize,
+    span: usize,
+) -> (f32, f32) {
+    let offset = sizes[..start].iter().sum::<f32>() + spacing * start as f32;
+    let length = sizes[start..start + span].iter().sum::<f32>()
+        + spacing * span.saturating_sub(1) as f32;
+
+    (offset, length)
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Grid<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits
+            .width(self.width)
+            .height(self.height)
+            .pad(self.padding);
+
+        let max = limits.max();
+        let row_spacing = f32::from(self.row_spacing);
+        let column_spacing = f32::from(self.column_spacing);
+
+        let row_count = self
+            .cells
+            .iter()
+            .map(|cell| cell.row + cell.row_span)
+            .max()
+            .unwrap_or(0)
+            .max(self.rows.len());
+
+        let column_count = self
+            .cells
+            .iter()
+            .map(|cell| cell.column + cell.column_span)
+            .max()
+            .unwrap_or(0)
+            .max(self.columns.len());
+
+        let loose = layout::Limits::new(Size::ZERO, max);
+
+        let column_spans: Vec<_> = self
+            .cells
+            .iter()
+            .map(|cell| {
+                let size = cell.content.layout(renderer, &loose).size();
+
+                (cell.column, cell.column_span, size.width)
+            })
+            .collect();
+
+        let columns = resolve_tracks(
+            &self.columns,
+            column_count,
+            column_spacing,
+            if self.width == Length::Shrink {
+                f32::INFINITY
+            } else {
+                max.width
+            },
+            &column_spans,
+        );
+
+        let row_spans: Vec<_> = self
+            .cells
+            .iter()
+            .map(|cell| {
+                let (_, width) = span_bounds(
+                    &columns,
+                    column_spacing,
+                    cell.column,
+                    cell.column_span,
+                );
+
+                let limits = layout::Limits::new(
+                    Size::ZERO,
+                    Size::new(width, max.height),
+                );
+
+                let size = cell.content.layout(renderer, &limits).size();
+
+                (cell.row, cell.row_span, size.height)
+            })
+            .collect();
+
+        let rows = resolve_tracks(
+            &self.rows,
+            row_count,
+            row_spacing,
+            if self.height == Length::Shrink {
+                f32::INFINITY
+            } else {
+                max.height
+            },
+            &row_spans,
+        );
+
+        let children = self
+            .cells
+            .iter()
+            .map(|cell| {
+                let (x, width) = span_bounds(
+                    &columns,
+                    column_spacing,
+                    cell.column,
+                    cell.column_span,
+                );
+
+                let (y, height) =
+                    span_bounds(&rows, row_spacing, cell.row, cell.row_span);
+
+                let limits =
+                    layout::Limits::new(Size::ZERO, Size::new(width, height));
+
+                let mut node = cell.content.layout(renderer, &limits);
+
+                node.move_to(Point::new(
+                    x + f32::from(self.padding.left),
+                    y + f32::from(self.padding.top),
+                ));
+
+                node
+            })
+            .collect();
+
+        let content_size = Size::new(
+            span_bounds(&columns, column_spacing, 0, column_count).1,
+            span_bounds(&rows, row_spacing, 0, row_count).1,
+        );
+
+        let size = limits.resolve(content_size).pad(self.padding);
+
+        layout::Node::with_children(size, children)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        self.cells
+            .iter_mut()
+            .zip(layout.children())
+            .map(|(cell, layout)| {
+                cell.content.on_event(
+                    event.clone(),
+                    layout,
+                    cursor_position,
+                    renderer,
+                    clipboard,
+                    shell,
+                )
+            })
+            .fold(event::Status::Ignored, event::Status::merge)
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) -> mouse::Interaction {
+        self.cells
+            .iter()
+            .zip(layout.children())
+            .map(|(cell, layout)| {
+                cell.content.mouse_interaction(
+                    layout,
+                    cursor_position,
+                    viewport,
+                )
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        for (cell, layout) in self.cells.iter().zip(layout.children()) {
+            cell.content.draw(
+                renderer,
+                style,
+                layout,
+                cursor_position,
+                viewport,
+            );
+        }
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.rows.hash(state);
+        self.columns.hash(state);
+        self.row_spacing.hash(state);
+        self.column_spacing.hash(state);
+        self.padding.hash(state);
+        self.width.hash(state);
+        self.height.hash(state);
+
+        for cell in &self.cells {
+            cell.row.hash(state);
+            cell.column.hash(state);
+            cell.row_span.hash(state);
+            cell.column_span.hash(state);
+            cell.content.hash_layout(state);
+        }
+    }
+
+    fn operate(
+        &mut self,
+        layout: Layout<'_>,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        operation.container(None, &mut |operation| {
+            self.cells.iter_mut().zip(layout.children()).for_each(
+                |(cell, layout)| {
+                    cell.content.operate(layout, operation);
+                },
+            )
+        });
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+        viewport: &Rectangle,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        overlay::from_children(
+            self.cells.iter_mut().zip(layout.children()).filter_map(
+                |(cell, layout)| cell.content.overlay(layout, viewport),
+            ),
+        )
+    }
+}
+
+impl<'a, Message, Renderer> From<Grid<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + crate::Renderer,
+    Message: 'a,
+{
+    fn from(
+        grid: Grid<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(grid)
+    }
+}
@@ -4,12 +4,14 @@ use crate::image;
 use crate::layout;
 use crate::mouse;
 use crate::renderer;
+use crate::touch;
 use crate::{
     Clipboard, Element, Hasher, Layout, Length, Point, Rectangle, Shell, Size,
     Vector, Widget,
 };
 
 use std::hash::Hash;
+use std::time::{Duration, Instant};
 
 /// A frame that displays an image with the ability to zoom in/out and pan.
 #[allow(missing_debug_implementations)]
@@ -21,6 +23,7 @@ pub struct Viewer<'a, Handle> {
     min_scale: f32,
     max_scale: f32,
     scale_step: f32,
+    double_tap_scale: f32,
     handle: Handle,
 }
 
@@ -35,6 +38,7 @@ impl<'a, Handle> Viewer<'a, Handle> {
             min_scale: 0.25,
             max_scale: 10.0,
             scale_step: 0.10,
+            double_tap_scale: 2.0,
             handle,
         }
     }
@@ -82,6 +86,17 @@ impl<'a, Handle> Viewer<'a, Handle> {
         self
     }
 
+    /// Sets the scale the image of the [`Viewer`] will be zoomed to when it
+    /// is double-tapped on a touch screen.
+    ///
+    /// Double-tapping a zoomed image will reset its scale to `1.0`.
+    ///
+    /// Default is `2.0`
+    pub fn double_tap_scale(mut self, double_tap_scale: f32) -> Self {
+        self.double_tap_scale = double_tap_scale;
+        self
+    }
+
     /// Returns the bounds of the underlying image, given the bounds of
     /// the [`Viewer`]. Scaling will be applied and original aspect ratio
     /// will be respected.
@@ -110,6 +125,78 @@ impl<'a, Handle> Viewer<'a, Handle> {
 
         Size::new(width, height)
     }
+
+    /// Scales the image of the [`Viewer`] to the given scale, keeping the
+    /// provided `focus` point steady.
+    fn scale_around<Renderer>(
+        &mut self,
+        renderer: &Renderer,
+        bounds: Rectangle,
+        scale: f32,
+        focus: Point,
+    ) where
+        Renderer: image::Renderer<Handle = Handle>,
+    {
+        let previous_scale = self.state.scale;
+
+        self.state.scale = scale.max(self.min_scale).min(self.max_scale);
+
+        let image_size = self.image_size(renderer, bounds.size());
+
+        let factor = self.state.scale / previous_scale - 1.0;
+
+        let focus_to_center = focus - bounds.center();
+
+        let adjustment =
+            focus_to_center * factor + self.state.current_offset * factor;
+
+        self.state.current_offset = Vector::new(
+            if image_size.width > bounds.width {
+                self.state.current_offset.x + adjustment.x
+            } else {
+                0.0
+            },
+            if image_size.height > bounds.height {
+                self.state.current_offset.y + adjustment.y
+            } else {
+                0.0
+            },
+        );
+    }
+
+    /// Clamps the given offset so the image of the [`Viewer`] cannot be
+    /// panned out of its bounds.
+    fn clamp_offset<Renderer>(
+        &self,
+        renderer: &Renderer,
+        bounds: Rectangle,
+        offset: Vector,
+    ) -> Vector
+    where
+        Renderer: image::Renderer<Handle = Handle>,
+    {
+        let image_size = self.image_size(renderer, bounds.size());
+
+        let hidden_width =
+            (image_size.width - bounds.width / 2.0).max(0.0).round();
+
+        let hidden_height =
+            (image_size.height - bounds.height / 2.0).max(0.0).round();
+
+        let x = if bounds.width < image_size.width {
+            offset.x.min(hidden_width).max(-hidden_width)
+        } else {
+            0.0
+        };
+
+        let y = if bounds.height < image_size.height {
+            offset.y.min(hidden_height).max(-hidden_height)
+        } else {
+            0.0
+        };
+
+        Vector::new(x, y)
+    }
 }
 
 impl<'a, Message, Renderer, Handle> Widget<Message, Renderer>
@@ -186,37 +273,17 @@ where
                         if y < 0.0 && previous_scale > self.min_scale
                             || y > 0.0 && previous_scale < self.max_scale
                         {
-                            self.state.scale = (if y > 0.0 {
-                                self.state.scale * (1.0 + self.scale_step)
+                            let scale = if y > 0.0 {
+                                previous_scale * (1.0 + self.scale_step)
                             } else {
-                                self.state.scale / (1.0 + self.scale_step)
-                            })
-                            .max(self.min_scale)
-                            .min(self.max_scale);
-
-                            let image_size =
-                                self.image_size(renderer, bounds.size());
-
-                            let factor =
-                                self.state.scale / previous_scale - 1.0;
-
-                            let cursor_to_center =
-                                cursor_position - bounds.center();
-
-                            let adjustment = cursor_to_center * factor
-                                + self.state.current_offset * factor;
-
-                            self.state.current_offset = Vector::new(
-                                if image_size.width > bounds.width {
-                                    self.state.current_offset.x + adjustment.x
-                                } else {
-                                    0.0
-                                },
-                                if image_size.height > bounds.height {
-                                    self.state.current_offset.y + adjustment.y
-                                } else {
-                                    0.0
-                                },
+                                previous_scale / (1.0 + self.scale_step)
+                            };
+
+                            self.scale_around(
+                                renderer,
+                                bounds,
+                                scale,
+                                cursor_position,
                             );
                         }
                     }
@@ -241,41 +308,150 @@ where
             }
             Event::Mouse(mouse::Event::CursorMoved { position }) => {
                 if let Some(origin) = self.state.cursor_grabbed_at {
-                    let image_size = self.image_size(renderer, bounds.size());
+                    let delta = position - origin;
 
-                    let hidden_width = (image_size.width - bounds.width / 2.0)
-                        .max(0.0)
-                        .round();
+                    self.state.current_offset = self.clamp_offset(
+                        renderer,
+                        bounds,
+                        self.state.starting_offset - delta,
+                    );
 
-                    let hidden_height = (image_size.height
-                        - bounds.height / 2.0)
-                        .max(0.0)
-                        .round();
+                    event::Status::Captured
+                } else {
+                    event::Status::Ignored
+                }
+            }
+            Event::Touch(touch::Event::FingerPressed { id, position })
+                if bounds.contains(position) =>
+            {
+                if self.state.fingers[0].is_none() {
+                    let is_double_tap = matches!(
+                        self.state.last_tap,
+                        Some(tap) if tap.is_followed_by(position, Instant::now())
+                    );
 
-                    let delta = position - origin;
+                    self.state.fingers[0] = Some((id, position));
 
-                    let x = if bounds.width < image_size.width {
-                        (self.state.starting_offset.x - delta.x)
-                            .min(hidden_width)
-                            .max(-hidden_width)
-                    } else {
-                        0.0
-                    };
+                    if is_double_tap {
+                        let scale = if self.state.scale > 1.0 {
+                            1.0
+                        } else {
+                            self.double_tap_scale
+                        };
+
+                        self.scale_around(renderer, bounds, scale, position);
 
-                    let y = if bounds.height < image_size.height {
-                        (self.state.starting_offset.y - delta.y)
-                            .min(hidden_height)
-                            .max(-hidden_height)
+                        self.state.last_tap = None;
+                        self.state.tap_origin = None;
                     } else {
-                        0.0
-                    };
+                        self.state.tap_origin = Some(position);
+                    }
 
-                    self.state.current_offset = Vector::new(x, y);
+                    self.state.cursor_grabbed_at = Some(position);
+                    self.state.starting_offset = self.state.current_offset;
+                } else if self.state.fingers[1].is_none() {
+                    self.state.fingers[1] = Some((id, position));
+                    self.state.pinch = self.state.pinch_from_fingers();
+                    self.state.cursor_grabbed_at = None;
+                    self.state.tap_origin = None;
+                }
 
-                    event::Status::Captured
+                event::Status::Captured
+            }
+            Event::Touch(touch::Event::FingerMoved { id, position }) => {
+                let finger = self
+                    .state
+                    .fingers
+                    .iter_mut()
+                    .flatten()
+                    .find(|(finger, _)| *finger == id);
+
+                if let Some((_, finger_position)) = finger {
+                    *finger_position = position;
                 } else {
-                    event::Status::Ignored
+                    return event::Status::Ignored;
+                }
+
+                if let Some(origin) = self.state.tap_origin {
+                    if origin.distance(position) > TAP_TOLERANCE {
+                        self.state.tap_origin = None;
+                    }
+                }
+
+                if let (Some(pinch), Some(current)) =
+                    (self.state.pinch, self.state.pinch_from_fingers())
+                {
+                    if pinch.distance > 0.0 {
+                        let scale =
+                            pinch.scale * current.distance / pinch.distance;
+
+                        self.scale_around(
+                            renderer,
+                            bounds,
+                            scale,
+                            current.centroid,
+                        );
+                    }
+
+                    let pan = current.centroid - self.state.last_centroid;
+
+                    self.state.current_offset = self.clamp_offset(
+                        renderer,
+                        bounds,
+                        self.state.current_offset - pan,
+                    );
+
+                    self.state.last_centroid = current.centroid;
+                } else if let Some(origin) = self.state.cursor_grabbed_at {
+                    let delta = position - origin;
+
+                    self.state.current_offset = self.clamp_offset(
+                        renderer,
+                        bounds,
+                        self.state.starting_offset - delta,
+                    );
+                }
+
+                event::Status::Captured
+            }
+            Event::Touch(touch::Event::FingerLifted { id, position })
+            | Event::Touch(touch::Event::FingerLost { id, position }) => {
+                let index = self.state.fingers.iter().position(
+                    |finger| matches!(finger, Some((finger, _)) if *finger == id),
+                );
+
+                let index = match index {
+                    Some(index) => index,
+                    None => return event::Status::Ignored,
+                };
+
+                self.state.fingers[index] = None;
+                self.state.fingers.sort_by_key(Option::is_none);
+
+                if self.state.pinch.take().is_some() {
+                    // Keep panning with the remaining finger, if any
+                    if let Some((_, remaining)) = self.state.fingers[0] {
+                        self.state.cursor_grabbed_at = Some(remaining);
+                        self.state.starting_offset = self.state.current_offset;
+                    }
+                } else {
+                    self.state.cursor_grabbed_at = None;
+
+                    if let (
+                        Event::Touch(touch::Event::FingerLifted { .. }),
+                        Some(origin),
+                    ) = (&event, self.state.tap_origin.take())
+                    {
+                        if origin.distance(position) <= TAP_TOLERANCE {
+                            self.state.last_tap = Some(Tap {
+                                position,
+                                time: Instant::now(),
+                            });
+                        }
+                    }
                 }
+
+                event::Status::Captured
             }
             _ => event::Status::Ignored,
         }
@@ -354,6 +530,11 @@ pub struct State {
     starting_offset: Vector,
     current_offset: Vector,
     cursor_grabbed_at: Option<Point>,
+    fingers: [Option<(touch::Finger, Point)>; 2],
+    pinch: Option<Pinch>,
+    last_centroid: Point,
+    tap_origin: Option<Point>,
+    last_tap: Option<Tap>,
 }
 
 impl Default for State {
@@ -363,6 +544,11 @@ impl Default for State {
             starting_offset: Vector::default(),
             current_offset: Vector::default(),
             cursor_grabbed_at: None,
+            fingers: [None; 2],
+            pinch: None,
+            last_centroid: Point::ORIGIN,
+            tap_origin: None,
+            last_tap: None,
         }
     }
 }
@@ -392,6 +578,61 @@ impl State {
     pub fn is_cursor_grabbed(&self) -> bool {
         self.cursor_grabbed_at.is_some()
     }
+
+    /// Returns the [`Pinch`] formed by the two tracked fingers, if any.
+    ///
+    /// It also records the centroid of the fingers, so that subsequent
+    /// movements can pan the image.
+    fn pinch_from_fingers(&mut self) -> Option<Pinch> {
+        match self.fingers {
+            [Some((_, a)), Some((_, b))] => {
+                let pinch = Pinch {
+                    distance: a.distance(b),
+                    scale: self.scale,
+                    centroid: Point::new((a.x + b.x) / 2.0, (a.y + b.y) / 2.0),
+                };
+
+                if self.pinch.is_none() {
+                    self.last_centroid = pinch.centroid;
+                }
+
+                Some(pinch)
+            }
+            _ => None,
+        }
+    }
+}
+
+/// The maximum distance a finger can travel for a touch to count as a tap.
+const TAP_TOLERANCE: f32 = 20.0;
+
+/// The maximum time allowed between two taps of a double tap.
+const DOUBLE_TAP_DELAY: Duration = Duration::from_millis(300);
+
+/// An on-going two-finger gesture, captured when the second finger touches
+/// the [`Viewer`].
+#[derive(Debug, Clone, Copy)]
+struct Pinch {
+    distance: f32,
+    scale: f32,
+    centroid: Point,
+}
+
+/// A completed tap on the [`Viewer`].
+#[derive(Debug, Clone, Copy)]
+struct Tap {
+    position: Point,
+    time: Instant,
+}
+
+impl Tap {
+    fn is_followed_by(&self, position: Point, time: Instant) -> bool {
+        self.position.distance(position) <= TAP_TOLERANCE
+            && time
+                .checked_duration_since(self.time)
+                .map(|duration| duration <= DOUBLE_TAP_DELAY)
+                .unwrap_or(false)
+    }
 }
 
 impl<'a, Message, Renderer, Handle> From<Viewer<'a, Handle>>
@@ -0,0 +1,695 @@
+//! Inspect the widgets of a user interface while it runs.
+//!
+//! An [`Inspector`] has some local [`State`].
+use std::hash::Hash;
+
+use crate::alignment;
+use crate::event::{self, Event};
+use crate::layout;
+use crate::mouse;
+use crate::overlay;
+use crate::renderer;
+use crate::text;
+use crate::touch;
+use crate::widget::{Id, Operation};
+use crate::{
+    Background, Clipboard, Color, Element, Hasher, Layout, Length, Point,
+    Rectangle, Shell, Vector, Widget,
+};
+
+const PANEL_WIDTH: f32 = 320.0;
+const PANEL_PADDING: f32 = 8.0;
+const LINE_HEIGHT: f32 = 18.0;
+const TEXT_SIZE: f32 = 14.0;
+const DETAILS: usize = 7;
+
+/// A widget that lets users inspect the widgets of its content, like the
+/// developer tools of a web browser.
+///
+/// While enabled, hovering highlights the widget under the cursor together
+/// with its padding, and clicking selects it. A panel shows the details of the
+/// selected widget and a tree view of the whole hierarchy, whose rows can be
+/// hovered and clicked as well.
+///
+/// Any mouse button or touch interaction is captured by the [`Inspector`]
+/// while enabled.
+#[allow(missing_debug_implementations)]
+pub struct Inspector<'a, Message, Renderer> {
+    state: &'a mut State,
+    content: Element<'a, Message, Renderer>,
+    is_enabled: bool,
+}
+
+impl<'a, Message, Renderer> Inspector<'a, Message, Renderer> {
+    /// Creates a new [`Inspector`] with the given [`State`] and content.
+    ///
+    /// The [`Inspector`] is enabled by default.
+    pub fn new(
+        state: &'a mut State,
+        content: impl Into<Element<'a, Message, Renderer>>,
+    ) -> Self {
+        Inspector {
+            state,
+            content: content.into(),
+            is_enabled: true,
+        }
+    }
+
+    /// Sets whether the [`Inspector`] is enabled.
+    ///
+    /// A disabled [`Inspector`] is invisible and lets every event through.
+    pub fn enabled(mut self, is_enabled: bool) -> Self {
+        self.is_enabled = is_enabled;
+        self
+    }
+}
+
+/// The local state of an [`Inspector`].
+#[derive(Debug, Clone, Default)]
+pub struct State {
+    hovered: Option<Vec<usize>>,
+    selected: Option<Vec<usize>>,
+}
+
+impl State {
+    /// Creates a new [`State`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the path of the selected [`Node`] in the widget tree, if any.
+    ///
+    /// A path contains the index of every child to follow from the root.
+    pub fn selected(&self) -> Option<&[usize]> {
+        self.selected.as_deref()
+    }
+
+    /// Clears the selection of the [`State`].
+    pub fn unselect(&mut self) {
+        self.selected = None;
+    }
+}
+
+/// The description of a widget and its children in an [`Inspector`].
+///
+/// A [`Node`] is produced by [`Widget::inspect`].
+#[derive(Debug, Clone)]
+pub struct Node {
+    name: &'static str,
+    id: Option<Id>,
+    bounds: Rectangle,
+    width: Length,
+    height: Length,
+    children: Vec<Node>,
+}
+
+impl Node {
+    /// Creates a new [`Node`] for the widget with the given type name, size
+    /// constraints, and [`Layout`].
+    ///
+    /// The name is shortened to the name of the type, without its path and
+    /// generic parameters.
+    pub fn new(
+        type_name: &'static str,
+        width: Length,
+        height: Length,
+        layout: Layout<'_>,
+    ) -> Self {
+        let name = type_name.split('<').next().unwrap_or(type_name);
+
+        Node {
+            name: name.rsplit("::").next().unwrap_or(name),
+            id: None,
+            bounds: layout.bounds(),
+            width,
+            height,
+            children: Vec::new(),
+        }
+    }
+
+    /// Creates a new [`Node`] that only describes the given [`Layout`] and
+    /// its children.
+    ///
+    /// This is used for the children of widgets that cannot describe them.
+    pub fn from_layout(layout: Layout<'_>) -> Self {
+        let bounds = layout.bounds();
+
+        Node {
+            name: "?",
+            id: None,
+            bounds,
+            width: Length::Units(bounds.width as u16),
+            height: Length::Units(bounds.height as u16),
+            children: layout.children().map(Self::from_layout).collect(),
+        }
+    }
+
+    /// Sets the [`Id`] of the [`Node`].
+    pub fn with_id(mut self, id: Option<Id>) -> Self {
+        self.id = id;
+        self
+    }
+
+    /// Sets the children of the [`Node`].
+    pub fn with_children(mut self, children: Vec<Node>) -> Self {
+        self.children = children;
+        self
+    }
+
+    /// Translates the [`Node`] and its children by the given [`Vector`].
+    pub fn translate(mut self, translation: Vector) -> Self {
+        self.bounds = self.bounds + translation;
+        self.children = self
+            .children
+            .into_iter()
+            .map(|child| child.translate(translation))
+            .collect();
+        self
+    }
+
+    /// Returns the name of the type of the widget.
+    pub fn name(&self) -> &str {
+        self.name
+    }
+
+    /// Returns the [`Id`] of the widget, if any.
+    pub fn id(&self) -> Option<&Id> {
+        self.id.as_ref()
+    }
+
+    /// Returns the bounds of the widget.
+    pub fn bounds(&self) -> Rectangle {
+        self.bounds
+    }
+
+    /// Returns the [`Node`] of every child of the widget.
+    pub fn children(&self) -> &[Node] {
+        &self.children
+    }
+
+    /// Returns the [`Node`] at the given path, if any.
+    pub fn get(&self, path: &[usize]) -> Option<&Node> {
+        match path.split_first() {
+            Some((index, rest)) => self.children.get(*index)?.get(rest),
+            None => Some(self),
+        }
+    }
+
+    /// Returns the path of the deepest [`Node`] that contains the given
+    /// point, if any.
+    pub fn locate(&self, point: Point) -> Option<Vec<usize>> {
+        if !self.bounds.contains(point) {
+            return None;
+        }
+
+        // The last children are drawn on top
+        let path = self
+            .children
+            .iter()
+            .enumerate()
+            .rev()
+            .find_map(|(i, child)| {
+                child.locate(point).map(|mut path| {
+                    path.insert(0, i);
+                    path
+                })
+            })
+            .unwrap_or_default();
+
+        Some(path)
+    }
+
+    /// Returns the bounds of the children of the widget together, if it has
+    /// any.
+    fn content_bounds(&self) -> Option<Rectangle> {
+        let mut children = self.children.iter().map(|child| child.bounds);
+        let first = children.next()?;
+
+        Some(children.fold(first, |a, b| {
+            let x = a.x.min(b.x);
+            let y = a.y.min(b.y);
+
+            Rectangle {
+                x,
+                y,
+                width: (a.x + a.width).max(b.x + b.width) - x,
+                height: (a.y + a.height).max(b.y + b.height) - y,
+            }
+        }))
+    }
+
+    /// Flattens the tree in depth-first order, returning the depth and the
+    /// path of every [`Node`].
+    fn rows(&self) -> Vec<(usize, Vec<usize>)> {
+        fn visit(
+            node: &Node,
+            path: &mut Vec<usize>,
+            rows: &mut Vec<(usize, Vec<usize>)>,
+        ) {
+            rows.push((path.len(), path.clone()));
+
+            for (i, child) in node.children.iter().enumerate() {
+                path.push(i);
+                visit(child, path, rows);
+                let _ = path.pop();
+            }
+        }
+
+        let mut rows = Vec::new();
+        visit(self, &mut Vec::new(), &mut rows);
+
+        rows
+    }
+
+    fn details(&self) -> [String; DETAILS] {
+        let padding = match self.content_bounds() {
+            Some(content) => format!(
+                "Padding: {} {} {} {}",
+                content.y - self.bounds.y,
+                self.bounds.x + self.bounds.width - content.x - content.width,
+                self.bounds.y + self.bounds.height - content.y - content.height,
+                content.x - self.bounds.x,
+            ),
+            None => String::from("Padding: -"),
+        };
+
+        [
+            String::from(self.name),
+            match &self.id {
+                Some(id) => format!("Id: {:?}", id),
+                None => String::from("Id: -"),
+            },
+            format!("Position: {} {}", self.bounds.x, self.bounds.y),
+            format!("Size: {} x {}", self.bounds.width, self.bounds.height),
+            format!("Width: {:?}, Height: {:?}", self.width, self.height),
+            padding,
+            format!("Children: {}", self.children.len()),
+        ]
+    }
+}
+
+impl<'a, Message, Renderer> Inspector<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+{
+    fn panel(bounds: Rectangle) -> Rectangle {
+        let width = PANEL_WIDTH.min(bounds.width);
+
+        Rectangle {
+            x: bounds.x + bounds.width - width,
+            width,
+            ..bounds
+        }
+    }
+
+    /// Returns the bounds of the row of the tree view with the given index.
+    fn row(panel: Rectangle, index: usize) -> Rectangle {
+        Rectangle {
+            x: panel.x,
+            y: panel.y
+                + PANEL_PADDING
+                + LINE_HEIGHT * (DETAILS + 1 + index) as f32,
+            width: panel.width,
+            height: LINE_HEIGHT,
+        }
+    }
+
+    /// Returns the path of the [`Node`] under the cursor, either in the
+    /// content or in the tree view of the panel.
+    fn locate(
+        tree: &Node,
+        bounds: Rectangle,
+        cursor_position: Point,
+    ) -> Option<Vec<usize>> {
+        let panel = Self::panel(bounds);
+
+        if panel.contains(cursor_position) {
+            tree.rows()
+                .into_iter()
+                .enumerate()
+                .find(|(i, _)| Self::row(panel, *i).contains(cursor_position))
+                .map(|(_, (_, path))| path)
+        } else {
+            tree.locate(cursor_position)
+        }
+    }
+
+    fn draw_highlight(
+        renderer: &mut Renderer,
+        node: &Node,
+        color: Color,
+        border_width: f32,
+    ) {
+        let bounds = node.bounds;
+
+        if let Some(content) = node.content_bounds() {
+            let padding = Color { a: 0.25, ..color };
+
+            let areas = [
+                Rectangle {
+                    height: (content.y - bounds.y).max(0.0),
+                    ..bounds
+                },
+                Rectangle {
+                    y: content.y + content.height,
+                    height: (bounds.y + bounds.height
+                        - content.y
+                        - content.height)
+                        .max(0.0),
+                    ..bounds
+                },
+                Rectangle {
+                    y: content.y,
+                    width: (content.x - bounds.x).max(0.0),
+                    height: content.height,
+                    ..bounds
+                },
+                Rectangle {
+                    x: content.x + content.width,
+                    y: content.y,
+                    width: (bounds.x + bounds.width
+                        - content.x
+                        - content.width)
+                        .max(0.0),
+                    height: content.height,
+                },
+            ];
+
+            for area in areas.iter() {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: *area,
+                        border_radius: 0.0,
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                    },
+                    padding,
+                );
+            }
+        }
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border_radius: 0.0,
+                border_width,
+                border_color: color,
+            },
+            Color { a: 0.1, ..color },
+        );
+    }
+
+    fn draw_panel(
+        &self,
+        renderer: &mut Renderer,
+        tree: &Node,
+        bounds: Rectangle,
+    ) {
+        let panel = Self::panel(bounds);
+
+        let text = |renderer: &mut Renderer, content: &str, bounds, color| {
+            renderer.fill_text(text::Text {
+                content,
+                bounds,
+                size: TEXT_SIZE,
+                color,
+                font: Default::default(),
+                horizontal_alignment: alignment::Horizontal::Left,
+                vertical_alignment: alignment::Vertical::Top,
+            });
+        };
+
+        renderer.with_layer(panel, |renderer| {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: panel,
+                    border_radius: 0.0,
+                    border_width: 1.0,
+                    border_color: Color::from_rgb(0.3, 0.3, 0.3),
+                },
+                Background::Color(Color::from_rgba(0.1, 0.1, 0.1, 0.9)),
+            );
+
+            let focused = self
+                .state
+                .selected
+                .as_deref()
+                .or(self.state.hovered.as_deref())
+                .and_then(|path| tree.get(path));
+
+            if let Some(node) = focused {
+                for (i, line) in node.details().iter().enumerate() {
+                    text(
+                        renderer,
+                        line,
+                        Rectangle {
+                            x: panel.x + PANEL_PADDING,
+                            y: panel.y + PANEL_PADDING + LINE_HEIGHT * i as f32,
+                            width: panel.width - 2.0 * PANEL_PADDING,
+                            height: LINE_HEIGHT,
+                        },
+                        if i == 0 {
+                            Color::WHITE
+                        } else {
+                            Color::from_rgb(0.7, 0.7, 0.7)
+                        },
+                    );
+                }
+            }
+
+            for (i, (depth, path)) in tree.rows().iter().enumerate() {
+                let row = Self::row(panel, i);
+
+                if row.y > panel.y + panel.height {
+                    break;
+                }
+
+                let is_selected = self.state.selected.as_ref() == Some(path);
+                let is_hovered = self.state.hovered.as_ref() == Some(path);
+
+                if is_selected || is_hovered {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: row,
+                            border_radius: 0.0,
+                            border_width: 0.0,
+                            border_color: Color::TRANSPARENT,
+                        },
+                        if is_selected {
+                            Color::from_rgba(0.8, 0.2, 0.8, 0.4)
+                        } else {
+                            Color::from_rgba(0.2, 0.5, 1.0, 0.4)
+                        },
+                    );
+                }
+
+                if let Some(node) = tree.get(path) {
+                    text(
+                        renderer,
+                        node.name,
+                        Rectangle {
+                            x: row.x + PANEL_PADDING + 12.0 * *depth as f32,
+                            ..row
+                        },
+                        Color::WHITE,
+                    );
+                }
+            }
+        });
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Inspector<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+{
+    fn width(&self) -> Length {
+        self.content.width()
+    }
+
+    fn height(&self) -> Length {
+        self.content.height()
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content.layout(renderer, limits)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        if !self.is_enabled {
+            return self.content.on_event(
+                event,
+                layout,
+                cursor_position,
+                renderer,
+                clipboard,
+                shell,
+            );
+        }
+
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                let tree = self.content.inspect(layout);
+
+                self.state.hovered =
+                    Self::locate(&tree, bounds, cursor_position);
+            }
+            Event::Mouse(mouse::Event::CursorLeft) => {
+                self.state.hovered = None;
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. })
+                if bounds.contains(cursor_position) =>
+            {
+                let tree = self.content.inspect(layout);
+
+                self.state.selected =
+                    Self::locate(&tree, bounds, cursor_position);
+
+                return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Right))
+                if bounds.contains(cursor_position) =>
+            {
+                self.state.selected = None;
+
+                return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(_))
+            | Event::Mouse(mouse::Event::ButtonReleased(_))
+            | Event::Touch(_) => {
+                return event::Status::Captured;
+            }
+            _ => {}
+        }
+
+        self.content.on_event(
+            event,
+            layout,
+            cursor_position,
+            renderer,
+            clipboard,
+            shell,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) -> mouse::Interaction {
+        if self.is_enabled && layout.bounds().contains(cursor_position) {
+            return mouse::Interaction::Crosshair;
+        }
+
+        self.content
+            .mouse_interaction(layout, cursor_position, viewport)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        self.content
+            .draw(renderer, style, layout, cursor_position, viewport);
+
+        if !self.is_enabled {
+            return;
+        }
+
+        let bounds = layout.bounds();
+        let tree = self.content.inspect(layout);
+
+        renderer.with_layer(bounds, |renderer| {
+            if let Some(node) = self
+                .state
+                .hovered
+                .as_deref()
+                .and_then(|path| tree.get(path))
+            {
+                Self::draw_highlight(
+                    renderer,
+                    node,
+                    Color::from_rgb(0.2, 0.5, 1.0),
+                    1.0,
+                );
+            }
+
+            if let Some(node) = self
+                .state
+                .selected
+                .as_deref()
+                .and_then(|path| tree.get(path))
+            {
+                Self::draw_highlight(
+                    renderer,
+                    node,
+                    Color::from_rgb(0.8, 0.2, 0.8),
+                    2.0,
+                );
+            }
+        });
+
+        self.draw_panel(renderer, &tree, bounds);
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.content.hash_layout(state);
+    }
+
+    fn operate(
+        &mut self,
+        layout: Layout<'_>,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        self.content.operate(layout, operation);
+    }
+
+    fn inspect(&self, layout: Layout<'_>) -> Node {
+        self.content.inspect(layout)
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+        viewport: &Rectangle,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        self.content.overlay(layout, viewport)
+    }
+}
+
+impl<'a, Message, Renderer> From<Inspector<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + text::Renderer,
+    Message: 'a,
+{
+    fn from(
+        inspector: Inspector<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(inspector)
+    }
+}
@@ -0,0 +1,262 @@
+//! Reuse the widgets of some content as long as its data does not change.
+//!
+//! A [`Lazy`] has some local [`State`].
+use std::cell::Cell;
+use std::fmt;
+use std::hash::{Hash, Hasher as _};
+
+use crate::event::{self, Event};
+use crate::layout;
+use crate::mouse;
+use crate::overlay;
+use crate::renderer;
+use crate::widget::Operation;
+use crate::{
+    Clipboard, Element, Hasher, Layout, Length, Point, Rectangle, Shell, Widget,
+};
+
+/// A widget that only builds its content when the data it depends on changes.
+///
+/// A [`Lazy`] is identified by a key, normally the data its view logic
+/// depends on. As long as the key does not change, the view logic is not run
+/// again and the widgets built previously, together with their layout, are
+/// reused. This way, large static regions of a user interface can be cheap to
+/// rebuild.
+///
+/// The content is kept in the [`State`] of the [`Lazy`] between frames. Thus,
+/// it cannot borrow any data.
+///
+/// # Example
+/// ```
+/// # use iced_native::widget::{lazy, Column, Lazy, Text};
+/// # use iced_native::renderer::Null as Renderer;
+/// #
+/// let items = vec![String::from("Apples"), String::from("Oranges")];
+/// let mut state = lazy::State::new();
+///
+/// let lazy: Lazy<'_, (), Renderer> = Lazy::new(&mut state, &items, || {
+///     items
+///         .iter()
+///         .fold(Column::new(), |column, item| {
+///             column.push(Text::new(item.clone()))
+///         })
+/// });
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Lazy<'a, Message, Renderer> {
+    state: &'a mut State<Message, Renderer>,
+}
+
+impl<'a, Message, Renderer> Lazy<'a, Message, Renderer> {
+    /// Creates a new [`Lazy`] with the given [`State`], key and view logic.
+    ///
+    /// The view logic is only run if the key is different from the one used
+    /// the last time.
+    pub fn new<K, F, E>(
+        state: &'a mut State<Message, Renderer>,
+        key: K,
+        view: F,
+    ) -> Self
+    where
+        K: Hash,
+        F: FnOnce() -> E,
+        E: Into<Element<'static, Message, Renderer>>,
+    {
+        let key = {
+            let hasher = &mut Hasher::default();
+            key.hash(hasher);
+
+            hasher.finish()
+        };
+
+        let is_outdated = match &state.content {
+            Some(content) => content.key != key,
+            None => true,
+        };
+
+        if is_outdated {
+            state.content = Some(Content {
+                key,
+                element: view().into(),
+                hash: Cell::new(None),
+            });
+        }
+
+        Lazy { state }
+    }
+
+    fn content(&self) -> &Content<Message, Renderer> {
+        self.state.content.as_ref().expect("Lazy content")
+    }
+
+    fn content_mut(&mut self) -> &mut Content<Message, Renderer> {
+        self.state.content.as_mut().expect("Lazy content")
+    }
+}
+
+/// The local state of a [`Lazy`].
+pub struct State<Message, Renderer> {
+    content: Option<Content<Message, Renderer>>,
+}
+
+impl<Message, Renderer> State<Message, Renderer> {
+    /// Creates a new [`State`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<Message, Renderer> Default for State<Message, Renderer> {
+    fn default() -> Self {
+        State { content: None }
+    }
+}
+
+impl<Message, Renderer> fmt::Debug for State<Message, Renderer> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("State")
+            .field("key", &self.content.as_ref().map(|content| content.key))
+            .finish()
+    }
+}
+
+struct Content<Message, Renderer> {
+    key: u64,
+    element: Element<'static, Message, Renderer>,
+    hash: Cell<Option<u64>>,
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Lazy<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+{
+    fn width(&self) -> Length {
+        self.content().element.width()
+    }
+
+    fn height(&self) -> Length {
+        self.content().element.height()
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content().element.layout(renderer, limits)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let content = self.content_mut();
+
+        let mut messages = Vec::new();
+        let mut local_shell = Shell::new(&mut messages);
+
+        let status = content.element.on_event(
+            event,
+            layout,
+            cursor_position,
+            renderer,
+            clipboard,
+            &mut local_shell,
+        );
+
+        // The content needs to be hashed again if its layout changed
+        local_shell.with_invalid_layout(|| {
+            content.hash.set(None);
+
+            shell.invalidate_layout();
+        });
+
+        shell.merge(local_shell, std::convert::identity);
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) -> mouse::Interaction {
+        self.content().element.mouse_interaction(
+            layout,
+            cursor_position,
+            viewport,
+        )
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        self.content().element.draw(
+            renderer,
+            style,
+            layout,
+            cursor_position,
+            viewport,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        // The content is only hashed once, so its cached layout can be reused
+        // without walking through its widgets
+        let content = self.content();
+
+        let hash = content.hash.get().unwrap_or_else(|| {
+            let hasher = &mut Hasher::default();
+            content.element.hash_layout(hasher);
+
+            hasher.finish()
+        });
+
+        content.hash.set(Some(hash));
+        hash.hash(state);
+    }
+
+    fn operate(
+        &mut self,
+        layout: Layout<'_>,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        self.content_mut().element.operate(layout, operation);
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+        viewport: &Rectangle,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        self.content_mut().element.overlay(layout, viewport)
+    }
+}
+
+impl<'a, Message, Renderer> From<Lazy<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + crate::Renderer,
+    Message: 'a,
+{
+    fn from(
+        lazy: Lazy<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(lazy)
+    }
+}
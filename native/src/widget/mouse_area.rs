@@ -0,0 +1,380 @@
+//! Listen to mouse events on some content.
+//!
+//! A [`MouseArea`] has some local [`State`].
+use std::hash::Hash;
+use std::time::{Duration, Instant};
+
+use crate::event::{self, Event};
+use crate::layout;
+use crate::mouse;
+use crate::overlay;
+use crate::renderer;
+use crate::widget::Operation;
+use crate::{
+    Clipboard, Element, Hasher, Layout, Length, Point, Rectangle, Shell, Widget,
+};
+
+/// The maximum distance between the two clicks of a double click.
+const DOUBLE_CLICK_DISTANCE: f32 = 4.0;
+
+/// A widget that produces messages for the mouse events happening over its
+/// content.
+///
+/// The presses captured by the content are not handled by the [`MouseArea`].
+#[allow(missing_debug_implementations)]
+pub struct MouseArea<'a, Message, Renderer> {
+    state: &'a mut State,
+    content: Element<'a, Message, Renderer>,
+    on_press: Option<Message>,
+    on_release: Option<Message>,
+    on_right_press: Option<Message>,
+    on_right_release: Option<Message>,
+    on_middle_press: Option<Message>,
+    on_middle_release: Option<Message>,
+    on_double_click: Option<Message>,
+    on_enter: Option<Message>,
+    on_exit: Option<Message>,
+    on_move: Option<Box<dyn Fn(Point) -> Message + 'a>>,
+    double_click_interval: Duration,
+    interaction: Option<mouse::Interaction>,
+}
+
+impl<'a, Message, Renderer> MouseArea<'a, Message, Renderer> {
+    /// The default maximum amount of time between the two clicks of a double
+    /// click.
+    pub const DEFAULT_DOUBLE_CLICK_INTERVAL: Duration =
+        Duration::from_millis(300);
+
+    /// Creates a new [`MouseArea`] with the given [`State`] and content.
+    pub fn new(
+        state: &'a mut State,
+        content: impl Into<Element<'a, Message, Renderer>>,
+    ) -> Self {
+        MouseArea {
+            state,
+            content: content.into(),
+            on_press: None,
+            on_release: None,
+            on_right_press: None,
+            on_right_release: None,
+            on_middle_press: None,
+            on_middle_release: None,
+            on_double_click: None,
+            on_enter: None,
+            on_exit: None,
+            on_move: None,
+            double_click_interval: Self::DEFAULT_DOUBLE_CLICK_INTERVAL,
+            interaction: None,
+        }
+    }
+
+    /// Sets the message produced when the left mouse button is pressed.
+    pub fn on_press(mut self, message: Message) -> Self {
+        self.on_press = Some(message);
+        self
+    }
+
+    /// Sets the message produced when the left mouse button is released.
+    pub fn on_release(mut self, message: Message) -> Self {
+        self.on_release = Some(message);
+        self
+    }
+
+    /// Sets the message produced when the right mouse button is pressed.
+    pub fn on_right_press(mut self, message: Message) -> Self {
+        self.on_right_press = Some(message);
+        self
+    }
+
+    /// Sets the message produced when the right mouse button is released.
+    pub fn on_right_release(mut self, message: Message) -> Self {
+        self.on_right_release = Some(message);
+        self
+    }
+
+    /// Sets the message produced when the middle mouse button is pressed.
+    pub fn on_middle_press(mut self, message: Message) -> Self {
+        self.on_middle_press = Some(message);
+        self
+    }
+
+    /// Sets the message produced when the middle mouse button is released.
+    pub fn on_middle_release(mut self, message: Message) -> Self {
+        self.on_middle_release = Some(message);
+        self
+    }
+
+    /// Sets the message produced when the left mouse button is pressed twice
+    /// in a row.
+    ///
+    /// The second press produces both this message and the one of
+    /// [`MouseArea::on_press`].
+    pub fn on_double_click(mut self, message: Message) -> Self {
+        self.on_double_click = Some(message);
+        self
+    }
+
+    /// Sets the maximum amount of time between the two clicks of a double
+    /// click.
+    pub fn double_click_interval(mut self, interval: Duration) -> Self {
+        self.double_click_interval = interval;
+        self
+    }
+
+    /// Sets the message produced when the cursor enters the [`MouseArea`].
+    pub fn on_enter(mut self, message: Message) -> Self {
+        self.on_enter = Some(message);
+        self
+    }
+
+    /// Sets the message produced when the cursor leaves the [`MouseArea`].
+    pub fn on_exit(mut self, message: Message) -> Self {
+        self.on_exit = Some(message);
+        self
+    }
+
+    /// Sets the function that produces a message when the cursor moves over
+    /// the [`MouseArea`].
+    ///
+    /// The position of the cursor is relative to the top-left corner of the
+    /// [`MouseArea`].
+    pub fn on_move<F>(mut self, f: F) -> Self
+    where
+        F: 'a + Fn(Point) -> Message,
+    {
+        self.on_move = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the [`mouse::Interaction`] of the cursor while it is over the
+    /// [`MouseArea`].
+    ///
+    /// By default, the interaction of the content is used.
+    pub fn interaction(mut self, interaction: mouse::Interaction) -> Self {
+        self.interaction = Some(interaction);
+        self
+    }
+}
+
+/// The local state of a [`MouseArea`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct State {
+    is_hovered: bool,
+    last_click: Option<(Point, Instant)>,
+}
+
+impl State {
+    /// Creates a new [`State`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns whether the cursor is currently over the [`MouseArea`].
+    pub fn is_hovered(&self) -> bool {
+        self.is_hovered
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for MouseArea<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: crate::Renderer,
+{
+    fn width(&self) -> Length {
+        self.content.width()
+    }
+
+    fn height(&self) -> Length {
+        self.content.height()
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content.layout(renderer, limits)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let bounds = layout.bounds();
+        let is_mouse_over = bounds.contains(cursor_position);
+
+        if let Event::Mouse(mouse::Event::CursorMoved { .. })
+        | Event::Mouse(mouse::Event::CursorLeft) = event
+        {
+            let is_hovered = is_mouse_over
+                && event != Event::Mouse(mouse::Event::CursorLeft);
+
+            if is_hovered != self.state.is_hovered {
+                self.state.is_hovered = is_hovered;
+
+                let message = if is_hovered {
+                    self.on_enter.clone()
+                } else {
+                    self.on_exit.clone()
+                };
+
+                if let Some(message) = message {
+                    shell.publish(message);
+                }
+            }
+
+            if let (true, Some(on_move)) = (is_hovered, &self.on_move) {
+                shell.publish(on_move(Point::new(
+                    cursor_position.x - bounds.x,
+                    cursor_position.y - bounds.y,
+                )));
+            }
+        }
+
+        if let event::Status::Captured = self.content.on_event(
+            event.clone(),
+            layout,
+            cursor_position,
+            renderer,
+            clipboard,
+            shell,
+        ) {
+            return event::Status::Captured;
+        }
+
+        if !is_mouse_over {
+            return event::Status::Ignored;
+        }
+
+        let mut status = event::Status::Ignored;
+
+        let message = match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                let now = Instant::now();
+
+                let is_double_click = match self.state.last_click {
+                    Some((position, at)) => {
+                        now.duration_since(at) <= self.double_click_interval
+                            && position.distance(cursor_position)
+                                <= DOUBLE_CLICK_DISTANCE
+                    }
+                    None => false,
+                };
+
+                if is_double_click {
+                    self.state.last_click = None;
+
+                    if let Some(on_double_click) = self.on_double_click.clone()
+                    {
+                        shell.publish(on_double_click);
+                        status = event::Status::Captured;
+                    }
+                } else {
+                    self.state.last_click = Some((cursor_position, now));
+                }
+
+                self.on_press.clone()
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+                self.on_release.clone()
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Right)) => {
+                self.on_right_press.clone()
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(
+                mouse::Button::Right,
+            )) => self.on_right_release.clone(),
+            Event::Mouse(mouse::Event::ButtonPressed(
+                mouse::Button::Middle,
+            )) => self.on_middle_press.clone(),
+            Event::Mouse(mouse::Event::ButtonReleased(
+                mouse::Button::Middle,
+            )) => self.on_middle_release.clone(),
+            _ => None,
+        };
+
+        if let Some(message) = message {
+            shell.publish(message);
+            status = event::Status::Captured;
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) -> mouse::Interaction {
+        let content_interaction =
+            self.content
+                .mouse_interaction(layout, cursor_position, viewport);
+
+        match self.interaction {
+            Some(interaction)
+                if content_interaction == mouse::Interaction::Idle
+                    && layout.bounds().contains(cursor_position) =>
+            {
+                interaction
+            }
+            _ => content_interaction,
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        self.content
+            .draw(renderer, style, layout, cursor_position, viewport);
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.content.hash_layout(state);
+    }
+
+    fn operate(
+        &mut self,
+        layout: Layout<'_>,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        operation.container(None, &mut |operation| {
+            self.content.operate(layout, operation);
+        });
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+        viewport: &Rectangle,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        self.content.overlay(layout, viewport)
+    }
+}
+
+impl<'a, Message, Renderer> From<MouseArea<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + crate::Renderer,
+{
+    fn from(
+        area: MouseArea<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(area)
+    }
+}
@@ -0,0 +1,170 @@
+//! Query or update internal widget state.
+pub mod focusable;
+pub mod scrollable;
+pub mod text_input;
+
+pub use focusable::Focusable;
+pub use scrollable::Scrollable;
+pub use text_input::TextInput;
+
+use crate::accessibility;
+use crate::widget::Id;
+
+use std::fmt;
+use std::rc::Rc;
+
+/// A piece of logic that can traverse the widget tree of an application in
+/// order to query or update some widget state.
+pub trait Operation<T> {
+    /// Operates on a widget that contains other widgets.
+    ///
+    /// The `operate_on_children` function can be called to return control to
+    /// the widget tree, so it can keep traversing it.
+    fn container(
+        &mut self,
+        id: Option<&Id>,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+    );
+
+    /// Operates on a widget that can be focused.
+    fn focusable(&mut self, _state: &mut dyn Focusable, _id: Option<&Id>) {}
+
+    /// Operates on a widget that can be scrolled.
+    fn scrollable(&mut self, _state: &mut dyn Scrollable, _id: Option<&Id>) {}
+
+    /// Operates on a widget that can be edited like a text input.
+    fn text_input(&mut self, _state: &mut dyn TextInput, _id: Option<&Id>) {}
+
+    /// Operates on a widget that describes itself to assistive technologies.
+    fn accessible(&mut self, _node: accessibility::Node, _id: Option<&Id>) {}
+
+    /// Finishes the [`Operation`] and returns its [`Outcome`].
+    fn finish(&self) -> Outcome<T> {
+        Outcome::None
+    }
+}
+
+/// The result of an [`Operation`].
+pub enum Outcome<T> {
+    /// The [`Operation`] produced no result.
+    None,
+
+    /// The [`Operation`] produced some result.
+    Some(T),
+
+    /// The [`Operation`] needs to be followed by another [`Operation`].
+    Chain(Box<dyn Operation<T>>),
+}
+
+impl<T> fmt::Debug for Outcome<T>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::None => write!(f, "Outcome::None"),
+            Self::Some(output) => write!(f, "Outcome::Some({:?})", output),
+            Self::Chain(_) => write!(f, "Outcome::Chain(...)"),
+        }
+    }
+}
+
+/// Maps the output of an [`Operation`] using the given function.
+pub fn map<A, B>(
+    operation: Box<dyn Operation<A>>,
+    f: impl Fn(A) -> B + 'static,
+) -> impl Operation<B>
+where
+    A: 'static,
+    B: 'static,
+{
+    Map {
+        operation,
+        f: Rc::new(f),
+    }
+}
+
+struct Map<A, B> {
+    operation: Box<dyn Operation<A>>,
+    f: Rc<dyn Fn(A) -> B>,
+}
+
+impl<A, B> Operation<B> for Map<A, B>
+where
+    A: 'static,
+    B: 'static,
+{
+    fn container(
+        &mut self,
+        id: Option<&Id>,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<B>),
+    ) {
+        MapRef {
+            operation: self.operation.as_mut(),
+        }
+        .container(id, operate_on_children);
+    }
+
+    fn focusable(&mut self, state: &mut dyn Focusable, id: Option<&Id>) {
+        self.operation.focusable(state, id);
+    }
+
+    fn scrollable(&mut self, state: &mut dyn Scrollable, id: Option<&Id>) {
+        self.operation.scrollable(state, id);
+    }
+
+    fn text_input(&mut self, state: &mut dyn TextInput, id: Option<&Id>) {
+        self.operation.text_input(state, id);
+    }
+
+    fn accessible(&mut self, node: accessibility::Node, id: Option<&Id>) {
+        self.operation.accessible(node, id);
+    }
+
+    fn finish(&self) -> Outcome<B> {
+        match self.operation.finish() {
+            Outcome::None => Outcome::None,
+            Outcome::Some(output) => Outcome::Some((self.f)(output)),
+            Outcome::Chain(next) => Outcome::Chain(Box::new(Map {
+                operation: next,
+                f: self.f.clone(),
+            })),
+        }
+    }
+}
+
+/// Exposes an [`Operation`] with a different output type to some widgets.
+///
+/// Only the traversal of the widgets is forwarded, since they cannot produce
+/// any output on their own.
+pub(crate) struct MapRef<'a, A> {
+    pub(crate) operation: &'a mut dyn Operation<A>,
+}
+
+impl<'a, A, B> Operation<B> for MapRef<'a, A> {
+    fn container(
+        &mut self,
+        id: Option<&Id>,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<B>),
+    ) {
+        self.operation.container(id, &mut |operation| {
+            operate_on_children(&mut MapRef { operation });
+        });
+    }
+
+    fn focusable(&mut self, state: &mut dyn Focusable, id: Option<&Id>) {
+        self.operation.focusable(state, id);
+    }
+
+    fn scrollable(&mut self, state: &mut dyn Scrollable, id: Option<&Id>) {
+        self.operation.scrollable(state, id);
+    }
+
+    fn text_input(&mut self, state: &mut dyn TextInput, id: Option<&Id>) {
+        self.operation.text_input(state, id);
+    }
+
+    fn accessible(&mut self, node: accessibility::Node, id: Option<&Id>) {
+        self.operation.accessible(node, id);
+    }
+}
@@ -0,0 +1,300 @@
+//! Operate on widgets that can be focused.
+use crate::widget::operation::{Operation, Outcome};
+use crate::widget::Id;
+use crate::{Element, Layout};
+
+/// The internal state of a widget that can be focused.
+pub trait Focusable {
+    /// Returns whether the widget is focused or not.
+    fn is_focused(&self) -> bool;
+
+    /// Focuses the widget.
+    fn focus(&mut self);
+
+    /// Unfocuses the widget.
+    fn unfocus(&mut self);
+
+    /// Returns the position of the widget in the focus traversal order.
+    ///
+    /// Widgets with a positive index are visited first, in ascending order.
+    /// Then, widgets with an index of `0` are visited in layout order.
+    /// Widgets with a negative index are skipped, but they can still be
+    /// focused directly.
+    ///
+    /// By default, it returns `0`.
+    fn tab_index(&self) -> i32 {
+        0
+    }
+}
+
+/// A [`Focusable`] with an explicit [`Focusable::tab_index`].
+#[allow(missing_debug_implementations)]
+pub struct WithTabIndex<'a> {
+    state: &'a mut dyn Focusable,
+    tab_index: i32,
+}
+
+impl<'a> WithTabIndex<'a> {
+    /// Overrides the [`Focusable::tab_index`] of the given state.
+    pub fn new(state: &'a mut dyn Focusable, tab_index: i32) -> Self {
+        Self { state, tab_index }
+    }
+}
+
+impl<'a> Focusable for WithTabIndex<'a> {
+    fn is_focused(&self) -> bool {
+        self.state.is_focused()
+    }
+
+    fn focus(&mut self) {
+        self.state.focus()
+    }
+
+    fn unfocus(&mut self) {
+        self.state.unfocus()
+    }
+
+    fn tab_index(&self) -> i32 {
+        self.tab_index
+    }
+}
+
+/// Produces an [`Operation`] that focuses the widget with the given [`Id`],
+/// unfocusing any other widget.
+pub fn focus<T>(target: Id) -> impl Operation<T> {
+    struct Focus {
+        target: Id,
+    }
+
+    impl<T> Operation<T> for Focus {
+        fn focusable(&mut self, state: &mut dyn Focusable, id: Option<&Id>) {
+            match id {
+                Some(id) if id == &self.target => {
+                    state.focus();
+                }
+                _ => {
+                    state.unfocus();
+                }
+            }
+        }
+
+        fn container(
+            &mut self,
+            _id: Option<&Id>,
+            operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+        ) {
+            operate_on_children(self)
+        }
+    }
+
+    Focus { target }
+}
+
+/// Produces an [`Operation`] that unfocuses every widget.
+pub fn unfocus<T>() -> impl Operation<T> {
+    struct Unfocus;
+
+    impl<T> Operation<T> for Unfocus {
+        fn focusable(&mut self, state: &mut dyn Focusable, _id: Option<&Id>) {
+            state.unfocus();
+        }
+
+        fn container(
+            &mut self,
+            _id: Option<&Id>,
+            operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+        ) {
+            operate_on_children(self)
+        }
+    }
+
+    Unfocus
+}
+
+/// Produces an [`Operation`] that searches for the [`Id`] of the focused
+/// widget, if any.
+pub fn find_focused() -> impl Operation<Id> {
+    struct FindFocused {
+        focused: Option<Id>,
+    }
+
+    impl Operation<Id> for FindFocused {
+        fn focusable(&mut self, state: &mut dyn Focusable, id: Option<&Id>) {
+            if state.is_focused() && id.is_some() {
+                self.focused = id.cloned();
+            }
+        }
+
+        fn container(
+            &mut self,
+            _id: Option<&Id>,
+            operate_on_children: &mut dyn FnMut(&mut dyn Operation<Id>),
+        ) {
+            operate_on_children(self)
+        }
+
+        fn finish(&self) -> Outcome<Id> {
+            match &self.focused {
+                Some(id) => Outcome::Some(id.clone()),
+                None => Outcome::None,
+            }
+        }
+    }
+
+    FindFocused { focused: None }
+}
+
+/// The direction of a focus traversal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Direction {
+    Next,
+    Previous,
+}
+
+/// Produces an [`Operation`] that moves the focus to the next widget in the
+/// focus traversal order, wrapping around at the end.
+///
+/// If no widget is focused, the first one is focused.
+pub fn focus_next<T>() -> impl Operation<T> {
+    Traverse::new(Direction::Next)
+}
+
+/// Produces an [`Operation`] that moves the focus to the previous widget in
+/// the focus traversal order, wrapping around at the start.
+///
+/// If no widget is focused, the last one is focused.
+pub fn focus_previous<T>() -> impl Operation<T> {
+    Traverse::new(Direction::Previous)
+}
+
+/// Collects the [`Focusable::tab_index`] of every widget, so the target of a
+/// traversal can be found and focused in a second pass.
+struct Traverse {
+    direction: Direction,
+    tab_indices: Vec<i32>,
+    focused: Option<usize>,
+}
+
+impl Traverse {
+    fn new(direction: Direction) -> Self {
+        Self {
+            direction,
+            tab_indices: Vec::new(),
+            focused: None,
+        }
+    }
+
+    fn target(&self) -> Option<usize> {
+        let mut order: Vec<usize> = (0..self.tab_indices.len())
+            .filter(|i| self.tab_indices[*i] >= 0)
+            .collect();
+
+        order.sort_by_key(|i| {
+            let tab_index = self.tab_indices[*i];
+
+            (tab_index == 0, tab_index)
+        });
+
+        let position = self
+            .focused
+            .and_then(|focused| order.iter().position(|i| *i == focused));
+
+        let target = match (self.direction, position) {
+            (Direction::Next, Some(position)) => (position + 1) % order.len(),
+            (Direction::Previous, Some(position)) => {
+                (position + order.len() - 1) % order.len()
+            }
+            (Direction::Next, None) => 0,
+            (Direction::Previous, None) => order.len().checked_sub(1)?,
+        };
+
+        order.get(target).copied()
+    }
+}
+
+impl<T> Operation<T> for Traverse {
+    fn focusable(&mut self, state: &mut dyn Focusable, _id: Option<&Id>) {
+        if state.is_focused() {
+            self.focused = Some(self.tab_indices.len());
+        }
+
+        self.tab_indices.push(state.tab_index());
+    }
+
+    fn container(
+        &mut self,
+        _id: Option<&Id>,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+    ) {
+        operate_on_children(self)
+    }
+
+    fn finish(&self) -> Outcome<T> {
+        struct FocusIndex {
+            target: usize,
+            current: usize,
+        }
+
+        impl<T> Operation<T> for FocusIndex {
+            fn focusable(
+                &mut self,
+                state: &mut dyn Focusable,
+                _id: Option<&Id>,
+            ) {
+                if self.current == self.target {
+                    state.focus();
+                } else {
+                    state.unfocus();
+                }
+
+                self.current += 1;
+            }
+
+            fn container(
+                &mut self,
+                _id: Option<&Id>,
+                operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+            ) {
+                operate_on_children(self)
+            }
+        }
+
+        match self.target() {
+            Some(target) => {
+                Outcome::Chain(Box::new(FocusIndex { target, current: 0 }))
+            }
+            None => Outcome::None,
+        }
+    }
+}
+
+/// Returns true if a widget inside of the given content is focused.
+pub(crate) fn has_focus<Message, Renderer>(
+    content: &mut Element<'_, Message, Renderer>,
+    layout: Layout<'_>,
+) -> bool
+where
+    Renderer: crate::Renderer,
+{
+    struct HasFocus(bool);
+
+    impl<T> Operation<T> for HasFocus {
+        fn container(
+            &mut self,
+            _id: Option<&Id>,
+            operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+        ) {
+            operate_on_children(self)
+        }
+
+        fn focusable(&mut self, state: &mut dyn Focusable, _id: Option<&Id>) {
+            self.0 = self.0 || state.is_focused();
+        }
+    }
+
+    let mut operation = HasFocus(false);
+
+    content.operate(layout, &mut operation);
+
+    operation.0
+}
@@ -0,0 +1,97 @@
+//! Operate on widgets that can be scrolled.
+use crate::widget::operation::Operation;
+use crate::widget::Id;
+
+/// The internal state of a widget that can be scrolled.
+pub trait Scrollable {
+    /// Snaps the scroll position of the widget to a [`RelativeOffset`].
+    fn snap_to(&mut self, offset: RelativeOffset);
+
+    /// Scrolls the widget to an [`AbsoluteOffset`].
+    fn scroll_to(&mut self, offset: AbsoluteOffset);
+}
+
+/// Produces an [`Operation`] that snaps the scroll position of the widget with
+/// the given [`Id`] to a [`RelativeOffset`].
+pub fn snap_to<T>(target: Id, offset: RelativeOffset) -> impl Operation<T> {
+    struct SnapTo {
+        target: Id,
+        offset: RelativeOffset,
+    }
+
+    impl<T> Operation<T> for SnapTo {
+        fn scrollable(&mut self, state: &mut dyn Scrollable, id: Option<&Id>) {
+            if Some(&self.target) == id {
+                state.snap_to(self.offset);
+            }
+        }
+
+        fn container(
+            &mut self,
+            _id: Option<&Id>,
+            operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+        ) {
+            operate_on_children(self)
+        }
+    }
+
+    SnapTo { target, offset }
+}
+
+/// Produces an [`Operation`] that scrolls the widget with the given [`Id`] to
+/// an [`AbsoluteOffset`].
+pub fn scroll_to<T>(target: Id, offset: AbsoluteOffset) -> impl Operation<T> {
+    struct ScrollTo {
+        target: Id,
+        offset: AbsoluteOffset,
+    }
+
+    impl<T> Operation<T> for ScrollTo {
+        fn scrollable(&mut self, state: &mut dyn Scrollable, id: Option<&Id>) {
+            if Some(&self.target) == id {
+                state.scroll_to(self.offset);
+            }
+        }
+
+        fn container(
+            &mut self,
+            _id: Option<&Id>,
+            operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+        ) {
+            operate_on_children(self)
+        }
+    }
+
+    ScrollTo { target, offset }
+}
+
+/// The scroll offset of some content, in logical pixels.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct AbsoluteOffset {
+    /// The amount of horizontal offset.
+    pub x: f32,
+
+    /// The amount of vertical offset.
+    pub y: f32,
+}
+
+/// The scroll offset of some content, relative to the amount of content that
+/// can be scrolled.
+///
+/// `0` represents the start of the content, while `1` represents its end.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct RelativeOffset {
+    /// The amount of horizontal offset.
+    pub x: f32,
+
+    /// The amount of vertical offset.
+    pub y: f32,
+}
+
+impl RelativeOffset {
+    /// A [`RelativeOffset`] at the start of the content.
+    pub const START: Self = Self { x: 0.0, y: 0.0 };
+
+    /// A [`RelativeOffset`] at the end of the content.
+    pub const END: Self = Self { x: 1.0, y: 1.0 };
+}
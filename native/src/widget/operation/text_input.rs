@@ -0,0 +1,65 @@
+//! Operate on widgets that can be edited like a text input.
+use crate::widget::operation::Operation;
+use crate::widget::Id;
+
+/// The internal state of a widget that can be edited like a text input.
+pub trait TextInput {
+    /// Forgets every undo and redo step of the widget.
+    fn clear_history(&mut self);
+
+    /// Closes the undo step that is currently open, so the next edit of the
+    /// widget can be undone on its own.
+    fn snapshot(&mut self);
+}
+
+/// Produces an [`Operation`] that clears the undo history of the widget with
+/// the given [`Id`].
+pub fn clear_history<T>(target: Id) -> impl Operation<T> {
+    struct ClearHistory {
+        target: Id,
+    }
+
+    impl<T> Operation<T> for ClearHistory {
+        fn text_input(&mut self, state: &mut dyn TextInput, id: Option<&Id>) {
+            if Some(&self.target) == id {
+                state.clear_history();
+            }
+        }
+
+        fn container(
+            &mut self,
+            _id: Option<&Id>,
+            operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+        ) {
+            operate_on_children(self)
+        }
+    }
+
+    ClearHistory { target }
+}
+
+/// Produces an [`Operation`] that closes the current undo step of the widget
+/// with the given [`Id`].
+pub fn snapshot<T>(target: Id) -> impl Operation<T> {
+    struct Snapshot {
+        target: Id,
+    }
+
+    impl<T> Operation<T> for Snapshot {
+        fn text_input(&mut self, state: &mut dyn TextInput, id: Option<&Id>) {
+            if Some(&self.target) == id {
+                state.snapshot();
+            }
+        }
+
+        fn container(
+            &mut self,
+            _id: Option<&Id>,
+            operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+        ) {
+            operate_on_children(self)
+        }
+    }
+
+    Snapshot { target }
+}
@@ -0,0 +1,826 @@
+//! Navigate through a collection split in pages.
+//!
+//! A [`Pagination`] has some local [`State`].
+use crate::alignment;
+use crate::event::{self, Event};
+use crate::layout;
+use crate::mouse;
+use crate::renderer;
+use crate::text::{self, Text};
+use crate::theme;
+use crate::touch;
+use crate::widget::operation::{self, Operation};
+use crate::widget::text_input::{self, TextInput};
+use crate::{
+    Background, Clipboard, Color, Element, Hasher, Layout, Length, Padding,
+    Point, Rectangle, Shell, Size, Widget,
+};
+
+use std::hash::Hash;
+
+pub use iced_style::pagination::{Style, StyleSheet};
+
+/// A control to navigate through a collection split in pages.
+///
+/// It displays previous and next buttons, a window of numbered pages with
+/// ellipses for the hidden ones, and an input to jump to a specific page.
+/// The amount of numbered pages shown adapts to the available width.
+///
+/// Pages are zero-indexed, but they are displayed starting from `1`.
+///
+/// # Example
+/// ```
+/// # use iced_native::widget::pagination;
+/// #
+/// # type Pagination<'a, Message> =
+/// #     iced_native::widget::Pagination<'a, Message, iced_native::renderer::Null>;
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     PageChanged(usize),
+/// }
+///
+/// let mut state = pagination::State::new();
+/// let current_page = 4;
+/// let total_pages = 20;
+///
+/// let pagination = Pagination::new(
+///     &mut state,
+///     current_page,
+///     total_pages,
+///     Message::PageChanged,
+/// );
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Pagination<'a, Message, Renderer: text::Renderer> {
+    pressed: &'a mut Option<Target>,
+    jump: &'a mut String,
+    input: Option<TextInput<'a, Input, Renderer>>,
+    current: usize,
+    total: usize,
+    on_change: Box<dyn Fn(usize) -> Message>,
+    width: Length,
+    max_pages: usize,
+    spacing: u16,
+    padding: Padding,
+    text_size: Option<u16>,
+    font: Renderer::Font,
+    style_sheet: Option<Box<dyn StyleSheet + 'a>>,
+    class: Option<String>,
+}
+
+impl<'a, Message, Renderer> Pagination<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+{
+    /// The default padding of the buttons of a [`Pagination`].
+    pub const DEFAULT_PADDING: Padding = Padding::new(5);
+
+    /// Creates a new [`Pagination`].
+    ///
+    /// It expects:
+    /// - some [`State`]
+    /// - the index of the current page
+    /// - the total amount of pages
+    /// - a function that produces a message when a new page is requested
+    pub fn new<F>(
+        state: &'a mut State,
+        current: usize,
+        total: usize,
+        on_change: F,
+    ) -> Self
+    where
+        F: 'static + Fn(usize) -> Message,
+    {
+        let State {
+            pressed,
+            jump,
+            input,
+        } = state;
+
+        let input = TextInput::new(input, "Page", jump, Input::Changed)
+            .on_submit(Input::Submitted)
+            .padding(Self::DEFAULT_PADDING);
+
+        Pagination {
+            pressed,
+            jump,
+            input: Some(input),
+            current,
+            total,
+            on_change: Box::new(on_change),
+            width: Length::Shrink,
+            max_pages: 9,
+            spacing: 2,
+            padding: Self::DEFAULT_PADDING,
+            text_size: None,
+            font: Default::default(),
+            style_sheet: None,
+            class: None,
+        }
+    }
+
+    /// Sets the width of the [`Pagination`].
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the maximum amount of page slots, including ellipses, that the
+    /// [`Pagination`] will display.
+    ///
+    /// Less slots will be displayed if they do not fit the available width.
+    ///
+    /// Default is `9`.
+    pub fn max_pages(mut self, max_pages: usize) -> Self {
+        self.max_pages = max_pages.max(1);
+        self
+    }
+
+    /// Sets the horizontal spacing between the buttons of the [`Pagination`].
+    pub fn spacing(mut self, units: u16) -> Self {
+        self.spacing = units;
+        self
+    }
+
+    /// Sets the [`Padding`] of the buttons of the [`Pagination`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+
+        let padding = self.padding;
+        self.input = self.input.map(|input| input.padding(padding));
+        self
+    }
+
+    /// Sets the text size of the [`Pagination`].
+    pub fn text_size(mut self, size: u16) -> Self {
+        self.text_size = Some(size);
+        self.input = self.input.map(|input| input.size(size));
+        self
+    }
+
+    /// Sets the font of the [`Pagination`].
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.font = font.clone();
+        self.input = self.input.map(|input| input.font(font));
+        self
+    }
+
+    /// Sets whether the [`Pagination`] displays an input to jump to a
+    /// specific page.
+    ///
+    /// The input is enabled by default. It is hidden automatically when
+    /// there is not enough space for it.
+    pub fn jump_input(mut self, enabled: bool) -> Self {
+        if !enabled {
+            self.input = None;
+        }
+
+        self
+    }
+
+    /// Sets the style of the page buttons of the [`Pagination`].
+    pub fn style(
+        mut self,
+        style_sheet: impl Into<Box<dyn StyleSheet + 'a>>,
+    ) -> Self {
+        self.style_sheet = Some(style_sheet.into());
+        self
+    }
+
+    /// Sets the style class of the [`Pagination`].
+    ///
+    /// The class is resolved through the current [`Theme`] when the
+    /// [`Pagination`] does not have a custom style sheet.
+    ///
+    /// [`Theme`]: crate::Theme
+    pub fn class(mut self, class: impl Into<String>) -> Self {
+        self.class = Some(class.into());
+        self
+    }
+
+    /// Sets the style of the jump input of the [`Pagination`].
+    pub fn input_style(
+        mut self,
+        style_sheet: impl Into<Box<dyn text_input::StyleSheet + 'a>>,
+    ) -> Self {
+        let style_sheet = style_sheet.into();
+
+        self.input = self.input.map(|input| input.style(style_sheet));
+        self
+    }
+
+    fn is_enabled(&self, target: Target) -> bool {
+        match target {
+            Target::Previous => self.current > 0,
+            Target::Next => self.current + 1 < self.total,
+            Target::Page(page) => page != self.current,
+        }
+    }
+
+    fn page_of(&self, target: Target) -> usize {
+        match target {
+            Target::Previous => self.current.saturating_sub(1),
+            Target::Next => self.current + 1,
+            Target::Page(page) => page,
+        }
+    }
+
+    /// Returns the [`Target`] under the cursor, if any.
+    fn target_at(&self, layout: Layout<'_>, position: Point) -> Option<Target> {
+        let mut children = layout.children();
+
+        let previous = children.next()?;
+        let pages = children.next()?;
+        let next = children.next()?;
+
+        if previous.bounds().contains(position) {
+            return Some(Target::Previous);
+        }
+
+        if next.bounds().contains(position) {
+            return Some(Target::Next);
+        }
+
+        let slots = pages.children().count();
+
+        items(self.current, self.total, slots)
+            .into_iter()
+            .zip(pages.children())
+            .find_map(|(item, layout)| match item {
+                Item::Page(page) if layout.bounds().contains(position) => {
+                    Some(Target::Page(page))
+                }
+                _ => None,
+            })
+    }
+
+    fn draw_slot(
+        &self,
+        renderer: &mut Renderer,
+        bounds: Rectangle,
+        label: &str,
+        style: Style,
+    ) {
+        if style.background.is_some() || style.border_width > 0.0 {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border_radius: style.border_radius,
+                    border_width: style.border_width,
+                    border_color: style.border_color,
+                },
+                style
+                    .background
+                    .unwrap_or(Background::Color(Color::TRANSPARENT)),
+            );
+        }
+
+        renderer.fill_text(Text {
+            content: label,
+            bounds: Rectangle {
+                x: bounds.center_x(),
+                y: bounds.center_y(),
+                ..bounds
+            },
+            size: f32::from(self.text_size.unwrap_or(renderer.default_size())),
+            color: style.text_color,
+            font: self.font.clone(),
+            horizontal_alignment: alignment::Horizontal::Center,
+            vertical_alignment: alignment::Vertical::Center,
+        });
+    }
+}
+
+/// The local state of a [`Pagination`].
+#[derive(Debug, Clone, Default)]
+pub struct State {
+    pressed: Option<Target>,
+    jump: String,
+    input: text_input::State,
+}
+
+impl State {
+    /// Creates a new [`State`].
+    pub fn new() -> Self {
+        State::default()
+    }
+}
+
+/// An interactive button of a [`Pagination`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Target {
+    Previous,
+    Next,
+    Page(usize),
+}
+
+/// A slot in the window of pages of a [`Pagination`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Item {
+    Page(usize),
+    Ellipsis,
+}
+
+/// The internal messages of the jump input of a [`Pagination`].
+#[derive(Debug, Clone)]
+enum Input {
+    Changed(String),
+    Submitted,
+}
+
+/// Computes the items displayed in the given amount of slots.
+///
+/// The first and last pages are always visible when there are enough slots,
+/// and ellipses replace the hidden ranges around the current page.
+fn items(current: usize, total: usize, slots: usize) -> Vec<Item> {
+    if total <= slots {
+        return (0..total).map(Item::Page).collect();
+    }
+
+    if slots < 5 {
+        let start = current
+            .saturating_sub(slots / 2)
+            .min(total.saturating_sub(slots));
+
+        return (start..start + slots).map(Item::Page).collect();
+    }
+
+    let edge = slots - 2;
+
+    if current < edge - 1 {
+        (0..edge)
+            .map(Item::Page)
+            .chain([Item::Ellipsis, Item::Page(total - 1)])
+            .collect()
+    } else if current + edge > total {
+        [Item::Page(0), Item::Ellipsis]
+            .iter()
+            .copied()
+            .chain((total - edge..total).map(Item::Page))
+            .collect()
+    } else {
+        let middle = slots - 4;
+        let start = current - (middle - 1) / 2;
+
+        [Item::Page(0), Item::Ellipsis]
+            .iter()
+            .copied()
+            .chain((start..start + middle).map(Item::Page))
+            .chain([Item::Ellipsis, Item::Page(total - 1)])
+            .collect()
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Pagination<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(Length::Shrink);
+
+        let text_size = self.text_size.unwrap_or(renderer.default_size());
+        let spacing = f32::from(self.spacing);
+
+        let slot = {
+            let label = self.total.max(1).to_string();
+            let label_width =
+                renderer.measure_width(&label, text_size, self.font.clone());
+
+            Size::new(
+                label_width.max(f32::from(text_size))
+                    + f32::from(self.padding.horizontal()),
+                f32::from(text_size + self.padding.vertical()),
+            )
+        };
+
+        let input_width = slot.width * 2.0 + f32::from(text_size);
+
+        let mut available = limits.max().width - 2.0 * (slot.width + spacing);
+
+        let has_input = self.input.is_some()
+            && available - (input_width + spacing) >= slot.width;
+
+        if has_input {
+            available -= input_width + spacing;
+        }
+
+        let slots = (((available + spacing) / (slot.width + spacing)).max(1.0)
+            as usize)
+            .min(self.max_pages)
+            .min(self.total);
+
+        let slot_at = |x: f32| {
+            let mut node = layout::Node::new(slot);
+            node.move_to(Point::new(x, 0.0));
+            node
+        };
+
+        let previous = slot_at(0.0);
+
+        let pages = {
+            let children: Vec<_> = (0..slots)
+                .map(|i| slot_at(i as f32 * (slot.width + spacing)))
+                .collect();
+
+            let width = slots as f32 * (slot.width + spacing) - spacing;
+
+            let mut pages = layout::Node::with_children(
+                Size::new(width.max(0.0), slot.height),
+                children,
+            );
+            pages.move_to(Point::new(slot.width + spacing, 0.0));
+            pages
+        };
+
+        let next = slot_at(pages.bounds().x + pages.bounds().width + spacing);
+
+        let mut x = next.bounds().x + slot.width;
+        let mut children = vec![previous, pages, next];
+
+        if let Some(input) = self.input.as_ref().filter(|_| has_input) {
+            let mut input = Widget::<Input, Renderer>::layout(
+                input,
+                renderer,
+                &layout::Limits::new(
+                    Size::ZERO,
+                    Size::new(input_width, slot.height),
+                ),
+            );
+            input.move_to(Point::new(x + spacing, 0.0));
+
+            x += spacing + input_width;
+            children.push(input);
+        }
+
+        let size = limits.resolve(Size::new(x, slot.height));
+
+        layout::Node::with_children(size, children)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        if let (Some(input), Some(input_layout)) =
+            (&mut self.input, layout.children().nth(3))
+        {
+            let mut messages = Vec::new();
+            let mut input_shell = Shell::new(&mut messages);
+
+            let status = input.on_event(
+                event.clone(),
+                input_layout,
+                cursor_position,
+                renderer,
+                clipboard,
+                &mut input_shell,
+            );
+
+            shell.forward(&mut input_shell);
+
+            for message in messages {
+                match message {
+                    Input::Changed(value) => {
+                        *self.jump = value
+                            .chars()
+                            .filter(char::is_ascii_digit)
+                            .collect();
+                    }
+                    Input::Submitted => {
+                        if let Ok(page) = self.jump.parse::<usize>() {
+                            if page > 0 && page <= self.total {
+                                shell.publish((self.on_change)(page - 1));
+                            }
+                        }
+
+                        self.jump.clear();
+                    }
+                }
+
+                input.set_value(self.jump);
+            }
+
+            if status == event::Status::Captured {
+                return status;
+            }
+        }
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if let Some(target) = self
+                    .target_at(layout, cursor_position)
+                    .filter(|target| self.is_enabled(*target))
+                {
+                    *self.pressed = Some(target);
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. }) => {
+                if let Some(target) = self.pressed.take() {
+                    if self.target_at(layout, cursor_position) == Some(target)
+                        && self.is_enabled(target)
+                    {
+                        shell.publish((self.on_change)(self.page_of(target)));
+                    }
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Touch(touch::Event::FingerLost { .. }) => {
+                *self.pressed = None;
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) -> mouse::Interaction {
+        if let (Some(input), Some(input_layout)) =
+            (&self.input, layout.children().nth(3))
+        {
+            if input_layout.bounds().contains(cursor_position) {
+                return Widget::<Input, Renderer>::mouse_interaction(
+                    input,
+                    input_layout,
+                    cursor_position,
+                    viewport,
+                );
+            }
+        }
+
+        match self.target_at(layout, cursor_position) {
+            Some(target) if self.is_enabled(target) => {
+                mouse::Interaction::Pointer
+            }
+            _ => mouse::Interaction::default(),
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        let theme = style
+            .theme
+            .resolve(theme::Widget::Pagination, self.class.as_deref());
+        let style_sheet = self.style_sheet.as_deref().unwrap_or(&theme);
+
+        let hovered = self.target_at(layout, cursor_position);
+
+        let style_of = |target: Target| {
+            if !self.is_enabled(target) {
+                match target {
+                    Target::Page(_) => style_sheet.selected(),
+                    _ => style_sheet.disabled(),
+                }
+            } else if hovered == Some(target) {
+                style_sheet.hovered()
+            } else {
+                style_sheet.active()
+            }
+        };
+
+        let mut children = layout.children();
+
+        if let (Some(previous), Some(pages), Some(next)) =
+            (children.next(), children.next(), children.next())
+        {
+            self.draw_slot(
+                renderer,
+                previous.bounds(),
+                "‹",
+                style_of(Target::Previous),
+            );
+
+            let slots = pages.children().count();
+
+            for (item, layout) in items(self.current, self.total, slots)
+                .into_iter()
+                .zip(pages.children())
+            {
+                match item {
+                    Item::Page(page) => self.draw_slot(
+                        renderer,
+                        layout.bounds(),
+                        &(page + 1).to_string(),
+                        style_of(Target::Page(page)),
+                    ),
+                    Item::Ellipsis => self.draw_slot(
+                        renderer,
+                        layout.bounds(),
+                        "…",
+                        style_sheet.disabled(),
+                    ),
+                }
+            }
+
+            self.draw_slot(
+                renderer,
+                next.bounds(),
+                "›",
+                style_of(Target::Next),
+            );
+        }
+
+        if let (Some(input), Some(input_layout)) =
+            (&self.input, children.next())
+        {
+            Widget::<Input, Renderer>::draw(
+                input,
+                renderer,
+                style,
+                input_layout,
+                cursor_position,
+                viewport,
+            );
+        }
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.total.hash(state);
+        self.width.hash(state);
+        self.max_pages.hash(state);
+        self.spacing.hash(state);
+        self.padding.hash(state);
+        self.text_size.hash(state);
+        self.input.is_some().hash(state);
+    }
+
+    fn operate(
+        &mut self,
+        layout: Layout<'_>,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        operation.container(None, &mut |operation| {
+            if let (Some(input), Some(input_layout)) =
+                (&mut self.input, layout.children().nth(3))
+            {
+                Widget::<Input, Renderer>::operate(
+                    input,
+                    input_layout,
+                    &mut operation::MapRef { operation },
+                );
+            }
+        });
+    }
+}
+
+impl<'a, Message, Renderer> From<Pagination<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + text::Renderer,
+{
+    fn from(
+        pagination: Pagination<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(pagination)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pages(items: &[Item]) -> Vec<Option<usize>> {
+        items
+            .iter()
+            .map(|item| match item {
+                Item::Page(page) => Some(*page),
+                Item::Ellipsis => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn operations_reach_the_jump_input() {
+        use crate::layout;
+        use crate::renderer::Null;
+        use crate::widget::operation::{focusable, Outcome};
+
+        let mut state = State::new();
+
+        {
+            let mut element: Element<'_, (), Null> =
+                Pagination::new(&mut state, 0, 20, |_| ()).into();
+
+            let node = element.layout(
+                &Null::new(),
+                &layout::Limits::new(Size::ZERO, Size::new(800.0, 100.0)),
+            );
+
+            let mut operation: Box<dyn Operation<()>> =
+                Box::new(focusable::focus_next());
+
+            loop {
+                element.operate(Layout::new(&node), operation.as_mut());
+
+                match operation.finish() {
+                    Outcome::Chain(next) => operation = next,
+                    _ => break,
+                }
+            }
+        }
+
+        assert!(state.input.is_focused());
+    }
+
+    #[test]
+    fn items_fill_slots() {
+        for total in 0..30 {
+            for slots in 1..12 {
+                for current in 0..total {
+                    let items = items(current, total, slots);
+
+                    assert_eq!(items.len(), slots.min(total));
+                    assert!(items.contains(&Item::Page(current)));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn items_windows() {
+        assert_eq!(
+            pages(&items(0, 20, 9)),
+            vec![
+                Some(0),
+                Some(1),
+                Some(2),
+                Some(3),
+                Some(4),
+                Some(5),
+                Some(6),
+                None,
+                Some(19)
+            ]
+        );
+
+        assert_eq!(
+            pages(&items(10, 20, 9)),
+            vec![
+                Some(0),
+                None,
+                Some(8),
+                Some(9),
+                Some(10),
+                Some(11),
+                Some(12),
+                None,
+                Some(19)
+            ]
+        );
+
+        assert_eq!(
+            pages(&items(19, 20, 9)),
+            vec![
+                Some(0),
+                None,
+                Some(13),
+                Some(14),
+                Some(15),
+                Some(16),
+                Some(17),
+                Some(18),
+                Some(19)
+            ]
+        );
+
+        assert_eq!(pages(&items(7, 20, 3)), vec![Some(6), Some(7), Some(8)]);
+    }
+}
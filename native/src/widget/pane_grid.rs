@@ -4,7 +4,7 @@
 //!
 //! # Example
 //! The [`pane_grid` example] showcases how to use a [`PaneGrid`] with resizing,
-//! drag and drop, and hotkey support.
+//! drag and drop, floating and maximized panes, and hotkey support.
 //!
 //! [`pane_grid` example]: https://github.com/iced-rs/iced/tree/0.3/examples/pane_grid
 mod axis;
@@ -32,7 +32,9 @@ use crate::layout;
 use crate::mouse;
 use crate::overlay;
 use crate::renderer;
+use crate::theme;
 use crate::touch;
+use crate::widget::Operation;
 use crate::{
     Clipboard, Color, Element, Hasher, Layout, Length, Point, Rectangle, Shell,
     Size, Vector, Widget,
@@ -55,9 +57,11 @@ pub use iced_style::pane_grid::{Line, StyleSheet};
 /// * Tracking of the last active pane
 /// * Mouse-based resizing
 /// * Drag and drop to reorganize panes
+/// * Floating panes that can be moved and resized on top of the rest
+/// * Maximizing a pane temporarily by double-clicking its title bar
 /// * Hotkey support
 /// * Configurable modifier keys
-/// * [`State`] API to perform actions programmatically (`split`, `swap`, `resize`, etc.)
+/// * [`State`] API to perform actions programmatically (`split`, `swap`, `resize`, `float`, `maximize`, etc.)
 ///
 /// ## Example
 ///
@@ -99,7 +103,10 @@ pub struct PaneGrid<'a, Message, Renderer> {
     on_click: Option<Box<dyn Fn(Pane) -> Message + 'a>>,
     on_drag: Option<Box<dyn Fn(DragEvent) -> Message + 'a>>,
     on_resize: Option<(u16, Box<dyn Fn(ResizeEvent) -> Message + 'a>)>,
-    style_sheet: Box<dyn StyleSheet + 'a>,
+    on_float: Option<Box<dyn Fn(FloatEvent) -> Message + 'a>>,
+    on_maximize: Option<Box<dyn Fn(Pane) -> Message + 'a>>,
+    style_sheet: Option<Box<dyn StyleSheet + 'a>>,
+    class: Option<String>,
 }
 
 impl<'a, Message, Renderer> PaneGrid<'a, Message, Renderer>
@@ -108,19 +115,25 @@ where
 {
     /// Creates a [`PaneGrid`] with the given [`State`] and view function.
     ///
-    /// The view function will be called to display each [`Pane`] present in the
-    /// [`State`].
+    /// The view function will be called to display each visible [`Pane`]
+    /// present in the [`State`].
     pub fn new<T>(
         state: &'a mut State<T>,
         view: impl Fn(Pane, &'a mut T) -> Content<'a, Message, Renderer>,
     ) -> Self {
-        let elements = {
-            state
-                .panes
-                .iter_mut()
-                .map(|(pane, pane_state)| (*pane, view(*pane, pane_state)))
-                .collect()
-        };
+        let internal = &state.internal;
+
+        let mut elements: Vec<_> = state
+            .panes
+            .iter_mut()
+            .filter(|(pane, _)| !internal.is_hidden(pane))
+            .map(|(pane, pane_state)| (*pane, view(*pane, pane_state)))
+            .collect();
+
+        // Floating panes go last, from bottom to top
+        elements.sort_by_key(|(pane, _)| {
+            internal.floating_index(pane).map_or(0, |index| index + 1)
+        });
 
         Self {
             state: &mut state.internal,
@@ -131,7 +144,10 @@ where
             on_click: None,
             on_drag: None,
             on_resize: None,
-            style_sheet: Default::default(),
+            on_float: None,
+            on_maximize: None,
+            style_sheet: None,
+            class: None,
         }
     }
 
@@ -190,9 +206,47 @@ where
         self
     }
 
+    /// Enables the interactions of the floating panes of the [`PaneGrid`],
+    /// which will use the provided function to produce messages.
+    ///
+    /// A floating [`Pane`] can be moved by dragging its title bar and resized
+    /// by dragging its bottom right corner. Pressing a floating [`Pane`] that
+    /// is not on top also produces a message, so it can be raised.
+    pub fn on_float<F>(mut self, f: F) -> Self
+    where
+        F: 'a + Fn(FloatEvent) -> Message,
+    {
+        self.on_float = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the message that will be produced when the title bar of a tiled
+    /// [`Pane`] of the [`PaneGrid`] is double-clicked.
+    ///
+    /// It is meant to toggle whether the [`Pane`] is maximized, using
+    /// [`State::maximize`] and [`State::restore`].
+    pub fn on_maximize<F>(mut self, f: F) -> Self
+    where
+        F: 'a + Fn(Pane) -> Message,
+    {
+        self.on_maximize = Some(Box::new(f));
+        self
+    }
+
     /// Sets the style of the [`PaneGrid`].
     pub fn style(mut self, style: impl Into<Box<dyn StyleSheet + 'a>>) -> Self {
-        self.style_sheet = style.into();
+        self.style_sheet = Some(style.into());
+        self
+    }
+
+    /// Sets the style class of the [`PaneGrid`].
+    ///
+    /// The class is resolved through the current [`Theme`] when the
+    /// [`PaneGrid`] does not have a custom style sheet.
+    ///
+    /// [`Theme`]: crate::Theme
+    pub fn class(mut self, class: impl Into<String>) -> Self {
+        self.class = Some(class.into());
         self
     }
 }
@@ -217,21 +271,159 @@ where
                 shell.publish(on_click(*pane));
             }
 
-            if let Some(on_drag) = &self.on_drag {
-                if content.can_be_picked_at(layout, cursor_position) {
-                    let pane_position = layout.position();
+            if !content.can_be_picked_at(layout, cursor_position) {
+                return;
+            }
 
-                    let origin = cursor_position
-                        - Vector::new(pane_position.x, pane_position.y);
+            let click = self.state.click(pane, cursor_position);
 
-                    self.state.pick_pane(pane, origin);
+            if let Some(on_maximize) = &self.on_maximize {
+                if let mouse::click::Kind::Double = click.kind() {
+                    shell.publish(on_maximize(*pane));
 
-                    shell.publish(on_drag(DragEvent::Picked { pane: *pane }));
+                    return;
                 }
             }
+
+            if let Some(on_drag) = &self.on_drag {
+                let pane_position = layout.position();
+
+                let origin = cursor_position
+                    - Vector::new(pane_position.x, pane_position.y);
+
+                self.state.pick_pane(pane, origin);
+
+                shell.publish(on_drag(DragEvent::Picked { pane: *pane }));
+            }
         }
     }
 
+    fn click_floating_pane(
+        &mut self,
+        index: usize,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let grid_bounds = layout.bounds();
+
+        let (pane, content) = &self.elements[index];
+        let layout = layout.children().nth(index).expect("Get pane layout");
+
+        if let Some(on_click) = &self.on_click {
+            shell.publish(on_click(*pane));
+        }
+
+        if let Some(on_float) = &self.on_float {
+            let bounds = layout.bounds();
+
+            if is_over_grip(bounds, cursor_position) {
+                self.state.size_pane(pane);
+            } else if content.can_be_picked_at(layout, cursor_position) {
+                let origin = cursor_position - Vector::new(bounds.x, bounds.y);
+
+                self.state.move_pane(pane, origin);
+            }
+
+            // Pressing a floating pane raises it
+            if index + 1 < self.elements.len() {
+                shell.publish(on_float(FloatEvent {
+                    pane: *pane,
+                    bounds: Rectangle {
+                        x: bounds.x - grid_bounds.x,
+                        y: bounds.y - grid_bounds.y,
+                        ..bounds
+                    },
+                }));
+            }
+        }
+    }
+
+    fn trigger_float(
+        &mut self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        if let Some(on_float) = &self.on_float {
+            let bounds = layout.bounds();
+
+            let region_of = |pane| {
+                self.elements
+                    .iter()
+                    .zip(layout.children())
+                    .find(|((id, _), _)| *id == pane)
+                    .map(|(_, layout)| {
+                        let region = layout.bounds();
+
+                        Rectangle {
+                            x: region.x - bounds.x,
+                            y: region.y - bounds.y,
+                            ..region
+                        }
+                    })
+            };
+
+            let event = if let Some((pane, origin)) = self.state.moved_pane() {
+                region_of(pane).map(|region| FloatEvent {
+                    pane,
+                    bounds: Rectangle {
+                        x: cursor_position.x - bounds.x - origin.x,
+                        y: cursor_position.y - bounds.y - origin.y,
+                        ..region
+                    },
+                })
+            } else if let Some(pane) = self.state.sized_pane() {
+                region_of(pane).map(|region| FloatEvent {
+                    pane,
+                    bounds: Rectangle {
+                        width: (cursor_position.x - bounds.x - region.x)
+                            .max(MIN_FLOATING_SIZE),
+                        height: (cursor_position.y - bounds.y - region.y)
+                            .max(MIN_FLOATING_SIZE),
+                        ..region
+                    },
+                })
+            } else {
+                None
+            };
+
+            if let Some(FloatEvent {
+                pane,
+                bounds: region,
+            }) = event
+            {
+                shell.publish(on_float(FloatEvent {
+                    pane,
+                    bounds: fit(region, bounds.size()),
+                }));
+
+                return event::Status::Captured;
+            }
+        }
+
+        event::Status::Ignored
+    }
+
+    /// Returns the index of the topmost floating pane under the cursor, if
+    /// any.
+    fn floating_pane_at(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Option<usize> {
+        self.elements
+            .iter()
+            .zip(layout.children())
+            .enumerate()
+            .filter(|(_, ((pane, _), layout))| {
+                self.state.floating_index(pane).is_some()
+                    && layout.bounds().contains(cursor_position)
+            })
+            .map(|(index, _)| index)
+            .last()
+    }
+
     fn trigger_resize(
         &mut self,
         layout: Layout<'_>,
@@ -313,6 +505,17 @@ pub struct ResizeEvent {
     pub ratio: f32,
 }
 
+/// An event produced when a floating [`Pane`] of a [`PaneGrid`] is pressed,
+/// moved or resized.
+#[derive(Debug, Clone, Copy)]
+pub struct FloatEvent {
+    /// The floating [`Pane`].
+    pub pane: Pane,
+
+    /// The new bounds of the floating [`Pane`], relative to the [`PaneGrid`].
+    pub bounds: Rectangle,
+}
+
 impl<'a, Message, Renderer> Widget<Message, Renderer>
     for PaneGrid<'a, Message, Renderer>
 where
@@ -340,7 +543,11 @@ where
             .elements
             .iter()
             .filter_map(|(pane, element)| {
-                let region = regions.get(pane)?;
+                let region = match self.state.floating_bounds(pane) {
+                    Some(bounds) => fit(bounds, size),
+                    None => *regions.get(pane)?,
+                };
+
                 let size = Size::new(region.width, region.height);
 
                 let mut node =
@@ -366,6 +573,8 @@ where
     ) -> event::Status {
         let mut event_status = event::Status::Ignored;
 
+        let floating_pane = self.floating_pane_at(layout, cursor_position);
+
         match event {
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
             | Event::Touch(touch::Event::FingerPressed { .. }) => {
@@ -374,33 +583,46 @@ where
                 if bounds.contains(cursor_position) {
                     event_status = event::Status::Captured;
 
-                    match self.on_resize {
-                        Some((leeway, _)) => {
-                            let relative_cursor = Point::new(
-                                cursor_position.x - bounds.x,
-                                cursor_position.y - bounds.y,
-                            );
+                    if let Some(index) = floating_pane {
+                        self.click_floating_pane(
+                            index,
+                            layout,
+                            cursor_position,
+                            shell,
+                        );
+                    } else {
+                        match self.on_resize {
+                            Some((leeway, _)) => {
+                                let relative_cursor = Point::new(
+                                    cursor_position.x - bounds.x,
+                                    cursor_position.y - bounds.y,
+                                );
 
-                            let splits = self.state.split_regions(
-                                f32::from(self.spacing),
-                                Size::new(bounds.width, bounds.height),
-                            );
+                                let splits = self.state.split_regions(
+                                    f32::from(self.spacing),
+                                    Size::new(bounds.width, bounds.height),
+                                );
 
-                            let clicked_split = hovered_split(
-                                splits.iter(),
-                                f32::from(self.spacing + leeway),
-                                relative_cursor,
-                            );
+                                let clicked_split = hovered_split(
+                                    splits.iter(),
+                                    f32::from(self.spacing + leeway),
+                                    relative_cursor,
+                                );
 
-                            if let Some((split, axis, _)) = clicked_split {
-                                self.state.pick_split(&split, axis);
-                            } else {
+                                if let Some((split, axis, _)) = clicked_split {
+                                    self.state.pick_split(&split, axis);
+                                } else {
+                                    self.click_pane(
+                                        layout,
+                                        cursor_position,
+                                        shell,
+                                    );
+                                }
+                            }
+                            None => {
                                 self.click_pane(layout, cursor_position, shell);
                             }
                         }
-                        None => {
-                            self.click_pane(layout, cursor_position, shell);
-                        }
                     }
                 }
             }
@@ -409,14 +631,15 @@ where
             | Event::Touch(touch::Event::FingerLost { .. }) => {
                 if let Some((pane, _)) = self.state.picked_pane() {
                     if let Some(on_drag) = &self.on_drag {
-                        let mut dropped_region =
+                        let dropped_region =
                             self.elements.iter().zip(layout.children()).filter(
                                 |(_, layout)| {
                                     layout.bounds().contains(cursor_position)
                                 },
                             );
 
-                        let event = match dropped_region.next() {
+                        // The topmost pane is the last one
+                        let event = match dropped_region.last() {
                             Some(((target, _), _)) if pane != *target => {
                                 DragEvent::Dropped {
                                     pane,
@@ -432,7 +655,10 @@ where
                     self.state.idle();
 
                     event_status = event::Status::Captured;
-                } else if self.state.picked_split().is_some() {
+                } else if self.state.picked_split().is_some()
+                    || self.state.moved_pane().is_some()
+                    || self.state.sized_pane().is_some()
+                {
                     self.state.idle();
 
                     event_status = event::Status::Captured;
@@ -440,8 +666,9 @@ where
             }
             Event::Mouse(mouse::Event::CursorMoved { .. })
             | Event::Touch(touch::Event::FingerMoved { .. }) => {
-                event_status =
-                    self.trigger_resize(layout, cursor_position, shell);
+                event_status = self
+                    .trigger_resize(layout, cursor_position, shell)
+                    .merge(self.trigger_float(layout, cursor_position, shell));
             }
             _ => {}
         }
@@ -451,13 +678,14 @@ where
         self.elements
             .iter_mut()
             .zip(layout.children())
-            .map(|((pane, content), layout)| {
+            .enumerate()
+            .map(|(index, ((pane, content), layout))| {
                 let is_picked = picked_pane == Some(*pane);
 
                 content.on_event(
                     event.clone(),
                     layout,
-                    cursor_position,
+                    pane_cursor_position(index, floating_pane, cursor_position),
                     renderer,
                     clipboard,
                     shell,
@@ -477,6 +705,31 @@ where
             return mouse::Interaction::Grab;
         }
 
+        if self.state.moved_pane().is_some() {
+            return mouse::Interaction::Grabbing;
+        }
+
+        if self.state.sized_pane().is_some() {
+            return mouse::Interaction::ResizingDiagonallyDown;
+        }
+
+        if let Some(index) = self.floating_pane_at(layout, cursor_position) {
+            let (_, content) = &self.elements[index];
+            let layout = layout.children().nth(index).expect("Get pane layout");
+
+            if self.on_float.is_some()
+                && is_over_grip(layout.bounds(), cursor_position)
+            {
+                return mouse::Interaction::ResizingDiagonallyDown;
+            }
+
+            return content.mouse_interaction(
+                layout,
+                cursor_position,
+                viewport,
+            );
+        }
+
         let resize_axis =
             self.state.picked_split().map(|(_, axis)| axis).or_else(|| {
                 self.on_resize.as_ref().and_then(|(leeway, _)| {
@@ -525,7 +778,13 @@ where
         cursor_position: Point,
         viewport: &Rectangle,
     ) {
+        let theme = style
+            .theme
+            .resolve(theme::Widget::PaneGrid, self.class.as_deref());
+        let style_sheet = self.style_sheet.as_deref().unwrap_or(&theme);
+
         let picked_pane = self.state.picked_pane();
+        let floating_pane = self.floating_pane_at(layout, cursor_position);
 
         let picked_split = self
             .state
@@ -548,7 +807,7 @@ where
                 Some((axis, region + Vector::new(bounds.x, bounds.y), true))
             })
             .or_else(|| match self.on_resize {
-                Some((leeway, _)) => {
+                Some((leeway, _)) if floating_pane.is_none() => {
                     let bounds = layout.bounds();
 
                     let relative_cursor = Point::new(
@@ -572,48 +831,43 @@ where
                         false,
                     ))
                 }
-                None => None,
+                _ => None,
             });
 
-        let pane_cursor_position = if picked_pane.is_some() {
-            // TODO: Remove once cursor availability is encoded in the type
-            // system
-            Point::new(-1.0, -1.0)
-        } else {
-            cursor_position
-        };
+        let mut dragged_pane = None;
 
-        for ((id, pane), layout) in self.elements.iter().zip(layout.children())
+        for (index, ((id, pane), layout)) in
+            self.elements.iter().zip(layout.children()).enumerate()
         {
+            let cursor_position = if picked_pane.is_some() {
+                // TODO: Remove once cursor availability is encoded in the type
+                // system
+                Point::new(-1.0, -1.0)
+            } else {
+                pane_cursor_position(index, floating_pane, cursor_position)
+            };
+
             match picked_pane {
                 Some((dragging, origin)) if *id == dragging => {
-                    let bounds = layout.bounds();
-
-                    renderer.with_translation(
-                        cursor_position
-                            - Point::new(
-                                bounds.x + origin.x,
-                                bounds.y + origin.y,
-                            ),
-                        |renderer| {
-                            renderer.with_layer(bounds, |renderer| {
-                                pane.draw(
-                                    renderer,
-                                    style,
-                                    layout,
-                                    pane_cursor_position,
-                                    viewport,
-                                );
-                            });
-                        },
-                    );
+                    dragged_pane = Some((pane, layout, origin));
+                }
+                _ if self.state.floating_index(id).is_some() => {
+                    renderer.with_layer(layout.bounds(), |renderer| {
+                        pane.draw(
+                            renderer,
+                            style,
+                            layout,
+                            cursor_position,
+                            viewport,
+                        );
+                    });
                 }
                 _ => {
                     pane.draw(
                         renderer,
                         style,
                         layout,
-                        pane_cursor_position,
+                        cursor_position,
                         viewport,
                     );
                 }
@@ -622,9 +876,9 @@ where
 
         if let Some((axis, split_region, is_picked)) = picked_split {
             let highlight = if is_picked {
-                self.style_sheet.picked_split()
+                style_sheet.picked_split()
             } else {
-                self.style_sheet.hovered_split()
+                style_sheet.hovered_split()
             };
 
             if let Some(highlight) = highlight {
@@ -658,6 +912,27 @@ where
                 );
             }
         }
+
+        // The dragged pane is drawn on top of everything else
+        if let Some((pane, layout, origin)) = dragged_pane {
+            let bounds = layout.bounds();
+
+            renderer.with_translation(
+                cursor_position
+                    - Point::new(bounds.x + origin.x, bounds.y + origin.y),
+                |renderer| {
+                    renderer.with_layer(bounds, |renderer| {
+                        pane.draw(
+                            renderer,
+                            style,
+                            layout,
+                            Point::new(-1.0, -1.0),
+                            viewport,
+                        );
+                    });
+                },
+            );
+        }
     }
 
     fn hash_layout(&self, state: &mut Hasher) {
@@ -675,15 +950,30 @@ where
         }
     }
 
+    fn operate(
+        &mut self,
+        layout: Layout<'_>,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        operation.container(None, &mut |operation| {
+            self.elements.iter_mut().zip(layout.children()).for_each(
+                |((_, content), layout)| {
+                    content.operate(layout, operation);
+                },
+            )
+        });
+    }
+
     fn overlay(
         &mut self,
         layout: Layout<'_>,
+        viewport: &Rectangle,
     ) -> Option<overlay::Element<'_, Message, Renderer>> {
-        self.elements
-            .iter_mut()
-            .zip(layout.children())
-            .filter_map(|((_, pane), layout)| pane.overlay(layout))
-            .next()
+        overlay::from_children(
+            self.elements.iter_mut().zip(layout.children()).filter_map(
+                |((_, pane), layout)| pane.overlay(layout, viewport),
+            ),
+        )
     }
 }
 
@@ -703,6 +993,46 @@ where
 /*
  * Helpers
  */
+
+/// The size of the corner of a floating pane that can be dragged to resize it.
+const GRIP_SIZE: f32 = 10.0;
+
+/// The minimum size of a floating pane resized by dragging its corner.
+const MIN_FLOATING_SIZE: f32 = 50.0;
+
+fn is_over_grip(bounds: Rectangle, cursor_position: Point) -> bool {
+    bounds.contains(cursor_position)
+        && cursor_position.x >= bounds.x + bounds.width - GRIP_SIZE
+        && cursor_position.y >= bounds.y + bounds.height - GRIP_SIZE
+}
+
+/// Moves and shrinks the given bounds of a floating pane to fit in a
+/// [`PaneGrid`] of the given size.
+fn fit(bounds: Rectangle, size: Size) -> Rectangle {
+    let width = bounds.width.min(size.width);
+    let height = bounds.height.min(size.height);
+
+    Rectangle {
+        x: bounds.x.max(0.0).min(size.width - width),
+        y: bounds.y.max(0.0).min(size.height - height),
+        width,
+        height,
+    }
+}
+
+/// Hides the cursor from the panes below the floating pane under it.
+fn pane_cursor_position(
+    index: usize,
+    floating_pane: Option<usize>,
+    cursor_position: Point,
+) -> Point {
+    match floating_pane {
+        // TODO: Remove once cursor availability is encoded in the type system
+        Some(top) if index < top => Point::new(-1.0, -1.0),
+        _ => cursor_position,
+    }
+}
+
 fn hovered_split<'a>(
     splits: impl Iterator<Item = (&'a Split, &'a (Axis, Rectangle, f32))>,
     spacing: f32,
@@ -2,6 +2,10 @@ use crate::Rectangle;
 
 /// A fixed reference line for the measurement of coordinates.
 #[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "persistence",
+    derive(serde::Serialize, serde::Deserialize)
+)]
 pub enum Axis {
     /// The horizontal axis: —
     Horizontal,
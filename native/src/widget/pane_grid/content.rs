@@ -5,6 +5,7 @@ use crate::overlay;
 use crate::renderer;
 use crate::widget::container;
 use crate::widget::pane_grid::TitleBar;
+use crate::widget::Operation;
 use crate::{
     Clipboard, Element, Hasher, Layout, Point, Rectangle, Shell, Size,
 };
@@ -16,7 +17,7 @@ use crate::{
 pub struct Content<'a, Message, Renderer> {
     title_bar: Option<TitleBar<'a, Message, Renderer>>,
     body: Element<'a, Message, Renderer>,
-    style_sheet: Box<dyn container::StyleSheet + 'a>,
+    style_sheet: Option<Box<dyn container::StyleSheet + 'a>>,
 }
 
 impl<'a, Message, Renderer> Content<'a, Message, Renderer>
@@ -28,7 +29,7 @@ where
         Self {
             title_bar: None,
             body: body.into(),
-            style_sheet: Default::default(),
+            style_sheet: None,
         }
     }
 
@@ -46,7 +47,7 @@ where
         mut self,
         style_sheet: impl Into<Box<dyn container::StyleSheet + 'a>>,
     ) -> Self {
-        self.style_sheet = style_sheet.into();
+        self.style_sheet = Some(style_sheet.into());
         self
     }
 }
@@ -66,10 +67,12 @@ where
         cursor_position: Point,
         viewport: &Rectangle,
     ) {
+        let style_sheet = self.style_sheet.as_deref().unwrap_or(&style.theme);
+
         let bounds = layout.bounds();
 
         {
-            let style = self.style_sheet.style();
+            let style = style_sheet.style();
 
             container::draw_background(renderer, &style, bounds);
         }
@@ -242,20 +245,36 @@ where
         self.body.hash_layout(state);
     }
 
+    pub(crate) fn operate(
+        &mut self,
+        layout: Layout<'_>,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        if let Some(title_bar) = &mut self.title_bar {
+            let mut children = layout.children();
+
+            title_bar.operate(children.next().unwrap(), operation);
+            self.body.operate(children.next().unwrap(), operation);
+        } else {
+            self.body.operate(layout, operation);
+        }
+    }
+
     pub(crate) fn overlay(
         &mut self,
         layout: Layout<'_>,
+        viewport: &Rectangle,
     ) -> Option<overlay::Element<'_, Message, Renderer>> {
         if let Some(title_bar) = self.title_bar.as_mut() {
             let mut children = layout.children();
             let title_bar_layout = children.next()?;
 
-            match title_bar.overlay(title_bar_layout) {
+            match title_bar.overlay(title_bar_layout, viewport) {
                 Some(overlay) => Some(overlay),
-                None => self.body.overlay(children.next()?),
+                None => self.body.overlay(children.next()?, viewport),
             }
         } else {
-            self.body.overlay(layout)
+            self.body.overlay(layout, viewport)
         }
     }
 }
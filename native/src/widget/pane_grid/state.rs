@@ -1,3 +1,4 @@
+use crate::mouse;
 use crate::widget::pane_grid::{
     Axis, Configuration, Direction, Node, Pane, Split,
 };
@@ -8,7 +9,8 @@ use std::collections::{BTreeMap, HashMap};
 /// The state of a [`PaneGrid`].
 ///
 /// It keeps track of the state of each [`Pane`] and the position of each
-/// [`Split`].
+/// [`Split`], together with the panes that are floating and the one that is
+/// maximized, if any.
 ///
 /// The [`State`] needs to own any mutable contents a [`Pane`] may need. This is
 /// why this struct is generic over the type `T`. Values of this type are
@@ -48,6 +50,9 @@ impl<T> State<T> {
                 layout,
                 last_id,
                 action: Action::Idle,
+                floating: Vec::new(),
+                maximized: None,
+                last_click: None,
             },
         }
     }
@@ -81,6 +86,8 @@ impl<T> State<T> {
     }
 
     /// Returns the layout of the [`State`].
+    ///
+    /// Floating panes are not part of the layout.
     pub fn layout(&self) -> &Node {
         &self.internal.layout
     }
@@ -167,6 +174,16 @@ impl<T> State<T> {
                 }
             }
         });
+
+        let floating = self.internal.floating.iter_mut().map(|(pane, _)| pane);
+
+        for pane in floating.chain(&mut self.internal.maximized) {
+            if pane == a {
+                *pane = *b;
+            } else if pane == b {
+                *pane = *a;
+            }
+        }
     }
 
     /// Resizes two panes by setting the position of the provided [`Split`].
@@ -185,14 +202,128 @@ impl<T> State<T> {
 
     /// Closes the given [`Pane`] and returns its internal state and its closest
     /// sibling, if it exists.
+    ///
+    /// The sibling of a floating [`Pane`] is the first tiled [`Pane`].
     pub fn close(&mut self, pane: &Pane) -> Option<(T, Pane)> {
-        if let Some(sibling) = self.internal.layout.remove(pane) {
-            self.panes.remove(pane).map(|state| (state, sibling))
-        } else {
-            None
+        let sibling = match self.internal.floating_index(pane) {
+            Some(index) => {
+                let _ = self.internal.floating.remove(index);
+
+                self.internal.layout.first_pane()
+            }
+            None => self.internal.layout.remove(pane)?,
+        };
+
+        if self.internal.maximized == Some(*pane) {
+            self.internal.maximized = None;
+        }
+
+        self.panes.remove(pane).map(|state| (state, sibling))
+    }
+
+    /// Maximizes the given tiled [`Pane`], so it fills the whole [`PaneGrid`]
+    /// and hides the rest of the tiled panes until the [`State`] is restored.
+    ///
+    /// Floating panes cannot be maximized, and they stay on top of the
+    /// maximized [`Pane`].
+    ///
+    /// If you want to maximize panes when their title bar is double-clicked,
+    /// you will need to call this method, or [`State::restore`], when handling
+    /// the message of [`PaneGrid::on_maximize`].
+    ///
+    /// [`PaneGrid`]: crate::widget::PaneGrid
+    /// [`PaneGrid::on_maximize`]: crate::widget::PaneGrid::on_maximize
+    pub fn maximize(&mut self, pane: &Pane) {
+        if self.internal.layout.find(pane).is_some() {
+            self.internal.maximized = Some(*pane);
+        }
+    }
+
+    /// Restores the layout of the [`State`] after a [`Pane`] was maximized.
+    pub fn restore(&mut self) {
+        self.internal.maximized = None;
+    }
+
+    /// Returns the maximized [`Pane`] of the [`State`], if any.
+    pub fn maximized(&self) -> Option<Pane> {
+        self.internal.maximized
+    }
+
+    /// Pops the given tiled [`Pane`] out of the layout, making it float on top
+    /// of the rest of the panes with the given bounds.
+    ///
+    /// The bounds are relative to the [`PaneGrid`]. The [`Pane`] keeps its
+    /// internal state, and the space it leaves is taken by its sibling.
+    ///
+    /// Returns `false` if the [`Pane`] is not tiled or it is the last tiled
+    /// [`Pane`], which cannot float.
+    ///
+    /// [`PaneGrid`]: crate::widget::PaneGrid
+    pub fn float(&mut self, pane: &Pane, bounds: Rectangle) -> bool {
+        if self.internal.layout.remove(pane).is_none() {
+            return false;
+        }
+
+        if self.internal.maximized == Some(*pane) {
+            self.internal.maximized = None;
+        }
+
+        self.internal.floating.push((*pane, bounds));
+
+        true
+    }
+
+    /// Docks the given floating [`Pane`] back into the layout, splitting the
+    /// target [`Pane`] in the given [`Axis`].
+    ///
+    /// Returns the new [`Split`], if the [`Pane`] was floating and the target
+    /// is tiled.
+    pub fn dock(
+        &mut self,
+        pane: &Pane,
+        target: &Pane,
+        axis: Axis,
+    ) -> Option<Split> {
+        let index = self.internal.floating_index(pane)?;
+        let node = self.internal.layout.find(target)?;
+
+        let new_split = {
+            self.internal.last_id = self.internal.last_id.checked_add(1)?;
+
+            Split(self.internal.last_id)
+        };
+
+        node.split(new_split, axis, *pane);
+
+        let _ = self.internal.floating.remove(index);
+
+        Some(new_split)
+    }
+
+    /// Moves or resizes the given floating [`Pane`] to the given bounds,
+    /// raising it on top of the rest of floating panes.
+    ///
+    /// The bounds are relative to the [`PaneGrid`].
+    ///
+    /// If you want to enable the interactions of floating panes in your
+    /// [`PaneGrid`], you will need to call this method when handling a
+    /// [`FloatEvent`].
+    ///
+    /// [`PaneGrid`]: crate::widget::PaneGrid
+    /// [`FloatEvent`]: crate::widget::pane_grid::FloatEvent
+    pub fn place(&mut self, pane: &Pane, bounds: Rectangle) {
+        if let Some(index) = self.internal.floating_index(pane) {
+            let _ = self.internal.floating.remove(index);
+
+            self.internal.floating.push((*pane, bounds));
         }
     }
 
+    /// Returns the bounds of the given [`Pane`] if it is floating.
+    pub fn floating(&self, pane: &Pane) -> Option<Rectangle> {
+        self.internal.floating_bounds(pane)
+    }
+
     fn distribute_content(
         panes: &mut HashMap<Pane, T>,
         content: Configuration<T>,
@@ -229,6 +360,10 @@ pub struct Internal {
     layout: Node,
     last_id: usize,
     action: Action,
+    /// The floating panes with their bounds, from bottom to top
+    floating: Vec<(Pane, Rectangle)>,
+    maximized: Option<Pane>,
+    last_click: Option<(Pane, mouse::Click)>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -236,6 +371,8 @@ pub enum Action {
     Idle,
     Dragging { pane: Pane, origin: Point },
     Resizing { split: Split, axis: Axis },
+    Moving { pane: Pane, origin: Point },
+    Sizing { pane: Pane },
 }
 
 impl Internal {
@@ -253,12 +390,38 @@ impl Internal {
         }
     }
 
+    pub fn moved_pane(&self) -> Option<(Pane, Point)> {
+        match self.action {
+            Action::Moving { pane, origin } => Some((pane, origin)),
+            _ => None,
+        }
+    }
+
+    pub fn sized_pane(&self) -> Option<Pane> {
+        match self.action {
+            Action::Sizing { pane } => Some(pane),
+            _ => None,
+        }
+    }
+
     pub fn pane_regions(
         &self,
         spacing: f32,
         size: Size,
     ) -> BTreeMap<Pane, Rectangle> {
-        self.layout.pane_regions(spacing, size)
+        match self.maximized {
+            Some(pane) => std::iter::once((
+                pane,
+                Rectangle {
+                    x: 0.0,
+                    y: 0.0,
+                    width: size.width,
+                    height: size.height,
+                },
+            ))
+            .collect(),
+            None => self.layout.pane_regions(spacing, size),
+        }
     }
 
     pub fn split_regions(
@@ -266,7 +429,32 @@ impl Internal {
         spacing: f32,
         size: Size,
     ) -> BTreeMap<Split, (Axis, Rectangle, f32)> {
-        self.layout.split_regions(spacing, size)
+        match self.maximized {
+            Some(_) => BTreeMap::new(),
+            None => self.layout.split_regions(spacing, size),
+        }
+    }
+
+    pub fn is_hidden(&self, pane: &Pane) -> bool {
+        match self.maximized {
+            Some(maximized) => {
+                maximized != *pane && self.floating_index(pane).is_none()
+            }
+            None => false,
+        }
+    }
+
+    pub fn floating_index(&self, pane: &Pane) -> Option<usize> {
+        self.floating
+            .iter()
+            .position(|(floating, _)| floating == pane)
+    }
+
+    pub fn floating_bounds(&self, pane: &Pane) -> Option<Rectangle> {
+        self.floating
+            .iter()
+            .find(|(floating, _)| floating == pane)
+            .map(|(_, bounds)| *bounds)
     }
 
     pub fn pick_pane(&mut self, pane: &Pane, origin: Point) {
@@ -289,6 +477,29 @@ impl Internal {
         };
     }
 
+    pub fn move_pane(&mut self, pane: &Pane, origin: Point) {
+        self.action = Action::Moving {
+            pane: *pane,
+            origin,
+        };
+    }
+
+    pub fn size_pane(&mut self, pane: &Pane) {
+        self.action = Action::Sizing { pane: *pane };
+    }
+
+    pub fn click(&mut self, pane: &Pane, position: Point) -> mouse::Click {
+        let previous = match self.last_click {
+            Some((last, click)) if last == *pane => Some(click),
+            _ => None,
+        };
+
+        let click = mouse::Click::new(position, previous);
+        self.last_click = Some((*pane, click));
+
+        click
+    }
+
     pub fn idle(&mut self) {
         self.action = Action::Idle;
     }
@@ -297,5 +508,14 @@ impl Internal {
         use std::hash::Hash;
 
         self.layout.hash(hasher);
+        self.maximized.hash(hasher);
+
+        for (pane, bounds) in &self.floating {
+            pane.hash(hasher);
+            bounds.x.to_bits().hash(hasher);
+            bounds.y.to_bits().hash(hasher);
+            bounds.width.to_bits().hash(hasher);
+            bounds.height.to_bits().hash(hasher);
+        }
     }
 }
@@ -4,6 +4,7 @@ use crate::mouse;
 use crate::overlay;
 use crate::renderer;
 use crate::widget::container;
+use crate::widget::Operation;
 use crate::{
     Clipboard, Element, Hasher, Layout, Padding, Point, Rectangle, Shell, Size,
 };
@@ -17,7 +18,7 @@ pub struct TitleBar<'a, Message, Renderer> {
     controls: Option<Element<'a, Message, Renderer>>,
     padding: Padding,
     always_show_controls: bool,
-    style_sheet: Box<dyn container::StyleSheet + 'a>,
+    style_sheet: Option<Box<dyn container::StyleSheet + 'a>>,
 }
 
 impl<'a, Message, Renderer> TitleBar<'a, Message, Renderer>
@@ -34,7 +35,7 @@ where
             controls: None,
             padding: Padding::ZERO,
             always_show_controls: false,
-            style_sheet: Default::default(),
+            style_sheet: None,
         }
     }
 
@@ -58,7 +59,7 @@ where
         mut self,
         style: impl Into<Box<dyn container::StyleSheet + 'a>>,
     ) -> Self {
-        self.style_sheet = style.into();
+        self.style_sheet = Some(style.into());
         self
     }
 
@@ -92,10 +93,16 @@ where
         viewport: &Rectangle,
         show_controls: bool,
     ) {
+        let style_sheet = self
+            .style_sheet
+            .as_deref()
+            .unwrap_or(&inherited_style.theme);
+
         let bounds = layout.bounds();
-        let style = self.style_sheet.style();
+        let style = style_sheet.style();
         let inherited_style = renderer::Style {
             text_color: style.text_color.unwrap_or(inherited_style.text_color),
+            theme: inherited_style.theme.clone(),
         };
 
         container::draw_background(renderer, &style, bounds);
@@ -282,9 +289,28 @@ where
         }
     }
 
+    pub(crate) fn operate(
+        &mut self,
+        layout: Layout<'_>,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let mut children = layout.children();
+        let padded = children.next().unwrap();
+
+        let mut children = padded.children();
+        let title_layout = children.next().unwrap();
+
+        self.content.operate(title_layout, operation);
+
+        if let Some(controls) = &mut self.controls {
+            controls.operate(children.next().unwrap(), operation);
+        }
+    }
+
     pub(crate) fn overlay(
         &mut self,
         layout: Layout<'_>,
+        viewport: &Rectangle,
     ) -> Option<overlay::Element<'_, Message, Renderer>> {
         let mut children = layout.children();
         let padded = children.next()?;
@@ -296,11 +322,11 @@ where
             content, controls, ..
         } = self;
 
-        content.overlay(title_layout).or_else(move || {
+        content.overlay(title_layout, viewport).or_else(move || {
             controls.as_mut().and_then(|controls| {
                 let controls_layout = children.next()?;
 
-                controls.overlay(controls_layout)
+                controls.overlay(controls_layout, viewport)
             })
         })
     }
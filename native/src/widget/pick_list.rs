@@ -8,6 +8,7 @@ use crate::overlay;
 use crate::overlay::menu::{self, Menu};
 use crate::renderer;
 use crate::text::{self, Text};
+use crate::theme;
 use crate::touch;
 use crate::{
     Clipboard, Element, Hasher, Layout, Length, Padding, Point, Rectangle,
@@ -17,7 +18,13 @@ use std::borrow::Cow;
 
 pub use iced_style::pick_list::{Style, StyleSheet};
 
+/// A function that may produce the tooltip of an option.
+type TooltipFn<'a, T> = dyn Fn(&T) -> Option<String> + 'a;
+
 /// A widget for selecting a single value from a list of options.
+///
+/// While its menu is open, the `Enter` key selects the hovered option and
+/// the `Escape` key closes the menu.
 #[allow(missing_debug_implementations)]
 pub struct PickList<'a, T, Message, Renderer: text::Renderer>
 where
@@ -36,7 +43,9 @@ where
     padding: Padding,
     text_size: Option<u16>,
     font: Renderer::Font,
-    style_sheet: Box<dyn StyleSheet + 'a>,
+    style_sheet: Option<Box<dyn StyleSheet + 'a>>,
+    class: Option<String>,
+    option_tooltip: Option<Box<TooltipFn<'a, T>>>,
 }
 
 /// The local state of a [`PickList`].
@@ -101,7 +110,9 @@ where
             text_size: None,
             padding: Self::DEFAULT_PADDING,
             font: Default::default(),
-            style_sheet: Default::default(),
+            style_sheet: None,
+            class: None,
+            option_tooltip: None,
         }
     }
 
@@ -140,7 +151,28 @@ where
         mut self,
         style_sheet: impl Into<Box<dyn StyleSheet + 'a>>,
     ) -> Self {
-        self.style_sheet = style_sheet.into();
+        self.style_sheet = Some(style_sheet.into());
+        self
+    }
+
+    /// Sets the style class of the [`PickList`].
+    ///
+    /// The class is resolved through the current [`Theme`] when the
+    /// [`PickList`] does not have a custom style sheet.
+    ///
+    /// [`Theme`]: crate::Theme
+    pub fn class(mut self, class: impl Into<String>) -> Self {
+        self.class = Some(class.into());
+        self
+    }
+
+    /// Sets the function that produces the tooltip of the hovered option of
+    /// the [`PickList`], if any.
+    pub fn option_tooltip(
+        mut self,
+        tooltip: impl Fn(&T) -> Option<String> + 'a,
+    ) -> Self {
+        self.option_tooltip = Some(Box::new(tooltip));
         self
     }
 }
@@ -317,6 +349,22 @@ where
 
                 event::Status::Captured
             }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code:
+                    keyboard::KeyCode::Enter
+                    | keyboard::KeyCode::NumpadEnter
+                    | keyboard::KeyCode::Escape,
+                ..
+            }) if *self.is_open => {
+                // The menu has already handled the event
+                if let Some(last_selection) = self.last_selection.take() {
+                    shell.publish((self.on_selected)(last_selection));
+                }
+
+                *self.is_open = false;
+
+                event::Status::Captured
+            }
             Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
                 *self.keyboard_modifiers = modifiers;
 
@@ -345,19 +393,24 @@ where
     fn draw(
         &self,
         renderer: &mut Renderer,
-        _style: &renderer::Style,
+        style: &renderer::Style,
         layout: Layout<'_>,
         cursor_position: Point,
         _viewport: &Rectangle,
     ) {
+        let theme = style
+            .theme
+            .resolve(theme::Widget::PickList, self.class.as_deref());
+        let style_sheet = self.style_sheet.as_deref().unwrap_or(&theme);
+
         let bounds = layout.bounds();
         let is_mouse_over = bounds.contains(cursor_position);
         let is_selected = self.selected.is_some();
 
         let style = if is_mouse_over {
-            self.style_sheet.hovered()
+            style_sheet.hovered()
         } else {
-            self.style_sheet.active()
+            style_sheet.active()
         };
 
         renderer.fill_quad(
@@ -415,25 +468,44 @@ where
     fn overlay(
         &mut self,
         layout: Layout<'_>,
+        viewport: &Rectangle,
     ) -> Option<overlay::Element<'_, Message, Renderer>> {
         if *self.is_open {
             let bounds = layout.bounds();
 
+            // The menu closes once the pick list is scrolled out of view
+            if viewport.intersection(&bounds).is_none() {
+                *self.is_open = false;
+
+                return None;
+            }
+
             let mut menu = Menu::new(
-                &mut self.menu,
+                self.menu,
                 &self.options,
-                &mut self.hovered_option,
-                &mut self.last_selection,
+                self.hovered_option,
+                self.last_selection,
             )
             .width(bounds.width.round() as u16)
             .padding(self.padding)
-            .font(self.font.clone())
-            .style(self.style_sheet.menu());
+            .font(self.font.clone());
+
+            if let Some(style_sheet) = &self.style_sheet {
+                menu = menu.style(style_sheet.menu());
+            }
+
+            if let Some(class) = &self.class {
+                menu = menu.class(class);
+            }
 
             if let Some(text_size) = self.text_size {
                 menu = menu.text_size(text_size);
             }
 
+            if let Some(tooltip) = &self.option_tooltip {
+                menu = menu.tooltip(tooltip.as_ref());
+            }
+
             Some(menu.overlay(layout.position(), bounds.height))
         } else {
             None
@@ -453,3 +525,143 @@ where
         Element::new(self)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::simulator::Simulator;
+    use crate::{Background, Font, Vector};
+
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    /// A renderer that records the text it draws.
+    #[derive(Debug, Default)]
+    struct Recorder {
+        texts: Rc<RefCell<Vec<String>>>,
+    }
+
+    impl crate::Renderer for Recorder {
+        fn with_layer(
+            &mut self,
+            _bounds: Rectangle,
+            f: impl FnOnce(&mut Self),
+        ) {
+            f(self)
+        }
+
+        fn with_translation(
+            &mut self,
+            _translation: Vector,
+            f: impl FnOnce(&mut Self),
+        ) {
+            f(self)
+        }
+
+        fn with_opacity(&mut self, _opacity: f32, f: impl FnOnce(&mut Self)) {
+            f(self)
+        }
+
+        fn with_scale(
+            &mut self,
+            _origin: Point,
+            _scale: f32,
+            f: impl FnOnce(&mut Self),
+        ) {
+            f(self)
+        }
+
+        fn clear(&mut self) {
+            self.texts.borrow_mut().clear();
+        }
+
+        fn fill_quad(
+            &mut self,
+            _quad: renderer::Quad,
+            _background: impl Into<Background>,
+        ) {
+        }
+    }
+
+    impl text::Renderer for Recorder {
+        type Font = Font;
+
+        const ICON_FONT: Font = Font::Default;
+        const CHECKMARK_ICON: char = '0';
+        const ARROW_DOWN_ICON: char = '0';
+
+        fn default_size(&self) -> u16 {
+            20
+        }
+
+        fn measure(
+            &self,
+            content: &str,
+            size: u16,
+            _font: Font,
+            _bounds: Size,
+        ) -> (f32, f32) {
+            (
+                content.len() as f32 * f32::from(size) / 2.0,
+                f32::from(size),
+            )
+        }
+
+        fn baseline(&self, size: u16, _font: Font) -> f32 {
+            f32::from(size)
+        }
+
+        fn hit_test(
+            &self,
+            _contents: &str,
+            _size: f32,
+            _font: Self::Font,
+            _bounds: Size,
+            _point: Point,
+            _nearest_only: bool,
+        ) -> Option<text::Hit> {
+            None
+        }
+
+        fn fill_text(&mut self, text: text::Text<'_, Self::Font>) {
+            self.texts.borrow_mut().push(text.content.to_owned());
+        }
+    }
+
+    #[test]
+    fn shows_the_tooltip_of_the_hovered_option_on_top_of_the_menu() {
+        let mut state = State::default();
+        let texts = Rc::new(RefCell::new(Vec::new()));
+
+        let pick_list = PickList::new(
+            &mut state,
+            vec!["Apple", "Banana"],
+            None,
+            |option: &str| option,
+        )
+        .width(Length::Units(100))
+        .option_tooltip(|option| Some(format!("About {}", option)));
+
+        let mut simulator = Simulator::with_renderer(
+            pick_list,
+            Size::new(300.0, 300.0),
+            Recorder {
+                texts: texts.clone(),
+            },
+        );
+
+        let _ = simulator.click(Point::new(5.0, 5.0));
+
+        // The options are 30 units tall and start under the pick list
+        let _ = simulator.point_at(Point::new(5.0, 75.0));
+        let _ = simulator.draw();
+
+        let texts = texts.borrow();
+        let menu = texts.iter().position(|text| text == "Banana");
+        let tooltip = texts.iter().position(|text| text == "About Banana");
+
+        assert!(menu.is_some());
+        assert!(tooltip > menu);
+        assert!(!texts.iter().any(|text| text == "About Apple"));
+    }
+}
@@ -1,6 +1,7 @@
 //! Provide progress feedback to your users.
 use crate::layout;
 use crate::renderer;
+use crate::theme;
 use crate::{
     Color, Element, Hasher, Layout, Length, Point, Rectangle, Size, Widget,
 };
@@ -26,7 +27,8 @@ pub struct ProgressBar<'a> {
     value: f32,
     width: Length,
     height: Option<Length>,
-    style_sheet: Box<dyn StyleSheet + 'a>,
+    style_sheet: Option<Box<dyn StyleSheet + 'a>>,
+    class: Option<String>,
 }
 
 impl<'a> ProgressBar<'a> {
@@ -44,7 +46,8 @@ impl<'a> ProgressBar<'a> {
             range,
             width: Length::Fill,
             height: None,
-            style_sheet: Default::default(),
+            style_sheet: None,
+            class: None,
         }
     }
 
@@ -65,7 +68,18 @@ impl<'a> ProgressBar<'a> {
         mut self,
         style_sheet: impl Into<Box<dyn StyleSheet + 'a>>,
     ) -> Self {
-        self.style_sheet = style_sheet.into();
+        self.style_sheet = Some(style_sheet.into());
+        self
+    }
+
+    /// Sets the style class of the [`ProgressBar`].
+    ///
+    /// The class is resolved through the current [`Theme`] when the
+    /// [`ProgressBar`] does not have a custom style sheet.
+    ///
+    /// [`Theme`]: crate::Theme
+    pub fn class(mut self, class: impl Into<String>) -> Self {
+        self.class = Some(class.into());
         self
     }
 }
@@ -99,11 +113,16 @@ where
     fn draw(
         &self,
         renderer: &mut Renderer,
-        _style: &renderer::Style,
+        style: &renderer::Style,
         layout: Layout<'_>,
         _cursor_position: Point,
         _viewport: &Rectangle,
     ) {
+        let theme = style
+            .theme
+            .resolve(theme::Widget::ProgressBar, self.class.as_deref());
+        let style_sheet = self.style_sheet.as_deref().unwrap_or(&theme);
+
         let bounds = layout.bounds();
         let (range_start, range_end) = self.range.clone().into_inner();
 
@@ -114,7 +133,7 @@ where
                 / (range_end - range_start)
         };
 
-        let style = self.style_sheet.style();
+        let style = style_sheet.style();
 
         renderer.fill_quad(
             renderer::Quad {
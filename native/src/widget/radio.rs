@@ -7,6 +7,7 @@ use crate::layout;
 use crate::mouse;
 use crate::renderer;
 use crate::text;
+use crate::theme;
 use crate::touch;
 use crate::widget::{self, Row, Text};
 use crate::{
@@ -53,7 +54,8 @@ pub struct Radio<'a, Message, Renderer: text::Renderer> {
     text_size: Option<u16>,
     text_color: Option<Color>,
     font: Renderer::Font,
-    style_sheet: Box<dyn StyleSheet + 'a>,
+    style_sheet: Option<Box<dyn StyleSheet + 'a>>,
+    class: Option<String>,
 }
 
 impl<'a, Message, Renderer: text::Renderer> Radio<'a, Message, Renderer>
@@ -94,7 +96,8 @@ where
             text_size: None,
             text_color: None,
             font: Default::default(),
-            style_sheet: Default::default(),
+            style_sheet: None,
+            class: None,
         }
     }
 
@@ -139,7 +142,18 @@ where
         mut self,
         style_sheet: impl Into<Box<dyn StyleSheet + 'a>>,
     ) -> Self {
-        self.style_sheet = style_sheet.into();
+        self.style_sheet = Some(style_sheet.into());
+        self
+    }
+
+    /// Sets the style class of the [`Radio`].
+    ///
+    /// The class is resolved through the current [`Theme`] when the
+    /// [`Radio`] does not have a custom style sheet.
+    ///
+    /// [`Theme`]: crate::Theme
+    pub fn class(mut self, class: impl Into<String>) -> Self {
+        self.class = Some(class.into());
         self
     }
 }
@@ -225,6 +239,11 @@ where
         cursor_position: Point,
         _viewport: &Rectangle,
     ) {
+        let theme = style
+            .theme
+            .resolve(theme::Widget::Radio, self.class.as_deref());
+        let style_sheet = self.style_sheet.as_deref().unwrap_or(&theme);
+
         let bounds = layout.bounds();
         let is_mouse_over = bounds.contains(cursor_position);
 
@@ -238,9 +257,9 @@ where
             let dot_size = size / 2.0;
 
             let style = if is_mouse_over {
-                self.style_sheet.hovered()
+                style_sheet.hovered()
             } else {
-                self.style_sheet.active()
+                style_sheet.active()
             };
 
             renderer.fill_quad(
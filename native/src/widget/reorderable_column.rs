@@ -0,0 +1,641 @@
+//! Let users reorder a vertical list by dragging its rows.
+//!
+//! A [`ReorderableColumn`] has some local [`State`].
+use std::hash::Hash;
+
+use crate::event::{self, Event};
+use crate::layout;
+use crate::mouse;
+use crate::overlay;
+use crate::renderer;
+use crate::theme;
+use crate::touch;
+use crate::widget::Operation;
+use crate::{
+    Alignment, Clipboard, Color, Element, Hasher, Layout, Length, Padding,
+    Point, Rectangle, Shell, Vector, Widget,
+};
+
+pub use iced_style::reorderable_column::{Style, StyleSheet};
+
+/// A container that distributes its contents vertically and lets users
+/// reorder them by dragging the handle next to each row.
+///
+/// Dropping a row publishes a message produced by the `on_reorder` closure
+/// with the original index of the row and its new index. The new index is
+/// computed _after_ removing the row from the list, so you can apply it
+/// directly:
+///
+/// ```
+/// # use iced_native::{renderer::Null, Element};
+/// # use iced_native::widget::{reorderable_column, ReorderableColumn, Text};
+/// #
+/// # pub type Column<'a, Message> = ReorderableColumn<'a, Message, Null>;
+/// #[derive(Debug, Clone, Copy)]
+/// enum Message {
+///     Reordered(usize, usize),
+/// }
+///
+/// struct List {
+///     items: Vec<String>,
+///     state: reorderable_column::State,
+/// }
+///
+/// impl List {
+///     fn update(&mut self, message: Message) {
+///         match message {
+///             Message::Reordered(from, to) => {
+///                 let item = self.items.remove(from);
+///                 self.items.insert(to, item);
+///             }
+///         }
+///     }
+///
+///     fn view(&mut self) -> Element<'_, Message, Null> {
+///         self.items
+///             .iter()
+///             .fold(
+///                 Column::new(&mut self.state, Message::Reordered),
+///                 |column, item| column.push(Text::new(item.clone())),
+///             )
+///             .into()
+///     }
+/// }
+/// ```
+///
+/// When placed inside a [`Scrollable`] with [`auto_scroll`] enabled, the
+/// [`Scrollable`] will follow the dragged row when it gets close to its
+/// edges.
+///
+/// [`Scrollable`]: crate::widget::Scrollable
+/// [`auto_scroll`]: crate::widget::Scrollable::auto_scroll
+#[allow(missing_debug_implementations)]
+pub struct ReorderableColumn<'a, Message, Renderer> {
+    state: &'a mut State,
+    spacing: u16,
+    padding: Padding,
+    width: Length,
+    height: Length,
+    max_width: u32,
+    max_height: u32,
+    handle_width: u16,
+    on_reorder: Box<dyn Fn(usize, usize) -> Message + 'a>,
+    style_sheet: Option<Box<dyn StyleSheet + 'a>>,
+    class: Option<String>,
+    children: Vec<Element<'a, Message, Renderer>>,
+}
+
+impl<'a, Message, Renderer> ReorderableColumn<'a, Message, Renderer> {
+    /// Creates an empty [`ReorderableColumn`] with the given [`State`] and
+    /// the message to produce when a row is moved.
+    pub fn new<F>(state: &'a mut State, on_reorder: F) -> Self
+    where
+        F: 'a + Fn(usize, usize) -> Message,
+    {
+        Self::with_children(state, Vec::new(), on_reorder)
+    }
+
+    /// Creates a [`ReorderableColumn`] with the given [`State`], elements, and
+    /// the message to produce when a row is moved.
+    pub fn with_children<F>(
+        state: &'a mut State,
+        children: Vec<Element<'a, Message, Renderer>>,
+        on_reorder: F,
+    ) -> Self
+    where
+        F: 'a + Fn(usize, usize) -> Message,
+    {
+        ReorderableColumn {
+            state,
+            spacing: 0,
+            padding: Padding::ZERO,
+            width: Length::Shrink,
+            height: Length::Shrink,
+            max_width: u32::MAX,
+            max_height: u32::MAX,
+            handle_width: 16,
+            on_reorder: Box::new(on_reorder),
+            style_sheet: None,
+            class: None,
+            children,
+        }
+    }
+
+    /// Sets the vertical spacing _between_ rows.
+    pub fn spacing(mut self, units: u16) -> Self {
+        self.spacing = units;
+        self
+    }
+
+    /// Sets the [`Padding`] of the [`ReorderableColumn`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the width of the [`ReorderableColumn`].
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`ReorderableColumn`].
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the maximum width of the [`ReorderableColumn`].
+    pub fn max_width(mut self, max_width: u32) -> Self {
+        self.max_width = max_width;
+        self
+    }
+
+    /// Sets the maximum height of the [`ReorderableColumn`] in pixels.
+    pub fn max_height(mut self, max_height: u32) -> Self {
+        self.max_height = max_height;
+        self
+    }
+
+    /// Sets the width of the drag handle placed to the left of every row.
+    pub fn handle_width(mut self, handle_width: u16) -> Self {
+        self.handle_width = handle_width;
+        self
+    }
+
+    /// Sets the style of the [`ReorderableColumn`].
+    pub fn style(
+        mut self,
+        style_sheet: impl Into<Box<dyn StyleSheet + 'a>>,
+    ) -> Self {
+        self.style_sheet = Some(style_sheet.into());
+        self
+    }
+
+    /// Sets the style class of the [`ReorderableColumn`].
+    ///
+    /// The class is resolved through the current [`Theme`] when the
+    /// [`ReorderableColumn`] does not have a custom style sheet.
+    ///
+    /// [`Theme`]: crate::Theme
+    pub fn class(mut self, class: impl Into<String>) -> Self {
+        self.class = Some(class.into());
+        self
+    }
+
+    /// Adds a row to the [`ReorderableColumn`].
+    pub fn push<E>(mut self, child: E) -> Self
+    where
+        E: Into<Element<'a, Message, Renderer>>,
+    {
+        self.children.push(child.into());
+        self
+    }
+
+    fn handle_bounds(&self, row: Rectangle) -> Rectangle {
+        let handle_width = f32::from(self.handle_width);
+
+        Rectangle {
+            x: row.x - handle_width,
+            width: handle_width,
+            ..row
+        }
+    }
+}
+
+/// The local state of a [`ReorderableColumn`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct State {
+    drag: Option<Drag>,
+}
+
+impl State {
+    /// Creates a new [`State`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the index of the row being dragged, if any.
+    pub fn dragged(&self) -> Option<usize> {
+        self.drag.map(|drag| drag.from)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Drag {
+    from: usize,
+    origin: Point,
+    cursor: Point,
+    slot: usize,
+}
+
+impl Drag {
+    /// Returns the index of the dragged row after removing it from the list
+    /// and inserting it in the current slot.
+    fn target(&self) -> usize {
+        if self.slot > self.from {
+            self.slot - 1
+        } else {
+            self.slot
+        }
+    }
+}
+
+/// Returns the insertion slot, between `0` and the amount of rows, closest to
+/// the given vertical position.
+fn slot_at(layout: Layout<'_>, y: f32) -> usize {
+    layout
+        .children()
+        .take_while(|row| {
+            let bounds = row.bounds();
+
+            bounds.y + bounds.height / 2.0 < y
+        })
+        .count()
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for ReorderableColumn<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits
+            .max_width(self.max_width)
+            .max_height(self.max_height)
+            .width(self.width)
+            .height(self.height);
+
+        let padding = Padding {
+            left: self.padding.left + self.handle_width,
+            ..self.padding
+        };
+
+        layout::flex::resolve(
+            layout::flex::Axis::Vertical,
+            renderer,
+            &limits,
+            padding,
+            self.spacing as f32,
+            Alignment::Start,
+            &self.children,
+        )
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        if let Some(mut drag) = self.state.drag {
+            match event {
+                Event::Mouse(mouse::Event::CursorMoved { .. })
+                | Event::Touch(touch::Event::FingerMoved { .. }) => {
+                    // TODO: Remove once cursor availability is encoded in the
+                    // type system
+                    if cursor_position.y >= 0.0 {
+                        drag.cursor = cursor_position;
+                        drag.slot = slot_at(layout, cursor_position.y);
+
+                        self.state.drag = Some(drag);
+                    }
+                }
+                Event::Mouse(mouse::Event::ButtonReleased(
+                    mouse::Button::Left,
+                ))
+                | Event::Touch(touch::Event::FingerLifted { .. }) => {
+                    self.state.drag = None;
+
+                    let target = drag.target();
+
+                    if target != drag.from {
+                        shell.publish((self.on_reorder)(drag.from, target));
+                    }
+                }
+                Event::Touch(touch::Event::FingerLost { .. }) => {
+                    self.state.drag = None;
+                }
+                _ => return event::Status::Ignored,
+            }
+
+            return event::Status::Captured;
+        }
+
+        if let Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+        | Event::Touch(touch::Event::FingerPressed { .. }) = event
+        {
+            let picked = layout.children().enumerate().find(|(_, row)| {
+                self.handle_bounds(row.bounds()).contains(cursor_position)
+            });
+
+            if let Some((index, _)) = picked {
+                self.state.drag = Some(Drag {
+                    from: index,
+                    origin: cursor_position,
+                    cursor: cursor_position,
+                    slot: index,
+                });
+
+                return event::Status::Captured;
+            }
+        }
+
+        self.children
+            .iter_mut()
+            .zip(layout.children())
+            .map(|(child, layout)| {
+                child.widget.on_event(
+                    event.clone(),
+                    layout,
+                    cursor_position,
+                    renderer,
+                    clipboard,
+                    shell,
+                )
+            })
+            .fold(event::Status::Ignored, event::Status::merge)
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) -> mouse::Interaction {
+        if self.state.drag.is_some() {
+            return mouse::Interaction::Grabbing;
+        }
+
+        let is_mouse_over_handle = layout.children().any(|row| {
+            self.handle_bounds(row.bounds()).contains(cursor_position)
+        });
+
+        if is_mouse_over_handle {
+            return mouse::Interaction::Grab;
+        }
+
+        self.children
+            .iter()
+            .zip(layout.children())
+            .map(|(child, layout)| {
+                child.widget.mouse_interaction(
+                    layout,
+                    cursor_position,
+                    viewport,
+                )
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        let theme = style
+            .theme
+            .resolve(theme::Widget::ReorderableColumn, self.class.as_deref());
+        let sheet = self.style_sheet.as_deref().unwrap_or(&theme);
+
+        let drag = self.state.drag;
+
+        let style_sheet = if drag.is_some() {
+            sheet.dragging()
+        } else {
+            sheet.active()
+        };
+
+        let child_cursor_position = if drag.is_some() {
+            // TODO: Remove once cursor availability is encoded in the type
+            // system
+            Point::new(-1.0, -1.0)
+        } else {
+            cursor_position
+        };
+
+        let draw_handle = |renderer: &mut Renderer, bounds: Rectangle| {
+            let handle_style =
+                if drag.is_none() && bounds.contains(cursor_position) {
+                    sheet.hovered()
+                } else {
+                    style_sheet
+                };
+
+            let width = (bounds.width * 0.5).round();
+            let x = (bounds.x + (bounds.width - width) / 2.0).round();
+            let center = bounds.y + bounds.height / 2.0;
+
+            for line in -1..=1 {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x,
+                            y: (center + line as f32 * 4.0 - 1.0).round(),
+                            width,
+                            height: 2.0,
+                        },
+                        border_radius: 1.0,
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                    },
+                    handle_style.handle_color,
+                );
+            }
+        };
+
+        for (index, (child, layout)) in
+            self.children.iter().zip(layout.children()).enumerate()
+        {
+            let handle_bounds = self.handle_bounds(layout.bounds());
+
+            match drag {
+                Some(drag) if drag.from == index => {
+                    if let Some(background) = style_sheet.dragged_background {
+                        let bounds = layout.bounds();
+
+                        renderer.fill_quad(
+                            renderer::Quad {
+                                bounds: Rectangle {
+                                    x: handle_bounds.x,
+                                    width: bounds.width + handle_bounds.width,
+                                    ..bounds
+                                },
+                                border_radius: 0.0,
+                                border_width: 0.0,
+                                border_color: Color::TRANSPARENT,
+                            },
+                            background,
+                        );
+                    }
+                }
+                _ => {
+                    draw_handle(renderer, handle_bounds);
+
+                    child.draw(
+                        renderer,
+                        style,
+                        layout,
+                        child_cursor_position,
+                        viewport,
+                    );
+                }
+            }
+        }
+
+        if let Some(drag) = drag {
+            let rows: Vec<Rectangle> =
+                layout.children().map(|row| row.bounds()).collect();
+
+            if drag.target() != drag.from {
+                let spacing = f32::from(self.spacing);
+
+                let y = if drag.slot == 0 {
+                    rows[0].y - spacing / 2.0
+                } else if drag.slot == rows.len() {
+                    let last = rows[rows.len() - 1];
+
+                    last.y + last.height + spacing / 2.0
+                } else {
+                    let above = rows[drag.slot - 1];
+                    let below = rows[drag.slot];
+
+                    (above.y + above.height + below.y) / 2.0
+                };
+
+                let bounds = layout.bounds();
+                let width = style_sheet.indicator_width;
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x: bounds.x + f32::from(self.padding.left),
+                            y: (y - width / 2.0)
+                                .max(bounds.y)
+                                .min(bounds.y + bounds.height - width)
+                                .round(),
+                            width: bounds.width
+                                - f32::from(self.padding.horizontal()),
+                            height: width,
+                        },
+                        border_radius: width / 2.0,
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                    },
+                    style_sheet.indicator_color,
+                );
+            }
+
+            if let (Some(child), Some(layout)) = (
+                self.children.get(drag.from),
+                layout.children().nth(drag.from),
+            ) {
+                let bounds = layout.bounds();
+                let handle_bounds = self.handle_bounds(bounds);
+
+                renderer.with_translation(
+                    Vector::new(0.0, drag.cursor.y - drag.origin.y),
+                    |renderer| {
+                        renderer.with_layer(
+                            Rectangle {
+                                x: handle_bounds.x,
+                                width: bounds.width + handle_bounds.width,
+                                ..bounds
+                            },
+                            |renderer| {
+                                draw_handle(renderer, handle_bounds);
+
+                                child.draw(
+                                    renderer,
+                                    style,
+                                    layout,
+                                    child_cursor_position,
+                                    viewport,
+                                );
+                            },
+                        );
+                    },
+                );
+            }
+        }
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.width.hash(state);
+        self.height.hash(state);
+        self.max_width.hash(state);
+        self.max_height.hash(state);
+        self.spacing.hash(state);
+        self.padding.hash(state);
+        self.handle_width.hash(state);
+
+        for child in &self.children {
+            child.widget.hash_layout(state);
+        }
+    }
+
+    fn operate(
+        &mut self,
+        layout: Layout<'_>,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        operation.container(None, &mut |operation| {
+            self.children.iter_mut().zip(layout.children()).for_each(
+                |(child, layout)| {
+                    child.operate(layout, operation);
+                },
+            )
+        });
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+        viewport: &Rectangle,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        if self.state.drag.is_some() {
+            return None;
+        }
+
+        overlay::from_children(
+            self.children.iter_mut().zip(layout.children()).filter_map(
+                |(child, layout)| child.widget.overlay(layout, viewport),
+            ),
+        )
+    }
+}
+
+impl<'a, Message, Renderer> From<ReorderableColumn<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + crate::Renderer,
+    Message: 'a,
+{
+    fn from(
+        column: ReorderableColumn<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(column)
+    }
+}
@@ -0,0 +1,408 @@
+//! Build content that adapts to the space available to it.
+//!
+//! A [`Responsive`] has some local [`State`].
+use std::cell::RefCell;
+use std::hash::{Hash, Hasher as _};
+
+use crate::event::{self, Event};
+use crate::layout;
+use crate::mouse;
+use crate::overlay;
+use crate::renderer;
+use crate::widget::Operation;
+use crate::window;
+use crate::{
+    Clipboard, Element, Hasher, Layout, Length, Point, Rectangle, Shell, Size,
+    Widget,
+};
+
+/// A widget that produces its content from the space available to it.
+///
+/// A [`Responsive`] fills all the space it is given and calls its view logic
+/// with the resulting [`Size`]. This way, you can switch between different
+/// layouts depending on some breakpoints; like collapsing a sidebar when the
+/// window gets too narrow.
+///
+/// When the available space changes, the [`Responsive`] asks the shell to
+/// rebuild the widgets of the application, so its view logic can run again
+/// with the new [`Size`].
+///
+/// A [`Responsive`] should not be placed inside a container with unbounded
+/// space, like a [`Scrollable`].
+///
+/// [`Scrollable`]: crate::widget::Scrollable
+///
+/// # Example
+/// ```
+/// # use iced_native::widget::{responsive, Column, Responsive, Row, Text};
+/// # use iced_native::renderer::Null as Renderer;
+/// #
+/// # type Element<'a> = iced_native::Element<'a, (), Renderer>;
+/// #
+/// let mut state = responsive::State::new();
+///
+/// let responsive = Responsive::new(&mut state, |size| -> Element<'_> {
+///     if size.width < 600.0 {
+///         Column::new()
+///             .push(Text::new("Sidebar"))
+///             .push(Text::new("Content"))
+///             .into()
+///     } else {
+///         Row::new()
+///             .push(Text::new("Sidebar"))
+///             .push(Text::new("Content"))
+///             .into()
+///     }
+/// });
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Responsive<'a, Message, Renderer> {
+    internal: RefCell<Internal<'a, Message, Renderer>>,
+}
+
+impl<'a, Message, Renderer> Responsive<'a, Message, Renderer> {
+    /// Creates a new [`Responsive`] with the given [`State`] and view logic.
+    pub fn new<F, E>(state: &'a mut State, view: F) -> Self
+    where
+        F: 'a + FnOnce(Size) -> E,
+        E: Into<Element<'a, Message, Renderer>>,
+    {
+        Responsive {
+            internal: RefCell::new(Internal {
+                state,
+                view: Some(Box::new(move |size| view(size).into())),
+                content: None,
+            }),
+        }
+    }
+}
+
+/// The local state of a [`Responsive`].
+#[derive(Debug, Clone, Default)]
+pub struct State {
+    size: Option<Size>,
+    layout: Option<(u64, Size, layout::Node)>,
+}
+
+impl State {
+    /// Creates a new [`State`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+type View<'a, Message, Renderer> =
+    Box<dyn FnOnce(Size) -> Element<'a, Message, Renderer> + 'a>;
+
+struct Internal<'a, Message, Renderer> {
+    state: &'a mut State,
+    view: Option<View<'a, Message, Renderer>>,
+    content: Option<Content<'a, Message, Renderer>>,
+}
+
+struct Content<'a, Message, Renderer> {
+    element: Element<'a, Message, Renderer>,
+    size: Size,
+    layout_size: Option<Size>,
+}
+
+impl<'a, Message, Renderer> Internal<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+{
+    /// Returns whether the content was produced for a size different from the
+    /// one currently available.
+    fn is_outdated(&self) -> bool {
+        matches!(
+            &self.content,
+            Some(content) if Some(content.size) != self.state.size
+        )
+    }
+
+    /// Produces the content, if needed, and returns it together with its
+    /// layout.
+    ///
+    /// The content is only produced once the [`Responsive`] has been laid out,
+    /// so its view logic never runs with a made up [`Size`].
+    ///
+    /// The layout of the content is reused from the [`State`] whenever
+    /// possible. Otherwise, a `renderer` is needed to compute it.
+    fn resolve(
+        &mut self,
+        renderer: Option<&Renderer>,
+    ) -> Option<(&mut Element<'a, Message, Renderer>, &layout::Node)> {
+        let size = self.state.size?;
+
+        if self.content.is_none() {
+            if let Some(view) = self.view.take() {
+                self.content = Some(Content {
+                    element: view(size),
+                    size,
+                    layout_size: None,
+                });
+            }
+        }
+
+        let content = self.content.as_mut()?;
+
+        if content.layout_size != Some(size) {
+            let hash = {
+                let hasher = &mut Hasher::default();
+                content.element.hash_layout(hasher);
+
+                hasher.finish()
+            };
+
+            let is_cached = matches!(
+                &self.state.layout,
+                Some((cached_hash, cached_size, _))
+                    if *cached_hash == hash && *cached_size == size
+            );
+
+            if !is_cached {
+                let layout = content
+                    .element
+                    .layout(renderer?, &layout::Limits::new(Size::ZERO, size));
+
+                self.state.layout = Some((hash, size, layout));
+            }
+
+            content.layout_size = Some(size);
+        }
+
+        let (_, _, layout) = self.state.layout.as_ref()?;
+
+        Some((&mut content.element, layout))
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Responsive<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+{
+    fn width(&self) -> Length {
+        Length::Fill
+    }
+
+    fn height(&self) -> Length {
+        Length::Fill
+    }
+
+    fn layout(
+        &self,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let size = limits.max();
+
+        let mut internal = self.internal.borrow_mut();
+        internal.state.size = Some(size);
+
+        // The content may have changed since it was last laid out
+        if let Some(content) = &mut internal.content {
+            content.layout_size = None;
+        }
+
+        layout::Node::new(size)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let internal = self.internal.get_mut();
+
+        if internal.is_outdated() {
+            shell.invalidate_widgets();
+        }
+
+        // The new layout is only computed after the resize is processed, so
+        // we need another event to notice if our size changed
+        if let Event::Window(window::Event::Resized { .. }) = event {
+            shell.request_redraw(window::RedrawRequest::NextFrame);
+        }
+
+        match internal.resolve(Some(renderer)) {
+            Some((content, node)) => content.on_event(
+                event,
+                Layout::with_offset(layout.position() - Point::ORIGIN, node),
+                cursor_position,
+                renderer,
+                clipboard,
+                shell,
+            ),
+            None => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) -> mouse::Interaction {
+        let mut internal = self.internal.borrow_mut();
+
+        internal
+            .resolve(None)
+            .map(|(content, node)| {
+                content.mouse_interaction(
+                    Layout::with_offset(
+                        layout.position() - Point::ORIGIN,
+                        node,
+                    ),
+                    cursor_position,
+                    viewport,
+                )
+            })
+            .unwrap_or_default()
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        let mut internal = self.internal.borrow_mut();
+
+        if let Some((content, node)) = internal.resolve(Some(renderer)) {
+            content.draw(
+                renderer,
+                style,
+                Layout::with_offset(layout.position() - Point::ORIGIN, node),
+                cursor_position,
+                viewport,
+            );
+        }
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+    }
+
+    fn operate(
+        &mut self,
+        layout: Layout<'_>,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        if let Some((content, node)) = self.internal.get_mut().resolve(None) {
+            operation.container(None, &mut |operation| {
+                content.operate(
+                    Layout::with_offset(
+                        layout.position() - Point::ORIGIN,
+                        node,
+                    ),
+                    operation,
+                );
+            });
+        }
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+        viewport: &Rectangle,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        let (content, node) = self.internal.get_mut().resolve(None)?;
+
+        content.overlay(
+            Layout::with_offset(layout.position() - Point::ORIGIN, node),
+            viewport,
+        )
+    }
+}
+
+impl<'a, Message, Renderer> From<Responsive<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + crate::Renderer,
+    Message: 'a,
+{
+    fn from(
+        responsive: Responsive<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(responsive)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::renderer::Null;
+    use crate::user_interface::{self, UserInterface};
+    use crate::widget::Space;
+
+    use std::cell::Cell;
+
+    #[test]
+    fn view_runs_with_the_laid_out_size() {
+        let mut state = State::new();
+        let sizes = Cell::new(Vec::new());
+        let mut renderer = Null::new();
+
+        let view = |size| {
+            let mut seen = sizes.take();
+            seen.push(size);
+            sizes.set(seen);
+
+            Space::new(Length::Fill, Length::Fill)
+        };
+
+        let mut user_interface = UserInterface::<(), _>::build(
+            Responsive::new(&mut state, view),
+            Size::new(400.0, 300.0),
+            user_interface::Cache::default(),
+            &mut renderer,
+        );
+
+        let _ = user_interface.draw(
+            &mut renderer,
+            &renderer::Style::default(),
+            Point::ORIGIN,
+        );
+
+        let resized = Event::Window(window::Event::Resized {
+            width: 400,
+            height: 300,
+        });
+
+        let _ = user_interface.update(
+            &[resized],
+            Point::ORIGIN,
+            &mut renderer,
+            &mut crate::clipboard::Null,
+            &mut Vec::new(),
+        );
+
+        assert!(!user_interface.is_outdated());
+
+        let mut user_interface =
+            user_interface.relayout(Size::new(200.0, 300.0), &mut renderer);
+
+        let _ = user_interface.update(
+            &[Event::Window(window::Event::RedrawRequested(
+                std::time::Instant::now(),
+            ))],
+            Point::ORIGIN,
+            &mut renderer,
+            &mut crate::clipboard::Null,
+            &mut Vec::new(),
+        );
+
+        assert!(user_interface.is_outdated());
+
+        drop(user_interface);
+
+        assert_eq!(sizes.take(), vec![Size::new(400.0, 300.0)]);
+    }
+}
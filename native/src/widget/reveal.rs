@@ -0,0 +1,337 @@
+//! Animate some content when it enters or exits the view.
+use std::hash::Hash;
+use std::time::Instant;
+
+use crate::animation::{Interpolate, Presence};
+use crate::event::{self, Event};
+use crate::layout;
+use crate::mouse;
+use crate::overlay;
+use crate::renderer;
+use crate::widget::Operation;
+use crate::window;
+use crate::{
+    Clipboard, Element, Hasher, Layout, Length, Point, Rectangle, Shell, Size,
+    Vector, Widget,
+};
+
+/// A widget that animates its content when it enters or exits the view,
+/// following a [`Presence`].
+///
+/// The content fades in and out by default. It can also slide from an offset
+/// and grow from a scale. The overlays of the content are animated as well.
+///
+/// The content ignores any input while it is exiting the view.
+#[allow(missing_debug_implementations)]
+pub struct Reveal<'a, Message, Renderer> {
+    content: Element<'a, Message, Renderer>,
+    is_visible: bool,
+    progress: f32,
+    redraw_request: Option<window::RedrawRequest>,
+    fade: bool,
+    offset: Vector,
+    scale: f32,
+}
+
+impl<'a, Message, Renderer> Reveal<'a, Message, Renderer> {
+    /// Creates a new [`Reveal`] widget that animates the given content
+    /// following the given [`Presence`].
+    pub fn new(
+        presence: &Presence,
+        content: impl Into<Element<'a, Message, Renderer>>,
+    ) -> Self {
+        let now = Instant::now();
+
+        Reveal {
+            content: content.into(),
+            is_visible: presence.is_visible(),
+            progress: presence.progress(now),
+            redraw_request: presence.redraw_request(now),
+            fade: true,
+            offset: Vector::new(0.0, 0.0),
+            scale: 1.0,
+        }
+    }
+
+    /// Sets whether the content of the [`Reveal`] fades in and out.
+    pub fn fade(mut self, fade: bool) -> Self {
+        self.fade = fade;
+        self
+    }
+
+    /// Makes the content of the [`Reveal`] slide from the given offset when
+    /// it enters the view, and towards it when it exits.
+    pub fn slide(mut self, offset: Vector) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Makes the content of the [`Reveal`] grow from the given scale when it
+    /// enters the view, and shrink towards it when it exits.
+    pub fn scale(mut self, scale: f32) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    fn effect(&self) -> Effect {
+        Effect {
+            opacity: if self.fade { self.progress } else { 1.0 },
+            translation: self.offset * (1.0 - self.progress),
+            scale: self.scale.interpolate(&1.0, self.progress),
+        }
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Reveal<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+{
+    fn width(&self) -> Length {
+        self.content.width()
+    }
+
+    fn height(&self) -> Length {
+        self.content.height()
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content.layout(renderer, limits)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        if let Some(request) = self.redraw_request {
+            if let Event::Window(window::Event::RedrawRequested(_)) = event {
+                shell.invalidate_widgets();
+            }
+
+            shell.request_redraw(request);
+        }
+
+        if !self.is_visible {
+            return event::Status::Ignored;
+        }
+
+        self.content.on_event(
+            event,
+            layout,
+            cursor_position,
+            renderer,
+            clipboard,
+            shell,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) -> mouse::Interaction {
+        if !self.is_visible {
+            return mouse::Interaction::default();
+        }
+
+        self.content
+            .mouse_interaction(layout, cursor_position, viewport)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        if self.progress <= 0.0 {
+            return;
+        }
+
+        self.effect().apply(renderer, layout.bounds(), |renderer| {
+            self.content.draw(
+                renderer,
+                style,
+                layout,
+                cursor_position,
+                viewport,
+            );
+        });
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.content.hash_layout(state);
+    }
+
+    fn operate(
+        &mut self,
+        layout: Layout<'_>,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        self.content.operate(layout, operation);
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+        viewport: &Rectangle,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        let effect = self.effect();
+        let is_visible = self.is_visible;
+
+        let content = self.content.overlay(layout, viewport)?;
+        let position = content.position();
+
+        Some(overlay::Element::new(
+            position,
+            Box::new(Overlay {
+                content,
+                effect,
+                is_visible,
+            }),
+        ))
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Effect {
+    opacity: f32,
+    translation: Vector,
+    scale: f32,
+}
+
+impl Effect {
+    fn apply<Renderer>(
+        &self,
+        renderer: &mut Renderer,
+        bounds: Rectangle,
+        f: impl FnOnce(&mut Renderer),
+    ) where
+        Renderer: crate::Renderer,
+    {
+        renderer.with_opacity(self.opacity, |renderer| {
+            renderer.with_translation(self.translation, |renderer| {
+                renderer.with_scale(bounds.center(), self.scale, f);
+            });
+        });
+    }
+}
+
+struct Overlay<'a, Message, Renderer> {
+    content: overlay::Element<'a, Message, Renderer>,
+    effect: Effect,
+    is_visible: bool,
+}
+
+impl<'a, Message, Renderer> overlay::Overlay<Message, Renderer>
+    for Overlay<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+{
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        bounds: Size,
+        _position: Point,
+    ) -> layout::Node {
+        self.content.layout(renderer, bounds)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        if !self.is_visible {
+            return event::Status::Ignored;
+        }
+
+        self.content.on_event(
+            event,
+            layout,
+            cursor_position,
+            renderer,
+            clipboard,
+            shell,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) -> mouse::Interaction {
+        if !self.is_visible {
+            return mouse::Interaction::default();
+        }
+
+        self.content
+            .mouse_interaction(layout, cursor_position, viewport)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) {
+        let content = &self.content;
+
+        self.effect.apply(renderer, layout.bounds(), |renderer| {
+            content.draw(renderer, style, layout, cursor_position);
+        });
+    }
+
+    fn hash_layout(&self, state: &mut Hasher, _position: Point) {
+        self.content.hash_layout(state);
+    }
+
+    fn is_over(&self, layout: Layout<'_>, cursor_position: Point) -> bool {
+        self.is_visible && self.content.is_over(layout, cursor_position)
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        if !self.is_visible {
+            return None;
+        }
+
+        self.content.overlay(layout)
+    }
+}
+
+impl<'a, Message, Renderer> From<Reveal<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + crate::Renderer,
+{
+    fn from(
+        reveal: Reveal<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(reveal)
+    }
+}
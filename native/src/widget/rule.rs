@@ -1,6 +1,7 @@
 //! Display a horizontal or vertical rule for dividing content.
 use crate::layout;
 use crate::renderer;
+use crate::theme;
 use crate::{
     Color, Element, Hasher, Layout, Length, Point, Rectangle, Size, Widget,
 };
@@ -15,7 +16,8 @@ pub struct Rule<'a> {
     width: Length,
     height: Length,
     is_horizontal: bool,
-    style_sheet: Box<dyn StyleSheet + 'a>,
+    style_sheet: Option<Box<dyn StyleSheet + 'a>>,
+    class: Option<String>,
 }
 
 impl<'a> Rule<'a> {
@@ -25,7 +27,8 @@ impl<'a> Rule<'a> {
             width: Length::Fill,
             height: Length::from(Length::Units(spacing)),
             is_horizontal: true,
-            style_sheet: Default::default(),
+            style_sheet: None,
+            class: None,
         }
     }
 
@@ -35,7 +38,8 @@ impl<'a> Rule<'a> {
             width: Length::from(Length::Units(spacing)),
             height: Length::Fill,
             is_horizontal: false,
-            style_sheet: Default::default(),
+            style_sheet: None,
+            class: None,
         }
     }
 
@@ -44,7 +48,18 @@ impl<'a> Rule<'a> {
         mut self,
         style_sheet: impl Into<Box<dyn StyleSheet + 'a>>,
     ) -> Self {
-        self.style_sheet = style_sheet.into();
+        self.style_sheet = Some(style_sheet.into());
+        self
+    }
+
+    /// Sets the style class of the [`Rule`].
+    ///
+    /// The class is resolved through the current [`Theme`] when the
+    /// [`Rule`] does not have a custom style sheet.
+    ///
+    /// [`Theme`]: crate::Theme
+    pub fn class(mut self, class: impl Into<String>) -> Self {
+        self.class = Some(class.into());
         self
     }
 }
@@ -74,13 +89,18 @@ where
     fn draw(
         &self,
         renderer: &mut Renderer,
-        _style: &renderer::Style,
+        style: &renderer::Style,
         layout: Layout<'_>,
         _cursor_position: Point,
         _viewport: &Rectangle,
     ) {
+        let theme = style
+            .theme
+            .resolve(theme::Widget::Rule, self.class.as_deref());
+        let style_sheet = self.style_sheet.as_deref().unwrap_or(&theme);
+
         let bounds = layout.bounds();
-        let style = self.style_sheet.style();
+        let style = style_sheet.style();
 
         let bounds = if self.is_horizontal {
             let line_y = (bounds.y + (bounds.height / 2.0)
@@ -1,33 +1,76 @@
-//! Navigate an endless amount of content with a scrollbar.
+//! Navigate an endless amount of content with scrollbars.
+mod kinetic;
+
+use crate::accessibility;
 use crate::event::{self, Event};
+use crate::keyboard;
 use crate::layout;
 use crate::mouse;
 use crate::overlay;
+#[cfg(feature = "persistence")]
+use crate::persistence;
 use crate::renderer;
+use crate::theme;
 use crate::touch;
-use crate::widget::Column;
+use crate::widget::inspector;
+use crate::widget::operation::{self, Operation};
+use crate::widget::{Column, Id};
+use crate::window;
 use crate::{
-    Alignment, Background, Clipboard, Color, Element, Hasher, Layout, Length,
-    Padding, Point, Rectangle, Shell, Size, Vector, Widget,
+    Alignment, Background, Clipboard, Color, Command, Direction, Element,
+    Hasher, Layout, Length, Padding, Point, Rectangle, Shell, Size, Vector,
+    Widget,
 };
 
+use std::time::{Duration, Instant};
 use std::{f32, hash::Hash, u32};
 
 pub use iced_style::scrollable::StyleSheet;
+pub use operation::scrollable::{AbsoluteOffset, RelativeOffset};
+
+/// The distance to the edges of a [`Scrollable`] at which dragged contents
+/// start scrolling it.
+const AUTO_SCROLL_MARGIN: f32 = 30.0;
+
+/// The maximum amount of pixels a [`Scrollable`] scrolls every time dragged
+/// contents move close to its edges.
+const AUTO_SCROLL_SPEED: f32 = 20.0;
+
+/// The time after a [`Scrollable`] is scrolled during which the wheel events of
+/// the same gesture are not chained to its parent.
+const SCROLL_LATCH: Duration = Duration::from_millis(300);
 
-/// A widget that can vertically display an infinite amount of content with a
-/// scrollbar.
+/// The time it takes for hidden scrollbars to fade out completely.
+const AUTO_HIDE_FADE: Duration = Duration::from_millis(300);
+
+/// A widget that can display an infinite amount of content with scrollbars.
+///
+/// By default, a [`Scrollable`] only scrolls vertically. Use
+/// [`Scrollable::axes`] to scroll content wider than the [`Scrollable`].
 #[allow(missing_debug_implementations)]
 pub struct Scrollable<'a, Message, Renderer> {
     state: &'a mut State,
+    id: Option<Id>,
+    axes: Axes,
+    width: Length,
     height: Length,
     max_height: u32,
     scrollbar_width: u16,
     scrollbar_margin: u16,
     scroller_width: u16,
+    expanded_width: Option<u16>,
+    auto_hide: Option<Duration>,
+    auto_scroll: bool,
+    kinetic: bool,
+    bounce: bool,
+    scroll_chaining: bool,
+    anchor: Anchor,
+    on_start_reached: Option<(f32, Box<dyn Fn() -> Message + 'a>)>,
+    on_end_reached: Option<(f32, Box<dyn Fn() -> Message + 'a>)>,
     content: Column<'a, Message, Renderer>,
-    on_scroll: Option<Box<dyn Fn(f32) -> Message>>,
-    style_sheet: Box<dyn StyleSheet + 'a>,
+    on_scroll: Option<Box<dyn Fn(Viewport) -> Message>>,
+    style_sheet: Option<Box<dyn StyleSheet + 'a>>,
+    class: Option<String>,
 }
 
 impl<'a, Message, Renderer: crate::Renderer> Scrollable<'a, Message, Renderer> {
@@ -35,17 +78,50 @@ impl<'a, Message, Renderer: crate::Renderer> Scrollable<'a, Message, Renderer> {
     pub fn new(state: &'a mut State) -> Self {
         Scrollable {
             state,
+            id: None,
+            axes: Axes::default(),
+            width: Length::Shrink,
             height: Length::Shrink,
             max_height: u32::MAX,
             scrollbar_width: 10,
             scrollbar_margin: 0,
             scroller_width: 10,
+            expanded_width: None,
+            auto_hide: None,
+            auto_scroll: false,
+            kinetic: false,
+            bounce: false,
+            scroll_chaining: false,
+            anchor: Anchor::default(),
+            on_start_reached: None,
+            on_end_reached: None,
             content: Column::new(),
             on_scroll: None,
-            style_sheet: Default::default(),
+            style_sheet: None,
+            class: None,
         }
     }
 
+    /// Sets the [`Id`] of the [`Scrollable`].
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Sets the [`Axes`] along which the [`Scrollable`] can be scrolled.
+    ///
+    /// When horizontal scrolling is enabled, the content is laid out with
+    /// unlimited width and keeps its intrinsic size.
+    pub fn axes(mut self, axes: Axes) -> Self {
+        self.axes = axes;
+        self.content = self.content.width(if axes.is_horizontal() {
+            Length::Shrink
+        } else {
+            self.width
+        });
+        self
+    }
+
     /// Sets the vertical spacing _between_ elements.
     ///
     /// Custom margins per element do not exist in Iced. You should use this
@@ -64,7 +140,12 @@ impl<'a, Message, Renderer: crate::Renderer> Scrollable<'a, Message, Renderer> {
 
     /// Sets the width of the [`Scrollable`].
     pub fn width(mut self, width: Length) -> Self {
-        self.content = self.content.width(width);
+        self.width = width;
+
+        if !self.axes.is_horizontal() {
+            self.content = self.content.width(width);
+        }
+
         self
     }
 
@@ -113,11 +194,117 @@ impl<'a, Message, Renderer: crate::Renderer> Scrollable<'a, Message, Renderer> {
         self
     }
 
+    /// Sets the width that the scrollbar and scroller of the [`Scrollable`]
+    /// expand to while they are hovered or dragged.
+    ///
+    /// The area that reacts to the mouse always fits the expanded scrollbar,
+    /// so it does not change while expanding.
+    pub fn expanded_width(mut self, expanded_width: u16) -> Self {
+        self.expanded_width = Some(expanded_width.max(1));
+        self
+    }
+
+    /// Hides the scrollbars of the [`Scrollable`] after they have not been
+    /// used for the given delay.
+    ///
+    /// The scrollbars fade out when hidden. They show up again as soon as
+    /// the [`Scrollable`] is scrolled or its scrollbars are hovered.
+    pub fn auto_hide(mut self, delay: Duration) -> Self {
+        self.auto_hide = Some(delay);
+        self
+    }
+
+    /// Sets whether the [`Scrollable`] should scroll automatically when its
+    /// contents are dragging something close to its edges.
+    ///
+    /// This is useful for widgets that let users drag elements around, like a
+    /// [`ReorderableColumn`].
+    ///
+    /// [`ReorderableColumn`]: crate::widget::ReorderableColumn
+    pub fn auto_scroll(mut self, auto_scroll: bool) -> Self {
+        self.auto_scroll = auto_scroll;
+        self
+    }
+
+    /// Sets whether the [`Scrollable`] should keep scrolling with momentum
+    /// after a touch drag ends.
+    ///
+    /// The content slows down gradually, like on most touch devices.
+    /// Trackpads already produce their own momentum on most platforms.
+    pub fn kinetic(mut self, kinetic: bool) -> Self {
+        self.kinetic = kinetic;
+        self
+    }
+
+    /// Sets whether the content of the [`Scrollable`] can be dragged past its
+    /// edges, bouncing back when released.
+    ///
+    /// It only has an effect on a [`Scrollable::kinetic`] scrollable.
+    pub fn bounce(mut self, bounce: bool) -> Self {
+        self.bounce = bounce;
+        self
+    }
+
+    /// Sets whether the [`Scrollable`] should let wheel events through when
+    /// its content cannot be scrolled any further, so an outer [`Scrollable`]
+    /// is scrolled instead.
+    ///
+    /// Wheel events keep scrolling the inner [`Scrollable`] for a short while
+    /// after it moves, so a single gesture does not spill over to the outer
+    /// one. By default, wheel events are always captured while the content
+    /// can be scrolled in their direction.
+    pub fn scroll_chaining(mut self, scroll_chaining: bool) -> Self {
+        self.scroll_chaining = scroll_chaining;
+        self
+    }
+
+    /// Sets the [`Anchor`] of the [`Scrollable`].
+    ///
+    /// Use [`Anchor::End`] to keep the visible content in place while adding
+    /// items on top of it.
+    pub fn anchor(mut self, anchor: Anchor) -> Self {
+        self.anchor = anchor;
+        self
+    }
+
+    /// Sets the message that should be produced when the [`Scrollable`] is
+    /// scrolled within the given distance of the start of its content.
+    ///
+    /// The message is produced once every time the start is approached, or
+    /// when the size of the content changes while close to it.
+    pub fn on_start_reached(mut self, distance: u16, message: Message) -> Self
+    where
+        Message: Clone + 'a,
+    {
+        self.on_start_reached =
+            Some((f32::from(distance), Box::new(move || message.clone())));
+        self
+    }
+
+    /// Sets the message that should be produced when the [`Scrollable`] is
+    /// scrolled within the given distance of the end of its content.
+    ///
+    /// This can be used to load the next page of a feed. The message is
+    /// produced once every time the end is approached, or when the size of
+    /// the content changes while close to it. Thus, content that does not
+    /// fill the [`Scrollable`] keeps asking for more.
+    pub fn on_end_reached(mut self, distance: u16, message: Message) -> Self
+    where
+        Message: Clone + 'a,
+    {
+        self.on_end_reached =
+            Some((f32::from(distance), Box::new(move || message.clone())));
+        self
+    }
+
     /// Sets a function to call when the [`Scrollable`] is scrolled.
     ///
-    /// The function takes the new relative offset of the [`Scrollable`]
-    /// (e.g. `0` means top, while `1` means bottom).
-    pub fn on_scroll(mut self, f: impl Fn(f32) -> Message + 'static) -> Self {
+    /// The function takes the new [`Viewport`] of the [`Scrollable`], which
+    /// can be used to find out its current offset and the size of its content.
+    pub fn on_scroll(
+        mut self,
+        f: impl Fn(Viewport) -> Message + 'static,
+    ) -> Self {
         self.on_scroll = Some(Box::new(f));
         self
     }
@@ -127,7 +314,18 @@ impl<'a, Message, Renderer: crate::Renderer> Scrollable<'a, Message, Renderer> {
         mut self,
         style_sheet: impl Into<Box<dyn StyleSheet + 'a>>,
     ) -> Self {
-        self.style_sheet = style_sheet.into();
+        self.style_sheet = Some(style_sheet.into());
+        self
+    }
+
+    /// Sets the style class of the [`Scrollable`].
+    ///
+    /// The class is resolved through the current [`Theme`] when the
+    /// [`Scrollable`] does not have a custom style sheet.
+    ///
+    /// [`Theme`]: crate::Theme
+    pub fn class(mut self, class: impl Into<String>) -> Self {
+        self.class = Some(class.into());
         self
     }
 
@@ -141,39 +339,268 @@ impl<'a, Message, Renderer: crate::Renderer> Scrollable<'a, Message, Renderer> {
     }
 
     fn notify_on_scroll(
-        &self,
+        &mut self,
         bounds: Rectangle,
         content_bounds: Rectangle,
         shell: &mut Shell<'_, Message>,
     ) {
-        if content_bounds.height <= bounds.height {
+        self.reveal_scrollbars(shell);
+        self.check_edges(bounds, content_bounds, shell);
+
+        if content_bounds.width <= bounds.width
+            && content_bounds.height <= bounds.height
+        {
             return;
         }
 
         if let Some(on_scroll) = &self.on_scroll {
-            shell.publish(on_scroll(
-                self.state.offset.absolute(bounds, content_bounds)
-                    / (content_bounds.height - bounds.height),
-            ));
+            shell.publish(on_scroll(Viewport {
+                offset: Vector::new(
+                    self.state
+                        .offset_x
+                        .absolute(bounds.width, content_bounds.width),
+                    self.state
+                        .offset_y
+                        .absolute(bounds.height, content_bounds.height),
+                ),
+                bounds,
+                content_bounds,
+            }));
         }
     }
 
-    fn scrollbar(
+    /// Produces the messages of the [`Scrollable`] if the start or the end of
+    /// its content has been reached.
+    ///
+    /// The vertical axis is used, unless the [`Scrollable`] only scrolls
+    /// horizontally.
+    fn check_edges(
+        &mut self,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        if self.on_start_reached.is_none() && self.on_end_reached.is_none() {
+            return;
+        }
+
+        let offset = self.state.absolute_offset(bounds, content_bounds);
+
+        let (offset, viewport, content) = if self.axes == Axes::Horizontal {
+            (offset.x, bounds.width, content_bounds.width)
+        } else {
+            (offset.y, bounds.height, content_bounds.height)
+        };
+
+        let hidden_content = (content - viewport).max(0.0);
+
+        let edges = [
+            (
+                &self.on_start_reached,
+                &mut self.state.start_reached_at,
+                offset,
+            ),
+            (
+                &self.on_end_reached,
+                &mut self.state.end_reached_at,
+                hidden_content - offset,
+            ),
+        ];
+
+        for (on_reached, reached_at, distance_to_edge) in edges {
+            if let Some((distance, on_reached)) = on_reached {
+                if distance_to_edge > *distance {
+                    *reached_at = None;
+                } else if *reached_at != Some(content) {
+                    *reached_at = Some(content);
+
+                    shell.publish(on_reached());
+                }
+            }
+        }
+    }
+
+    /// Shows the scrollbars again if they are automatically hidden.
+    /// Scrolls the [`Scrollable`] until the given bounds of its content are
+    /// visible, returning whether the offset changed or not.
+    fn scroll_into_view(
+        &mut self,
+        target: Rectangle,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+    ) -> bool {
+        if !content_bounds.contains(target.center()) {
+            return false;
+        }
+
+        let offset = self.state.offset(bounds, content_bounds);
+
+        let reveal = |offset: f32, start: f32, size: f32, target, extent| {
+            if target < start + offset {
+                target - start
+            } else if target + extent > start + offset + size {
+                target + extent - start - size
+            } else {
+                offset
+            }
+        };
+
+        let new_offset = Vector::new(
+            reveal(offset.x, bounds.x, bounds.width, target.x, target.width),
+            reveal(offset.y, bounds.y, bounds.height, target.y, target.height),
+        );
+
+        if new_offset == offset {
+            return false;
+        }
+
+        self.state
+            .set_absolute_offset(new_offset, bounds, content_bounds);
+
+        true
+    }
+
+    fn reveal_scrollbars(&mut self, shell: &mut Shell<'_, Message>) {
+        if let Some(delay) = self.auto_hide {
+            let now = Instant::now();
+
+            self.state.revealed_at = Some(now);
+
+            shell.request_redraw(window::RedrawRequest::At(now + delay));
+        }
+    }
+
+    /// Returns the maximum offset of the [`Scrollable`] along the axes it can
+    /// be scrolled.
+    fn max_offset(
         &self,
         bounds: Rectangle,
         content_bounds: Rectangle,
-    ) -> Option<Scrollbar> {
+    ) -> Vector {
+        self.axes.mask(Vector::new(
+            (content_bounds.width - bounds.width).max(0.0),
+            (content_bounds.height - bounds.height).max(0.0),
+        ))
+    }
+
+    /// Keeps redrawing the [`Scrollable`] while its scrollbars fade out.
+    fn schedule_fade(&self, now: Instant, shell: &mut Shell<'_, Message>) {
+        if let (Some(delay), Some(revealed_at)) =
+            (self.auto_hide, self.state.revealed_at)
+        {
+            let hide_at = revealed_at + delay;
+
+            if now < hide_at {
+                shell.request_redraw(window::RedrawRequest::At(hide_at));
+            } else if now < hide_at + AUTO_HIDE_FADE {
+                shell.request_redraw(window::RedrawRequest::NextFrame);
+            }
+        }
+    }
+
+    /// Returns the opacity of the scrollbars at the given time.
+    fn scrollbar_alpha(&self, now: Instant) -> f32 {
+        let delay = match self.auto_hide {
+            Some(delay) => delay,
+            None => return 1.0,
+        };
+
+        match self.state.revealed_at {
+            Some(revealed_at) => {
+                let elapsed = now.saturating_duration_since(revealed_at);
+
+                if elapsed <= delay {
+                    1.0
+                } else {
+                    let fading = (elapsed - delay).as_secs_f32();
+
+                    (1.0 - fading / AUTO_HIDE_FADE.as_secs_f32()).max(0.0)
+                }
+            }
+            None => 0.0,
+        }
+    }
+
+    fn follow_drag(
+        &mut self,
+        cursor_position: Point,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let delta = |distance_to_start: f32, distance_to_end: f32| {
+            if distance_to_start < AUTO_SCROLL_MARGIN {
+                (AUTO_SCROLL_MARGIN - distance_to_start).min(AUTO_SCROLL_MARGIN)
+            } else if distance_to_end < AUTO_SCROLL_MARGIN {
+                -(AUTO_SCROLL_MARGIN - distance_to_end).min(AUTO_SCROLL_MARGIN)
+            } else {
+                0.0
+            }
+        };
+
+        let delta_x = if self.axes.is_horizontal() {
+            delta(
+                cursor_position.x - bounds.x,
+                bounds.x + bounds.width - cursor_position.x,
+            )
+        } else {
+            0.0
+        };
+
+        let delta_y = if self.axes.is_vertical() {
+            delta(
+                cursor_position.y - bounds.y,
+                bounds.y + bounds.height - cursor_position.y,
+            )
+        } else {
+            0.0
+        };
+
+        if delta_x == 0.0 && delta_y == 0.0 {
+            return;
+        }
+
+        self.state.scroll(
+            Vector::new(delta_x, delta_y)
+                * (AUTO_SCROLL_SPEED / AUTO_SCROLL_MARGIN),
+            bounds,
+            content_bounds,
+        );
+
+        self.notify_on_scroll(bounds, content_bounds, shell);
+    }
+
+    fn scrollbars(
+        &self,
+        direction: Direction,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+    ) -> Scrollbars {
         let offset = self.state.offset(bounds, content_bounds);
 
-        if content_bounds.height > bounds.height {
-            let outer_width = self.scrollbar_width.max(self.scroller_width)
-                + 2 * self.scrollbar_margin;
+        let show_y =
+            self.axes.is_vertical() && content_bounds.height > bounds.height;
+        let show_x =
+            self.axes.is_horizontal() && content_bounds.width > bounds.width;
+
+        let outer_width = self
+            .scrollbar_width
+            .max(self.scroller_width)
+            .max(self.expanded_width.unwrap_or(0))
+            + 2 * self.scrollbar_margin;
+
+        // The scrollbars do not overlap each other when both are shown
+        let y_corner = if show_x { outer_width as f32 } else { 0.0 };
+        let x_corner = if show_y { outer_width as f32 } else { 0.0 };
+
+        let y = if show_y {
+            let height = bounds.height - y_corner;
 
             let outer_bounds = Rectangle {
                 x: bounds.x + bounds.width - outer_width as f32,
                 y: bounds.y,
                 width: outer_width as f32,
-                height: bounds.height,
+                height,
             };
 
             let scrollbar_bounds = Rectangle {
@@ -181,12 +608,13 @@ impl<'a, Message, Renderer: crate::Renderer> Scrollable<'a, Message, Renderer> {
                     - f32::from(outer_width / 2 + self.scrollbar_width / 2),
                 y: bounds.y,
                 width: self.scrollbar_width as f32,
-                height: bounds.height,
+                height,
             };
 
-            let ratio = bounds.height / content_bounds.height;
+            let ratio = height / content_bounds.height;
             let scroller_height = bounds.height * ratio;
-            let y_offset = offset as f32 * ratio;
+            let y_offset =
+                (offset.y * ratio).max(0.0).min(height - scroller_height);
 
             let scroller_bounds = Rectangle {
                 x: bounds.x + bounds.width
@@ -196,7 +624,67 @@ impl<'a, Message, Renderer: crate::Renderer> Scrollable<'a, Message, Renderer> {
                 height: scroller_height,
             };
 
+            // The vertical scrollbar is placed on the left when content flows
+            // from right to left
+            let place = |rectangle: Rectangle| match direction {
+                Direction::LeftToRight => rectangle,
+                Direction::RightToLeft => Rectangle {
+                    x: 2.0 * bounds.x + bounds.width
+                        - rectangle.x
+                        - rectangle.width,
+                    ..rectangle
+                },
+            };
+
+            Some(Scrollbar {
+                orientation: Orientation::Vertical,
+                outer_bounds: place(outer_bounds),
+                bounds: place(scrollbar_bounds),
+                scroller: Scroller {
+                    bounds: place(scroller_bounds),
+                },
+            })
+        } else {
+            None
+        };
+
+        let x = if show_x {
+            let x = match direction {
+                Direction::LeftToRight => bounds.x,
+                Direction::RightToLeft => bounds.x + x_corner,
+            };
+            let width = bounds.width - x_corner;
+
+            let outer_bounds = Rectangle {
+                x,
+                y: bounds.y + bounds.height - outer_width as f32,
+                width,
+                height: outer_width as f32,
+            };
+
+            let scrollbar_bounds = Rectangle {
+                x,
+                y: bounds.y + bounds.height
+                    - f32::from(outer_width / 2 + self.scrollbar_width / 2),
+                width,
+                height: self.scrollbar_width as f32,
+            };
+
+            let ratio = width / content_bounds.width;
+            let scroller_width = bounds.width * ratio;
+            let x_offset =
+                (offset.x * ratio).max(0.0).min(width - scroller_width);
+
+            let scroller_bounds = Rectangle {
+                x: scrollbar_bounds.x + x_offset,
+                y: bounds.y + bounds.height
+                    - f32::from(outer_width / 2 + self.scroller_width / 2),
+                width: scroller_width,
+                height: self.scroller_width as f32,
+            };
+
             Some(Scrollbar {
+                orientation: Orientation::Horizontal,
                 outer_bounds,
                 bounds: scrollbar_bounds,
                 scroller: Scroller {
@@ -205,7 +693,9 @@ impl<'a, Message, Renderer: crate::Renderer> Scrollable<'a, Message, Renderer> {
             })
         } else {
             None
-        }
+        };
+
+        Scrollbars { y, x }
     }
 }
 
@@ -215,7 +705,7 @@ where
     Renderer: crate::Renderer,
 {
     fn width(&self) -> Length {
-        Widget::<Message, Renderer>::width(&self.content)
+        self.width
     }
 
     fn height(&self) -> Length {
@@ -229,13 +719,26 @@ where
     ) -> layout::Node {
         let limits = limits
             .max_height(self.max_height)
-            .width(Widget::<Message, Renderer>::width(&self.content))
+            .width(self.width)
             .height(self.height);
 
+        let max_width = if self.axes.is_horizontal() {
+            f32::INFINITY
+        } else {
+            limits.max().width
+        };
+
+        let max_height = if self.axes.is_vertical() {
+            f32::INFINITY
+        } else {
+            limits.max().height
+        };
+
         let child_limits = layout::Limits::new(
             Size::new(limits.min().width, 0.0),
-            Size::new(limits.max().width, f32::INFINITY),
-        );
+            Size::new(max_width, max_height),
+        )
+        .with_direction(limits.direction());
 
         let content = self.content.layout(renderer, &child_limits);
         let size = limits.resolve(content.size());
@@ -252,25 +755,67 @@ where
         clipboard: &mut dyn Clipboard,
         shell: &mut Shell<'_, Message>,
     ) -> event::Status {
+        if let Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) =
+            event
+        {
+            self.state.keyboard_modifiers = modifiers;
+        }
+
+        self.state.anchor = self.anchor;
+
         let bounds = layout.bounds();
         let is_mouse_over = bounds.contains(cursor_position);
 
         let content = layout.children().next().unwrap();
         let content_bounds = content.bounds();
 
-        let scrollbar = self.scrollbar(bounds, content_bounds);
-        let is_mouse_over_scrollbar = scrollbar
-            .as_ref()
-            .map(|scrollbar| scrollbar.is_mouse_over(cursor_position))
-            .unwrap_or(false);
+        let scrollbars =
+            self.scrollbars(layout.direction(), bounds, content_bounds);
+        let (is_mouse_over_y_scrollbar, is_mouse_over_x_scrollbar) =
+            scrollbars.is_mouse_over(cursor_position);
+        let is_mouse_over_scrollbar =
+            is_mouse_over_y_scrollbar || is_mouse_over_x_scrollbar;
+
+        self.check_edges(bounds, content_bounds, shell);
+
+        if is_mouse_over_scrollbar || self.state.is_scroller_grabbed() {
+            self.reveal_scrollbars(shell);
+        } else if let Event::Window(window::Event::RedrawRequested(now)) = event
+        {
+            self.schedule_fade(now, shell);
+        }
+
+        if let Event::Window(window::Event::RedrawRequested(now)) = event {
+            if self.state.kinetic.is_flinging() {
+                let max = self.max_offset(bounds, content_bounds);
+
+                if self.state.fling(
+                    max,
+                    self.bounce,
+                    now,
+                    bounds,
+                    content_bounds,
+                ) {
+                    shell.request_redraw(window::RedrawRequest::NextFrame);
+                }
+
+                self.notify_on_scroll(bounds, content_bounds, shell);
+            }
+        }
+
+        if let Event::Accessibility(accessibility::Request {
+            target,
+            action: accessibility::Action::ScrollIntoView,
+        }) = &event
+        {
+            if self.scroll_into_view(*target, bounds, content_bounds) {
+                self.notify_on_scroll(bounds, content_bounds, shell);
+            }
+        }
 
         let event_status = {
             let cursor_position = if is_mouse_over && !is_mouse_over_scrollbar {
-                Point::new(
-                    cursor_position.x,
-                    cursor_position.y
-                        + self.state.offset(bounds, content_bounds) as f32,
-                )
+                cursor_position + self.state.offset(bounds, content_bounds)
             } else {
                 // TODO: Make `cursor_position` an `Option<Point>` so we can encode
                 // cursor availability.
@@ -290,23 +835,85 @@ where
         };
 
         if let event::Status::Captured = event_status {
+            if self.auto_scroll {
+                if let Event::Mouse(mouse::Event::CursorMoved { .. })
+                | Event::Touch(touch::Event::FingerMoved { .. }) = event
+                {
+                    self.follow_drag(
+                        cursor_position,
+                        bounds,
+                        content_bounds,
+                        shell,
+                    );
+                }
+            }
+
             return event::Status::Captured;
         }
 
         if is_mouse_over {
             match event {
                 Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
-                    match delta {
-                        mouse::ScrollDelta::Lines { y, .. } => {
+                    let delta = match delta {
+                        mouse::ScrollDelta::Lines { x, y } => {
                             // TODO: Configurable speed (?)
-                            self.state.scroll(y * 60.0, bounds, content_bounds);
+                            Vector::new(x, y) * 60.0
                         }
-                        mouse::ScrollDelta::Pixels { y, .. } => {
-                            self.state.scroll(y, bounds, content_bounds);
+                        mouse::ScrollDelta::Pixels { x, y } => {
+                            Vector::new(x, y)
                         }
+                    };
+
+                    // Holding shift turns the vertical scrolling of a mouse
+                    // wheel into horizontal scrolling
+                    let delta = if self.axes.is_horizontal()
+                        && self.state.keyboard_modifiers.shift()
+                        && delta.x == 0.0
+                    {
+                        Vector::new(delta.y, 0.0)
+                    } else {
+                        delta
+                    };
+
+                    let delta = self.axes.mask(delta);
+                    let max = self.max_offset(bounds, content_bounds);
+
+                    // Content that cannot be scrolled in the direction of the
+                    // wheel lets the event through
+                    if (delta.x == 0.0 || max.x == 0.0)
+                        && (delta.y == 0.0 || max.y == 0.0)
+                    {
+                        return event::Status::Ignored;
                     }
 
-                    self.notify_on_scroll(bounds, content_bounds, shell);
+                    let now = Instant::now();
+                    let offset =
+                        self.state.absolute_offset(bounds, content_bounds);
+
+                    self.state.kinetic.stop();
+                    self.state.scroll(delta, bounds, content_bounds);
+
+                    if self.state.absolute_offset(bounds, content_bounds)
+                        != offset
+                    {
+                        self.state.wheel_scrolled_at = Some(now);
+                        self.notify_on_scroll(bounds, content_bounds, shell);
+                    } else if self.scroll_chaining {
+                        // A scroll gesture stays latched to the scrollable
+                        // that started moving with it
+                        let is_latched = self
+                            .state
+                            .wheel_scrolled_at
+                            .map(|scrolled_at| {
+                                now.saturating_duration_since(scrolled_at)
+                                    < SCROLL_LATCH
+                            })
+                            .unwrap_or(false);
+
+                        if !is_latched {
+                            return event::Status::Ignored;
+                        }
+                    }
 
                     return event::Status::Captured;
                 }
@@ -315,20 +922,37 @@ where
                         touch::Event::FingerPressed { .. } => {
                             self.state.scroll_box_touched_at =
                                 Some(cursor_position);
+
+                            self.state.kinetic.stop();
                         }
                         touch::Event::FingerMoved { .. } => {
                             if let Some(scroll_box_touched_at) =
                                 self.state.scroll_box_touched_at
                             {
-                                let delta =
-                                    cursor_position.y - scroll_box_touched_at.y;
-
-                                self.state.scroll(
-                                    delta,
-                                    bounds,
-                                    content_bounds,
+                                let delta = self.axes.mask(
+                                    cursor_position - scroll_box_touched_at,
                                 );
 
+                                if self.kinetic {
+                                    let max =
+                                        self.max_offset(bounds, content_bounds);
+
+                                    self.state.drag(
+                                        delta,
+                                        max,
+                                        self.bounce,
+                                        Instant::now(),
+                                        bounds,
+                                        content_bounds,
+                                    );
+                                } else {
+                                    self.state.scroll(
+                                        delta,
+                                        bounds,
+                                        content_bounds,
+                                    );
+                                }
+
                                 self.state.scroll_box_touched_at =
                                     Some(cursor_position);
 
@@ -342,6 +966,14 @@ where
                         touch::Event::FingerLifted { .. }
                         | touch::Event::FingerLost { .. } => {
                             self.state.scroll_box_touched_at = None;
+
+                            if self.kinetic
+                                && self.state.kinetic.release(Instant::now())
+                            {
+                                shell.request_redraw(
+                                    window::RedrawRequest::NextFrame,
+                                );
+                            }
                         }
                     }
 
@@ -358,16 +990,17 @@ where
                 ))
                 | Event::Touch(touch::Event::FingerLifted { .. })
                 | Event::Touch(touch::Event::FingerLost { .. }) => {
-                    self.state.scroller_grabbed_at = None;
+                    self.state.y_scroller_grabbed_at = None;
+                    self.state.x_scroller_grabbed_at = None;
 
                     return event::Status::Captured;
                 }
                 Event::Mouse(mouse::Event::CursorMoved { .. })
                 | Event::Touch(touch::Event::FingerMoved { .. }) => {
                     if let (Some(scrollbar), Some(scroller_grabbed_at)) =
-                        (scrollbar, self.state.scroller_grabbed_at)
+                        (&scrollbars.y, self.state.y_scroller_grabbed_at)
                     {
-                        self.state.scroll_to(
+                        self.state.scroll_y_to(
                             scrollbar.scroll_percentage(
                                 scroller_grabbed_at,
                                 cursor_position,
@@ -375,11 +1008,24 @@ where
                             bounds,
                             content_bounds,
                         );
+                    }
 
-                        self.notify_on_scroll(bounds, content_bounds, shell);
-
-                        return event::Status::Captured;
+                    if let (Some(scrollbar), Some(scroller_grabbed_at)) =
+                        (&scrollbars.x, self.state.x_scroller_grabbed_at)
+                    {
+                        self.state.scroll_x_to(
+                            scrollbar.scroll_percentage(
+                                scroller_grabbed_at,
+                                cursor_position,
+                            ),
+                            bounds,
+                            content_bounds,
+                        );
                     }
+
+                    self.notify_on_scroll(bounds, content_bounds, shell);
+
+                    return event::Status::Captured;
                 }
                 _ => {}
             }
@@ -389,11 +1035,13 @@ where
                     mouse::Button::Left,
                 ))
                 | Event::Touch(touch::Event::FingerPressed { .. }) => {
-                    if let Some(scrollbar) = scrollbar {
-                        if let Some(scroller_grabbed_at) =
+                    if let Some(scroller_grabbed_at) =
+                        scrollbars.y.as_ref().and_then(|scrollbar| {
                             scrollbar.grab_scroller(cursor_position)
-                        {
-                            self.state.scroll_to(
+                        })
+                    {
+                        if let Some(scrollbar) = &scrollbars.y {
+                            self.state.scroll_y_to(
                                 scrollbar.scroll_percentage(
                                     scroller_grabbed_at,
                                     cursor_position,
@@ -401,19 +1049,35 @@ where
                                 bounds,
                                 content_bounds,
                             );
+                        }
 
-                            self.state.scroller_grabbed_at =
-                                Some(scroller_grabbed_at);
-
-                            self.notify_on_scroll(
+                        self.state.y_scroller_grabbed_at =
+                            Some(scroller_grabbed_at);
+                    } else if let Some(scroller_grabbed_at) =
+                        scrollbars.x.as_ref().and_then(|scrollbar| {
+                            scrollbar.grab_scroller(cursor_position)
+                        })
+                    {
+                        if let Some(scrollbar) = &scrollbars.x {
+                            self.state.scroll_x_to(
+                                scrollbar.scroll_percentage(
+                                    scroller_grabbed_at,
+                                    cursor_position,
+                                ),
                                 bounds,
                                 content_bounds,
-                                shell,
                             );
-
-                            return event::Status::Captured;
                         }
+
+                        self.state.x_scroller_grabbed_at =
+                            Some(scroller_grabbed_at);
+                    } else {
+                        return event::Status::Ignored;
                     }
+
+                    self.notify_on_scroll(bounds, content_bounds, shell);
+
+                    return event::Status::Captured;
                 }
                 _ => {}
             }
@@ -431,13 +1095,14 @@ where
         let bounds = layout.bounds();
         let content_layout = layout.children().next().unwrap();
         let content_bounds = content_layout.bounds();
-        let scrollbar = self.scrollbar(bounds, content_bounds);
+        let scrollbars =
+            self.scrollbars(layout.direction(), bounds, content_bounds);
 
         let is_mouse_over = bounds.contains(cursor_position);
-        let is_mouse_over_scrollbar = scrollbar
-            .as_ref()
-            .map(|scrollbar| scrollbar.is_mouse_over(cursor_position))
-            .unwrap_or(false);
+        let (is_mouse_over_y_scrollbar, is_mouse_over_x_scrollbar) =
+            scrollbars.is_mouse_over(cursor_position);
+        let is_mouse_over_scrollbar =
+            is_mouse_over_y_scrollbar || is_mouse_over_x_scrollbar;
 
         if is_mouse_over_scrollbar || self.state.is_scroller_grabbed() {
             mouse::Interaction::Idle
@@ -445,7 +1110,7 @@ where
             let offset = self.state.offset(bounds, content_bounds);
 
             let cursor_position = if is_mouse_over && !is_mouse_over_scrollbar {
-                Point::new(cursor_position.x, cursor_position.y + offset as f32)
+                cursor_position + offset
             } else {
                 Point::new(cursor_position.x, -1.0)
             };
@@ -454,7 +1119,8 @@ where
                 content_layout,
                 cursor_position,
                 &Rectangle {
-                    y: bounds.y + offset as f32,
+                    x: bounds.x + offset.x,
+                    y: bounds.y + offset.y,
                     ..bounds
                 },
             )
@@ -469,66 +1135,99 @@ where
         cursor_position: Point,
         _viewport: &Rectangle,
     ) {
+        let theme = style
+            .theme
+            .resolve(theme::Widget::Scrollable, self.class.as_deref());
+        let style_sheet = self.style_sheet.as_deref().unwrap_or(&theme);
+
         let bounds = layout.bounds();
         let content_layout = layout.children().next().unwrap();
         let content_bounds = content_layout.bounds();
         let offset = self.state.offset(bounds, content_bounds);
-        let scrollbar = self.scrollbar(bounds, content_bounds);
+        let scrollbars =
+            self.scrollbars(layout.direction(), bounds, content_bounds);
 
         let is_mouse_over = bounds.contains(cursor_position);
-        let is_mouse_over_scrollbar = scrollbar
-            .as_ref()
-            .map(|scrollbar| scrollbar.is_mouse_over(cursor_position))
-            .unwrap_or(false);
+        let (is_mouse_over_y_scrollbar, is_mouse_over_x_scrollbar) =
+            scrollbars.is_mouse_over(cursor_position);
+        let is_mouse_over_scrollbar =
+            is_mouse_over_y_scrollbar || is_mouse_over_x_scrollbar;
 
         let cursor_position = if is_mouse_over && !is_mouse_over_scrollbar {
-            Point::new(cursor_position.x, cursor_position.y + offset as f32)
+            cursor_position + offset
         } else {
             Point::new(cursor_position.x, -1.0)
         };
 
-        if let Some(scrollbar) = scrollbar {
+        let viewport = Rectangle {
+            x: bounds.x + offset.x,
+            y: bounds.y + offset.y,
+            ..bounds
+        };
+
+        if scrollbars.is_active() {
             renderer.with_layer(bounds, |renderer| {
                 renderer.with_translation(
-                    Vector::new(0.0, -(offset as f32)),
+                    Vector::new(-offset.x, -offset.y),
                     |renderer| {
                         self.content.draw(
                             renderer,
                             style,
                             content_layout,
                             cursor_position,
-                            &Rectangle {
-                                y: bounds.y + offset as f32,
-                                ..bounds
-                            },
+                            &viewport,
                         );
                     },
                 );
             });
 
-            let style = if self.state.is_scroller_grabbed() {
-                self.style_sheet.dragging()
-            } else if is_mouse_over_scrollbar {
-                self.style_sheet.hovered()
-            } else {
-                self.style_sheet.active()
-            };
+            let draw_scrollbar =
+                |renderer: &mut Renderer,
+                 scrollbar: &Scrollbar,
+                 is_mouse_over_scrollbar: bool,
+                 is_scroller_grabbed: bool| {
+                    let is_expanded =
+                        is_mouse_over_scrollbar || is_scroller_grabbed;
+
+                    let alpha = if is_expanded {
+                        1.0
+                    } else {
+                        self.scrollbar_alpha(Instant::now())
+                    };
+
+                    if alpha <= 0.0 {
+                        return;
+                    }
 
-            let is_scrollbar_visible =
-                style.background.is_some() || style.border_width > 0.0;
+                    let style = if is_scroller_grabbed {
+                        style_sheet.dragging()
+                    } else if is_mouse_over_scrollbar {
+                        style_sheet.hovered()
+                    } else {
+                        style_sheet.active()
+                    };
+
+                    let style = fade(style, alpha);
+
+                    let (track_bounds, scroller_bounds) =
+                        match self.expanded_width {
+                            Some(width) if is_expanded => {
+                                scrollbar.expand(f32::from(width))
+                            }
+                            _ => (scrollbar.bounds, scrollbar.scroller.bounds),
+                        };
+
+                    let is_scrollbar_visible =
+                        style.background.is_some() || style.border_width > 0.0;
 
-            renderer.with_layer(
-                Rectangle {
-                    width: bounds.width + 2.0,
-                    height: bounds.height + 2.0,
-                    ..bounds
-                },
-                |renderer| {
                     if is_scrollbar_visible {
                         renderer.fill_quad(
                             renderer::Quad {
-                                bounds: scrollbar.bounds,
-                                border_radius: style.border_radius,
+                                bounds: track_bounds,
+                                border_radius: rounded(
+                                    style.border_radius,
+                                    track_bounds,
+                                ),
                                 border_width: style.border_width,
                                 border_color: style.border_color,
                             },
@@ -541,17 +1240,47 @@ where
                     if is_mouse_over
                         || self.state.is_scroller_grabbed()
                         || is_scrollbar_visible
+                        || self.auto_hide.is_some()
                     {
                         renderer.fill_quad(
                             renderer::Quad {
-                                bounds: scrollbar.scroller.bounds,
-                                border_radius: style.scroller.border_radius,
+                                bounds: scroller_bounds,
+                                border_radius: rounded(
+                                    style.scroller.border_radius,
+                                    scroller_bounds,
+                                ),
                                 border_width: style.scroller.border_width,
                                 border_color: style.scroller.border_color,
                             },
                             style.scroller.color,
                         );
                     }
+                };
+
+            renderer.with_layer(
+                Rectangle {
+                    width: bounds.width + 2.0,
+                    height: bounds.height + 2.0,
+                    ..bounds
+                },
+                |renderer| {
+                    if let Some(scrollbar) = &scrollbars.y {
+                        draw_scrollbar(
+                            renderer,
+                            scrollbar,
+                            is_mouse_over_y_scrollbar,
+                            self.state.y_scroller_grabbed_at.is_some(),
+                        );
+                    }
+
+                    if let Some(scrollbar) = &scrollbars.x {
+                        draw_scrollbar(
+                            renderer,
+                            scrollbar,
+                            is_mouse_over_x_scrollbar,
+                            self.state.x_scroller_grabbed_at.is_some(),
+                        );
+                    }
                 },
             );
         } else {
@@ -560,10 +1289,7 @@ where
                 style,
                 content_layout,
                 cursor_position,
-                &Rectangle {
-                    y: bounds.y + offset as f32,
-                    ..bounds
-                },
+                &viewport,
             );
         }
     }
@@ -572,74 +1298,220 @@ where
         struct Marker;
         std::any::TypeId::of::<Marker>().hash(state);
 
+        self.axes.hash(state);
+        self.width.hash(state);
         self.height.hash(state);
         self.max_height.hash(state);
 
         self.content.hash_layout(state)
     }
 
+    fn operate(
+        &mut self,
+        layout: Layout<'_>,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let Self {
+            state,
+            id,
+            anchor,
+            content,
+            ..
+        } = self;
+
+        state.anchor = *anchor;
+
+        operation.accessible(
+            accessibility::Node::new(
+                accessibility::Role::ScrollView,
+                layout.bounds(),
+            ),
+            id.as_ref(),
+        );
+        operation.scrollable(*state, id.as_ref());
+        operation.container(id.as_ref(), &mut |operation| {
+            content.operate(layout.children().next().unwrap(), operation);
+        });
+    }
+
+    fn inspect(&self, layout: Layout<'_>) -> inspector::Node {
+        let bounds = layout.bounds();
+        let content_layout = layout.children().next().unwrap();
+        let offset = self.state.offset(bounds, content_layout.bounds());
+
+        inspector::Node::new(
+            std::any::type_name::<Self>(),
+            self.width,
+            self.height,
+            layout,
+        )
+        .with_id(self.id.clone())
+        .with_children(vec![self
+            .content
+            .inspect(content_layout)
+            .translate(Vector::new(-offset.x, -offset.y))])
+    }
+
     fn overlay(
         &mut self,
         layout: Layout<'_>,
+        viewport: &Rectangle,
     ) -> Option<overlay::Element<'_, Message, Renderer>> {
         let Self { content, state, .. } = self;
 
+        let bounds = layout.bounds();
+        let content_layout = layout.children().next().unwrap();
+        let offset = state.offset(bounds, content_layout.bounds());
+
+        // The content only sees the visible part of the scrollable
+        let visible = bounds.intersection(viewport).unwrap_or(Rectangle {
+            width: 0.0,
+            height: 0.0,
+            ..bounds
+        });
+
+        let viewport = Rectangle {
+            x: visible.x + offset.x,
+            y: visible.y + offset.y,
+            ..visible
+        };
+
         content
-            .overlay(layout.children().next().unwrap())
-            .map(|overlay| {
-                let bounds = layout.bounds();
-                let content_layout = layout.children().next().unwrap();
-                let content_bounds = content_layout.bounds();
-                let offset = state.offset(bounds, content_bounds);
-
-                overlay.translate(Vector::new(0.0, -(offset as f32)))
-            })
+            .overlay(content_layout, &viewport)
+            .map(|overlay| overlay.translate(Vector::new(-offset.x, -offset.y)))
     }
 }
 
+/// The axes along which a [`Scrollable`] can be scrolled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Axes {
+    /// The content can only be scrolled vertically.
+    #[default]
+    Vertical,
+
+    /// The content can only be scrolled horizontally.
+    Horizontal,
+
+    /// The content can be scrolled both vertically and horizontally.
+    Both,
+}
+
+impl Axes {
+    fn is_vertical(self) -> bool {
+        matches!(self, Axes::Vertical | Axes::Both)
+    }
+
+    fn is_horizontal(self) -> bool {
+        matches!(self, Axes::Horizontal | Axes::Both)
+    }
+
+    /// Discards the components of the given [`Vector`] along the axes that
+    /// cannot be scrolled.
+    fn mask(self, vector: Vector) -> Vector {
+        Vector::new(
+            if self.is_horizontal() { vector.x } else { 0.0 },
+            if self.is_vertical() { vector.y } else { 0.0 },
+        )
+    }
+}
+
+/// The edge of the content of a [`Scrollable`] that its scroll offset is
+/// measured from.
+///
+/// The visible content stays in place when content is added or removed on
+/// the opposite edge.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Anchor {
+    /// The offset is measured from the start of the content.
+    ///
+    /// Appended content does not move the visible content.
+    #[default]
+    Start,
+
+    /// The offset is measured from the end of the content.
+    ///
+    /// Prepended content does not move the visible content. This is useful
+    /// to load older items on top of a feed or chat.
+    End,
+}
+
 /// The local state of a [`Scrollable`].
 #[derive(Debug, Clone, Copy)]
 pub struct State {
-    scroller_grabbed_at: Option<f32>,
+    y_scroller_grabbed_at: Option<f32>,
+    x_scroller_grabbed_at: Option<f32>,
     scroll_box_touched_at: Option<Point>,
-    offset: Offset,
+    revealed_at: Option<Instant>,
+    wheel_scrolled_at: Option<Instant>,
+    kinetic: kinetic::Kinetic,
+    anchor: Anchor,
+    offset_y: Offset,
+    offset_x: Offset,
+    start_reached_at: Option<f32>,
+    end_reached_at: Option<f32>,
+    keyboard_modifiers: keyboard::Modifiers,
 }
 
 impl Default for State {
     fn default() -> Self {
         Self {
-            scroller_grabbed_at: None,
+            y_scroller_grabbed_at: None,
+            x_scroller_grabbed_at: None,
             scroll_box_touched_at: None,
-            offset: Offset::Absolute(0.0),
+            revealed_at: None,
+            wheel_scrolled_at: None,
+            kinetic: kinetic::Kinetic::default(),
+            anchor: Anchor::default(),
+            offset_y: Offset::Absolute(0.0),
+            offset_x: Offset::Absolute(0.0),
+            start_reached_at: None,
+            end_reached_at: None,
+            keyboard_modifiers: keyboard::Modifiers::default(),
         }
     }
 }
 
-/// The local state of a [`Scrollable`].
-#[derive(Debug, Clone, Copy)]
-enum Offset {
+/// The scroll offset of a [`Scrollable`] along one of its axes.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(
+    feature = "persistence",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub(crate) enum Offset {
     Absolute(f32),
+    FromEnd(f32),
     Relative(f32),
 }
 
 impl Offset {
-    fn absolute(self, bounds: Rectangle, content_bounds: Rectangle) -> f32 {
-        match self {
-            Self::Absolute(absolute) => {
-                let hidden_content =
-                    (content_bounds.height - bounds.height).max(0.0);
+    /// Stores an absolute offset measured from the given [`Anchor`].
+    fn anchored(
+        absolute: f32,
+        viewport: f32,
+        content: f32,
+        anchor: Anchor,
+    ) -> Self {
+        match anchor {
+            Anchor::Start => Self::Absolute(absolute),
+            Anchor::End => Self::FromEnd(
+                ((content - viewport).max(0.0) - absolute).max(0.0),
+            ),
+        }
+    }
 
-                absolute.min(hidden_content)
-            }
-            Self::Relative(percentage) => {
-                ((content_bounds.height - bounds.height) * percentage).max(0.0)
-            }
+    fn absolute(self, viewport: f32, content: f32) -> f32 {
+        let hidden_content = (content - viewport).max(0.0);
+
+        match self {
+            Self::Absolute(absolute) => absolute.min(hidden_content),
+            Self::FromEnd(distance) => (hidden_content - distance).max(0.0),
+            Self::Relative(percentage) => hidden_content * percentage,
         }
     }
 }
 
 impl State {
-    /// Creates a new [`State`] with the scrollbar located at the top.
+    /// Creates a new [`State`] with the scrollbars located at the start.
     pub fn new() -> Self {
         State::default()
     }
@@ -648,70 +1520,352 @@ impl State {
     /// the [`Scrollable`] and its contents.
     pub fn scroll(
         &mut self,
-        delta_y: f32,
+        delta: Vector,
         bounds: Rectangle,
         content_bounds: Rectangle,
     ) {
-        if bounds.height >= content_bounds.height {
-            return;
-        }
+        let offset = self.absolute_offset(bounds, content_bounds);
+        let hidden_content = Vector::new(
+            (content_bounds.width - bounds.width).max(0.0),
+            (content_bounds.height - bounds.height).max(0.0),
+        );
 
-        self.offset = Offset::Absolute(
-            (self.offset.absolute(bounds, content_bounds) - delta_y)
-                .max(0.0)
-                .min((content_bounds.height - bounds.height) as f32),
+        self.set_absolute_offset(
+            Vector::new(
+                (offset.x - delta.x).clamp(0.0, hidden_content.x),
+                (offset.y - delta.y).clamp(0.0, hidden_content.y),
+            ),
+            bounds,
+            content_bounds,
         );
     }
 
-    /// Scrolls the [`Scrollable`] to a relative amount.
+    /// Drags the content with a touch, tracking its velocity for a later
+    /// fling.
+    fn drag(
+        &mut self,
+        delta: Vector,
+        max: Vector,
+        bounce: bool,
+        now: Instant,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+    ) {
+        let mut offset = self.absolute_offset(bounds, content_bounds);
+
+        self.kinetic.drag(&mut offset, delta, max, bounce, now);
+
+        self.set_absolute_offset(offset, bounds, content_bounds);
+    }
+
+    /// Advances the current fling until the given time.
+    ///
+    /// It returns whether the content keeps moving.
+    fn fling(
+        &mut self,
+        max: Vector,
+        bounce: bool,
+        now: Instant,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+    ) -> bool {
+        let mut offset = self.absolute_offset(bounds, content_bounds);
+
+        let is_flinging = self.kinetic.step(&mut offset, max, bounce, now);
+
+        self.set_absolute_offset(offset, bounds, content_bounds);
+
+        is_flinging
+    }
+
+    fn absolute_offset(
+        &self,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+    ) -> Vector {
+        Vector::new(
+            self.offset_x.absolute(bounds.width, content_bounds.width),
+            self.offset_y.absolute(bounds.height, content_bounds.height),
+        )
+    }
+
+    fn set_absolute_offset(
+        &mut self,
+        offset: Vector,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+    ) {
+        self.offset_x = Offset::anchored(
+            offset.x,
+            bounds.width,
+            content_bounds.width,
+            self.anchor,
+        );
+        self.offset_y = Offset::anchored(
+            offset.y,
+            bounds.height,
+            content_bounds.height,
+            self.anchor,
+        );
+    }
+
+    /// Scrolls the [`Scrollable`] vertically to a relative amount.
     ///
     /// `0` represents scrollbar at the top, while `1` represents scrollbar at
     /// the bottom.
-    pub fn scroll_to(
+    pub fn scroll_y_to(
         &mut self,
         percentage: f32,
         bounds: Rectangle,
         content_bounds: Rectangle,
     ) {
-        self.snap_to(percentage);
-        self.unsnap(bounds, content_bounds);
+        self.offset_y = Offset::anchored(
+            Offset::Relative(percentage.clamp(0.0, 1.0))
+                .absolute(bounds.height, content_bounds.height),
+            bounds.height,
+            content_bounds.height,
+            self.anchor,
+        );
     }
 
-    /// Snaps the scroll position to a relative amount.
+    /// Scrolls the [`Scrollable`] horizontally to a relative amount.
     ///
-    /// `0` represents scrollbar at the top, while `1` represents scrollbar at
-    /// the bottom.
-    pub fn snap_to(&mut self, percentage: f32) {
-        self.offset = Offset::Relative(percentage.max(0.0).min(1.0));
+    /// `0` represents scrollbar at the left, while `1` represents scrollbar at
+    /// the right.
+    pub fn scroll_x_to(
+        &mut self,
+        percentage: f32,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+    ) {
+        self.offset_x = Offset::anchored(
+            Offset::Relative(percentage.clamp(0.0, 1.0))
+                .absolute(bounds.width, content_bounds.width),
+            bounds.width,
+            content_bounds.width,
+            self.anchor,
+        );
+    }
+
+    /// Snaps the scroll position to a [`RelativeOffset`].
+    pub fn snap_to(&mut self, offset: RelativeOffset) {
+        self.offset_x = Offset::Relative(offset.x.clamp(0.0, 1.0));
+        self.offset_y = Offset::Relative(offset.y.clamp(0.0, 1.0));
     }
 
     /// Unsnaps the current scroll position, if snapped, given the bounds of the
     /// [`Scrollable`] and its contents.
     pub fn unsnap(&mut self, bounds: Rectangle, content_bounds: Rectangle) {
-        self.offset =
-            Offset::Absolute(self.offset.absolute(bounds, content_bounds));
+        self.set_absolute_offset(
+            self.absolute_offset(bounds, content_bounds),
+            bounds,
+            content_bounds,
+        );
     }
 
-    /// Returns the current scrolling offset of the [`State`], given the bounds
-    /// of the [`Scrollable`] and its contents.
-    pub fn offset(&self, bounds: Rectangle, content_bounds: Rectangle) -> u32 {
-        self.offset.absolute(bounds, content_bounds) as u32
+    /// Returns the current scrolling offset of the [`State`] in whole pixels,
+    /// given the bounds of the [`Scrollable`] and its contents.
+    ///
+    /// It includes the amount of content dragged past its edges.
+    pub fn offset(
+        &self,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+    ) -> Vector {
+        let offset = self.absolute_offset(bounds, content_bounds)
+            + self.kinetic.overscroll();
+
+        Vector::new(offset.x.floor(), offset.y.floor())
     }
 
-    /// Returns whether the scroller is currently grabbed or not.
+    /// Returns whether a scroller is currently grabbed or not.
     pub fn is_scroller_grabbed(&self) -> bool {
-        self.scroller_grabbed_at.is_some()
+        self.y_scroller_grabbed_at.is_some()
+            || self.x_scroller_grabbed_at.is_some()
     }
 
     /// Returns whether the scroll box is currently touched or not.
     pub fn is_scroll_box_touched(&self) -> bool {
         self.scroll_box_touched_at.is_some()
     }
+
+    /// Returns the scroll position of the [`State`], so it can be persisted.
+    #[cfg(feature = "persistence")]
+    pub(crate) fn snapshot(&self) -> persistence::Scroll {
+        persistence::Scroll {
+            x: self.offset_x,
+            y: self.offset_y,
+        }
+    }
+
+    /// Restores a scroll position obtained with [`State::snapshot`], stopping
+    /// any kinetic scrolling in progress.
+    #[cfg(feature = "persistence")]
+    pub(crate) fn restore(&mut self, scroll: persistence::Scroll) {
+        self.offset_x = scroll.x;
+        self.offset_y = scroll.y;
+        self.kinetic = kinetic::Kinetic::default();
+    }
+}
+
+impl operation::Scrollable for State {
+    fn snap_to(&mut self, offset: RelativeOffset) {
+        State::snap_to(self, offset);
+    }
+
+    fn scroll_to(&mut self, offset: AbsoluteOffset) {
+        self.offset_x = Offset::Absolute(offset.x.max(0.0));
+        self.offset_y = Offset::Absolute(offset.y.max(0.0));
+    }
+}
+
+/// Applies the given opacity to the colors of a scrollbar [`Style`].
+///
+/// [`Style`]: iced_style::scrollable::Scrollbar
+fn fade(
+    style: iced_style::scrollable::Scrollbar,
+    alpha: f32,
+) -> iced_style::scrollable::Scrollbar {
+    let fade = |color: Color| Color {
+        a: color.a * alpha,
+        ..color
+    };
+
+    iced_style::scrollable::Scrollbar {
+        background: style.background.map(|background| match background {
+            Background::Color(color) => Background::Color(fade(color)),
+        }),
+        border_color: fade(style.border_color),
+        scroller: iced_style::scrollable::Scroller {
+            color: fade(style.scroller.color),
+            border_color: fade(style.scroller.border_color),
+            ..style.scroller
+        },
+        ..style
+    }
 }
 
-/// The scrollbar of a [`Scrollable`].
+/// Limits a border radius to half the thickness of the given bounds, so large
+/// radii produce fully rounded ends.
+fn rounded(border_radius: f32, bounds: Rectangle) -> f32 {
+    border_radius.min(bounds.width.min(bounds.height) / 2.0)
+}
+
+/// Produces a [`Command`] that snaps the [`Scrollable`] with the given [`Id`]
+/// to a [`RelativeOffset`].
+///
+/// The [`Scrollable`] will stay snapped to the offset even if its content
+/// changes. For instance, snapping to [`RelativeOffset::END`] keeps the
+/// newest content of a chat in view.
+pub fn snap_to<Message: 'static>(
+    id: Id,
+    offset: RelativeOffset,
+) -> Command<Message> {
+    Command::widget(operation::scrollable::snap_to(id, offset))
+}
+
+/// Produces a [`Command`] that scrolls the [`Scrollable`] with the given [`Id`]
+/// to an [`AbsoluteOffset`].
+pub fn scroll_to<Message: 'static>(
+    id: Id,
+    offset: AbsoluteOffset,
+) -> Command<Message> {
+    Command::widget(operation::scrollable::scroll_to(id, offset))
+}
+
+/// The visible region of the content of a [`Scrollable`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Viewport {
+    offset: Vector,
+    bounds: Rectangle,
+    content_bounds: Rectangle,
+}
+
+impl Viewport {
+    /// Returns the [`AbsoluteOffset`] of the [`Viewport`].
+    pub fn absolute_offset(&self) -> AbsoluteOffset {
+        AbsoluteOffset {
+            x: self.offset.x,
+            y: self.offset.y,
+        }
+    }
+
+    /// Returns the [`RelativeOffset`] of the [`Viewport`].
+    pub fn relative_offset(&self) -> RelativeOffset {
+        let relative = |offset: f32, viewport: f32, content: f32| {
+            let hidden_content = (content - viewport).max(0.0);
+
+            if hidden_content > 0.0 {
+                offset / hidden_content
+            } else {
+                0.0
+            }
+        };
+
+        RelativeOffset {
+            x: relative(
+                self.offset.x,
+                self.bounds.width,
+                self.content_bounds.width,
+            ),
+            y: relative(
+                self.offset.y,
+                self.bounds.height,
+                self.content_bounds.height,
+            ),
+        }
+    }
+
+    /// Returns the bounds of the [`Scrollable`].
+    pub fn bounds(&self) -> Rectangle {
+        self.bounds
+    }
+
+    /// Returns the bounds of the content of the [`Scrollable`].
+    ///
+    /// Its size is the full size of the content, visible or not.
+    pub fn content_bounds(&self) -> Rectangle {
+        self.content_bounds
+    }
+}
+
+/// The scrollbars of a [`Scrollable`].
+#[derive(Debug)]
+struct Scrollbars {
+    y: Option<Scrollbar>,
+    x: Option<Scrollbar>,
+}
+
+impl Scrollbars {
+    fn is_active(&self) -> bool {
+        self.y.is_some() || self.x.is_some()
+    }
+
+    fn is_mouse_over(&self, cursor_position: Point) -> (bool, bool) {
+        let is_mouse_over = |scrollbar: &Option<Scrollbar>| {
+            scrollbar
+                .as_ref()
+                .map(|scrollbar| scrollbar.is_mouse_over(cursor_position))
+                .unwrap_or(false)
+        };
+
+        (is_mouse_over(&self.y), is_mouse_over(&self.x))
+    }
+}
+
+/// The direction in which a [`Scrollbar`] scrolls.
+#[derive(Debug, Clone, Copy)]
+enum Orientation {
+    Vertical,
+    Horizontal,
+}
+
+/// A scrollbar of a [`Scrollable`].
 #[derive(Debug)]
 struct Scrollbar {
+    /// The direction in which the [`Scrollbar`] scrolls.
+    orientation: Orientation,
+
     /// The outer bounds of the scrollable, including the [`Scrollbar`] and
     /// [`Scroller`].
     outer_bounds: Rectangle,
@@ -728,11 +1882,58 @@ impl Scrollbar {
         self.outer_bounds.contains(cursor_position)
     }
 
+    /// Returns the bounds of the [`Scrollbar`] and its [`Scroller`] when
+    /// expanded to the given width, centered in the outer bounds.
+    fn expand(&self, width: f32) -> (Rectangle, Rectangle) {
+        match self.orientation {
+            Orientation::Vertical => {
+                let x = self.outer_bounds.center_x() - width / 2.0;
+
+                (
+                    Rectangle {
+                        x,
+                        width,
+                        ..self.bounds
+                    },
+                    Rectangle {
+                        x,
+                        width,
+                        ..self.scroller.bounds
+                    },
+                )
+            }
+            Orientation::Horizontal => {
+                let y = self.outer_bounds.center_y() - width / 2.0;
+
+                (
+                    Rectangle {
+                        y,
+                        height: width,
+                        ..self.bounds
+                    },
+                    Rectangle {
+                        y,
+                        height: width,
+                        ..self.scroller.bounds
+                    },
+                )
+            }
+        }
+    }
+
     fn grab_scroller(&self, cursor_position: Point) -> Option<f32> {
         if self.outer_bounds.contains(cursor_position) {
             Some(if self.scroller.bounds.contains(cursor_position) {
-                (cursor_position.y - self.scroller.bounds.y)
-                    / self.scroller.bounds.height
+                match self.orientation {
+                    Orientation::Vertical => {
+                        (cursor_position.y - self.scroller.bounds.y)
+                            / self.scroller.bounds.height
+                    }
+                    Orientation::Horizontal => {
+                        (cursor_position.x - self.scroller.bounds.x)
+                            / self.scroller.bounds.width
+                    }
+                }
             } else {
                 0.5
             })
@@ -746,10 +1947,20 @@ impl Scrollbar {
         grabbed_at: f32,
         cursor_position: Point,
     ) -> f32 {
-        (cursor_position.y
-            - self.bounds.y
-            - self.scroller.bounds.height * grabbed_at)
-            / (self.bounds.height - self.scroller.bounds.height)
+        match self.orientation {
+            Orientation::Vertical => {
+                (cursor_position.y
+                    - self.bounds.y
+                    - self.scroller.bounds.height * grabbed_at)
+                    / (self.bounds.height - self.scroller.bounds.height)
+            }
+            Orientation::Horizontal => {
+                (cursor_position.x
+                    - self.bounds.x
+                    - self.scroller.bounds.width * grabbed_at)
+                    / (self.bounds.width - self.scroller.bounds.width)
+            }
+        }
     }
 }
 
@@ -0,0 +1,247 @@
+//! Keep scrolling with momentum after a drag ends.
+use crate::Vector;
+
+use std::time::Instant;
+
+/// The time it takes for the velocity of a fling to decrease by a factor of
+/// `e`, in seconds.
+const DECELERATION: f32 = 0.325;
+
+/// The minimum velocity, in pixels per second, of a fling.
+const MIN_VELOCITY: f32 = 50.0;
+
+/// The time after which a drag that stopped moving does not fling anymore,
+/// in seconds.
+const MAX_IDLE_TIME: f32 = 0.1;
+
+/// The fraction of a drag applied to the content when dragging it past its
+/// edges.
+const RESISTANCE: f32 = 0.5;
+
+/// The stiffness of the spring that pulls overscrolled content back.
+const STIFFNESS: f32 = 150.0;
+
+/// The longest step of the simulation, in seconds.
+const MAX_STEP: f32 = 1.0 / 120.0;
+
+/// The kinetic motion of a scrollable.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Kinetic {
+    x: Motion,
+    y: Motion,
+    last_moved_at: Option<Instant>,
+    last_step_at: Option<Instant>,
+}
+
+/// The motion of a scrollable along one of its axes.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+struct Motion {
+    /// The velocity of the offset, in pixels per second.
+    velocity: f32,
+
+    /// The amount of offset past the edges of the content.
+    overscroll: f32,
+}
+
+impl Kinetic {
+    /// Returns whether the content is moving on its own.
+    pub fn is_flinging(&self) -> bool {
+        self.last_step_at.is_some()
+    }
+
+    /// Returns the amount of offset past the edges of the content.
+    pub fn overscroll(&self) -> Vector {
+        Vector::new(self.x.overscroll, self.y.overscroll)
+    }
+
+    /// Stops the current fling, if any, keeping the content overscrolled.
+    pub fn stop(&mut self) {
+        self.x.velocity = 0.0;
+        self.y.velocity = 0.0;
+        self.last_moved_at = None;
+        self.last_step_at = None;
+    }
+
+    /// Drags the offset by the given amount, tracking its velocity.
+    ///
+    /// The maximum offset is the amount of hidden content.
+    pub fn drag(
+        &mut self,
+        offset: &mut Vector,
+        delta: Vector,
+        max: Vector,
+        bounce: bool,
+        now: Instant,
+    ) {
+        let elapsed = self
+            .last_moved_at
+            .map(|last_moved_at| {
+                now.saturating_duration_since(last_moved_at).as_secs_f32()
+            })
+            .unwrap_or(0.0);
+
+        self.last_moved_at = Some(now);
+        self.last_step_at = None;
+
+        self.x.drag(&mut offset.x, delta.x, max.x, bounce, elapsed);
+        self.y.drag(&mut offset.y, delta.y, max.y, bounce, elapsed);
+    }
+
+    /// Releases a drag, starting a fling if the content was moving fast
+    /// enough or is overscrolled.
+    ///
+    /// It returns whether the content keeps moving.
+    pub fn release(&mut self, now: Instant) -> bool {
+        let is_idle = self
+            .last_moved_at
+            .map(|last_moved_at| {
+                now.saturating_duration_since(last_moved_at).as_secs_f32()
+                    > MAX_IDLE_TIME
+            })
+            .unwrap_or(true);
+
+        for motion in [&mut self.x, &mut self.y] {
+            if is_idle || motion.velocity.abs() < MIN_VELOCITY {
+                motion.velocity = 0.0;
+            }
+        }
+
+        self.last_moved_at = None;
+        self.last_step_at = if self.x.is_settled() && self.y.is_settled() {
+            None
+        } else {
+            Some(now)
+        };
+
+        self.is_flinging()
+    }
+
+    /// Advances the fling until the given time.
+    ///
+    /// It returns whether the content keeps moving.
+    pub fn step(
+        &mut self,
+        offset: &mut Vector,
+        max: Vector,
+        bounce: bool,
+        now: Instant,
+    ) -> bool {
+        let last_step_at = match self.last_step_at {
+            Some(last_step_at) => last_step_at,
+            None => return false,
+        };
+
+        let mut elapsed =
+            now.saturating_duration_since(last_step_at).as_secs_f32();
+
+        while elapsed > 0.0 {
+            let dt = elapsed.min(MAX_STEP);
+
+            self.x.step(&mut offset.x, max.x, bounce, dt);
+            self.y.step(&mut offset.y, max.y, bounce, dt);
+
+            elapsed -= dt;
+        }
+
+        self.last_step_at = if self.x.is_settled() && self.y.is_settled() {
+            None
+        } else {
+            Some(now)
+        };
+
+        self.is_flinging()
+    }
+}
+
+impl Motion {
+    fn is_settled(&self) -> bool {
+        self.velocity == 0.0 && self.overscroll == 0.0
+    }
+
+    fn drag(
+        &mut self,
+        offset: &mut f32,
+        delta: f32,
+        max: f32,
+        bounce: bool,
+        elapsed: f32,
+    ) {
+        // Dragging the content down moves the offset up
+        let mut delta = -delta;
+
+        if elapsed > 0.0 {
+            // The velocity is smoothed to ignore jittery movements
+            self.velocity = 0.8 * (delta / elapsed) + 0.2 * self.velocity;
+        }
+
+        if !bounce || max <= 0.0 {
+            *offset = (*offset + delta).clamp(0.0, max.max(0.0));
+            return;
+        }
+
+        if self.overscroll != 0.0 {
+            let overscroll = self.overscroll + delta * RESISTANCE;
+
+            if overscroll.signum() == self.overscroll.signum() {
+                self.overscroll = overscroll;
+                return;
+            }
+
+            // The drag moved back into the content
+            delta = overscroll / RESISTANCE;
+            self.overscroll = 0.0;
+        }
+
+        let target = *offset + delta;
+
+        if target < 0.0 {
+            *offset = 0.0;
+            self.overscroll = target * RESISTANCE;
+        } else if target > max {
+            *offset = max;
+            self.overscroll = (target - max) * RESISTANCE;
+        } else {
+            *offset = target;
+        }
+    }
+
+    fn step(&mut self, offset: &mut f32, max: f32, bounce: bool, dt: f32) {
+        if self.overscroll != 0.0 {
+            // A critically damped spring pulls the content back
+            let damping = 2.0 * STIFFNESS.sqrt();
+            let acceleration =
+                -STIFFNESS * self.overscroll - damping * self.velocity;
+
+            self.velocity += acceleration * dt;
+            self.overscroll += self.velocity * dt;
+
+            if self.overscroll.abs() < 0.5 && self.velocity.abs() < MIN_VELOCITY
+            {
+                self.overscroll = 0.0;
+                self.velocity = 0.0;
+            }
+
+            return;
+        }
+
+        let target = *offset + self.velocity * dt;
+
+        self.velocity *= (-dt / DECELERATION).exp();
+
+        if target < 0.0 || target > max.max(0.0) {
+            *offset = target.clamp(0.0, max.max(0.0));
+
+            if bounce && max > 0.0 {
+                self.overscroll = target - *offset;
+            } else {
+                self.velocity = 0.0;
+            }
+        } else {
+            *offset = target;
+        }
+
+        if self.overscroll == 0.0 && self.velocity.abs() < MIN_VELOCITY {
+            self.velocity = 0.0;
+        }
+    }
+}
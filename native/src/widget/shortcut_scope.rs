@@ -0,0 +1,174 @@
+//! Bind keyboard shortcuts to a subtree of widgets.
+use crate::event::{self, Event};
+use crate::keyboard::{self, Shortcuts};
+use crate::layout;
+use crate::mouse;
+use crate::overlay;
+use crate::renderer;
+use crate::widget::operation::{self, Operation};
+use crate::{
+    Clipboard, Element, Hasher, Layout, Length, Point, Rectangle, Shell, Widget,
+};
+
+use std::hash::Hash;
+
+/// A widget that triggers some [`Shortcuts`] while a widget inside of its
+/// content is focused.
+///
+/// Shortcuts of nested scopes take precedence over the ones of their
+/// parents, and the shortcuts of an application are only triggered when no
+/// scope captures the key press.
+#[allow(missing_debug_implementations)]
+pub struct ShortcutScope<'a, Message, Renderer> {
+    content: Element<'a, Message, Renderer>,
+    shortcuts: Shortcuts<Message>,
+    is_global: bool,
+}
+
+impl<'a, Message, Renderer> ShortcutScope<'a, Message, Renderer> {
+    /// Creates a new [`ShortcutScope`] with the given content and
+    /// [`Shortcuts`].
+    pub fn new(
+        content: impl Into<Element<'a, Message, Renderer>>,
+        shortcuts: Shortcuts<Message>,
+    ) -> Self {
+        ShortcutScope {
+            content: content.into(),
+            shortcuts,
+            is_global: false,
+        }
+    }
+
+    /// Sets whether the [`Shortcuts`] of the [`ShortcutScope`] are triggered
+    /// even when no widget inside of its content is focused.
+    pub fn global(mut self, is_global: bool) -> Self {
+        self.is_global = is_global;
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for ShortcutScope<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+{
+    fn width(&self) -> Length {
+        self.content.width()
+    }
+
+    fn height(&self) -> Length {
+        self.content.height()
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content.layout(renderer, limits)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let status = self.content.on_event(
+            event.clone(),
+            layout,
+            cursor_position,
+            renderer,
+            clipboard,
+            shell,
+        );
+
+        if status == event::Status::Captured {
+            return status;
+        }
+
+        if let Event::Keyboard(keyboard::Event::KeyPressed {
+            key_code,
+            modifiers,
+        }) = event
+        {
+            if let Some(message) = self.shortcuts.find(key_code, modifiers) {
+                if self.is_global
+                    || operation::focusable::has_focus(
+                        &mut self.content,
+                        layout,
+                    )
+                {
+                    shell.publish(message);
+
+                    return event::Status::Captured;
+                }
+            }
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) -> mouse::Interaction {
+        self.content
+            .mouse_interaction(layout, cursor_position, viewport)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        self.content
+            .draw(renderer, style, layout, cursor_position, viewport);
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.content.hash_layout(state);
+    }
+
+    fn operate(
+        &mut self,
+        layout: Layout<'_>,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        operation.container(None, &mut |operation| {
+            self.content.operate(layout, operation);
+        });
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+        viewport: &Rectangle,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        self.content.overlay(layout, viewport)
+    }
+}
+
+impl<'a, Message, Renderer> From<ShortcutScope<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + crate::Renderer,
+{
+    fn from(
+        scope: ShortcutScope<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(scope)
+    }
+}
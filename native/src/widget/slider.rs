@@ -1,11 +1,15 @@
 //! Display an interactive selector of a single value from a range of values.
 //!
 //! A [`Slider`] has some local [`State`].
+use crate::accessibility;
 use crate::event::{self, Event};
+use crate::keyboard;
 use crate::layout;
 use crate::mouse;
 use crate::renderer;
+use crate::theme;
 use crate::touch;
+use crate::widget::Operation;
 use crate::{
     Background, Clipboard, Color, Element, Hasher, Layout, Length, Point,
     Rectangle, Shell, Size, Widget,
@@ -48,9 +52,11 @@ pub struct Slider<'a, T, Message> {
     value: T,
     on_change: Box<dyn Fn(T) -> Message>,
     on_release: Option<Message>,
+    drag_value: bool,
     width: Length,
     height: u16,
-    style_sheet: Box<dyn StyleSheet + 'a>,
+    style_sheet: Option<Box<dyn StyleSheet + 'a>>,
+    class: Option<String>,
 }
 
 impl<'a, T, Message> Slider<'a, T, Message>
@@ -98,9 +104,11 @@ where
             step: T::from(1),
             on_change: Box::new(on_change),
             on_release: None,
+            drag_value: false,
             width: Length::Fill,
             height: Self::DEFAULT_HEIGHT,
-            style_sheet: Default::default(),
+            style_sheet: None,
+            class: None,
         }
     }
 
@@ -115,6 +123,20 @@ where
         self
     }
 
+    /// Makes the [`Slider`] scrub its value instead of following the cursor.
+    ///
+    /// When enabled, clicking the [`Slider`] does not change its value.
+    /// Instead, dragging horizontally adjusts the value by one step for every
+    /// unit of movement, relative to the current value. The pointer is locked
+    /// during the drag, so the cursor can leave the bounds of the [`Slider`].
+    ///
+    /// Holding `Shift` makes the adjustment ten times finer, while holding
+    /// the command key (`Ctrl` on most platforms) makes it ten times coarser.
+    pub fn drag_value(mut self) -> Self {
+        self.drag_value = true;
+        self
+    }
+
     /// Sets the width of the [`Slider`].
     pub fn width(mut self, width: Length) -> Self {
         self.width = width;
@@ -132,7 +154,18 @@ where
         mut self,
         style_sheet: impl Into<Box<dyn StyleSheet + 'a>>,
     ) -> Self {
-        self.style_sheet = style_sheet.into();
+        self.style_sheet = Some(style_sheet.into());
+        self
+    }
+
+    /// Sets the style class of the [`Slider`].
+    ///
+    /// The class is resolved through the current [`Theme`] when the
+    /// [`Slider`] does not have a custom style sheet.
+    ///
+    /// [`Theme`]: crate::Theme
+    pub fn class(mut self, class: impl Into<String>) -> Self {
+        self.class = Some(class.into());
         self
     }
 
@@ -143,10 +176,128 @@ where
     }
 }
 
+impl<'a, T, Message> Slider<'a, T, Message>
+where
+    T: Copy + Into<f64> + num_traits::FromPrimitive,
+    Message: Clone,
+{
+    /// Processes an [`Event`] when the [`Slider`] scrubs its value.
+    fn scrub(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let bounds = layout.bounds();
+
+        let status = match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+                if bounds.contains(cursor_position) =>
+            {
+                self.state.is_dragging = true;
+                self.state.scrubbed_steps = 0.0;
+                self.state.last_finger_position = None;
+
+                event::Status::Captured
+            }
+            Event::Touch(touch::Event::FingerPressed { position, .. })
+                if bounds.contains(position) =>
+            {
+                self.state.is_dragging = true;
+                self.state.scrubbed_steps = 0.0;
+                self.state.last_finger_position = Some(position);
+
+                event::Status::Captured
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+            | Event::Touch(touch::Event::FingerLost { .. })
+                if self.state.is_dragging =>
+            {
+                if let Some(on_release) = self.on_release.clone() {
+                    shell.publish(on_release);
+                }
+
+                self.state.is_dragging = false;
+                self.state.last_finger_position = None;
+
+                event::Status::Captured
+            }
+            Event::Mouse(mouse::Event::Motion { delta })
+                if self.state.is_dragging =>
+            {
+                self.scrub_by(delta.x, shell);
+
+                event::Status::Captured
+            }
+            Event::Touch(touch::Event::FingerMoved { position, .. })
+                if self.state.is_dragging =>
+            {
+                if let Some(last_position) = self.state.last_finger_position {
+                    self.scrub_by(position.x - last_position.x, shell);
+                }
+
+                self.state.last_finger_position = Some(position);
+
+                event::Status::Captured
+            }
+            _ => event::Status::Ignored,
+        };
+
+        // Fingers cannot be locked, but the mouse stays locked while dragging
+        if self.state.is_dragging && self.state.last_finger_position.is_none() {
+            shell.request_pointer_lock();
+        }
+
+        status
+    }
+
+    /// Adjusts the value of the [`Slider`] by the given horizontal movement.
+    fn scrub_by(&mut self, movement: f32, shell: &mut Shell<'_, Message>) {
+        let modifiers = self.state.keyboard_modifiers;
+
+        let factor = if modifiers.shift() {
+            0.1
+        } else if modifiers.command() {
+            10.0
+        } else {
+            1.0
+        };
+
+        self.state.scrubbed_steps += f64::from(movement) * factor;
+
+        // Only whole steps are applied, the rest is kept for later
+        let steps = self.state.scrubbed_steps.trunc();
+        self.state.scrubbed_steps -= steps;
+
+        if steps == 0.0 {
+            return;
+        }
+
+        let step = self.step.into();
+        let start = (*self.range.start()).into();
+        let end = (*self.range.end()).into();
+
+        let value = (self.value.into() + steps * step).clamp(start, end);
+
+        if let Some(new_value) = T::from_f64(value) {
+            if (self.value.into() - new_value.into()).abs() > f64::EPSILON {
+                shell.publish((self.on_change)(new_value));
+
+                self.value = new_value;
+            }
+        }
+    }
+}
+
 /// The local state of a [`Slider`].
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
 pub struct State {
     is_dragging: bool,
+    keyboard_modifiers: keyboard::Modifiers,
+    scrubbed_steps: f64,
+    last_finger_position: Option<Point>,
 }
 
 impl State {
@@ -193,6 +344,42 @@ where
         _clipboard: &mut dyn Clipboard,
         shell: &mut Shell<'_, Message>,
     ) -> event::Status {
+        if let Event::Accessibility(accessibility::Request {
+            target,
+            action: accessibility::Action::SetNumericValue(value),
+        }) = event
+        {
+            if target == layout.bounds() {
+                let step = self.step.into();
+                let start = (*self.range.start()).into();
+                let end = (*self.range.end()).into();
+
+                let steps = ((value.clamp(start, end) - start) / step).round();
+
+                if let Some(new_value) = T::from_f64(steps * step + start) {
+                    shell.publish((self.on_change)(new_value));
+
+                    if let Some(on_release) = self.on_release.clone() {
+                        shell.publish(on_release);
+                    }
+
+                    self.value = new_value;
+                }
+
+                return event::Status::Captured;
+            }
+        }
+
+        if let Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) =
+            event
+        {
+            self.state.keyboard_modifiers = modifiers;
+        }
+
+        if self.drag_value {
+            return self.scrub(event, layout, cursor_position, shell);
+        }
+
         let is_dragging = self.state.is_dragging;
 
         let mut change = || {
@@ -265,20 +452,25 @@ where
     fn draw(
         &self,
         renderer: &mut Renderer,
-        _style: &renderer::Style,
+        style: &renderer::Style,
         layout: Layout<'_>,
         cursor_position: Point,
         _viewport: &Rectangle,
     ) {
+        let theme = style
+            .theme
+            .resolve(theme::Widget::Slider, self.class.as_deref());
+        let style_sheet = self.style_sheet.as_deref().unwrap_or(&theme);
+
         let bounds = layout.bounds();
         let is_mouse_over = bounds.contains(cursor_position);
 
         let style = if self.state.is_dragging {
-            self.style_sheet.dragging()
+            style_sheet.dragging()
         } else if is_mouse_over {
-            self.style_sheet.hovered()
+            style_sheet.hovered()
         } else {
-            self.style_sheet.active()
+            style_sheet.active()
         };
 
         let rail_y = bounds.y + (bounds.height / 2.0).round();
@@ -367,6 +559,8 @@ where
 
         if self.state.is_dragging {
             mouse::Interaction::Grabbing
+        } else if is_mouse_over && self.drag_value {
+            mouse::Interaction::ResizingHorizontally
         } else if is_mouse_over {
             mouse::Interaction::Grab
         } else {
@@ -380,6 +574,26 @@ where
 
         self.width.hash(state);
     }
+
+    fn operate(
+        &mut self,
+        layout: Layout<'_>,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        operation.accessible(
+            accessibility::Node::new(
+                accessibility::Role::Slider,
+                layout.bounds(),
+            )
+            .value(accessibility::Value::Number {
+                value: self.value.into(),
+                min: (*self.range.start()).into(),
+                max: (*self.range.end()).into(),
+            })
+            .editable(true),
+            None,
+        );
+    }
 }
 
 impl<'a, T, Message, Renderer> From<Slider<'a, T, Message>>
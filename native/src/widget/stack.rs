@@ -0,0 +1,319 @@
+//! Display content on top of other content.
+use std::hash::Hash;
+
+use crate::alignment::{self, Alignment};
+use crate::event::{self, Event};
+use crate::layout;
+use crate::mouse;
+use crate::overlay;
+use crate::renderer;
+use crate::widget::Operation;
+use crate::{
+    Clipboard, Element, Hasher, Layout, Length, Point, Rectangle, Shell, Size,
+    Vector, Widget,
+};
+
+/// A container that displays its contents on top of each other, in the same
+/// bounds.
+///
+/// Items with a higher z-index are drawn on top of items with a lower one,
+/// and they receive events first. An event captured by an item is not
+/// propagated to the items below it. Items with the same z-index are stacked
+/// in the order they were pushed.
+#[allow(missing_debug_implementations)]
+pub struct Stack<'a, Message, Renderer> {
+    width: Length,
+    height: Length,
+    items: Vec<Item<'a, Message, Renderer>>,
+}
+
+impl<'a, Message, Renderer> Stack<'a, Message, Renderer> {
+    /// Creates an empty [`Stack`].
+    pub fn new() -> Self {
+        Stack {
+            width: Length::Shrink,
+            height: Length::Shrink,
+            items: Vec::new(),
+        }
+    }
+
+    /// Sets the width of the [`Stack`].
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`Stack`].
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Adds an element on top of the [`Stack`], aligned to its top-left
+    /// corner.
+    pub fn push<E>(self, content: E) -> Self
+    where
+        E: Into<Element<'a, Message, Renderer>>,
+    {
+        self.push_item(Item::new(content))
+    }
+
+    /// Adds an [`Item`] to the [`Stack`].
+    pub fn push_item(mut self, item: Item<'a, Message, Renderer>) -> Self {
+        self.items.push(item);
+        self
+    }
+
+    /// Returns the indices of the items of the [`Stack`], from bottom to top.
+    fn order(&self) -> Vec<usize> {
+        let mut order: Vec<usize> = (0..self.items.len()).collect();
+        order.sort_by_key(|i| self.items[*i].z_index);
+
+        order
+    }
+}
+
+impl<'a, Message, Renderer> Default for Stack<'a, Message, Renderer> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// An element of a [`Stack`].
+#[allow(missing_debug_implementations)]
+pub struct Item<'a, Message, Renderer> {
+    content: Element<'a, Message, Renderer>,
+    z_index: i32,
+    horizontal_alignment: alignment::Horizontal,
+    vertical_alignment: alignment::Vertical,
+    offset: Vector,
+}
+
+impl<'a, Message, Renderer> Item<'a, Message, Renderer> {
+    /// Creates a new [`Item`] with the given content.
+    pub fn new(content: impl Into<Element<'a, Message, Renderer>>) -> Self {
+        Item {
+            content: content.into(),
+            z_index: 0,
+            horizontal_alignment: alignment::Horizontal::Left,
+            vertical_alignment: alignment::Vertical::Top,
+            offset: Vector::new(0.0, 0.0),
+        }
+    }
+
+    /// Sets the z-index of the [`Item`].
+    pub fn z_index(mut self, z_index: i32) -> Self {
+        self.z_index = z_index;
+        self
+    }
+
+    /// Sets the horizontal alignment of the [`Item`] in the [`Stack`].
+    pub fn align_x(mut self, alignment: alignment::Horizontal) -> Self {
+        self.horizontal_alignment = alignment;
+        self
+    }
+
+    /// Sets the vertical alignment of the [`Item`] in the [`Stack`].
+    pub fn align_y(mut self, alignment: alignment::Vertical) -> Self {
+        self.vertical_alignment = alignment;
+        self
+    }
+
+    /// Sets the offset of the [`Item`] from its aligned position.
+    pub fn offset(mut self, offset: Vector) -> Self {
+        self.offset = offset;
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Stack<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        let mut children: Vec<layout::Node> = self
+            .items
+            .iter()
+            .map(|item| item.content.layout(renderer, &limits.loose()))
+            .collect();
+
+        let content_size = children.iter().fold(Size::ZERO, |size, child| {
+            Size::new(
+                size.width.max(child.size().width),
+                size.height.max(child.size().height),
+            )
+        });
+
+        let size = limits.resolve(content_size);
+
+        for (child, item) in children.iter_mut().zip(&self.items) {
+            child.move_to(Point::new(item.offset.x, item.offset.y));
+            child.align(
+                Alignment::from(item.horizontal_alignment),
+                Alignment::from(item.vertical_alignment),
+                size,
+            );
+        }
+
+        layout::Node::with_children(size, children)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let layouts: Vec<Layout<'_>> = layout.children().collect();
+
+        for i in self.order().into_iter().rev() {
+            let status = self.items[i].content.on_event(
+                event.clone(),
+                layouts[i],
+                cursor_position,
+                renderer,
+                clipboard,
+                shell,
+            );
+
+            if let event::Status::Captured = status {
+                return event::Status::Captured;
+            }
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) -> mouse::Interaction {
+        let layouts: Vec<Layout<'_>> = layout.children().collect();
+
+        self.order()
+            .into_iter()
+            .rev()
+            .map(|i| {
+                self.items[i].content.mouse_interaction(
+                    layouts[i],
+                    cursor_position,
+                    viewport,
+                )
+            })
+            .find(|interaction| *interaction != mouse::Interaction::Idle)
+            .unwrap_or_default()
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        let layouts: Vec<Layout<'_>> = layout.children().collect();
+
+        for (n, i) in self.order().into_iter().enumerate() {
+            let draw = |renderer: &mut Renderer| {
+                self.items[i].content.draw(
+                    renderer,
+                    style,
+                    layouts[i],
+                    cursor_position,
+                    viewport,
+                );
+            };
+
+            // Every item but the bottom one is drawn in a new layer, so it
+            // is always displayed on top of the previous ones.
+            if n == 0 {
+                draw(renderer);
+            } else {
+                renderer.with_layer(*viewport, draw);
+            }
+        }
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.width.hash(state);
+        self.height.hash(state);
+
+        for item in &self.items {
+            item.horizontal_alignment.hash(state);
+            item.vertical_alignment.hash(state);
+            item.offset.x.to_bits().hash(state);
+            item.offset.y.to_bits().hash(state);
+            item.content.hash_layout(state);
+        }
+    }
+
+    fn operate(
+        &mut self,
+        layout: Layout<'_>,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        operation.container(None, &mut |operation| {
+            self.items.iter_mut().zip(layout.children()).for_each(
+                |(item, layout)| {
+                    item.content.operate(layout, operation);
+                },
+            )
+        });
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+        viewport: &Rectangle,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        let order = self.order();
+        let mut layouts: Vec<Option<Layout<'_>>> =
+            layout.children().map(Some).collect();
+        let mut items: Vec<Option<&mut Item<'a, Message, Renderer>>> =
+            self.items.iter_mut().map(Some).collect();
+
+        overlay::from_children(order.into_iter().filter_map(|i| {
+            let item = items[i].take()?;
+            let layout = layouts[i].take()?;
+
+            item.content.overlay(layout, viewport)
+        }))
+    }
+}
+
+impl<'a, Message, Renderer> From<Stack<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + crate::Renderer,
+    Message: 'a,
+{
+    fn from(
+        stack: Stack<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(stack)
+    }
+}
@@ -2,7 +2,9 @@
 use crate::layout;
 use crate::renderer;
 use crate::svg::{self, Handle};
-use crate::{Element, Hasher, Layout, Length, Point, Rectangle, Size, Widget};
+use crate::{
+    Color, Element, Hasher, Layout, Length, Point, Rectangle, Size, Widget,
+};
 
 use std::hash::Hash;
 use std::path::PathBuf;
@@ -13,11 +15,16 @@ use std::path::PathBuf;
 ///
 /// [`Svg`] images can have a considerable rendering cost when resized,
 /// specially when they are complex.
+///
+/// Any `currentColor` reference in the SVG follows the text color of the
+/// current style, unless an explicit [`Svg::color`] is set. This allows
+/// monochrome icons to adapt to their surroundings.
 #[derive(Debug, Clone)]
 pub struct Svg {
     handle: Handle,
     width: Length,
     height: Length,
+    color: Option<Color>,
 }
 
 impl Svg {
@@ -27,6 +34,7 @@ impl Svg {
             handle: handle.into(),
             width: Length::Fill,
             height: Length::Shrink,
+            color: None,
         }
     }
 
@@ -47,6 +55,13 @@ impl Svg {
         self.height = height;
         self
     }
+
+    /// Sets the [`Color`] that `currentColor` references will resolve to,
+    /// overriding the text color of the current style.
+    pub fn color(mut self, color: impl Into<Color>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
 }
 
 impl<Message, Renderer> Widget<Message, Renderer> for Svg
@@ -89,12 +104,16 @@ where
     fn draw(
         &self,
         renderer: &mut Renderer,
-        _style: &renderer::Style,
+        style: &renderer::Style,
         layout: Layout<'_>,
         _cursor_position: Point,
         _viewport: &Rectangle,
     ) {
-        renderer.draw(self.handle.clone(), layout.bounds())
+        renderer.draw(
+            self.handle.clone(),
+            Some(self.color.unwrap_or(style.text_color)),
+            layout.bounds(),
+        )
     }
 
     fn hash_layout(&self, state: &mut Hasher) {
@@ -1,8 +1,10 @@
 //! Write some text for your users to read.
+use crate::accessibility;
 use crate::alignment;
 use crate::layout;
 use crate::renderer;
 use crate::text;
+use crate::widget::Operation;
 use crate::{
     Color, Element, Hasher, Layout, Length, Point, Rectangle, Size, Widget,
 };
@@ -119,16 +121,28 @@ where
     ) -> layout::Node {
         let limits = limits.width(self.width).height(self.height);
 
-        let size = self.size.unwrap_or(renderer.default_size());
+        let text_size = self.size.unwrap_or(renderer.default_size());
 
         let bounds = limits.max();
 
-        let (width, height) =
-            renderer.measure(&self.content, size, self.font.clone(), bounds);
+        let (width, height) = renderer.measure(
+            &self.content,
+            text_size,
+            self.font.clone(),
+            bounds,
+        );
 
         let size = limits.resolve(Size::new(width, height));
 
-        layout::Node::new(size)
+        let offset = match self.vertical_alignment {
+            alignment::Vertical::Top => 0.0,
+            alignment::Vertical::Center => (size.height - height) / 2.0,
+            alignment::Vertical::Bottom => size.height - height,
+        };
+
+        layout::Node::new(size).with_baseline(
+            offset + renderer.baseline(text_size, self.font.clone()),
+        )
     }
 
     fn draw(
@@ -161,6 +175,21 @@ where
         self.width.hash(state);
         self.height.hash(state);
     }
+
+    fn operate(
+        &mut self,
+        layout: Layout<'_>,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        operation.accessible(
+            accessibility::Node::new(
+                accessibility::Role::Label,
+                layout.bounds(),
+            )
+            .label(&self.content),
+            None,
+        );
+    }
 }
 
 /// Draws text using the same logic as the [`Text`] widget.
@@ -173,6 +202,8 @@ where
 ///   used.
 /// * The alignment attributes do not affect the position of the bounds of the
 ///   [`Layout`].
+/// * The horizontal alignment is mirrored if the [`Layout`] flows from right to
+///   left.
 pub fn draw<Renderer>(
     renderer: &mut Renderer,
     style: &renderer::Style,
@@ -187,6 +218,7 @@ pub fn draw<Renderer>(
     Renderer: text::Renderer,
 {
     let bounds = layout.bounds();
+    let horizontal_alignment = layout.direction().align_x(horizontal_alignment);
 
     let x = match horizontal_alignment {
         alignment::Horizontal::Left => bounds.x,
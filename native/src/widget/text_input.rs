@@ -2,28 +2,41 @@
 //!
 //! A [`TextInput`] has some local [`State`].
 mod editor;
+mod history;
 mod value;
 
 pub mod cursor;
+pub mod mask;
 
-pub use cursor::Cursor;
+pub use cursor::{Cursor, Selection};
+pub use mask::Mask;
 pub use value::Value;
 
 use editor::Editor;
+use history::History;
 
+use crate::accessibility;
 use crate::alignment;
+use crate::animation::{Interpolate, Tracker, Transition};
 use crate::event::{self, Event};
+use crate::input_method;
 use crate::keyboard;
 use crate::layout;
 use crate::mouse::{self, click};
 use crate::renderer;
 use crate::text::{self, Text};
+use crate::theme;
 use crate::touch;
+use crate::widget::inspector;
+use crate::widget::operation::{self, Operation};
+use crate::widget::Id;
+use crate::window;
 use crate::{
-    Clipboard, Color, Element, Hasher, Layout, Length, Padding, Point,
+    Clipboard, Color, Command, Element, Hasher, Layout, Length, Padding, Point,
     Rectangle, Shell, Size, Vector, Widget,
 };
 
+use std::time::{Duration, Instant};
 use std::u32;
 
 pub use iced_style::text_input::{Style, StyleSheet};
@@ -56,9 +69,12 @@ pub use iced_style::text_input::{Style, StyleSheet};
 #[allow(missing_debug_implementations)]
 pub struct TextInput<'a, Message, Renderer: text::Renderer> {
     state: &'a mut State,
+    id: Option<Id>,
+    tab_index: i32,
     placeholder: String,
     value: Value,
     is_secure: bool,
+    copy_on_select: bool,
     font: Renderer::Font,
     width: Length,
     max_width: u32,
@@ -66,9 +82,57 @@ pub struct TextInput<'a, Message, Renderer: text::Renderer> {
     size: Option<u16>,
     on_change: Box<dyn Fn(String) -> Message>,
     on_submit: Option<Message>,
-    style_sheet: Box<dyn StyleSheet + 'a>,
+    on_select: Option<Box<dyn Fn(Selection) -> Message>>,
+    mask: Option<Mask>,
+    validate: Option<Box<dyn Fn(String) -> Validation>>,
+    icon: Option<Icon<Renderer::Font>>,
+    on_clear: Option<Message>,
+    reveal: Option<Icon<Renderer::Font>>,
+    style_sheet: Option<Box<dyn StyleSheet + 'a>>,
+    class: Option<String>,
+    transition: Option<Transition>,
 }
 
+/// The content of an icon drawn inside the bounds of a [`TextInput`].
+#[derive(Debug, Clone)]
+pub struct Icon<Font> {
+    /// The font that will be used to display the `code_point`.
+    pub font: Font,
+    /// The unicode code point that will be used as the icon.
+    pub code_point: char,
+    /// The font size of the icon, which defaults to the text size of the
+    /// [`TextInput`].
+    pub size: Option<u16>,
+    /// The spacing between the icon and the text of the [`TextInput`].
+    pub spacing: u16,
+    /// The side of the [`TextInput`] where the icon is placed.
+    pub side: Side,
+}
+
+/// The side of a [`TextInput`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Side {
+    /// The left side of a [`TextInput`].
+    Left,
+    /// The right side of a [`TextInput`].
+    Right,
+}
+
+/// A slot laid out next to the text of a [`TextInput`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Slot {
+    Icon,
+    Clear,
+    Reveal,
+}
+
+/// The spacing between the text of a [`TextInput`] and its built-in buttons.
+const BUTTON_SPACING: u16 = 5;
+
+/// The time the cursor of a focused [`TextInput`] stays visible, and then
+/// hidden, while it blinks.
+const CURSOR_BLINK_INTERVAL_MILLIS: u128 = 500;
+
 impl<'a, Message, Renderer> TextInput<'a, Message, Renderer>
 where
     Message: Clone,
@@ -92,9 +156,12 @@ where
     {
         TextInput {
             state,
+            id: None,
+            tab_index: 0,
             placeholder: String::from(placeholder),
             value: Value::new(value),
             is_secure: false,
+            copy_on_select: false,
             font: Default::default(),
             width: Length::Fill,
             max_width: u32::MAX,
@@ -102,16 +169,48 @@ where
             size: None,
             on_change: Box::new(on_change),
             on_submit: None,
-            style_sheet: Default::default(),
+            on_select: None,
+            mask: None,
+            validate: None,
+            icon: None,
+            on_clear: None,
+            reveal: None,
+            style_sheet: None,
+            class: None,
+            transition: None,
         }
     }
 
+    /// Sets the [`Id`] of the [`TextInput`].
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Sets the position of the [`TextInput`] in the focus traversal order.
+    ///
+    /// See [`operation::Focusable::tab_index`].
+    pub fn tab_index(mut self, tab_index: i32) -> Self {
+        self.tab_index = tab_index;
+        self
+    }
+
     /// Converts the [`TextInput`] into a secure password input.
     pub fn password(mut self) -> Self {
         self.is_secure = true;
         self
     }
 
+    /// Makes the [`TextInput`] copy any text selected to the primary
+    /// selection, and paste the primary selection when clicked with the
+    /// middle mouse button.
+    ///
+    /// A secure [`TextInput`] never copies its text.
+    pub fn copy_on_select(mut self) -> Self {
+        self.copy_on_select = true;
+        self
+    }
+
     /// Sets the [`Font`] of the [`Text`].
     ///
     /// [`Font`]: crate::widget::text::Renderer::Font
@@ -146,17 +245,104 @@ where
 
     /// Sets the message that should be produced when the [`TextInput`] is
     /// focused and the enter key is pressed.
+    ///
+    /// Without it, the enter key is left to the parents of the
+    /// [`TextInput`]; like a [`Form`], which submits all of its fields.
+    ///
+    /// [`Form`]: crate::widget::Form
     pub fn on_submit(mut self, message: Message) -> Self {
         self.on_submit = Some(message);
         self
     }
 
+    /// Sets the message that should be produced when the [`Selection`] of
+    /// the [`TextInput`] changes.
+    ///
+    /// This includes moving the caret without selecting anything.
+    pub fn on_select<F>(mut self, on_select: F) -> Self
+    where
+        F: 'static + Fn(Selection) -> Message,
+    {
+        self.on_select = Some(Box::new(on_select));
+        self
+    }
+
+    /// Sets the [`Mask`] of the [`TextInput`].
+    ///
+    /// Every edit is formatted according to the [`Mask`] before it is
+    /// published.
+    pub fn mask(mut self, mask: Mask) -> Self {
+        self.mask = Some(mask);
+        self
+    }
+
+    /// Sets the function used to validate the edits of the [`TextInput`].
+    ///
+    /// The function receives the value that an edit would produce, after
+    /// applying any [`Mask`], and decides what to do with it by returning a
+    /// [`Validation`].
+    pub fn validate<F>(mut self, validate: F) -> Self
+    where
+        F: 'static + Fn(String) -> Validation,
+    {
+        self.validate = Some(Box::new(validate));
+        self
+    }
+
+    /// Sets the [`Icon`] of the [`TextInput`].
+    pub fn icon(mut self, icon: Icon<Renderer::Font>) -> Self {
+        self.icon = Some(icon);
+        self
+    }
+
+    /// Sets the message that should be produced when the clear button of the
+    /// [`TextInput`] is pressed.
+    ///
+    /// The clear button is only displayed when the [`TextInput`] is not
+    /// empty.
+    pub fn on_clear(mut self, message: Message) -> Self {
+        self.on_clear = Some(message);
+        self
+    }
+
+    /// Adds a button that reveals the value of a [`password`] input while
+    /// it is toggled, displaying the given [`Icon`].
+    ///
+    /// The [`Side`] of the [`Icon`] is ignored, the button is always placed on
+    /// the right.
+    ///
+    /// [`password`]: Self::password
+    pub fn revealable(mut self, icon: Icon<Renderer::Font>) -> Self {
+        self.reveal = Some(icon);
+        self
+    }
+
     /// Sets the style of the [`TextInput`].
     pub fn style(
         mut self,
         style_sheet: impl Into<Box<dyn StyleSheet + 'a>>,
     ) -> Self {
-        self.style_sheet = style_sheet.into();
+        self.style_sheet = Some(style_sheet.into());
+        self
+    }
+
+    /// Sets the style class of the [`TextInput`].
+    ///
+    /// The class is resolved through the current [`Theme`] when the
+    /// [`TextInput`] does not have a custom style sheet.
+    ///
+    /// [`Theme`]: crate::Theme
+    pub fn class(mut self, class: impl Into<String>) -> Self {
+        self.class = Some(class.into());
+        self
+    }
+
+    /// Sets the [`Transition`] used to animate the changes of the appearance
+    /// of the [`TextInput`] when it is hovered, focused, or marked as invalid.
+    ///
+    /// By default, the appearance of a [`TextInput`] changes instantly.
+    pub fn transition(mut self, transition: Transition) -> Self {
+        self.transition = Some(transition);
         self
     }
 
@@ -164,220 +350,106 @@ where
     pub fn state(&self) -> &State {
         self.state
     }
-}
-
-impl<'a, Message, Renderer> TextInput<'a, Message, Renderer>
-where
-    Renderer: text::Renderer,
-{
-    /// Draws the [`TextInput`] with the given [`Renderer`], overriding its
-    /// [`Value`] if provided.
-    pub fn draw(
-        &self,
-        renderer: &mut Renderer,
-        layout: Layout<'_>,
-        cursor_position: Point,
-        value: Option<&Value>,
-    ) {
-        let value = value.unwrap_or(&self.value);
-        let secure_value = self.is_secure.then(|| value.secure());
-        let value = secure_value.as_ref().unwrap_or(&value);
-
-        let bounds = layout.bounds();
-        let text_bounds = layout.children().next().unwrap().bounds();
-
-        let is_mouse_over = bounds.contains(cursor_position);
-
-        let style = if self.state.is_focused() {
-            self.style_sheet.focused()
-        } else if is_mouse_over {
-            self.style_sheet.hovered()
-        } else {
-            self.style_sheet.active()
-        };
 
-        renderer.fill_quad(
-            renderer::Quad {
-                bounds,
-                border_radius: style.border_radius,
-                border_width: style.border_width,
-                border_color: style.border_color,
-            },
-            style.background,
-        );
+    /// Replaces the current [`Value`] of the [`TextInput`].
+    ///
+    /// This is useful for composite widgets that own the contents of a
+    /// [`TextInput`] and need it to reflect changes immediately.
+    pub(crate) fn set_value(&mut self, value: &str) {
+        self.value = Value::new(value);
+    }
 
-        let text = value.to_string();
-        let size = self.size.unwrap_or(renderer.default_size());
+    fn entry(&self) -> history::Entry {
+        history::Entry {
+            value: self.value.clone(),
+            cursor: self.state.cursor,
+        }
+    }
 
-        let (cursor, offset) = if self.state.is_focused() {
-            match self.state.cursor.state(&value) {
-                cursor::State::Index(position) => {
-                    let (text_value_width, offset) =
-                        measure_cursor_and_scroll_offset(
-                            renderer,
-                            text_bounds,
-                            &value,
-                            size,
-                            position,
-                            self.font.clone(),
-                        );
+    /// Applies the [`Mask`] and the validation of the [`TextInput`] to the
+    /// edit that turned `before` into the current value, publishing the
+    /// result unless it is rejected.
+    fn commit(
+        &mut self,
+        kind: history::Kind,
+        before: history::Entry,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        if let Some(mask) = &self.mask {
+            let cursor = self
+                .value
+                .until(self.state.cursor.end(&self.value))
+                .to_string()
+                .chars()
+                .count();
+
+            let (masked, position) =
+                mask.format(&self.value.to_string(), cursor);
+
+            self.value = Value::new(&masked);
+            self.state.cursor.move_to(position);
+        }
 
-                    (
-                        Some((
-                            renderer::Quad {
-                                bounds: Rectangle {
-                                    x: text_bounds.x + text_value_width,
-                                    y: text_bounds.y,
-                                    width: 1.0,
-                                    height: text_bounds.height,
-                                },
-                                border_radius: 0.0,
-                                border_width: 0.0,
-                                border_color: Color::TRANSPARENT,
-                            },
-                            self.style_sheet.value_color(),
-                        )),
-                        offset,
-                    )
+        if let Some(validate) = &self.validate {
+            match validate(self.value.to_string()) {
+                Validation::Valid => {
+                    self.state.is_invalid = false;
                 }
-                cursor::State::Selection { start, end } => {
-                    let left = start.min(end);
-                    let right = end.max(start);
-
-                    let (left_position, left_offset) =
-                        measure_cursor_and_scroll_offset(
-                            renderer,
-                            text_bounds,
-                            &value,
-                            size,
-                            left,
-                            self.font.clone(),
-                        );
-
-                    let (right_position, right_offset) =
-                        measure_cursor_and_scroll_offset(
-                            renderer,
-                            text_bounds,
-                            &value,
-                            size,
-                            right,
-                            self.font.clone(),
-                        );
-
-                    let width = right_position - left_position;
+                Validation::Invalid => {
+                    self.state.is_invalid = true;
+                }
+                Validation::Transform(value) => {
+                    self.value = Value::new(&value);
+                    self.state.is_invalid = false;
+                }
+                Validation::Reject => {
+                    self.value = before.value;
+                    self.state.cursor = before.cursor;
 
-                    (
-                        Some((
-                            renderer::Quad {
-                                bounds: Rectangle {
-                                    x: text_bounds.x + left_position,
-                                    y: text_bounds.y,
-                                    width,
-                                    height: text_bounds.height,
-                                },
-                                border_radius: 0.0,
-                                border_width: 0.0,
-                                border_color: Color::TRANSPARENT,
-                            },
-                            self.style_sheet.selection_color(),
-                        )),
-                        if end == right {
-                            right_offset
-                        } else {
-                            left_offset
-                        },
-                    )
+                    return;
                 }
             }
-        } else {
-            (None, 0.0)
-        };
-
-        let text_width = renderer.measure_width(
-            if text.is_empty() {
-                &self.placeholder
-            } else {
-                &text
-            },
-            size,
-            self.font.clone(),
-        );
+        }
 
-        let render = |renderer: &mut Renderer| {
-            if let Some((cursor, color)) = cursor {
-                renderer.fill_quad(cursor, color);
-            }
+        if self.value == before.value {
+            return;
+        }
 
-            renderer.fill_text(Text {
-                content: if text.is_empty() {
-                    &self.placeholder
-                } else {
-                    &text
-                },
-                color: if text.is_empty() {
-                    self.style_sheet.placeholder_color()
-                } else {
-                    self.style_sheet.value_color()
-                },
-                font: self.font.clone(),
-                bounds: Rectangle {
-                    y: text_bounds.center_y(),
-                    width: f32::INFINITY,
-                    ..text_bounds
-                },
-                size: f32::from(size),
-                horizontal_alignment: alignment::Horizontal::Left,
-                vertical_alignment: alignment::Vertical::Center,
-            });
-        };
+        shell.publish((self.on_change)(self.value.to_string()));
 
-        if text_width > text_bounds.width {
-            renderer.with_layer(text_bounds, |renderer| {
-                renderer.with_translation(Vector::new(-offset, 0.0), render)
-            });
-        } else {
-            render(renderer);
-        }
+        self.state
+            .history
+            .record(kind, before, &self.value, Instant::now());
     }
-}
 
-impl<'a, Message, Renderer> Widget<Message, Renderer>
-    for TextInput<'a, Message, Renderer>
-where
-    Message: Clone,
-    Renderer: text::Renderer,
-{
-    fn width(&self) -> Length {
-        self.width
-    }
+    fn undo(&mut self, shell: &mut Shell<'_, Message>) {
+        let current = self.entry();
 
-    fn height(&self) -> Length {
-        Length::Shrink
+        if let Some(entry) = self.state.history.undo(current) {
+            self.restore(entry, shell);
+        }
     }
 
-    fn layout(
-        &self,
-        renderer: &Renderer,
-        limits: &layout::Limits,
-    ) -> layout::Node {
-        let text_size = self.size.unwrap_or(renderer.default_size());
+    fn redo(&mut self, shell: &mut Shell<'_, Message>) {
+        let current = self.entry();
 
-        let limits = limits
-            .pad(self.padding)
-            .width(self.width)
-            .max_width(self.max_width)
-            .height(Length::Units(text_size));
+        if let Some(entry) = self.state.history.redo(current) {
+            self.restore(entry, shell);
+        }
+    }
 
-        let mut text = layout::Node::new(limits.resolve(Size::ZERO));
-        text.move_to(Point::new(
-            self.padding.left.into(),
-            self.padding.top.into(),
-        ));
+    fn restore(
+        &mut self,
+        entry: history::Entry,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        self.value = entry.value;
+        self.state.cursor = entry.cursor;
 
-        layout::Node::with_children(text.size().pad(self.padding), vec![text])
+        shell.publish((self.on_change)(self.value.to_string()));
     }
 
-    fn on_event(
+    fn update(
         &mut self,
         event: Event,
         layout: Layout<'_>,
@@ -394,18 +466,38 @@ where
                 self.state.is_focused = is_clicked;
 
                 if is_clicked {
-                    let text_layout = layout.children().next().unwrap();
-                    let target = cursor_position.x - text_layout.bounds().x;
+                    match self.button_at(layout, &self.value, cursor_position) {
+                        Some(Slot::Clear) => {
+                            if let Some(on_clear) = self.on_clear.clone() {
+                                shell.publish(on_clear);
+                            }
 
-                    let click = mouse::Click::new(
-                        cursor_position,
-                        self.state.last_click,
+                            self.state.cursor.move_to(0);
+                            self.state.is_dragging = false;
+
+                            return event::Status::Captured;
+                        }
+                        Some(Slot::Reveal) => {
+                            self.state.is_revealed = !self.state.is_revealed;
+                            self.state.is_dragging = false;
+
+                            return event::Status::Captured;
+                        }
+                        _ => {}
+                    }
+
+                    let text_layout = layout.children().next().unwrap();
+                    let target = cursor_position.x - text_layout.bounds().x;
+
+                    let click = mouse::Click::new(
+                        cursor_position,
+                        self.state.last_click,
                     );
 
                     match click.kind() {
                         click::Kind::Single => {
                             let position = if target > 0.0 {
-                                let value = if self.is_secure {
+                                let value = if self.is_hidden() {
                                     self.value.secure()
                                 } else {
                                     self.value.clone()
@@ -417,7 +509,7 @@ where
                                     self.font.clone(),
                                     self.size,
                                     &value,
-                                    &self.state,
+                                    self.state,
                                     target,
                                 )
                             } else {
@@ -437,7 +529,7 @@ where
                                     self.font.clone(),
                                     self.size,
                                     &self.value,
-                                    &self.state,
+                                    self.state,
                                     target,
                                 )
                                 .unwrap_or(0);
@@ -461,6 +553,54 @@ where
                     return event::Status::Captured;
                 }
             }
+            Event::Mouse(mouse::Event::ButtonPressed(
+                mouse::Button::Middle,
+            )) if self.copy_on_select
+                && layout.bounds().contains(cursor_position) =>
+            {
+                let text_layout = layout.children().next().unwrap();
+                let target = cursor_position.x - text_layout.bounds().x;
+
+                let value = if self.is_hidden() {
+                    self.value.secure()
+                } else {
+                    self.value.clone()
+                };
+
+                let position = find_cursor_position(
+                    renderer,
+                    text_layout.bounds(),
+                    self.font.clone(),
+                    self.size,
+                    &value,
+                    self.state,
+                    target,
+                )
+                .unwrap_or(0);
+
+                self.state.is_focused = true;
+                self.state.cursor.move_to(position);
+
+                let content: String = clipboard
+                    .read_primary()
+                    .unwrap_or_default()
+                    .chars()
+                    .filter(|c| !c.is_control())
+                    .collect();
+
+                if !content.is_empty() {
+                    let before = self.entry();
+
+                    let mut editor =
+                        Editor::new(&mut self.value, &mut self.state.cursor);
+
+                    editor.paste(Value::new(&content));
+
+                    self.commit(history::Kind::Other, before, shell);
+                }
+
+                return event::Status::Captured;
+            }
             Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
             | Event::Touch(touch::Event::FingerLifted { .. })
             | Event::Touch(touch::Event::FingerLost { .. }) => {
@@ -472,7 +612,7 @@ where
                     let text_layout = layout.children().next().unwrap();
                     let target = position.x - text_layout.bounds().x;
 
-                    let value = if self.is_secure {
+                    let value = if self.is_hidden() {
                         self.value.secure()
                     } else {
                         self.value.clone()
@@ -484,7 +624,7 @@ where
                         self.font.clone(),
                         self.size,
                         &value,
-                        &self.state,
+                        self.state,
                         target,
                     )
                     .unwrap_or(0);
@@ -497,19 +637,67 @@ where
                     return event::Status::Captured;
                 }
             }
+            Event::InputMethod(input_method::Event::Preedit {
+                content,
+                ..
+            }) if self.state.is_focused => {
+                self.state.preedit =
+                    Some(content).filter(|content| !content.is_empty());
+
+                return event::Status::Captured;
+            }
+            Event::Accessibility(accessibility::Request { target, action }) => {
+                let is_target = target == layout.bounds();
+
+                match action {
+                    accessibility::Action::Focus => {
+                        self.state.is_focused = is_target;
+                    }
+                    accessibility::Action::SetValue(text) if is_target => {
+                        let before = self.entry();
+
+                        self.value = Value::new(&text);
+                        self.state.cursor.move_to(self.value.len());
+                        self.commit(history::Kind::Other, before, shell);
+
+                        return event::Status::Captured;
+                    }
+                    _ => {}
+                }
+            }
+            Event::InputMethod(input_method::Event::Commit(text))
+                if self.state.is_focused =>
+            {
+                self.state.preedit = None;
+
+                let before = self.entry();
+
+                let mut editor =
+                    Editor::new(&mut self.value, &mut self.state.cursor);
+
+                editor.paste(Value::new(&text));
+
+                self.commit(history::Kind::Insert, before, shell);
+
+                return event::Status::Captured;
+            }
+            Event::InputMethod(input_method::Event::Closed) => {
+                self.state.preedit = None;
+            }
             Event::Keyboard(keyboard::Event::CharacterReceived(c))
                 if self.state.is_focused
                     && self.state.is_pasting.is_none()
                     && !self.state.keyboard_modifiers.command()
                     && !c.is_control() =>
             {
+                let before = self.entry();
+
                 let mut editor =
                     Editor::new(&mut self.value, &mut self.state.cursor);
 
                 editor.insert(c);
 
-                let message = (self.on_change)(editor.contents());
-                shell.publish(message);
+                self.commit(history::Kind::Insert, before, shell);
 
                 return event::Status::Captured;
             }
@@ -521,11 +709,15 @@ where
                 match key_code {
                     keyboard::KeyCode::Enter
                     | keyboard::KeyCode::NumpadEnter => {
-                        if let Some(on_submit) = self.on_submit.clone() {
-                            shell.publish(on_submit);
+                        match self.on_submit.clone() {
+                            Some(on_submit) => shell.publish(on_submit),
+                            // Let a parent, like a `Form`, handle it
+                            None => return event::Status::Ignored,
                         }
                     }
                     keyboard::KeyCode::Backspace => {
+                        let before = self.entry();
+
                         if platform::is_jump_modifier_pressed(modifiers)
                             && self
                                 .state
@@ -551,10 +743,11 @@ where
 
                         editor.backspace();
 
-                        let message = (self.on_change)(editor.contents());
-                        shell.publish(message);
+                        self.commit(history::Kind::Delete, before, shell);
                     }
                     keyboard::KeyCode::Delete => {
+                        let before = self.entry();
+
                         if platform::is_jump_modifier_pressed(modifiers)
                             && self
                                 .state
@@ -582,8 +775,31 @@ where
 
                         editor.delete();
 
-                        let message = (self.on_change)(editor.contents());
-                        shell.publish(message);
+                        self.commit(history::Kind::Delete, before, shell);
+                    }
+                    keyboard::KeyCode::Left
+                        if platform::is_line_modifier_pressed(modifiers) =>
+                    {
+                        if modifiers.shift() {
+                            self.state.cursor.select_range(
+                                self.state.cursor.start(&self.value),
+                                0,
+                            );
+                        } else {
+                            self.state.cursor.move_to(0);
+                        }
+                    }
+                    keyboard::KeyCode::Right
+                        if platform::is_line_modifier_pressed(modifiers) =>
+                    {
+                        if modifiers.shift() {
+                            self.state.cursor.select_range(
+                                self.state.cursor.start(&self.value),
+                                self.value.len(),
+                            );
+                        } else {
+                            self.state.cursor.move_to(self.value.len());
+                        }
                     }
                     keyboard::KeyCode::Left => {
                         if platform::is_jump_modifier_pressed(modifiers)
@@ -644,119 +860,713 @@ where
                         }
                     }
                     keyboard::KeyCode::C
-                        if self.state.keyboard_modifiers.command() =>
+                        if self.state.keyboard_modifiers.command()
+                            && !self.is_secure =>
                     {
-                        match self.state.cursor.selection(&self.value) {
-                            Some((start, end)) => {
-                                clipboard.write(
-                                    self.value.select(start, end).to_string(),
-                                );
-                            }
-                            None => {}
+                        if let Some((start, end)) =
+                            self.state.cursor.selection(&self.value)
+                        {
+                            clipboard.write(
+                                self.value.select(start, end).to_string(),
+                            );
                         }
                     }
                     keyboard::KeyCode::X
+                        if self.state.keyboard_modifiers.command()
+                            && !self.is_secure =>
+                    {
+                        if let Some((start, end)) =
+                            self.state.cursor.selection(&self.value)
+                        {
+                            clipboard.write(
+                                self.value.select(start, end).to_string(),
+                            );
+
+                            let before = self.entry();
+
+                            let mut editor = Editor::new(
+                                &mut self.value,
+                                &mut self.state.cursor,
+                            );
+
+                            editor.delete();
+
+                            self.commit(history::Kind::Other, before, shell);
+                        }
+                    }
+                    keyboard::KeyCode::V => {
+                        if self.state.keyboard_modifiers.command() {
+                            let content = match self.state.is_pasting.take() {
+                                Some(content) => content,
+                                None => {
+                                    let content: String = clipboard
+                                        .read()
+                                        .unwrap_or(String::new())
+                                        .chars()
+                                        .filter(|c| !c.is_control())
+                                        .collect();
+
+                                    Value::new(&content)
+                                }
+                            };
+
+                            let before = self.entry();
+
+                            let mut editor = Editor::new(
+                                &mut self.value,
+                                &mut self.state.cursor,
+                            );
+
+                            editor.paste(content.clone());
+
+                            self.commit(history::Kind::Other, before, shell);
+
+                            self.state.is_pasting = Some(content);
+                        } else {
+                            self.state.is_pasting = None;
+                        }
+                    }
+                    keyboard::KeyCode::Z
                         if self.state.keyboard_modifiers.command() =>
                     {
-                        match self.state.cursor.selection(&self.value) {
-                            Some((start, end)) => {
-                                clipboard.write(
-                                    self.value.select(start, end).to_string(),
-                                );
-                            }
-                            None => {}
+                        if modifiers.shift() {
+                            self.redo(shell);
+                        } else {
+                            self.undo(shell);
                         }
+                    }
+                    keyboard::KeyCode::Y
+                        if self.state.keyboard_modifiers.command() =>
+                    {
+                        self.redo(shell);
+                    }
+                    keyboard::KeyCode::A
+                        if self.state.keyboard_modifiers.command() =>
+                    {
+                        self.state.cursor.select_all(&self.value);
+                    }
+                    keyboard::KeyCode::Escape => {
+                        self.state.is_focused = false;
+                        self.state.is_dragging = false;
+                        self.state.is_pasting = None;
+
+                        self.state.keyboard_modifiers =
+                            keyboard::Modifiers::default();
+                    }
+                    keyboard::KeyCode::Tab
+                    | keyboard::KeyCode::Up
+                    | keyboard::KeyCode::Down => {
+                        return event::Status::Ignored;
+                    }
+                    _ => {}
+                }
+
+                return event::Status::Captured;
+            }
+            Event::Keyboard(keyboard::Event::KeyReleased {
+                key_code, ..
+            }) if self.state.is_focused => {
+                match key_code {
+                    keyboard::KeyCode::V => {
+                        self.state.is_pasting = None;
+                    }
+                    keyboard::KeyCode::Tab
+                    | keyboard::KeyCode::Up
+                    | keyboard::KeyCode::Down => {
+                        return event::Status::Ignored;
+                    }
+                    _ => {}
+                }
+
+                return event::Status::Captured;
+            }
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers))
+                if self.state.is_focused =>
+            {
+                self.state.keyboard_modifiers = modifiers;
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+}
+
+impl<'a, Message, Renderer> TextInput<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+{
+    /// Returns whether the value of the [`TextInput`] is currently hidden.
+    fn is_hidden(&self) -> bool {
+        self.is_secure && !self.state.is_revealed
+    }
+
+    /// Returns the slots of the [`TextInput`], in the order of their layout
+    /// nodes.
+    fn slots(&self) -> Vec<Slot> {
+        let mut slots = Vec::new();
+
+        if self.icon.is_some() {
+            slots.push(Slot::Icon);
+        }
+
+        if self.on_clear.is_some() {
+            slots.push(Slot::Clear);
+        }
+
+        if self.is_secure && self.reveal.is_some() {
+            slots.push(Slot::Reveal);
+        }
+
+        slots
+    }
+
+    /// Returns the side, width, and spacing of a [`Slot`].
+    fn slot_metrics(&self, slot: Slot, text_size: u16) -> (Side, f32, f32) {
+        let (side, size, spacing) = match slot {
+            Slot::Icon => {
+                let icon = self.icon.as_ref().unwrap();
+
+                (icon.side, icon.size, icon.spacing)
+            }
+            Slot::Clear => (Side::Right, None, BUTTON_SPACING),
+            Slot::Reveal => {
+                let icon = self.reveal.as_ref().unwrap();
+
+                (Side::Right, icon.size, BUTTON_SPACING)
+            }
+        };
+
+        (
+            side,
+            f32::from(size.unwrap_or(text_size)),
+            f32::from(spacing),
+        )
+    }
+
+    /// Returns the button of the [`TextInput`] under the given point, if any.
+    fn button_at(
+        &self,
+        layout: Layout<'_>,
+        value: &Value,
+        point: Point,
+    ) -> Option<Slot> {
+        self.slots()
+            .into_iter()
+            .zip(layout.children().skip(1))
+            .find(|(slot, layout)| {
+                let is_active = match slot {
+                    Slot::Icon => false,
+                    Slot::Clear => !value.is_empty(),
+                    Slot::Reveal => true,
+                };
+
+                is_active && layout.bounds().contains(point)
+            })
+            .map(|(slot, _)| slot)
+    }
+
+    /// Returns the bounds of the caret of the [`TextInput`], as displayed
+    /// inside the given text bounds.
+    fn caret(&self, renderer: &Renderer, text_bounds: Rectangle) -> Rectangle {
+        let size = self.size.unwrap_or(renderer.default_size());
+
+        let value = if self.is_hidden() {
+            self.value.secure()
+        } else {
+            self.value.clone()
+        };
+
+        let (position, offset) = measure_cursor_and_scroll_offset(
+            renderer,
+            text_bounds,
+            &value,
+            size,
+            self.state.cursor.end(&value),
+            self.font.clone(),
+        );
+
+        Rectangle {
+            x: text_bounds.x + position - offset,
+            width: 1.0,
+            ..text_bounds
+        }
+    }
+
+    fn status(&self, bounds: Rectangle, cursor_position: Point) -> Status {
+        if self.state.is_invalid() {
+            Status::Invalid
+        } else if self.state.is_focused() {
+            Status::Focused
+        } else if bounds.contains(cursor_position) {
+            Status::Hovered
+        } else {
+            Status::Active
+        }
+    }
+
+    /// Draws the [`TextInput`] with the given [`Renderer`], overriding its
+    /// [`Value`] if provided.
+    pub fn draw(
+        &self,
+        renderer: &mut Renderer,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        value: Option<&Value>,
+    ) {
+        let theme = style
+            .theme
+            .resolve(theme::Widget::TextInput, self.class.as_deref());
+        let style_sheet = self.style_sheet.as_deref().unwrap_or(&theme);
+
+        let value = value.unwrap_or(&self.value);
+        let is_empty = value.is_empty();
+        let secure_value = self.is_hidden().then(|| value.secure());
+        let value = secure_value.as_ref().unwrap_or(value);
+
+        let preedit = self
+            .state
+            .preedit
+            .as_ref()
+            .filter(|_| self.state.is_focused())
+            .map(|preedit| {
+                let start = self.state.cursor.end(value);
+                let preedit = Value::new(preedit);
+                let end = start + preedit.len();
+
+                let mut composed = value.clone();
+                composed.insert_many(start, preedit);
+
+                (composed, start, end)
+            });
+
+        let value = preedit
+            .as_ref()
+            .map(|(composed, _, _)| composed)
+            .unwrap_or(value);
+
+        let bounds = layout.bounds();
+        let text_bounds = layout.children().next().unwrap().bounds();
+
+        let style = self.status(bounds, cursor_position).style(style_sheet);
+
+        let style = match self.transition.and_then(|transition| {
+            self.state.status.progress(transition, Instant::now())
+        }) {
+            Some((previous, progress)) => {
+                previous.style(style_sheet).interpolate(&style, progress)
+            }
+            None => style,
+        };
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border_radius: style.border_radius,
+                border_width: style.border_width,
+                border_color: style.border_color,
+            },
+            style.background,
+        );
+
+        let text = value.to_string();
+        let size = self.size.unwrap_or(renderer.default_size());
+
+        let (cursor, offset) = if let Some((_, start, end)) = preedit {
+            let (left, _) = measure_cursor_and_scroll_offset(
+                renderer,
+                text_bounds,
+                value,
+                size,
+                start,
+                self.font.clone(),
+            );
+
+            let (right, offset) = measure_cursor_and_scroll_offset(
+                renderer,
+                text_bounds,
+                value,
+                size,
+                end,
+                self.font.clone(),
+            );
+
+            (
+                Some((
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x: text_bounds.x + left,
+                            y: text_bounds.y + text_bounds.height - 1.0,
+                            width: right - left,
+                            height: 1.0,
+                        },
+                        border_radius: 0.0,
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                    },
+                    style_sheet.value_color(),
+                )),
+                offset,
+            )
+        } else if self.state.is_focused() {
+            match self.state.cursor.state(value) {
+                cursor::State::Index(position)
+                    if !self.state.is_cursor_visible(Instant::now()) =>
+                {
+                    let (_, offset) = measure_cursor_and_scroll_offset(
+                        renderer,
+                        text_bounds,
+                        value,
+                        size,
+                        position,
+                        self.font.clone(),
+                    );
+
+                    (None, offset)
+                }
+                cursor::State::Index(position) => {
+                    let (text_value_width, offset) =
+                        measure_cursor_and_scroll_offset(
+                            renderer,
+                            text_bounds,
+                            value,
+                            size,
+                            position,
+                            self.font.clone(),
+                        );
+
+                    (
+                        Some((
+                            renderer::Quad {
+                                bounds: Rectangle {
+                                    x: text_bounds.x + text_value_width,
+                                    y: text_bounds.y,
+                                    width: 1.0,
+                                    height: text_bounds.height,
+                                },
+                                border_radius: 0.0,
+                                border_width: 0.0,
+                                border_color: Color::TRANSPARENT,
+                            },
+                            style_sheet.value_color(),
+                        )),
+                        offset,
+                    )
+                }
+                cursor::State::Selection { start, end } => {
+                    let left = start.min(end);
+                    let right = end.max(start);
+
+                    let (left_position, left_offset) =
+                        measure_cursor_and_scroll_offset(
+                            renderer,
+                            text_bounds,
+                            value,
+                            size,
+                            left,
+                            self.font.clone(),
+                        );
+
+                    let (right_position, right_offset) =
+                        measure_cursor_and_scroll_offset(
+                            renderer,
+                            text_bounds,
+                            value,
+                            size,
+                            right,
+                            self.font.clone(),
+                        );
+
+                    let width = right_position - left_position;
+
+                    (
+                        Some((
+                            renderer::Quad {
+                                bounds: Rectangle {
+                                    x: text_bounds.x + left_position,
+                                    y: text_bounds.y,
+                                    width,
+                                    height: text_bounds.height,
+                                },
+                                border_radius: 0.0,
+                                border_width: 0.0,
+                                border_color: Color::TRANSPARENT,
+                            },
+                            style_sheet.selection_color(),
+                        )),
+                        if end == right {
+                            right_offset
+                        } else {
+                            left_offset
+                        },
+                    )
+                }
+            }
+        } else {
+            (None, 0.0)
+        };
+
+        let text_width = renderer.measure_width(
+            if text.is_empty() {
+                &self.placeholder
+            } else {
+                &text
+            },
+            size,
+            self.font.clone(),
+        );
+
+        let render = |renderer: &mut Renderer| {
+            if let Some((cursor, color)) = cursor {
+                renderer.fill_quad(cursor, color);
+            }
+
+            renderer.fill_text(Text {
+                content: if text.is_empty() {
+                    &self.placeholder
+                } else {
+                    &text
+                },
+                color: if text.is_empty() {
+                    style_sheet.placeholder_color()
+                } else {
+                    style_sheet.value_color()
+                },
+                font: self.font.clone(),
+                bounds: Rectangle {
+                    y: text_bounds.center_y(),
+                    width: f32::INFINITY,
+                    ..text_bounds
+                },
+                size: f32::from(size),
+                horizontal_alignment: alignment::Horizontal::Left,
+                vertical_alignment: alignment::Vertical::Center,
+            });
+        };
+
+        if text_width > text_bounds.width {
+            renderer.with_layer(text_bounds, |renderer| {
+                renderer.with_translation(Vector::new(-offset, 0.0), render)
+            });
+        } else {
+            render(renderer);
+        }
+
+        for (slot, layout) in
+            self.slots().into_iter().zip(layout.children().skip(1))
+        {
+            let (code_point, font, icon_size, color) = match slot {
+                Slot::Icon => {
+                    let icon = self.icon.as_ref().unwrap();
+
+                    (
+                        icon.code_point,
+                        icon.font.clone(),
+                        icon.size,
+                        style_sheet.value_color(),
+                    )
+                }
+                Slot::Clear if !is_empty => (
+                    '×',
+                    self.font.clone(),
+                    None,
+                    style_sheet.placeholder_color(),
+                ),
+                Slot::Clear => continue,
+                Slot::Reveal => {
+                    let icon = self.reveal.as_ref().unwrap();
+
+                    (
+                        icon.code_point,
+                        icon.font.clone(),
+                        icon.size,
+                        if self.state.is_revealed {
+                            style_sheet.value_color()
+                        } else {
+                            style_sheet.placeholder_color()
+                        },
+                    )
+                }
+            };
 
-                        let mut editor = Editor::new(
-                            &mut self.value,
-                            &mut self.state.cursor,
-                        );
+            let bounds = layout.bounds();
 
-                        editor.delete();
+            renderer.fill_text(Text {
+                content: &code_point.to_string(),
+                color,
+                font,
+                bounds: Rectangle {
+                    x: bounds.center_x(),
+                    y: bounds.center_y(),
+                    ..bounds
+                },
+                size: f32::from(icon_size.unwrap_or(size)),
+                horizontal_alignment: alignment::Horizontal::Center,
+                vertical_alignment: alignment::Vertical::Center,
+            });
+        }
+    }
+}
 
-                        let message = (self.on_change)(editor.contents());
-                        shell.publish(message);
-                    }
-                    keyboard::KeyCode::V => {
-                        if self.state.keyboard_modifiers.command() {
-                            let content = match self.state.is_pasting.take() {
-                                Some(content) => content,
-                                None => {
-                                    let content: String = clipboard
-                                        .read()
-                                        .unwrap_or(String::new())
-                                        .chars()
-                                        .filter(|c| !c.is_control())
-                                        .collect();
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for TextInput<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: text::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
 
-                                    Value::new(&content)
-                                }
-                            };
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
 
-                            let mut editor = Editor::new(
-                                &mut self.value,
-                                &mut self.state.cursor,
-                            );
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let text_size = self.size.unwrap_or(renderer.default_size());
 
-                            editor.paste(content.clone());
+        let limits = limits
+            .pad(self.padding)
+            .width(self.width)
+            .max_width(self.max_width)
+            .height(Length::Units(text_size));
 
-                            let message = (self.on_change)(editor.contents());
-                            shell.publish(message);
+        let bounds = limits.resolve(Size::ZERO);
 
-                            self.state.is_pasting = Some(content);
-                        } else {
-                            self.state.is_pasting = None;
-                        }
-                    }
-                    keyboard::KeyCode::A
-                        if self.state.keyboard_modifiers.command() =>
-                    {
-                        self.state.cursor.select_all(&self.value);
-                    }
-                    keyboard::KeyCode::Escape => {
-                        self.state.is_focused = false;
-                        self.state.is_dragging = false;
-                        self.state.is_pasting = None;
+        let mut leading = 0.0;
+        let mut trailing = 0.0;
+        let mut slots = Vec::new();
 
-                        self.state.keyboard_modifiers =
-                            keyboard::Modifiers::default();
-                    }
-                    keyboard::KeyCode::Tab
-                    | keyboard::KeyCode::Up
-                    | keyboard::KeyCode::Down => {
-                        return event::Status::Ignored;
-                    }
-                    _ => {}
+        for slot in self.slots() {
+            let (side, width, spacing) = self.slot_metrics(slot, text_size);
+
+            let x = match side {
+                Side::Left => {
+                    let x = leading;
+                    leading += width + spacing;
+
+                    x
                 }
+                Side::Right => {
+                    trailing += width + spacing;
 
-                return event::Status::Captured;
-            }
-            Event::Keyboard(keyboard::Event::KeyReleased {
-                key_code, ..
-            }) if self.state.is_focused => {
-                match key_code {
-                    keyboard::KeyCode::V => {
-                        self.state.is_pasting = None;
-                    }
-                    keyboard::KeyCode::Tab
-                    | keyboard::KeyCode::Up
-                    | keyboard::KeyCode::Down => {
-                        return event::Status::Ignored;
-                    }
-                    _ => {}
+                    bounds.width - trailing + spacing
                 }
+            };
 
-                return event::Status::Captured;
+            let mut node =
+                layout::Node::new(Size::new(width, f32::from(text_size)));
+            node.move_to(Point::new(
+                f32::from(self.padding.left) + x,
+                self.padding.top.into(),
+            ));
+
+            slots.push(node);
+        }
+
+        let mut text = layout::Node::new(Size::new(
+            (bounds.width - leading - trailing).max(0.0),
+            bounds.height,
+        ))
+        .with_baseline(renderer.baseline(text_size, self.font.clone()));
+        text.move_to(Point::new(
+            f32::from(self.padding.left) + leading,
+            self.padding.top.into(),
+        ));
+
+        let mut children = vec![text];
+        children.extend(slots);
+
+        layout::Node::with_children(bounds.pad(self.padding), children)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let selection = self.state.cursor.span(&self.value);
+        let was_focused = self.state.is_focused;
+
+        let status = self.update(
+            event,
+            layout,
+            cursor_position,
+            renderer,
+            clipboard,
+            shell,
+        );
+
+        let new_selection = self.state.cursor.span(&self.value);
+
+        if new_selection != selection {
+            if let Some(on_select) = &self.on_select {
+                shell.publish(on_select(new_selection));
             }
-            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers))
-                if self.state.is_focused =>
+
+            if self.copy_on_select && !self.is_secure {
+                if let Some((start, end)) =
+                    self.state.cursor.selection(&self.value)
+                {
+                    clipboard.write_primary(
+                        self.value.select(start, end).to_string(),
+                    );
+                }
+            }
+        }
+
+        if self.state.is_focused {
+            let now = Instant::now();
+
+            // The cursor stays visible while the user interacts with the
+            // input, and blinks again afterwards
+            if !was_focused
+                || new_selection != selection
+                || status == event::Status::Captured
             {
-                self.state.keyboard_modifiers = modifiers;
+                self.state.updated_at = Some(now);
             }
-            _ => {}
+
+            let elapsed = now
+                .saturating_duration_since(
+                    *self.state.updated_at.get_or_insert(now),
+                )
+                .as_millis();
+
+            let millis_until_redraw = CURSOR_BLINK_INTERVAL_MILLIS
+                - elapsed % CURSOR_BLINK_INTERVAL_MILLIS;
+
+            shell.request_redraw(window::RedrawRequest::At(
+                now + Duration::from_millis(millis_until_redraw as u64),
+            ));
+
+            let text_bounds = layout.children().next().unwrap().bounds();
+
+            shell.request_input_method(self.caret(renderer, text_bounds));
         }
 
-        event::Status::Ignored
+        if let Some(transition) = self.transition {
+            let now = Instant::now();
+
+            self.state
+                .status
+                .update(self.status(layout.bounds(), cursor_position), now);
+
+            if self.state.status.progress(transition, now).is_some() {
+                shell.request_redraw(window::RedrawRequest::NextFrame);
+            }
+        }
+
+        status
     }
 
     fn mouse_interaction(
@@ -765,7 +1575,12 @@ where
         cursor_position: Point,
         _viewport: &Rectangle,
     ) -> mouse::Interaction {
-        if layout.bounds().contains(cursor_position) {
+        if self
+            .button_at(layout, &self.value, cursor_position)
+            .is_some()
+        {
+            mouse::Interaction::Pointer
+        } else if layout.bounds().contains(cursor_position) {
             mouse::Interaction::Text
         } else {
             mouse::Interaction::default()
@@ -775,12 +1590,12 @@ where
     fn draw(
         &self,
         renderer: &mut Renderer,
-        _style: &renderer::Style,
+        style: &renderer::Style,
         layout: Layout<'_>,
         cursor_position: Point,
         _viewport: &Rectangle,
     ) {
-        self.draw(renderer, layout, cursor_position, None)
+        self.draw(renderer, style, layout, cursor_position, None)
     }
 
     fn hash_layout(&self, state: &mut Hasher) {
@@ -792,6 +1607,60 @@ where
         self.max_width.hash(state);
         self.padding.hash(state);
         self.size.hash(state);
+
+        for slot in self.slots() {
+            let (side, _, _) = self.slot_metrics(slot, 0);
+            side.hash(state);
+        }
+
+        if let Some(icon) = &self.icon {
+            icon.size.hash(state);
+            icon.spacing.hash(state);
+        }
+
+        if let Some(icon) = &self.reveal {
+            icon.size.hash(state);
+        }
+    }
+
+    fn operate(
+        &mut self,
+        layout: Layout<'_>,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let mut node = accessibility::Node::new(
+            accessibility::Role::TextInput,
+            layout.bounds(),
+        )
+        .label(&self.placeholder)
+        .focused(self.state.is_focused)
+        .focusable(true)
+        .editable(true);
+
+        if !self.is_secure {
+            node =
+                node.value(accessibility::Value::Text(self.value.to_string()));
+        }
+
+        operation.accessible(node, self.id.as_ref());
+        operation.focusable(
+            &mut operation::focusable::WithTabIndex::new(
+                self.state,
+                self.tab_index,
+            ),
+            self.id.as_ref(),
+        );
+        operation.text_input(self.state, self.id.as_ref());
+    }
+
+    fn inspect(&self, layout: Layout<'_>) -> inspector::Node {
+        inspector::Node::new(
+            std::any::type_name::<Self>(),
+            self.width,
+            Length::Shrink,
+            layout,
+        )
+        .with_id(self.id.clone())
     }
 }
 
@@ -808,15 +1677,38 @@ where
     }
 }
 
+/// The outcome of validating an edit of a [`TextInput`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Validation {
+    /// The edit is accepted as is.
+    Valid,
+
+    /// The edit is accepted, but the [`TextInput`] is styled as invalid until
+    /// a later edit is valid.
+    Invalid,
+
+    /// The edit is accepted, replacing the value with the given one.
+    Transform(String),
+
+    /// The edit is discarded.
+    Reject,
+}
+
 /// The state of a [`TextInput`].
 #[derive(Debug, Default, Clone)]
 pub struct State {
     is_focused: bool,
     is_dragging: bool,
+    is_invalid: bool,
+    is_revealed: bool,
     is_pasting: Option<Value>,
+    preedit: Option<String>,
     last_click: Option<mouse::Click>,
     cursor: Cursor,
+    history: History,
     keyboard_modifiers: keyboard::Modifiers,
+    status: Tracker<Status>,
+    updated_at: Option<Instant>,
     // TODO: Add stateful horizontal scrolling offset
 }
 
@@ -831,10 +1723,16 @@ impl State {
         Self {
             is_focused: true,
             is_dragging: false,
+            is_invalid: false,
+            is_revealed: false,
             is_pasting: None,
+            preedit: None,
             last_click: None,
             cursor: Cursor::default(),
+            history: History::default(),
             keyboard_modifiers: keyboard::Modifiers::default(),
+            status: Tracker::new(),
+            updated_at: None,
         }
     }
 
@@ -843,14 +1741,40 @@ impl State {
         self.is_focused
     }
 
+    /// Returns whether the last edit of the [`TextInput`] was deemed
+    /// [`Validation::Invalid`].
+    pub fn is_invalid(&self) -> bool {
+        self.is_invalid
+    }
+
+    /// Returns whether the value of a password [`TextInput`] is currently
+    /// revealed.
+    pub fn is_revealed(&self) -> bool {
+        self.is_revealed
+    }
+
     /// Returns the [`Cursor`] of the [`TextInput`].
     pub fn cursor(&self) -> Cursor {
         self.cursor
     }
 
+    /// Returns whether the blinking cursor of a focused [`TextInput`] is
+    /// visible at the given [`Instant`].
+    pub fn is_cursor_visible(&self, now: Instant) -> bool {
+        let elapsed = match self.updated_at {
+            Some(updated_at) => {
+                now.saturating_duration_since(updated_at).as_millis()
+            }
+            None => 0,
+        };
+
+        (elapsed / CURSOR_BLINK_INTERVAL_MILLIS) % 2 != 1
+    }
+
     /// Focuses the [`TextInput`].
     pub fn focus(&mut self) {
         self.is_focused = true;
+        self.updated_at = Some(Instant::now());
     }
 
     /// Unfocuses the [`TextInput`].
@@ -877,6 +1801,107 @@ impl State {
     pub fn select_all(&mut self) {
         self.cursor.select_range(0, usize::MAX);
     }
+
+    /// Selects the text between the `anchor` and the `cursor` positions of
+    /// the [`TextInput`].
+    ///
+    /// The caret is placed at `cursor`, and positions past the end of the
+    /// value are clamped when the [`TextInput`] is drawn.
+    pub fn select_range(&mut self, anchor: usize, cursor: usize) {
+        self.cursor.select_range(anchor, cursor);
+    }
+
+    /// Forgets every undo and redo step of the [`TextInput`].
+    ///
+    /// This is useful when the application replaces the value of the
+    /// [`TextInput`] wholesale, like after submitting a form.
+    pub fn clear_history(&mut self) {
+        self.history.clear();
+    }
+
+    /// Closes the undo step that is currently open, so the next edit of the
+    /// [`TextInput`] can be undone on its own.
+    pub fn snapshot(&mut self) {
+        self.history.seal();
+    }
+}
+
+/// The interaction status of a [`TextInput`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Status {
+    Active,
+    Hovered,
+    Focused,
+    Invalid,
+}
+
+impl Status {
+    fn style(self, style_sheet: &dyn StyleSheet) -> Style {
+        match self {
+            Status::Active => style_sheet.active(),
+            Status::Hovered => style_sheet.hovered(),
+            Status::Focused => style_sheet.focused(),
+            Status::Invalid => style_sheet.invalid(),
+        }
+    }
+}
+
+impl Interpolate for Style {
+    fn interpolate(&self, other: &Self, factor: f32) -> Self {
+        Style {
+            background: self.background.interpolate(&other.background, factor),
+            border_radius: self
+                .border_radius
+                .interpolate(&other.border_radius, factor),
+            border_width: self
+                .border_width
+                .interpolate(&other.border_width, factor),
+            border_color: self
+                .border_color
+                .interpolate(&other.border_color, factor),
+        }
+    }
+}
+
+impl operation::TextInput for State {
+    fn clear_history(&mut self) {
+        State::clear_history(self)
+    }
+
+    fn snapshot(&mut self) {
+        State::snapshot(self)
+    }
+}
+
+impl operation::Focusable for State {
+    fn is_focused(&self) -> bool {
+        State::is_focused(self)
+    }
+
+    fn focus(&mut self) {
+        State::focus(self)
+    }
+
+    fn unfocus(&mut self) {
+        State::unfocus(self)
+    }
+}
+
+/// Produces a [`Command`] that focuses the [`TextInput`] with the given [`Id`].
+pub fn focus<Message: 'static>(id: Id) -> Command<Message> {
+    Command::widget(operation::focusable::focus(id))
+}
+
+/// Produces a [`Command`] that clears the undo history of the [`TextInput`]
+/// with the given [`Id`].
+pub fn clear_history<Message: 'static>(id: Id) -> Command<Message> {
+    Command::widget(operation::text_input::clear_history(id))
+}
+
+/// Produces a [`Command`] that closes the current undo step of the
+/// [`TextInput`] with the given [`Id`].
+pub fn snapshot<Message: 'static>(id: Id) -> Command<Message> {
+    Command::widget(operation::text_input::snapshot(id))
 }
 
 mod platform {
@@ -889,6 +1914,10 @@ mod platform {
             modifiers.control()
         }
     }
+
+    pub fn is_line_modifier_pressed(modifiers: keyboard::Modifiers) -> bool {
+        cfg!(target_os = "macos") && modifiers.logo()
+    }
 }
 
 fn offset<Renderer>(
@@ -963,7 +1992,7 @@ where
     let size = size.unwrap_or(renderer.default_size());
 
     let offset =
-        offset(renderer, text_bounds, font.clone(), size, &value, &state);
+        offset(renderer, text_bounds, font.clone(), size, value, state);
 
     renderer
         .hit_test(
@@ -976,3 +2005,121 @@ where
         )
         .map(text::Hit::cursor)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::renderer::Null;
+    use crate::simulator::Simulator;
+
+    fn type_text(
+        state: &mut State,
+        text: &str,
+        configure: impl FnOnce(
+            TextInput<'_, String, Null>,
+        ) -> TextInput<'_, String, Null>,
+    ) -> Vec<String> {
+        let input = TextInput::new(state, "", "", String::from);
+
+        let mut simulator =
+            Simulator::<_, Null>::new(configure(input), Size::new(200.0, 40.0));
+
+        let _ = simulator.type_text(text);
+
+        simulator.into_messages()
+    }
+
+    #[test]
+    fn masks_edits() {
+        let mut state = State::focused();
+
+        let messages = type_text(&mut state, "12x34", |input| {
+            input.mask(Mask::new("99-99"))
+        });
+
+        assert_eq!(messages, vec!["1", "12", "12-3", "12-34"]);
+    }
+
+    #[test]
+    fn rejects_invalid_edits() {
+        let mut state = State::focused();
+
+        let messages = type_text(&mut state, "1a2", |input| {
+            input.validate(|value| {
+                if value.chars().all(|c| c.is_ascii_digit()) {
+                    Validation::Valid
+                } else {
+                    Validation::Reject
+                }
+            })
+        });
+
+        assert_eq!(messages, vec!["1", "12"]);
+    }
+
+    #[test]
+    fn transforms_edits() {
+        let mut state = State::focused();
+
+        let messages = type_text(&mut state, "ab", |input| {
+            input.validate(|value| Validation::Transform(value.to_uppercase()))
+        });
+
+        assert_eq!(messages, vec!["A", "AB"]);
+    }
+
+    #[test]
+    fn marks_invalid_edits() {
+        let mut state = State::focused();
+        let is_short = |value: String| {
+            if value.len() < 3 {
+                Validation::Invalid
+            } else {
+                Validation::Valid
+            }
+        };
+
+        let messages =
+            type_text(&mut state, "ab", |input| input.validate(is_short));
+
+        assert_eq!(messages, vec!["a", "ab"]);
+        assert!(state.is_invalid());
+
+        let _ = type_text(&mut state, "abc", |input| input.validate(is_short));
+
+        assert!(!state.is_invalid());
+    }
+
+    #[test]
+    fn commits_the_composition_of_input_methods() {
+        let mut state = State::focused();
+
+        let preedit = |content: &str| {
+            Event::InputMethod(input_method::Event::Preedit {
+                content: String::from(content),
+                selection: Some(content.len()..content.len()),
+            })
+        };
+
+        let messages = {
+            let input = TextInput::new(&mut state, "", "", String::from);
+
+            let mut simulator =
+                Simulator::<_, Null>::new(input, Size::new(200.0, 40.0));
+
+            let statuses = simulator.simulate([preedit("に"), preedit("日本")]);
+
+            assert_eq!(statuses, [event::Status::Captured; 2]);
+            assert!(simulator.messages().is_empty());
+
+            let _ = simulator.simulate([Event::InputMethod(
+                input_method::Event::Commit(String::from("日本")),
+            )]);
+
+            simulator.into_messages()
+        };
+
+        assert_eq!(messages, vec!["日本"]);
+        assert_eq!(state.preedit, None);
+    }
+}
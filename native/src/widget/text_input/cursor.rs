@@ -22,6 +22,35 @@ pub enum State {
     },
 }
 
+/// The selection of a [`Cursor`], as observed by an application.
+///
+/// The `anchor` stays put while the selection is extended, and the `cursor`
+/// follows the caret. Both are equal when nothing is selected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Selection {
+    /// The position where the selection started.
+    pub anchor: usize,
+    /// The position of the caret.
+    pub cursor: usize,
+}
+
+impl Selection {
+    /// Returns true if the [`Selection`] does not span any text.
+    pub fn is_empty(&self) -> bool {
+        self.anchor == self.cursor
+    }
+
+    /// Returns the lowest position of the [`Selection`].
+    pub fn start(&self) -> usize {
+        self.anchor.min(self.cursor)
+    }
+
+    /// Returns the highest position of the [`Selection`].
+    pub fn end(&self) -> usize {
+        self.anchor.max(self.cursor)
+    }
+}
+
 impl Default for Cursor {
     fn default() -> Self {
         Cursor {
@@ -60,6 +89,14 @@ impl Cursor {
         }
     }
 
+    /// Returns the [`Selection`] of the [`Cursor`] for the given [`Value`].
+    pub fn span(&self, value: &Value) -> Selection {
+        Selection {
+            anchor: self.start(value),
+            cursor: self.end(value),
+        }
+    }
+
     pub(crate) fn move_to(&mut self, position: usize) {
         self.state = State::Index(position);
     }
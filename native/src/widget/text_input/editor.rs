@@ -10,10 +10,6 @@ impl<'a> Editor<'a> {
         Editor { value, cursor }
     }
 
-    pub fn contents(&self) -> String {
-        self.value.to_string()
-    }
-
     pub fn insert(&mut self, character: char) {
         match self.cursor.selection(self.value) {
             Some((left, right)) => {
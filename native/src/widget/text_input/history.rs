@@ -0,0 +1,103 @@
+use crate::widget::text_input::{Cursor, Value};
+
+use std::time::{Duration, Instant};
+
+/// The amount of time consecutive edits of the same kind are grouped into a
+/// single undo step.
+const COALESCE: Duration = Duration::from_millis(1000);
+
+/// The maximum amount of undo steps kept around.
+const MAX_ENTRIES: usize = 100;
+
+/// The undo and redo stacks of a text input.
+#[derive(Debug, Clone, Default)]
+pub struct History {
+    undo: Vec<Entry>,
+    redo: Vec<Entry>,
+    group: Option<(Kind, Instant)>,
+}
+
+/// A snapshot of a text input right before an edit.
+#[derive(Debug, Clone)]
+pub struct Entry {
+    pub value: Value,
+    pub cursor: Cursor,
+}
+
+/// The kind of an edit, used to coalesce bursts of typing or deleting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Kind {
+    Insert,
+    Delete,
+    Other,
+}
+
+impl History {
+    /// Records an edit that turned `before` into `after`.
+    ///
+    /// Edits of the same kind following each other quickly are merged into
+    /// the step that is already open.
+    pub fn record(
+        &mut self,
+        kind: Kind,
+        before: Entry,
+        after: &Value,
+        now: Instant,
+    ) {
+        if &before.value == after {
+            return;
+        }
+
+        let is_coalesced = match self.group {
+            Some((group, at)) => {
+                group == kind && kind != Kind::Other && now - at < COALESCE
+            }
+            None => false,
+        };
+
+        if !is_coalesced {
+            self.undo.push(before);
+
+            if self.undo.len() > MAX_ENTRIES {
+                let _ = self.undo.remove(0);
+            }
+        }
+
+        self.redo.clear();
+        self.group = Some((kind, now));
+    }
+
+    /// Reverts the last step, given the `current` contents of the input.
+    pub fn undo(&mut self, current: Entry) -> Option<Entry> {
+        let entry = self.undo.pop()?;
+
+        self.redo.push(current);
+        self.group = None;
+
+        Some(entry)
+    }
+
+    /// Reapplies the last reverted step, given the `current` contents of the
+    /// input.
+    pub fn redo(&mut self, current: Entry) -> Option<Entry> {
+        let entry = self.redo.pop()?;
+
+        self.undo.push(current);
+        self.group = None;
+
+        Some(entry)
+    }
+
+    /// Closes the step that is currently open, so the next edit starts a new
+    /// one.
+    pub fn seal(&mut self) {
+        self.group = None;
+    }
+
+    /// Forgets every recorded step.
+    pub fn clear(&mut self) {
+        self.undo.clear();
+        self.redo.clear();
+        self.group = None;
+    }
+}
@@ -0,0 +1,218 @@
+//! Constrain the value of a text input to a pattern.
+
+/// A pattern that the value of a text input must follow, like a phone number
+/// or a date.
+///
+/// Every character of the pattern is either a slot or a literal:
+///
+/// - `9` accepts any digit.
+/// - `a` accepts any letter.
+/// - `*` accepts any letter or digit.
+/// - `\` makes the next character a literal, even if it is a slot.
+/// - Anything else is a literal, which is inserted automatically.
+///
+/// # Example
+/// ```
+/// # use iced_native::widget::text_input::Mask;
+/// let phone = Mask::new("(999) 999-9999");
+///
+/// assert_eq!(phone.apply("5551234"), "(555) 123-4");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mask {
+    slots: Vec<Slot>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Slot {
+    Digit,
+    Letter,
+    Alphanumeric,
+    Literal(char),
+}
+
+impl Slot {
+    fn accepts(self, c: char) -> bool {
+        match self {
+            Slot::Digit => c.is_ascii_digit(),
+            Slot::Letter => c.is_alphabetic(),
+            Slot::Alphanumeric => c.is_alphanumeric(),
+            Slot::Literal(_) => false,
+        }
+    }
+
+    fn is_literal(self) -> bool {
+        matches!(self, Slot::Literal(_))
+    }
+}
+
+impl Mask {
+    /// Creates a new [`Mask`] from the given pattern.
+    pub fn new(pattern: &str) -> Self {
+        let mut slots = Vec::new();
+        let mut chars = pattern.chars();
+
+        while let Some(c) = chars.next() {
+            slots.push(match c {
+                '9' => Slot::Digit,
+                'a' => Slot::Letter,
+                '*' => Slot::Alphanumeric,
+                '\\' => match chars.next() {
+                    Some(escaped) => Slot::Literal(escaped),
+                    None => Slot::Literal('\\'),
+                },
+                _ => Slot::Literal(c),
+            });
+        }
+
+        Self { slots }
+    }
+
+    /// Returns true if the given value fills every slot of the [`Mask`].
+    pub fn is_complete(&self, value: &str) -> bool {
+        let (data, _) = self.extract(value, 0);
+
+        data.len()
+            == self.slots.iter().filter(|slot| !slot.is_literal()).count()
+    }
+
+    /// Formats the given value according to the [`Mask`].
+    ///
+    /// Characters that do not fit any slot are dropped.
+    pub fn apply(&self, value: &str) -> String {
+        self.format(value, 0).0
+    }
+
+    /// Formats the given value, mapping a cursor position in the original
+    /// value to a position in the formatted one.
+    ///
+    /// Positions are counted in characters.
+    pub(crate) fn format(&self, value: &str, cursor: usize) -> (String, usize) {
+        let (data, before_cursor) = self.extract(value, cursor);
+
+        let mut output = String::new();
+        let mut length = 0;
+        let mut position = 0;
+        let mut data = data.into_iter().enumerate().peekable();
+
+        for slot in &self.slots {
+            if data.peek().is_none() {
+                break;
+            }
+
+            match slot {
+                Slot::Literal(literal) => {
+                    output.push(*literal);
+                    length += 1;
+                }
+                _ => {
+                    let (index, c) = data.next().unwrap();
+
+                    output.push(c);
+                    length += 1;
+
+                    if index < before_cursor {
+                        position = length;
+                    }
+                }
+            }
+        }
+
+        (output, position)
+    }
+
+    /// Collects the characters of the value that fill a slot, skipping the
+    /// literals that a previous formatting inserted.
+    ///
+    /// Also returns how many of them come before the given cursor position.
+    fn extract(&self, value: &str, cursor: usize) -> (Vec<char>, usize) {
+        let mut data = Vec::new();
+        let mut before_cursor = 0;
+        let mut i = 0;
+
+        for (position, c) in value.chars().enumerate() {
+            if let Some(Slot::Literal(literal)) = self.slots.get(i) {
+                if *literal == c {
+                    i += 1;
+                    continue;
+                }
+            }
+
+            while let Some(Slot::Literal(_)) = self.slots.get(i) {
+                i += 1;
+            }
+
+            match self.slots.get(i) {
+                Some(slot) if slot.accepts(c) => {
+                    data.push(c);
+                    i += 1;
+
+                    if position < cursor {
+                        before_cursor += 1;
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        (data, before_cursor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_slots_and_literals() {
+        assert_eq!(
+            Mask::new("9a*-\\9\\").slots,
+            vec![
+                Slot::Digit,
+                Slot::Letter,
+                Slot::Alphanumeric,
+                Slot::Literal('-'),
+                Slot::Literal('9'),
+                Slot::Literal('\\'),
+            ]
+        );
+    }
+
+    #[test]
+    fn drops_characters_that_do_not_fit() {
+        let mask = Mask::new("aa-99");
+
+        assert_eq!(mask.apply("a1b2c3"), "ab-23");
+        assert_eq!(mask.apply("12"), "");
+        assert_eq!(mask.apply("ab123"), "ab-12");
+    }
+
+    #[test]
+    fn reformats_formatted_values() {
+        let date = Mask::new("99/99/9999");
+
+        assert_eq!(date.apply("01/02/2021"), "01/02/2021");
+        assert_eq!(date.apply("01/022021"), "01/02/2021");
+        assert_eq!(date.apply("0102"), "01/02");
+    }
+
+    #[test]
+    fn checks_completion() {
+        let phone = Mask::new("(999) 999-9999");
+
+        assert!(!phone.is_complete("(555) 123-4"));
+        assert!(phone.is_complete("(555) 123-4567"));
+        assert!(phone.is_complete("5551234567"));
+    }
+
+    #[test]
+    fn maps_the_cursor() {
+        let phone = Mask::new("(999) 999-9999");
+
+        // After typing the fourth digit, the cursor skips the literals
+        assert_eq!(phone.format("5551", 4), (String::from("(555) 1"), 7));
+
+        // Inserting a digit in an already formatted value
+        assert_eq!(phone.format("(55x5) 1", 3), (String::from("(555) 1"), 3));
+    }
+}
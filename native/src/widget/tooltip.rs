@@ -1,5 +1,8 @@
 //! Display a widget over another.
+//!
+//! A [`Tooltip`] has some local [`State`].
 use std::hash::Hash;
+use std::time::{Duration, Instant};
 
 use iced_core::Rectangle;
 
@@ -7,73 +10,93 @@ use crate::event;
 use crate::layout;
 use crate::mouse;
 use crate::renderer;
-use crate::text;
 use crate::widget::container;
-use crate::widget::text::Text;
+use crate::widget::Operation;
+use crate::window;
 use crate::{
     Clipboard, Element, Event, Hasher, Layout, Length, Padding, Point, Shell,
     Size, Vector, Widget,
 };
 
 /// An element to display a widget over another.
+///
+/// The tooltip can be any widget. It is shown after the cursor rests over
+/// the content for the show delay, and it is hidden once the cursor has been
+/// away from the content for the hide delay. Both delays are zero by default.
 #[allow(missing_debug_implementations)]
-pub struct Tooltip<'a, Message, Renderer: text::Renderer> {
+pub struct Tooltip<'a, Message, Renderer> {
+    state: &'a mut State,
     content: Element<'a, Message, Renderer>,
-    tooltip: Text<Renderer>,
+    tooltip: Element<'a, Message, Renderer>,
     position: Position,
-    style_sheet: Box<dyn container::StyleSheet + 'a>,
+    style_sheet: Option<Box<dyn container::StyleSheet + 'a>>,
     gap: u16,
     padding: u16,
+    max_width: u32,
+    show_delay: Duration,
+    hide_delay: Duration,
 }
 
 impl<'a, Message, Renderer> Tooltip<'a, Message, Renderer>
 where
-    Renderer: text::Renderer,
+    Renderer: crate::Renderer,
 {
     /// The default padding of a [`Tooltip`] drawn by this renderer.
     const DEFAULT_PADDING: u16 = 5;
 
-    /// Creates an empty [`Tooltip`].
-    ///
-    /// [`Tooltip`]: struct.Tooltip.html
+    /// Creates a new [`Tooltip`] with the given [`State`], content, tooltip
+    /// and [`Position`].
     pub fn new(
+        state: &'a mut State,
         content: impl Into<Element<'a, Message, Renderer>>,
-        tooltip: impl ToString,
+        tooltip: impl Into<Element<'a, Message, Renderer>>,
         position: Position,
     ) -> Self {
         Tooltip {
+            state,
             content: content.into(),
-            tooltip: Text::new(tooltip.to_string()),
+            tooltip: tooltip.into(),
             position,
-            style_sheet: Default::default(),
+            style_sheet: None,
             gap: 0,
             padding: Self::DEFAULT_PADDING,
+            max_width: u32::MAX,
+            show_delay: Duration::ZERO,
+            hide_delay: Duration::ZERO,
         }
     }
 
-    /// Sets the size of the text of the [`Tooltip`].
-    pub fn size(mut self, size: u16) -> Self {
-        self.tooltip = self.tooltip.size(size);
+    /// Sets the gap between the content and its [`Tooltip`].
+    pub fn gap(mut self, gap: u16) -> Self {
+        self.gap = gap;
         self
     }
 
-    /// Sets the font of the [`Tooltip`].
+    /// Sets the padding of the [`Tooltip`].
+    pub fn padding(mut self, padding: u16) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Sets the maximum width of the [`Tooltip`].
     ///
-    /// [`Font`]: Renderer::Font
-    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
-        self.tooltip = self.tooltip.font(font);
+    /// Any text in a wider tooltip is wrapped.
+    pub fn max_width(mut self, max_width: u32) -> Self {
+        self.max_width = max_width;
         self
     }
 
-    /// Sets the gap between the content and its [`Tooltip`].
-    pub fn gap(mut self, gap: u16) -> Self {
-        self.gap = gap;
+    /// Sets the time the cursor needs to rest over the content before the
+    /// [`Tooltip`] is shown.
+    pub fn show_delay(mut self, delay: Duration) -> Self {
+        self.show_delay = delay;
         self
     }
 
-    /// Sets the padding of the [`Tooltip`].
-    pub fn padding(mut self, padding: u16) -> Self {
-        self.padding = padding;
+    /// Sets the time the [`Tooltip`] stays visible after the cursor leaves
+    /// the content.
+    pub fn hide_delay(mut self, delay: Duration) -> Self {
+        self.hide_delay = delay;
         self
     }
 
@@ -82,7 +105,7 @@ where
         mut self,
         style_sheet: impl Into<Box<dyn container::StyleSheet + 'a>>,
     ) -> Self {
-        self.style_sheet = style_sheet.into();
+        self.style_sheet = Some(style_sheet.into());
         self
     }
 }
@@ -102,10 +125,38 @@ pub enum Position {
     Right,
 }
 
+/// The local state of a [`Tooltip`].
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct State {
+    phase: Phase,
+    cursor_position: Point,
+}
+
+impl State {
+    /// Creates a new [`State`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns whether the [`Tooltip`] is currently shown.
+    pub fn is_visible(&self) -> bool {
+        matches!(self.phase, Phase::Visible | Phase::Hiding(_))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+enum Phase {
+    #[default]
+    Hidden,
+    Showing(Instant),
+    Visible,
+    Hiding(Instant),
+}
+
 impl<'a, Message, Renderer> Widget<Message, Renderer>
     for Tooltip<'a, Message, Renderer>
 where
-    Renderer: text::Renderer,
+    Renderer: crate::Renderer,
 {
     fn width(&self) -> Length {
         self.content.width()
@@ -132,6 +183,31 @@ where
         clipboard: &mut dyn Clipboard,
         shell: &mut Shell<'_, Message>,
     ) -> event::Status {
+        let now = Instant::now();
+        let is_mouse_over = layout.bounds().contains(cursor_position);
+
+        if is_mouse_over {
+            self.state.cursor_position = cursor_position;
+        }
+
+        self.state.phase = match (self.state.phase, is_mouse_over) {
+            (Phase::Hidden, true) => Phase::Showing(now + self.show_delay),
+            (Phase::Showing(_), false) => Phase::Hidden,
+            (Phase::Visible, false) => Phase::Hiding(now + self.hide_delay),
+            (Phase::Hiding(_), true) => Phase::Visible,
+            (phase, _) => phase,
+        };
+
+        self.state.phase = match self.state.phase {
+            Phase::Showing(at) if at <= now => Phase::Visible,
+            Phase::Hiding(at) if at <= now => Phase::Hidden,
+            phase => phase,
+        };
+
+        if let Phase::Showing(at) | Phase::Hiding(at) = self.state.phase {
+            shell.request_redraw(window::RedrawRequest::At(at));
+        }
+
         self.content.widget.on_event(
             event,
             layout,
@@ -160,6 +236,11 @@ where
         cursor_position: Point,
         viewport: &Rectangle,
     ) {
+        let style_sheet = self
+            .style_sheet
+            .as_deref()
+            .unwrap_or(&inherited_style.theme);
+
         self.content.draw(
             renderer,
             inherited_style,
@@ -168,43 +249,46 @@ where
             viewport,
         );
 
-        let bounds = layout.bounds();
-
-        if bounds.contains(cursor_position) {
+        if self.state.is_visible() {
+            let bounds = layout.bounds();
             let gap = f32::from(self.gap);
-            let style = self.style_sheet.style();
+            let style = style_sheet.style();
 
             let defaults = renderer::Style {
                 text_color: style
                     .text_color
                     .unwrap_or(inherited_style.text_color),
+                theme: inherited_style.theme.clone(),
             };
 
-            let text_layout = Widget::<(), Renderer>::layout(
-                &self.tooltip,
+            let tooltip_layout = self.tooltip.layout(
                 renderer,
                 &layout::Limits::new(Size::ZERO, viewport.size())
+                    .max_width(self.max_width)
                     .pad(Padding::new(self.padding)),
             );
 
+            // The cursor may have left the content while the tooltip hides
+            let cursor = self.state.cursor_position;
+
             let padding = f32::from(self.padding);
-            let text_bounds = text_layout.bounds();
-            let x_center = bounds.x + (bounds.width - text_bounds.width) / 2.0;
+            let tooltip_size = tooltip_layout.size();
+            let x_center = bounds.x + (bounds.width - tooltip_size.width) / 2.0;
             let y_center =
-                bounds.y + (bounds.height - text_bounds.height) / 2.0;
+                bounds.y + (bounds.height - tooltip_size.height) / 2.0;
 
             let mut tooltip_bounds = {
                 let offset = match self.position {
                     Position::Top => Vector::new(
                         x_center,
-                        bounds.y - text_bounds.height - gap - padding,
+                        bounds.y - tooltip_size.height - gap - padding,
                     ),
                     Position::Bottom => Vector::new(
                         x_center,
                         bounds.y + bounds.height + gap + padding,
                     ),
                     Position::Left => Vector::new(
-                        bounds.x - text_bounds.width - gap - padding,
+                        bounds.x - tooltip_size.width - gap - padding,
                         y_center,
                     ),
                     Position::Right => Vector::new(
@@ -212,16 +296,16 @@ where
                         y_center,
                     ),
                     Position::FollowCursor => Vector::new(
-                        cursor_position.x,
-                        cursor_position.y - text_bounds.height,
+                        cursor.x,
+                        cursor.y - tooltip_size.height - gap - padding,
                     ),
                 };
 
                 Rectangle {
                     x: offset.x - padding,
                     y: offset.y - padding,
-                    width: text_bounds.width + padding * 2.0,
-                    height: text_bounds.height + padding * 2.0,
+                    width: tooltip_size.width + padding * 2.0,
+                    height: tooltip_size.height + padding * 2.0,
                 }
             };
 
@@ -235,7 +319,11 @@ where
             }
 
             if tooltip_bounds.y < viewport.y {
-                tooltip_bounds.y = viewport.y;
+                // A tooltip following the cursor moves below it instead
+                tooltip_bounds.y = match self.position {
+                    Position::FollowCursor => cursor.y + gap,
+                    _ => viewport.y,
+                };
             } else if viewport.y + viewport.height
                 < tooltip_bounds.y + tooltip_bounds.height
             {
@@ -246,8 +334,7 @@ where
             renderer.with_layer(*viewport, |renderer| {
                 container::draw_background(renderer, &style, tooltip_bounds);
 
-                Widget::<(), Renderer>::draw(
-                    &self.tooltip,
+                self.tooltip.draw(
                     renderer,
                     &defaults,
                     Layout::with_offset(
@@ -255,7 +342,7 @@ where
                             tooltip_bounds.x + padding,
                             tooltip_bounds.y + padding,
                         ),
-                        &text_layout,
+                        &tooltip_layout,
                     ),
                     cursor_position,
                     viewport,
@@ -270,12 +357,22 @@ where
 
         self.content.hash_layout(state);
     }
+
+    fn operate(
+        &mut self,
+        layout: Layout<'_>,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        operation.container(None, &mut |operation| {
+            self.content.operate(layout, operation);
+        });
+    }
 }
 
 impl<'a, Message, Renderer> From<Tooltip<'a, Message, Renderer>>
     for Element<'a, Message, Renderer>
 where
-    Renderer: 'a + text::Renderer,
+    Renderer: 'a + crate::Renderer,
     Message: 'a,
 {
     fn from(
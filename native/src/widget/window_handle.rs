@@ -0,0 +1,192 @@
+//! Move or resize the window by dragging some content.
+use std::hash::Hash;
+
+use crate::event::{self, Event};
+use crate::layout;
+use crate::mouse;
+use crate::overlay;
+use crate::renderer;
+use crate::touch;
+use crate::widget::Operation;
+use crate::window;
+use crate::{
+    Clipboard, Element, Hasher, Layout, Length, Point, Rectangle, Shell, Widget,
+};
+
+/// A widget that moves or resizes the window when its content is dragged.
+///
+/// It can be used to build a custom title bar and custom resize borders for
+/// a window without decorations.
+///
+/// The presses captured by the content do not affect the window. For
+/// instance, the buttons of a custom title bar keep working as usual.
+#[allow(missing_debug_implementations)]
+pub struct WindowHandle<'a, Message, Renderer> {
+    content: Element<'a, Message, Renderer>,
+    resize: Option<window::ResizeDirection>,
+}
+
+impl<'a, Message, Renderer> WindowHandle<'a, Message, Renderer> {
+    /// Creates a new [`WindowHandle`] that moves the window when the given
+    /// content is dragged.
+    pub fn new(content: impl Into<Element<'a, Message, Renderer>>) -> Self {
+        WindowHandle {
+            content: content.into(),
+            resize: None,
+        }
+    }
+
+    /// Makes the [`WindowHandle`] resize the window from the given
+    /// [`window::ResizeDirection`], instead of moving it.
+    pub fn resize(mut self, direction: window::ResizeDirection) -> Self {
+        self.resize = Some(direction);
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for WindowHandle<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+{
+    fn width(&self) -> Length {
+        self.content.width()
+    }
+
+    fn height(&self) -> Length {
+        self.content.height()
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content.layout(renderer, limits)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        if let event::Status::Captured = self.content.on_event(
+            event.clone(),
+            layout,
+            cursor_position,
+            renderer,
+            clipboard,
+            shell,
+        ) {
+            return event::Status::Captured;
+        }
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if !layout.bounds().contains(cursor_position) {
+                    return event::Status::Ignored;
+                }
+
+                shell.request_window_action(match self.resize {
+                    Some(direction) => window::Action::DragResize(direction),
+                    None => window::Action::Drag,
+                });
+
+                event::Status::Captured
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) -> mouse::Interaction {
+        let content_interaction =
+            self.content
+                .mouse_interaction(layout, cursor_position, viewport);
+
+        if content_interaction != mouse::Interaction::Idle
+            || !layout.bounds().contains(cursor_position)
+        {
+            return content_interaction;
+        }
+
+        match self.resize {
+            Some(window::ResizeDirection::North)
+            | Some(window::ResizeDirection::South) => {
+                mouse::Interaction::ResizingVertically
+            }
+            Some(window::ResizeDirection::East)
+            | Some(window::ResizeDirection::West) => {
+                mouse::Interaction::ResizingHorizontally
+            }
+            Some(window::ResizeDirection::NorthEast)
+            | Some(window::ResizeDirection::SouthWest) => {
+                mouse::Interaction::ResizingDiagonallyUp
+            }
+            Some(window::ResizeDirection::NorthWest)
+            | Some(window::ResizeDirection::SouthEast) => {
+                mouse::Interaction::ResizingDiagonallyDown
+            }
+            None => content_interaction,
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        self.content
+            .draw(renderer, style, layout, cursor_position, viewport);
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.content.hash_layout(state);
+    }
+
+    fn operate(
+        &mut self,
+        layout: Layout<'_>,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        operation.container(None, &mut |operation| {
+            self.content.operate(layout, operation);
+        });
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+        viewport: &Rectangle,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        self.content.overlay(layout, viewport)
+    }
+}
+
+impl<'a, Message, Renderer> From<WindowHandle<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + crate::Renderer,
+{
+    fn from(
+        handle: WindowHandle<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(handle)
+    }
+}
@@ -0,0 +1,365 @@
+//! Distribute content horizontally, wrapping it into multiple lines.
+use std::hash::Hash;
+
+use crate::event::{self, Event};
+use crate::layout;
+use crate::mouse;
+use crate::overlay;
+use crate::renderer;
+use crate::widget::Operation;
+use crate::{
+    Alignment, Clipboard, Element, Hasher, Layout, Length, Padding, Point,
+    Rectangle, Shell, Size, Widget,
+};
+
+/// A container that distributes its contents horizontally and wraps them
+/// into a new line when they do not fit in the available width.
+#[allow(missing_debug_implementations)]
+pub struct Wrap<'a, Message, Renderer> {
+    spacing: u16,
+    line_spacing: u16,
+    padding: Padding,
+    width: Length,
+    height: Length,
+    max_width: u32,
+    align_items: Alignment,
+    line_alignment: Alignment,
+    children: Vec<Element<'a, Message, Renderer>>,
+}
+
+impl<'a, Message, Renderer> Wrap<'a, Message, Renderer> {
+    /// Creates an empty [`Wrap`].
+    pub fn new() -> Self {
+        Self::with_children(Vec::new())
+    }
+
+    /// Creates a [`Wrap`] with the given elements.
+    pub fn with_children(
+        children: Vec<Element<'a, Message, Renderer>>,
+    ) -> Self {
+        Wrap {
+            spacing: 0,
+            line_spacing: 0,
+            padding: Padding::ZERO,
+            width: Length::Shrink,
+            height: Length::Shrink,
+            max_width: u32::MAX,
+            align_items: Alignment::Start,
+            line_alignment: Alignment::Start,
+            children,
+        }
+    }
+
+    /// Sets the horizontal spacing _between_ elements of the same line.
+    pub fn spacing(mut self, units: u16) -> Self {
+        self.spacing = units;
+        self
+    }
+
+    /// Sets the vertical spacing _between_ lines.
+    pub fn line_spacing(mut self, units: u16) -> Self {
+        self.line_spacing = units;
+        self
+    }
+
+    /// Sets the [`Padding`] of the [`Wrap`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the width of the [`Wrap`].
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`Wrap`].
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the maximum width of the [`Wrap`].
+    pub fn max_width(mut self, max_width: u32) -> Self {
+        self.max_width = max_width;
+        self
+    }
+
+    /// Sets the vertical alignment of the elements of a line of the
+    /// [`Wrap`].
+    pub fn align_items(mut self, align: Alignment) -> Self {
+        self.align_items = align;
+        self
+    }
+
+    /// Sets the horizontal alignment of every line of the [`Wrap`].
+    ///
+    /// [`Alignment::Fill`] distributes the remaining space of every line,
+    /// except the last one, between its elements.
+    pub fn line_alignment(mut self, align: Alignment) -> Self {
+        self.line_alignment = align;
+        self
+    }
+
+    /// Adds an element to the [`Wrap`].
+    pub fn push<E>(mut self, child: E) -> Self
+    where
+        E: Into<Element<'a, Message, Renderer>>,
+    {
+        self.children.push(child.into());
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Default for Wrap<'a, Message, Renderer> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Wrap<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits
+            .max_width(self.max_width)
+            .width(self.width)
+            .height(self.height)
+            .pad(self.padding);
+
+        let max_width = limits.max().width;
+        let spacing = f32::from(self.spacing);
+        let line_spacing = f32::from(self.line_spacing);
+        let child_limits = layout::Limits::new(Size::ZERO, limits.max())
+            .with_direction(limits.direction());
+
+        let mut nodes: Vec<layout::Node> = self
+            .children
+            .iter()
+            .map(|child| child.layout(renderer, &child_limits))
+            .collect();
+
+        // Break the children into lines
+        let mut lines: Vec<std::ops::Range<usize>> = Vec::new();
+        let mut start = 0;
+        let mut x = 0.0;
+
+        for (i, node) in nodes.iter().enumerate() {
+            let width = node.size().width;
+
+            if i > start && x + width > max_width {
+                lines.push(start..i);
+
+                start = i;
+                x = 0.0;
+            }
+
+            x += width + spacing;
+        }
+
+        if start < nodes.len() {
+            lines.push(start..nodes.len());
+        }
+
+        let line_widths: Vec<f32> = lines
+            .iter()
+            .map(|line| {
+                nodes[line.clone()]
+                    .iter()
+                    .map(|node| node.size().width)
+                    .sum::<f32>()
+                    + spacing * (line.len() - 1) as f32
+            })
+            .collect();
+
+        let content_width = if self.width == Length::Shrink {
+            line_widths.iter().copied().fold(0.0, f32::max)
+        } else {
+            max_width
+        };
+
+        let mut y = 0.0;
+
+        for (index, (line, line_width)) in
+            lines.iter().zip(line_widths).enumerate()
+        {
+            let free = (content_width - line_width).max(0.0);
+            let is_last = index + 1 == lines.len();
+
+            let (mut x, gap) = match self.line_alignment {
+                Alignment::Start | Alignment::Baseline => (0.0, spacing),
+                Alignment::Center => (free / 2.0, spacing),
+                Alignment::End => (free, spacing),
+                Alignment::Fill if is_last || line.len() < 2 => (0.0, spacing),
+                Alignment::Fill => {
+                    (0.0, spacing + free / (line.len() - 1) as f32)
+                }
+            };
+
+            let line_height = nodes[line.clone()]
+                .iter()
+                .map(|node| node.size().height)
+                .fold(0.0, f32::max);
+
+            for node in &mut nodes[line.clone()] {
+                let width = node.size().width;
+
+                node.move_to(Point::new(
+                    x + f32::from(self.padding.left),
+                    y + f32::from(self.padding.top),
+                ));
+
+                node.align(
+                    Alignment::Start,
+                    self.align_items,
+                    Size::new(width, line_height),
+                );
+
+                x += width + gap;
+            }
+
+            y += line_height + line_spacing;
+        }
+
+        let content_height = (y - line_spacing).max(0.0);
+
+        let size = limits
+            .resolve(Size::new(content_width, content_height))
+            .pad(self.padding);
+
+        let mut node = layout::Node::with_children(size, nodes);
+        node.mirror(limits.direction());
+
+        node
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        self.children
+            .iter_mut()
+            .zip(layout.children())
+            .map(|(child, layout)| {
+                child.widget.on_event(
+                    event.clone(),
+                    layout,
+                    cursor_position,
+                    renderer,
+                    clipboard,
+                    shell,
+                )
+            })
+            .fold(event::Status::Ignored, event::Status::merge)
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) -> mouse::Interaction {
+        self.children
+            .iter()
+            .zip(layout.children())
+            .map(|(child, layout)| {
+                child.widget.mouse_interaction(
+                    layout,
+                    cursor_position,
+                    viewport,
+                )
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        for (child, layout) in self.children.iter().zip(layout.children()) {
+            child.draw(renderer, style, layout, cursor_position, viewport);
+        }
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.width.hash(state);
+        self.height.hash(state);
+        self.max_width.hash(state);
+        self.align_items.hash(state);
+        self.line_alignment.hash(state);
+        self.spacing.hash(state);
+        self.line_spacing.hash(state);
+        self.padding.hash(state);
+
+        for child in &self.children {
+            child.widget.hash_layout(state);
+        }
+    }
+
+    fn operate(
+        &mut self,
+        layout: Layout<'_>,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        operation.container(None, &mut |operation| {
+            self.children.iter_mut().zip(layout.children()).for_each(
+                |(child, layout)| {
+                    child.operate(layout, operation);
+                },
+            )
+        });
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+        viewport: &Rectangle,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        overlay::from_children(
+            self.children.iter_mut().zip(layout.children()).filter_map(
+                |(child, layout)| child.widget.overlay(layout, viewport),
+            ),
+        )
+    }
+}
+
+impl<'a, Message, Renderer> From<Wrap<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + crate::Renderer,
+    Message: 'a,
+{
+    fn from(
+        wrap: Wrap<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(wrap)
+    }
+}
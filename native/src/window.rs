@@ -1,6 +1,30 @@
 //! Build window-based GUI applications.
 mod action;
+mod color_scheme;
 mod event;
+mod frame;
+mod id;
+mod level;
+mod monitor;
+mod position;
+mod present_mode;
+mod redraw_request;
+mod resize_direction;
+mod screenshot;
+mod settings;
+mod user_attention;
 
 pub use action::Action;
+pub use color_scheme::ColorScheme;
 pub use event::Event;
+pub use frame::Frame;
+pub use id::Id;
+pub use level::Level;
+pub use monitor::Monitor;
+pub use position::Position;
+pub use present_mode::PresentMode;
+pub use redraw_request::RedrawRequest;
+pub use resize_direction::ResizeDirection;
+pub use screenshot::Screenshot;
+pub use settings::Settings;
+pub use user_attention::UserAttention;
@@ -1,6 +1,26 @@
+use crate::image;
+use crate::window::{
+    Id, Level, Monitor, Position, PresentMode, ResizeDirection, Settings,
+    UserAttention,
+};
+
 /// An operation to be performed on some window.
+///
+/// In an application with multiple windows, the actions that do not carry a
+/// window [`Id`] target the main window.
 #[derive(Debug)]
 pub enum Action {
+    /// Open a new window.
+    ///
+    /// It is only supported by the runtimes that can handle multiple windows.
+    Open {
+        /// The [`Id`] of the new window
+        id: Id,
+        /// The [`Settings`] of the new window
+        settings: Settings,
+    },
+    /// Close the window with the given [`Id`].
+    Close(Id),
     /// Resize the window.
     Resize {
         /// The new logical width of the window
@@ -15,4 +35,76 @@ pub enum Action {
         /// The new logical y location of the window
         y: i32,
     },
+    /// Move the window to the given [`Monitor`], placing it at the given
+    /// [`Position`] relative to the monitor.
+    MoveToMonitor {
+        /// The target [`Monitor`]
+        monitor: Monitor,
+        /// The [`Position`] of the window in the monitor
+        position: Position,
+    },
+    /// Make the window take the whole given [`Monitor`], changing its video
+    /// mode exclusively.
+    ///
+    /// When `None`, the window leaves fullscreen.
+    Fullscreen(Option<Monitor>),
+    /// Set the minimum logical size of the window.
+    ///
+    /// When `None`, the size of the window is not limited.
+    SetMinSize(Option<(u32, u32)>),
+    /// Set the maximum logical size of the window.
+    ///
+    /// When `None`, the size of the window is not limited.
+    SetMaxSize(Option<(u32, u32)>),
+    /// Set whether the window can be resized by the user or not.
+    SetResizable(bool),
+    /// Set whether the window should have a border, a title bar, etc.
+    SetDecorations(bool),
+    /// Set the stacking [`Level`] of the window.
+    SetLevel(Level),
+    /// Minimize the window.
+    Minimize,
+    /// Maximize the window.
+    Maximize,
+    /// Restore the window after it was minimized or maximized.
+    Restore,
+    /// Set the icon of the window, which is usually also shown in the
+    /// taskbar.
+    ///
+    /// When `None`, the default icon of the platform is used.
+    SetIcon(Option<image::Handle>),
+    /// Set the progress shown in the taskbar or dock entry of the window,
+    /// from `0.0` to `1.0`.
+    ///
+    /// When `None`, the progress is hidden.
+    SetProgress(Option<f32>),
+    /// Request the [`UserAttention`] of the user.
+    ///
+    /// When `None`, a previous request is cancelled.
+    RequestAttention(Option<UserAttention>),
+    /// Start moving the window with the cursor.
+    ///
+    /// The window follows the cursor until the mouse button is released.
+    Drag,
+    /// Start resizing the window with the cursor, from the given
+    /// [`ResizeDirection`].
+    ///
+    /// The window is resized until the mouse button is released.
+    DragResize(ResizeDirection),
+    /// Change the [`PresentMode`] of the windows of the application.
+    ///
+    /// It is ignored by renderers that cannot change it at runtime.
+    SetPresentMode(PresentMode),
+    /// Limit the amount of frames presented per second by the windows of the
+    /// application.
+    ///
+    /// When `None`, frames are presented as fast as the [`PresentMode`]
+    /// allows.
+    SetFrameRateCap(Option<u16>),
+    /// Set whether the window with the given [`Id`] is redrawn continuously,
+    /// once per frame, instead of only when an event or a message is
+    /// processed.
+    SetContinuousRendering(Id, bool),
+    /// Show or hide the software keyboard of the platform, if any.
+    SetSoftKeyboardVisible(bool),
 }
@@ -0,0 +1,9 @@
+/// The color scheme preferred by the user of the operating system.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ColorScheme {
+    /// Dark text on light backgrounds.
+    Light,
+
+    /// Light text on dark backgrounds.
+    Dark,
+}
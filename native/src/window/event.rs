@@ -1,4 +1,8 @@
+use crate::window::ColorScheme;
+use crate::Padding;
+
 use std::path::PathBuf;
+use std::time::Instant;
 
 /// A window-related event.
 #[derive(PartialEq, Clone, Debug)]
@@ -31,6 +35,26 @@ pub enum Event {
     /// A window was unfocused.
     Unfocused,
 
+    /// A window was minimized.
+    Minimized,
+
+    /// A window was restored after being minimized.
+    Restored,
+
+    /// A window was hidden from the user, or shown again.
+    ///
+    /// This happens when the application is suspended by the platform; for
+    /// instance, when it is sent to the background on a mobile device.
+    Occluded(bool),
+
+    /// The safe area of a window changed.
+    ///
+    /// It carries the logical insets of the parts of the window that are
+    /// covered by system bars, notches, or rounded corners of the display;
+    /// like in mobile devices. The contents of the window are laid out
+    /// inside of the safe area, so they stay visible and interactive.
+    SafeAreaChanged(Padding),
+
     /// A file is being hovered over the window.
     ///
     /// When the user hovers multiple files at once, this event will be emitted
@@ -48,4 +72,23 @@ pub enum Event {
     /// There will be a single `FilesHoveredLeft` event triggered even if
     /// multiple files were hovered.
     FilesHoveredLeft,
+
+    /// A window is about to be redrawn, as requested by a widget with a
+    /// [`RedrawRequest`].
+    ///
+    /// It carries the time of the redraw, which should be used to advance
+    /// animations.
+    ///
+    /// [`RedrawRequest`]: crate::window::RedrawRequest
+    RedrawRequested(Instant),
+
+    /// A frame of the window has been presented on the screen.
+    ///
+    /// It carries the time of the presentation. This event is only delivered
+    /// to subscriptions.
+    FramePresented(Instant),
+
+    /// The color scheme preferred by the user of the operating system has
+    /// changed.
+    ColorSchemeChanged(ColorScheme),
 }
@@ -0,0 +1,18 @@
+use crate::window::Id;
+
+use std::time::{Duration, Instant};
+
+/// A frame presented on the screen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Frame {
+    /// The [`Id`] of the window that presented the [`Frame`].
+    pub window: Id,
+
+    /// The time the [`Frame`] was presented.
+    pub at: Instant,
+
+    /// The time elapsed since the previous [`Frame`] of the same window.
+    ///
+    /// It is zero for the first [`Frame`].
+    pub delta: Duration,
+}
@@ -0,0 +1,13 @@
+/// The stacking level of a window, relative to the other windows of the
+/// desktop.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Level {
+    /// The window is stacked like any other window.
+    Normal,
+
+    /// The window is always on top of the other windows.
+    AlwaysOnTop,
+
+    /// The window is always below the other windows.
+    AlwaysOnBottom,
+}
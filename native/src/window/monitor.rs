@@ -0,0 +1,23 @@
+/// A monitor connected to the system.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Monitor {
+    /// The name of the monitor, if available.
+    pub name: Option<String>,
+
+    /// The physical position of the top-left corner of the monitor, relative
+    /// to the whole desktop.
+    pub position: (i32, i32),
+
+    /// The physical size of the monitor.
+    pub size: (u32, u32),
+
+    /// The scale factor of the monitor.
+    pub scale_factor: f64,
+
+    /// The highest refresh rate supported by the monitor at its current
+    /// size, in hertz, if available.
+    pub refresh_rate: Option<u16>,
+
+    /// Whether the monitor is the primary monitor of the system or not.
+    pub is_primary: bool,
+}
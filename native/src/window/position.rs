@@ -1,7 +1,8 @@
 /// The position of a window in a given screen.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum Position {
     /// The platform-specific default position for a new window.
+    #[default]
     Default,
     /// The window is completely centered on the screen.
     Centered,
@@ -14,9 +15,3 @@ pub enum Position {
     /// `(PADDING_X, PADDING_Y)`.
     Specific(i32, i32),
 }
-
-impl Default for Position {
-    fn default() -> Self {
-        Self::Default
-    }
-}
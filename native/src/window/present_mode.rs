@@ -0,0 +1,24 @@
+/// The strategy used to present the frames of a window to the display.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum PresentMode {
+    /// Frames are presented in sync with the refresh of the display, waiting
+    /// when they are produced faster than they can be shown.
+    ///
+    /// This is commonly known as vsync. It never tears and keeps the power
+    /// usage low, at the expense of some latency.
+    Fifo,
+
+    /// Frames are presented in sync with the refresh of the display, but a
+    /// newer frame replaces a pending one instead of waiting.
+    ///
+    /// It never tears and has lower latency than [`PresentMode::Fifo`], but
+    /// the application may produce frames that are never shown.
+    #[default]
+    Mailbox,
+
+    /// Frames are presented as soon as possible, without waiting for the
+    /// refresh of the display.
+    ///
+    /// It has the lowest latency, but tearing may be visible.
+    Immediate,
+}
@@ -0,0 +1,22 @@
+use std::time::Instant;
+
+/// A request to redraw a window.
+///
+/// Widgets use it to keep animating without any input from the user, or to
+/// wake up exactly when they need to change; like the blinking cursor of a
+/// text input. The earliest request produced while processing some events
+/// wins, and the event loop sleeps until then.
+///
+/// Applications can schedule a redraw by wrapping some content in an
+/// [`Animated`] widget; for instance, to rebuild the view when a notification
+/// times out.
+///
+/// [`Animated`]: crate::widget::Animated
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum RedrawRequest {
+    /// Redraw the next frame.
+    NextFrame,
+
+    /// Redraw at the given time.
+    At(Instant),
+}
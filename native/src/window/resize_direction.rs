@@ -0,0 +1,27 @@
+/// The edge or corner of a window that is dragged to resize it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ResizeDirection {
+    /// The top edge.
+    North,
+
+    /// The bottom edge.
+    South,
+
+    /// The right edge.
+    East,
+
+    /// The left edge.
+    West,
+
+    /// The top-right corner.
+    NorthEast,
+
+    /// The top-left corner.
+    NorthWest,
+
+    /// The bottom-right corner.
+    SouthEast,
+
+    /// The bottom-left corner.
+    SouthWest,
+}
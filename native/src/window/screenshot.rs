@@ -0,0 +1,38 @@
+use crate::Size;
+
+use std::fmt;
+use std::sync::Arc;
+
+/// The contents of a window captured with a screenshot.
+#[derive(Clone)]
+pub struct Screenshot {
+    bytes: Arc<Vec<u8>>,
+    size: Size<u32>,
+}
+
+impl Screenshot {
+    /// Creates a new [`Screenshot`] with the given RGBA pixels and physical
+    /// [`Size`].
+    pub fn new(bytes: Vec<u8>, size: Size<u32>) -> Self {
+        Self {
+            bytes: Arc::new(bytes),
+            size,
+        }
+    }
+
+    /// Returns the pixels of the [`Screenshot`] in RGBA order, row by row.
+    pub fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Returns the physical [`Size`] of the [`Screenshot`].
+    pub fn size(&self) -> Size<u32> {
+        self.size
+    }
+}
+
+impl fmt::Debug for Screenshot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Screenshot({} * {})", self.size.width, self.size.height)
+    }
+}
@@ -0,0 +1,15 @@
+/// The kind of attention a window requests from the user.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum UserAttention {
+    /// The window needs the attention of the user urgently, like an
+    /// incoming call.
+    ///
+    /// Some platforms keep signaling the window until it is focused.
+    Critical,
+
+    /// The window has some information for the user, like a finished
+    /// download.
+    ///
+    /// Some platforms signal the window only once.
+    Informational,
+}
@@ -0,0 +1,45 @@
+//! Access the clipboard.
+use sdl2::clipboard::ClipboardUtil;
+use sdl2::VideoSubsystem;
+
+/// A buffer for short-term storage and transfer within and between
+/// applications.
+#[allow(missing_debug_implementations)]
+pub struct Clipboard {
+    util: ClipboardUtil,
+}
+
+impl Clipboard {
+    /// Creates a new [`Clipboard`] for the given video subsystem.
+    pub fn connect(video: &VideoSubsystem) -> Clipboard {
+        Clipboard {
+            util: video.clipboard(),
+        }
+    }
+
+    /// Reads the current content of the [`Clipboard`] as text.
+    pub fn read(&self) -> Option<String> {
+        if !self.util.has_clipboard_text() {
+            return None;
+        }
+
+        self.util.clipboard_text().ok()
+    }
+
+    /// Writes the given text contents to the [`Clipboard`].
+    pub fn write(&mut self, contents: String) {
+        if let Err(error) = self.util.set_clipboard_text(&contents) {
+            log::warn!("error writing to clipboard: {}", error)
+        }
+    }
+}
+
+impl iced_native::Clipboard for Clipboard {
+    fn read(&self) -> Option<String> {
+        self.read()
+    }
+
+    fn write(&mut self, contents: String) {
+        self.write(contents)
+    }
+}
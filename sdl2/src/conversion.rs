@@ -0,0 +1,413 @@
+//! Convert [`sdl2`] types into [`iced_native`] types, and viceversa.
+//!
+//! [`sdl2`]: https://github.com/Rust-SDL2/rust-sdl2
+//! [`iced_native`]: https://github.com/hecrj/iced/tree/master/native
+use crate::keyboard;
+use crate::mouse;
+use crate::touch;
+use crate::window;
+use crate::{Event, Point, Size};
+
+use sdl2::event::WindowEvent;
+use sdl2::keyboard::{Keycode, Mod};
+use sdl2::mouse::{MouseButton, MouseWheelDirection, SystemCursor};
+use std::convert::TryFrom;
+use std::ops::Range;
+
+/// The id SDL2 gives to the mouse events it synthesizes from touch events.
+const TOUCH_MOUSE_ID: u32 = u32::MAX;
+
+/// Converts an [`sdl2`] event into an iced event.
+///
+/// The `size` is the logical size of the window the event was produced in,
+/// used to place the normalized positions of touch events.
+///
+/// Keyboard modifiers and text input are translated by the [`Host`], since
+/// they may produce more than one event.
+///
+/// [`sdl2`]: https://github.com/Rust-SDL2/rust-sdl2
+/// [`Host`]: crate::Host
+pub fn event(event: &sdl2::event::Event, size: Size) -> Option<Event> {
+    use sdl2::event::Event as SdlEvent;
+
+    match event {
+        SdlEvent::Window { win_event, .. } => match *win_event {
+            WindowEvent::SizeChanged(width, height) => {
+                Some(Event::Window(window::Event::Resized {
+                    width: width.max(0) as u32,
+                    height: height.max(0) as u32,
+                }))
+            }
+            WindowEvent::Moved(x, y) => {
+                Some(Event::Window(window::Event::Moved { x, y }))
+            }
+            WindowEvent::Close => {
+                Some(Event::Window(window::Event::CloseRequested))
+            }
+            WindowEvent::FocusGained => {
+                Some(Event::Window(window::Event::Focused))
+            }
+            WindowEvent::FocusLost => {
+                Some(Event::Window(window::Event::Unfocused))
+            }
+            WindowEvent::Minimized => {
+                Some(Event::Window(window::Event::Minimized))
+            }
+            WindowEvent::Restored => {
+                Some(Event::Window(window::Event::Restored))
+            }
+            WindowEvent::Enter => {
+                Some(Event::Mouse(mouse::Event::CursorEntered))
+            }
+            WindowEvent::Leave => Some(Event::Mouse(mouse::Event::CursorLeft)),
+            _ => None,
+        },
+        SdlEvent::DropFile { filename, .. } => {
+            Some(Event::Window(window::Event::FileDropped(filename.into())))
+        }
+        SdlEvent::KeyDown {
+            keycode: Some(keycode),
+            keymod,
+            ..
+        } => key_code(*keycode).map(|key_code| {
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code,
+                modifiers: modifiers(*keymod),
+            })
+        }),
+        SdlEvent::KeyUp {
+            keycode: Some(keycode),
+            keymod,
+            ..
+        } => key_code(*keycode).map(|key_code| {
+            Event::Keyboard(keyboard::Event::KeyReleased {
+                key_code,
+                modifiers: modifiers(*keymod),
+            })
+        }),
+        // Touch input is reported by the finger events instead
+        SdlEvent::MouseMotion { which, .. }
+        | SdlEvent::MouseButtonDown { which, .. }
+        | SdlEvent::MouseButtonUp { which, .. }
+        | SdlEvent::MouseWheel { which, .. }
+            if *which == TOUCH_MOUSE_ID =>
+        {
+            None
+        }
+        SdlEvent::MouseMotion { x, y, .. } => {
+            Some(Event::Mouse(mouse::Event::CursorMoved {
+                position: Point::new(*x as f32, *y as f32),
+            }))
+        }
+        SdlEvent::MouseButtonDown { mouse_btn, .. } => Some(Event::Mouse(
+            mouse::Event::ButtonPressed(mouse_button(*mouse_btn)),
+        )),
+        SdlEvent::MouseButtonUp { mouse_btn, .. } => Some(Event::Mouse(
+            mouse::Event::ButtonReleased(mouse_button(*mouse_btn)),
+        )),
+        SdlEvent::MouseWheel {
+            x, y, direction, ..
+        } => {
+            let sign = match direction {
+                MouseWheelDirection::Flipped => -1.0,
+                _ => 1.0,
+            };
+
+            Some(Event::Mouse(mouse::Event::WheelScrolled {
+                delta: mouse::ScrollDelta::Lines {
+                    x: sign * *x as f32,
+                    y: sign * *y as f32,
+                },
+            }))
+        }
+        SdlEvent::FingerDown {
+            finger_id, x, y, ..
+        } => Some(Event::Touch(touch::Event::FingerPressed {
+            id: finger(*finger_id),
+            position: finger_position(*x, *y, size),
+        })),
+        SdlEvent::FingerMotion {
+            finger_id, x, y, ..
+        } => Some(Event::Touch(touch::Event::FingerMoved {
+            id: finger(*finger_id),
+            position: finger_position(*x, *y, size),
+        })),
+        SdlEvent::FingerUp {
+            finger_id, x, y, ..
+        } => Some(Event::Touch(touch::Event::FingerLifted {
+            id: finger(*finger_id),
+            position: finger_position(*x, *y, size),
+        })),
+        _ => None,
+    }
+}
+
+fn finger(id: i64) -> touch::Finger {
+    touch::Finger(id as u64)
+}
+
+fn finger_position(x: f32, y: f32, size: Size) -> Point {
+    Point::new(x * size.width, y * size.height)
+}
+
+/// Converts a `mouse::Interaction` from [`iced_native`] to an [`sdl2`]
+/// system cursor.
+///
+/// Returns `None` for [`mouse::Interaction::Hidden`], since SDL2 hides the
+/// cursor instead.
+///
+/// [`sdl2`]: https://github.com/Rust-SDL2/rust-sdl2
+/// [`iced_native`]: https://github.com/hecrj/iced/tree/master/native
+pub fn mouse_interaction(
+    interaction: mouse::Interaction,
+) -> Option<SystemCursor> {
+    use mouse::Interaction;
+
+    Some(match interaction {
+        Interaction::Idle
+        | Interaction::Help
+        | Interaction::ContextMenu
+        | Interaction::Copy
+        | Interaction::Alias
+        | Interaction::ZoomIn
+        | Interaction::ZoomOut => SystemCursor::Arrow,
+        Interaction::Pointer | Interaction::Grab | Interaction::Grabbing => {
+            SystemCursor::Hand
+        }
+        Interaction::Working => SystemCursor::WaitArrow,
+        Interaction::Crosshair | Interaction::Cell => SystemCursor::Crosshair,
+        Interaction::Text => SystemCursor::IBeam,
+        Interaction::ResizingHorizontally => SystemCursor::SizeWE,
+        Interaction::ResizingVertically => SystemCursor::SizeNS,
+        Interaction::ResizingDiagonallyUp => SystemCursor::SizeNESW,
+        Interaction::ResizingDiagonallyDown => SystemCursor::SizeNWSE,
+        Interaction::Move => SystemCursor::SizeAll,
+        Interaction::NotAllowed => SystemCursor::No,
+        Interaction::Hidden => return None,
+    })
+}
+
+/// Converts a `MouseButton` from [`sdl2`] to an [`iced_native`] mouse button.
+///
+/// [`sdl2`]: https://github.com/Rust-SDL2/rust-sdl2
+/// [`iced_native`]: https://github.com/hecrj/iced/tree/master/native
+pub fn mouse_button(mouse_button: MouseButton) -> mouse::Button {
+    match mouse_button {
+        MouseButton::Left => mouse::Button::Left,
+        MouseButton::Right => mouse::Button::Right,
+        MouseButton::Middle => mouse::Button::Middle,
+        MouseButton::X1 => mouse::Button::Other(4),
+        MouseButton::X2 => mouse::Button::Other(5),
+        MouseButton::Unknown => mouse::Button::Other(0),
+    }
+}
+
+/// Converts a `Mod` from [`sdl2`] to an [`iced_native`] modifiers state.
+///
+/// [`sdl2`]: https://github.com/Rust-SDL2/rust-sdl2
+/// [`iced_native`]: https://github.com/hecrj/iced/tree/master/native
+pub fn modifiers(modifiers: Mod) -> keyboard::Modifiers {
+    let mut result = keyboard::Modifiers::empty();
+
+    result.set(
+        keyboard::Modifiers::SHIFT,
+        modifiers.intersects(Mod::LSHIFTMOD | Mod::RSHIFTMOD),
+    );
+    result.set(
+        keyboard::Modifiers::CTRL,
+        modifiers.intersects(Mod::LCTRLMOD | Mod::RCTRLMOD),
+    );
+    result.set(
+        keyboard::Modifiers::ALT,
+        modifiers.intersects(Mod::LALTMOD | Mod::RALTMOD),
+    );
+    result.set(
+        keyboard::Modifiers::LOGO,
+        modifiers.intersects(Mod::LGUIMOD | Mod::RGUIMOD),
+    );
+
+    result
+}
+
+/// Converts a `Keycode` from [`sdl2`] to an [`iced_native`] key code, if
+/// iced has an equivalent.
+///
+/// [`sdl2`]: https://github.com/Rust-SDL2/rust-sdl2
+/// [`iced_native`]: https://github.com/hecrj/iced/tree/master/native
+pub fn key_code(keycode: Keycode) -> Option<keyboard::KeyCode> {
+    use keyboard::KeyCode;
+
+    Some(match keycode {
+        Keycode::Num1 => KeyCode::Key1,
+        Keycode::Num2 => KeyCode::Key2,
+        Keycode::Num3 => KeyCode::Key3,
+        Keycode::Num4 => KeyCode::Key4,
+        Keycode::Num5 => KeyCode::Key5,
+        Keycode::Num6 => KeyCode::Key6,
+        Keycode::Num7 => KeyCode::Key7,
+        Keycode::Num8 => KeyCode::Key8,
+        Keycode::Num9 => KeyCode::Key9,
+        Keycode::Num0 => KeyCode::Key0,
+        Keycode::A => KeyCode::A,
+        Keycode::B => KeyCode::B,
+        Keycode::C => KeyCode::C,
+        Keycode::D => KeyCode::D,
+        Keycode::E => KeyCode::E,
+        Keycode::F => KeyCode::F,
+        Keycode::G => KeyCode::G,
+        Keycode::H => KeyCode::H,
+        Keycode::I => KeyCode::I,
+        Keycode::J => KeyCode::J,
+        Keycode::K => KeyCode::K,
+        Keycode::L => KeyCode::L,
+        Keycode::M => KeyCode::M,
+        Keycode::N => KeyCode::N,
+        Keycode::O => KeyCode::O,
+        Keycode::P => KeyCode::P,
+        Keycode::Q => KeyCode::Q,
+        Keycode::R => KeyCode::R,
+        Keycode::S => KeyCode::S,
+        Keycode::T => KeyCode::T,
+        Keycode::U => KeyCode::U,
+        Keycode::V => KeyCode::V,
+        Keycode::W => KeyCode::W,
+        Keycode::X => KeyCode::X,
+        Keycode::Y => KeyCode::Y,
+        Keycode::Z => KeyCode::Z,
+        Keycode::Escape => KeyCode::Escape,
+        Keycode::F1 => KeyCode::F1,
+        Keycode::F2 => KeyCode::F2,
+        Keycode::F3 => KeyCode::F3,
+        Keycode::F4 => KeyCode::F4,
+        Keycode::F5 => KeyCode::F5,
+        Keycode::F6 => KeyCode::F6,
+        Keycode::F7 => KeyCode::F7,
+        Keycode::F8 => KeyCode::F8,
+        Keycode::F9 => KeyCode::F9,
+        Keycode::F10 => KeyCode::F10,
+        Keycode::F11 => KeyCode::F11,
+        Keycode::F12 => KeyCode::F12,
+        Keycode::F13 => KeyCode::F13,
+        Keycode::F14 => KeyCode::F14,
+        Keycode::F15 => KeyCode::F15,
+        Keycode::F16 => KeyCode::F16,
+        Keycode::F17 => KeyCode::F17,
+        Keycode::F18 => KeyCode::F18,
+        Keycode::F19 => KeyCode::F19,
+        Keycode::F20 => KeyCode::F20,
+        Keycode::F21 => KeyCode::F21,
+        Keycode::F22 => KeyCode::F22,
+        Keycode::F23 => KeyCode::F23,
+        Keycode::F24 => KeyCode::F24,
+        Keycode::PrintScreen => KeyCode::Snapshot,
+        Keycode::ScrollLock => KeyCode::Scroll,
+        Keycode::Pause => KeyCode::Pause,
+        Keycode::Insert => KeyCode::Insert,
+        Keycode::Home => KeyCode::Home,
+        Keycode::Delete => KeyCode::Delete,
+        Keycode::End => KeyCode::End,
+        Keycode::PageDown => KeyCode::PageDown,
+        Keycode::PageUp => KeyCode::PageUp,
+        Keycode::Left => KeyCode::Left,
+        Keycode::Up => KeyCode::Up,
+        Keycode::Right => KeyCode::Right,
+        Keycode::Down => KeyCode::Down,
+        Keycode::Backspace => KeyCode::Backspace,
+        Keycode::Return | Keycode::Return2 => KeyCode::Enter,
+        Keycode::Space => KeyCode::Space,
+        Keycode::Caret => KeyCode::Caret,
+        Keycode::NumLockClear => KeyCode::Numlock,
+        Keycode::Kp0 => KeyCode::Numpad0,
+        Keycode::Kp1 => KeyCode::Numpad1,
+        Keycode::Kp2 => KeyCode::Numpad2,
+        Keycode::Kp3 => KeyCode::Numpad3,
+        Keycode::Kp4 => KeyCode::Numpad4,
+        Keycode::Kp5 => KeyCode::Numpad5,
+        Keycode::Kp6 => KeyCode::Numpad6,
+        Keycode::Kp7 => KeyCode::Numpad7,
+        Keycode::Kp8 => KeyCode::Numpad8,
+        Keycode::Kp9 => KeyCode::Numpad9,
+        Keycode::KpPlus => KeyCode::NumpadAdd,
+        Keycode::KpDivide => KeyCode::NumpadDivide,
+        Keycode::KpPeriod | Keycode::KpDecimal => KeyCode::NumpadDecimal,
+        Keycode::KpComma => KeyCode::NumpadComma,
+        Keycode::KpEnter => KeyCode::NumpadEnter,
+        Keycode::KpEquals => KeyCode::NumpadEquals,
+        Keycode::KpMultiply => KeyCode::NumpadMultiply,
+        Keycode::KpMinus => KeyCode::NumpadSubtract,
+        Keycode::Quote => KeyCode::Apostrophe,
+        Keycode::Application => KeyCode::Apps,
+        Keycode::Asterisk => KeyCode::Asterisk,
+        Keycode::At => KeyCode::At,
+        Keycode::Backslash => KeyCode::Backslash,
+        Keycode::Calculator => KeyCode::Calculator,
+        Keycode::CapsLock => KeyCode::Capital,
+        Keycode::Colon => KeyCode::Colon,
+        Keycode::Comma => KeyCode::Comma,
+        Keycode::Equals => KeyCode::Equals,
+        Keycode::Backquote => KeyCode::Grave,
+        Keycode::LAlt => KeyCode::LAlt,
+        Keycode::LeftBracket => KeyCode::LBracket,
+        Keycode::LCtrl => KeyCode::LControl,
+        Keycode::LShift => KeyCode::LShift,
+        Keycode::LGui => KeyCode::LWin,
+        Keycode::Mail => KeyCode::Mail,
+        Keycode::MediaSelect => KeyCode::MediaSelect,
+        Keycode::AudioStop => KeyCode::MediaStop,
+        Keycode::Minus => KeyCode::Minus,
+        Keycode::Mute | Keycode::AudioMute => KeyCode::Mute,
+        Keycode::Computer => KeyCode::MyComputer,
+        Keycode::AcForward => KeyCode::NavigateForward,
+        Keycode::AcBack => KeyCode::NavigateBackward,
+        Keycode::AudioNext => KeyCode::NextTrack,
+        Keycode::Period => KeyCode::Period,
+        Keycode::AudioPlay => KeyCode::PlayPause,
+        Keycode::Plus => KeyCode::Plus,
+        Keycode::Power => KeyCode::Power,
+        Keycode::AudioPrev => KeyCode::PrevTrack,
+        Keycode::RAlt => KeyCode::RAlt,
+        Keycode::RightBracket => KeyCode::RBracket,
+        Keycode::RCtrl => KeyCode::RControl,
+        Keycode::RShift => KeyCode::RShift,
+        Keycode::RGui => KeyCode::RWin,
+        Keycode::Semicolon => KeyCode::Semicolon,
+        Keycode::Slash => KeyCode::Slash,
+        Keycode::Sleep => KeyCode::Sleep,
+        Keycode::Stop => KeyCode::Stop,
+        Keycode::Sysreq => KeyCode::Sysrq,
+        Keycode::Tab => KeyCode::Tab,
+        Keycode::Underscore => KeyCode::Underline,
+        Keycode::VolumeDown => KeyCode::VolumeDown,
+        Keycode::VolumeUp => KeyCode::VolumeUp,
+        Keycode::AcBookmarks => KeyCode::WebFavorites,
+        Keycode::AcHome => KeyCode::WebHome,
+        Keycode::AcRefresh => KeyCode::WebRefresh,
+        Keycode::AcSearch => KeyCode::WebSearch,
+        Keycode::AcStop => KeyCode::WebStop,
+        Keycode::Copy => KeyCode::Copy,
+        Keycode::Paste => KeyCode::Paste,
+        Keycode::Cut => KeyCode::Cut,
+        _ => return None,
+    })
+}
+
+/// Converts the selection of an [`sdl2`] text editing event, given in
+/// characters, into a byte range of its text.
+///
+/// [`sdl2`]: https://github.com/Rust-SDL2/rust-sdl2
+pub fn preedit_selection(
+    text: &str,
+    start: i32,
+    length: i32,
+) -> Option<Range<usize>> {
+    let start = usize::try_from(start).ok()?;
+    let length = usize::try_from(length).ok()?;
+
+    let byte = |chars: usize| {
+        text.char_indices()
+            .nth(chars)
+            .map_or(text.len(), |(index, _)| index)
+    };
+
+    Some(byte(start)..byte(start + length))
+}
@@ -0,0 +1,285 @@
+//! Run iced inside the event loop of an SDL2 application.
+use crate::conversion;
+use crate::input_method;
+use crate::keyboard;
+use crate::mouse;
+use crate::window;
+use crate::{image, Clipboard, Event, Size};
+
+use sdl2::mouse::{Cursor, MouseUtil};
+use sdl2::pixels::PixelFormatEnum;
+use sdl2::surface::Surface;
+use sdl2::video::{FullscreenType, WindowPos};
+
+/// An [`sdl2`] window hosting an iced [`Program`].
+///
+/// Feed the events of your event pump to
+/// [`program::State::queue_host_event`] and process them with
+/// [`program::State::process`], using the [`Host::logical_size`] as bounds.
+/// Graphics compositors can draw into the [`Host::window`] directly, since it
+/// implements `HasRawWindowHandle`.
+///
+/// [`sdl2`]: https://github.com/Rust-SDL2/rust-sdl2
+/// [`Program`]: iced_native::Program
+/// [`program::State::queue_host_event`]: iced_native::program::State::queue_host_event
+/// [`program::State::process`]: iced_native::program::State::process
+#[allow(missing_debug_implementations)]
+pub struct Host {
+    window: sdl2::video::Window,
+    clipboard: Clipboard,
+    mouse: MouseUtil,
+    cursor: Option<Cursor>,
+    interaction: mouse::Interaction,
+    modifiers: keyboard::Modifiers,
+    is_composing: bool,
+}
+
+impl Host {
+    /// Creates a new [`Host`] for the given window.
+    pub fn new(window: sdl2::video::Window) -> Self {
+        let clipboard = Clipboard::connect(window.subsystem());
+        let mouse = window.subsystem().sdl().mouse();
+
+        Self {
+            window,
+            clipboard,
+            mouse,
+            cursor: None,
+            interaction: mouse::Interaction::Idle,
+            modifiers: keyboard::Modifiers::default(),
+            is_composing: false,
+        }
+    }
+
+    /// Returns the window of the [`Host`].
+    pub fn window(&self) -> &sdl2::video::Window {
+        &self.window
+    }
+
+    /// Returns the scale factor of the window of the [`Host`].
+    ///
+    /// It is the ratio between the size of its drawable in pixels and its
+    /// logical size, which only differs on high-DPI displays when the window
+    /// was created with `allow_highdpi`.
+    pub fn scale_factor(&self) -> f64 {
+        let (width, _) = self.window.size();
+        let (drawable_width, _) = self.window.drawable_size();
+
+        if width == 0 {
+            1.0
+        } else {
+            f64::from(drawable_width) / f64::from(width)
+        }
+    }
+
+    /// Returns the logical size of the window of the [`Host`].
+    pub fn logical_size(&self) -> Size {
+        let (width, height) = self.window.size();
+
+        Size::new(width as f32, height as f32)
+    }
+}
+
+impl iced_native::Host for Host {
+    type Event<'a> = sdl2::event::Event;
+
+    fn translate(&mut self, event: &sdl2::event::Event) -> Vec<Event> {
+        use sdl2::event::Event as SdlEvent;
+
+        if let Some(id) = event.get_window_id() {
+            if id != self.window.id() {
+                return Vec::new();
+            }
+        }
+
+        let mut events = Vec::new();
+
+        match event {
+            SdlEvent::KeyDown { keymod, .. }
+            | SdlEvent::KeyUp { keymod, .. } => {
+                let modifiers = conversion::modifiers(*keymod);
+
+                if modifiers != self.modifiers {
+                    self.modifiers = modifiers;
+
+                    events.push(Event::Keyboard(
+                        keyboard::Event::ModifiersChanged(modifiers),
+                    ));
+                }
+            }
+            SdlEvent::TextEditing {
+                text,
+                start,
+                length,
+                ..
+            } => {
+                // An empty text clears the composition
+                if !text.is_empty() || self.is_composing {
+                    self.is_composing = !text.is_empty();
+
+                    events.push(Event::InputMethod(
+                        input_method::Event::Preedit {
+                            content: text.clone(),
+                            selection: conversion::preedit_selection(
+                                text, *start, *length,
+                            ),
+                        },
+                    ));
+                }
+            }
+            SdlEvent::TextInput { text, .. } if self.is_composing => {
+                self.is_composing = false;
+
+                events.push(Event::InputMethod(input_method::Event::Commit(
+                    text.clone(),
+                )));
+            }
+            SdlEvent::TextInput { text, .. } => {
+                events.extend(text.chars().map(|c| {
+                    Event::Keyboard(keyboard::Event::CharacterReceived(c))
+                }));
+            }
+            _ => {}
+        }
+
+        events.extend(conversion::event(event, self.logical_size()));
+        events
+    }
+
+    fn clipboard(&mut self) -> &mut dyn iced_native::Clipboard {
+        &mut self.clipboard
+    }
+
+    fn run_window_action(&mut self, action: window::Action) {
+        let result = match action {
+            window::Action::Resize { width, height } => self
+                .window
+                .set_size(width, height)
+                .map_err(|error| error.to_string()),
+            window::Action::Move { x, y } => {
+                self.window.set_position(
+                    WindowPos::Positioned(x),
+                    WindowPos::Positioned(y),
+                );
+
+                Ok(())
+            }
+            // SDL2 only takes the window fullscreen in its current display
+            window::Action::Fullscreen(monitor) => {
+                self.window.set_fullscreen(if monitor.is_some() {
+                    FullscreenType::Desktop
+                } else {
+                    FullscreenType::Off
+                })
+            }
+            window::Action::SetMinSize(size) => {
+                let (width, height) = size.unwrap_or((1, 1));
+
+                self.window
+                    .set_minimum_size(width.max(1), height.max(1))
+                    .map_err(|error| error.to_string())
+            }
+            window::Action::SetMaxSize(size) => {
+                let (width, height) =
+                    size.unwrap_or((i32::MAX as u32, i32::MAX as u32));
+
+                self.window
+                    .set_maximum_size(width, height)
+                    .map_err(|error| error.to_string())
+            }
+            window::Action::SetDecorations(decorations) => {
+                self.window.set_bordered(decorations);
+
+                Ok(())
+            }
+            window::Action::Minimize => {
+                self.window.minimize();
+
+                Ok(())
+            }
+            window::Action::Maximize => {
+                self.window.maximize();
+
+                Ok(())
+            }
+            window::Action::Restore => {
+                self.window.restore();
+
+                Ok(())
+            }
+            window::Action::SetIcon(Some(handle)) => {
+                match handle.data() {
+                    image::Data::Pixels {
+                        width,
+                        height,
+                        pixels,
+                    } => {
+                        // The pixels of a handle are stored in BGRA
+                        let mut pixels = pixels.clone();
+
+                        Surface::from_data(
+                            &mut pixels,
+                            *width,
+                            *height,
+                            width * 4,
+                            PixelFormatEnum::BGRA32,
+                        )
+                        .map(|icon| self.window.set_icon(icon))
+                    }
+                    _ => Err(String::from(
+                        "only icons made of decoded pixels are supported",
+                    )),
+                }
+            }
+            window::Action::SetSoftKeyboardVisible(is_visible) => {
+                let text_input = self.window.subsystem().text_input();
+
+                // SDL shows the software keyboard while text input is active,
+                // which must stay active on desktops to receive any text
+                if text_input.has_screen_keyboard_support() {
+                    if is_visible {
+                        text_input.start();
+                    } else {
+                        text_input.stop();
+                    }
+                }
+
+                Ok(())
+            }
+            // These are handled by the renderer of the application
+            window::Action::SetPresentMode(_)
+            | window::Action::SetFrameRateCap(_)
+            | window::Action::SetContinuousRendering(..) => Ok(()),
+            action => Err(format!("{:?} is not supported by SDL2", action)),
+        };
+
+        if let Err(error) = result {
+            log::warn!("error running window action: {}", error);
+        }
+    }
+
+    fn set_mouse_interaction(&mut self, interaction: mouse::Interaction) {
+        if interaction == self.interaction {
+            return;
+        }
+
+        self.interaction = interaction;
+
+        match conversion::mouse_interaction(interaction) {
+            Some(system_cursor) => {
+                self.mouse.show_cursor(true);
+
+                match Cursor::from_system(system_cursor) {
+                    Ok(cursor) => {
+                        cursor.set();
+                        self.cursor = Some(cursor);
+                    }
+                    Err(error) => {
+                        log::warn!("error setting cursor: {}", error)
+                    }
+                }
+            }
+            None => self.mouse.show_cursor(false),
+        }
+    }
+}
@@ -0,0 +1,34 @@
+//! An SDL2 host for Iced.
+//!
+//! `iced_sdl2` runs an [`iced_native`] program inside the event loop of an
+//! [`sdl2`] application, through a [`Host`] for one of its windows.
+//!
+//! Feed the events of your event loop to
+//! [`program::State::queue_host_event`] and process them with
+//! [`program::State::process`]. Additionally, a [`conversion`] module is
+//! available for users that decide to translate the events themselves.
+//!
+//! [`iced_native`]: https://github.com/hecrj/iced/tree/master/native
+//! [`sdl2`]: https://github.com/Rust-SDL2/rust-sdl2
+//! [`program::State::queue_host_event`]: iced_native::program::State::queue_host_event
+//! [`program::State::process`]: iced_native::program::State::process
+//! [`conversion`]: crate::conversion
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/iced-rs/iced/9ab6923e943f784985e9ef9ca28b10278297225d/docs/logo.svg"
+)]
+#![deny(missing_docs)]
+#![deny(missing_debug_implementations)]
+#![deny(unused_results)]
+#![forbid(unsafe_code)]
+#![forbid(rust_2018_idioms)]
+
+#[doc(no_inline)]
+pub use iced_native::*;
+pub use sdl2;
+
+pub mod clipboard;
+pub mod conversion;
+pub mod host;
+
+pub use clipboard::Clipboard;
+pub use host::Host;
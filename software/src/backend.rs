@@ -0,0 +1,493 @@
+use crate::raster::Canvas;
+use crate::text;
+use crate::Settings;
+
+use iced_graphics::backend;
+use iced_graphics::font;
+use iced_graphics::layer::{self, Layer};
+use iced_graphics::{Primitive, Viewport};
+use iced_native::{Color, Font, Size};
+
+#[cfg(feature = "image_rs")]
+use iced_native::image;
+
+/// A software graphics backend for [`iced`].
+///
+/// It draws primitives into a buffer of pixels in memory, without the help
+/// of a GPU. This makes it useful for headless environments, like tests
+/// and servers, and as a reference for writing new backends.
+///
+/// [`iced`]: https://github.com/iced-rs/iced
+#[derive(Debug)]
+pub struct Backend {
+    text_pipeline: text::Pipeline,
+
+    #[cfg(feature = "image_rs")]
+    images: std::cell::RefCell<image_cache::Cache>,
+
+    default_text_size: u16,
+}
+
+impl Backend {
+    /// Creates a new [`Backend`].
+    pub fn new(settings: Settings) -> Self {
+        Self {
+            text_pipeline: text::Pipeline::new(settings.default_font),
+
+            #[cfg(feature = "image_rs")]
+            images: std::cell::RefCell::new(image_cache::Cache::default()),
+
+            default_text_size: settings.default_text_size,
+        }
+    }
+
+    /// Draws the provided primitives over the given background color,
+    /// returning the pixels of the resulting image.
+    ///
+    /// The pixels are in sRGB RGBA format, row by row, and the image has the
+    /// physical size of the [`Viewport`].
+    ///
+    /// The text provided as overlay will be rendered on top of the primitives.
+    /// This is useful for rendering debug information.
+    pub fn draw<T: AsRef<str>>(
+        &mut self,
+        primitives: &[Primitive],
+        viewport: &Viewport,
+        background_color: Color,
+        overlay_text: &[T],
+    ) -> Vec<u8> {
+        log::debug!("Drawing");
+
+        let target_size = viewport.physical_size();
+        let bounds = viewport.physical_bounds();
+        let scale_factor = viewport.scale_factor() as f32;
+
+        // Layers are drawn unrotated and the result is rotated and moved
+        // inside of the padding of the viewport at the end
+        let (width, height) = if viewport.rotation().is_quarter_turn() {
+            (bounds.height, bounds.width)
+        } else {
+            (bounds.width, bounds.height)
+        };
+
+        if width == 0 || height == 0 {
+            return Canvas::new(
+                target_size.width,
+                target_size.height,
+                background_color,
+            )
+            .into_rgba8();
+        }
+
+        let mut canvas = Canvas::new(width, height, background_color);
+
+        let layers = Layer::generate(primitives, viewport);
+        let overlay = if overlay_text.is_empty() {
+            Vec::new()
+        } else {
+            let mut lines: Vec<String> = overlay_text
+                .iter()
+                .map(|line| String::from(line.as_ref()))
+                .collect();
+
+            lines.extend(layer::Stats::of(&layers).overlay());
+            lines
+        };
+
+        for layer in layers.iter().chain(&[Layer::overlay(&overlay, viewport)])
+        {
+            self.flush(&mut canvas, scale_factor, layer);
+        }
+
+        #[cfg(feature = "image_rs")]
+        self.images.borrow_mut().trim();
+
+        canvas
+            .rotate(viewport.rotation())
+            .place(target_size, bounds.x, bounds.y, background_color)
+            .into_rgba8()
+    }
+
+    fn flush(
+        &mut self,
+        canvas: &mut Canvas,
+        scale_factor: f32,
+        layer: &Layer<'_>,
+    ) {
+        let bounds = (layer.bounds * scale_factor).snap();
+
+        if bounds.width < 1 || bounds.height < 1 {
+            return;
+        }
+
+        let clip_mask = canvas.clip_mask(bounds);
+
+        // Primitives are drawn in the same order as in `iced_wgpu`
+        for quad in &layer.quads {
+            canvas.draw_quad(quad, scale_factor, clip_mask.as_ref());
+        }
+
+        for mesh in &layer.meshes {
+            canvas.draw_mesh(mesh, scale_factor, bounds);
+        }
+
+        #[cfg(feature = "image_rs")]
+        for image in &layer.images {
+            if let layer::Image::Raster {
+                handle,
+                bounds: image_bounds,
+            } = image
+            {
+                if let Some(pixels) = self.images.borrow_mut().load(handle) {
+                    canvas.draw_image(
+                        pixels,
+                        *image_bounds * scale_factor,
+                        clip_mask.as_ref(),
+                    );
+                }
+            }
+        }
+
+        for text in &layer.text {
+            self.text_pipeline.draw(
+                canvas,
+                text,
+                scale_factor,
+                clip_mask.as_ref(),
+            );
+        }
+    }
+}
+
+impl iced_graphics::Backend for Backend {
+    fn trim_measurements(&mut self) {
+        self.text_pipeline.trim_measurement_cache()
+    }
+}
+
+impl backend::Text for Backend {
+    const ICON_FONT: Font = font::ICONS;
+    const CHECKMARK_ICON: char = font::CHECKMARK_ICON;
+    const ARROW_DOWN_ICON: char = font::ARROW_DOWN_ICON;
+
+    fn default_size(&self) -> u16 {
+        self.default_text_size
+    }
+
+    fn measure(
+        &self,
+        contents: &str,
+        size: f32,
+        font: Font,
+        bounds: Size,
+    ) -> (f32, f32) {
+        self.text_pipeline.measure(contents, size, font, bounds)
+    }
+
+    fn baseline(&self, size: f32, font: Font) -> f32 {
+        self.text_pipeline.baseline(size, font)
+    }
+
+    fn hit_test(
+        &self,
+        contents: &str,
+        size: f32,
+        font: Font,
+        bounds: Size,
+        point: iced_native::Point,
+        nearest_only: bool,
+    ) -> Option<text::Hit> {
+        self.text_pipeline.hit_test(
+            contents,
+            size,
+            font,
+            bounds,
+            point,
+            nearest_only,
+        )
+    }
+}
+
+#[cfg(feature = "image_rs")]
+impl backend::Image for Backend {
+    fn dimensions(&self, handle: &image::Handle) -> (u32, u32) {
+        self.images
+            .borrow_mut()
+            .load(handle)
+            .map(|pixmap| (pixmap.width(), pixmap.height()))
+            .unwrap_or((1, 1))
+    }
+}
+
+#[cfg(feature = "image_rs")]
+mod image_cache {
+    use iced_native::image;
+
+    use std::collections::{HashMap, HashSet};
+
+    #[derive(Debug, Default)]
+    pub struct Cache {
+        map: HashMap<u64, Option<tiny_skia::Pixmap>>,
+        hits: HashSet<u64>,
+    }
+
+    impl Cache {
+        pub fn load(
+            &mut self,
+            handle: &image::Handle,
+        ) -> Option<&tiny_skia::Pixmap> {
+            let _ = self.hits.insert(handle.id());
+
+            self.map
+                .entry(handle.id())
+                .or_insert_with(|| decode(handle.data()))
+                .as_ref()
+        }
+
+        pub fn trim(&mut self) {
+            let hits = &self.hits;
+
+            self.map.retain(|id, _| hits.contains(id));
+            self.hits.clear();
+        }
+    }
+
+    fn decode(data: &image::Data) -> Option<tiny_skia::Pixmap> {
+        let image = match data {
+            image::Data::Path(path) => {
+                image_rs::open(path).ok().map(|image| image.to_rgba8())
+            }
+            image::Data::Bytes(bytes) => image_rs::load_from_memory(bytes)
+                .ok()
+                .map(|image| image.to_rgba8()),
+            image::Data::Pixels {
+                width,
+                height,
+                pixels,
+            } => {
+                // Raw pixels are stored in BGRA format
+                let pixels = pixels
+                    .chunks_exact(4)
+                    .flat_map(|bgra| [bgra[2], bgra[1], bgra[0], bgra[3]])
+                    .collect();
+
+                image_rs::RgbaImage::from_vec(*width, *height, pixels)
+            }
+        }?;
+
+        let mut pixmap = tiny_skia::Pixmap::new(image.width(), image.height())?;
+
+        // Pixmaps store premultiplied pixels
+        for (pixel, [r, g, b, a]) in pixmap
+            .data_mut()
+            .chunks_exact_mut(4)
+            .zip(image.pixels().map(|pixel| pixel.0))
+        {
+            let premultiply =
+                |channel: u8| (u16::from(channel) * u16::from(a) / 255) as u8;
+
+            pixel.copy_from_slice(&[
+                premultiply(r),
+                premultiply(g),
+                premultiply(b),
+                a,
+            ]);
+        }
+
+        Some(pixmap)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iced_graphics::{triangle, Rotation};
+    use iced_native::{alignment, Background, Padding, Rectangle};
+
+    const WHITE: [u8; 4] = [255, 255, 255, 255];
+    const RED: [u8; 4] = [255, 0, 0, 255];
+    const BLUE: [u8; 4] = [0, 0, 255, 255];
+
+    fn draw(primitives: &[Primitive], viewport: &Viewport) -> Vec<[u8; 4]> {
+        let mut backend = Backend::new(Settings::default());
+
+        backend
+            .draw::<&str>(primitives, viewport, Color::WHITE, &[])
+            .chunks_exact(4)
+            .map(|pixel| [pixel[0], pixel[1], pixel[2], pixel[3]])
+            .collect()
+    }
+
+    fn quad(bounds: Rectangle, border_width: f32) -> Primitive {
+        Primitive::Quad {
+            bounds,
+            background: Background::Color(Color::from_rgb(1.0, 0.0, 0.0)),
+            border_radius: 0.0,
+            border_width,
+            border_color: Color::from_rgb(0.0, 0.0, 1.0),
+        }
+    }
+
+    fn viewport(width: u32, height: u32) -> Viewport {
+        Viewport::with_physical_size(Size::new(width, height), 1.0)
+    }
+
+    #[test]
+    fn draws_quads_with_their_border_inside() {
+        let pixels = draw(
+            &[quad(
+                Rectangle::new([1.0, 1.0].into(), [4.0, 4.0].into()),
+                1.0,
+            )],
+            &viewport(6, 6),
+        );
+
+        #[rustfmt::skip]
+        let reference = [
+            WHITE, WHITE, WHITE, WHITE, WHITE, WHITE,
+            WHITE, BLUE,  BLUE,  BLUE,  BLUE,  WHITE,
+            WHITE, BLUE,  RED,   RED,   BLUE,  WHITE,
+            WHITE, BLUE,  RED,   RED,   BLUE,  WHITE,
+            WHITE, BLUE,  BLUE,  BLUE,  BLUE,  WHITE,
+            WHITE, WHITE, WHITE, WHITE, WHITE, WHITE,
+        ];
+
+        assert_eq!(pixels, reference);
+    }
+
+    #[test]
+    fn clips_layers_to_their_bounds() {
+        let pixels = draw(
+            &[Primitive::Clip {
+                bounds: Rectangle::new([0.0, 0.0].into(), [2.0, 4.0].into()),
+                content: Box::new(quad(
+                    Rectangle::new([0.0, 0.0].into(), [4.0, 4.0].into()),
+                    0.0,
+                )),
+            }],
+            &viewport(4, 2),
+        );
+
+        assert_eq!(pixels, [RED, RED, WHITE, WHITE, RED, RED, WHITE, WHITE]);
+    }
+
+    #[test]
+    fn fills_meshes_without_seams() {
+        let vertex = |x, y| triangle::Vertex2D {
+            position: [x, y],
+            color: [1.0, 0.0, 0.0, 1.0],
+        };
+
+        // The triangles have opposite windings and share a diagonal
+        let pixels = draw(
+            &[Primitive::Mesh2D {
+                buffers: triangle::Mesh2D {
+                    vertices: vec![
+                        vertex(0.0, 0.0),
+                        vertex(3.0, 0.0),
+                        vertex(3.0, 3.0),
+                        vertex(0.0, 3.0),
+                    ],
+                    indices: vec![0, 1, 2, 0, 3, 2],
+                },
+                size: Size::new(4.0, 4.0),
+            }],
+            &viewport(4, 4),
+        );
+
+        #[rustfmt::skip]
+        let reference = [
+            RED,   RED,   RED,   WHITE,
+            RED,   RED,   RED,   WHITE,
+            RED,   RED,   RED,   WHITE,
+            WHITE, WHITE, WHITE, WHITE,
+        ];
+
+        assert_eq!(pixels, reference);
+    }
+
+    #[test]
+    fn rotates_the_contents_of_the_viewport() {
+        let primitives = [quad(
+            Rectangle::new([0.0, 0.0].into(), [1.0, 1.0].into()),
+            0.0,
+        )];
+
+        let rotated = |rotation| {
+            draw(&primitives, &viewport(3, 2).with_rotation(rotation))
+        };
+
+        #[rustfmt::skip]
+        let reference = [
+            (Rotation::None, [
+                RED,   WHITE, WHITE,
+                WHITE, WHITE, WHITE,
+            ]),
+            (Rotation::Clockwise90, [
+                WHITE, WHITE, RED,
+                WHITE, WHITE, WHITE,
+            ]),
+            (Rotation::Clockwise180, [
+                WHITE, WHITE, WHITE,
+                WHITE, WHITE, RED,
+            ]),
+            (Rotation::Clockwise270, [
+                WHITE, WHITE, WHITE,
+                RED,   WHITE, WHITE,
+            ]),
+        ];
+
+        for (rotation, pixels) in reference {
+            assert_eq!(rotated(rotation), pixels, "{:?}", rotation);
+        }
+    }
+
+    #[test]
+    fn insets_the_contents_by_the_padding_of_the_viewport() {
+        let primitives = [quad(
+            Rectangle::new([0.0, 0.0].into(), [2.0, 2.0].into()),
+            0.0,
+        )];
+
+        let pixels = draw(
+            &primitives,
+            &viewport(4, 4).with_padding(Padding {
+                top: 1,
+                right: 0,
+                bottom: 2,
+                left: 1,
+            }),
+        );
+
+        #[rustfmt::skip]
+        let reference = [
+            WHITE, WHITE, WHITE, WHITE,
+            WHITE, RED,   RED,   WHITE,
+            WHITE, WHITE, WHITE, WHITE,
+            WHITE, WHITE, WHITE, WHITE,
+        ];
+
+        assert_eq!(pixels, reference);
+    }
+
+    #[test]
+    fn draws_text_inside_its_bounds() {
+        let pixels = draw(
+            &[Primitive::Text {
+                content: String::from("I"),
+                bounds: Rectangle::new([0.0, 0.0].into(), [10.0, 20.0].into()),
+                color: Color::BLACK,
+                size: 20.0,
+                font: Font::Default,
+                horizontal_alignment: alignment::Horizontal::Left,
+                vertical_alignment: alignment::Vertical::Top,
+            }],
+            &viewport(20, 20),
+        );
+
+        let inked = |x: usize, y: usize| pixels[y * 20 + x] != WHITE;
+
+        assert!((0..20).any(|y| (0..10).any(|x| inked(x, y))));
+        assert!((0..20).all(|y| (10..20).all(|x| !inked(x, y))));
+    }
+}
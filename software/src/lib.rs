@@ -0,0 +1,52 @@
+//! A software renderer for [`iced_native`].
+//!
+//! `iced_software` draws the primitives of an [`iced_graphics::Renderer`]
+//! into a buffer of pixels in memory with [`tiny-skia`], using only the CPU.
+//! It is useful in headless environments, like tests and servers, and on
+//! platforms where a GPU may not be available.
+//!
+//! It also serves as a reference implementation of the backend traits of
+//! [`iced_graphics`]. A new backend only needs to:
+//! - Implement [`iced_graphics::Backend`] and [`iced_graphics::backend::Text`]
+//!   to lay out and measure text.
+//! - Implement [`iced_graphics::backend::Image`] and
+//!   [`iced_graphics::backend::Svg`] to support the image widgets, if desired.
+//! - Flatten the primitives into layers with [`iced_graphics::Layer`] and
+//!   draw every layer in order.
+//!
+//! Currently, `iced_software` supports the following primitives:
+//! - Text, which is laid out using [`glyph_brush`]. No shaping at all.
+//! - Quads or rectangles, with rounded borders and a solid background color.
+//! - Clip areas, useful to implement scrollables or hide overflowing content.
+//! - Raster images, loaded from memory or the file system.
+//! - Meshes of triangles, useful to draw geometry freely. Every triangle is
+//!   filled with the average color of its vertices.
+//!
+//! [`iced_native`]: https://github.com/iced-rs/iced/tree/master/native
+//! [`glyph_brush`]: https://github.com/alexheretic/glyph-brush
+//! [`tiny-skia`]: https://github.com/RazrFalcon/tiny-skia
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/iced-rs/iced/9ab6923e943f784985e9ef9ca28b10278297225d/docs/logo.svg"
+)]
+#![deny(missing_docs)]
+#![deny(missing_debug_implementations)]
+#![deny(unused_results)]
+#![deny(unsafe_code)]
+#![forbid(rust_2018_idioms)]
+#![cfg_attr(docsrs, feature(doc_cfg))]
+
+pub mod settings;
+
+mod backend;
+mod raster;
+mod text;
+
+pub use iced_graphics::{Color, Primitive, Viewport};
+
+pub use backend::Backend;
+pub use settings::Settings;
+
+/// A software graphics renderer for [`iced`].
+///
+/// [`iced`]: https://github.com/iced-rs/iced
+pub type Renderer = iced_graphics::Renderer<Backend>;
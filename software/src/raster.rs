@@ -0,0 +1,475 @@
+use iced_graphics::layer;
+use iced_graphics::Rotation;
+use iced_native::{Color, Rectangle, Size};
+
+use tiny_skia::{
+    ClipMask, FillRule, Paint, Path, PathBuilder, Pixmap, PixmapPaint,
+    Transform,
+};
+
+/// A [`tiny_skia`] pixmap that layers are drawn into.
+#[derive(Debug)]
+pub struct Canvas {
+    pixmap: Pixmap,
+}
+
+impl Canvas {
+    pub fn new(width: u32, height: u32, background: Color) -> Self {
+        let mut pixmap = Pixmap::new(width, height).expect("Create pixmap");
+
+        pixmap.fill(into_color(background.into_linear()));
+
+        Canvas { pixmap }
+    }
+
+    pub fn pixmap_mut(&mut self) -> &mut Pixmap {
+        &mut self.pixmap
+    }
+
+    /// Returns a [`ClipMask`] for the given physical bounds, or `None` if
+    /// they cover the whole [`Canvas`].
+    pub fn clip_mask(&self, bounds: Rectangle<u32>) -> Option<ClipMask> {
+        if bounds.x == 0
+            && bounds.y == 0
+            && bounds.width >= self.pixmap.width()
+            && bounds.height >= self.pixmap.height()
+        {
+            return None;
+        }
+
+        let rect = tiny_skia::Rect::from_xywh(
+            bounds.x as f32,
+            bounds.y as f32,
+            bounds.width as f32,
+            bounds.height as f32,
+        )?;
+
+        let mut mask = ClipMask::new();
+
+        let _ = mask.set_path(
+            self.pixmap.width(),
+            self.pixmap.height(),
+            &PathBuilder::from_rect(rect),
+            FillRule::Winding,
+            false,
+        );
+
+        Some(mask)
+    }
+
+    pub fn draw_quad(
+        &mut self,
+        quad: &layer::Quad,
+        scale_factor: f32,
+        clip_mask: Option<&ClipMask>,
+    ) {
+        let [x, y] = [
+            quad.position[0] * scale_factor,
+            quad.position[1] * scale_factor,
+        ];
+        let [width, height] =
+            [quad.size[0] * scale_factor, quad.size[1] * scale_factor];
+        let border_width =
+            (quad.border_width * scale_factor).min(width.min(height) / 2.0);
+        let border_radius =
+            (quad.border_radius * scale_factor).min(width.min(height) / 2.0);
+
+        let path = match rounded_rectangle(x, y, width, height, border_radius) {
+            Some(path) => path,
+            None => return,
+        };
+
+        let _ = self.pixmap.fill_path(
+            &path,
+            &paint(quad.color),
+            FillRule::Winding,
+            Transform::identity(),
+            clip_mask,
+        );
+
+        if border_width <= 0.0 {
+            return;
+        }
+
+        // Like in `iced_wgpu`, the border is drawn inside the bounds of the
+        // quad, following an inner radius reduced by its width
+        let mut border = PathBuilder::new();
+
+        push_rounded_rectangle(&mut border, x, y, width, height, border_radius);
+        push_rounded_rectangle(
+            &mut border,
+            x + border_width,
+            y + border_width,
+            width - border_width * 2.0,
+            height - border_width * 2.0,
+            (border_radius - border_width).max(0.0),
+        );
+
+        if let Some(border) = border.finish() {
+            let _ = self.pixmap.fill_path(
+                &border,
+                &paint(quad.border_color),
+                FillRule::EvenOdd,
+                Transform::identity(),
+                clip_mask,
+            );
+        }
+    }
+
+    pub fn draw_mesh(
+        &mut self,
+        mesh: &layer::Mesh<'_>,
+        scale_factor: f32,
+        clip_bounds: Rectangle<u32>,
+    ) {
+        let clip_bounds = match intersection(
+            clip_bounds,
+            (mesh.clip_bounds * scale_factor).snap(),
+        ) {
+            Some(bounds) => bounds,
+            None => return,
+        };
+
+        let clip_mask = self.clip_mask(clip_bounds);
+        let vertices = &mesh.buffers.vertices;
+
+        let position = |index: u32| {
+            let vertex = &vertices[index as usize];
+
+            [
+                (vertex.position[0] + mesh.origin.x) * scale_factor,
+                (vertex.position[1] + mesh.origin.y) * scale_factor,
+            ]
+        };
+
+        // `tiny-skia` has no vertex colors, so every triangle is filled with
+        // the average color of its vertices. Consecutive triangles of the same
+        // color are filled together, to avoid seams between them.
+        let mut run: Option<([f32; 4], PathBuilder)> = None;
+
+        for triangle in mesh.buffers.indices.chunks_exact(3) {
+            let [a, mut b, mut c] = [
+                position(triangle[0]),
+                position(triangle[1]),
+                position(triangle[2]),
+            ];
+
+            let mut color = [0.0; 4];
+
+            for index in triangle {
+                for (channel, value) in
+                    color.iter_mut().zip(&vertices[*index as usize].color)
+                {
+                    *channel += value / 3.0;
+                }
+            }
+
+            let mut builder = match run.take() {
+                Some((run_color, builder)) if run_color == color => builder,
+                Some((run_color, builder)) => {
+                    self.fill(builder, run_color, clip_mask.as_ref());
+
+                    PathBuilder::new()
+                }
+                None => PathBuilder::new(),
+            };
+
+            // Triangles must have the same winding for the nonzero rule
+            // to fill their union
+            if edge(a, b, c) < 0.0 {
+                std::mem::swap(&mut b, &mut c);
+            }
+
+            builder.move_to(a[0], a[1]);
+            builder.line_to(b[0], b[1]);
+            builder.line_to(c[0], c[1]);
+            builder.close();
+
+            run = Some((color, builder));
+        }
+
+        if let Some((color, builder)) = run {
+            self.fill(builder, color, clip_mask.as_ref());
+        }
+    }
+
+    /// Draws an image, scaling it to the given physical bounds.
+    #[cfg(feature = "image_rs")]
+    pub fn draw_image(
+        &mut self,
+        image: &Pixmap,
+        bounds: Rectangle,
+        clip_mask: Option<&ClipMask>,
+    ) {
+        let rect = match tiny_skia::Rect::from_xywh(
+            bounds.x,
+            bounds.y,
+            bounds.width,
+            bounds.height,
+        ) {
+            Some(rect) => rect,
+            None => return,
+        };
+
+        let transform = Transform::from_row(
+            bounds.width / image.width() as f32,
+            0.0,
+            0.0,
+            bounds.height / image.height() as f32,
+            bounds.x,
+            bounds.y,
+        );
+
+        let paint = Paint {
+            shader: tiny_skia::Pattern::new(
+                image.as_ref(),
+                tiny_skia::SpreadMode::Pad,
+                tiny_skia::FilterQuality::Bilinear,
+                1.0,
+                transform,
+            ),
+            anti_alias: true,
+            ..Paint::default()
+        };
+
+        let _ = self.pixmap.fill_rect(
+            rect,
+            &paint,
+            Transform::identity(),
+            clip_mask,
+        );
+    }
+
+    /// Rotates the [`Canvas`] clockwise.
+    pub fn rotate(self, rotation: Rotation) -> Canvas {
+        let width = self.pixmap.width() as f32;
+        let height = self.pixmap.height() as f32;
+
+        let (transform, target_width, target_height) = match rotation {
+            Rotation::None => return self,
+            Rotation::Clockwise90 => (
+                Transform::from_row(0.0, 1.0, -1.0, 0.0, height, 0.0),
+                height,
+                width,
+            ),
+            Rotation::Clockwise180 => (
+                Transform::from_row(-1.0, 0.0, 0.0, -1.0, width, height),
+                width,
+                height,
+            ),
+            Rotation::Clockwise270 => (
+                Transform::from_row(0.0, -1.0, 1.0, 0.0, 0.0, width),
+                height,
+                width,
+            ),
+        };
+
+        let mut rotated =
+            Pixmap::new(target_width as u32, target_height as u32)
+                .expect("Create pixmap");
+
+        let _ = rotated.draw_pixmap(
+            0,
+            0,
+            self.pixmap.as_ref(),
+            &PixmapPaint {
+                blend_mode: tiny_skia::BlendMode::Source,
+                ..PixmapPaint::default()
+            },
+            transform,
+            None,
+        );
+
+        Canvas { pixmap: rotated }
+    }
+
+    /// Places the [`Canvas`] at the given position of a new [`Canvas`] of
+    /// the given size, filled with the background [`Color`].
+    pub fn place(
+        self,
+        size: Size<u32>,
+        x: u32,
+        y: u32,
+        background: Color,
+    ) -> Canvas {
+        if x == 0
+            && y == 0
+            && size.width == self.pixmap.width()
+            && size.height == self.pixmap.height()
+        {
+            return self;
+        }
+
+        let mut target = Canvas::new(size.width, size.height, background);
+
+        let _ = target.pixmap.draw_pixmap(
+            x as i32,
+            y as i32,
+            self.pixmap.as_ref(),
+            &PixmapPaint {
+                blend_mode: tiny_skia::BlendMode::Source,
+                ..PixmapPaint::default()
+            },
+            Transform::identity(),
+            None,
+        );
+
+        target
+    }
+
+    /// Converts the [`Canvas`] into sRGB RGBA pixels with straight alpha.
+    pub fn into_rgba8(self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(self.pixmap.data().len());
+
+        for pixel in self.pixmap.pixels() {
+            let color = pixel.demultiply();
+
+            bytes.extend_from_slice(&[
+                color.red(),
+                color.green(),
+                color.blue(),
+                color.alpha(),
+            ]);
+        }
+
+        bytes
+    }
+
+    fn fill(
+        &mut self,
+        builder: PathBuilder,
+        color: [f32; 4],
+        clip_mask: Option<&ClipMask>,
+    ) {
+        if let Some(path) = builder.finish() {
+            let _ = self.pixmap.fill_path(
+                &path,
+                &paint(color),
+                FillRule::Winding,
+                Transform::identity(),
+                clip_mask,
+            );
+        }
+    }
+}
+
+/// Converts a color in linear RGBA into a [`tiny_skia::Color`], which is
+/// in sRGB.
+pub fn into_color(linear: [f32; 4]) -> tiny_skia::Color {
+    let [r, g, b, a] = linear;
+
+    tiny_skia::Color::from_rgba(
+        to_srgb(r),
+        to_srgb(g),
+        to_srgb(b),
+        a.clamp(0.0, 1.0),
+    )
+    .unwrap_or(tiny_skia::Color::BLACK)
+}
+
+fn paint(linear: [f32; 4]) -> Paint<'static> {
+    let mut paint = Paint {
+        anti_alias: true,
+        ..Paint::default()
+    };
+
+    paint.set_color(into_color(linear));
+    paint
+}
+
+fn rounded_rectangle(
+    x: f32,
+    y: f32,
+    width: f32,
+    height: f32,
+    radius: f32,
+) -> Option<Path> {
+    let mut builder = PathBuilder::new();
+
+    push_rounded_rectangle(&mut builder, x, y, width, height, radius);
+
+    builder.finish()
+}
+
+fn push_rounded_rectangle(
+    builder: &mut PathBuilder,
+    x: f32,
+    y: f32,
+    width: f32,
+    height: f32,
+    radius: f32,
+) {
+    if width <= 0.0 || height <= 0.0 {
+        return;
+    }
+
+    if radius <= 0.0 {
+        builder.push_rect(x, y, width, height);
+        return;
+    }
+
+    // The distance of the control points of a cubic curve approximating
+    // a quarter of a circle
+    let control = radius * (1.0 - 0.552_284_8);
+    let [right, bottom] = [x + width, y + height];
+
+    builder.move_to(x + radius, y);
+    builder.line_to(right - radius, y);
+    builder.cubic_to(right - control, y, right, y + control, right, y + radius);
+    builder.line_to(right, bottom - radius);
+    builder.cubic_to(
+        right,
+        bottom - control,
+        right - control,
+        bottom,
+        right - radius,
+        bottom,
+    );
+    builder.line_to(x + radius, bottom);
+    builder.cubic_to(
+        x + control,
+        bottom,
+        x,
+        bottom - control,
+        x,
+        bottom - radius,
+    );
+    builder.line_to(x, y + radius);
+    builder.cubic_to(x, y + control, x + control, y, x + radius, y);
+    builder.close();
+}
+
+fn intersection(
+    a: Rectangle<u32>,
+    b: Rectangle<u32>,
+) -> Option<Rectangle<u32>> {
+    let x = a.x.max(b.x);
+    let y = a.y.max(b.y);
+    let right = (a.x + a.width).min(b.x + b.width);
+    let bottom = (a.y + a.height).min(b.y + b.height);
+
+    if right > x && bottom > y {
+        Some(Rectangle {
+            x,
+            y,
+            width: right - x,
+            height: bottom - y,
+        })
+    } else {
+        None
+    }
+}
+
+fn edge(a: [f32; 2], b: [f32; 2], point: [f32; 2]) -> f32 {
+    (b[0] - a[0]) * (point[1] - a[1]) - (b[1] - a[1]) * (point[0] - a[0])
+}
+
+fn to_srgb(linear: f32) -> f32 {
+    let linear = linear.clamp(0.0, 1.0);
+
+    if linear <= 0.003_130_8 {
+        linear * 12.92
+    } else {
+        (1.055 * linear.powf(1.0 / 2.4) - 0.055).min(1.0)
+    }
+}
@@ -0,0 +1,301 @@
+use crate::raster::{self, Canvas};
+
+use iced_graphics::font;
+use iced_graphics::layer;
+use iced_native::alignment;
+use iced_native::{Point, Rectangle, Size};
+
+use glyph_brush::ab_glyph;
+use glyph_brush::GlyphCruncher;
+use std::{cell::RefCell, collections::HashMap};
+
+pub use iced_native::text::Hit;
+
+#[derive(Debug)]
+pub struct Pipeline {
+    brush: RefCell<glyph_brush::GlyphBrush<()>>,
+    font_map: RefCell<HashMap<String, glyph_brush::FontId>>,
+}
+
+impl Pipeline {
+    pub fn new(default_font: Option<&[u8]>) -> Self {
+        let font = default_font
+            .and_then(|bytes| {
+                ab_glyph::FontArc::try_from_vec(bytes.to_vec()).ok()
+            })
+            .unwrap_or_else(|| {
+                ab_glyph::FontArc::try_from_slice(font::FALLBACK)
+                    .expect("Load fallback font")
+            });
+
+        let brush = glyph_brush::GlyphBrushBuilder::using_font(font).build();
+
+        Pipeline {
+            brush: RefCell::new(brush),
+            font_map: RefCell::new(HashMap::new()),
+        }
+    }
+
+    pub fn draw(
+        &self,
+        canvas: &mut Canvas,
+        text: &layer::Text<'_>,
+        scale_factor: f32,
+        clip_mask: Option<&tiny_skia::ClipMask>,
+    ) {
+        use ab_glyph::Font as _;
+
+        let section = glyph_brush::Section {
+            screen_position: (
+                (text.bounds.x * scale_factor).round(),
+                (text.bounds.y * scale_factor).round(),
+            ),
+            bounds: (
+                (text.bounds.width * scale_factor).ceil(),
+                (text.bounds.height * scale_factor).ceil(),
+            ),
+            text: vec![glyph_brush::Text {
+                text: text.content,
+                scale: (text.size * scale_factor).into(),
+                font_id: self.find_font(text.font),
+                extra: glyph_brush::Extra::default(),
+            }],
+            layout: glyph_brush::Layout::default()
+                .h_align(match text.horizontal_alignment {
+                    alignment::Horizontal::Left => {
+                        glyph_brush::HorizontalAlign::Left
+                    }
+                    alignment::Horizontal::Center => {
+                        glyph_brush::HorizontalAlign::Center
+                    }
+                    alignment::Horizontal::Right => {
+                        glyph_brush::HorizontalAlign::Right
+                    }
+                })
+                .v_align(match text.vertical_alignment {
+                    alignment::Vertical::Top => glyph_brush::VerticalAlign::Top,
+                    alignment::Vertical::Center => {
+                        glyph_brush::VerticalAlign::Center
+                    }
+                    alignment::Vertical::Bottom => {
+                        glyph_brush::VerticalAlign::Bottom
+                    }
+                }),
+        };
+
+        let mut brush = self.brush.borrow_mut();
+        let fonts = brush.fonts().to_vec();
+
+        let color = raster::into_color(text.color).to_color_u8();
+        let [r, g, b, a] =
+            [color.red(), color.green(), color.blue(), color.alpha()];
+
+        for section_glyph in brush.glyphs(section) {
+            let font = &fonts[section_glyph.font_id.0];
+
+            let outline = match font.outline_glyph(section_glyph.glyph.clone())
+            {
+                Some(outline) => outline,
+                None => continue,
+            };
+
+            let bounds = outline.px_bounds();
+
+            let mut glyph = match tiny_skia::Pixmap::new(
+                bounds.width() as u32,
+                bounds.height() as u32,
+            ) {
+                Some(glyph) => glyph,
+                None => continue,
+            };
+
+            let width = glyph.width();
+            let pixels = glyph.data_mut();
+
+            outline.draw(|x, y, coverage| {
+                let alpha = f32::from(a) * coverage.clamp(0.0, 1.0) / 255.0;
+                let index = ((y * width + x) * 4) as usize;
+
+                if let Some(pixel) = pixels.get_mut(index..index + 4) {
+                    pixel.copy_from_slice(&[
+                        (f32::from(r) * alpha).round() as u8,
+                        (f32::from(g) * alpha).round() as u8,
+                        (f32::from(b) * alpha).round() as u8,
+                        (alpha * 255.0).round() as u8,
+                    ]);
+                }
+            });
+
+            let _ = canvas.pixmap_mut().draw_pixmap(
+                bounds.min.x as i32,
+                bounds.min.y as i32,
+                glyph.as_ref(),
+                &tiny_skia::PixmapPaint::default(),
+                tiny_skia::Transform::identity(),
+                clip_mask,
+            );
+        }
+    }
+
+    pub fn measure(
+        &self,
+        content: &str,
+        size: f32,
+        font: iced_native::Font,
+        bounds: Size,
+    ) -> (f32, f32) {
+        let section = glyph_brush::Section {
+            bounds: (bounds.width, bounds.height),
+            text: vec![glyph_brush::Text {
+                text: content,
+                scale: size.into(),
+                font_id: self.find_font(font),
+                extra: glyph_brush::Extra::default(),
+            }],
+            ..Default::default()
+        };
+
+        if let Some(bounds) = self.brush.borrow_mut().glyph_bounds(section) {
+            (bounds.width().ceil(), bounds.height().ceil())
+        } else {
+            (0.0, 0.0)
+        }
+    }
+
+    pub fn baseline(&self, size: f32, font: iced_native::Font) -> f32 {
+        use ab_glyph::{Font as _, ScaleFont as _};
+
+        let glyph_brush::FontId(font_id) = self.find_font(font);
+
+        self.brush.borrow().fonts()[font_id]
+            .as_scaled(size)
+            .ascent()
+    }
+
+    pub fn hit_test(
+        &self,
+        content: &str,
+        size: f32,
+        font: iced_native::Font,
+        bounds: Size,
+        point: Point,
+        nearest_only: bool,
+    ) -> Option<Hit> {
+        use ab_glyph::{Font as _, ScaleFont as _};
+
+        let glyph_brush::FontId(font_id) = self.find_font(font);
+
+        let section = glyph_brush::Section {
+            bounds: (bounds.width, bounds.height),
+            text: vec![glyph_brush::Text {
+                text: content,
+                scale: size.into(),
+                font_id: glyph_brush::FontId(font_id),
+                extra: glyph_brush::Extra::default(),
+            }],
+            ..Default::default()
+        };
+
+        let mut brush = self.brush.borrow_mut();
+
+        // The underlying type is FontArc, so clones are cheap.
+        let font = brush.fonts()[font_id].clone().into_scaled(size);
+
+        // Implements an iterator over the glyph bounding boxes.
+        let bounds = brush.glyphs(section).map(
+            |glyph_brush::SectionGlyph {
+                 byte_index, glyph, ..
+             }| {
+                (
+                    *byte_index,
+                    Rectangle::new(
+                        Point::new(
+                            glyph.position.x - font.h_side_bearing(glyph.id),
+                            glyph.position.y - font.ascent(),
+                        ),
+                        Size::new(
+                            font.h_advance(glyph.id),
+                            font.ascent() - font.descent(),
+                        ),
+                    ),
+                )
+            },
+        );
+
+        // Implements computation of the character index based on the byte index
+        // within the input string.
+        let char_index = |byte_index| {
+            let mut b_count = 0;
+            for (i, utf8_len) in
+                content.chars().map(|c| c.len_utf8()).enumerate()
+            {
+                if byte_index < (b_count + utf8_len) {
+                    return i;
+                }
+                b_count += utf8_len;
+            }
+            byte_index
+        };
+
+        if !nearest_only {
+            for (idx, bounds) in bounds.clone() {
+                if bounds.contains(point) {
+                    return Some(Hit::CharOffset(char_index(idx)));
+                }
+            }
+        }
+
+        let nearest = bounds
+            .map(|(index, bounds)| (index, bounds.center()))
+            .min_by(|(_, center_a), (_, center_b)| {
+                center_a
+                    .distance(point)
+                    .partial_cmp(&center_b.distance(point))
+                    .unwrap_or(std::cmp::Ordering::Greater)
+            });
+
+        nearest.map(|(idx, center)| {
+            Hit::NearestCharOffset(char_index(idx), point - center)
+        })
+    }
+
+    pub fn trim_measurement_cache(&mut self) {
+        // The brush is only used to lay out text, so any texture size works
+        loop {
+            let action =
+                self.brush.borrow_mut().process_queued(|_, _| {}, |_| {});
+
+            match action {
+                Ok(_) => break,
+                Err(glyph_brush::BrushError::TextureTooSmall { suggested }) => {
+                    let (width, height) = suggested;
+
+                    self.brush.borrow_mut().resize_texture(width, height);
+                }
+            }
+        }
+    }
+
+    pub fn find_font(&self, font: iced_native::Font) -> glyph_brush::FontId {
+        match font {
+            iced_native::Font::Default => glyph_brush::FontId(0),
+            iced_native::Font::External { name, bytes } => {
+                if let Some(font_id) = self.font_map.borrow().get(name) {
+                    return *font_id;
+                }
+
+                let font = ab_glyph::FontArc::try_from_slice(bytes)
+                    .expect("Load font");
+
+                let font_id = self.brush.borrow_mut().add_font(font);
+
+                let _ = self
+                    .font_map
+                    .borrow_mut()
+                    .insert(String::from(name), font_id);
+
+                font_id
+            }
+        }
+    }
+}
@@ -1,5 +1,11 @@
+use crate::futures::future::BoxFuture;
+use crate::keyboard::Shortcuts;
 use crate::window;
-use crate::{Color, Command, Element, Executor, Settings, Subscription};
+use crate::{
+    Color, Command, Direction, Element, Executor, Settings, Subscription, Theme,
+};
+
+use std::time::Duration;
 
 /// An interactive cross-platform application.
 ///
@@ -141,6 +147,34 @@ pub trait Application: Sized {
         Subscription::none()
     }
 
+    /// Returns the keyboard [`Shortcuts`] of the [`Application`].
+    ///
+    /// A shortcut produces its message when its key is pressed and no widget
+    /// captures the event.
+    ///
+    /// By default, this method returns no shortcuts.
+    fn shortcuts(&self) -> Shortcuts<Self::Message> {
+        Shortcuts::new()
+    }
+
+    /// Returns the [`MenuBar`] of the [`Application`], shown by the operating
+    /// system.
+    ///
+    /// The shortcuts of its items are added to the [`Shortcuts`] of the
+    /// [`Application`].
+    ///
+    /// _**Note:** The operating system only shows the menu bar on macOS and on
+    /// Linux X11 desktops with global menus, with the `menu_bar` feature.
+    /// Elsewhere, including on Wayland, it is drawn at the top of the window._
+    ///
+    /// By default, it returns an empty [`MenuBar`].
+    ///
+    /// [`MenuBar`]: crate::menu_bar::MenuBar
+    #[cfg(not(target_arch = "wasm32"))]
+    fn menu_bar(&self) -> crate::menu_bar::MenuBar<Self::Message> {
+        crate::menu_bar::MenuBar::new()
+    }
+
     /// Returns the widgets to display in the [`Application`].
     ///
     /// These widgets can produce __messages__ based on user interaction.
@@ -158,11 +192,33 @@ pub trait Application: Sized {
         window::Mode::Windowed
     }
 
+    /// Returns the current [`Theme`] of the [`Application`].
+    ///
+    /// The [`Theme`] styles every built-in widget that does not have a
+    /// custom style sheet.
+    ///
+    /// By default, it returns [`Theme::Light`].
+    fn theme(&self) -> Theme {
+        Theme::default()
+    }
+
+    /// Returns the duration of the cross-fade played when the [`Theme`] of
+    /// the [`Application`] changes.
+    ///
+    /// By default, it returns `None` and the [`Theme`] changes instantly.
+    fn theme_transition(&self) -> Option<Duration> {
+        None
+    }
+
     /// Returns the background color of the [`Application`].
     ///
-    /// By default, it returns [`Color::WHITE`].
+    /// The desktop shows through a background with some transparency, like
+    /// [`Color::TRANSPARENT`], as long as the window is `transparent` in its
+    /// [`window::Settings`].
+    ///
+    /// By default, it returns the background of the current [`Theme`].
     fn background_color(&self) -> Color {
-        Color::WHITE
+        self.theme().palette().background
     }
 
     /// Returns the scale factor of the [`Application`].
@@ -178,6 +234,33 @@ pub trait Application: Sized {
         1.0
     }
 
+    /// Returns the [`Direction`] in which the content of the [`Application`]
+    /// flows.
+    ///
+    /// It can be used to mirror the user interface when localizing it to a
+    /// right-to-left language, like Arabic or Hebrew. Parts of the user
+    /// interface can flow in a different direction by using a
+    /// [`Directional`] widget.
+    ///
+    /// [`Directional`]: crate::widget::Directional
+    ///
+    /// By default, it returns [`Direction::LeftToRight`].
+    fn direction(&self) -> Direction {
+        Direction::LeftToRight
+    }
+
+    /// Returns the [`window::Rotation`] of the contents of the
+    /// [`Application`].
+    ///
+    /// It can be used to render correctly on displays mounted in a rotated
+    /// orientation, like the panels of embedded devices and kiosks. The
+    /// positions of mouse, touch and pen events are rotated accordingly.
+    ///
+    /// By default, it returns [`window::Rotation::None`].
+    fn rotation(&self) -> window::Rotation {
+        window::Rotation::None
+    }
+
     /// Returns whether the [`Application`] should be terminated.
     ///
     /// By default, it returns `false`.
@@ -185,6 +268,19 @@ pub trait Application: Sized {
         false
     }
 
+    /// Returns a future that runs to completion right before the
+    /// [`Application`] exits.
+    ///
+    /// It can be used to shut down gracefully; like saving some state to
+    /// disk or closing a connection.
+    ///
+    /// _**Note:** This hook is not run on the web._
+    ///
+    /// By default, it returns `None`.
+    fn shutdown(&mut self) -> Option<BoxFuture<'static, ()>> {
+        None
+    }
+
     /// Runs the [`Application`].
     ///
     /// On native platforms, this method will take control of the current thread
@@ -228,7 +324,7 @@ pub trait Application: Sized {
     }
 }
 
-struct Instance<A: Application>(A);
+pub(crate) struct Instance<A: Application>(pub(crate) A);
 
 #[cfg(not(target_arch = "wasm32"))]
 impl<A> iced_winit::Program for Instance<A>
@@ -276,6 +372,22 @@ where
         self.0.subscription()
     }
 
+    fn shortcuts(&self) -> iced_winit::keyboard::Shortcuts<Self::Message> {
+        self.0.shortcuts()
+    }
+
+    fn menu_bar(&self) -> iced_winit::menu_bar::MenuBar<Self::Message> {
+        self.0.menu_bar()
+    }
+
+    fn theme(&self) -> Theme {
+        self.0.theme()
+    }
+
+    fn theme_transition(&self) -> Option<Duration> {
+        self.0.theme_transition()
+    }
+
     fn background_color(&self) -> Color {
         self.0.background_color()
     }
@@ -284,9 +396,30 @@ where
         self.0.scale_factor()
     }
 
+    fn direction(&self) -> Direction {
+        self.0.direction()
+    }
+
+    fn rotation(&self) -> iced_winit::Rotation {
+        match self.0.rotation() {
+            window::Rotation::None => iced_winit::Rotation::None,
+            window::Rotation::Clockwise90 => iced_winit::Rotation::Clockwise90,
+            window::Rotation::Clockwise180 => {
+                iced_winit::Rotation::Clockwise180
+            }
+            window::Rotation::Clockwise270 => {
+                iced_winit::Rotation::Clockwise270
+            }
+        }
+    }
+
     fn should_exit(&self) -> bool {
         self.0.should_exit()
     }
+
+    fn shutdown(&mut self) -> Option<BoxFuture<'static, ()>> {
+        self.0.shutdown()
+    }
 }
 
 #[cfg(target_arch = "wasm32")]
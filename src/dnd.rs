@@ -0,0 +1,2 @@
+//! Drag and drop data between widgets and applications.
+pub use crate::runtime::dnd::{Data, Event, Files, Payload};
@@ -1,4 +1,5 @@
 //! Choose your preferred executor to power your application.
+pub use crate::runtime::executor::Settings;
 pub use crate::runtime::Executor;
 
 pub use platform::Default;
@@ -53,6 +54,12 @@ mod platform {
             Ok(Default(Executor::new()?))
         }
 
+        fn with_settings(
+            settings: executor::Settings,
+        ) -> Result<Self, futures::io::Error> {
+            Ok(Default(Executor::with_settings(settings)?))
+        }
+
         fn spawn(
             &self,
             future: impl futures::Future<Output = ()> + Send + 'static,
@@ -60,6 +67,13 @@ mod platform {
             let _ = self.0.spawn(future);
         }
 
+        fn spawn_background(
+            &self,
+            future: impl futures::Future<Output = ()> + Send + 'static,
+        ) {
+            self.0.spawn_background(future);
+        }
+
         fn enter<R>(&self, f: impl FnOnce() -> R) -> R {
             super::Executor::enter(&self.0, f)
         }
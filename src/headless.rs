@@ -0,0 +1,84 @@
+//! Run the logic of an [`Application`] without a window.
+use crate::application::Instance;
+use crate::Application;
+
+use std::time::{Duration, Instant};
+
+/// A runtime that runs an [`Application`] without a window or a graphics
+/// device, with a clock that is controlled manually.
+///
+/// It is useful to test the message flows of an [`Application`]:
+///
+/// - Every command runs in the current thread, until it stalls.
+/// - Window and widget actions are ignored.
+/// - The clipboard is kept in memory.
+/// - Subscriptions that depend on a specific executor, like `time::every`,
+///   will not produce any messages. Use [`window::frames`] and
+///   [`Headless::advance`] to drive time instead.
+///
+/// [`window::frames`]: crate::window::frames
+#[allow(missing_debug_implementations)]
+pub struct Headless<A: Application + 'static>(
+    iced_winit::Headless<Instance<A>>,
+);
+
+impl<A> Headless<A>
+where
+    A: Application + 'static,
+{
+    /// Creates a new [`Headless`] runtime for the [`Application`] with the
+    /// given flags, running its initial command until it stalls.
+    pub fn new(flags: A::Flags) -> Self {
+        Self(iced_winit::Headless::new(flags))
+    }
+
+    /// Returns a reference to the [`Application`].
+    pub fn application(&self) -> &A {
+        &self.0.application().0
+    }
+
+    /// Returns the current time of the [`Headless`] runtime.
+    pub fn now(&self) -> Instant {
+        self.0.now()
+    }
+
+    /// Returns the amount of messages processed by the [`Application`] so
+    /// far.
+    pub fn updates(&self) -> usize {
+        self.0.updates()
+    }
+
+    /// Returns the current contents of the clipboard.
+    pub fn clipboard(&self) -> Option<&str> {
+        self.0.clipboard()
+    }
+
+    /// Returns true if the [`Application`] wants to exit.
+    pub fn should_exit(&self) -> bool {
+        self.0.should_exit()
+    }
+
+    /// Updates the [`Application`] with the given message and runs the
+    /// resulting command until it stalls.
+    pub fn update(&mut self, message: A::Message) {
+        self.0.update(message);
+    }
+
+    /// Broadcasts the given event to the subscriptions of the
+    /// [`Application`] and runs until it stalls.
+    pub fn broadcast(&mut self, event: iced_winit::Event) {
+        self.0.broadcast(event);
+    }
+
+    /// Advances the time of the [`Headless`] runtime by the given
+    /// [`Duration`], presenting a new frame, and runs until it stalls.
+    pub fn advance(&mut self, duration: Duration) {
+        self.0.advance(duration);
+    }
+
+    /// Runs the pending commands and subscriptions of the [`Application`]
+    /// until they stall.
+    pub fn settle(&mut self) {
+        self.0.settle();
+    }
+}
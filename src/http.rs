@@ -0,0 +1,2 @@
+//! Perform HTTP requests and track their progress.
+pub use iced_futures::http::{download, upload, Error};
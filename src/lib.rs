@@ -187,13 +187,40 @@ mod result;
 mod sandbox;
 
 pub mod clipboard;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod dnd;
 pub mod executor;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod headless;
 pub mod keyboard;
 pub mod mouse;
 pub mod settings;
 pub mod widget;
 pub mod window;
 
+#[cfg(all(
+    any(feature = "watch", feature = "websocket"),
+    not(target_arch = "wasm32")
+))]
+#[cfg_attr(docsrs, doc(cfg(any(feature = "watch", feature = "websocket"))))]
+pub mod subscription;
+
+#[cfg(all(feature = "http", not(target_arch = "wasm32")))]
+#[cfg_attr(docsrs, doc(cfg(feature = "http")))]
+pub mod http;
+
+#[cfg(all(feature = "l10n", not(target_arch = "wasm32")))]
+#[cfg_attr(docsrs, doc(cfg(feature = "l10n")))]
+pub use runtime::l10n;
+
+#[cfg(all(feature = "persistence", not(target_arch = "wasm32")))]
+#[cfg_attr(docsrs, doc(cfg(feature = "persistence")))]
+pub use runtime::persistence;
+
+#[cfg(all(feature = "tray", not(target_arch = "wasm32")))]
+#[cfg_attr(docsrs, doc(cfg(feature = "tray")))]
+pub use runtime::tray;
+
 #[cfg(all(
     any(
         feature = "tokio",
@@ -248,9 +275,23 @@ pub use result::Result;
 pub use sandbox::Sandbox;
 pub use settings::Settings;
 
+pub use iced_futures::progress;
 pub use runtime::alignment;
+#[cfg(not(target_arch = "wasm32"))]
+pub use runtime::animation;
+#[cfg(not(target_arch = "wasm32"))]
+pub use runtime::command::Proxy;
+#[cfg(not(target_arch = "wasm32"))]
+pub use runtime::history;
+#[cfg(not(target_arch = "wasm32"))]
+pub use runtime::menu_bar;
+
+#[cfg(all(feature = "snapshot", not(target_arch = "wasm32")))]
+#[cfg_attr(docsrs, doc(cfg(feature = "snapshot")))]
+pub use iced_wgpu::{assert_snapshot, snapshot};
 pub use runtime::futures;
+pub use runtime::theme;
 pub use runtime::{
-    Alignment, Background, Color, Command, Font, Length, Point, Rectangle,
-    Size, Subscription, Vector,
+    Alignment, Background, Color, Command, Direction, Font, Length, Point,
+    Rectangle, Size, Subscription, Theme, Vector,
 };
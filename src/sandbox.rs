@@ -1,7 +1,11 @@
+use crate::window;
 use crate::{
-    Application, Color, Command, Element, Error, Settings, Subscription,
+    Application, Color, Command, Direction, Element, Error, Settings,
+    Subscription, Theme,
 };
 
+use std::time::Duration;
+
 /// A sandboxed [`Application`].
 ///
 /// If you are a just getting started with the library, this trait offers a
@@ -111,11 +115,29 @@ pub trait Sandbox {
     /// These widgets can produce __messages__ based on user interaction.
     fn view(&mut self) -> Element<'_, Self::Message>;
 
+    /// Returns the current [`Theme`] of the [`Sandbox`].
+    ///
+    /// The [`Theme`] styles every built-in widget that does not have a
+    /// custom style sheet.
+    ///
+    /// By default, it returns [`Theme::Light`].
+    fn theme(&self) -> Theme {
+        Theme::default()
+    }
+
+    /// Returns the duration of the cross-fade played when the [`Theme`] of
+    /// the [`Sandbox`] changes.
+    ///
+    /// By default, it returns `None` and the [`Theme`] changes instantly.
+    fn theme_transition(&self) -> Option<Duration> {
+        None
+    }
+
     /// Returns the background color of the [`Sandbox`].
     ///
-    /// By default, it returns [`Color::WHITE`].
+    /// By default, it returns the background of the current [`Theme`].
     fn background_color(&self) -> Color {
-        Color::WHITE
+        self.theme().palette().background
     }
 
     /// Returns the scale factor of the [`Sandbox`].
@@ -131,6 +153,27 @@ pub trait Sandbox {
         1.0
     }
 
+    /// Returns the [`Direction`] in which the content of the [`Sandbox`]
+    /// flows.
+    ///
+    /// It can be used to mirror the user interface when localizing it to a
+    /// right-to-left language, like Arabic or Hebrew.
+    ///
+    /// By default, it returns [`Direction::LeftToRight`].
+    fn direction(&self) -> Direction {
+        Direction::LeftToRight
+    }
+
+    /// Returns the [`window::Rotation`] of the contents of the [`Sandbox`].
+    ///
+    /// It can be used to render correctly on displays mounted in a rotated
+    /// orientation, like the panels of embedded devices and kiosks.
+    ///
+    /// By default, it returns [`window::Rotation::None`].
+    fn rotation(&self) -> window::Rotation {
+        window::Rotation::None
+    }
+
     /// Returns whether the [`Sandbox`] should be terminated.
     ///
     /// By default, it returns `false`.
@@ -182,6 +225,14 @@ where
         T::view(self)
     }
 
+    fn theme(&self) -> Theme {
+        T::theme(self)
+    }
+
+    fn theme_transition(&self) -> Option<Duration> {
+        T::theme_transition(self)
+    }
+
     fn background_color(&self) -> Color {
         T::background_color(self)
     }
@@ -190,6 +241,14 @@ where
         T::scale_factor(self)
     }
 
+    fn direction(&self) -> Direction {
+        T::direction(self)
+    }
+
+    fn rotation(&self) -> window::Rotation {
+        T::rotation(self)
+    }
+
     fn should_exit(&self) -> bool {
         T::should_exit(self)
     }
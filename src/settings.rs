@@ -1,4 +1,5 @@
 //! Configure your application.
+use crate::executor;
 use crate::window;
 
 /// The settings of an application.
@@ -51,10 +52,42 @@ pub struct Settings<Flags> {
     /// Whether the [`Application`] should exit when the user requests the
     /// window to close (e.g. the user presses the close button).
     ///
+    /// When disabled, a [`window::Event::CloseRequested`] is produced instead,
+    /// and the [`Application`] can decide to close the window with
+    /// [`window::close`]; for instance, after asking the user to save any
+    /// unsaved changes.
+    ///
     /// By default, it is enabled.
     ///
     /// [`Application`]: crate::Application
     pub exit_on_close_request: bool,
+
+    /// Whether the user can zoom the whole user interface with the keyboard.
+    ///
+    /// When enabled, <kbd>Ctrl</kbd>+<kbd>+</kbd> zooms in,
+    /// <kbd>Ctrl</kbd>+<kbd>-</kbd> zooms out, and <kbd>Ctrl</kbd>+<kbd>0</kbd>
+    /// resets the zoom, as long as no widget captures these presses.
+    ///
+    /// By default, it is disabled.
+    pub keyboard_zoom: bool,
+
+    /// The maximum amount of frames presented per second, if any.
+    ///
+    /// It can be changed at runtime with [`window::set_frame_rate_cap`],
+    /// together with the present mode of the windows with
+    /// [`window::set_present_mode`].
+    ///
+    /// By default, it is `None`.
+    pub frame_rate_cap: Option<u16>,
+
+    /// The settings of the [`Executor`] of the [`Application`]; like its
+    /// amount of threads.
+    ///
+    /// They will be ignored on the Web.
+    ///
+    /// [`Executor`]: crate::Executor
+    /// [`Application`]: crate::Application
+    pub executor: executor::Settings,
 }
 
 impl<Flags> Settings<Flags> {
@@ -73,6 +106,9 @@ impl<Flags> Settings<Flags> {
             text_multithreading: default_settings.text_multithreading,
             antialiasing: default_settings.antialiasing,
             exit_on_close_request: default_settings.exit_on_close_request,
+            keyboard_zoom: default_settings.keyboard_zoom,
+            frame_rate_cap: default_settings.frame_rate_cap,
+            executor: default_settings.executor,
         }
     }
 }
@@ -91,6 +127,9 @@ where
             text_multithreading: false,
             antialiasing: false,
             exit_on_close_request: true,
+            keyboard_zoom: false,
+            frame_rate_cap: None,
+            executor: executor::Settings::default(),
         }
     }
 }
@@ -103,6 +142,9 @@ impl<Flags> From<Settings<Flags>> for iced_winit::Settings<Flags> {
             window: settings.window.into(),
             flags: settings.flags,
             exit_on_close_request: settings.exit_on_close_request,
+            keyboard_zoom: settings.keyboard_zoom,
+            frame_rate_cap: settings.frame_rate_cap,
+            executor: settings.executor,
         }
     }
 }
@@ -0,0 +1,6 @@
+//! Listen to external events in your application.
+#[cfg(feature = "watch")]
+pub use iced_futures::subscription::{watch, watch_debounced};
+
+#[cfg(feature = "websocket")]
+pub use iced_futures::subscription::websocket;
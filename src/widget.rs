@@ -16,9 +16,16 @@
 #[cfg(not(target_arch = "wasm32"))]
 mod platform {
     pub use crate::renderer::widget::{
-        button, checkbox, container, pane_grid, pick_list, progress_bar, radio,
-        rule, scrollable, slider, text_input, toggler, tooltip, Column, Row,
-        Space, Text,
+        button, cached, checkbox, container, disabled, draggable, drop_target,
+        error_boundary, form, grid, inspector, lazy, mouse_area, pagination,
+        pane_grid, pick_list, progress_bar, radio, reorderable_column,
+        responsive, rule, scrollable, slider, stack, text_input, toggler,
+        tooltip, Animated, AspectRatio, Column, Directional, MouseArea, Reveal,
+        Row, ShortcutScope, Space, Text, WindowHandle, Wrap,
+    };
+
+    pub use crate::runtime::widget::{
+        focus, focus_next, focus_previous, operation, Id,
     };
 
     #[cfg(any(feature = "canvas", feature = "glow_canvas"))]
@@ -39,6 +46,10 @@ mod platform {
     pub mod image {
         //! Display images in your user interface.
         pub use crate::runtime::image::Handle;
+
+        #[cfg(feature = "image_fetch")]
+        #[cfg_attr(docsrs, doc(cfg(feature = "image_fetch")))]
+        pub use crate::runtime::image::{fetch, fetch::Cache};
         pub use crate::runtime::widget::image::viewer;
         pub use crate::runtime::widget::image::{Image, Viewer};
     }
@@ -52,10 +63,34 @@ mod platform {
 
     #[doc(no_inline)]
     pub use {
-        button::Button, checkbox::Checkbox, container::Container, image::Image,
-        pane_grid::PaneGrid, pick_list::PickList, progress_bar::ProgressBar,
-        radio::Radio, rule::Rule, scrollable::Scrollable, slider::Slider,
-        svg::Svg, text_input::TextInput, toggler::Toggler, tooltip::Tooltip,
+        button::Button,
+        cached::Cached,
+        checkbox::Checkbox,
+        container::Container,
+        disabled::{disabled, Disabled},
+        draggable::Draggable,
+        drop_target::DropTarget,
+        error_boundary::ErrorBoundary,
+        form::Form,
+        grid::Grid,
+        image::Image,
+        inspector::Inspector,
+        lazy::Lazy,
+        pagination::Pagination,
+        pane_grid::PaneGrid,
+        pick_list::PickList,
+        progress_bar::ProgressBar,
+        radio::Radio,
+        reorderable_column::ReorderableColumn,
+        responsive::Responsive,
+        rule::Rule,
+        scrollable::Scrollable,
+        slider::Slider,
+        stack::Stack,
+        svg::Svg,
+        text_input::TextInput,
+        toggler::Toggler,
+        tooltip::Tooltip,
     };
 
     #[cfg(any(feature = "canvas", feature = "glow_canvas"))]
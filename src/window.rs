@@ -1,6 +1,7 @@
 //! Configure the window of your application in native platforms.
 mod mode;
 mod position;
+mod rotation;
 mod settings;
 
 pub mod icon;
@@ -8,7 +9,23 @@ pub mod icon;
 pub use icon::Icon;
 pub use mode::Mode;
 pub use position::Position;
+pub use rotation::Rotation;
 pub use settings::Settings;
 
 #[cfg(not(target_arch = "wasm32"))]
-pub use crate::runtime::window::{move_to, resize};
+pub use crate::runtime::window::{
+    center_on_monitor, close, color_scheme, color_scheme_changes, drag,
+    drag_resize, exit_fullscreen, focus_changes, frames, fullscreen_on,
+    maximize, minimize, monitors, move_to, move_to_monitor, request_attention,
+    resize, restore, screenshot, screenshot_of, set_continuous_rendering,
+    set_continuous_rendering_of, set_decorations, set_frame_rate_cap, set_icon,
+    set_level, set_max_size, set_min_size, set_present_mode, set_progress,
+    set_resizable, start_drag, visibility_changes, ColorScheme, Frame, Level,
+    Monitor, PresentMode, ResizeDirection, Screenshot, UserAttention,
+};
+
+#[cfg(target_os = "android")]
+pub use crate::runtime::window::{hide_soft_keyboard, show_soft_keyboard};
+
+#[cfg(not(target_arch = "wasm32"))]
+pub use crate::runtime::window::{Event, Id};
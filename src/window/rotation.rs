@@ -0,0 +1,16 @@
+/// The clockwise rotation of the contents of a window.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Rotation {
+    /// The contents are not rotated.
+    #[default]
+    None,
+
+    /// The contents are rotated by 90 degrees.
+    Clockwise90,
+
+    /// The contents are rotated by 180 degrees.
+    Clockwise180,
+
+    /// The contents are rotated by 270 degrees.
+    Clockwise270,
+}
@@ -1,4 +1,6 @@
 //! Allow your users to perform actions by pressing a button.
+use crate::theme::Widget;
+use crate::Theme;
 use iced_core::{Background, Color, Vector};
 
 /// The appearance of a button.
@@ -45,6 +47,16 @@ pub trait StyleSheet {
         }
     }
 
+    fn focused(&self) -> Style {
+        let active = self.active();
+
+        Style {
+            border_width: active.border_width.max(2.0),
+            border_color: [0.3, 0.55, 0.95].into(),
+            ..active
+        }
+    }
+
     fn disabled(&self) -> Style {
         let active = self.active();
 
@@ -65,24 +77,65 @@ pub trait StyleSheet {
     }
 }
 
-struct Default;
-
-impl StyleSheet for Default {
+impl StyleSheet for Theme {
     fn active(&self) -> Style {
+        let palette = self.extended_palette_of(Widget::Button);
+
         Style {
-            shadow_offset: Vector::new(0.0, 0.0),
-            background: Some(Background::Color([0.87, 0.87, 0.87].into())),
+            shadow_offset: Vector::default(),
+            background: Some(Background::Color(palette.primary.base)),
             border_radius: 2.0,
-            border_width: 1.0,
-            border_color: [0.7, 0.7, 0.7].into(),
-            text_color: Color::BLACK,
+            border_width: 0.0,
+            border_color: Color::TRANSPARENT,
+            text_color: palette.primary.text,
+        }
+    }
+
+    fn hovered(&self) -> Style {
+        let palette = self.extended_palette_of(Widget::Button);
+
+        Style {
+            background: Some(Background::Color(palette.primary.hovered)),
+            ..self.active()
+        }
+    }
+
+    fn pressed(&self) -> Style {
+        let palette = self.extended_palette_of(Widget::Button);
+
+        Style {
+            background: Some(Background::Color(palette.primary.pressed)),
+            ..self.active()
+        }
+    }
+
+    fn focused(&self) -> Style {
+        let palette = self.extended_palette_of(Widget::Button);
+
+        Style {
+            border_width: 2.0,
+            border_color: palette.primary.pressed,
+            ..self.active()
+        }
+    }
+
+    fn disabled(&self) -> Style {
+        let palette = self.extended_palette_of(Widget::Button);
+
+        Style {
+            background: Some(Background::Color(palette.primary.disabled)),
+            text_color: Color {
+                a: 0.5,
+                ..palette.primary.text
+            },
+            ..self.active()
         }
     }
 }
 
 impl<'a> std::default::Default for Box<dyn StyleSheet + 'a> {
     fn default() -> Self {
-        Box::new(Default)
+        Box::new(Theme::default())
     }
 }
 
@@ -1,4 +1,6 @@
 //! Show toggle controls using checkboxes.
+use crate::theme::Widget;
+use crate::Theme;
 use iced_core::{Background, Color};
 
 /// The appearance of a checkbox.
@@ -19,23 +21,41 @@ pub trait StyleSheet {
     fn hovered(&self, is_checked: bool) -> Style;
 }
 
-struct Default;
+impl StyleSheet for Theme {
+    fn active(&self, is_checked: bool) -> Style {
+        let palette = self.extended_palette_of(Widget::Checkbox);
+
+        let shades = if is_checked {
+            palette.primary
+        } else {
+            palette.background
+        };
 
-impl StyleSheet for Default {
-    fn active(&self, _is_checked: bool) -> Style {
         Style {
-            background: Background::Color(Color::from_rgb(0.95, 0.95, 0.95)),
-            checkmark_color: Color::from_rgb(0.3, 0.3, 0.3),
-            text_color: Color::BLACK,
-            border_radius: 5.0,
+            background: Background::Color(shades.base),
+            checkmark_color: palette.primary.text,
+            text_color: self.palette_of(Widget::Checkbox).text,
+            border_radius: 2.0,
             border_width: 1.0,
-            border_color: Color::from_rgb(0.6, 0.6, 0.6),
+            border_color: if is_checked {
+                palette.primary.base
+            } else {
+                palette.border
+            },
         }
     }
 
     fn hovered(&self, is_checked: bool) -> Style {
+        let palette = self.extended_palette_of(Widget::Checkbox);
+
+        let shades = if is_checked {
+            palette.primary
+        } else {
+            palette.background
+        };
+
         Style {
-            background: Background::Color(Color::from_rgb(0.90, 0.90, 0.90)),
+            background: Background::Color(shades.hovered),
             ..self.active(is_checked)
         }
     }
@@ -43,7 +63,7 @@ impl StyleSheet for Default {
 
 impl<'a> std::default::Default for Box<dyn StyleSheet + 'a> {
     fn default() -> Self {
-        Box::new(Default)
+        Box::new(Theme::default())
     }
 }
 
@@ -1,4 +1,5 @@
 //! Decorate content and apply alignment.
+use crate::Theme;
 use iced_core::{Background, Color};
 
 /// The appearance of a container.
@@ -29,23 +30,15 @@ pub trait StyleSheet {
     fn style(&self) -> Style;
 }
 
-struct Default;
-
-impl StyleSheet for Default {
+impl StyleSheet for Theme {
     fn style(&self) -> Style {
-        Style {
-            text_color: None,
-            background: None,
-            border_radius: 0.0,
-            border_width: 0.0,
-            border_color: Color::TRANSPARENT,
-        }
+        Style::default()
     }
 }
 
 impl<'a> std::default::Default for Box<dyn StyleSheet + 'a> {
     fn default() -> Self {
-        Box::new(Default)
+        Box::new(Theme::default())
     }
 }
 
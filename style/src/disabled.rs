@@ -0,0 +1,42 @@
+//! Dim a disabled subtree of widgets.
+use crate::Theme;
+
+/// The appearance of a disabled subtree of widgets.
+#[derive(Debug, Clone, Copy)]
+pub struct Style {
+    /// The opacity applied to the whole subtree.
+    pub opacity: f32,
+}
+
+impl std::default::Default for Style {
+    fn default() -> Self {
+        Style { opacity: 0.5 }
+    }
+}
+
+/// A set of rules that dictate the style of a disabled subtree of widgets.
+pub trait StyleSheet {
+    /// Produces the style of a disabled subtree of widgets.
+    fn style(&self) -> Style;
+}
+
+impl StyleSheet for Theme {
+    fn style(&self) -> Style {
+        Style::default()
+    }
+}
+
+impl<'a> std::default::Default for Box<dyn StyleSheet + 'a> {
+    fn default() -> Self {
+        Box::new(Theme::default())
+    }
+}
+
+impl<'a, T> From<T> for Box<dyn StyleSheet + 'a>
+where
+    T: 'a + StyleSheet,
+{
+    fn from(style: T) -> Self {
+        Box::new(style)
+    }
+}
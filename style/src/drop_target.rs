@@ -0,0 +1,75 @@
+//! Accept data dropped by the user.
+use crate::theme::Widget;
+use crate::Theme;
+use iced_core::{Background, Color};
+
+/// The appearance of a drop target.
+#[derive(Debug, Clone, Copy)]
+pub struct Style {
+    pub background: Option<Background>,
+    pub border_radius: f32,
+    pub border_width: f32,
+    pub border_color: Color,
+}
+
+impl std::default::Default for Style {
+    fn default() -> Self {
+        Self {
+            background: None,
+            border_radius: 0.0,
+            border_width: 0.0,
+            border_color: Color::TRANSPARENT,
+        }
+    }
+}
+
+/// A set of rules that dictate the style of a drop target.
+pub trait StyleSheet {
+    /// Produces the style of a drop target.
+    fn active(&self) -> Style;
+
+    /// Produces the style of a drop target while an acceptable payload is
+    /// being dragged anywhere.
+    fn expecting(&self) -> Style {
+        self.active()
+    }
+
+    /// Produces the style of a drop target while an acceptable payload is
+    /// being dragged over it.
+    fn hovered(&self) -> Style;
+}
+
+impl StyleSheet for Theme {
+    fn active(&self) -> Style {
+        Style::default()
+    }
+
+    fn hovered(&self) -> Style {
+        let palette = self.extended_palette_of(Widget::DropTarget);
+
+        Style {
+            background: Some(Background::Color(Color {
+                a: 0.1,
+                ..palette.primary.base
+            })),
+            border_radius: 2.0,
+            border_width: 1.0,
+            border_color: palette.primary.base,
+        }
+    }
+}
+
+impl<'a> std::default::Default for Box<dyn StyleSheet + 'a> {
+    fn default() -> Self {
+        Box::new(Theme::default())
+    }
+}
+
+impl<'a, T> From<T> for Box<dyn StyleSheet + 'a>
+where
+    T: 'a + StyleSheet,
+{
+    fn from(style: T) -> Self {
+        Box::new(style)
+    }
+}
@@ -1,3 +1,5 @@
+use crate::theme::Widget;
+use crate::Theme;
 use iced_core::{Background, Color};
 
 /// The appearance of a menu.
@@ -23,3 +25,18 @@ impl std::default::Default for Style {
         }
     }
 }
+
+impl From<&Theme> for Style {
+    fn from(theme: &Theme) -> Self {
+        let palette = theme.extended_palette_of(Widget::Menu);
+
+        Self {
+            text_color: theme.palette_of(Widget::Menu).text,
+            background: Background::Color(palette.background.base),
+            border_width: 1.0,
+            border_color: palette.border,
+            selected_text_color: palette.primary.text,
+            selected_background: Background::Color(palette.primary.base),
+        }
+    }
+}
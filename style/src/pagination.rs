@@ -0,0 +1,102 @@
+//! Navigate through a collection split in pages.
+use crate::theme::Widget;
+use crate::Theme;
+use iced_core::{Background, Color};
+
+/// The appearance of a page button of a pagination control.
+#[derive(Debug, Clone, Copy)]
+pub struct Style {
+    pub background: Option<Background>,
+    pub border_radius: f32,
+    pub border_width: f32,
+    pub border_color: Color,
+    pub text_color: Color,
+}
+
+impl std::default::Default for Style {
+    fn default() -> Self {
+        Self {
+            background: None,
+            border_radius: 2.0,
+            border_width: 0.0,
+            border_color: Color::TRANSPARENT,
+            text_color: Color::BLACK,
+        }
+    }
+}
+
+/// A set of rules that dictate the style of a pagination control.
+pub trait StyleSheet {
+    fn active(&self) -> Style;
+
+    fn hovered(&self) -> Style {
+        Style {
+            background: Some(Background::Color([0.9, 0.9, 0.9].into())),
+            ..self.active()
+        }
+    }
+
+    fn selected(&self) -> Style {
+        Style {
+            background: Some(Background::Color([0.87, 0.87, 0.87].into())),
+            border_width: 1.0,
+            border_color: [0.7, 0.7, 0.7].into(),
+            ..self.active()
+        }
+    }
+
+    fn disabled(&self) -> Style {
+        let active = self.active();
+
+        Style {
+            text_color: Color {
+                a: active.text_color.a * 0.5,
+                ..active.text_color
+            },
+            ..active
+        }
+    }
+}
+
+impl StyleSheet for Theme {
+    fn active(&self) -> Style {
+        Style {
+            text_color: self.palette_of(Widget::Pagination).text,
+            ..Style::default()
+        }
+    }
+
+    fn hovered(&self) -> Style {
+        let palette = self.extended_palette_of(Widget::Pagination);
+
+        Style {
+            background: Some(Background::Color(palette.background.hovered)),
+            ..self.active()
+        }
+    }
+
+    fn selected(&self) -> Style {
+        let palette = self.extended_palette_of(Widget::Pagination);
+
+        Style {
+            background: Some(Background::Color(palette.primary.base)),
+            text_color: palette.primary.text,
+            ..self.active()
+        }
+    }
+}
+
+impl<'a> std::default::Default for Box<dyn StyleSheet + 'a> {
+    fn default() -> Self {
+        Box::new(Theme::default())
+    }
+}
+
+impl<'a, T> From<T> for Box<dyn StyleSheet + 'a>
+where
+    T: 'a + StyleSheet,
+{
+    fn from(style: T) -> Self {
+        Box::new(style)
+    }
+}
@@ -1,5 +1,7 @@
 //! Let your users split regions of your application and organize layout
 //! dynamically.
+use crate::theme::Widget;
+use crate::Theme;
 use iced_core::Color;
 
 /// A set of rules that dictate the style of a container.
@@ -23,21 +25,29 @@ pub struct Line {
     pub width: f32,
 }
 
-struct Default;
-
-impl StyleSheet for Default {
+impl StyleSheet for Theme {
     fn picked_split(&self) -> Option<Line> {
-        None
+        let palette = self.extended_palette_of(Widget::PaneGrid);
+
+        Some(Line {
+            color: palette.primary.pressed,
+            width: 2.0,
+        })
     }
 
     fn hovered_split(&self) -> Option<Line> {
-        None
+        let palette = self.extended_palette_of(Widget::PaneGrid);
+
+        Some(Line {
+            color: palette.primary.hovered,
+            width: 2.0,
+        })
     }
 }
 
 impl<'a> std::default::Default for Box<dyn StyleSheet + 'a> {
     fn default() -> Self {
-        Box::new(Default)
+        Box::new(Theme::default())
     }
 }
 
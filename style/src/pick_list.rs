@@ -1,4 +1,6 @@
 use crate::menu;
+use crate::theme::Widget;
+use crate::Theme;
 use iced_core::{Background, Color};
 
 /// The appearance of a pick list.
@@ -37,20 +39,30 @@ pub trait StyleSheet {
     fn hovered(&self) -> Style;
 }
 
-struct Default;
-
-impl StyleSheet for Default {
+impl StyleSheet for Theme {
     fn menu(&self) -> menu::Style {
-        menu::Style::default()
+        menu::Style::from(self)
     }
 
     fn active(&self) -> Style {
-        Style::default()
+        let palette = self.extended_palette_of(Widget::PickList);
+
+        Style {
+            text_color: self.palette_of(Widget::PickList).text,
+            placeholder_color: palette.weak_text,
+            background: Background::Color(palette.background.base),
+            border_radius: 2.0,
+            border_width: 1.0,
+            border_color: palette.border,
+            icon_size: 0.7,
+        }
     }
 
     fn hovered(&self) -> Style {
+        let palette = self.extended_palette_of(Widget::PickList);
+
         Style {
-            border_color: Color::BLACK,
+            border_color: palette.primary.base,
             ..self.active()
         }
     }
@@ -58,7 +70,7 @@ impl StyleSheet for Default {
 
 impl<'a> std::default::Default for Box<dyn StyleSheet + 'a> {
     fn default() -> Self {
-        Box::new(Default)
+        Box::new(Theme::default())
     }
 }
 
@@ -1,5 +1,7 @@
 //! Provide progress feedback to your users.
-use iced_core::{Background, Color};
+use crate::theme::Widget;
+use crate::Theme;
+use iced_core::Background;
 
 /// The appearance of a progress bar.
 #[derive(Debug, Clone, Copy)]
@@ -14,13 +16,13 @@ pub trait StyleSheet {
     fn style(&self) -> Style;
 }
 
-struct Default;
-
-impl StyleSheet for Default {
+impl StyleSheet for Theme {
     fn style(&self) -> Style {
+        let palette = self.extended_palette_of(Widget::ProgressBar);
+
         Style {
-            background: Background::Color(Color::from_rgb(0.6, 0.6, 0.6)),
-            bar: Background::Color(Color::from_rgb(0.3, 0.9, 0.3)),
+            background: Background::Color(palette.background.pressed),
+            bar: Background::Color(palette.primary.base),
             border_radius: 5.0,
         }
     }
@@ -28,7 +30,7 @@ impl StyleSheet for Default {
 
 impl<'a> std::default::Default for Box<dyn StyleSheet + 'a> {
     fn default() -> Self {
-        Box::new(Default)
+        Box::new(Theme::default())
     }
 }
 
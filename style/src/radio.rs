@@ -1,4 +1,6 @@
 //! Create choices using radio buttons.
+use crate::theme::Widget;
+use crate::Theme;
 use iced_core::{Background, Color};
 
 /// The appearance of a radio button.
@@ -17,21 +19,24 @@ pub trait StyleSheet {
     fn hovered(&self) -> Style;
 }
 
-struct Default;
-
-impl StyleSheet for Default {
+impl StyleSheet for Theme {
     fn active(&self) -> Style {
+        let palette = self.extended_palette_of(Widget::Radio);
+
         Style {
-            background: Background::Color(Color::from_rgb(0.95, 0.95, 0.95)),
-            dot_color: Color::from_rgb(0.3, 0.3, 0.3),
+            background: Background::Color(palette.background.base),
+            dot_color: palette.primary.base,
             border_width: 1.0,
-            border_color: Color::from_rgb(0.6, 0.6, 0.6),
+            border_color: palette.border,
         }
     }
 
     fn hovered(&self) -> Style {
+        let palette = self.extended_palette_of(Widget::Radio);
+
         Style {
-            background: Background::Color(Color::from_rgb(0.90, 0.90, 0.90)),
+            background: Background::Color(palette.background.hovered),
+            border_color: palette.primary.base,
             ..self.active()
         }
     }
@@ -39,7 +44,7 @@ impl StyleSheet for Default {
 
 impl<'a> std::default::Default for Box<dyn StyleSheet + 'a> {
     fn default() -> Self {
-        Box::new(Default)
+        Box::new(Theme::default())
     }
 }
 
@@ -0,0 +1,84 @@
+//! Let users reorder a vertical list by dragging its rows.
+use crate::theme::Widget;
+use crate::Theme;
+use iced_core::{Background, Color};
+
+/// The appearance of a reorderable column.
+#[derive(Debug, Clone, Copy)]
+pub struct Style {
+    pub handle_color: Color,
+    pub dragged_background: Option<Background>,
+    pub indicator_color: Color,
+    pub indicator_width: f32,
+}
+
+impl std::default::Default for Style {
+    fn default() -> Self {
+        Self {
+            handle_color: [0.6, 0.6, 0.6].into(),
+            dragged_background: Some(Background::Color(
+                [0.0, 0.0, 0.0, 0.05].into(),
+            )),
+            indicator_color: [0.3, 0.5, 0.9].into(),
+            indicator_width: 2.0,
+        }
+    }
+}
+
+/// A set of rules that dictate the style of a reorderable column.
+pub trait StyleSheet {
+    /// Produces the style of a reorderable column.
+    fn active(&self) -> Style;
+
+    /// Produces the style of a reorderable column when the handle of a row is
+    /// hovered.
+    fn hovered(&self) -> Style {
+        Style {
+            handle_color: [0.3, 0.3, 0.3].into(),
+            ..self.active()
+        }
+    }
+
+    /// Produces the style of a reorderable column while a row is being
+    /// dragged.
+    fn dragging(&self) -> Style {
+        self.hovered()
+    }
+}
+
+impl StyleSheet for Theme {
+    fn active(&self) -> Style {
+        let palette = self.extended_palette_of(Widget::ReorderableColumn);
+
+        Style {
+            handle_color: palette.border,
+            dragged_background: Some(Background::Color(
+                palette.background.hovered,
+            )),
+            indicator_color: palette.primary.base,
+            indicator_width: 2.0,
+        }
+    }
+
+    fn hovered(&self) -> Style {
+        Style {
+            handle_color: self.palette_of(Widget::ReorderableColumn).text,
+            ..self.active()
+        }
+    }
+}
+
+impl<'a> std::default::Default for Box<dyn StyleSheet + 'a> {
+    fn default() -> Self {
+        Box::new(Theme::default())
+    }
+}
+
+impl<'a, T> From<T> for Box<dyn StyleSheet + 'a>
+where
+    T: 'a + StyleSheet,
+{
+    fn from(style: T) -> Self {
+        Box::new(style)
+    }
+}
@@ -1,4 +1,6 @@
 //! Display a horizontal or vertical rule for dividing content.
+use crate::theme::Widget;
+use crate::Theme;
 use iced_core::Color;
 
 /// The fill mode of a rule.
@@ -95,17 +97,20 @@ pub trait StyleSheet {
     fn style(&self) -> Style;
 }
 
-struct Default;
-
-impl StyleSheet for Default {
+impl StyleSheet for Theme {
     fn style(&self) -> Style {
-        Style::default()
+        let palette = self.extended_palette_of(Widget::Rule);
+
+        Style {
+            color: palette.border,
+            ..Style::default()
+        }
     }
 }
 
 impl<'a> std::default::Default for Box<dyn StyleSheet + 'a> {
     fn default() -> Self {
-        Box::new(Default)
+        Box::new(Theme::default())
     }
 }
 
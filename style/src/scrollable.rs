@@ -1,4 +1,6 @@
 //! Navigate an endless amount of content with a scrollbar.
+use crate::theme::Widget;
+use crate::Theme;
 use iced_core::{Background, Color};
 
 /// The appearance of a scrollable.
@@ -34,17 +36,17 @@ pub trait StyleSheet {
     }
 }
 
-struct Default;
-
-impl StyleSheet for Default {
+impl StyleSheet for Theme {
     fn active(&self) -> Scrollbar {
+        let palette = self.extended_palette_of(Widget::Scrollable);
+
         Scrollbar {
             background: None,
             border_radius: 5.0,
             border_width: 0.0,
             border_color: Color::TRANSPARENT,
             scroller: Scroller {
-                color: [0.0, 0.0, 0.0, 0.7].into(),
+                color: palette.border,
                 border_radius: 5.0,
                 border_width: 0.0,
                 border_color: Color::TRANSPARENT,
@@ -53,16 +55,36 @@ impl StyleSheet for Default {
     }
 
     fn hovered(&self) -> Scrollbar {
+        let palette = self.extended_palette_of(Widget::Scrollable);
+        let active = self.active();
+
         Scrollbar {
-            background: Some(Background::Color([0.0, 0.0, 0.0, 0.3].into())),
-            ..self.active()
+            background: Some(Background::Color(palette.background.hovered)),
+            scroller: Scroller {
+                color: palette.weak_text,
+                ..active.scroller
+            },
+            ..active
+        }
+    }
+
+    fn dragging(&self) -> Scrollbar {
+        let palette = self.extended_palette_of(Widget::Scrollable);
+        let hovered = self.hovered();
+
+        Scrollbar {
+            scroller: Scroller {
+                color: palette.primary.base,
+                ..hovered.scroller
+            },
+            ..hovered
         }
     }
 }
 
 impl<'a> std::default::Default for Box<dyn StyleSheet + 'a> {
     fn default() -> Self {
-        Box::new(Default)
+        Box::new(Theme::default())
     }
 }
 
@@ -1,4 +1,6 @@
 //! Display an interactive selector of a single value from a range of values.
+use crate::theme::Widget;
+use crate::Theme;
 use iced_core::Color;
 
 /// The appearance of a slider.
@@ -36,30 +38,31 @@ pub trait StyleSheet {
     fn dragging(&self) -> Style;
 }
 
-struct Default;
-
-impl StyleSheet for Default {
+impl StyleSheet for Theme {
     fn active(&self) -> Style {
+        let palette = self.extended_palette_of(Widget::Slider);
+
         Style {
-            rail_colors: ([0.6, 0.6, 0.6, 0.5].into(), Color::WHITE),
+            rail_colors: (palette.primary.base, palette.border),
             handle: Handle {
                 shape: HandleShape::Rectangle {
                     width: 8,
                     border_radius: 4.0,
                 },
-                color: Color::from_rgb(0.95, 0.95, 0.95),
-                border_color: Color::from_rgb(0.6, 0.6, 0.6),
+                color: palette.primary.base,
+                border_color: palette.primary.base,
                 border_width: 1.0,
             },
         }
     }
 
     fn hovered(&self) -> Style {
+        let palette = self.extended_palette_of(Widget::Slider);
         let active = self.active();
 
         Style {
             handle: Handle {
-                color: Color::from_rgb(0.90, 0.90, 0.90),
+                color: palette.primary.hovered,
                 ..active.handle
             },
             ..active
@@ -67,11 +70,12 @@ impl StyleSheet for Default {
     }
 
     fn dragging(&self) -> Style {
+        let palette = self.extended_palette_of(Widget::Slider);
         let active = self.active();
 
         Style {
             handle: Handle {
-                color: Color::from_rgb(0.85, 0.85, 0.85),
+                color: palette.primary.pressed,
                 ..active.handle
             },
             ..active
@@ -81,7 +85,7 @@ impl StyleSheet for Default {
 
 impl<'a> std::default::Default for Box<dyn StyleSheet + 'a> {
     fn default() -> Self {
-        Box::new(Default)
+        Box::new(Theme::default())
     }
 }
 
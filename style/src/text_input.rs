@@ -1,4 +1,6 @@
 //! Display fields that can be filled with text.
+use crate::theme::Widget;
+use crate::Theme;
 use iced_core::{Background, Color};
 
 /// The appearance of a text input.
@@ -39,43 +41,71 @@ pub trait StyleSheet {
     fn hovered(&self) -> Style {
         self.focused()
     }
-}
 
-struct Default;
+    /// Produces the style of a text input holding an invalid value.
+    fn invalid(&self) -> Style {
+        Style {
+            border_color: Color::from_rgb(0.8, 0.2, 0.2),
+            ..self.focused()
+        }
+    }
+}
 
-impl StyleSheet for Default {
+impl StyleSheet for Theme {
     fn active(&self) -> Style {
+        let palette = self.extended_palette_of(Widget::TextInput);
+
         Style {
-            background: Background::Color(Color::WHITE),
-            border_radius: 5.0,
+            background: Background::Color(palette.background.base),
+            border_radius: 2.0,
             border_width: 1.0,
-            border_color: Color::from_rgb(0.7, 0.7, 0.7),
+            border_color: palette.border,
         }
     }
 
     fn focused(&self) -> Style {
+        let palette = self.extended_palette_of(Widget::TextInput);
+
         Style {
-            border_color: Color::from_rgb(0.5, 0.5, 0.5),
+            border_color: palette.primary.base,
             ..self.active()
         }
     }
 
     fn placeholder_color(&self) -> Color {
-        Color::from_rgb(0.7, 0.7, 0.7)
+        self.extended_palette_of(Widget::TextInput).weak_text
     }
 
     fn value_color(&self) -> Color {
-        Color::from_rgb(0.3, 0.3, 0.3)
+        self.palette_of(Widget::TextInput).text
     }
 
     fn selection_color(&self) -> Color {
-        Color::from_rgb(0.8, 0.8, 1.0)
+        self.extended_palette_of(Widget::TextInput).primary.weak
+    }
+
+    fn hovered(&self) -> Style {
+        let palette = self.extended_palette_of(Widget::TextInput);
+
+        Style {
+            border_color: palette.weak_text,
+            ..self.active()
+        }
+    }
+
+    fn invalid(&self) -> Style {
+        let palette = self.extended_palette_of(Widget::TextInput);
+
+        Style {
+            border_color: palette.danger.base,
+            ..self.focused()
+        }
     }
 }
 
 impl<'a> std::default::Default for Box<dyn StyleSheet + 'a> {
     fn default() -> Self {
-        Box::new(Default)
+        Box::new(Theme::default())
     }
 }
 
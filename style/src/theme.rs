@@ -0,0 +1,196 @@
+//! Use the built-in theme of Iced.
+pub mod palette;
+
+#[cfg(feature = "file")]
+mod file;
+
+pub use palette::Palette;
+
+#[cfg(feature = "file")]
+pub use file::{Error, Format};
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+/// A built-in theme.
+///
+/// Every built-in widget is styled by the [`Theme`] of the application by
+/// default. All the colors of a [`Theme`] are derived from a small
+/// [`Palette`].
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum Theme {
+    /// The built-in light theme.
+    #[default]
+    Light,
+    /// The built-in dark theme.
+    Dark,
+    /// A theme with a custom [`Palette`].
+    Custom(Palette),
+    /// A theme with a custom [`Palette`] and some [`Overrides`] for specific
+    /// kinds of [`Widget`].
+    Overridden(Palette, Arc<Overrides>),
+}
+
+impl Theme {
+    /// Returns the [`Palette`] of the [`Theme`].
+    pub fn palette(&self) -> Palette {
+        match self {
+            Theme::Light => Palette::LIGHT,
+            Theme::Dark => Palette::DARK,
+            Theme::Custom(palette) | Theme::Overridden(palette, _) => *palette,
+        }
+    }
+
+    /// Returns the [`palette::Extended`] of the [`Theme`], which contains the
+    /// colors derived from its [`Palette`].
+    pub fn extended_palette(&self) -> palette::Extended {
+        palette::Extended::generate(self.palette())
+    }
+
+    /// Returns the [`Palette`] used by the given kind of [`Widget`], taking
+    /// the [`Overrides`] of the [`Theme`] into account.
+    pub fn palette_of(&self, widget: Widget) -> Palette {
+        match self {
+            Theme::Overridden(palette, overrides) => {
+                overrides.get(widget).unwrap_or(*palette)
+            }
+            _ => self.palette(),
+        }
+    }
+
+    /// Returns the [`palette::Extended`] used by the given kind of
+    /// [`Widget`], taking the [`Overrides`] of the [`Theme`] into account.
+    pub fn extended_palette_of(&self, widget: Widget) -> palette::Extended {
+        palette::Extended::generate(self.palette_of(widget))
+    }
+
+    /// Returns the [`Palette`] used by the given kind of [`Widget`] with the
+    /// given style class.
+    ///
+    /// A class is resolved in cascade. The [`Palette`] of the class for the
+    /// specific kind of [`Widget`] comes first, then the [`Palette`] of the
+    /// class for every widget, then the built-in classes, and finally the
+    /// [`Palette`] of the [`Widget`] itself.
+    ///
+    /// The built-in classes are `"primary"`, `"secondary"`, `"success"` and
+    /// `"danger"`, which use the respective color as the primary color.
+    pub fn palette_of_class(&self, widget: Widget, class: &str) -> Palette {
+        if let Theme::Overridden(_, overrides) = self {
+            if let Some(palette) = overrides
+                .get_class(class, Some(widget))
+                .or_else(|| overrides.get_class(class, None))
+            {
+                return palette;
+            }
+        }
+
+        built_in_class(self.palette_of(widget), class)
+    }
+
+    /// Resolves the [`Theme`] that styles a [`Widget`] with the given style
+    /// class, if any.
+    pub fn resolve(&self, widget: Widget, class: Option<&str>) -> Theme {
+        match class {
+            Some(class) => Theme::Custom(self.palette_of_class(widget, class)),
+            None => Theme::Custom(self.palette_of(widget)),
+        }
+    }
+}
+
+fn built_in_class(palette: Palette, class: &str) -> Palette {
+    match class {
+        "secondary" => Palette {
+            primary: palette::mix(palette.background, palette.text, 0.5),
+            ..palette
+        },
+        "success" => Palette {
+            primary: palette.success,
+            ..palette
+        },
+        "danger" => Palette {
+            primary: palette.danger,
+            ..palette
+        },
+        _ => palette,
+    }
+}
+
+/// A kind of widget styled by a [`Theme`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[allow(missing_docs)]
+pub enum Widget {
+    Button,
+    Checkbox,
+    DropTarget,
+    Menu,
+    Pagination,
+    PaneGrid,
+    PickList,
+    ProgressBar,
+    Radio,
+    ReorderableColumn,
+    Rule,
+    Scrollable,
+    Slider,
+    TextInput,
+    Toggler,
+}
+
+/// The [`Palette`] overrides of a [`Theme`] for specific kinds of
+/// [`Widget`] and style classes.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Overrides {
+    palettes: BTreeMap<Widget, Palette>,
+    classes: BTreeMap<String, BTreeMap<Option<Widget>, Palette>>,
+}
+
+impl Overrides {
+    /// Creates an empty set of [`Overrides`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Overrides the [`Palette`] of the given kind of [`Widget`].
+    pub fn with(mut self, widget: Widget, palette: Palette) -> Self {
+        let _ = self.palettes.insert(widget, palette);
+        self
+    }
+
+    /// Returns the [`Palette`] overriden for the given kind of [`Widget`],
+    /// if any.
+    pub fn get(&self, widget: Widget) -> Option<Palette> {
+        self.palettes.get(&widget).copied()
+    }
+
+    /// Defines the [`Palette`] of a style class.
+    ///
+    /// The class will only apply to the given kind of [`Widget`], if any.
+    pub fn with_class(
+        mut self,
+        class: impl Into<String>,
+        widget: Option<Widget>,
+        palette: Palette,
+    ) -> Self {
+        let _ = self
+            .classes
+            .entry(class.into())
+            .or_default()
+            .insert(widget, palette);
+        self
+    }
+
+    /// Returns the [`Palette`] defined for the given style class and kind of
+    /// [`Widget`], if any.
+    pub fn get_class(
+        &self,
+        class: &str,
+        widget: Option<Widget>,
+    ) -> Option<Palette> {
+        self.classes.get(class)?.get(&widget).copied()
+    }
+
+    /// Returns true if no [`Palette`] is overriden.
+    pub fn is_empty(&self) -> bool {
+        self.palettes.is_empty() && self.classes.is_empty()
+    }
+}
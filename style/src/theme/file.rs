@@ -0,0 +1,300 @@
+//! Load a [`Theme`] from a TOML or JSON file.
+use crate::theme::{self, Overrides, Palette, Theme, Widget};
+use iced_core::Color;
+
+use serde::de::{self, Deserializer};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+/// The format of the source of a [`Theme`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// [TOML](https://toml.io).
+    Toml,
+    /// [JSON](https://www.json.org).
+    Json,
+}
+
+impl Format {
+    /// Guesses the [`Format`] of a file from the extension of its path.
+    pub fn from_path(path: &Path) -> Option<Self> {
+        match path.extension()?.to_str()? {
+            "toml" => Some(Format::Toml),
+            "json" => Some(Format::Json),
+            _ => None,
+        }
+    }
+}
+
+/// An error that occurred while loading a [`Theme`].
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum Error {
+    /// The file of the [`Theme`] could not be read.
+    #[error("the theme file could not be read: {0}")]
+    Io(Arc<io::Error>),
+
+    /// The [`Format`] of the file could not be guessed from its extension.
+    #[error("the format of the theme file {0:?} is unknown")]
+    UnknownFormat(PathBuf),
+
+    /// The source does not define a valid [`Theme`].
+    #[error("the theme is invalid: {0}")]
+    Invalid(String),
+}
+
+impl Theme {
+    /// Parses a [`Theme`] from the given source in the given [`Format`].
+    ///
+    /// The source may choose a `base` theme (`"light"` or `"dark"`), replace
+    /// some colors of its `palette`, override the palette of specific
+    /// `widgets`, and define style `classes`. For instance, in TOML:
+    ///
+    /// ```toml
+    /// base = "dark"
+    ///
+    /// [palette]
+    /// primary = "#5E7CE2"
+    ///
+    /// [widgets.button]
+    /// primary = "#12664F"
+    ///
+    /// [classes.warning.palette]
+    /// primary = "#E2B85E"
+    ///
+    /// [classes.warning.widgets.text_input]
+    /// text = "#E2B85E"
+    /// ```
+    pub fn from_str(source: &str, format: Format) -> Result<Self, Error> {
+        let definition: Definition = match format {
+            Format::Toml => toml::from_str(source)
+                .map_err(|error| Error::Invalid(error.to_string()))?,
+            Format::Json => serde_json::from_str(source)
+                .map_err(|error| Error::Invalid(error.to_string()))?,
+        };
+
+        Ok(definition.into_theme())
+    }
+
+    /// Loads a [`Theme`] from the file at the given path.
+    ///
+    /// The [`Format`] of the file is guessed from its extension.
+    pub fn from_path(path: impl AsRef<Path>) -> Result<Self, Error> {
+        let path = path.as_ref();
+
+        let format = Format::from_path(path)
+            .ok_or_else(|| Error::UnknownFormat(path.to_path_buf()))?;
+
+        let source = std::fs::read_to_string(path)
+            .map_err(|error| Error::Io(Arc::new(error)))?;
+
+        Self::from_str(&source, format)
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Definition {
+    #[serde(default)]
+    base: Base,
+    #[serde(default)]
+    palette: Partial,
+    #[serde(default)]
+    widgets: Widgets,
+    #[serde(default)]
+    classes: BTreeMap<String, Class>,
+}
+
+impl Definition {
+    fn into_theme(self) -> Theme {
+        let palette = self.palette.apply(match self.base {
+            Base::Light => Palette::LIGHT,
+            Base::Dark => Palette::DARK,
+        });
+
+        let overrides = self.widgets.partials().iter().fold(
+            Overrides::new(),
+            |overrides, (widget, partial)| match partial {
+                Some(partial) => {
+                    overrides.with(*widget, partial.apply(palette))
+                }
+                None => overrides,
+            },
+        );
+
+        let overrides =
+            self.classes
+                .iter()
+                .fold(overrides, |overrides, (name, class)| {
+                    let class_palette = class
+                        .palette
+                        .apply(theme::built_in_class(palette, name));
+
+                    let overrides = if class.palette.is_empty() {
+                        overrides
+                    } else {
+                        overrides.with_class(name.as_str(), None, class_palette)
+                    };
+
+                    class.widgets.partials().iter().fold(
+                        overrides,
+                        |overrides, (widget, partial)| match partial {
+                            Some(partial) => overrides.with_class(
+                                name.as_str(),
+                                Some(*widget),
+                                partial.apply(class_palette),
+                            ),
+                            None => overrides,
+                        },
+                    )
+                });
+
+        if overrides.is_empty() {
+            Theme::Custom(palette)
+        } else {
+            Theme::Overridden(palette, Arc::new(overrides))
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Class {
+    #[serde(default)]
+    palette: Partial,
+    #[serde(default)]
+    widgets: Widgets,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Widgets {
+    button: Option<Partial>,
+    checkbox: Option<Partial>,
+    drop_target: Option<Partial>,
+    menu: Option<Partial>,
+    pagination: Option<Partial>,
+    pane_grid: Option<Partial>,
+    pick_list: Option<Partial>,
+    progress_bar: Option<Partial>,
+    radio: Option<Partial>,
+    reorderable_column: Option<Partial>,
+    rule: Option<Partial>,
+    scrollable: Option<Partial>,
+    slider: Option<Partial>,
+    text_input: Option<Partial>,
+    toggler: Option<Partial>,
+}
+
+impl Widgets {
+    fn partials(&self) -> [(Widget, &Option<Partial>); 15] {
+        [
+            (Widget::Button, &self.button),
+            (Widget::Checkbox, &self.checkbox),
+            (Widget::DropTarget, &self.drop_target),
+            (Widget::Menu, &self.menu),
+            (Widget::Pagination, &self.pagination),
+            (Widget::PaneGrid, &self.pane_grid),
+            (Widget::PickList, &self.pick_list),
+            (Widget::ProgressBar, &self.progress_bar),
+            (Widget::Radio, &self.radio),
+            (Widget::ReorderableColumn, &self.reorderable_column),
+            (Widget::Rule, &self.rule),
+            (Widget::Scrollable, &self.scrollable),
+            (Widget::Slider, &self.slider),
+            (Widget::TextInput, &self.text_input),
+            (Widget::Toggler, &self.toggler),
+        ]
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum Base {
+    Light,
+    Dark,
+}
+
+impl Default for Base {
+    fn default() -> Self {
+        Base::Light
+    }
+}
+
+#[derive(Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Partial {
+    background: Option<Hex>,
+    text: Option<Hex>,
+    primary: Option<Hex>,
+    success: Option<Hex>,
+    danger: Option<Hex>,
+}
+
+impl Partial {
+    fn is_empty(&self) -> bool {
+        self.background.is_none()
+            && self.text.is_none()
+            && self.primary.is_none()
+            && self.success.is_none()
+            && self.danger.is_none()
+    }
+
+    fn apply(&self, palette: Palette) -> Palette {
+        let color = |hex: &Option<Hex>, default| {
+            hex.as_ref().map(|hex| hex.0).unwrap_or(default)
+        };
+
+        Palette {
+            background: color(&self.background, palette.background),
+            text: color(&self.text, palette.text),
+            primary: color(&self.primary, palette.primary),
+            success: color(&self.success, palette.success),
+            danger: color(&self.danger, palette.danger),
+        }
+    }
+}
+
+/// A [`Color`] written as `#RRGGBB` or `#RRGGBBAA`.
+struct Hex(Color);
+
+impl<'de> Deserialize<'de> for Hex {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let hex = String::deserialize(deserializer)?;
+
+        parse_hex(&hex).map(Hex).ok_or_else(|| {
+            de::Error::custom(format!(
+                "invalid color {:?}, expected #RRGGBB or #RRGGBBAA",
+                hex
+            ))
+        })
+    }
+}
+
+fn parse_hex(hex: &str) -> Option<Color> {
+    let hex = hex.strip_prefix('#')?;
+
+    if !hex.is_ascii() || (hex.len() != 6 && hex.len() != 8) {
+        return None;
+    }
+
+    let channel = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).ok();
+
+    let alpha = if hex.len() == 8 {
+        f32::from(channel(6)?) / 255.0
+    } else {
+        1.0
+    };
+
+    Some(Color::from_rgba8(
+        channel(0)?,
+        channel(2)?,
+        channel(4)?,
+        alpha,
+    ))
+}
@@ -0,0 +1,217 @@
+//! Define the colors of a [`Theme`].
+//!
+//! [`Theme`]: crate::Theme
+use iced_core::Color;
+
+/// A small set of colors that defines a [`Theme`].
+///
+/// [`Theme`]: crate::Theme
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Palette {
+    /// The background [`Color`] of the [`Palette`].
+    pub background: Color,
+    /// The text [`Color`] of the [`Palette`].
+    pub text: Color,
+    /// The primary [`Color`] of the [`Palette`].
+    pub primary: Color,
+    /// The success [`Color`] of the [`Palette`].
+    pub success: Color,
+    /// The danger [`Color`] of the [`Palette`].
+    pub danger: Color,
+}
+
+impl Palette {
+    /// The built-in light [`Palette`].
+    pub const LIGHT: Self = Self {
+        background: Color::WHITE,
+        text: Color::BLACK,
+        primary: Color::from_rgb(
+            0x5E as f32 / 255.0,
+            0x7C as f32 / 255.0,
+            0xE2 as f32 / 255.0,
+        ),
+        success: Color::from_rgb(
+            0x12 as f32 / 255.0,
+            0x66 as f32 / 255.0,
+            0x4F as f32 / 255.0,
+        ),
+        danger: Color::from_rgb(
+            0xC3 as f32 / 255.0,
+            0x42 as f32 / 255.0,
+            0x3F as f32 / 255.0,
+        ),
+    };
+
+    /// The built-in dark [`Palette`].
+    pub const DARK: Self = Self {
+        background: Color::from_rgb(
+            0x20 as f32 / 255.0,
+            0x22 as f32 / 255.0,
+            0x25 as f32 / 255.0,
+        ),
+        text: Color::from_rgb(0.90, 0.90, 0.90),
+        primary: Color::from_rgb(
+            0x5E as f32 / 255.0,
+            0x7C as f32 / 255.0,
+            0xE2 as f32 / 255.0,
+        ),
+        success: Color::from_rgb(
+            0x12 as f32 / 255.0,
+            0x66 as f32 / 255.0,
+            0x4F as f32 / 255.0,
+        ),
+        danger: Color::from_rgb(
+            0xC3 as f32 / 255.0,
+            0x42 as f32 / 255.0,
+            0x3F as f32 / 255.0,
+        ),
+    };
+
+    /// Mixes the [`Palette`] with another one, moving every color towards
+    /// `other` by the given factor.
+    pub fn mix(self, other: Palette, factor: f32) -> Palette {
+        Palette {
+            background: mix(self.background, other.background, factor),
+            text: mix(self.text, other.text, factor),
+            primary: mix(self.primary, other.primary, factor),
+            success: mix(self.success, other.success, factor),
+            danger: mix(self.danger, other.danger, factor),
+        }
+    }
+}
+
+/// The colors derived from a [`Palette`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Extended {
+    /// The [`Shades`] of the background color.
+    pub background: Shades,
+    /// The [`Shades`] of the primary color.
+    pub primary: Shades,
+    /// The [`Shades`] of the success color.
+    pub success: Shades,
+    /// The [`Shades`] of the danger color.
+    pub danger: Shades,
+    /// The color of the borders drawn on top of the background.
+    pub border: Color,
+    /// A faint text color, like the one of a placeholder.
+    pub weak_text: Color,
+    /// Whether the [`Palette`] has a dark background.
+    pub is_dark: bool,
+}
+
+impl Extended {
+    /// Generates the [`Extended`] colors of the given [`Palette`].
+    pub fn generate(palette: Palette) -> Self {
+        Self {
+            background: Shades::generate(
+                palette.background,
+                palette.background,
+                palette.text,
+            ),
+            primary: Shades::generate(
+                palette.primary,
+                palette.background,
+                palette.text,
+            ),
+            success: Shades::generate(
+                palette.success,
+                palette.background,
+                palette.text,
+            ),
+            danger: Shades::generate(
+                palette.danger,
+                palette.background,
+                palette.text,
+            ),
+            border: mix(palette.background, palette.text, 0.3),
+            weak_text: mix(palette.background, palette.text, 0.5),
+            is_dark: is_dark(palette.background),
+        }
+    }
+}
+
+/// The variations of a color, used by the different states of a widget.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Shades {
+    /// The color itself.
+    pub base: Color,
+    /// A faint variation of the color, closer to the background.
+    pub weak: Color,
+    /// The color of a hovered widget.
+    pub hovered: Color,
+    /// The color of a pressed widget.
+    pub pressed: Color,
+    /// The color of a disabled widget.
+    pub disabled: Color,
+    /// The color of the text drawn on top of the color.
+    pub text: Color,
+}
+
+impl Shades {
+    /// Generates the [`Shades`] of the given base color, for the given
+    /// background and text colors.
+    pub fn generate(base: Color, background: Color, text: Color) -> Self {
+        Self {
+            base,
+            weak: mix(base, background, 0.6),
+            hovered: deviate(base, 0.1),
+            pressed: deviate(base, 0.2),
+            disabled: mix(base, background, 0.5),
+            text: readable(base, text),
+        }
+    }
+}
+
+/// Mixes the given colors, moving from `a` to `b` by the given factor.
+pub fn mix(a: Color, b: Color, factor: f32) -> Color {
+    Color {
+        r: a.r + (b.r - a.r) * factor,
+        g: a.g + (b.g - a.g) * factor,
+        b: a.b + (b.b - a.b) * factor,
+        a: a.a + (b.a - a.a) * factor,
+    }
+}
+
+/// Returns true if the given color is closer to black than to white.
+pub fn is_dark(color: Color) -> bool {
+    relative_luminance(color) < 0.5
+}
+
+/// Lightens a dark color and darkens a light color by the given amount.
+pub fn deviate(color: Color, amount: f32) -> Color {
+    if is_dark(color) {
+        mix(
+            color,
+            Color {
+                a: color.a,
+                ..Color::WHITE
+            },
+            amount,
+        )
+    } else {
+        mix(
+            color,
+            Color {
+                a: color.a,
+                ..Color::BLACK
+            },
+            amount,
+        )
+    }
+}
+
+/// Returns the given text color if it is readable on top of the given
+/// background; otherwise, black or white.
+pub fn readable(background: Color, text: Color) -> Color {
+    if is_dark(background) != is_dark(text) {
+        text
+    } else if is_dark(background) {
+        Color::WHITE
+    } else {
+        Color::BLACK
+    }
+}
+
+fn relative_luminance(color: Color) -> f32 {
+    0.299 * color.r + 0.587 * color.g + 0.114 * color.b
+}
@@ -1,4 +1,6 @@
 //! Show toggle controls using togglers.
+use crate::theme::Widget;
+use crate::Theme;
 use iced_core::Color;
 
 /// The appearance of a toggler.
@@ -17,25 +19,35 @@ pub trait StyleSheet {
     fn hovered(&self, is_active: bool) -> Style;
 }
 
-struct Default;
-
-impl StyleSheet for Default {
+impl StyleSheet for Theme {
     fn active(&self, is_active: bool) -> Style {
+        let palette = self.extended_palette_of(Widget::Toggler);
+
         Style {
             background: if is_active {
-                Color::from_rgb(0.0, 1.0, 0.0)
+                palette.primary.base
             } else {
-                Color::from_rgb(0.7, 0.7, 0.7)
+                palette.background.pressed
             },
             background_border: None,
-            foreground: Color::WHITE,
+            foreground: if is_active {
+                palette.primary.text
+            } else {
+                palette.background.base
+            },
             foreground_border: None,
         }
     }
 
     fn hovered(&self, is_active: bool) -> Style {
+        let palette = self.extended_palette_of(Widget::Toggler);
+
         Style {
-            foreground: Color::from_rgb(0.95, 0.95, 0.95),
+            background: if is_active {
+                palette.primary.hovered
+            } else {
+                palette.border
+            },
             ..self.active(is_active)
         }
     }
@@ -43,7 +55,7 @@ impl StyleSheet for Default {
 
 impl<'a> std::default::Default for Box<dyn StyleSheet + 'a> {
     fn default() -> Self {
-        Box::new(Default)
+        Box::new(Theme::default())
     }
 }
 
@@ -4,6 +4,8 @@ pub use action::Action;
 
 use std::fmt;
 
+#[cfg(target_arch = "wasm32")]
+use iced_futures::futures::Stream;
 #[cfg(target_arch = "wasm32")]
 use std::future::Future;
 
@@ -34,6 +36,30 @@ impl<T> Command<T> {
         Command::single(Action::Future(Box::pin(future.map(f))))
     }
 
+    /// Creates a [`Command`] that performs the action of the given future in
+    /// the background.
+    ///
+    /// On the Web, it is the same as [`Command::perform`].
+    #[cfg(target_arch = "wasm32")]
+    pub fn perform_in_background<A>(
+        future: impl Future<Output = T> + 'static,
+        f: impl Fn(T) -> A + 'static + Send,
+    ) -> Command<A> {
+        Self::perform(future, f)
+    }
+
+    /// Creates a [`Command`] that runs the given stream to completion,
+    /// producing a message for every item.
+    #[cfg(target_arch = "wasm32")]
+    pub fn run<A>(
+        stream: impl Stream<Item = T> + 'static,
+        f: impl Fn(T) -> A + 'static + Send,
+    ) -> Command<A> {
+        use iced_futures::futures::StreamExt;
+
+        Command::single(Action::Stream(Box::pin(stream.map(f))))
+    }
+
     /// Creates a [`Command`] that performs the actions of all the given
     /// commands.
     ///
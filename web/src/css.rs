@@ -203,6 +203,7 @@ pub fn alignment(alignment: Alignment) -> &'static str {
         Alignment::Center => "center",
         Alignment::End => "flex-end",
         Alignment::Fill => "stretch",
+        Alignment::Baseline => "baseline",
     }
 }
 
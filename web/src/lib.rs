@@ -83,10 +83,11 @@ pub use iced_core::keyboard;
 pub use iced_core::mouse;
 pub use iced_futures::executor;
 pub use iced_futures::futures;
+pub use iced_style::theme;
 
 pub use iced_core::{
-    Alignment, Background, Color, Font, Length, Padding, Point, Rectangle,
-    Size, Vector,
+    Alignment, Background, Color, Direction, Font, Length, Padding, Point,
+    Rectangle, Size, Vector,
 };
 
 #[doc(no_inline)]
@@ -95,6 +96,8 @@ pub use widget::*;
 #[doc(no_inline)]
 pub use executor::Executor;
 
+pub use theme::Theme;
+
 /// An interactive web application.
 ///
 /// This trait is the main entrypoint of Iced. Once implemented, you can run
@@ -398,6 +401,9 @@ fn run_command<Message: 'static + Send, E: Executor>(
             command::Action::Future(future) => {
                 runtime.spawn(future);
             }
+            command::Action::Stream(stream) => {
+                runtime.run(stream);
+            }
         }
     }
 }
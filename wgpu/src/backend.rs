@@ -1,3 +1,4 @@
+use crate::profiler::{Pipeline, Profiler, Timings};
 use crate::quad;
 use crate::text;
 use crate::triangle;
@@ -5,7 +6,7 @@ use crate::{Settings, Transformation};
 
 use iced_graphics::backend;
 use iced_graphics::font;
-use iced_graphics::layer::Layer;
+use iced_graphics::layer::{self, Layer};
 use iced_graphics::{Primitive, Viewport};
 use iced_native::alignment;
 use iced_native::{Font, Size};
@@ -27,6 +28,7 @@ pub struct Backend {
     image_pipeline: image::Pipeline,
 
     default_text_size: u16,
+    profiler: Option<Profiler>,
 }
 
 impl Backend {
@@ -48,7 +50,8 @@ impl Backend {
             triangle::Pipeline::new(device, format, settings.antialiasing);
 
         #[cfg(any(feature = "image_rs", feature = "svg"))]
-        let image_pipeline = image::Pipeline::new(device, format);
+        let image_pipeline =
+            image::Pipeline::new(device, format, settings.texture_arrays);
 
         Self {
             quad_pipeline,
@@ -59,9 +62,30 @@ impl Backend {
             image_pipeline,
 
             default_text_size: settings.default_text_size,
+            profiler: None,
         }
     }
 
+    /// Enables measuring the time the GPU spends in every pipeline of the
+    /// [`Backend`] with timestamp queries.
+    ///
+    /// The given device must have [`wgpu::Features::TIMESTAMP_QUERY`]
+    /// enabled, and the timestamp period must be the one returned by
+    /// [`wgpu::Queue::get_timestamp_period`].
+    pub fn enable_profiling(
+        &mut self,
+        device: &wgpu::Device,
+        timestamp_period: f32,
+    ) {
+        self.profiler = Some(Profiler::new(device, timestamp_period));
+    }
+
+    /// Returns the latest GPU [`Timings`] of the [`Backend`], if profiling
+    /// is enabled and any frame has been measured already.
+    pub fn gpu_timings(&self) -> Option<Timings> {
+        self.profiler.as_ref().and_then(Profiler::timings)
+    }
+
     /// Draws the provided primitives in the given `TextureView`.
     ///
     /// The text provided as overlay will be rendered on top of the primitives.
@@ -82,15 +106,21 @@ impl Backend {
         let scale_factor = viewport.scale_factor() as f32;
         let transformation = viewport.projection();
 
-        let mut layers = Layer::generate(primitives, viewport);
-        layers.push(Layer::overlay(overlay_text, viewport));
+        if let Some(profiler) = &mut self.profiler {
+            profiler.begin_frame(device);
+        }
 
-        for layer in layers {
+        let layers = Layer::generate(primitives, viewport);
+        let overlay = self.overlay(&layers, overlay_text);
+
+        for layer in layers.iter().chain(&[Layer::overlay(&overlay, viewport)])
+        {
             self.flush(
                 device,
+                viewport,
                 scale_factor,
                 transformation,
-                &layer,
+                layer,
                 staging_belt,
                 encoder,
                 &frame,
@@ -99,13 +129,78 @@ impl Backend {
             );
         }
 
+        if let Some(profiler) = &mut self.profiler {
+            profiler.end_frame(encoder);
+        }
+
         #[cfg(any(feature = "image_rs", feature = "svg"))]
         self.image_pipeline.trim_cache();
     }
 
+    /// Extends the given overlay text with the [`layer::Stats`] of the given
+    /// layers and the occupancy of the texture atlas, if the overlay is not
+    /// empty.
+    fn overlay<T: AsRef<str>>(
+        &self,
+        layers: &[Layer<'_>],
+        overlay_text: &[T],
+    ) -> Vec<String> {
+        if overlay_text.is_empty() {
+            return Vec::new();
+        }
+
+        let mut lines: Vec<String> = overlay_text
+            .iter()
+            .map(|line| String::from(line.as_ref()))
+            .collect();
+
+        lines.extend(layer::Stats::of(layers).overlay());
+
+        #[cfg(any(feature = "image_rs", feature = "svg"))]
+        {
+            let (occupied, total) = self.image_pipeline.atlas_occupancy();
+
+            lines.push(format!(
+                "Atlas: {} of {} layers occupied",
+                occupied, total
+            ));
+        }
+
+        if let Some(timings) = self.gpu_timings() {
+            lines.push(format!("GPU: {:?}", timings.total()));
+            lines.push(format!(
+                "    Quads: {:?}, Images: {:?}",
+                timings.quads, timings.images
+            ));
+            lines.push(format!(
+                "    Text: {:?}, Meshes: {:?}",
+                timings.text, timings.meshes
+            ));
+        }
+
+        lines
+    }
+
+    fn profile(
+        &mut self,
+        encoder: &mut wgpu::CommandEncoder,
+        pipeline: Pipeline,
+    ) {
+        if let Some(profiler) = &mut self.profiler {
+            profiler.begin(encoder, pipeline);
+        }
+    }
+
+    fn profile_end(&mut self, encoder: &mut wgpu::CommandEncoder) {
+        if let Some(profiler) = &mut self.profiler {
+            profiler.end(encoder);
+        }
+    }
+
     fn flush(
         &mut self,
         device: &wgpu::Device,
+        viewport: &Viewport,
         scale_factor: f32,
         transformation: Transformation,
         layer: &Layer<'_>,
@@ -121,7 +216,11 @@ impl Backend {
             return;
         }
 
+        // Scissor rectangles are in the coordinates of the target
+        let bounds = viewport.rotate_bounds(bounds);
+
         if !layer.quads.is_empty() {
+            self.profile(encoder, Pipeline::Quads);
             self.quad_pipeline.draw(
                 device,
                 staging_belt,
@@ -132,12 +231,14 @@ impl Backend {
                 bounds,
                 target,
             );
+            self.profile_end(encoder);
         }
 
         if !layer.meshes.is_empty() {
             let scaled = transformation
                 * Transformation::scale(scale_factor, scale_factor);
 
+            self.profile(encoder, Pipeline::Meshes);
             self.triangle_pipeline.draw(
                 device,
                 staging_belt,
@@ -146,9 +247,10 @@ impl Backend {
                 target_width,
                 target_height,
                 scaled,
-                scale_factor,
+                viewport,
                 &layer.meshes,
             );
+            self.profile_end(encoder);
         }
 
         #[cfg(any(feature = "image_rs", feature = "svg"))]
@@ -157,6 +259,7 @@ impl Backend {
                 let scaled = transformation
                     * Transformation::scale(scale_factor, scale_factor);
 
+                self.profile(encoder, Pipeline::Images);
                 self.image_pipeline.draw(
                     device,
                     staging_belt,
@@ -167,6 +270,7 @@ impl Backend {
                     target,
                     scale_factor,
                 );
+                self.profile_end(encoder);
             }
         }
 
@@ -236,6 +340,7 @@ impl Backend {
                 self.text_pipeline.queue(text);
             }
 
+            self.profile(encoder, Pipeline::Text);
             self.text_pipeline.draw_queued(
                 device,
                 staging_belt,
@@ -249,6 +354,7 @@ impl Backend {
                     height: bounds.height,
                 },
             );
+            self.profile_end(encoder);
         }
     }
 }
@@ -278,6 +384,10 @@ impl backend::Text for Backend {
         self.text_pipeline.measure(contents, size, font, bounds)
     }
 
+    fn baseline(&self, size: f32, font: Font) -> f32 {
+        self.text_pipeline.baseline(size, font)
+    }
+
     fn hit_test(
         &self,
         contents: &str,
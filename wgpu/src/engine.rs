@@ -0,0 +1,155 @@
+//! Draw iced on top of the scene of your own `wgpu` application.
+//!
+//! An [`Engine`] renders the primitives of a [`Renderer`] into any
+//! [`wgpu::TextureView`] using a caller-provided [`wgpu::CommandEncoder`].
+//! This way, game engines and other applications that own their swapchain
+//! can composite a user interface over their own content.
+//!
+//! Events produced by the application must be in logical coordinates before
+//! being fed to a [`UserInterface`]. Use [`cursor_position`] and [`event`]
+//! to convert physical coordinates with the [`Viewport`] of the target.
+//!
+//! [`UserInterface`]: iced_native::UserInterface
+use crate::{Backend, Renderer, Settings, Viewport};
+
+use futures::task::SpawnExt;
+use iced_native::futures;
+use iced_native::{mouse, pen, touch, Event, Point};
+
+/// A renderer of iced primitives into caller-provided textures.
+#[allow(missing_debug_implementations)]
+pub struct Engine {
+    format: wgpu::TextureFormat,
+    staging_belt: wgpu::util::StagingBelt,
+    local_pool: futures::executor::LocalPool,
+}
+
+impl Engine {
+    const CHUNK_SIZE: u64 = 10 * 1024;
+
+    /// Creates a new [`Engine`] that renders into textures of the given
+    /// format.
+    pub fn new(format: wgpu::TextureFormat) -> Self {
+        Self {
+            format,
+            staging_belt: wgpu::util::StagingBelt::new(Self::CHUNK_SIZE),
+            local_pool: futures::executor::LocalPool::new(),
+        }
+    }
+
+    /// Creates a new [`Renderer`] compatible with the [`Engine`].
+    pub fn create_renderer(
+        &self,
+        device: &wgpu::Device,
+        settings: Settings,
+    ) -> Renderer {
+        Renderer::new(Backend::new(device, settings, self.format))
+    }
+
+    /// Renders the primitives drawn by the given [`Renderer`] into the
+    /// target [`wgpu::TextureView`].
+    ///
+    /// The contents of the target are preserved, so the primitives are
+    /// drawn on top of them. The commands are recorded in the given
+    /// encoder, which must be submitted before calling this method again.
+    pub fn render(
+        &mut self,
+        device: &wgpu::Device,
+        renderer: &mut Renderer,
+        target: &wgpu::TextureView,
+        viewport: &Viewport,
+        encoder: &mut wgpu::CommandEncoder,
+    ) {
+        // The encoder of the previous frame has been submitted by now
+        self.local_pool
+            .spawner()
+            .spawn(self.staging_belt.recall())
+            .expect("Recall staging belt");
+
+        self.local_pool.run_until_stalled();
+
+        let staging_belt = &mut self.staging_belt;
+
+        renderer.with_primitives(|backend, primitives| {
+            backend.present::<&str>(
+                device,
+                staging_belt,
+                encoder,
+                target,
+                primitives,
+                viewport,
+                &[],
+            );
+        });
+
+        self.staging_belt.finish();
+    }
+}
+
+/// Converts a cursor position in physical pixels into logical coordinates
+/// of the given [`Viewport`], taking its rotation into account.
+pub fn cursor_position(position: Point, viewport: &Viewport) -> Point {
+    let scale_factor = viewport.scale_factor() as f32;
+
+    viewport.rotate_position(Point::new(
+        position.x / scale_factor,
+        position.y / scale_factor,
+    ))
+}
+
+/// Converts the positions of an [`Event`] in physical pixels into logical
+/// coordinates of the given [`Viewport`].
+pub fn event(event: Event, viewport: &Viewport) -> Event {
+    let logical = |position| cursor_position(position, viewport);
+
+    match event {
+        Event::Mouse(mouse::Event::CursorMoved { position }) => {
+            Event::Mouse(mouse::Event::CursorMoved {
+                position: logical(position),
+            })
+        }
+        Event::Touch(touch::Event::FingerPressed { id, position }) => {
+            Event::Touch(touch::Event::FingerPressed {
+                id,
+                position: logical(position),
+            })
+        }
+        Event::Touch(touch::Event::FingerMoved { id, position }) => {
+            Event::Touch(touch::Event::FingerMoved {
+                id,
+                position: logical(position),
+            })
+        }
+        Event::Touch(touch::Event::FingerLifted { id, position }) => {
+            Event::Touch(touch::Event::FingerLifted {
+                id,
+                position: logical(position),
+            })
+        }
+        Event::Touch(touch::Event::FingerLost { id, position }) => {
+            Event::Touch(touch::Event::FingerLost {
+                id,
+                position: logical(position),
+            })
+        }
+        Event::Pen(pen::Event::Pressed(sample)) => {
+            Event::Pen(pen::Event::Pressed(pen::Sample {
+                position: logical(sample.position),
+                ..sample
+            }))
+        }
+        Event::Pen(pen::Event::Moved(sample)) => {
+            Event::Pen(pen::Event::Moved(pen::Sample {
+                position: logical(sample.position),
+                ..sample
+            }))
+        }
+        Event::Pen(pen::Event::Lifted(sample)) => {
+            Event::Pen(pen::Event::Lifted(pen::Sample {
+                position: logical(sample.position),
+                ..sample
+            }))
+        }
+        _ => event,
+    }
+}
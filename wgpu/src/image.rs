@@ -35,14 +35,18 @@ pub struct Pipeline {
     indices: wgpu::Buffer,
     instances: wgpu::Buffer,
     constants: wgpu::BindGroup,
-    texture: wgpu::BindGroup,
+    textures: Vec<wgpu::BindGroup>,
     texture_version: usize,
     texture_layout: wgpu::BindGroupLayout,
     texture_atlas: Atlas,
 }
 
 impl Pipeline {
-    pub fn new(device: &wgpu::Device, format: wgpu::TextureFormat) -> Self {
+    pub fn new(
+        device: &wgpu::Device,
+        format: wgpu::TextureFormat,
+        texture_arrays: bool,
+    ) -> Self {
         use wgpu::util::DeviceExt;
 
         let sampler = device.create_sampler(&wgpu::SamplerDescriptor {
@@ -122,7 +126,11 @@ impl Pipeline {
                         sample_type: wgpu::TextureSampleType::Float {
                             filterable: true,
                         },
-                        view_dimension: wgpu::TextureViewDimension::D2Array,
+                        view_dimension: if texture_arrays {
+                            wgpu::TextureViewDimension::D2Array
+                        } else {
+                            wgpu::TextureViewDimension::D2
+                        },
                         multisampled: false,
                     },
                     count: None,
@@ -140,7 +148,11 @@ impl Pipeline {
             device.create_shader_module(&wgpu::ShaderModuleDescriptor {
                 label: Some("iced_wgpu::image::shader"),
                 source: wgpu::ShaderSource::Wgsl(std::borrow::Cow::Borrowed(
-                    include_str!("shader/image.wgsl"),
+                    if texture_arrays {
+                        include_str!("shader/image.wgsl")
+                    } else {
+                        include_str!("shader/image_2d.wgsl")
+                    },
                 )),
             });
 
@@ -228,18 +240,9 @@ impl Pipeline {
             mapped_at_creation: false,
         });
 
-        let texture_atlas = Atlas::new(device);
-
-        let texture = device.create_bind_group(&wgpu::BindGroupDescriptor {
-            label: Some("iced_wgpu::image texture atlas bind group"),
-            layout: &texture_layout,
-            entries: &[wgpu::BindGroupEntry {
-                binding: 0,
-                resource: wgpu::BindingResource::TextureView(
-                    &texture_atlas.view(),
-                ),
-            }],
-        });
+        let texture_atlas = Atlas::new(device, texture_arrays);
+        let textures =
+            create_texture_bind_groups(device, &texture_layout, &texture_atlas);
 
         Pipeline {
             #[cfg(feature = "image_rs")]
@@ -254,7 +257,7 @@ impl Pipeline {
             indices,
             instances,
             constants: constant_bind_group,
-            texture,
+            textures,
             texture_version: texture_atlas.layer_count(),
             texture_layout,
             texture_atlas,
@@ -277,6 +280,15 @@ impl Pipeline {
         svg.viewport_dimensions()
     }
 
+    /// Returns the amount of occupied layers of the texture atlas, and the
+    /// total amount of layers.
+    pub fn atlas_occupancy(&self) -> (usize, usize) {
+        (
+            self.texture_atlas.occupied_layers(),
+            self.texture_atlas.layer_count(),
+        )
+    }
+
     pub fn draw(
         &mut self,
         device: &wgpu::Device,
@@ -288,6 +300,9 @@ impl Pipeline {
         target: &wgpu::TextureView,
         _scale: f32,
     ) {
+        #[cfg(feature = "tracing")]
+        let _span = iced_graphics::tracing::info_span!("images").entered();
+
         let instances: &mut Vec<Instance> = &mut Vec::new();
 
         #[cfg(feature = "image_rs")]
@@ -318,11 +333,16 @@ impl Pipeline {
                 layer::Image::Raster { .. } => {}
 
                 #[cfg(feature = "svg")]
-                layer::Image::Vector { handle, bounds } => {
+                layer::Image::Vector {
+                    handle,
+                    color,
+                    bounds,
+                } => {
                     let size = [bounds.width, bounds.height];
 
                     if let Some(atlas_entry) = vector_cache.upload(
                         handle,
+                        *color,
                         size,
                         _scale,
                         device,
@@ -351,17 +371,11 @@ impl Pipeline {
         if self.texture_version != texture_version {
             log::info!("Atlas has grown. Recreating bind group...");
 
-            self.texture =
-                device.create_bind_group(&wgpu::BindGroupDescriptor {
-                    label: Some("iced_wgpu::image texture atlas bind group"),
-                    layout: &self.texture_layout,
-                    entries: &[wgpu::BindGroupEntry {
-                        binding: 0,
-                        resource: wgpu::BindingResource::TextureView(
-                            &self.texture_atlas.view(),
-                        ),
-                    }],
-                });
+            self.textures = create_texture_bind_groups(
+                device,
+                &self.texture_layout,
+                &self.texture_atlas,
+            );
 
             self.texture_version = texture_version;
         }
@@ -381,6 +395,50 @@ impl Pipeline {
             }));
         }
 
+        // Without a texture array, every layer of the atlas has its own bind
+        // group, so we split the instances in runs sharing the same layer
+        let runs: Vec<(usize, usize, usize)> = if self.texture_atlas.is_array()
+        {
+            vec![(0, instances.len(), 0)]
+        } else {
+            let mut runs = Vec::new();
+            let mut start = 0;
+
+            for i in 1..=instances.len() {
+                if i == instances.len()
+                    || instances[i]._layer != instances[start]._layer
+                {
+                    runs.push((start, i, instances[start]._layer as usize));
+                    start = i;
+                }
+            }
+
+            runs
+        };
+
+        for (start, end, texture) in runs {
+            self.draw_run(
+                device,
+                staging_belt,
+                encoder,
+                &instances[start..end],
+                &self.textures[texture],
+                bounds,
+                target,
+            );
+        }
+    }
+
+    fn draw_run(
+        &self,
+        device: &wgpu::Device,
+        staging_belt: &mut wgpu::util::StagingBelt,
+        encoder: &mut wgpu::CommandEncoder,
+        instances: &[Instance],
+        texture: &wgpu::BindGroup,
+        bounds: Rectangle<u32>,
+        target: &wgpu::TextureView,
+    ) {
         let mut i = 0;
         let total = instances.len();
 
@@ -419,7 +477,7 @@ impl Pipeline {
 
             render_pass.set_pipeline(&self.pipeline);
             render_pass.set_bind_group(0, &self.constants, &[]);
-            render_pass.set_bind_group(1, &self.texture, &[]);
+            render_pass.set_bind_group(1, texture, &[]);
             render_pass.set_index_buffer(
                 self.indices.slice(..),
                 wgpu::IndexFormat::Uint16,
@@ -453,6 +511,27 @@ impl Pipeline {
     }
 }
 
+fn create_texture_bind_groups(
+    device: &wgpu::Device,
+    layout: &wgpu::BindGroupLayout,
+    atlas: &Atlas,
+) -> Vec<wgpu::BindGroup> {
+    atlas
+        .views()
+        .into_iter()
+        .map(|view| {
+            device.create_bind_group(&wgpu::BindGroupDescriptor {
+                label: Some("iced_wgpu::image texture atlas bind group"),
+                layout,
+                entries: &[wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: wgpu::BindingResource::TextureView(view),
+                }],
+            })
+        })
+        .collect()
+}
+
 #[repr(C)]
 #[derive(Clone, Copy, Zeroable, Pod)]
 pub struct Vertex {
@@ -16,51 +16,69 @@ pub const SIZE: u32 = 2048;
 
 #[derive(Debug)]
 pub struct Atlas {
-    texture: wgpu::Texture,
-    texture_view: wgpu::TextureView,
+    backing: Backing,
     layers: Vec<Layer>,
 }
 
+#[derive(Debug)]
+enum Backing {
+    /// All the layers are stored in a single texture array.
+    Array {
+        texture: wgpu::Texture,
+        view: wgpu::TextureView,
+    },
+    /// Every layer is stored in its own texture.
+    Textures(Vec<(wgpu::Texture, wgpu::TextureView)>),
+}
+
 impl Atlas {
-    pub fn new(device: &wgpu::Device) -> Self {
-        let extent = wgpu::Extent3d {
-            width: SIZE,
-            height: SIZE,
-            depth_or_array_layers: 1,
+    pub fn new(device: &wgpu::Device, texture_arrays: bool) -> Self {
+        let backing = if texture_arrays {
+            let texture = create_texture(device, 1);
+            let view = create_array_view(&texture);
+
+            Backing::Array { texture, view }
+        } else {
+            let texture = create_texture(device, 1);
+            let view =
+                texture.create_view(&wgpu::TextureViewDescriptor::default());
+
+            Backing::Textures(vec![(texture, view)])
         };
 
-        let texture = device.create_texture(&wgpu::TextureDescriptor {
-            label: Some("iced_wgpu::image texture atlas"),
-            size: extent,
-            mip_level_count: 1,
-            sample_count: 1,
-            dimension: wgpu::TextureDimension::D2,
-            format: wgpu::TextureFormat::Bgra8UnormSrgb,
-            usage: wgpu::TextureUsages::COPY_DST
-                | wgpu::TextureUsages::COPY_SRC
-                | wgpu::TextureUsages::TEXTURE_BINDING,
-        });
-
-        let texture_view = texture.create_view(&wgpu::TextureViewDescriptor {
-            dimension: Some(wgpu::TextureViewDimension::D2Array),
-            ..Default::default()
-        });
-
         Atlas {
-            texture,
-            texture_view,
+            backing,
             layers: vec![Layer::Empty],
         }
     }
 
-    pub fn view(&self) -> &wgpu::TextureView {
-        &self.texture_view
+    /// Returns whether the layers of the [`Atlas`] are stored in a single
+    /// texture array.
+    pub fn is_array(&self) -> bool {
+        matches!(self.backing, Backing::Array { .. })
+    }
+
+    /// Returns the views of the textures of the [`Atlas`].
+    ///
+    /// There is a single view when the [`Atlas`] is a texture array, and a
+    /// view per layer otherwise.
+    pub fn views(&self) -> Vec<&wgpu::TextureView> {
+        match &self.backing {
+            Backing::Array { view, .. } => vec![view],
+            Backing::Textures(textures) => {
+                textures.iter().map(|(_, view)| view).collect()
+            }
+        }
     }
 
     pub fn layer_count(&self) -> usize {
         self.layers.len()
     }
 
+    pub fn occupied_layers(&self) -> usize {
+        self.layers.iter().filter(|layer| !layer.is_empty()).count()
+    }
+
     pub fn upload(
         &mut self,
         width: u32,
@@ -293,6 +311,11 @@ impl Atlas {
         let (width, height) = allocation.size();
         let layer = allocation.layer();
 
+        let (texture, z) = match &self.backing {
+            Backing::Array { texture, .. } => (texture, layer as u32),
+            Backing::Textures(textures) => (&textures[layer].0, 0),
+        };
+
         let extent = wgpu::Extent3d {
             width,
             height,
@@ -309,13 +332,9 @@ impl Atlas {
                 },
             },
             wgpu::ImageCopyTexture {
-                texture: &self.texture,
+                texture,
                 mip_level: 0,
-                origin: wgpu::Origin3d {
-                    x,
-                    y,
-                    z: layer as u32,
-                },
+                origin: wgpu::Origin3d { x, y, z },
                 aspect: wgpu::TextureAspect::default(),
             },
             extent,
@@ -332,34 +351,33 @@ impl Atlas {
             return;
         }
 
-        let new_texture = device.create_texture(&wgpu::TextureDescriptor {
-            label: Some("iced_wgpu::image texture atlas"),
-            size: wgpu::Extent3d {
-                width: SIZE,
-                height: SIZE,
-                depth_or_array_layers: self.layers.len() as u32,
-            },
-            mip_level_count: 1,
-            sample_count: 1,
-            dimension: wgpu::TextureDimension::D2,
-            format: wgpu::TextureFormat::Bgra8UnormSrgb,
-            usage: wgpu::TextureUsages::COPY_DST
-                | wgpu::TextureUsages::COPY_SRC
-                | wgpu::TextureUsages::TEXTURE_BINDING,
-        });
+        let (texture, view) = match &mut self.backing {
+            Backing::Array { texture, view } => (texture, view),
+            Backing::Textures(textures) => {
+                for _ in 0..amount {
+                    let texture = create_texture(device, 1);
+                    let view = texture
+                        .create_view(&wgpu::TextureViewDescriptor::default());
+
+                    textures.push((texture, view));
+                }
+
+                return;
+            }
+        };
+
+        let new_texture = create_texture(device, self.layers.len() as u32);
 
         let amount_to_copy = self.layers.len() - amount;
 
-        for (i, layer) in
-            self.layers.iter_mut().take(amount_to_copy).enumerate()
-        {
+        for (i, layer) in self.layers.iter().take(amount_to_copy).enumerate() {
             if layer.is_empty() {
                 continue;
             }
 
             encoder.copy_texture_to_texture(
                 wgpu::ImageCopyTexture {
-                    texture: &self.texture,
+                    texture,
                     mip_level: 0,
                     origin: wgpu::Origin3d {
                         x: 0,
@@ -386,11 +404,32 @@ impl Atlas {
             );
         }
 
-        self.texture = new_texture;
-        self.texture_view =
-            self.texture.create_view(&wgpu::TextureViewDescriptor {
-                dimension: Some(wgpu::TextureViewDimension::D2Array),
-                ..Default::default()
-            });
+        *view = create_array_view(&new_texture);
+        *texture = new_texture;
     }
 }
+
+fn create_texture(device: &wgpu::Device, layers: u32) -> wgpu::Texture {
+    device.create_texture(&wgpu::TextureDescriptor {
+        label: Some("iced_wgpu::image texture atlas"),
+        size: wgpu::Extent3d {
+            width: SIZE,
+            height: SIZE,
+            depth_or_array_layers: layers,
+        },
+        mip_level_count: 1,
+        sample_count: 1,
+        dimension: wgpu::TextureDimension::D2,
+        format: wgpu::TextureFormat::Bgra8UnormSrgb,
+        usage: wgpu::TextureUsages::COPY_DST
+            | wgpu::TextureUsages::COPY_SRC
+            | wgpu::TextureUsages::TEXTURE_BINDING,
+    })
+}
+
+fn create_array_view(texture: &wgpu::Texture) -> wgpu::TextureView {
+    texture.create_view(&wgpu::TextureViewDescriptor {
+        dimension: Some(wgpu::TextureViewDimension::D2Array),
+        ..Default::default()
+    })
+}
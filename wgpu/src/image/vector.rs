@@ -1,6 +1,7 @@
 use crate::image::atlas::{self, Atlas};
 
 use iced_native::svg;
+use iced_native::Color;
 
 use std::collections::{HashMap, HashSet};
 use std::fs;
@@ -23,20 +24,32 @@ impl Svg {
     }
 }
 
+type RasterKey = (u64, Option<[u8; 4]>, u32, u32);
+
+type ColorKey = (u64, [u8; 3]);
+
+const CURRENT_COLOR: &str = "currentcolor";
+
 #[derive(Debug)]
 pub struct Cache {
     svgs: HashMap<u64, Svg>,
-    rasterized: HashMap<(u64, u32, u32), atlas::Entry>,
+    colorable: HashMap<u64, Option<String>>,
+    colored: HashMap<ColorKey, Svg>,
+    rasterized: HashMap<RasterKey, atlas::Entry>,
     svg_hits: HashSet<u64>,
-    rasterized_hits: HashSet<(u64, u32, u32)>,
+    colored_hits: HashSet<ColorKey>,
+    rasterized_hits: HashSet<RasterKey>,
 }
 
 impl Cache {
     pub fn new() -> Self {
         Self {
             svgs: HashMap::new(),
+            colorable: HashMap::new(),
+            colored: HashMap::new(),
             rasterized: HashMap::new(),
             svg_hits: HashSet::new(),
+            colored_hits: HashSet::new(),
             rasterized_hits: HashSet::new(),
         }
     }
@@ -48,7 +61,11 @@ impl Cache {
 
         let svg = match handle.data() {
             svg::Data::Path(path) => {
-                let tree = fs::read_to_string(path).ok().and_then(|contents| {
+                let contents = fs::read_to_string(path).ok();
+
+                self.remember_source(handle.id(), contents.as_deref());
+
+                let tree = contents.and_then(|contents| {
                     usvg::Tree::from_str(
                         &contents,
                         &usvg::Options::default().to_ref(),
@@ -59,6 +76,11 @@ impl Cache {
                 tree.map(Svg::Loaded).unwrap_or(Svg::NotFound)
             }
             svg::Data::Bytes(bytes) => {
+                self.remember_source(
+                    handle.id(),
+                    std::str::from_utf8(bytes).ok(),
+                );
+
                 match usvg::Tree::from_data(
                     &bytes,
                     &usvg::Options::default().to_ref(),
@@ -73,9 +95,68 @@ impl Cache {
         self.svgs.get(&handle.id()).unwrap()
     }
 
+    /// Keeps the source of the SVG with the given id if it references
+    /// `currentColor`, so it can be colored later without reading it again.
+    ///
+    /// Gzip compressed data is never considered colorable.
+    fn remember_source(&mut self, id: u64, source: Option<&str>) {
+        let _ = self.colorable.entry(id).or_insert_with(|| {
+            source
+                .filter(|source| {
+                    source.to_ascii_lowercase().contains(CURRENT_COLOR)
+                })
+                .map(String::from)
+        });
+    }
+
+    /// Returns whether the SVG of the given [`svg::Handle`] references
+    /// `currentColor`, and therefore depends on the color it is drawn with.
+    fn is_colorable(&mut self, handle: &svg::Handle) -> bool {
+        let _ = self.load(handle);
+
+        matches!(self.colorable.get(&handle.id()), Some(Some(_)))
+    }
+
+    /// Loads the SVG of the given [`svg::Handle`], resolving any
+    /// `currentColor` reference to the provided [`Color`].
+    ///
+    /// `usvg` has no option for the color that `currentColor` resolves to,
+    /// so the keyword is replaced in the source before parsing it. Unlike an
+    /// inherited `color` attribute, this also takes precedence over any
+    /// `color` already present in the SVG.
+    fn load_with_color(&mut self, handle: &svg::Handle, color: Color) -> &Svg {
+        let key = color_key(handle.id(), color);
+
+        let _ = self.colored_hits.insert(key);
+
+        if !self.colored.contains_key(&key) {
+            let tree = self
+                .colorable
+                .get(&handle.id())
+                .and_then(Option::as_deref)
+                .and_then(|source| {
+                    let [r, g, b] = key.1;
+                    let color = format!("#{:02x}{:02x}{:02x}", r, g, b);
+
+                    usvg::Tree::from_str(
+                        &replace_current_color(source, &color),
+                        &usvg::Options::default().to_ref(),
+                    )
+                    .ok()
+                });
+
+            let _ = self
+                .colored
+                .insert(key, tree.map(Svg::Loaded).unwrap_or(Svg::NotFound));
+        }
+
+        self.colored.get(&key).unwrap()
+    }
+
     pub fn upload(
         &mut self,
         handle: &svg::Handle,
+        color: Option<Color>,
         [width, height]: [f32; 2],
         scale: f32,
         device: &wgpu::Device,
@@ -89,18 +170,32 @@ impl Cache {
             (scale * height).ceil() as u32,
         );
 
+        // Only the rasterizations of an SVG that uses `currentColor` depend on
+        // the color it is drawn with
+        let color = color.filter(|_| self.is_colorable(handle));
+        let key = (id, color.map(Color::into_rgba8), width, height);
+
         // TODO: Optimize!
         // We currently rerasterize the SVG when its size changes. This is slow
         // as heck. A GPU rasterizer like `pathfinder` may perform better.
         // It would be cool to be able to smooth resize the `svg` example.
-        if self.rasterized.contains_key(&(id, width, height)) {
+        if self.rasterized.contains_key(&key) {
             let _ = self.svg_hits.insert(id);
-            let _ = self.rasterized_hits.insert((id, width, height));
+            let _ = self.rasterized_hits.insert(key);
 
-            return self.rasterized.get(&(id, width, height));
+            if let Some(color) = color {
+                let _ = self.colored_hits.insert(color_key(id, color));
+            }
+
+            return self.rasterized.get(&key);
         }
 
-        match self.load(handle) {
+        let svg = match color {
+            Some(color) => self.load_with_color(handle, color),
+            None => self.load(handle),
+        };
+
+        match svg {
             Svg::Loaded(tree) => {
                 if width == 0 || height == 0 {
                     return None;
@@ -125,6 +220,15 @@ impl Cache {
                 let mut rgba = img.take();
                 rgba.chunks_exact_mut(4).for_each(|rgba| rgba.swap(0, 2));
 
+                // `currentColor` ignores alpha, so we apply it afterwards
+                if let Some(alpha) = color.map(|color| color.a) {
+                    if alpha < 1.0 {
+                        rgba.iter_mut().for_each(|component| {
+                            *component = (f32::from(*component) * alpha) as u8
+                        });
+                    }
+                }
+
                 let allocation = texture_atlas.upload(
                     width,
                     height,
@@ -135,10 +239,10 @@ impl Cache {
                 log::debug!("allocating {} {}x{}", id, width, height);
 
                 let _ = self.svg_hits.insert(id);
-                let _ = self.rasterized_hits.insert((id, width, height));
-                let _ = self.rasterized.insert((id, width, height), allocation);
+                let _ = self.rasterized_hits.insert(key);
+                let _ = self.rasterized.insert(key, allocation);
 
-                self.rasterized.get(&(id, width, height))
+                self.rasterized.get(&key)
             }
             Svg::NotFound => None,
         }
@@ -146,9 +250,12 @@ impl Cache {
 
     pub fn trim(&mut self, atlas: &mut Atlas) {
         let svg_hits = &self.svg_hits;
+        let colored_hits = &self.colored_hits;
         let rasterized_hits = &self.rasterized_hits;
 
         self.svgs.retain(|k, _| svg_hits.contains(k));
+        self.colorable.retain(|k, _| svg_hits.contains(k));
+        self.colored.retain(|k, _| colored_hits.contains(k));
         self.rasterized.retain(|k, entry| {
             let retain = rasterized_hits.contains(k);
 
@@ -159,10 +266,39 @@ impl Cache {
             retain
         });
         self.svg_hits.clear();
+        self.colored_hits.clear();
         self.rasterized_hits.clear();
     }
 }
 
+/// Returns the key of the SVG with the given id colored with the given
+/// [`Color`], which ignores alpha like `currentColor` does.
+fn color_key(id: u64, color: Color) -> ColorKey {
+    let [r, g, b, _] = color.into_rgba8();
+
+    (id, [r, g, b])
+}
+
+/// Replaces every `currentColor` keyword of the given SVG source with the
+/// given color.
+fn replace_current_color(source: &str, color: &str) -> String {
+    // CSS keywords are case-insensitive and ASCII lowercasing keeps the byte
+    // offsets of the source intact
+    let lowercase = source.to_ascii_lowercase();
+    let mut colored = String::with_capacity(source.len());
+    let mut last = 0;
+
+    for (start, _) in lowercase.match_indices(CURRENT_COLOR) {
+        colored.push_str(&source[last..start]);
+        colored.push_str(color);
+
+        last = start + CURRENT_COLOR.len();
+    }
+
+    colored.push_str(&source[last..]);
+    colored
+}
+
 impl std::fmt::Debug for Svg {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
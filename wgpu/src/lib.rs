@@ -30,6 +30,8 @@
 #![forbid(rust_2018_idioms)]
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
+pub mod engine;
+pub mod profiler;
 pub mod settings;
 pub mod triangle;
 pub mod widget;
@@ -43,6 +45,7 @@ pub use iced_graphics::{Antialiasing, Color, Error, Primitive, Viewport};
 pub use wgpu;
 
 pub use backend::Backend;
+pub use engine::Engine;
 pub use settings::Settings;
 
 #[doc(no_inline)]
@@ -53,6 +56,10 @@ pub(crate) use iced_graphics::Transformation;
 #[cfg(any(feature = "image_rs", feature = "svg"))]
 mod image;
 
+#[cfg(feature = "snapshot")]
+#[cfg_attr(docsrs, doc(cfg(feature = "snapshot")))]
+pub mod snapshot;
+
 /// A [`wgpu`] graphics renderer for [`iced`].
 ///
 /// [`wgpu`]: https://github.com/gfx-rs/wgpu-rs
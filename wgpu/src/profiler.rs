@@ -0,0 +1,228 @@
+//! Measure the time the GPU spends in every pipeline.
+use futures::FutureExt;
+
+use std::future::Future;
+use std::pin::Pin;
+use std::time::Duration;
+
+/// The maximum amount of timestamps written in a frame.
+const MAX_TIMESTAMPS: u32 = 512;
+
+/// A pipeline of a [`Backend`].
+///
+/// [`Backend`]: crate::Backend
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Pipeline {
+    /// The pipeline that draws quads.
+    Quads,
+    /// The pipeline that draws images and vector graphics.
+    Images,
+    /// The pipeline that draws text.
+    Text,
+    /// The pipeline that draws triangle meshes.
+    Meshes,
+}
+
+/// The time the GPU spent in every [`Pipeline`] during a frame.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Timings {
+    /// The time spent drawing quads.
+    pub quads: Duration,
+    /// The time spent drawing images and vector graphics.
+    pub images: Duration,
+    /// The time spent drawing text.
+    pub text: Duration,
+    /// The time spent drawing triangle meshes.
+    pub meshes: Duration,
+}
+
+impl Timings {
+    /// Returns the total time spent in all the pipelines.
+    pub fn total(&self) -> Duration {
+        self.quads + self.images + self.text + self.meshes
+    }
+
+    fn get_mut(&mut self, pipeline: Pipeline) -> &mut Duration {
+        match pipeline {
+            Pipeline::Quads => &mut self.quads,
+            Pipeline::Images => &mut self.images,
+            Pipeline::Text => &mut self.text,
+            Pipeline::Meshes => &mut self.meshes,
+        }
+    }
+}
+
+type Mapping =
+    Pin<Box<dyn Future<Output = Result<(), wgpu::BufferAsyncError>> + Send>>;
+
+/// Writes timestamps around the passes of every [`Pipeline`] and reads them
+/// back without stalling.
+///
+/// The timestamps of a frame are only written if the ones of the previous
+/// frames have been read back already. Therefore, [`Timings`] are only
+/// produced every few frames.
+pub(crate) struct Profiler {
+    query_set: wgpu::QuerySet,
+    buffer: wgpu::Buffer,
+    period: f32,
+    passes: Vec<Pipeline>,
+    is_open: bool,
+    state: State,
+    timings: Option<Timings>,
+}
+
+enum State {
+    Idle,
+    Recording,
+    Resolved,
+    Mapping(Mapping),
+}
+
+impl Profiler {
+    /// Creates a new [`Profiler`] for a device with timestamp queries
+    /// enabled, given the period of its timestamps in nanoseconds.
+    pub fn new(device: &wgpu::Device, period: f32) -> Self {
+        let query_set = device.create_query_set(&wgpu::QuerySetDescriptor {
+            label: Some("iced_wgpu::profiler query set"),
+            ty: wgpu::QueryType::Timestamp,
+            count: MAX_TIMESTAMPS,
+        });
+
+        let buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("iced_wgpu::profiler buffer"),
+            size: u64::from(MAX_TIMESTAMPS) * 8,
+            usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::MAP_READ,
+            mapped_at_creation: false,
+        });
+
+        Profiler {
+            query_set,
+            buffer,
+            period,
+            passes: Vec::new(),
+            is_open: false,
+            state: State::Idle,
+            timings: None,
+        }
+    }
+
+    /// Returns the [`Timings`] of the latest frame that was read back.
+    pub fn timings(&self) -> Option<Timings> {
+        self.timings
+    }
+
+    /// Reads back the timestamps of a previous frame, if available, and
+    /// starts recording a new frame, if possible.
+    pub fn begin_frame(&mut self, device: &wgpu::Device) {
+        if let State::Resolved = self.state {
+            let mapping = self.buffer.slice(..).map_async(wgpu::MapMode::Read);
+
+            self.state = State::Mapping(Box::pin(mapping));
+        }
+
+        if let State::Mapping(mapping) = &mut self.state {
+            device.poll(wgpu::Maintain::Poll);
+
+            match mapping.as_mut().now_or_never() {
+                Some(Ok(())) => {
+                    self.timings = Some(self.read());
+                    self.buffer.unmap();
+                    self.state = State::Idle;
+                }
+                Some(Err(error)) => {
+                    log::warn!("failed to read GPU timestamps: {:?}", error);
+                    self.state = State::Idle;
+                }
+                None => {}
+            }
+        }
+
+        if let State::Idle = self.state {
+            self.passes.clear();
+            self.state = State::Recording;
+        }
+    }
+
+    /// Writes the timestamp at the start of a pass of the given [`Pipeline`].
+    pub fn begin(
+        &mut self,
+        encoder: &mut wgpu::CommandEncoder,
+        pipeline: Pipeline,
+    ) {
+        let index = 2 * self.passes.len() as u32;
+
+        if let State::Recording = self.state {
+            if index + 2 <= MAX_TIMESTAMPS {
+                encoder.write_timestamp(&self.query_set, index);
+
+                self.passes.push(pipeline);
+                self.is_open = true;
+            }
+        }
+    }
+
+    /// Writes the timestamp at the end of the current pass, if any.
+    pub fn end(&mut self, encoder: &mut wgpu::CommandEncoder) {
+        if self.is_open {
+            let index = 2 * self.passes.len() as u32 - 1;
+
+            encoder.write_timestamp(&self.query_set, index);
+
+            self.is_open = false;
+        }
+    }
+
+    /// Resolves the timestamps of the frame being recorded, if any.
+    pub fn end_frame(&mut self, encoder: &mut wgpu::CommandEncoder) {
+        if let State::Recording = self.state {
+            if self.passes.is_empty() {
+                self.state = State::Idle;
+            } else {
+                encoder.resolve_query_set(
+                    &self.query_set,
+                    0..2 * self.passes.len() as u32,
+                    &self.buffer,
+                    0,
+                );
+
+                self.state = State::Resolved;
+            }
+        }
+    }
+
+    fn read(&self) -> Timings {
+        let data = self.buffer.slice(..).get_mapped_range();
+
+        let timestamps: Vec<u64> = data
+            .chunks_exact(8)
+            .take(2 * self.passes.len())
+            .map(|bytes| {
+                let mut timestamp = [0; 8];
+                timestamp.copy_from_slice(bytes);
+
+                u64::from_le_bytes(timestamp)
+            })
+            .collect();
+
+        self.passes.iter().zip(timestamps.chunks_exact(2)).fold(
+            Timings::default(),
+            |mut timings, (pipeline, pass)| {
+                let ticks = pass[1].saturating_sub(pass[0]);
+                let nanos = (ticks as f64 * f64::from(self.period)) as u64;
+
+                *timings.get_mut(*pipeline) += Duration::from_nanos(nanos);
+
+                timings
+            },
+        )
+    }
+}
+
+impl std::fmt::Debug for Profiler {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Profiler")
+            .field("passes", &self.passes)
+            .field("timings", &self.timings)
+            .finish()
+    }
+}
@@ -172,6 +172,9 @@ impl Pipeline {
         bounds: Rectangle<u32>,
         target: &wgpu::TextureView,
     ) {
+        #[cfg(feature = "tracing")]
+        let _span = iced_graphics::tracing::info_span!("quads").entered();
+
         let uniforms = Uniforms::new(transformation, scale);
 
         {
@@ -34,6 +34,28 @@ pub struct Settings {
     ///
     /// By default, it is `None`.
     pub antialiasing: Option<Antialiasing>,
+
+    /// If enabled, the time the GPU spends in every pipeline is measured with
+    /// timestamp queries, when supported by the graphics adapter.
+    ///
+    /// The measurements are shown in the debug overlay and can be obtained
+    /// with [`Backend::gpu_timings`].
+    ///
+    /// By default, it is disabled.
+    ///
+    /// [`Backend::gpu_timings`]: crate::Backend::gpu_timings
+    pub gpu_timestamps: bool,
+
+    /// If enabled, the texture atlas of images is stored in a single texture
+    /// array. Otherwise, every layer of the atlas is stored in its own
+    /// texture, which needs more draw calls but works on platforms with
+    /// poor support for texture arrays, like WebGL2.
+    ///
+    /// By default, it is enabled. The [`Compositor`] disables it
+    /// automatically when the graphics adapter is not WebGPU compliant.
+    ///
+    /// [`Compositor`]: crate::window::Compositor
+    pub texture_arrays: bool,
 }
 
 impl Settings {
@@ -69,6 +91,8 @@ impl Default for Settings {
             default_text_size: 20,
             text_multithreading: false,
             antialiasing: None,
+            gpu_timestamps: false,
+            texture_arrays: true,
         }
     }
 }
@@ -24,6 +24,7 @@ struct VertexOutput {
     [[location(3)]] scale: vec2<f32>;
     [[location(4)]] border_radius: f32;
     [[location(5)]] border_width: f32;
+    [[location(6)]] frag_pos: vec2<f32>;
 };
 
 [[stage(vertex)]]
@@ -51,6 +52,7 @@ fn vs_main(input: VertexInput) -> VertexOutput {
     out.scale = scale;
     out.border_radius = border_radius * globals.scale;
     out.border_width = input.border_width * globals.scale;
+    out.frag_pos = (transform * vec4<f32>(input.v_pos, 0.0, 1.0)).xy;
     out.position = globals.transform * transform * vec4<f32>(input.v_pos, 0.0, 1.0);
 
     return out;
@@ -91,7 +93,7 @@ fn fs_main(
         );
 
         var internal_distance: f32 = distance_alg(
-            vec2<f32>(input.position.x, input.position.y),
+            input.frag_pos,
             input.pos + vec2<f32>(input.border_width, input.border_width),
             input.scale - vec2<f32>(input.border_width * 2.0, input.border_width * 2.0),
             internal_border
@@ -107,7 +109,7 @@ fn fs_main(
     }
 
     var dist: f32 = distance_alg(
-        vec2<f32>(input.position.x, input.position.y),
+        input.frag_pos,
         input.pos,
         input.scale,
         input.border_radius
@@ -0,0 +1,289 @@
+//! Compare the rendered output of a user interface against reference images.
+//!
+//! Use [`assert_snapshot!`] to render an [`Element`] offscreen and compare it
+//! with a stored PNG image, so visual regressions are caught automatically:
+//!
+//! ```no_run
+//! # use iced_wgpu::{assert_snapshot, Renderer};
+//! # use iced_native::widget::Text;
+//! # use iced_native::Size;
+//! #
+//! # fn view() -> Text<Renderer> { Text::new("Hello!") }
+//! #
+//! #[test]
+//! fn hello() {
+//!     assert_snapshot!(view(), Size::new(200, 100));
+//! }
+//! ```
+//!
+//! The reference images are stored in the `snapshots` directory of the
+//! crate. When a reference image does not exist, or the `ICED_SNAPSHOT_UPDATE`
+//! environment variable is set, the rendered output is saved as the new
+//! reference instead.
+//!
+//! [`Element`]: iced_native::Element
+use crate::window::Compositor;
+use crate::{Renderer, Settings, Viewport};
+
+use iced_native::renderer;
+use iced_native::user_interface::{self, UserInterface};
+use iced_native::{Element, Point, Size};
+
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+/// The maximum difference allowed between two channels of a pixel for them
+/// to be considered equal.
+pub const DEFAULT_TOLERANCE: u8 = 2;
+
+/// The environment variable that forces the reference images to be updated.
+pub const UPDATE_VARIABLE: &str = "ICED_SNAPSHOT_UPDATE";
+
+/// An image rendered offscreen.
+#[derive(Clone, PartialEq)]
+pub struct Snapshot {
+    size: Size<u32>,
+    rgba: Vec<u8>,
+}
+
+impl Snapshot {
+    /// Renders the given [`Element`] offscreen with the given [`Size`] and
+    /// [`Settings`].
+    ///
+    /// Returns `None` if no compatible graphics adapter could be found.
+    pub fn render<'a, Message>(
+        element: impl Into<Element<'a, Message, Renderer>>,
+        size: Size<u32>,
+        settings: Settings,
+    ) -> Option<Self> {
+        let mut compositor = iced_native::futures::executor::block_on(
+            Compositor::request_headless(settings),
+        )?;
+
+        let mut renderer = Renderer::new(compositor.create_backend());
+
+        let mut user_interface = UserInterface::build(
+            element,
+            Size::new(size.width as f32, size.height as f32),
+            user_interface::Cache::default(),
+            &mut renderer,
+        );
+
+        let style = renderer::Style::default();
+
+        let _ =
+            user_interface.draw(&mut renderer, &style, Point::new(-1.0, -1.0));
+
+        let background_color = style.theme.palette().background;
+        let viewport = Viewport::with_physical_size(size, 1.0);
+
+        let rgba = compositor.render_offscreen::<&str>(
+            &mut renderer,
+            &viewport,
+            background_color,
+            &[],
+        );
+
+        Some(Self { size, rgba })
+    }
+
+    /// Returns the [`Size`] of the [`Snapshot`].
+    pub fn size(&self) -> Size<u32> {
+        self.size
+    }
+
+    /// Returns the pixels of the [`Snapshot`] in RGBA order.
+    pub fn rgba(&self) -> &[u8] {
+        &self.rgba
+    }
+
+    /// Loads a [`Snapshot`] from the PNG image at the given path.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, Error> {
+        let image = image_rs::open(path)?.to_rgba8();
+        let (width, height) = image.dimensions();
+
+        Ok(Self {
+            size: Size::new(width, height),
+            rgba: image.into_raw(),
+        })
+    }
+
+    /// Saves the [`Snapshot`] as a PNG image at the given path, creating its
+    /// parent directories if needed.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), Error> {
+        let path = path.as_ref();
+
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+
+        image_rs::save_buffer(
+            path,
+            &self.rgba,
+            self.size.width,
+            self.size.height,
+            image_rs::ColorType::Rgba8,
+        )?;
+
+        Ok(())
+    }
+
+    /// Returns the amount of pixels that differ between two snapshots by
+    /// more than the given tolerance in any channel.
+    ///
+    /// Returns `None` if the snapshots have different sizes.
+    pub fn diff(&self, other: &Snapshot, tolerance: u8) -> Option<usize> {
+        if self.size != other.size {
+            return None;
+        }
+
+        Some(
+            self.rgba
+                .chunks_exact(4)
+                .zip(other.rgba.chunks_exact(4))
+                .filter(|(a, b)| {
+                    a.iter()
+                        .zip(b.iter())
+                        .any(|(a, b)| a.max(b) - a.min(b) > tolerance)
+                })
+                .count(),
+        )
+    }
+}
+
+impl fmt::Debug for Snapshot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Snapshot({} * {})", self.size.width, self.size.height)
+    }
+}
+
+/// Compares a [`Snapshot`] against the reference image at the given path.
+///
+/// If the reference does not exist, or the [`UPDATE_VARIABLE`] is set, the
+/// [`Snapshot`] is saved as the new reference.
+///
+/// # Panics
+/// Panics if the [`Snapshot`] does not match the reference. The mismatching
+/// [`Snapshot`] is saved next to the reference with an `actual.png`
+/// extension.
+pub fn assert_matches(snapshot: &Snapshot, reference: &Path, tolerance: u8) {
+    if std::env::var_os(UPDATE_VARIABLE).is_some() || !reference.exists() {
+        snapshot.save(reference).expect("Save snapshot");
+        return;
+    }
+
+    let expected = Snapshot::load(reference).expect("Load snapshot");
+
+    let mismatch = match snapshot.diff(&expected, tolerance) {
+        Some(0) => return,
+        Some(pixels) => format!("{} pixels differ", pixels),
+        None => format!(
+            "the size differs: expected {:?}, found {:?}",
+            expected.size, snapshot.size
+        ),
+    };
+
+    let actual = reference.with_extension("actual.png");
+    snapshot.save(&actual).expect("Save snapshot");
+
+    panic!(
+        "snapshot {} does not match: {}; the result was saved in {}",
+        reference.display(),
+        mismatch,
+        actual.display()
+    );
+}
+
+/// Returns the path of the reference image with the given name in the
+/// `snapshots` directory of the given crate directory.
+pub fn reference(crate_directory: &str, name: &str) -> PathBuf {
+    Path::new(crate_directory)
+        .join("snapshots")
+        .join(name.replace("::", "-"))
+        .with_extension("png")
+}
+
+#[doc(hidden)]
+pub fn function_name<T>(_: T) -> &'static str {
+    let name = std::any::type_name::<T>();
+
+    name.strip_suffix("::f").unwrap_or(name)
+}
+
+/// Renders an [`Element`] offscreen with the given [`Size`] and compares it
+/// against its reference image.
+///
+/// The reference image is named after the function where the macro is
+/// called, unless a name is provided. An optional tolerance, the maximum
+/// difference allowed per channel, may follow.
+///
+/// See the [`snapshot`](crate::snapshot) module to learn more.
+///
+/// [`Element`]: iced_native::Element
+#[macro_export]
+macro_rules! assert_snapshot {
+    ($element:expr, $size:expr $(,)?) => {{
+        fn f() {}
+
+        $crate::assert_snapshot!(
+            $element,
+            $size,
+            $crate::snapshot::function_name(f)
+        )
+    }};
+    ($element:expr, $size:expr, $name:expr $(,)?) => {
+        $crate::assert_snapshot!(
+            $element,
+            $size,
+            $name,
+            $crate::snapshot::DEFAULT_TOLERANCE
+        )
+    };
+    ($element:expr, $size:expr, $name:expr, $tolerance:expr $(,)?) => {{
+        let snapshot = $crate::snapshot::Snapshot::render(
+            $element,
+            $size,
+            $crate::Settings::default(),
+        )
+        .expect("Find a graphics adapter");
+
+        $crate::snapshot::assert_matches(
+            &snapshot,
+            &$crate::snapshot::reference(env!("CARGO_MANIFEST_DIR"), $name),
+            $tolerance,
+        );
+    }};
+}
+
+/// An error that occurred while loading or saving a [`Snapshot`].
+#[derive(Debug)]
+pub enum Error {
+    /// An I/O error.
+    Io(std::io::Error),
+
+    /// The image could not be encoded or decoded.
+    Image(image_rs::ImageError),
+}
+
+impl From<std::io::Error> for Error {
+    fn from(error: std::io::Error) -> Self {
+        Error::Io(error)
+    }
+}
+
+impl From<image_rs::ImageError> for Error {
+    fn from(error: image_rs::ImageError) -> Self {
+        Error::Image(error)
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io(error) => write!(f, "{}", error),
+            Error::Image(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
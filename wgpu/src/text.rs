@@ -77,6 +77,9 @@ impl Pipeline {
         transformation: Transformation,
         region: wgpu_glyph::Region,
     ) {
+        #[cfg(feature = "tracing")]
+        let _span = iced_graphics::tracing::info_span!("text").entered();
+
         self.draw_brush
             .borrow_mut()
             .draw_queued_with_transform_and_scissoring(
@@ -121,6 +124,17 @@ impl Pipeline {
         }
     }
 
+    pub fn baseline(&self, size: f32, font: iced_native::Font) -> f32 {
+        use ab_glyph::{Font as _, ScaleFont as _};
+        use wgpu_glyph::GlyphCruncher;
+
+        let wgpu_glyph::FontId(font_id) = self.find_font(font);
+
+        self.measure_brush.borrow().fonts()[font_id]
+            .as_scaled(size)
+            .ascent()
+    }
+
     pub fn hit_test(
         &self,
         content: &str,
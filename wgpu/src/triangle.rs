@@ -1,5 +1,5 @@
 //! Draw meshes of triangles.
-use crate::{settings, Transformation};
+use crate::{settings, Transformation, Viewport};
 use iced_graphics::layer;
 
 use bytemuck::{Pod, Zeroable};
@@ -222,9 +222,12 @@ impl Pipeline {
         target_width: u32,
         target_height: u32,
         transformation: Transformation,
-        scale_factor: f32,
+        viewport: &Viewport,
         meshes: &[layer::Mesh<'_>],
     ) {
+        #[cfg(feature = "tracing")]
+        let _span = iced_graphics::tracing::info_span!("triangles").entered();
+
         // This looks a bit crazy, but we are just counting how many vertices
         // and indices we will need to handle.
         // TODO: Improve readability
@@ -373,7 +376,10 @@ impl Pipeline {
             for (i, (vertex_offset, index_offset, indices)) in
                 offsets.into_iter().enumerate()
             {
-                let clip_bounds = (meshes[i].clip_bounds * scale_factor).snap();
+                let clip_bounds = viewport.rotate_bounds(
+                    (meshes[i].clip_bounds * viewport.scale_factor() as f32)
+                        .snap(),
+                );
 
                 render_pass.set_scissor_rect(
                     clip_bounds.x,
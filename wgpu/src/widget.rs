@@ -10,15 +10,29 @@
 use crate::Renderer;
 
 pub mod button;
+pub mod cached;
 pub mod checkbox;
 pub mod container;
+pub mod disabled;
+pub mod draggable;
+pub mod drop_target;
+pub mod error_boundary;
+pub mod form;
+pub mod grid;
+pub mod inspector;
+pub mod lazy;
+pub mod mouse_area;
+pub mod pagination;
 pub mod pane_grid;
 pub mod pick_list;
 pub mod progress_bar;
 pub mod radio;
+pub mod reorderable_column;
+pub mod responsive;
 pub mod rule;
 pub mod scrollable;
 pub mod slider;
+pub mod stack;
 pub mod text_input;
 pub mod toggler;
 pub mod tooltip;
@@ -26,10 +40,32 @@ pub mod tooltip;
 #[doc(no_inline)]
 pub use button::Button;
 #[doc(no_inline)]
+pub use cached::Cached;
+#[doc(no_inline)]
 pub use checkbox::Checkbox;
 #[doc(no_inline)]
 pub use container::Container;
 #[doc(no_inline)]
+pub use disabled::{disabled, Disabled};
+#[doc(no_inline)]
+pub use draggable::Draggable;
+#[doc(no_inline)]
+pub use drop_target::DropTarget;
+#[doc(no_inline)]
+pub use error_boundary::ErrorBoundary;
+#[doc(no_inline)]
+pub use form::Form;
+#[doc(no_inline)]
+pub use grid::Grid;
+#[doc(no_inline)]
+pub use inspector::Inspector;
+#[doc(no_inline)]
+pub use lazy::Lazy;
+#[doc(no_inline)]
+pub use mouse_area::MouseArea;
+#[doc(no_inline)]
+pub use pagination::Pagination;
+#[doc(no_inline)]
 pub use pane_grid::PaneGrid;
 #[doc(no_inline)]
 pub use pick_list::PickList;
@@ -38,12 +74,18 @@ pub use progress_bar::ProgressBar;
 #[doc(no_inline)]
 pub use radio::Radio;
 #[doc(no_inline)]
+pub use reorderable_column::ReorderableColumn;
+#[doc(no_inline)]
+pub use responsive::Responsive;
+#[doc(no_inline)]
 pub use rule::Rule;
 #[doc(no_inline)]
 pub use scrollable::Scrollable;
 #[doc(no_inline)]
 pub use slider::Slider;
 #[doc(no_inline)]
+pub use stack::Stack;
+#[doc(no_inline)]
 pub use text_input::TextInput;
 #[doc(no_inline)]
 pub use toggler::Toggler;
@@ -68,12 +110,41 @@ pub use qr_code::QRCode;
 
 pub use iced_native::widget::Space;
 
+/// A widget that keeps redrawing its content while some animation is running.
+pub type Animated<'a, Message> =
+    iced_native::widget::Animated<'a, Message, Renderer>;
+
+/// A container that sizes its content to a fixed aspect ratio.
+pub type AspectRatio<'a, Message> =
+    iced_native::widget::AspectRatio<'a, Message, Renderer>;
+
 /// A container that distributes its contents vertically.
 pub type Column<'a, Message> =
     iced_native::widget::Column<'a, Message, Renderer>;
 
+/// A widget that sets the direction in which its content flows.
+pub type Directional<'a, Message> =
+    iced_native::widget::Directional<'a, Message, Renderer>;
+
+/// A widget that animates its content when it enters or exits the view.
+pub type Reveal<'a, Message> =
+    iced_native::widget::Reveal<'a, Message, Renderer>;
+
 /// A container that distributes its contents horizontally.
 pub type Row<'a, Message> = iced_native::widget::Row<'a, Message, Renderer>;
 
+/// A widget that triggers some keyboard shortcuts while a widget inside of its
+/// content is focused.
+pub type ShortcutScope<'a, Message> =
+    iced_native::widget::ShortcutScope<'a, Message, Renderer>;
+
 /// A paragraph of text.
 pub type Text = iced_native::widget::Text<Renderer>;
+
+/// A widget that moves or resizes the window when its content is dragged.
+pub type WindowHandle<'a, Message> =
+    iced_native::widget::WindowHandle<'a, Message, Renderer>;
+
+/// A container that distributes its contents horizontally, wrapping them into
+/// multiple lines.
+pub type Wrap<'a, Message> = iced_native::widget::Wrap<'a, Message, Renderer>;
@@ -0,0 +1,13 @@
+//! Inspect the widgets of a user interface while it runs.
+//!
+//! An [`Inspector`] has some local [`State`].
+use crate::Renderer;
+
+pub use iced_native::widget::inspector::{Node, State};
+
+/// A widget that lets users inspect the widgets of its content.
+///
+/// This is an alias of an `iced_native` inspector with an
+/// `iced_wgpu::Renderer`.
+pub type Inspector<'a, Message> =
+    iced_native::widget::Inspector<'a, Message, Renderer>;
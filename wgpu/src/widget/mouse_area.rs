@@ -0,0 +1,14 @@
+//! Listen to mouse events on some content.
+//!
+//! A [`MouseArea`] has some local [`State`].
+use crate::Renderer;
+
+pub use iced_native::widget::mouse_area::State;
+
+/// A widget that produces messages for the mouse events happening over its
+/// content.
+///
+/// This is an alias of an `iced_native` mouse area with an
+/// `iced_wgpu::Renderer`.
+pub type MouseArea<'a, Message> =
+    iced_native::widget::MouseArea<'a, Message, Renderer>;
@@ -4,7 +4,10 @@
 use crate::Renderer;
 
 pub use iced_graphics::text_input::{Style, StyleSheet};
-pub use iced_native::widget::text_input::State;
+pub use iced_native::widget::text_input::{
+    clear_history, focus, snapshot, Icon, Mask, Selection, Side, State,
+    Validation,
+};
 
 /// A field that can be filled with text.
 ///
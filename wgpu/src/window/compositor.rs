@@ -2,6 +2,7 @@ use crate::{Backend, Color, Error, Renderer, Settings, Viewport};
 
 use futures::task::SpawnExt;
 use iced_native::futures;
+use iced_native::window::PresentMode;
 use raw_window_handle::HasRawWindowHandle;
 
 /// A window graphics backend for iced powered by `wgpu`.
@@ -32,6 +33,27 @@ impl Compositor {
         let compatible_surface = compatible_window
             .map(|window| unsafe { instance.create_surface(window) });
 
+        Self::request_with_surface(settings, instance, compatible_surface).await
+    }
+
+    /// Requests a new [`Compositor`] with the given [`Settings`] that is not
+    /// tied to any window.
+    ///
+    /// A headless [`Compositor`] can only render offscreen. See
+    /// [`Compositor::render_offscreen`].
+    ///
+    /// Returns `None` if no compatible graphics adapter could be found.
+    pub async fn request_headless(settings: Settings) -> Option<Self> {
+        let instance = wgpu::Instance::new(settings.internal_backend);
+
+        Self::request_with_surface(settings, instance, None).await
+    }
+
+    async fn request_with_surface(
+        mut settings: Settings,
+        instance: wgpu::Instance,
+        compatible_surface: Option<wgpu::Surface>,
+    ) -> Option<Self> {
         let adapter = instance
             .request_adapter(&wgpu::RequestAdapterOptions {
                 power_preference: if settings.antialiasing.is_none() {
@@ -44,9 +66,31 @@ impl Compositor {
             })
             .await?;
 
-        let format = compatible_surface
-            .as_ref()
-            .and_then(|surface| surface.get_preferred_format(&adapter))?;
+        let format = match &compatible_surface {
+            Some(surface) => surface.get_preferred_format(&adapter)?,
+            None => wgpu::TextureFormat::Rgba8UnormSrgb,
+        };
+
+        let features = if settings.gpu_timestamps {
+            adapter.features() & wgpu::Features::TIMESTAMP_QUERY
+        } else {
+            wgpu::Features::empty()
+        };
+
+        // Adapters that are not fully WebGPU compliant, like the ones of
+        // WebGL2, only support the downlevel limits and have poor support
+        // for texture arrays
+        let is_downlevel =
+            !adapter.get_downlevel_properties().is_webgpu_compliant();
+
+        let limits = if is_downlevel {
+            settings.texture_arrays = false;
+
+            wgpu::Limits::downlevel_webgl2_defaults()
+                .using_resolution(adapter.limits())
+        } else {
+            wgpu::Limits::default()
+        };
 
         let (device, queue) = adapter
             .request_device(
@@ -54,10 +98,10 @@ impl Compositor {
                     label: Some(
                         "iced_wgpu::window::compositor device descriptor",
                     ),
-                    features: wgpu::Features::empty(),
+                    features,
                     limits: wgpu::Limits {
                         max_bind_groups: 2,
-                        ..wgpu::Limits::default()
+                        ..limits
                     },
                 },
                 None,
@@ -81,7 +125,199 @@ impl Compositor {
 
     /// Creates a new rendering [`Backend`] for this [`Compositor`].
     pub fn create_backend(&self) -> Backend {
-        Backend::new(&self.device, self.settings, self.format)
+        let mut backend =
+            Backend::new(&self.device, self.settings, self.format);
+
+        if self
+            .device
+            .features()
+            .contains(wgpu::Features::TIMESTAMP_QUERY)
+        {
+            backend.enable_profiling(
+                &self.device,
+                self.queue.get_timestamp_period(),
+            );
+        }
+
+        backend
+    }
+
+    /// Renders the primitives of the given [`Renderer`] into an offscreen
+    /// texture and returns its pixels.
+    ///
+    /// The pixels are in RGBA order, row by row, with the physical size of
+    /// the given [`Viewport`].
+    pub fn render_offscreen<T: AsRef<str>>(
+        &mut self,
+        renderer: &mut Renderer,
+        viewport: &Viewport,
+        background_color: Color,
+        overlay: &[T],
+    ) -> Vec<u8> {
+        let size = viewport.physical_size();
+
+        let extent = wgpu::Extent3d {
+            width: size.width,
+            height: size.height,
+            depth_or_array_layers: 1,
+        };
+
+        let texture = self.device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("iced_wgpu::window::Compositor offscreen texture"),
+            size: extent,
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: self.format,
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT
+                | wgpu::TextureUsages::COPY_SRC,
+        });
+
+        let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+
+        let mut encoder = self.device.create_command_encoder(
+            &wgpu::CommandEncoderDescriptor {
+                label: Some("iced_wgpu offscreen encoder"),
+            },
+        );
+
+        self.draw(
+            renderer,
+            &mut encoder,
+            &view,
+            viewport,
+            background_color,
+            overlay,
+        );
+
+        let unpadded_bytes_per_row = 4 * size.width;
+        let alignment = wgpu::COPY_BYTES_PER_ROW_ALIGNMENT;
+        let bytes_per_row =
+            (unpadded_bytes_per_row + alignment - 1) / alignment * alignment;
+
+        let buffer = self.device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("iced_wgpu::window::Compositor offscreen buffer"),
+            size: u64::from(bytes_per_row * size.height),
+            usage: wgpu::BufferUsages::MAP_READ | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        encoder.copy_texture_to_buffer(
+            wgpu::ImageCopyTexture {
+                texture: &texture,
+                mip_level: 0,
+                origin: wgpu::Origin3d::ZERO,
+                aspect: wgpu::TextureAspect::All,
+            },
+            wgpu::ImageCopyBuffer {
+                buffer: &buffer,
+                layout: wgpu::ImageDataLayout {
+                    offset: 0,
+                    bytes_per_row: std::num::NonZeroU32::new(bytes_per_row),
+                    rows_per_image: None,
+                },
+            },
+            extent,
+        );
+
+        self.staging_belt.finish();
+
+        {
+            #[cfg(feature = "tracing")]
+            let _span = iced_graphics::tracing::info_span!("submit").entered();
+
+            self.queue.submit(Some(encoder.finish()));
+        }
+
+        self.recall_staging_belt();
+
+        let slice = buffer.slice(..);
+        let mapping = slice.map_async(wgpu::MapMode::Read);
+
+        self.device.poll(wgpu::Maintain::Wait);
+
+        futures::executor::block_on(mapping).expect("Map offscreen buffer");
+
+        let mut pixels =
+            Vec::with_capacity((unpadded_bytes_per_row * size.height) as usize);
+
+        {
+            let data = slice.get_mapped_range();
+
+            for row in data.chunks(bytes_per_row as usize) {
+                pixels
+                    .extend_from_slice(&row[..unpadded_bytes_per_row as usize]);
+            }
+        }
+
+        buffer.unmap();
+
+        if let wgpu::TextureFormat::Bgra8Unorm
+        | wgpu::TextureFormat::Bgra8UnormSrgb = self.format
+        {
+            for pixel in pixels.chunks_exact_mut(4) {
+                pixel.swap(0, 2);
+            }
+        }
+
+        pixels
+    }
+
+    fn draw<T: AsRef<str>>(
+        &mut self,
+        renderer: &mut Renderer,
+        encoder: &mut wgpu::CommandEncoder,
+        view: &wgpu::TextureView,
+        viewport: &Viewport,
+        background_color: Color,
+        overlay: &[T],
+    ) {
+        let _ = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+            label: Some("iced_wgpu::window::Compositor render pass"),
+            color_attachments: &[wgpu::RenderPassColorAttachment {
+                view,
+                resolve_target: None,
+                ops: wgpu::Operations {
+                    load: wgpu::LoadOp::Clear({
+                        let [r, g, b, a] =
+                            background_color.into_linear_premultiplied();
+
+                        wgpu::Color {
+                            r: f64::from(r),
+                            g: f64::from(g),
+                            b: f64::from(b),
+                            a: f64::from(a),
+                        }
+                    }),
+                    store: true,
+                },
+            }],
+            depth_stencil_attachment: None,
+        });
+
+        let device = &mut self.device;
+        let staging_belt = &mut self.staging_belt;
+
+        renderer.with_primitives(|backend, primitives| {
+            backend.present(
+                device,
+                staging_belt,
+                encoder,
+                view,
+                primitives,
+                viewport,
+                overlay,
+            );
+        });
+    }
+
+    fn recall_staging_belt(&mut self) {
+        self.local_pool
+            .spawner()
+            .spawn(self.staging_belt.recall())
+            .expect("Recall staging belt");
+
+        self.local_pool.run_until_stalled();
     }
 }
 
@@ -115,6 +351,14 @@ impl iced_graphics::window::Compositor for Compositor {
         }
     }
 
+    fn set_present_mode(&mut self, present_mode: PresentMode) {
+        self.settings.present_mode = match present_mode {
+            PresentMode::Fifo => wgpu::PresentMode::Fifo,
+            PresentMode::Mailbox => wgpu::PresentMode::Mailbox,
+            PresentMode::Immediate => wgpu::PresentMode::Immediate,
+        };
+    }
+
     fn configure_surface(
         &mut self,
         surface: &mut Self::Surface,
@@ -153,56 +397,28 @@ impl iced_graphics::window::Compositor for Compositor {
                     .texture
                     .create_view(&wgpu::TextureViewDescriptor::default());
 
-                let _ =
-                    encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
-                        label: Some(
-                            "iced_wgpu::window::Compositor render pass",
-                        ),
-                        color_attachments: &[wgpu::RenderPassColorAttachment {
-                            view,
-                            resolve_target: None,
-                            ops: wgpu::Operations {
-                                load: wgpu::LoadOp::Clear({
-                                    let [r, g, b, a] =
-                                        background_color.into_linear();
-
-                                    wgpu::Color {
-                                        r: f64::from(r),
-                                        g: f64::from(g),
-                                        b: f64::from(b),
-                                        a: f64::from(a),
-                                    }
-                                }),
-                                store: true,
-                            },
-                        }],
-                        depth_stencil_attachment: None,
-                    });
-
-                renderer.with_primitives(|backend, primitives| {
-                    backend.present(
-                        &mut self.device,
-                        &mut self.staging_belt,
-                        &mut encoder,
-                        view,
-                        primitives,
-                        viewport,
-                        overlay,
-                    );
-                });
+                self.draw(
+                    renderer,
+                    &mut encoder,
+                    view,
+                    viewport,
+                    background_color,
+                    overlay,
+                );
 
                 // Submit work
                 self.staging_belt.finish();
-                self.queue.submit(Some(encoder.finish()));
-                frame.present();
 
-                // Recall staging buffers
-                self.local_pool
-                    .spawner()
-                    .spawn(self.staging_belt.recall())
-                    .expect("Recall staging belt");
+                {
+                    #[cfg(feature = "tracing")]
+                    let _span =
+                        iced_graphics::tracing::info_span!("submit").entered();
+
+                    self.queue.submit(Some(encoder.finish()));
+                    frame.present();
+                }
 
-                self.local_pool.run_until_stalled();
+                self.recall_staging_belt();
 
                 Ok(())
             }
@@ -222,4 +438,15 @@ impl iced_graphics::window::Compositor for Compositor {
             },
         }
     }
+
+    fn screenshot<T: AsRef<str>>(
+        &mut self,
+        renderer: &mut Self::Renderer,
+        _surface: &mut Self::Surface,
+        viewport: &Viewport,
+        background_color: Color,
+        overlay: &[T],
+    ) -> Vec<u8> {
+        self.render_offscreen(renderer, viewport, background_color, overlay)
+    }
 }
@@ -0,0 +1,361 @@
+//! Expose the user interface of an application to assistive technologies,
+//! like screen readers, through AccessKit.
+//!
+//! The accessibility tree of a [`UserInterface`] is handed to the AccessKit
+//! adapter of the platform, and the actions requested by assistive
+//! technologies are turned into events for the [`UserInterface`].
+//!
+//! Linux, Windows, and macOS are supported.
+use crate::accessibility;
+use crate::conversion;
+use crate::{Event, UserInterface};
+
+use std::sync::atomic::{self, AtomicBool};
+use std::sync::{mpsc, Arc};
+use winit::event::WindowEvent;
+use winit::window::Window;
+
+/// The AccessKit adapter of a window.
+///
+/// The window is woken up every time an assistive technology asks for the
+/// accessibility tree or requests an action.
+#[allow(missing_debug_implementations)]
+pub struct AccessibilityAdapter {
+    platform: platform::Adapter,
+    tree: accessibility::Tree,
+    requests: mpsc::Receiver<accesskit::ActionRequest>,
+    is_requested: Arc<AtomicBool>,
+}
+
+impl AccessibilityAdapter {
+    /// Creates a new [`AccessibilityAdapter`] for the given window.
+    pub fn new(window: &Arc<Window>) -> Self {
+        let (sender, requests) = mpsc::channel();
+        let is_requested = Arc::new(AtomicBool::new(false));
+
+        let platform = platform::Adapter::new(
+            window,
+            Activation {
+                window: window.clone(),
+                is_requested: is_requested.clone(),
+            },
+            Actions {
+                window: window.clone(),
+                sender,
+            },
+        );
+
+        Self {
+            platform,
+            tree: accessibility::Tree::new(),
+            requests,
+            is_requested,
+        }
+    }
+
+    /// Returns true if an assistive technology is waiting for the
+    /// accessibility tree.
+    pub fn is_requested(&self) -> bool {
+        self.is_requested.load(atomic::Ordering::Relaxed)
+    }
+
+    /// Hands the accessibility tree of the [`UserInterface`] to the platform,
+    /// if an assistive technology is active.
+    pub fn update<Message, Renderer>(
+        &mut self,
+        user_interface: &mut UserInterface<'_, Message, Renderer>,
+        title: &str,
+        scale_factor: f64,
+    ) where
+        Renderer: crate::Renderer,
+    {
+        self.is_requested.store(false, atomic::Ordering::Relaxed);
+
+        let tree = &mut self.tree;
+
+        self.platform.update_if_active(|| {
+            *tree = user_interface.accessibility();
+
+            conversion::tree_update(tree, title, scale_factor)
+        });
+    }
+
+    /// Keeps the bounds and the focus of the window up to date with the
+    /// provided window event.
+    pub fn process_event(&mut self, window: &Window, event: &WindowEvent<'_>) {
+        self.platform.process_event(window, event);
+    }
+
+    /// Returns the events requested by assistive technologies since the last
+    /// call.
+    pub fn events(&mut self) -> impl Iterator<Item = Event> + '_ {
+        let tree = &self.tree;
+
+        self.requests.try_iter().filter_map(move |request| {
+            conversion::accessibility_request(tree, &request)
+        })
+    }
+}
+
+struct Activation {
+    window: Arc<Window>,
+    is_requested: Arc<AtomicBool>,
+}
+
+impl accesskit::ActivationHandler for Activation {
+    fn request_initial_tree(&mut self) -> Option<accesskit::TreeUpdate> {
+        // The tree is built by the event loop, which is woken up to do so
+        self.is_requested.store(true, atomic::Ordering::Relaxed);
+        self.window.request_redraw();
+
+        None
+    }
+}
+
+struct Actions {
+    window: Arc<Window>,
+    sender: mpsc::Sender<accesskit::ActionRequest>,
+}
+
+impl accesskit::ActionHandler for Actions {
+    fn do_action(&mut self, request: accesskit::ActionRequest) {
+        if self.sender.send(request).is_ok() {
+            self.window.request_redraw();
+        }
+    }
+}
+
+#[cfg(target_os = "linux")]
+mod platform {
+    use super::{Actions, Activation};
+
+    use accesskit::Rect;
+    use winit::event::WindowEvent;
+    use winit::window::Window;
+
+    pub struct Adapter {
+        adapter: accesskit_unix::Adapter,
+    }
+
+    impl Adapter {
+        pub fn new(
+            window: &Window,
+            activation: Activation,
+            actions: Actions,
+        ) -> Self {
+            let mut adapter =
+                accesskit_unix::Adapter::new(activation, actions, Deactivation);
+
+            let (outer, inner) = bounds(window);
+            adapter.set_root_window_bounds(outer, inner);
+
+            Self { adapter }
+        }
+
+        pub fn update_if_active(
+            &mut self,
+            updater: impl FnOnce() -> accesskit::TreeUpdate,
+        ) {
+            self.adapter.update_if_active(updater);
+        }
+
+        pub fn process_event(
+            &mut self,
+            window: &Window,
+            event: &WindowEvent<'_>,
+        ) {
+            match event {
+                WindowEvent::Moved(_) | WindowEvent::Resized(_) => {
+                    let (outer, inner) = bounds(window);
+
+                    self.adapter.set_root_window_bounds(outer, inner);
+                }
+                WindowEvent::Focused(is_focused) => {
+                    self.adapter.update_window_focus_state(*is_focused);
+                }
+                _ => {}
+            }
+        }
+    }
+
+    struct Deactivation;
+
+    impl accesskit::DeactivationHandler for Deactivation {
+        fn deactivate_accessibility(&mut self) {}
+    }
+
+    /// Returns the outer and the inner bounds of the window, in physical
+    /// pixels.
+    fn bounds(window: &Window) -> (Rect, Rect) {
+        let outer_position = window.outer_position().unwrap_or_default();
+        let inner_position = window.inner_position().unwrap_or_default();
+        let outer_size = window.outer_size();
+        let inner_size = window.inner_size();
+
+        let rect = |x: i32, y: i32, width: u32, height: u32| Rect {
+            x0: f64::from(x),
+            y0: f64::from(y),
+            x1: f64::from(x) + f64::from(width),
+            y1: f64::from(y) + f64::from(height),
+        };
+
+        (
+            rect(
+                outer_position.x,
+                outer_position.y,
+                outer_size.width,
+                outer_size.height,
+            ),
+            rect(
+                inner_position.x,
+                inner_position.y,
+                inner_size.width,
+                inner_size.height,
+            ),
+        )
+    }
+}
+
+#[cfg(target_os = "windows")]
+mod platform {
+    use super::{Actions, Activation};
+
+    use winit::event::WindowEvent;
+    use winit::platform::windows::WindowExtWindows;
+    use winit::window::Window;
+
+    pub struct Adapter {
+        adapter: accesskit_windows::SubclassingAdapter,
+    }
+
+    impl Adapter {
+        pub fn new(
+            window: &Window,
+            activation: Activation,
+            actions: Actions,
+        ) -> Self {
+            let adapter = accesskit_windows::SubclassingAdapter::new(
+                accesskit_windows::HWND(window.hwnd() as _),
+                activation,
+                actions,
+            );
+
+            Self { adapter }
+        }
+
+        pub fn update_if_active(
+            &mut self,
+            updater: impl FnOnce() -> accesskit::TreeUpdate,
+        ) {
+            if let Some(events) = self.adapter.update_if_active(updater) {
+                events.raise();
+            }
+        }
+
+        pub fn process_event(
+            &mut self,
+            _window: &Window,
+            _event: &WindowEvent<'_>,
+        ) {
+        }
+    }
+}
+
+#[cfg(target_os = "macos")]
+#[allow(unsafe_code)]
+mod platform {
+    use super::{Actions, Activation};
+
+    use winit::event::WindowEvent;
+    use winit::platform::macos::WindowExtMacOS;
+    use winit::window::Window;
+
+    pub struct Adapter {
+        adapter: accesskit_macos::SubclassingAdapter,
+    }
+
+    impl Adapter {
+        pub fn new(
+            window: &Window,
+            activation: Activation,
+            actions: Actions,
+        ) -> Self {
+            // SAFETY: The view of the window is valid while the window is
+            // alive, and the adapter is dropped before the window.
+            let adapter = unsafe {
+                accesskit_macos::SubclassingAdapter::new(
+                    window.ns_view(),
+                    activation,
+                    actions,
+                )
+            };
+
+            Self { adapter }
+        }
+
+        pub fn update_if_active(
+            &mut self,
+            updater: impl FnOnce() -> accesskit::TreeUpdate,
+        ) {
+            if let Some(events) = self.adapter.update_if_active(updater) {
+                events.raise();
+            }
+        }
+
+        pub fn process_event(
+            &mut self,
+            _window: &Window,
+            event: &WindowEvent<'_>,
+        ) {
+            if let WindowEvent::Focused(is_focused) = event {
+                if let Some(events) =
+                    self.adapter.update_view_focus_state(*is_focused)
+                {
+                    events.raise();
+                }
+            }
+        }
+    }
+}
+
+#[cfg(not(any(
+    target_os = "linux",
+    target_os = "windows",
+    target_os = "macos"
+)))]
+mod platform {
+    use super::{Actions, Activation};
+
+    use winit::event::WindowEvent;
+    use winit::window::Window;
+
+    pub struct Adapter;
+
+    impl Adapter {
+        pub fn new(
+            _window: &Window,
+            _activation: Activation,
+            _actions: Actions,
+        ) -> Self {
+            log::warn!(
+                "assistive technologies are only supported on Linux, \
+                Windows, and macOS"
+            );
+
+            Self
+        }
+
+        pub fn update_if_active(
+            &mut self,
+            _updater: impl FnOnce() -> accesskit::TreeUpdate,
+        ) {
+        }
+
+        pub fn process_event(
+            &mut self,
+            _window: &Window,
+            _event: &WindowEvent<'_>,
+        ) {
+        }
+    }
+}
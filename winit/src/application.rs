@@ -1,23 +1,41 @@
 //! Create interactive, native cross-platform applications.
+mod drag_resize;
+mod frame_pacing;
+mod input;
 mod state;
+mod window_state;
 
+pub use drag_resize::DragResize;
+pub use frame_pacing::FramePacing;
+pub use input::Input;
 pub use state::State;
+pub use window_state::{Appearance, WindowState};
 
 use crate::clipboard::{self, Clipboard};
 use crate::conversion;
 use crate::mouse;
 use crate::{
-    Color, Command, Debug, Error, Executor, Mode, Proxy, Runtime, Settings,
-    Size, Subscription,
+    Color, Command, Debug, Direction, Error, Executor, Mode, NativeMenu, Proxy,
+    Rotation, Runtime, Settings, Size, Subscription, Theme,
 };
 
 use iced_futures::futures;
 use iced_futures::futures::channel::mpsc;
+use iced_futures::BoxFuture;
 use iced_graphics::window;
+use iced_native::event;
+use iced_native::keyboard::{self, Shortcuts};
+use iced_native::menu_bar::MenuBar;
 use iced_native::program::Program;
+use iced_native::renderer;
+use iced_native::text;
+use iced_native::widget::{self, Column, Directional};
+use iced_native::window::{RedrawRequest, Screenshot};
 use iced_native::{Cache, UserInterface};
 
 use std::mem::ManuallyDrop;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 /// An interactive, native cross-platform application.
 ///
@@ -63,6 +81,28 @@ pub trait Application: Program {
         Subscription::none()
     }
 
+    /// Returns the keyboard [`Shortcuts`] of the [`Application`].
+    ///
+    /// A [`Shortcut`] is triggered when its key is pressed and no widget
+    /// captures the event, producing the message bound to it.
+    ///
+    /// By default, it returns no shortcuts.
+    ///
+    /// [`Shortcut`]: keyboard::Shortcut
+    fn shortcuts(&self) -> Shortcuts<Self::Message> {
+        Shortcuts::new()
+    }
+
+    /// Returns the [`MenuBar`] of the [`Application`].
+    ///
+    /// The shortcuts of its items are added to the [`Shortcuts`] of the
+    /// [`Application`].
+    ///
+    /// By default, it returns an empty menu bar.
+    fn menu_bar(&self) -> MenuBar<Self::Message> {
+        MenuBar::new()
+    }
+
     /// Returns the current [`Application`] mode.
     ///
     /// The runtime will automatically transition your application if a new mode
@@ -73,11 +113,35 @@ pub trait Application: Program {
         Mode::Windowed
     }
 
+    /// Returns the current [`Theme`] of the [`Application`].
+    ///
+    /// The [`Theme`] styles every built-in widget that does not have a
+    /// custom style sheet.
+    ///
+    /// By default, it returns [`Theme::Light`].
+    fn theme(&self) -> Theme {
+        Theme::default()
+    }
+
+    /// Returns the duration of the cross-fade played when the [`Theme`] of
+    /// the [`Application`] changes.
+    ///
+    /// By default, it returns `None` and the [`Theme`] changes instantly.
+    fn theme_transition(&self) -> Option<Duration> {
+        None
+    }
+
     /// Returns the background [`Color`] of the [`Application`].
     ///
-    /// By default, it returns [`Color::WHITE`].
+    /// The desktop shows through a background with some transparency, like
+    /// [`Color::TRANSPARENT`], as long as the window is `transparent` in its
+    /// [`settings::Window`].
+    ///
+    /// By default, it returns the background of the current [`Theme`].
+    ///
+    /// [`settings::Window`]: crate::settings::Window
     fn background_color(&self) -> Color {
-        Color::WHITE
+        self.theme().palette().background
     }
 
     /// Returns the scale factor of the [`Application`].
@@ -93,12 +157,49 @@ pub trait Application: Program {
         1.0
     }
 
+    /// Returns the [`Direction`] in which the content of the [`Application`]
+    /// flows.
+    ///
+    /// It can be used to mirror the user interface when localizing it to a
+    /// right-to-left language, like Arabic or Hebrew. Parts of the user
+    /// interface can flow in a different direction by using a
+    /// [`Directional`] widget.
+    ///
+    /// [`Directional`]: crate::widget::Directional
+    ///
+    /// By default, it returns [`Direction::LeftToRight`].
+    fn direction(&self) -> Direction {
+        Direction::LeftToRight
+    }
+
+    /// Returns the [`Rotation`] of the contents of the [`Application`].
+    ///
+    /// It can be used to render correctly on displays mounted in a rotated
+    /// orientation, like the panels of embedded devices and kiosks. The
+    /// positions of mouse, touch and pen events are rotated accordingly.
+    ///
+    /// By default, it returns [`Rotation::None`].
+    fn rotation(&self) -> Rotation {
+        Rotation::None
+    }
+
     /// Returns whether the [`Application`] should be terminated.
     ///
     /// By default, it returns `false`.
     fn should_exit(&self) -> bool {
         false
     }
+
+    /// Returns a future that runs to completion right before the
+    /// [`Application`] exits.
+    ///
+    /// It can be used to shut down gracefully; like saving some state to
+    /// disk or closing a connection.
+    ///
+    /// By default, it returns `None`.
+    fn shutdown(&mut self) -> Option<BoxFuture<()>> {
+        None
+    }
 }
 
 /// Runs an [`Application`] with an executor, compositor, and the provided
@@ -109,6 +210,7 @@ pub fn run<A, E, C>(
 ) -> Result<(), Error>
 where
     A: Application + 'static,
+    A::Renderer: text::Renderer,
     E: Executor + 'static,
     C: window::Compositor<Renderer = A::Renderer> + 'static,
 {
@@ -125,12 +227,13 @@ where
 
     let mut runtime = {
         let proxy = Proxy::new(event_loop.create_proxy());
-        let executor = E::new().map_err(Error::ExecutorCreationFailed)?;
+        let executor = E::with_settings(settings.executor)
+            .map_err(Error::ExecutorCreationFailed)?;
 
         Runtime::new(executor, proxy)
     };
 
-    let (application, init_command) = {
+    let (mut application, init_command) = {
         let flags = settings.flags;
 
         runtime.enter(|| A::new(flags))
@@ -150,19 +253,34 @@ where
         .map_err(Error::WindowCreationFailed)?;
 
     let mut clipboard = Clipboard::connect(&window);
+    let mut operations = Vec::new();
+    let mut screenshots = Vec::new();
+    let mut frame_pacing = FramePacing::new(settings.frame_rate_cap);
 
-    run_command(
+    let close_requested = run_command(
         init_command,
         &mut runtime,
         &mut clipboard,
         &mut proxy,
+        &mut operations,
+        &mut screenshots,
+        &mut frame_pacing,
         &window,
+        &mut DragResize::new(),
     );
+
+    if close_requested {
+        run_shutdown(&mut application, &runtime);
+
+        return Ok(());
+    }
+
     runtime.track(subscription);
 
     let (compositor, renderer) = C::new(compositor_settings, Some(&window))?;
 
     let (mut sender, receiver) = mpsc::unbounded();
+    let (control_sender, control_receiver) = std::sync::mpsc::channel();
 
     let mut instance = Box::pin(run_instance::<A, E, C>(
         application,
@@ -171,10 +289,15 @@ where
         runtime,
         clipboard,
         proxy,
+        operations,
+        screenshots,
+        frame_pacing,
         debug,
         receiver,
-        window,
+        control_sender,
+        Arc::new(window),
         settings.exit_on_close_request,
+        settings.keyboard_zoom,
     ));
 
     let mut context = task::Context::from_waker(task::noop_waker_ref());
@@ -206,10 +329,18 @@ where
 
             let poll = instance.as_mut().poll(&mut context);
 
-            *control_flow = match poll {
-                task::Poll::Pending => ControlFlow::Wait,
-                task::Poll::Ready(_) => ControlFlow::Exit,
-            };
+            match poll {
+                task::Poll::Pending => {
+                    // The instance reports how long it can wait for new
+                    // events, as widgets may be animating
+                    while let Ok(flow) = control_receiver.try_recv() {
+                        *control_flow = flow;
+                    }
+                }
+                task::Poll::Ready(_) => {
+                    *control_flow = ControlFlow::Exit;
+                }
+            }
         }
     });
 
@@ -223,19 +354,25 @@ async fn run_instance<A, E, C>(
     mut runtime: Runtime<E, Proxy<A::Message>, A::Message>,
     mut clipboard: Clipboard,
     mut proxy: winit::event_loop::EventLoopProxy<A::Message>,
+    mut operations: Vec<Box<dyn widget::Operation<A::Message>>>,
+    mut screenshots: Vec<Box<dyn Fn(Screenshot) -> A::Message>>,
+    mut frame_pacing: FramePacing,
     mut debug: Debug,
     mut receiver: mpsc::UnboundedReceiver<winit::event::Event<'_, A::Message>>,
-    window: winit::window::Window,
+    control_sender: std::sync::mpsc::Sender<winit::event_loop::ControlFlow>,
+    window: Arc<winit::window::Window>,
     exit_on_close_request: bool,
+    keyboard_zoom: bool,
 ) where
     A: Application + 'static,
+    A::Renderer: text::Renderer,
     E: Executor + 'static,
     C: window::Compositor<Renderer = A::Renderer> + 'static,
 {
     use iced_futures::futures::stream::StreamExt;
     use winit::event;
 
-    let mut surface = compositor.create_surface(&window);
+    let mut surface = compositor.create_surface(window.as_ref());
 
     let mut state = State::new(&application, &window);
     let mut viewport_version = state.viewport_version();
@@ -248,88 +385,211 @@ async fn run_instance<A, E, C>(
         physical_size.height,
     );
 
+    let mut native_menu =
+        NativeMenu::new(application.menu_bar(), &window, &proxy);
+
+    let mut shortcuts = application.shortcuts().extend(native_menu.shortcuts());
+    report_shortcut_conflicts(&shortcuts);
+
     let mut user_interface = ManuallyDrop::new(build_user_interface(
         &mut application,
+        &mut native_menu,
         Cache::default(),
         &mut renderer,
         state.logical_size(),
         &mut debug,
     ));
 
-    let mut mouse_interaction = mouse::Interaction::default();
-    let mut events = Vec::new();
+    run_operations(&mut user_interface, &mut operations, &mut proxy);
+
+    #[cfg(feature = "accesskit")]
+    let mut accessibility = crate::AccessibilityAdapter::new(&window);
+
+    let mut input = Input::new(&state);
     let mut messages = Vec::new();
+    let mut redraw_request = None;
+    let mut last_frame = None;
+    let mut is_suspended = false;
 
     debug.startup_finished();
 
     while let Some(event) = receiver.next().await {
         match event {
             event::Event::MainEventsCleared => {
-                if events.is_empty() && messages.is_empty() {
-                    continue;
+                let now = Instant::now();
+
+                let is_redraw_due = match redraw_request {
+                    Some(RedrawRequest::NextFrame) => true,
+                    Some(RedrawRequest::At(at)) => at <= now,
+                    None => false,
+                };
+
+                if is_redraw_due {
+                    redraw_request = None;
+
+                    input.push(iced_native::Event::Window(
+                        iced_native::window::Event::RedrawRequested(now),
+                    ));
                 }
 
-                debug.event_processing_started();
+                input.recognize_gestures(now);
 
-                let statuses = user_interface.update(
-                    &events,
-                    state.cursor_position(),
+                #[cfg(feature = "accesskit")]
+                {
+                    for event in accessibility.events() {
+                        input.push(event);
+                    }
+
+                    if accessibility.is_requested() {
+                        accessibility.update(
+                            &mut user_interface,
+                            state.title(),
+                            state.scale_factor(),
+                        );
+                    }
+                }
+
+                if input.is_empty() && messages.is_empty() {
+                    control_sender
+                        .send(control_flow(redraw_request))
+                        .expect("Send control flow");
+
+                    continue;
+                }
+
+                let (events, request) = input.process(
+                    &mut user_interface,
+                    &mut state,
+                    &window,
                     &mut renderer,
                     &mut clipboard,
+                    &shortcuts,
+                    keyboard_zoom,
                     &mut messages,
+                    &mut operations,
+                    &mut debug,
                 );
 
-                debug.event_processing_finished();
+                for request in [request, state.redraw_request()] {
+                    redraw_request = match (redraw_request, request) {
+                        (Some(current), Some(new)) => Some(current.min(new)),
+                        (current, new) => current.or(new),
+                    };
+                }
+
+                control_sender
+                    .send(control_flow(redraw_request))
+                    .expect("Send control flow");
 
-                for event in events.drain(..).zip(statuses.into_iter()) {
-                    runtime.broadcast(event);
+                run_operations(
+                    &mut user_interface,
+                    &mut operations,
+                    &mut proxy,
+                );
+
+                for (event, status) in events {
+                    runtime.broadcast((
+                        iced_native::window::Id::MAIN,
+                        event,
+                        status,
+                    ));
                 }
 
-                if !messages.is_empty() {
+                if !messages.is_empty() || user_interface.is_outdated() {
                     let cache =
                         ManuallyDrop::into_inner(user_interface).into_cache();
 
                     // Update application
-                    update(
+                    let close_requested = update(
                         &mut application,
                         &mut runtime,
                         &mut clipboard,
                         &mut proxy,
+                        &mut operations,
+                        &mut screenshots,
+                        &mut frame_pacing,
                         &mut debug,
                         &mut messages,
                         &window,
+                        input.drag_resize(),
                     );
 
                     // Update window
                     state.synchronize(&application, &window);
 
-                    let should_exit = application.should_exit();
+                    native_menu.update(application.menu_bar());
+                    shortcuts =
+                        application.shortcuts().extend(native_menu.shortcuts());
+
+                    let should_exit =
+                        close_requested || application.should_exit();
 
                     user_interface = ManuallyDrop::new(build_user_interface(
                         &mut application,
+                        &mut native_menu,
                         cache,
                         &mut renderer,
                         state.logical_size(),
                         &mut debug,
                     ));
 
+                    run_operations(
+                        &mut user_interface,
+                        &mut operations,
+                        &mut proxy,
+                    );
+
+                    // Let the new widgets request redraws, so animations
+                    // started by the application get their first frame
+                    let _ = user_interface.update(
+                        &[iced_native::Event::Window(
+                            iced_native::window::Event::RedrawRequested(
+                                Instant::now(),
+                            ),
+                        )],
+                        state.cursor_position(),
+                        &mut renderer,
+                        &mut clipboard,
+                        &mut messages,
+                    );
+
+                    for request in [
+                        state.redraw_request(),
+                        user_interface.redraw_request(),
+                    ] {
+                        redraw_request = match (redraw_request, request) {
+                            (Some(current), Some(new)) => {
+                                Some(current.min(new))
+                            }
+                            (current, new) => current.or(new),
+                        };
+                    }
+
+                    control_sender
+                        .send(control_flow(redraw_request))
+                        .expect("Send control flow");
+
                     if should_exit {
                         break;
                     }
                 }
 
+                #[cfg(feature = "accesskit")]
+                accessibility.update(
+                    &mut user_interface,
+                    state.title(),
+                    state.scale_factor(),
+                );
+
                 debug.draw_started();
-                let new_mouse_interaction =
-                    user_interface.draw(&mut renderer, state.cursor_position());
+                let new_mouse_interaction = user_interface.draw(
+                    &mut renderer,
+                    &renderer::Style::from(state.theme()),
+                    state.cursor_position(),
+                );
                 debug.draw_finished();
 
-                if new_mouse_interaction != mouse_interaction {
-                    window.set_cursor_icon(conversion::mouse_interaction(
-                        new_mouse_interaction,
-                    ));
-
-                    mouse_interaction = new_mouse_interaction;
-                }
+                input.set_mouse_interaction(&window, new_mouse_interaction);
 
                 window.request_redraw();
             }
@@ -337,7 +597,7 @@ async fn run_instance<A, E, C>(
                 event::MacOS::ReceivedUrl(url),
             )) => {
                 use iced_native::event;
-                events.push(iced_native::Event::PlatformSpecific(
+                input.push(iced_native::Event::PlatformSpecific(
                     event::PlatformSpecific::MacOS(event::MacOS::ReceivedUrl(
                         url,
                     )),
@@ -346,13 +606,66 @@ async fn run_instance<A, E, C>(
             event::Event::UserEvent(message) => {
                 messages.push(message);
             }
+            event::Event::Suspended | event::Event::Resumed => {
+                let was_suspended = is_suspended;
+                is_suspended = matches!(event, event::Event::Suspended);
+
+                // Some platforms, like Android, destroy the native window
+                // while the application is suspended, so the surface must
+                // be created again when resumed
+                if was_suspended && !is_suspended {
+                    let physical_size = state.physical_size();
+
+                    surface = compositor.create_surface(window.as_ref());
+                    compositor.configure_surface(
+                        &mut surface,
+                        physical_size.width,
+                        physical_size.height,
+                    );
+
+                    window.request_redraw();
+                }
+
+                input.push(iced_native::Event::Window(
+                    iced_native::window::Event::Occluded(is_suspended),
+                ));
+            }
             event::Event::RedrawRequested(_) => {
                 let physical_size = state.physical_size();
 
-                if physical_size.width == 0 || physical_size.height == 0 {
+                if is_suspended
+                    || physical_size.width == 0
+                    || physical_size.height == 0
+                {
+                    continue;
+                }
+
+                if let Some(next_frame) =
+                    frame_pacing.delay(last_frame, Instant::now())
+                {
+                    redraw_request = Some(match redraw_request {
+                        Some(current) => {
+                            current.min(RedrawRequest::At(next_frame))
+                        }
+                        None => RedrawRequest::At(next_frame),
+                    });
+
+                    control_sender
+                        .send(control_flow(redraw_request))
+                        .expect("Send control flow");
+
                     continue;
                 }
 
+                if let Some(present_mode) = frame_pacing.take_present_mode() {
+                    compositor.set_present_mode(present_mode);
+                    compositor.configure_surface(
+                        &mut surface,
+                        physical_size.width,
+                        physical_size.height,
+                    );
+                }
+
                 debug.render_started();
                 let current_viewport_version = state.viewport_version();
 
@@ -367,16 +680,13 @@ async fn run_instance<A, E, C>(
                     debug.layout_finished();
 
                     debug.draw_started();
-                    let new_mouse_interaction = user_interface
-                        .draw(&mut renderer, state.cursor_position());
-
-                    if new_mouse_interaction != mouse_interaction {
-                        window.set_cursor_icon(conversion::mouse_interaction(
-                            new_mouse_interaction,
-                        ));
+                    let new_mouse_interaction = user_interface.draw(
+                        &mut renderer,
+                        &renderer::Style::from(state.theme()),
+                        state.cursor_position(),
+                    );
 
-                        mouse_interaction = new_mouse_interaction;
-                    }
+                    input.set_mouse_interaction(&window, new_mouse_interaction);
                     debug.draw_finished();
 
                     compositor.configure_surface(
@@ -388,6 +698,26 @@ async fn run_instance<A, E, C>(
                     viewport_version = current_viewport_version;
                 }
 
+                if !screenshots.is_empty() {
+                    let screenshot = Screenshot::new(
+                        compositor.screenshot(
+                            &mut renderer,
+                            &mut surface,
+                            state.viewport(),
+                            state.background_color(),
+                            &debug.overlay(),
+                        ),
+                        state.viewport().physical_size(),
+                    );
+
+                    for tag in screenshots.drain(..) {
+                        proxy
+                            .send_event(tag(screenshot.clone()))
+                            .expect("Send message to event loop");
+                    }
+                }
+
+                debug.present_started();
                 match compositor.present(
                     &mut renderer,
                     &mut surface,
@@ -396,10 +726,29 @@ async fn run_instance<A, E, C>(
                     &debug.overlay(),
                 ) {
                     Ok(()) => {
+                        debug.present_finished();
                         debug.render_finished();
 
-                        // TODO: Handle animations!
-                        // Maybe we can use `ControlFlow::WaitUntil` for this.
+                        let now = Instant::now();
+                        last_frame = Some(now);
+
+                        if frame_pacing
+                            .is_continuous(iced_native::window::Id::MAIN)
+                        {
+                            redraw_request = Some(RedrawRequest::NextFrame);
+
+                            control_sender
+                                .send(control_flow(redraw_request))
+                                .expect("Send control flow");
+                        }
+
+                        runtime.broadcast((
+                            iced_native::window::Id::MAIN,
+                            iced_native::Event::Window(
+                                iced_native::window::Event::FramePresented(now),
+                            ),
+                            iced_native::event::Status::Ignored,
+                        ));
                     }
                     Err(error) => match error {
                         // This is an unrecoverable error.
@@ -407,6 +756,7 @@ async fn run_instance<A, E, C>(
                             panic!("{:?}", error);
                         }
                         _ => {
+                            debug.present_finished();
                             debug.render_finished();
 
                             // Try rendering again next frame.
@@ -425,15 +775,21 @@ async fn run_instance<A, E, C>(
                     break;
                 }
 
-                state.update(&window, &window_event, &mut debug);
+                #[cfg(feature = "accesskit")]
+                accessibility.process_event(&window, &window_event);
 
-                if let Some(event) = conversion::window_event(
+                input.handle_window_event(
+                    &mut state,
+                    &window,
                     &window_event,
-                    state.scale_factor(),
-                    state.modifiers(),
-                ) {
-                    events.push(event);
-                }
+                    &mut debug,
+                );
+            }
+            event::Event::DeviceEvent {
+                event: winit::event::DeviceEvent::MouseMotion { delta },
+                ..
+            } => {
+                input.handle_mouse_motion(&state, delta);
             }
             _ => {}
         }
@@ -441,6 +797,24 @@ async fn run_instance<A, E, C>(
 
     // Manually drop the user interface
     drop(ManuallyDrop::into_inner(user_interface));
+
+    run_shutdown(&mut application, &runtime);
+}
+
+/// Returns the [`ControlFlow`] of the event loop needed to honor the given
+/// [`RedrawRequest`].
+///
+/// [`ControlFlow`]: winit::event_loop::ControlFlow
+pub fn control_flow(
+    redraw_request: Option<RedrawRequest>,
+) -> winit::event_loop::ControlFlow {
+    use winit::event_loop::ControlFlow;
+
+    match redraw_request {
+        Some(RedrawRequest::NextFrame) => ControlFlow::Poll,
+        Some(RedrawRequest::At(at)) => ControlFlow::WaitUntil(at),
+        None => ControlFlow::Wait,
+    }
 }
 
 /// Returns true if the provided event should cause an [`Application`] to
@@ -469,15 +843,31 @@ pub fn requests_exit(
 
 /// Builds a [`UserInterface`] for the provided [`Application`], logging
 /// [`struct@Debug`] information accordingly.
+///
+/// The [`NativeMenu`] is drawn above the view of the [`Application`] when the
+/// operating system cannot show it.
 pub fn build_user_interface<'a, A: Application>(
     application: &'a mut A,
+    native_menu: &'a mut NativeMenu<A::Message>,
     cache: Cache,
     renderer: &mut A::Renderer,
     size: Size,
     debug: &mut Debug,
-) -> UserInterface<'a, A::Message, A::Renderer> {
+) -> UserInterface<'a, A::Message, A::Renderer>
+where
+    A::Message: 'static,
+    A::Renderer: text::Renderer,
+{
+    let direction = application.direction();
+
     debug.view_started();
-    let view = application.view();
+    let view = match native_menu.view() {
+        Some(menu_bar) => {
+            Column::new().push(menu_bar).push(application.view()).into()
+        }
+        None => application.view(),
+    };
+    let view = Directional::new(direction, view);
     debug.view_finished();
 
     debug.layout_started();
@@ -489,15 +879,24 @@ pub fn build_user_interface<'a, A: Application>(
 
 /// Updates an [`Application`] by feeding it the provided messages, spawning any
 /// resulting [`Command`], and tracking its [`Subscription`].
+///
+/// Returns `true` if any [`Command`] requested the window to close.
+#[allow(clippy::too_many_arguments)]
 pub fn update<A: Application, E: Executor>(
     application: &mut A,
     runtime: &mut Runtime<E, Proxy<A::Message>, A::Message>,
     clipboard: &mut Clipboard,
     proxy: &mut winit::event_loop::EventLoopProxy<A::Message>,
+    operations: &mut Vec<Box<dyn widget::Operation<A::Message>>>,
+    screenshots: &mut Vec<Box<dyn Fn(Screenshot) -> A::Message>>,
+    frame_pacing: &mut FramePacing,
     debug: &mut Debug,
     messages: &mut Vec<A::Message>,
     window: &winit::window::Window,
-) {
+    drag_resize: &mut DragResize,
+) -> bool {
+    let mut close_requested = false;
+
     for message in messages.drain(..) {
         debug.log_message(&message);
 
@@ -505,55 +904,731 @@ pub fn update<A: Application, E: Executor>(
         let command = runtime.enter(|| application.update(message));
         debug.update_finished();
 
-        run_command(command, runtime, clipboard, proxy, window);
+        close_requested |= run_command(
+            command,
+            runtime,
+            clipboard,
+            proxy,
+            operations,
+            screenshots,
+            frame_pacing,
+            window,
+            drag_resize,
+        );
     }
 
     let subscription = application.subscription();
     runtime.track(subscription);
+
+    close_requested
+}
+
+/// Runs the [`Application::shutdown`] hook to completion.
+pub fn run_shutdown<A: Application, E: Executor>(
+    application: &mut A,
+    runtime: &Runtime<E, Proxy<A::Message>, A::Message>,
+) {
+    if let Some(shutdown) = application.shutdown() {
+        runtime.enter(|| futures::executor::block_on(shutdown));
+    }
+}
+
+/// Queues an event produced by the window, coalescing pointer movements
+/// unless the widgets asked for raw events.
+///
+/// See [`event::coalesce`] and [`UserInterface::wants_raw_events`].
+pub fn push_event(
+    events: &mut Vec<iced_native::Event>,
+    event: iced_native::Event,
+    raw_events: bool,
+) {
+    if raw_events {
+        events.push(event);
+    } else {
+        event::coalesce(events, event);
+    }
+}
+
+/// Produces the messages bound to the [`Shortcuts`] triggered by the given
+/// events.
+///
+/// Events captured by a widget do not trigger any shortcut.
+pub fn trigger_shortcuts<Message>(
+    shortcuts: &Shortcuts<Message>,
+    events: &[iced_native::Event],
+    statuses: &[event::Status],
+    messages: &mut Vec<Message>,
+) {
+    for (event, status) in events.iter().zip(statuses) {
+        if let (
+            iced_native::Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code,
+                modifiers,
+            }),
+            event::Status::Ignored,
+        ) = (event, status)
+        {
+            if let Some(message) = shortcuts.find(*key_code, *modifiers) {
+                messages.push(message);
+            }
+        }
+    }
+}
+
+/// Queues a focus traversal for every <kbd>Tab</kbd> or
+/// <kbd>Shift</kbd>+<kbd>Tab</kbd> press that no widget captured.
+pub fn traverse_focus<Message: 'static>(
+    events: &[iced_native::Event],
+    statuses: &[event::Status],
+    operations: &mut Vec<Box<dyn widget::Operation<Message>>>,
+) {
+    use iced_native::widget::operation;
+
+    for (event, status) in events.iter().zip(statuses) {
+        if let (
+            iced_native::Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code: keyboard::KeyCode::Tab,
+                modifiers,
+            }),
+            event::Status::Ignored,
+        ) = (event, status)
+        {
+            if modifiers.shift() {
+                operations
+                    .push(Box::new(operation::focusable::focus_previous()));
+            } else {
+                operations.push(Box::new(operation::focusable::focus_next()));
+            }
+        }
+    }
+}
+
+/// Returns the zoom level resulting from the <kbd>Ctrl</kbd>+<kbd>+</kbd>,
+/// <kbd>Ctrl</kbd>+<kbd>-</kbd>, and <kbd>Ctrl</kbd>+<kbd>0</kbd> presses
+/// that no widget captured, starting at the given zoom level.
+pub fn zoom_level(
+    zoom: f64,
+    events: &[iced_native::Event],
+    statuses: &[event::Status],
+) -> f64 {
+    const STEP: f64 = 1.1;
+    const MIN: f64 = 0.5;
+    const MAX: f64 = 3.0;
+
+    events
+        .iter()
+        .zip(statuses)
+        .fold(zoom, |zoom, (event, status)| match (event, status) {
+            (
+                iced_native::Event::Keyboard(keyboard::Event::KeyPressed {
+                    key_code,
+                    modifiers,
+                }),
+                event::Status::Ignored,
+            ) if modifiers.command() => match key_code {
+                keyboard::KeyCode::Equals
+                | keyboard::KeyCode::Plus
+                | keyboard::KeyCode::NumpadAdd => (zoom * STEP).min(MAX),
+                keyboard::KeyCode::Minus
+                | keyboard::KeyCode::NumpadSubtract => (zoom / STEP).max(MIN),
+                keyboard::KeyCode::Key0 | keyboard::KeyCode::Numpad0 => 1.0,
+                _ => zoom,
+            },
+            _ => zoom,
+        })
+}
+
+/// Locks or releases the pointer of the given window.
+///
+/// See [`UserInterface::wants_pointer_lock`].
+pub fn lock_pointer(window: &winit::window::Window, is_locked: bool) {
+    // The cursor is hidden by the mouse interaction of a locked pointer, so
+    // the motion is still usable on platforms that cannot grab the cursor.
+    if let Err(error) = window.set_cursor_grab(is_locked) {
+        log::warn!("failed to lock the pointer: {}", error);
+    }
+}
+
+/// Changes the cursor of the window to match the given [`mouse::Interaction`].
+pub fn set_mouse_interaction(
+    window: &winit::window::Window,
+    interaction: mouse::Interaction,
+) {
+    window.set_cursor_visible(interaction != mouse::Interaction::Hidden);
+    window.set_cursor_icon(conversion::mouse_interaction(interaction));
+}
+
+/// Logs a warning for every [`Shortcut`] that is bound more than once.
+///
+/// [`Shortcut`]: keyboard::Shortcut
+pub fn report_shortcut_conflicts<Message>(shortcuts: &Shortcuts<Message>) {
+    for conflict in shortcuts.conflicts() {
+        log::warn!("{}", conflict);
+    }
 }
 
 /// Runs the actions of a [`Command`].
+///
+/// Any widget operation is queued in `operations`, so it can be applied to
+/// the next [`UserInterface`] with [`run_operations`], and any screenshot is
+/// queued in `screenshots`, so it can be taken the next time the window is
+/// presented. Changes to the pacing of the frames are recorded in
+/// `frame_pacing`.
+///
+/// Returns `true` if the [`Command`] requested the window to close.
+#[allow(clippy::too_many_arguments)]
 pub fn run_command<Message: 'static + std::fmt::Debug + Send, E: Executor>(
     command: Command<Message>,
     runtime: &mut Runtime<E, Proxy<Message>, Message>,
     clipboard: &mut Clipboard,
     proxy: &mut winit::event_loop::EventLoopProxy<Message>,
+    operations: &mut Vec<Box<dyn widget::Operation<Message>>>,
+    screenshots: &mut Vec<Box<dyn Fn(Screenshot) -> Message>>,
+    frame_pacing: &mut FramePacing,
     window: &winit::window::Window,
-) {
+    drag_resize: &mut DragResize,
+) -> bool {
     use iced_native::command;
-    use iced_native::window;
+
+    let mut close_requested = false;
 
     for action in command.actions() {
         match action {
             command::Action::Future(future) => {
                 runtime.spawn(future);
             }
-            command::Action::Clipboard(action) => match action {
-                clipboard::Action::Read(tag) => {
-                    let message = tag(clipboard.read());
+            command::Action::Background(future) => {
+                runtime.spawn_background(future);
+            }
+            command::Action::Stream(stream) => {
+                runtime.run(stream);
+            }
+            command::Action::Clipboard(action) => {
+                run_clipboard_action(action, clipboard, proxy);
+            }
+            command::Action::Window(iced_native::window::Action::Close(id))
+                if id == iced_native::window::Id::MAIN =>
+            {
+                close_requested = true;
+            }
+            command::Action::Window(
+                iced_native::window::Action::SetPresentMode(present_mode),
+            ) => {
+                frame_pacing.set_present_mode(present_mode);
+                window.request_redraw();
+            }
+            command::Action::Window(
+                iced_native::window::Action::SetFrameRateCap(frame_rate_cap),
+            ) => {
+                frame_pacing.set_frame_rate_cap(frame_rate_cap);
+            }
+            command::Action::Window(
+                iced_native::window::Action::SetContinuousRendering(
+                    id,
+                    is_continuous,
+                ),
+            ) if id == iced_native::window::Id::MAIN => {
+                frame_pacing.set_continuous_rendering(id, is_continuous);
+                window.request_redraw();
+            }
+            command::Action::Window(action) => {
+                run_window_action(action, window, drag_resize);
+            }
+            command::Action::Tray(action) => {
+                run_tray_action(action, proxy);
+            }
+            command::Action::FetchMonitors(tag) => {
+                proxy
+                    .send_event(tag(monitors(window)))
+                    .expect("Send message to event loop");
+            }
+            command::Action::FetchColorScheme(tag) => {
+                proxy
+                    .send_event(tag(color_scheme(window)))
+                    .expect("Send message to event loop");
+            }
+            command::Action::Screenshot(id, tag) => {
+                if id == iced_native::window::Id::MAIN {
+                    screenshots.push(tag);
+                } else {
+                    log::warn!("window {:?} does not exist", id);
+                }
+            }
+            command::Action::StartDrag(data, tag) => {
+                proxy
+                    .send_event(tag(start_drag(window, data)))
+                    .expect("Send message to event loop");
+            }
+            command::Action::FetchProxy(tag) => {
+                proxy
+                    .send_event(tag(command_proxy(proxy)))
+                    .expect("Send message to event loop");
+            }
+            command::Action::Widget(operation) => {
+                operations.push(operation);
+            }
+        }
+    }
+
+    close_requested
+}
+
+/// Creates a [`command::Proxy`] that sends messages to the event loop of the
+/// given `EventLoopProxy`.
+///
+/// [`command::Proxy`]: iced_native::command::Proxy
+pub fn command_proxy<Message: 'static + std::fmt::Debug + Send>(
+    proxy: &winit::event_loop::EventLoopProxy<Message>,
+) -> iced_native::command::Proxy<Message> {
+    use iced_native::command;
+    use std::sync::Mutex;
+
+    let proxy = Mutex::new(proxy.clone());
+
+    command::Proxy::new(move |message| {
+        proxy
+            .lock()
+            .map_err(|_| command::Closed)?
+            .send_event(message)
+            .map_err(|_| command::Closed)
+    })
+}
+
+/// Performs a [`tray::Action`] on the system tray.
+///
+/// The messages of the tray are sent to the event loop.
+///
+/// [`tray::Action`]: iced_native::tray::Action
+pub fn run_tray_action<Message: 'static + std::fmt::Debug + Send>(
+    action: iced_native::tray::Action<Message>,
+    proxy: &winit::event_loop::EventLoopProxy<Message>,
+) {
+    #[cfg(feature = "tray")]
+    {
+        let proxy = command_proxy(proxy);
+
+        crate::system_tray::run_action(action.map(move |message| {
+            let _ = proxy.send(message);
+        }));
+    }
+
+    #[cfg(not(feature = "tray"))]
+    {
+        let _ = proxy;
+
+        log::warn!("{:?} needs the `tray` feature", action);
+    }
+}
+
+/// Performs a [`clipboard::Action`] on the given [`Clipboard`].
+///
+/// Any message produced by the action is sent to the event loop.
+pub fn run_clipboard_action<Message: 'static + std::fmt::Debug + Send>(
+    action: clipboard::Action<Message>,
+    clipboard: &mut Clipboard,
+    proxy: &mut winit::event_loop::EventLoopProxy<Message>,
+) {
+    let message = match action {
+        clipboard::Action::Read(tag) => tag(clipboard.read()),
+        clipboard::Action::Write(contents) => {
+            clipboard.write(contents);
+            return;
+        }
+        clipboard::Action::ReadPrimary(tag) => tag(clipboard.read_primary()),
+        clipboard::Action::WritePrimary(contents) => {
+            clipboard.write_primary(contents);
+            return;
+        }
+        clipboard::Action::ReadImage(tag) => tag(clipboard.read_image()),
+        clipboard::Action::WriteImage(image) => {
+            clipboard.write_image(image);
+            return;
+        }
+        clipboard::Action::ReadHtml(tag) => tag(clipboard.read_html()),
+        clipboard::Action::WriteHtml { html, alt_text } => {
+            clipboard.write_html(html, alt_text);
+            return;
+        }
+        clipboard::Action::ReadFiles(tag) => tag(clipboard.read_files()),
+        clipboard::Action::WriteFiles(files) => {
+            clipboard.write_files(files);
+            return;
+        }
+    };
+
+    proxy
+        .send_event(message)
+        .expect("Send message to event loop");
+}
+
+/// Performs a [`window::Action`] on the given window.
+///
+/// An [`Action::DragResize`] starts resizing the window with the given
+/// [`DragResize`], which must be fed the events of the window.
+///
+/// [`window::Action`]: iced_native::window::Action
+/// [`Action::DragResize`]: iced_native::window::Action::DragResize
+pub fn run_window_action(
+    action: iced_native::window::Action,
+    window: &winit::window::Window,
+    drag_resize: &mut DragResize,
+) {
+    use iced_native::window;
+
+    match action {
+        window::Action::Resize { width, height } => {
+            window.set_inner_size(winit::dpi::LogicalSize { width, height });
+        }
+        window::Action::Move { x, y } => {
+            window.set_outer_position(winit::dpi::LogicalPosition { x, y });
+        }
+        window::Action::MoveToMonitor { monitor, position } => {
+            let handle = match find_monitor(window, &monitor) {
+                Some(handle) => handle,
+                None => {
+                    log::warn!("monitor {:?} is not connected", monitor.name);
+                    return;
+                }
+            };
+
+            let start = handle.position();
+
+            let position = match position {
+                window::Position::Default => {
+                    Some(winit::dpi::Position::Physical(start))
+                }
+                window::Position::Specific(x, y) => {
+                    let offset: winit::dpi::PhysicalPosition<i32> =
+                        winit::dpi::LogicalPosition { x, y }
+                            .to_physical(handle.scale_factor());
+
+                    Some(winit::dpi::Position::Physical(
+                        winit::dpi::PhysicalPosition {
+                            x: start.x + offset.x,
+                            y: start.y + offset.y,
+                        },
+                    ))
+                }
+                window::Position::Centered => {
+                    let size: winit::dpi::LogicalSize<u32> =
+                        window.outer_size().to_logical(handle.scale_factor());
+
+                    conversion::position(
+                        Some(&handle),
+                        (size.width, size.height),
+                        position,
+                    )
+                }
+            };
+
+            if let Some(position) = position {
+                window.set_outer_position(position);
+            }
+        }
+        window::Action::Fullscreen(None) => {
+            window.set_fullscreen(None);
+        }
+        window::Action::Fullscreen(Some(monitor)) => {
+            let handle = match find_monitor(window, &monitor) {
+                Some(handle) => handle,
+                None => {
+                    log::warn!("monitor {:?} is not connected", monitor.name);
+                    return;
+                }
+            };
+
+            let size = handle.size();
 
+            let video_mode = handle
+                .video_modes()
+                .filter(|mode| mode.size() == size)
+                .max_by_key(|mode| (mode.refresh_rate(), mode.bit_depth()));
+
+            window.set_fullscreen(Some(match video_mode {
+                Some(video_mode) => {
+                    winit::window::Fullscreen::Exclusive(video_mode)
+                }
+                None => winit::window::Fullscreen::Borderless(Some(handle)),
+            }));
+        }
+        window::Action::SetMinSize(size) => {
+            window.set_min_inner_size(size.map(|(width, height)| {
+                winit::dpi::LogicalSize { width, height }
+            }));
+        }
+        window::Action::SetMaxSize(size) => {
+            window.set_max_inner_size(size.map(|(width, height)| {
+                winit::dpi::LogicalSize { width, height }
+            }));
+        }
+        window::Action::SetResizable(resizable) => {
+            window.set_resizable(resizable);
+        }
+        window::Action::SetDecorations(decorations) => {
+            window.set_decorations(decorations);
+        }
+        window::Action::SetLevel(level) => {
+            window.set_always_on_top(level == window::Level::AlwaysOnTop);
+
+            set_always_on_bottom(
+                window,
+                level == window::Level::AlwaysOnBottom,
+            );
+        }
+        window::Action::Minimize => {
+            window.set_minimized(true);
+        }
+        window::Action::Maximize => {
+            window.set_maximized(true);
+        }
+        window::Action::Restore => {
+            window.set_minimized(false);
+            window.set_maximized(false);
+        }
+        window::Action::SetIcon(None) => {
+            window.set_window_icon(None);
+        }
+        window::Action::SetIcon(Some(handle)) => {
+            match conversion::icon(&handle) {
+                Some(icon) => window.set_window_icon(Some(icon)),
+                None => log::warn!(
+                    "only images with decoded pixels can be used as icons"
+                ),
+            }
+        }
+        window::Action::SetProgress(progress) => {
+            set_progress(window, progress);
+        }
+        window::Action::RequestAttention(attention) => {
+            window.request_user_attention(
+                attention.map(conversion::user_attention),
+            );
+        }
+        window::Action::Drag => {
+            if let Err(error) = window.drag_window() {
+                log::warn!("failed to drag the window: {}", error);
+            }
+        }
+        window::Action::DragResize(direction) => {
+            drag_resize.start(window, direction);
+        }
+        window::Action::SetSoftKeyboardVisible(_is_visible) => {
+            // The commands showing and hiding the software keyboard are only
+            // available on Android
+            #[cfg(target_os = "android")]
+            set_soft_keyboard_visible(_is_visible);
+        }
+        window::Action::SetPresentMode(_)
+        | window::Action::SetFrameRateCap(_)
+        | window::Action::SetContinuousRendering(..) => {
+            // The pacing of the frames is tracked by the event loop, see
+            // `run_command`
+        }
+        window::Action::Open { .. } | window::Action::Close(_) => {
+            log::warn!(
+                "opening windows and closing other windows than the main \
+                one requires a multi-window application"
+            );
+        }
+    }
+}
+
+/// Shows the given progress in the taskbar or dock entry of the application,
+/// or hides it.
+///
+/// It is shown through the taskbar on Windows, and through the launcher entry
+/// of the application on Linux with the `launcher_entry` feature.
+fn set_progress(window: &winit::window::Window, progress: Option<f32>) {
+    #[cfg(target_os = "windows")]
+    if let Err(error) = crate::taskbar::set_progress(window, progress) {
+        log::warn!("failed to show the progress: {}", error);
+    }
+
+    #[cfg(all(target_os = "linux", feature = "launcher_entry"))]
+    {
+        let _ = window;
+
+        crate::launcher_entry::set_progress(progress);
+    }
+
+    #[cfg(not(any(
+        target_os = "windows",
+        all(target_os = "linux", feature = "launcher_entry")
+    )))]
+    {
+        let _ = window;
+
+        if progress.is_some() {
+            log::warn!(
+                "showing progress is only supported on Windows, and on \
+                Linux with the `launcher_entry` feature"
+            );
+        }
+    }
+}
+
+/// Keeps the window below every other window, or stops doing so.
+///
+/// `winit` only supports keeping windows on top, so the window manager is
+/// asked directly on X11 with the `x11` feature.
+fn set_always_on_bottom(window: &winit::window::Window, is_below: bool) {
+    #[cfg(all(target_os = "linux", feature = "x11"))]
+    match crate::x11::set_always_on_bottom(window, is_below) {
+        Ok(true) => return,
+        Ok(false) => {}
+        Err(error) => {
+            log::warn!("failed to change the level of the window: {}", error);
+            return;
+        }
+    }
+
+    #[cfg(not(all(target_os = "linux", feature = "x11")))]
+    let _ = window;
+
+    if is_below {
+        log::warn!(
+            "keeping a window below others is only supported on X11, with \
+            the `x11` feature"
+        );
+    }
+}
+
+/// Shows or hides the software keyboard of the platform.
+///
+/// `winit` cannot do it, so the native activity of the application is asked
+/// directly on Android.
+#[cfg(target_os = "android")]
+#[allow(unsafe_code)]
+fn set_soft_keyboard_visible(is_visible: bool) {
+    let activity = ndk_glue::native_activity().ptr().as_ptr();
+
+    // SAFETY: The native activity outlives the event loop of the application
+    unsafe {
+        if is_visible {
+            ndk_sys::ANativeActivity_showSoftInput(activity, 0);
+        } else {
+            ndk_sys::ANativeActivity_hideSoftInput(activity, 0);
+        }
+    }
+}
+
+/// Starts dragging some data out of the window, into other applications.
+///
+/// `winit` cannot start drags, so the XDND protocol is spoken directly on X11
+/// with the `x11` feature.
+pub fn start_drag(
+    window: &winit::window::Window,
+    data: iced_native::dnd::Data,
+) -> Result<(), iced_native::dnd::Error> {
+    #[cfg(all(target_os = "linux", feature = "x11"))]
+    match crate::x11::start_drag(window, data) {
+        Ok(true) => return Ok(()),
+        Ok(false) => {}
+        Err(error) => {
+            return Err(iced_native::dnd::Error::Failed(error.to_string()));
+        }
+    }
+
+    #[cfg(not(all(target_os = "linux", feature = "x11")))]
+    let _ = (window, data);
+
+    Err(iced_native::dnd::Error::Unsupported)
+}
+
+/// Returns the monitors connected to the system.
+pub fn monitors(
+    window: &winit::window::Window,
+) -> Vec<iced_native::window::Monitor> {
+    let primary = window.primary_monitor();
+
+    window
+        .available_monitors()
+        .map(|monitor| {
+            let is_primary = primary.as_ref() == Some(&monitor);
+
+            conversion::monitor(&monitor, is_primary)
+        })
+        .collect()
+}
+
+/// Returns the color scheme preferred by the user of the operating system,
+/// if it can be detected.
+///
+/// _**Note:** Outside of Windows, it can only be detected with the
+/// `system_theme` feature._
+pub fn color_scheme(
+    window: &winit::window::Window,
+) -> Option<iced_native::window::ColorScheme> {
+    #[cfg(target_os = "windows")]
+    {
+        use winit::platform::windows::WindowExtWindows;
+
+        Some(conversion::color_scheme(window.theme()))
+    }
+
+    #[cfg(all(not(target_os = "windows"), feature = "system_theme"))]
+    {
+        let _ = window;
+
+        match dark_light::detect() {
+            dark_light::Mode::Dark => {
+                Some(iced_native::window::ColorScheme::Dark)
+            }
+            dark_light::Mode::Light => {
+                Some(iced_native::window::ColorScheme::Light)
+            }
+            dark_light::Mode::Default => None,
+        }
+    }
+
+    #[cfg(all(not(target_os = "windows"), not(feature = "system_theme")))]
+    {
+        let _ = window;
+
+        None
+    }
+}
+
+fn find_monitor(
+    window: &winit::window::Window,
+    monitor: &iced_native::window::Monitor,
+) -> Option<winit::monitor::MonitorHandle> {
+    window.available_monitors().find(|handle| {
+        let position = handle.position();
+
+        handle.name() == monitor.name
+            && (position.x, position.y) == monitor.position
+    })
+}
+
+/// Applies the queued widget operations to a [`UserInterface`], sending any
+/// resulting message to the event loop.
+pub fn run_operations<Message: 'static + std::fmt::Debug + Send, Renderer>(
+    user_interface: &mut UserInterface<'_, Message, Renderer>,
+    operations: &mut Vec<Box<dyn widget::Operation<Message>>>,
+    proxy: &mut winit::event_loop::EventLoopProxy<Message>,
+) where
+    Renderer: iced_native::Renderer,
+{
+    use iced_native::widget::operation;
+
+    for mut operation in operations.drain(..) {
+        loop {
+            user_interface.operate(operation.as_mut());
+
+            match operation.finish() {
+                operation::Outcome::None => break,
+                operation::Outcome::Some(message) => {
                     proxy
                         .send_event(message)
                         .expect("Send message to event loop");
+
+                    break;
                 }
-                clipboard::Action::Write(contents) => {
-                    clipboard.write(contents);
-                }
-            },
-            command::Action::Window(action) => match action {
-                window::Action::Resize { width, height } => {
-                    window.set_inner_size(winit::dpi::LogicalSize {
-                        width,
-                        height,
-                    });
-                }
-                window::Action::Move { x, y } => {
-                    window.set_outer_position(winit::dpi::LogicalPosition {
-                        x,
-                        y,
-                    });
+                operation::Outcome::Chain(next) => {
+                    operation = next;
                 }
-            },
+            }
         }
     }
 }
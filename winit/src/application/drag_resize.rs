@@ -0,0 +1,191 @@
+use iced_native::window::ResizeDirection;
+
+use winit::dpi::{PhysicalPosition, PhysicalSize};
+use winit::event::{ElementState, MouseButton, WindowEvent};
+use winit::window::Window;
+
+/// An interactive resize of a window with the cursor, started by an
+/// [`Action::DragResize`].
+///
+/// winit cannot hand the resize over to the window manager, so the window is
+/// resized by following the cursor until the left mouse button is released.
+///
+/// [`Action::DragResize`]: iced_native::window::Action::DragResize
+#[derive(Debug, Default)]
+pub struct DragResize {
+    cursor: Option<PhysicalPosition<f64>>,
+    is_pressed: bool,
+    resize: Option<Resize>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Resize {
+    direction: ResizeDirection,
+    origin: PhysicalPosition<f64>,
+    size: PhysicalSize<u32>,
+    position: Option<PhysicalPosition<i32>>,
+}
+
+impl DragResize {
+    /// Creates a new [`DragResize`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns true if the window is being resized.
+    pub fn is_resizing(&self) -> bool {
+        self.resize.is_some()
+    }
+
+    /// Starts resizing the window by dragging the given edge or corner.
+    ///
+    /// The resize only starts while the left mouse button is pressed inside
+    /// of the window.
+    pub fn start(&mut self, window: &Window, direction: ResizeDirection) {
+        let cursor = match self.cursor {
+            Some(cursor) if self.is_pressed => cursor,
+            _ => return,
+        };
+
+        let position = window.outer_position().ok();
+
+        if position.is_none() && moves_origin(direction) {
+            log::warn!(
+                "the window cannot be moved, so it can only be resized \
+                 through its bottom and right edges"
+            );
+        }
+
+        self.resize = Some(Resize {
+            direction,
+            origin: on_screen(window, cursor),
+            size: window.inner_size(),
+            position,
+        });
+    }
+
+    /// Processes the provided window event, resizing the window if needed.
+    ///
+    /// Returns true if the event was consumed by the resize.
+    pub fn update(&mut self, window: &Window, event: &WindowEvent<'_>) -> bool {
+        match event {
+            WindowEvent::CursorMoved { position, .. } => {
+                self.cursor = Some(*position);
+
+                match self.resize {
+                    Some(resize) => {
+                        resize.apply(window, on_screen(window, *position));
+
+                        true
+                    }
+                    None => false,
+                }
+            }
+            WindowEvent::CursorLeft { .. } => {
+                self.cursor = None;
+
+                self.resize.is_some()
+            }
+            WindowEvent::MouseInput {
+                button: MouseButton::Left,
+                state,
+                ..
+            } => {
+                self.is_pressed = *state == ElementState::Pressed;
+
+                if !self.is_pressed {
+                    self.resize = None;
+                }
+
+                false
+            }
+            WindowEvent::Focused(false) => {
+                self.is_pressed = false;
+                self.resize = None;
+
+                false
+            }
+            _ => false,
+        }
+    }
+}
+
+impl Resize {
+    fn apply(&self, window: &Window, cursor: PhysicalPosition<f64>) {
+        let dx = (cursor.x - self.origin.x).round() as i64;
+        let dy = (cursor.y - self.origin.y).round() as i64;
+
+        let (west, east, north, south) = edges(self.direction);
+        let can_move = self.position.is_some();
+
+        let resize = |length: u32, delta: i64, grows: bool, shrinks: bool| {
+            let delta = if grows {
+                delta
+            } else if shrinks && can_move {
+                -delta
+            } else {
+                0
+            };
+
+            (i64::from(length) + delta).max(1) as u32
+        };
+
+        let width = resize(self.size.width, dx, east, west);
+        let height = resize(self.size.height, dy, south, north);
+
+        window.set_inner_size(PhysicalSize::new(width, height));
+
+        if let Some(position) = self.position {
+            if west || north {
+                let x = if west {
+                    position.x + self.size.width as i32 - width as i32
+                } else {
+                    position.x
+                };
+
+                let y = if north {
+                    position.y + self.size.height as i32 - height as i32
+                } else {
+                    position.y
+                };
+
+                window.set_outer_position(PhysicalPosition::new(x, y));
+            }
+        }
+    }
+}
+
+/// Returns the position of the cursor on the screen, or relative to the
+/// window if its position is unknown.
+fn on_screen(
+    window: &Window,
+    cursor: PhysicalPosition<f64>,
+) -> PhysicalPosition<f64> {
+    match window.inner_position() {
+        Ok(position) => PhysicalPosition::new(
+            f64::from(position.x) + cursor.x,
+            f64::from(position.y) + cursor.y,
+        ),
+        Err(_) => cursor,
+    }
+}
+
+fn moves_origin(direction: ResizeDirection) -> bool {
+    let (west, _, north, _) = edges(direction);
+
+    west || north
+}
+
+/// Returns whether the west, east, north, and south edges are dragged.
+fn edges(direction: ResizeDirection) -> (bool, bool, bool, bool) {
+    match direction {
+        ResizeDirection::North => (false, false, true, false),
+        ResizeDirection::South => (false, false, false, true),
+        ResizeDirection::East => (false, true, false, false),
+        ResizeDirection::West => (true, false, false, false),
+        ResizeDirection::NorthEast => (false, true, true, false),
+        ResizeDirection::NorthWest => (true, false, true, false),
+        ResizeDirection::SouthEast => (false, true, false, true),
+        ResizeDirection::SouthWest => (true, false, false, true),
+    }
+}
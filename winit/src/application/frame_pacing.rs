@@ -0,0 +1,83 @@
+use iced_native::window::{Id, PresentMode};
+
+use std::collections::HashSet;
+use std::time::{Duration, Instant};
+
+/// The pacing of the frames presented by the windows of an application.
+///
+/// It keeps track of the changes to the [`PresentMode`], the frame rate cap,
+/// and the windows rendered continuously requested with
+/// [`window::set_present_mode`], [`window::set_frame_rate_cap`], and
+/// [`window::set_continuous_rendering`].
+///
+/// [`window::set_present_mode`]: crate::window::set_present_mode
+/// [`window::set_frame_rate_cap`]: crate::window::set_frame_rate_cap
+/// [`window::set_continuous_rendering`]: crate::window::set_continuous_rendering
+#[derive(Debug, Clone, Default)]
+pub struct FramePacing {
+    present_mode: Option<PresentMode>,
+    frame_rate_cap: Option<u16>,
+    continuous: HashSet<Id>,
+}
+
+impl FramePacing {
+    /// Creates a new [`FramePacing`] with the given frame rate cap.
+    pub fn new(frame_rate_cap: Option<u16>) -> Self {
+        Self {
+            present_mode: None,
+            frame_rate_cap,
+            continuous: HashSet::new(),
+        }
+    }
+
+    /// Requests a change of the [`PresentMode`] of the windows.
+    pub fn set_present_mode(&mut self, present_mode: PresentMode) {
+        self.present_mode = Some(present_mode);
+    }
+
+    /// Takes the [`PresentMode`] requested since the last call, if any.
+    pub fn take_present_mode(&mut self) -> Option<PresentMode> {
+        self.present_mode.take()
+    }
+
+    /// Limits the amount of frames presented per second.
+    pub fn set_frame_rate_cap(&mut self, frame_rate_cap: Option<u16>) {
+        self.frame_rate_cap = frame_rate_cap;
+    }
+
+    /// Sets whether the window with the given [`Id`] is redrawn continuously.
+    pub fn set_continuous_rendering(&mut self, id: Id, is_continuous: bool) {
+        if is_continuous {
+            let _ = self.continuous.insert(id);
+        } else {
+            let _ = self.continuous.remove(&id);
+        }
+    }
+
+    /// Returns whether the window with the given [`Id`] is redrawn
+    /// continuously.
+    pub fn is_continuous(&self, id: Id) -> bool {
+        self.continuous.contains(&id)
+    }
+
+    /// Returns the instant a window that presented its last frame at
+    /// `last_frame` must wait for before presenting a new one, if the frame
+    /// rate cap does not allow presenting it `now`.
+    pub fn delay(
+        &self,
+        last_frame: Option<Instant>,
+        now: Instant,
+    ) -> Option<Instant> {
+        let frame_rate_cap = self.frame_rate_cap?;
+        let interval =
+            Duration::from_secs_f64(1.0 / f64::from(frame_rate_cap.max(1)));
+
+        let next_frame = last_frame? + interval;
+
+        if next_frame > now {
+            Some(next_frame)
+        } else {
+            None
+        }
+    }
+}
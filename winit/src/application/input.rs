@@ -0,0 +1,275 @@
+use crate::application::{self, DragResize, WindowState};
+use crate::clipboard::Clipboard;
+use crate::conversion;
+use crate::mouse;
+
+use iced_native::event;
+use iced_native::keyboard::Shortcuts;
+use iced_native::touch;
+use iced_native::widget;
+use iced_native::window::RedrawRequest;
+use iced_native::{Event, UserInterface};
+
+use std::time::Instant;
+use winit::event::WindowEvent;
+use winit::window::Window;
+
+/// The input of a window, waiting to be processed by its [`UserInterface`].
+///
+/// It queues the events of the window, recognizes its touch gestures, and
+/// keeps track of the requests of its widgets that outlive a single
+/// [`UserInterface`], like a pointer lock or a [`DragResize`].
+#[derive(Debug)]
+pub struct Input {
+    events: Vec<Event>,
+    gestures: touch::gesture::Recognizer,
+    drag_resize: DragResize,
+    raw_events: bool,
+    pointer_lock: bool,
+    mouse_interaction: mouse::Interaction,
+}
+
+impl Input {
+    /// Creates the [`Input`] of a window with the given [`WindowState`].
+    pub fn new(state: &WindowState) -> Self {
+        let mut events = Vec::new();
+
+        if state.safe_area() != iced_native::Padding::ZERO {
+            events.push(Event::Window(
+                iced_native::window::Event::SafeAreaChanged(state.safe_area()),
+            ));
+        }
+
+        Self {
+            events,
+            gestures: touch::gesture::Recognizer::new(),
+            drag_resize: DragResize::new(),
+            raw_events: false,
+            pointer_lock: false,
+            mouse_interaction: mouse::Interaction::default(),
+        }
+    }
+
+    /// Returns true if the [`Input`] has no events to process.
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+
+    /// Returns the [`DragResize`] of the window.
+    pub fn drag_resize(&mut self) -> &mut DragResize {
+        &mut self.drag_resize
+    }
+
+    /// Queues an [`Event`] as is.
+    pub fn push(&mut self, event: Event) {
+        self.events.push(event);
+    }
+
+    /// Updates the [`WindowState`] of the window with the provided window
+    /// event and queues the resulting events.
+    ///
+    /// The cursor movements consumed by a [`DragResize`] are not queued.
+    pub fn handle_window_event(
+        &mut self,
+        state: &mut WindowState,
+        window: &Window,
+        window_event: &WindowEvent<'_>,
+        debug: &mut crate::Debug,
+    ) {
+        if self.drag_resize.update(window, window_event) {
+            return;
+        }
+
+        // The cursor stays in place while the pointer is locked
+        if self.pointer_lock
+            && matches!(window_event, WindowEvent::CursorMoved { .. })
+        {
+            return;
+        }
+
+        let was_minimized = state.is_minimized();
+        let safe_area = state.safe_area();
+        let color_scheme = state.color_scheme();
+
+        state.update(window, window_event, debug);
+
+        if state.is_minimized() != was_minimized {
+            self.events.push(Event::Window(if state.is_minimized() {
+                iced_native::window::Event::Minimized
+            } else {
+                iced_native::window::Event::Restored
+            }));
+        }
+
+        if state.safe_area() != safe_area {
+            self.events.push(Event::Window(
+                iced_native::window::Event::SafeAreaChanged(state.safe_area()),
+            ));
+        }
+
+        // A `ThemeChanged` event is already converted below
+        if !matches!(window_event, WindowEvent::ThemeChanged(_)) {
+            if let Some(new_color_scheme) = state
+                .color_scheme()
+                .filter(|new| Some(*new) != color_scheme)
+            {
+                self.events.push(Event::Window(
+                    iced_native::window::Event::ColorSchemeChanged(
+                        new_color_scheme,
+                    ),
+                ));
+            }
+        }
+
+        if let Some(event) = conversion::window_event(
+            window_event,
+            state.scale_factor(),
+            state.modifiers(),
+        ) {
+            application::push_event(
+                &mut self.events,
+                conversion::rotate(event, state.viewport()),
+                self.raw_events,
+            );
+        }
+
+        if let WindowEvent::Touch(touch) = window_event {
+            if let Some(event) =
+                conversion::pen_event(*touch, state.scale_factor())
+            {
+                application::push_event(
+                    &mut self.events,
+                    conversion::rotate(Event::Pen(event), state.viewport()),
+                    self.raw_events,
+                );
+            }
+        }
+    }
+
+    /// Queues the relative motion of the mouse, as long as the pointer of the
+    /// window is locked.
+    pub fn handle_mouse_motion(
+        &mut self,
+        state: &WindowState,
+        delta: (f64, f64),
+    ) {
+        if self.pointer_lock {
+            self.events.push(conversion::rotate(
+                conversion::mouse_motion(delta),
+                state.viewport(),
+            ));
+        }
+    }
+
+    /// Queues the gestures recognized until the given [`Instant`].
+    pub fn recognize_gestures(&mut self, now: Instant) {
+        self.events
+            .extend(self.gestures.drain(now).into_iter().map(Event::Gesture));
+    }
+
+    /// Processes the queued events with the given [`UserInterface`].
+    ///
+    /// The messages produced by the widgets and the triggered [`Shortcuts`]
+    /// are pushed to `messages`, while focus traversals are pushed to
+    /// `operations`. The window actions of the widgets are run right away.
+    ///
+    /// Returns the processed events, together with their [`event::Status`],
+    /// and the [`RedrawRequest`] of the widgets and the gestures, if any.
+    #[allow(clippy::too_many_arguments)]
+    pub fn process<Message: 'static, Renderer: crate::Renderer>(
+        &mut self,
+        user_interface: &mut UserInterface<'_, Message, Renderer>,
+        state: &mut WindowState,
+        window: &Window,
+        renderer: &mut Renderer,
+        clipboard: &mut Clipboard,
+        shortcuts: &Shortcuts<Message>,
+        keyboard_zoom: bool,
+        messages: &mut Vec<Message>,
+        operations: &mut Vec<Box<dyn widget::Operation<Message>>>,
+        debug: &mut crate::Debug,
+    ) -> (Vec<(Event, event::Status)>, Option<RedrawRequest>) {
+        debug.event_processing_started();
+
+        let statuses = user_interface.update(
+            &self.events,
+            state.cursor_position(),
+            renderer,
+            clipboard,
+            messages,
+        );
+
+        debug.event_processing_finished();
+
+        self.raw_events = user_interface.wants_raw_events();
+
+        for action in user_interface.take_window_actions() {
+            application::run_window_action(
+                action,
+                window,
+                &mut self.drag_resize,
+            );
+        }
+
+        let now = Instant::now();
+        self.gestures.update(&self.events, &statuses, now);
+
+        let redraw_request = match (
+            user_interface.redraw_request(),
+            self.gestures.redraw_request(),
+        ) {
+            (Some(current), Some(new)) => Some(current.min(new)),
+            (current, new) => current.or(new),
+        };
+
+        if let Some(caret) = user_interface.input_method() {
+            let scale_factor = state.scale_factor();
+
+            window.set_ime_position(winit::dpi::PhysicalPosition::new(
+                f64::from(caret.x) * scale_factor,
+                f64::from(caret.y + caret.height) * scale_factor,
+            ));
+        }
+
+        if user_interface.wants_pointer_lock() != self.pointer_lock {
+            self.pointer_lock = !self.pointer_lock;
+
+            application::lock_pointer(window, self.pointer_lock);
+        }
+
+        application::trigger_shortcuts(
+            shortcuts,
+            &self.events,
+            &statuses,
+            messages,
+        );
+
+        application::traverse_focus(&self.events, &statuses, operations);
+
+        if keyboard_zoom {
+            state.set_zoom(
+                application::zoom_level(state.zoom(), &self.events, &statuses),
+                window,
+            );
+        }
+
+        (
+            self.events.drain(..).zip(statuses).collect(),
+            redraw_request,
+        )
+    }
+
+    /// Changes the cursor of the window to match the given
+    /// [`mouse::Interaction`], if it changed.
+    pub fn set_mouse_interaction(
+        &mut self,
+        window: &Window,
+        interaction: mouse::Interaction,
+    ) {
+        if interaction != self.mouse_interaction {
+            application::set_mouse_interaction(window, interaction);
+
+            self.mouse_interaction = interaction;
+        }
+    }
+}
@@ -0,0 +1,407 @@
+use crate::conversion;
+use crate::transition::Transition;
+use crate::{
+    Color, Debug, Mode, Padding, Point, Rotation, Size, Theme, Viewport,
+};
+
+use iced_native::window::{ColorScheme, RedrawRequest};
+
+use std::time::{Duration, Instant};
+use winit::event::{Touch, WindowEvent};
+use winit::window::Window;
+
+/// The appearance of a window, as described by an application.
+///
+/// It is described by an [`Application`] for its only window, and by a
+/// [`multi_window::Application`] for every one of its windows.
+///
+/// [`Application`]: crate::Application
+/// [`multi_window::Application`]: crate::multi_window::Application
+#[derive(Debug, Clone)]
+pub struct Appearance {
+    /// The title of the window.
+    pub title: String,
+
+    /// The [`Mode`] of the window.
+    pub mode: Mode,
+
+    /// The [`Theme`] of the window.
+    pub theme: Theme,
+
+    /// The duration of the cross-fade played when the [`Theme`] changes.
+    pub theme_transition: Option<Duration>,
+
+    /// The background [`Color`] of the window.
+    pub background_color: Color,
+
+    /// The scale factor of the application, applied on top of the one of the
+    /// window.
+    pub scale_factor: f64,
+
+    /// The [`Rotation`] of the contents of the window.
+    pub rotation: Rotation,
+}
+
+/// The state of a window, kept in sync with the [`Appearance`] described by
+/// its application.
+#[derive(Debug, Clone)]
+pub struct WindowState {
+    title: String,
+    mode: Mode,
+    theme: Theme,
+    theme_transition: Option<Transition>,
+    background_color: Color,
+    scale_factor: f64,
+    zoom: f64,
+    viewport: Viewport,
+    viewport_version: usize,
+    safe_area: Padding,
+    color_scheme: Option<ColorScheme>,
+    cursor_position: winit::dpi::PhysicalPosition<f64>,
+    modifiers: winit::event::ModifiersState,
+}
+
+impl WindowState {
+    /// Creates a new [`WindowState`] for the given window with the provided
+    /// [`Appearance`].
+    pub fn new(appearance: Appearance, window: &Window) -> Self {
+        let physical_size = physical_size(window);
+        let safe_area = conversion::safe_area(window, window.scale_factor());
+
+        let viewport = inset(
+            Viewport::with_physical_size(
+                physical_size,
+                window.scale_factor() * appearance.scale_factor,
+            )
+            .with_rotation(appearance.rotation),
+            safe_area,
+            appearance.scale_factor,
+        );
+
+        Self {
+            title: appearance.title,
+            mode: appearance.mode,
+            theme: appearance.theme,
+            theme_transition: None,
+            background_color: appearance.background_color,
+            scale_factor: appearance.scale_factor,
+            zoom: 1.0,
+            viewport,
+            viewport_version: 0,
+            safe_area,
+            color_scheme: crate::application::color_scheme(window),
+            // TODO: Encode cursor availability in the type-system
+            cursor_position: winit::dpi::PhysicalPosition::new(-1.0, -1.0),
+            modifiers: winit::event::ModifiersState::default(),
+        }
+    }
+
+    /// Returns the title of the window.
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    /// Returns the current [`Theme`] of the [`WindowState`].
+    ///
+    /// While the [`Theme`] is transitioning, the returned [`Theme`] mixes the
+    /// colors of the previous and the new one.
+    pub fn theme(&self) -> Theme {
+        match self.theme_transition {
+            Some(transition) => {
+                transition.theme(self.theme.clone(), Instant::now())
+            }
+            None => self.theme.clone(),
+        }
+    }
+
+    /// Returns the current background [`Color`] of the [`WindowState`].
+    pub fn background_color(&self) -> Color {
+        match self.theme_transition {
+            Some(transition) => transition
+                .background_color(self.background_color, Instant::now()),
+            None => self.background_color,
+        }
+    }
+
+    /// Returns the [`RedrawRequest`] needed to animate the [`WindowState`],
+    /// if any.
+    pub fn redraw_request(&self) -> Option<RedrawRequest> {
+        self.theme_transition
+            .and_then(|transition| transition.progress(Instant::now()))
+            .map(|_| RedrawRequest::NextFrame)
+    }
+
+    /// Returns the current [`Viewport`] of the [`WindowState`].
+    pub fn viewport(&self) -> &Viewport {
+        &self.viewport
+    }
+
+    /// Returns the version of the [`Viewport`] of the [`WindowState`].
+    ///
+    /// The version is incremented every time the [`Viewport`] changes.
+    pub fn viewport_version(&self) -> usize {
+        self.viewport_version
+    }
+
+    /// Returns the physical [`Size`] of the [`Viewport`] of the
+    /// [`WindowState`].
+    pub fn physical_size(&self) -> Size<u32> {
+        self.viewport.physical_size()
+    }
+
+    /// Returns the logical insets of the safe area of the window.
+    ///
+    /// The contents of the [`Viewport`] are kept inside of the safe area.
+    pub fn safe_area(&self) -> Padding {
+        self.safe_area
+    }
+
+    /// Returns the [`ColorScheme`] preferred by the user of the operating
+    /// system, if it could be detected.
+    pub fn color_scheme(&self) -> Option<ColorScheme> {
+        self.color_scheme
+    }
+
+    /// Returns true if the window is minimized; that is, if its [`Viewport`]
+    /// is empty.
+    pub fn is_minimized(&self) -> bool {
+        let size = self.physical_size();
+
+        size.width == 0 || size.height == 0
+    }
+
+    /// Returns the logical [`Size`] of the [`Viewport`] of the
+    /// [`WindowState`].
+    pub fn logical_size(&self) -> Size<f32> {
+        self.viewport.logical_size()
+    }
+
+    /// Returns the current scale factor of the [`Viewport`] of the
+    /// [`WindowState`].
+    pub fn scale_factor(&self) -> f64 {
+        self.viewport.scale_factor()
+    }
+
+    /// Returns the current zoom level of the [`WindowState`].
+    ///
+    /// The zoom level is applied on top of the scale factor of the window
+    /// and the one of the application.
+    pub fn zoom(&self) -> f64 {
+        self.zoom
+    }
+
+    /// Sets the zoom level of the [`WindowState`], updating its [`Viewport`]
+    /// accordingly.
+    pub fn set_zoom(&mut self, zoom: f64, window: &Window) {
+        if self.zoom == zoom {
+            return;
+        }
+
+        let size = physical_size(window);
+
+        self.viewport = inset(
+            Viewport::with_physical_size(
+                size,
+                window.scale_factor() * self.scale_factor * zoom,
+            )
+            .with_rotation(self.viewport.rotation()),
+            self.safe_area,
+            self.scale_factor * zoom,
+        );
+
+        self.zoom = zoom;
+        self.viewport_version = self.viewport_version.wrapping_add(1);
+    }
+
+    /// Returns the current cursor position of the [`WindowState`].
+    pub fn cursor_position(&self) -> Point {
+        self.viewport.rotate_position(conversion::cursor_position(
+            self.cursor_position,
+            self.viewport.scale_factor(),
+        ))
+    }
+
+    /// Returns the current keyboard modifiers of the [`WindowState`].
+    pub fn modifiers(&self) -> winit::event::ModifiersState {
+        self.modifiers
+    }
+
+    /// Processes the provided window event and updates the [`WindowState`]
+    /// accordingly.
+    pub fn update(
+        &mut self,
+        window: &Window,
+        event: &WindowEvent<'_>,
+        _debug: &mut Debug,
+    ) {
+        match event {
+            WindowEvent::Resized(new_size) => {
+                let size = Size::new(new_size.width, new_size.height);
+
+                self.safe_area =
+                    conversion::safe_area(window, window.scale_factor());
+                self.viewport = inset(
+                    Viewport::with_physical_size(
+                        size,
+                        window.scale_factor() * self.scale_factor * self.zoom,
+                    )
+                    .with_rotation(self.viewport.rotation()),
+                    self.safe_area,
+                    self.scale_factor * self.zoom,
+                );
+
+                self.viewport_version = self.viewport_version.wrapping_add(1);
+            }
+            WindowEvent::ScaleFactorChanged {
+                scale_factor: new_scale_factor,
+                new_inner_size,
+            } => {
+                let size =
+                    Size::new(new_inner_size.width, new_inner_size.height);
+
+                self.safe_area =
+                    conversion::safe_area(window, *new_scale_factor);
+                self.viewport = inset(
+                    Viewport::with_physical_size(
+                        size,
+                        new_scale_factor * self.scale_factor * self.zoom,
+                    )
+                    .with_rotation(self.viewport.rotation()),
+                    self.safe_area,
+                    self.scale_factor * self.zoom,
+                );
+
+                self.viewport_version = self.viewport_version.wrapping_add(1);
+            }
+            WindowEvent::CursorMoved { position, .. }
+            | WindowEvent::Touch(Touch {
+                location: position, ..
+            }) => {
+                self.cursor_position = *position;
+            }
+            WindowEvent::CursorLeft { .. } => {
+                // TODO: Encode cursor availability in the type-system
+                self.cursor_position =
+                    winit::dpi::PhysicalPosition::new(-1.0, -1.0);
+            }
+            WindowEvent::ModifiersChanged(new_modifiers) => {
+                self.modifiers = *new_modifiers;
+            }
+            WindowEvent::ThemeChanged(theme) => {
+                self.color_scheme = Some(conversion::color_scheme(*theme));
+            }
+            // Only Windows notifies changes of the color scheme, so it is
+            // detected again every time the window gains focus elsewhere
+            #[cfg(all(not(target_os = "windows"), feature = "system_theme"))]
+            WindowEvent::Focused(true) => {
+                self.color_scheme = crate::application::color_scheme(window);
+            }
+            #[cfg(feature = "debug")]
+            WindowEvent::KeyboardInput {
+                input:
+                    winit::event::KeyboardInput {
+                        virtual_keycode: Some(winit::event::VirtualKeyCode::F12),
+                        state: winit::event::ElementState::Pressed,
+                        ..
+                    },
+                ..
+            } => _debug.toggle(),
+            _ => {}
+        }
+    }
+
+    /// Synchronizes the [`WindowState`] and its window with the provided
+    /// [`Appearance`].
+    pub fn synchronize(&mut self, appearance: Appearance, window: &Window) {
+        // Update window title
+        if self.title != appearance.title {
+            window.set_title(&appearance.title);
+
+            self.title = appearance.title;
+        }
+
+        // Update window mode
+        if self.mode != appearance.mode {
+            window.set_fullscreen(conversion::fullscreen(
+                window.current_monitor(),
+                appearance.mode,
+            ));
+
+            window.set_visible(conversion::visible(appearance.mode));
+
+            self.mode = appearance.mode;
+        }
+
+        // Update theme and background color
+        if self.theme != appearance.theme {
+            self.theme_transition =
+                appearance.theme_transition.map(|duration| {
+                    Transition::new(
+                        self.theme().palette(),
+                        self.background_color(),
+                        duration,
+                    )
+                });
+
+            self.theme = appearance.theme;
+        }
+
+        self.background_color = appearance.background_color;
+
+        // Update scale factor
+        if self.scale_factor != appearance.scale_factor {
+            let size = physical_size(window);
+
+            self.viewport = inset(
+                Viewport::with_physical_size(
+                    size,
+                    window.scale_factor() * appearance.scale_factor * self.zoom,
+                )
+                .with_rotation(self.viewport.rotation()),
+                self.safe_area,
+                appearance.scale_factor * self.zoom,
+            );
+
+            self.scale_factor = appearance.scale_factor;
+        }
+
+        // Update rotation
+        if self.viewport.rotation() != appearance.rotation {
+            self.viewport =
+                self.viewport.clone().with_rotation(appearance.rotation);
+            self.viewport_version = self.viewport_version.wrapping_add(1);
+        }
+    }
+}
+
+/// Returns the physical size of the surface of the window.
+///
+/// On iOS, the inner size of a window is its safe area, while its surface
+/// covers the whole screen.
+fn physical_size(window: &Window) -> Size<u32> {
+    #[cfg(target_os = "ios")]
+    let size = window.outer_size();
+
+    #[cfg(not(target_os = "ios"))]
+    let size = window.inner_size();
+
+    Size::new(size.width, size.height)
+}
+
+/// Insets the contents of the [`Viewport`] by the logical safe area of the
+/// window, converted to the scale factor applied on top of the one of the
+/// window.
+fn inset(
+    viewport: Viewport,
+    safe_area: Padding,
+    scale_factor: f64,
+) -> Viewport {
+    let inset = |value: u16| (f64::from(value) / scale_factor).round() as u16;
+
+    viewport.with_padding(Padding {
+        top: inset(safe_area.top),
+        right: inset(safe_area.right),
+        bottom: inset(safe_area.bottom),
+        left: inset(safe_area.left),
+    })
+}
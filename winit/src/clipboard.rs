@@ -2,12 +2,20 @@
 pub use iced_native::clipboard::Action;
 
 use crate::command::{self, Command};
+use crate::image;
+
+use std::cell::RefCell;
+use std::path::PathBuf;
 
 /// A buffer for short-term storage and transfer within and between
 /// applications.
+///
+/// Text is exchanged through the clipboard of the window, while images, HTML,
+/// files and the primary selection use the clipboard of the system.
 #[allow(missing_debug_implementations)]
 pub struct Clipboard {
     state: State,
+    system: Option<RefCell<arboard::Clipboard>>,
 }
 
 enum State {
@@ -23,7 +31,19 @@ impl Clipboard {
             .map(State::Connected)
             .unwrap_or(State::Unavailable);
 
-        Clipboard { state }
+        let system = match arboard::Clipboard::new() {
+            Ok(clipboard) => Some(RefCell::new(clipboard)),
+            Err(error) => {
+                log::warn!(
+                    "error connecting to the system clipboard: {}",
+                    error
+                );
+
+                None
+            }
+        };
+
+        Clipboard { state, system }
     }
 
     /// Reads the current content of the [`Clipboard`] as text.
@@ -46,6 +66,191 @@ impl Clipboard {
             State::Unavailable => {}
         }
     }
+
+    /// Reads the current content of the primary selection as text.
+    ///
+    /// _**Note:** The primary selection is only available on Linux. On
+    /// Wayland, it needs a compositor that supports the data control
+    /// protocol or XWayland._
+    pub fn read_primary(&self) -> Option<String> {
+        self.with_system(primary::read)
+    }
+
+    /// Writes the given text contents to the primary selection.
+    ///
+    /// _**Note:** The primary selection is only available on Linux. On
+    /// Wayland, it needs a compositor that supports the data control
+    /// protocol or XWayland._
+    pub fn write_primary(&mut self, contents: String) {
+        let _ =
+            self.with_system(|clipboard| primary::write(clipboard, contents));
+    }
+
+    /// Reads the current content of the [`Clipboard`] as an image.
+    pub fn read_image(&self) -> Option<image::Handle> {
+        let image = self.with_system(|clipboard| clipboard.get_image())?;
+
+        // The pixels of an image handle are in BGRA format
+        let mut pixels = image.bytes.into_owned();
+        pixels
+            .chunks_exact_mut(4)
+            .for_each(|pixel| pixel.swap(0, 2));
+
+        Some(image::Handle::from_pixels(
+            image.width as u32,
+            image.height as u32,
+            pixels,
+        ))
+    }
+
+    /// Writes the given image to the [`Clipboard`].
+    pub fn write_image(&mut self, image: image::Handle) {
+        match decode(&image) {
+            Ok(image) => {
+                let _ =
+                    self.with_system(|clipboard| clipboard.set_image(image));
+            }
+            Err(error) => {
+                log::warn!("error decoding image for clipboard: {}", error)
+            }
+        }
+    }
+
+    /// Reads the current content of the [`Clipboard`] as HTML.
+    pub fn read_html(&self) -> Option<String> {
+        self.with_system(|clipboard| clipboard.get().html())
+    }
+
+    /// Writes the given HTML to the [`Clipboard`], along with an alternative
+    /// text for the applications that cannot paste HTML.
+    pub fn write_html(&mut self, html: String, alt_text: String) {
+        let _ = self.with_system(|clipboard| {
+            clipboard.set().html(html, Some(alt_text))
+        });
+    }
+
+    /// Reads the current content of the [`Clipboard`] as a list of files.
+    pub fn read_files(&self) -> Option<Vec<PathBuf>> {
+        self.with_system(|clipboard| clipboard.get().file_list())
+    }
+
+    /// Writes the given list of files to the [`Clipboard`].
+    pub fn write_files(&mut self, files: Vec<PathBuf>) {
+        let _ = self.with_system(|clipboard| clipboard.set().file_list(&files));
+    }
+
+    /// Runs the given operation on the clipboard of the system, if it is
+    /// available, logging any error other than missing contents.
+    fn with_system<T>(
+        &self,
+        f: impl FnOnce(&mut arboard::Clipboard) -> Result<T, arboard::Error>,
+    ) -> Option<T> {
+        let mut clipboard = self.system.as_ref()?.borrow_mut();
+
+        match f(&mut clipboard) {
+            Ok(value) => Some(value),
+            Err(
+                arboard::Error::ContentNotAvailable
+                | arboard::Error::ClipboardNotSupported,
+            ) => None,
+            Err(error) => {
+                log::warn!("error accessing the system clipboard: {}", error);
+
+                None
+            }
+        }
+    }
+}
+
+/// Decodes the pixels of the given image [`Handle`] in RGBA format.
+///
+/// [`Handle`]: image::Handle
+pub(crate) fn decode(
+    handle: &image::Handle,
+) -> Result<arboard::ImageData<'static>, image_rs::ImageError> {
+    let (width, height, bytes) = match handle.data() {
+        image::Data::Pixels {
+            width,
+            height,
+            pixels,
+        } => {
+            let mut bytes = pixels.clone();
+            bytes.chunks_exact_mut(4).for_each(|pixel| pixel.swap(0, 2));
+
+            (*width, *height, bytes)
+        }
+        image::Data::Path(path) => {
+            let image = image_rs::open(path)?.into_rgba8();
+
+            (image.width(), image.height(), image.into_raw())
+        }
+        image::Data::Bytes(bytes) => {
+            let image = image_rs::load_from_memory(bytes)?.into_rgba8();
+
+            (image.width(), image.height(), image.into_raw())
+        }
+    };
+
+    Ok(arboard::ImageData {
+        width: width as usize,
+        height: height as usize,
+        bytes: bytes.into(),
+    })
+}
+
+#[cfg(all(
+    unix,
+    not(any(
+        target_os = "macos",
+        target_os = "android",
+        target_os = "emscripten"
+    ))
+))]
+mod primary {
+    use arboard::{
+        Clipboard, Error, GetExtLinux, LinuxClipboardKind, SetExtLinux,
+    };
+
+    pub fn read(clipboard: &mut Clipboard) -> Result<String, Error> {
+        clipboard
+            .get()
+            .clipboard(LinuxClipboardKind::Primary)
+            .text()
+    }
+
+    pub fn write(
+        clipboard: &mut Clipboard,
+        contents: String,
+    ) -> Result<(), Error> {
+        clipboard
+            .set()
+            .clipboard(LinuxClipboardKind::Primary)
+            .text(contents)
+    }
+}
+
+#[cfg(not(all(
+    unix,
+    not(any(
+        target_os = "macos",
+        target_os = "android",
+        target_os = "emscripten"
+    ))
+)))]
+mod primary {
+    //! Other platforms have no primary selection.
+    use arboard::{Clipboard, Error};
+
+    pub fn read(_clipboard: &mut Clipboard) -> Result<String, Error> {
+        Err(Error::ClipboardNotSupported)
+    }
+
+    pub fn write(
+        _clipboard: &mut Clipboard,
+        _contents: String,
+    ) -> Result<(), Error> {
+        Err(Error::ClipboardNotSupported)
+    }
 }
 
 impl iced_native::Clipboard for Clipboard {
@@ -56,6 +261,38 @@ impl iced_native::Clipboard for Clipboard {
     fn write(&mut self, contents: String) {
         self.write(contents)
     }
+
+    fn read_primary(&self) -> Option<String> {
+        self.read_primary()
+    }
+
+    fn write_primary(&mut self, contents: String) {
+        self.write_primary(contents)
+    }
+
+    fn read_image(&self) -> Option<image::Handle> {
+        self.read_image()
+    }
+
+    fn write_image(&mut self, image: image::Handle) {
+        self.write_image(image)
+    }
+
+    fn read_html(&self) -> Option<String> {
+        self.read_html()
+    }
+
+    fn write_html(&mut self, html: String, alt_text: String) {
+        self.write_html(html, alt_text)
+    }
+
+    fn read_files(&self) -> Option<Vec<PathBuf>> {
+        self.read_files()
+    }
+
+    fn write_files(&mut self, files: Vec<PathBuf>) {
+        self.write_files(files)
+    }
 }
 
 /// Read the current contents of the clipboard.
@@ -69,3 +306,63 @@ pub fn read<Message>(
 pub fn write<Message>(contents: String) -> Command<Message> {
     Command::single(command::Action::Clipboard(Action::Write(contents)))
 }
+
+/// Read the current contents of the primary selection.
+///
+/// The primary selection holds the text selected last, and it is usually
+/// pasted with the middle mouse button. It is only available on Linux.
+///
+/// _**Note:** On Wayland, the primary selection needs a compositor that
+/// supports the data control protocol or XWayland._
+pub fn read_primary<Message>(
+    f: impl Fn(Option<String>) -> Message + 'static,
+) -> Command<Message> {
+    Command::single(command::Action::Clipboard(Action::ReadPrimary(Box::new(
+        f,
+    ))))
+}
+
+/// Write the given contents to the primary selection.
+pub fn write_primary<Message>(contents: String) -> Command<Message> {
+    Command::single(command::Action::Clipboard(Action::WritePrimary(contents)))
+}
+
+/// Read the current contents of the clipboard as an image.
+pub fn read_image<Message>(
+    f: impl Fn(Option<image::Handle>) -> Message + 'static,
+) -> Command<Message> {
+    Command::single(command::Action::Clipboard(Action::ReadImage(Box::new(f))))
+}
+
+/// Write the given image to the clipboard.
+pub fn write_image<Message>(image: image::Handle) -> Command<Message> {
+    Command::single(command::Action::Clipboard(Action::WriteImage(image)))
+}
+
+/// Read the current contents of the clipboard as HTML.
+pub fn read_html<Message>(
+    f: impl Fn(Option<String>) -> Message + 'static,
+) -> Command<Message> {
+    Command::single(command::Action::Clipboard(Action::ReadHtml(Box::new(f))))
+}
+
+/// Write the given HTML to the clipboard, along with an alternative text for
+/// the applications that cannot paste HTML.
+pub fn write_html<Message>(html: String, alt_text: String) -> Command<Message> {
+    Command::single(command::Action::Clipboard(Action::WriteHtml {
+        html,
+        alt_text,
+    }))
+}
+
+/// Read the current contents of the clipboard as a list of files.
+pub fn read_files<Message>(
+    f: impl Fn(Option<Vec<PathBuf>>) -> Message + 'static,
+) -> Command<Message> {
+    Command::single(command::Action::Clipboard(Action::ReadFiles(Box::new(f))))
+}
+
+/// Write the given list of files to the clipboard.
+pub fn write_files<Message>(files: Vec<PathBuf>) -> Command<Message> {
+    Command::single(command::Action::Clipboard(Action::WriteFiles(files)))
+}
@@ -2,11 +2,25 @@
 //!
 //! [`winit`]: https://github.com/rust-windowing/winit
 //! [`iced_native`]: https://github.com/hecrj/iced/tree/master/native
+#[cfg(feature = "accesskit")]
+use crate::accessibility;
+use crate::image;
 use crate::keyboard;
 use crate::mouse;
+use crate::pen;
 use crate::touch;
 use crate::window;
-use crate::{Event, Mode, Point, Position};
+use crate::{Event, Mode, Padding, Point, Position, Vector, Viewport};
+
+/// Converts the raw motion of a winit mouse device into an iced event.
+///
+/// This motion is only reported to the user interface while the pointer is
+/// locked.
+pub fn mouse_motion(delta: (f64, f64)) -> Event {
+    Event::Mouse(mouse::Event::Motion {
+        delta: Vector::new(delta.0 as f32, delta.1 as f32),
+    })
+}
 
 /// Converts a winit window event into an iced event.
 pub fn window_event(
@@ -36,6 +50,9 @@ pub fn window_event(
         WindowEvent::CloseRequested => {
             Some(Event::Window(window::Event::CloseRequested))
         }
+        WindowEvent::ThemeChanged(theme) => Some(Event::Window(
+            window::Event::ColorSchemeChanged(color_scheme(*theme)),
+        )),
         WindowEvent::CursorMoved { position, .. } => {
             let position = position.to_logical::<f64>(scale_factor);
 
@@ -197,6 +214,146 @@ pub fn fullscreen(
     }
 }
 
+/// Converts a [`winit`] monitor into a [`window::Monitor`].
+///
+/// [`winit`]: https://github.com/rust-windowing/winit
+pub fn monitor(
+    monitor: &winit::monitor::MonitorHandle,
+    is_primary: bool,
+) -> window::Monitor {
+    let position = monitor.position();
+    let size = monitor.size();
+
+    let refresh_rate = monitor
+        .video_modes()
+        .filter(|mode| mode.size() == size)
+        .map(|mode| mode.refresh_rate())
+        .max();
+
+    window::Monitor {
+        name: monitor.name(),
+        position: (position.x, position.y),
+        size: (size.width, size.height),
+        scale_factor: monitor.scale_factor(),
+        refresh_rate,
+        is_primary,
+    }
+}
+
+/// Converts an [`image::Handle`] into a [`winit`] icon.
+///
+/// Only a handle created from decoded pixels can be converted.
+///
+/// [`winit`]: https://github.com/rust-windowing/winit
+pub fn icon(handle: &image::Handle) -> Option<winit::window::Icon> {
+    match handle.data() {
+        image::Data::Pixels {
+            width,
+            height,
+            pixels,
+        } => {
+            // The pixels of a handle are stored in BGRA
+            let rgba = pixels
+                .chunks_exact(4)
+                .flat_map(|pixel| [pixel[2], pixel[1], pixel[0], pixel[3]])
+                .collect();
+
+            winit::window::Icon::from_rgba(rgba, *width, *height).ok()
+        }
+        _ => None,
+    }
+}
+
+/// Converts a [`window::UserAttention`] into a [`winit`] user attention
+/// type.
+///
+/// [`winit`]: https://github.com/rust-windowing/winit
+pub fn user_attention(
+    attention: window::UserAttention,
+) -> winit::window::UserAttentionType {
+    match attention {
+        window::UserAttention::Critical => {
+            winit::window::UserAttentionType::Critical
+        }
+        window::UserAttention::Informational => {
+            winit::window::UserAttentionType::Informational
+        }
+    }
+}
+
+/// Converts a [`winit`] theme into a [`window::ColorScheme`].
+///
+/// [`winit`]: https://github.com/rust-windowing/winit
+pub fn color_scheme(theme: winit::window::Theme) -> window::ColorScheme {
+    match theme {
+        winit::window::Theme::Light => window::ColorScheme::Light,
+        winit::window::Theme::Dark => window::ColorScheme::Dark,
+    }
+}
+
+/// Returns the logical insets of the safe area of the given window.
+///
+/// The safe area is only reported on iOS and Android. Other platforms always
+/// return [`Padding::ZERO`].
+pub fn safe_area(window: &winit::window::Window, scale_factor: f64) -> Padding {
+    #[cfg(target_os = "ios")]
+    {
+        // On iOS, the inner area of a window is its safe area
+        let (outer_position, inner_position) =
+            match (window.outer_position(), window.inner_position()) {
+                (Ok(outer), Ok(inner)) => (outer, inner),
+                _ => return Padding::ZERO,
+            };
+
+        let outer_size = window.outer_size();
+        let inner_size = window.inner_size();
+
+        let top = (inner_position.y - outer_position.y).max(0) as f64;
+        let left = (inner_position.x - outer_position.x).max(0) as f64;
+        let bottom =
+            (outer_size.height as f64 - inner_size.height as f64 - top)
+                .max(0.0);
+        let right =
+            (outer_size.width as f64 - inner_size.width as f64 - left).max(0.0);
+
+        let logical = |inset: f64| (inset / scale_factor).round() as u16;
+
+        Padding {
+            top: logical(top),
+            right: logical(right),
+            bottom: logical(bottom),
+            left: logical(left),
+        }
+    }
+
+    #[cfg(target_os = "android")]
+    {
+        use winit::platform::android::WindowExtAndroid;
+
+        // On Android, the content rectangle of a window excludes the system
+        // bars and the display cutouts
+        let content = window.content_rect();
+        let size = window.inner_size();
+
+        let logical =
+            |inset: f64| (inset.max(0.0) / scale_factor).round() as u16;
+
+        Padding {
+            top: logical(content.top as f64),
+            right: logical(size.width as f64 - content.right as f64),
+            bottom: logical(size.height as f64 - content.bottom as f64),
+            left: logical(content.left as f64),
+        }
+    }
+
+    #[cfg(not(any(target_os = "ios", target_os = "android")))]
+    {
+        let _ = (window, scale_factor);
+
+        Padding::ZERO
+    }
+}
+
 /// Converts a [`Mode`] to a visibility flag.
 pub fn visible(mode: Mode) -> bool {
     match mode {
@@ -226,6 +383,23 @@ pub fn mouse_interaction(
             winit::window::CursorIcon::EwResize
         }
         Interaction::ResizingVertically => winit::window::CursorIcon::NsResize,
+        Interaction::ResizingDiagonallyUp => {
+            winit::window::CursorIcon::NeswResize
+        }
+        Interaction::ResizingDiagonallyDown => {
+            winit::window::CursorIcon::NwseResize
+        }
+        Interaction::Move => winit::window::CursorIcon::Move,
+        Interaction::NotAllowed => winit::window::CursorIcon::NotAllowed,
+        Interaction::Help => winit::window::CursorIcon::Help,
+        Interaction::ContextMenu => winit::window::CursorIcon::ContextMenu,
+        Interaction::Copy => winit::window::CursorIcon::Copy,
+        Interaction::Alias => winit::window::CursorIcon::Alias,
+        Interaction::Cell => winit::window::CursorIcon::Cell,
+        Interaction::ZoomIn => winit::window::CursorIcon::ZoomIn,
+        Interaction::ZoomOut => winit::window::CursorIcon::ZoomOut,
+        // The cursor is hidden by the runtime instead
+        Interaction::Hidden => winit::window::CursorIcon::Default,
     }
 }
 
@@ -272,6 +446,76 @@ pub fn cursor_position(
     Point::new(logical_position.x, logical_position.y)
 }
 
+/// Converts the positions and sizes of an [`Event`] produced in a window
+/// into the rotated contents of the given [`Viewport`].
+pub fn rotate(event: Event, viewport: &Viewport) -> Event {
+    let rotate = |position| viewport.rotate_position(position);
+
+    match event {
+        Event::Window(window::Event::Resized { width, height })
+            if viewport.rotation().is_quarter_turn() =>
+        {
+            Event::Window(window::Event::Resized {
+                width: height,
+                height: width,
+            })
+        }
+        Event::Mouse(mouse::Event::CursorMoved { position }) => {
+            Event::Mouse(mouse::Event::CursorMoved {
+                position: rotate(position),
+            })
+        }
+        Event::Mouse(mouse::Event::Motion { delta }) => {
+            Event::Mouse(mouse::Event::Motion {
+                delta: rotate(Point::ORIGIN + delta) - rotate(Point::ORIGIN),
+            })
+        }
+        Event::Touch(touch::Event::FingerPressed { id, position }) => {
+            Event::Touch(touch::Event::FingerPressed {
+                id,
+                position: rotate(position),
+            })
+        }
+        Event::Touch(touch::Event::FingerMoved { id, position }) => {
+            Event::Touch(touch::Event::FingerMoved {
+                id,
+                position: rotate(position),
+            })
+        }
+        Event::Touch(touch::Event::FingerLifted { id, position }) => {
+            Event::Touch(touch::Event::FingerLifted {
+                id,
+                position: rotate(position),
+            })
+        }
+        Event::Touch(touch::Event::FingerLost { id, position }) => {
+            Event::Touch(touch::Event::FingerLost {
+                id,
+                position: rotate(position),
+            })
+        }
+        Event::Pen(pen::Event::Pressed(sample)) => {
+            Event::Pen(pen::Event::Pressed(pen::Sample {
+                position: rotate(sample.position),
+                ..sample
+            }))
+        }
+        Event::Pen(pen::Event::Moved(sample)) => {
+            Event::Pen(pen::Event::Moved(pen::Sample {
+                position: rotate(sample.position),
+                ..sample
+            }))
+        }
+        Event::Pen(pen::Event::Lifted(sample)) => {
+            Event::Pen(pen::Event::Lifted(pen::Sample {
+                position: rotate(sample.position),
+                ..sample
+            }))
+        }
+        _ => event,
+    }
+}
+
 /// Converts a `Touch` from [`winit`] to an [`iced_native`] touch event.
 ///
 /// [`winit`]: https://github.com/rust-windowing/winit
@@ -303,6 +547,51 @@ pub fn touch_event(
     }
 }
 
+/// Converts a `Touch` from [`winit`] to an [`iced_native`] pen event, if it
+/// was produced by a stylus.
+///
+/// Only a stylus reports its altitude, so any other touch is ignored. The
+/// normalized force reported by Windows is ignored too, since fingers report
+/// it as well.
+///
+/// _**Note:** `winit` does not report erasers, barrel buttons, nor the
+/// azimuth of a stylus yet._
+///
+/// [`winit`]: https://github.com/rust-windowing/winit
+/// [`iced_native`]: https://github.com/hecrj/iced/tree/master/native
+pub fn pen_event(
+    touch: winit::event::Touch,
+    scale_factor: f64,
+) -> Option<pen::Event> {
+    let (pressure, altitude) = match touch.force? {
+        winit::event::Force::Calibrated {
+            force,
+            max_possible_force,
+            altitude_angle,
+        } => (force / max_possible_force, altitude_angle?),
+        winit::event::Force::Normalized(_) => return None,
+    };
+
+    let location = touch.location.to_logical::<f64>(scale_factor);
+
+    let sample = pen::Sample {
+        position: Point::new(location.x as f32, location.y as f32),
+        pressure: pressure.clamp(0.0, 1.0) as f32,
+        tilt: Some(pen::Tilt {
+            altitude: altitude as f32,
+            azimuth: None,
+        }),
+        tool: pen::Tool::Pen,
+    };
+
+    Some(match touch.phase {
+        winit::event::TouchPhase::Started => pen::Event::Pressed(sample),
+        winit::event::TouchPhase::Moved => pen::Event::Moved(sample),
+        winit::event::TouchPhase::Ended => pen::Event::Lifted(sample),
+        winit::event::TouchPhase::Cancelled => pen::Event::Lost,
+    })
+}
+
 /// Converts a `VirtualKeyCode` from [`winit`] to an [`iced_native`] key code.
 ///
 /// [`winit`]: https://github.com/rust-windowing/winit
@@ -492,3 +781,134 @@ pub(crate) fn is_private_use_character(c: char) -> bool {
         _ => false,
     }
 }
+
+/// Converts an accessibility tree into an AccessKit tree update.
+///
+/// The nodes of the tree become the children of a window node named after
+/// the given title. Their bounds are converted to physical pixels.
+#[cfg(feature = "accesskit")]
+pub fn tree_update(
+    tree: &accessibility::Tree,
+    title: &str,
+    scale_factor: f64,
+) -> accesskit::TreeUpdate {
+    use accesskit::{NodeId, Rect, Role, Toggled};
+
+    let root = NodeId(0);
+
+    let mut window = accesskit::Node::new(Role::Window);
+    window.set_label(title);
+
+    let mut nodes = Vec::with_capacity(tree.nodes().len() + 1);
+
+    for (index, node) in tree.nodes().iter().enumerate() {
+        let id = NodeId(index as u64 + 1);
+
+        let mut update = accesskit::Node::new(match node.role {
+            accessibility::Role::Button => Role::Button,
+            accessibility::Role::CheckBox => Role::CheckBox,
+            accessibility::Role::Label => Role::Label,
+            accessibility::Role::ScrollView => Role::ScrollView,
+            accessibility::Role::Slider => Role::Slider,
+            accessibility::Role::TextInput => Role::TextInput,
+        });
+
+        update.set_bounds(Rect {
+            x0: f64::from(node.bounds.x) * scale_factor,
+            y0: f64::from(node.bounds.y) * scale_factor,
+            x1: f64::from(node.bounds.x + node.bounds.width) * scale_factor,
+            y1: f64::from(node.bounds.y + node.bounds.height) * scale_factor,
+        });
+
+        if let Some(label) = &node.label {
+            // The text of a label is its value for AccessKit
+            if node.role == accessibility::Role::Label {
+                update.set_value(label.as_str());
+            } else {
+                update.set_label(label.as_str());
+            }
+        }
+
+        match &node.value {
+            Some(accessibility::Value::Text(text)) => {
+                update.set_value(text.as_str());
+            }
+            Some(accessibility::Value::Number { value, min, max }) => {
+                update.set_numeric_value(*value);
+                update.set_min_numeric_value(*min);
+                update.set_max_numeric_value(*max);
+            }
+            Some(accessibility::Value::Toggled(is_toggled)) => {
+                update.set_toggled(if *is_toggled {
+                    Toggled::True
+                } else {
+                    Toggled::False
+                });
+            }
+            None => {}
+        }
+
+        if node.is_disabled {
+            update.set_disabled();
+        }
+
+        if node.is_focusable {
+            update.add_action(accesskit::Action::Focus);
+        }
+
+        if node.is_invokable {
+            update.add_action(accesskit::Action::Click);
+        }
+
+        if node.is_editable {
+            update.add_action(accesskit::Action::SetValue);
+        }
+
+        update.add_action(accesskit::Action::ScrollIntoView);
+
+        window.push_child(id);
+        nodes.push((id, update));
+    }
+
+    nodes.insert(0, (root, window));
+
+    accesskit::TreeUpdate {
+        nodes,
+        tree: Some(accesskit::Tree::new(root)),
+        focus: tree
+            .focus()
+            .map(|index| NodeId(index as u64 + 1))
+            .unwrap_or(root),
+    }
+}
+
+/// Converts an AccessKit action request into an iced event targeting the
+/// widget that published the corresponding node of the accessibility tree.
+#[cfg(feature = "accesskit")]
+pub fn accessibility_request(
+    tree: &accessibility::Tree,
+    request: &accesskit::ActionRequest,
+) -> Option<Event> {
+    use accesskit::ActionData;
+    use std::convert::TryFrom;
+
+    let index = usize::try_from(request.target.0).ok()?.checked_sub(1)?;
+
+    let action = match (request.action, &request.data) {
+        (accesskit::Action::Focus, _) => accessibility::Action::Focus,
+        (accesskit::Action::Click, _) => accessibility::Action::Invoke,
+        (accesskit::Action::SetValue, Some(ActionData::Value(value))) => {
+            accessibility::Action::SetValue(value.to_string())
+        }
+        (
+            accesskit::Action::SetValue,
+            Some(ActionData::NumericValue(value)),
+        ) => accessibility::Action::SetNumericValue(*value),
+        (accesskit::Action::ScrollIntoView, _) => {
+            accessibility::Action::ScrollIntoView
+        }
+        _ => return None,
+    };
+
+    tree.request(index, action)
+}
@@ -0,0 +1,406 @@
+//! Run the logic of an [`Application`] without a window.
+//!
+//! A [`Headless`] runtime drives [`Application::update`], its commands and
+//! its subscriptions without creating a window or a graphics device. Time is
+//! controlled manually, so the message flows of an [`Application`] can be
+//! tested deterministically; in CI, for instance.
+use crate::clipboard;
+use crate::command::{self, Command, Proxy};
+use crate::dnd;
+use crate::event::{self, Event};
+use crate::window;
+use crate::{Application, Executor, Runtime};
+
+use iced_futures::futures;
+use iced_futures::futures::channel::mpsc;
+use iced_futures::futures::executor::{LocalPool, LocalSpawner};
+use iced_futures::futures::task::SpawnExt;
+use iced_futures::futures::{FutureExt, StreamExt};
+
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// A runtime that runs an [`Application`] without a window.
+///
+/// Every command of the [`Application`] runs in the current thread, until
+/// it stalls. Window and widget actions are ignored, and the clipboard is
+/// kept in memory.
+///
+/// Subscriptions that depend on a specific executor, like `time::every`,
+/// will not produce any messages. Use [`window::frames`] and
+/// [`Headless::advance`] to drive time instead.
+///
+/// [`window::frames`]: crate::window::frames
+#[allow(missing_debug_implementations)]
+pub struct Headless<A: Application> {
+    application: A,
+    runtime: Runtime<Spawner, mpsc::UnboundedSender<A::Message>, A::Message>,
+    pool: LocalPool,
+    sender: mpsc::UnboundedSender<A::Message>,
+    receiver: mpsc::UnboundedReceiver<A::Message>,
+    clipboard: Memory,
+    now: Instant,
+    updates: usize,
+}
+
+impl<A> Headless<A>
+where
+    A: Application + 'static,
+{
+    /// Creates a new [`Headless`] runtime for the [`Application`] with the
+    /// given flags.
+    ///
+    /// The initial command of the [`Application`] is run until it stalls.
+    pub fn new(flags: A::Flags) -> Self {
+        let pool = LocalPool::new();
+        let (sender, receiver) = mpsc::unbounded();
+
+        let runtime = Runtime::new(
+            Spawner {
+                spawner: pool.spawner(),
+            },
+            sender.clone(),
+        );
+
+        let (application, command) = A::new(flags);
+
+        let mut headless = Self {
+            application,
+            runtime,
+            pool,
+            sender,
+            receiver,
+            clipboard: Memory::default(),
+            now: Instant::now(),
+            updates: 0,
+        };
+
+        headless.run_command(command);
+        headless.runtime.track(headless.application.subscription());
+        headless.settle();
+
+        headless
+    }
+
+    /// Returns a reference to the [`Application`].
+    pub fn application(&self) -> &A {
+        &self.application
+    }
+
+    /// Returns the current time of the [`Headless`] runtime.
+    pub fn now(&self) -> Instant {
+        self.now
+    }
+
+    /// Returns the amount of messages processed by the [`Application`] so
+    /// far.
+    pub fn updates(&self) -> usize {
+        self.updates
+    }
+
+    /// Returns the current contents of the clipboard.
+    pub fn clipboard(&self) -> Option<&str> {
+        self.clipboard.contents.as_deref()
+    }
+
+    /// Returns true if the [`Application`] wants to exit.
+    pub fn should_exit(&self) -> bool {
+        self.application.should_exit()
+    }
+
+    /// Updates the [`Application`] with the given message and runs the
+    /// resulting command until it stalls.
+    pub fn update(&mut self, message: A::Message) {
+        self.process(message);
+        self.settle();
+    }
+
+    /// Broadcasts the given [`Event`] to the subscriptions of the
+    /// [`Application`] and runs until it stalls.
+    pub fn broadcast(&mut self, event: Event) {
+        self.runtime.broadcast((
+            window::Id::MAIN,
+            event,
+            event::Status::Ignored,
+        ));
+
+        self.settle();
+    }
+
+    /// Advances the time of the [`Headless`] runtime by the given
+    /// [`Duration`] and runs until it stalls.
+    ///
+    /// A new frame is presented at the new time; therefore, subscriptions
+    /// like [`window::frames`] produce a message.
+    ///
+    /// [`window::frames`]: crate::window::frames
+    pub fn advance(&mut self, duration: Duration) {
+        self.now += duration;
+
+        self.broadcast(Event::Window(window::Event::RedrawRequested(self.now)));
+
+        self.broadcast(Event::Window(window::Event::FramePresented(self.now)));
+    }
+
+    /// Runs the pending commands and subscriptions of the [`Application`]
+    /// until they stall, processing every message they produce.
+    pub fn settle(&mut self) {
+        loop {
+            self.pool.run_until_stalled();
+
+            let mut messages = Vec::new();
+
+            while let Some(Some(message)) = self.receiver.next().now_or_never()
+            {
+                messages.push(message);
+            }
+
+            if messages.is_empty() {
+                break;
+            }
+
+            for message in messages {
+                self.process(message);
+            }
+        }
+    }
+
+    fn process(&mut self, message: A::Message) {
+        let application = &mut self.application;
+        let command = self.runtime.enter(|| application.update(message));
+
+        self.updates += 1;
+        self.run_command(command);
+        self.runtime.track(self.application.subscription());
+    }
+
+    fn run_command(&mut self, command: Command<A::Message>) {
+        for action in command.actions() {
+            match action {
+                command::Action::Future(future)
+                | command::Action::Background(future) => {
+                    self.runtime.spawn(future);
+                }
+                command::Action::Stream(stream) => {
+                    self.runtime.run(stream);
+                }
+                command::Action::Clipboard(action) => {
+                    if let Some(message) = self.clipboard.run(action) {
+                        let _ = self.sender.unbounded_send(message);
+                    }
+                }
+                command::Action::Window(_)
+                | command::Action::Tray(_)
+                | command::Action::Screenshot(..)
+                | command::Action::Widget(_) => {}
+                command::Action::FetchMonitors(tag) => {
+                    let _ = self.sender.unbounded_send(tag(Vec::new()));
+                }
+                command::Action::FetchColorScheme(tag) => {
+                    let _ = self.sender.unbounded_send(tag(None));
+                }
+                command::Action::StartDrag(_, tag) => {
+                    let _ = self
+                        .sender
+                        .unbounded_send(tag(Err(dnd::Error::Unsupported)));
+                }
+                command::Action::FetchProxy(tag) => {
+                    let sender = Mutex::new(self.sender.clone());
+
+                    let proxy = Proxy::new(move |message| {
+                        sender
+                            .lock()
+                            .map_err(|_| command::Closed)?
+                            .unbounded_send(message)
+                            .map_err(|_| command::Closed)
+                    });
+
+                    let _ = self.sender.unbounded_send(tag(proxy));
+                }
+            }
+        }
+    }
+}
+
+/// An [`Executor`] that spawns futures in a [`LocalPool`].
+#[derive(Debug)]
+struct Spawner {
+    spawner: LocalSpawner,
+}
+
+impl Executor for Spawner {
+    fn new() -> Result<Self, futures::io::Error> {
+        // A `Spawner` can only be created from a `LocalPool`
+        Err(futures::io::ErrorKind::Unsupported.into())
+    }
+
+    fn spawn(
+        &self,
+        future: impl futures::Future<Output = ()> + Send + 'static,
+    ) {
+        let _ = self.spawner.spawn(future);
+    }
+
+    fn spawn_background(
+        &self,
+        future: impl futures::Future<Output = ()> + Send + 'static,
+    ) {
+        self.spawn(future);
+    }
+}
+
+/// A clipboard kept in memory.
+#[derive(Debug, Default)]
+struct Memory {
+    contents: Option<String>,
+}
+
+impl Memory {
+    fn run<T>(&mut self, action: clipboard::Action<T>) -> Option<T> {
+        match action {
+            clipboard::Action::Read(tag)
+            | clipboard::Action::ReadPrimary(tag)
+            | clipboard::Action::ReadHtml(tag) => {
+                Some(tag(self.contents.clone()))
+            }
+            clipboard::Action::Write(contents)
+            | clipboard::Action::WritePrimary(contents)
+            | clipboard::Action::WriteHtml {
+                alt_text: contents, ..
+            } => {
+                self.contents = Some(contents);
+
+                None
+            }
+            clipboard::Action::ReadImage(tag) => Some(tag(None)),
+            clipboard::Action::ReadFiles(tag) => Some(tag(None)),
+            clipboard::Action::WriteImage(_)
+            | clipboard::Action::WriteFiles(_) => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::renderer::Null;
+    use crate::widget::Space;
+    use crate::{Element, Length, Program, Subscription};
+
+    #[derive(Debug, Default)]
+    struct Stopwatch {
+        laps: u32,
+        is_running: bool,
+        elapsed: Duration,
+        pasted: Option<String>,
+    }
+
+    #[derive(Debug, Clone)]
+    enum Message {
+        Loaded(u32),
+        Toggle,
+        Ticked(window::Frame),
+        Copy,
+        Pasted(Option<String>),
+    }
+
+    impl Program for Stopwatch {
+        type Renderer = Null;
+        type Message = Message;
+
+        fn update(&mut self, message: Message) -> Command<Message> {
+            match message {
+                Message::Loaded(laps) => {
+                    self.laps = laps;
+                }
+                Message::Toggle => {
+                    self.is_running = !self.is_running;
+                }
+                Message::Ticked(frame) => {
+                    self.elapsed += frame.delta;
+                }
+                Message::Copy => {
+                    return Command::batch([
+                        crate::clipboard::write(format!(
+                            "{}ms",
+                            self.elapsed.as_millis()
+                        )),
+                        crate::clipboard::read(Message::Pasted),
+                    ]);
+                }
+                Message::Pasted(contents) => {
+                    self.pasted = contents;
+                }
+            }
+
+            Command::none()
+        }
+
+        fn view(&mut self) -> Element<'_, Message, Null> {
+            Space::with_width(Length::Fill).into()
+        }
+    }
+
+    impl Application for Stopwatch {
+        type Flags = u32;
+
+        fn new(laps: u32) -> (Self, Command<Message>) {
+            (
+                Self::default(),
+                Command::perform(async move { laps }, Message::Loaded),
+            )
+        }
+
+        fn title(&self) -> String {
+            String::from("Stopwatch")
+        }
+
+        fn subscription(&self) -> Subscription<Message> {
+            if self.is_running {
+                window::frames().map(Message::Ticked)
+            } else {
+                Subscription::none()
+            }
+        }
+    }
+
+    #[test]
+    fn runs_the_initial_command() {
+        let headless = Headless::<Stopwatch>::new(3);
+
+        assert_eq!(headless.application().laps, 3);
+        assert_eq!(headless.updates(), 1);
+    }
+
+    #[test]
+    fn drives_subscriptions_with_the_time_of_the_runtime() {
+        let mut headless = Headless::<Stopwatch>::new(0);
+
+        headless.advance(Duration::from_millis(16));
+        assert_eq!(headless.application().elapsed, Duration::ZERO);
+
+        headless.update(Message::Toggle);
+
+        for _ in 0..3 {
+            headless.advance(Duration::from_millis(16));
+        }
+
+        assert_eq!(headless.application().elapsed, Duration::from_millis(32));
+
+        headless.update(Message::Toggle);
+        headless.advance(Duration::from_millis(16));
+
+        assert_eq!(headless.application().elapsed, Duration::from_millis(32));
+    }
+
+    #[test]
+    fn keeps_the_clipboard_in_memory() {
+        let mut headless = Headless::<Stopwatch>::new(0);
+
+        headless.update(Message::Copy);
+
+        assert_eq!(headless.clipboard(), Some("0ms"));
+        assert_eq!(headless.application().pasted.as_deref(), Some("0ms"));
+        assert_eq!(headless.updates(), 3);
+    }
+}
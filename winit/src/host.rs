@@ -0,0 +1,101 @@
+//! Run iced inside an event loop owned by your application.
+use crate::application;
+use crate::conversion;
+use crate::mouse;
+use crate::{Clipboard, Event, Size};
+
+use iced_native::window;
+use winit::event::WindowEvent;
+
+/// A [`winit`] window hosting an iced [`Program`].
+///
+/// Feed the events of your event loop to [`program::State::queue_host_event`]
+/// and process them with [`program::State::process`], using the
+/// [`Host::logical_size`] as bounds. Graphics compositors can draw into the
+/// [`Host::window`] directly.
+///
+/// [`Program`]: iced_native::Program
+/// [`program::State::queue_host_event`]: iced_native::program::State::queue_host_event
+/// [`program::State::process`]: iced_native::program::State::process
+#[allow(missing_debug_implementations)]
+pub struct Host {
+    window: winit::window::Window,
+    clipboard: Clipboard,
+    modifiers: winit::event::ModifiersState,
+    scale_factor: f64,
+    drag_resize: application::DragResize,
+}
+
+impl Host {
+    /// Creates a new [`Host`] for the given window.
+    pub fn new(window: winit::window::Window) -> Self {
+        let clipboard = Clipboard::connect(&window);
+        let scale_factor = window.scale_factor();
+
+        Self {
+            window,
+            clipboard,
+            modifiers: winit::event::ModifiersState::default(),
+            scale_factor,
+            drag_resize: application::DragResize::new(),
+        }
+    }
+
+    /// Returns the window of the [`Host`].
+    pub fn window(&self) -> &winit::window::Window {
+        &self.window
+    }
+
+    /// Returns the scale factor of the window of the [`Host`], as of the
+    /// last event it translated.
+    pub fn scale_factor(&self) -> f64 {
+        self.scale_factor
+    }
+
+    /// Returns the logical size of the window of the [`Host`].
+    pub fn logical_size(&self) -> Size {
+        let size = self.window.inner_size().to_logical(self.scale_factor);
+
+        Size::new(size.width, size.height)
+    }
+}
+
+impl iced_native::Host for Host {
+    type Event<'a> = WindowEvent<'a>;
+
+    fn translate(&mut self, event: &WindowEvent<'_>) -> Vec<Event> {
+        match event {
+            WindowEvent::ModifiersChanged(modifiers) => {
+                self.modifiers = *modifiers;
+            }
+            WindowEvent::ScaleFactorChanged { scale_factor, .. } => {
+                self.scale_factor = *scale_factor;
+            }
+            _ => {}
+        }
+
+        if self.drag_resize.update(&self.window, event) {
+            return Vec::new();
+        }
+
+        conversion::window_event(event, self.scale_factor, self.modifiers)
+            .into_iter()
+            .collect()
+    }
+
+    fn clipboard(&mut self) -> &mut dyn iced_native::Clipboard {
+        &mut self.clipboard
+    }
+
+    fn run_window_action(&mut self, action: window::Action) {
+        application::run_window_action(
+            action,
+            &self.window,
+            &mut self.drag_resize,
+        );
+    }
+
+    fn set_mouse_interaction(&mut self, interaction: mouse::Interaction) {
+        application::set_mouse_interaction(&self.window, interaction);
+    }
+}
@@ -0,0 +1,105 @@
+//! Show the progress of the application in the launchers and docks of Linux
+//! desktops, through the `com.canonical.Unity.LauncherEntry` D-Bus interface.
+//!
+//! The interface is implemented by the KDE Plasma task manager, the Dash to
+//! Dock and Ubuntu Dock extensions of GNOME, Plank, and others.
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+use std::sync::{mpsc, OnceLock};
+use std::thread;
+
+use zbus::blocking::Connection;
+use zbus::names::BusName;
+use zbus::zvariant::Value;
+
+/// The identifier of the desktop entry of the application.
+static DESKTOP_ID: OnceLock<String> = OnceLock::new();
+
+/// The progress updates sent to the thread that talks to the session bus.
+static UPDATES: OnceLock<mpsc::Sender<Option<f32>>> = OnceLock::new();
+
+/// Sets the identifier of the desktop entry of the application.
+///
+/// Only the first identifier is kept. When not set, the name of the
+/// executable is used instead.
+pub fn set_desktop_id(id: String) {
+    let _ = DESKTOP_ID.set(id);
+}
+
+/// Shows the given progress, from `0.0` to `1.0`, in the launcher entry of
+/// the application, or hides it.
+///
+/// The session bus is connected to once, in a background thread, so the
+/// event loop is never blocked.
+pub fn set_progress(progress: Option<f32>) {
+    let updates = UPDATES.get_or_init(|| {
+        let (sender, receiver) = mpsc::channel();
+
+        let spawned = thread::Builder::new()
+            .name(String::from("iced_winit launcher entry"))
+            .spawn(move || run(receiver));
+
+        if let Err(error) = spawned {
+            log::warn!("failed to show the progress: {}", error);
+        }
+
+        sender
+    });
+
+    // The thread is gone if the session bus is not available, which was
+    // already logged
+    let _ = updates.send(progress);
+}
+
+fn run(updates: mpsc::Receiver<Option<f32>>) {
+    let connection = match Connection::session() {
+        Ok(connection) => connection,
+        Err(error) => {
+            log::warn!("failed to show the progress: {}", error);
+            return;
+        }
+    };
+
+    let id = DESKTOP_ID
+        .get()
+        .cloned()
+        .or_else(|| {
+            let executable = std::env::current_exe().ok()?;
+
+            Some(executable.file_stem()?.to_string_lossy().into_owned())
+        })
+        .unwrap_or_else(|| String::from("iced"));
+
+    let id = id.trim_end_matches(".desktop");
+
+    let path = {
+        let mut hasher = DefaultHasher::new();
+        id.hash(&mut hasher);
+
+        format!("/com/canonical/unity/launcherentry/{}", hasher.finish())
+    };
+
+    let uri = format!("application://{}.desktop", id);
+
+    for progress in updates {
+        let mut properties = HashMap::new();
+
+        let _ = properties.insert(
+            "progress",
+            Value::from(f64::from(progress.unwrap_or(0.0).clamp(0.0, 1.0))),
+        );
+        let _ = properties
+            .insert("progress-visible", Value::from(progress.is_some()));
+
+        if let Err(error) = connection.emit_signal(
+            None::<BusName<'_>>,
+            path.as_str(),
+            "com.canonical.Unity.LauncherEntry",
+            "Update",
+            &(uri.as_str(), properties),
+        ) {
+            log::warn!("failed to show the progress: {}", error);
+        }
+    }
+}
@@ -20,30 +20,64 @@
 #![deny(missing_docs)]
 #![deny(missing_debug_implementations)]
 #![deny(unused_results)]
-#![forbid(unsafe_code)]
+#![deny(unsafe_code)]
 #![forbid(rust_2018_idioms)]
 
 #[doc(no_inline)]
 pub use iced_native::*;
 pub use winit;
 
+#[cfg(feature = "accesskit")]
+pub use accesskit;
+
 pub mod application;
 pub mod clipboard;
 pub mod conversion;
+pub mod headless;
+pub mod host;
+pub mod multi_window;
 pub mod settings;
 pub mod window;
 
 mod error;
 mod mode;
-mod position;
 mod proxy;
+mod transition;
+
+#[cfg(all(target_os = "linux", feature = "x11"))]
+mod x11;
+
+#[cfg(all(target_os = "linux", feature = "launcher_entry"))]
+mod launcher_entry;
+
+#[cfg(feature = "tray")]
+mod system_tray;
+
+#[cfg(any(
+    all(feature = "tray", any(target_os = "windows", target_os = "macos")),
+    all(feature = "menu_bar", target_os = "macos")
+))]
+mod menu_event;
+
+mod native_menu;
+
+#[cfg(feature = "accesskit")]
+mod accessibility_adapter;
+
+#[cfg(target_os = "windows")]
+mod taskbar;
 
+#[cfg(feature = "accesskit")]
+pub use accessibility_adapter::AccessibilityAdapter;
 pub use application::Application;
 pub use clipboard::Clipboard;
 pub use error::Error;
+pub use headless::Headless;
+pub use host::Host;
+pub use iced_native::window::Position;
 pub use mode::Mode;
-pub use position::Position;
+pub use native_menu::NativeMenu;
 pub use proxy::Proxy;
 pub use settings::Settings;
 
-pub use iced_graphics::Viewport;
+pub use iced_graphics::{Rotation, Viewport};
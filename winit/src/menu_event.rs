@@ -0,0 +1,55 @@
+//! Dispatch the activations of the native menus built with `muda`.
+//!
+//! `muda` reports the activations of every menu through a single handler,
+//! which can only be set once, so the system tray and the menu bar register
+//! their items here instead.
+use muda::{MenuEvent, MenuId};
+
+use std::sync::{Arc, Mutex, Once};
+
+/// The owner of some menu items.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Owner {
+    /// The menu of the system tray.
+    #[allow(dead_code)]
+    Tray,
+    /// The menu bar of the application.
+    #[allow(dead_code)]
+    MenuBar,
+}
+
+/// A callback run when a menu item is activated.
+pub type Callback = Arc<dyn Fn() + Send + Sync>;
+
+static ITEMS: Mutex<Vec<(Owner, MenuId, Callback)>> = Mutex::new(Vec::new());
+
+static HANDLER: Once = Once::new();
+
+/// Replaces the menu items of the given [`Owner`].
+pub fn replace(owner: Owner, items: Vec<(MenuId, Callback)>) {
+    HANDLER.call_once(|| {
+        MenuEvent::set_event_handler(Some(|event: MenuEvent| {
+            // The callback runs outside of the lock, since it may replace
+            // the items itself
+            let callback = ITEMS
+                .lock()
+                .expect("Lock menu items")
+                .iter()
+                .find(|(_, id, _)| *id == event.id)
+                .map(|(_, _, callback)| callback.clone());
+
+            if let Some(callback) = callback {
+                callback();
+            }
+        }));
+    });
+
+    let mut current = ITEMS.lock().expect("Lock menu items");
+
+    current.retain(|(current_owner, _, _)| *current_owner != owner);
+    current.extend(
+        items
+            .into_iter()
+            .map(|(id, callback)| (owner, id, callback)),
+    );
+}
@@ -0,0 +1,1179 @@
+//! Create interactive, native cross-platform applications with multiple
+//! windows.
+mod state;
+
+pub use state::State;
+
+use crate::application::{self, FramePacing, Input};
+use crate::clipboard::Clipboard;
+use crate::window;
+use crate::{
+    Color, Command, Debug, Element, Error, Executor, Mode, Proxy, Rotation,
+    Runtime, Settings, Size, Subscription, Theme,
+};
+
+use iced_futures::futures;
+use iced_futures::futures::channel::mpsc;
+use iced_futures::BoxFuture;
+use iced_graphics::window::{Compositor, SurfaceError};
+use iced_native::dnd;
+use iced_native::keyboard::Shortcuts;
+use iced_native::renderer;
+use iced_native::widget;
+use iced_native::window::RedrawRequest;
+use iced_native::{Cache, UserInterface};
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// A pending screenshot of a window.
+type Screenshot<Message> =
+    (window::Id, Box<dyn Fn(window::Screenshot) -> Message>);
+
+/// An interactive, native cross-platform application with multiple windows.
+///
+/// Every window of the application is identified by a [`window::Id`]. The
+/// application starts with a single window, identified by
+/// [`window::Id::MAIN`], and it can open more windows by producing a
+/// [`window::open`] command.
+///
+/// The events handled by the windows can be listened to with a
+/// [`Subscription`] created by [`events_with_window`], which provides the
+/// [`window::Id`] of the window every event originated from.
+///
+/// [`events_with_window`]: crate::subscription::events_with_window
+pub trait Application: Sized {
+    /// The data needed to initialize your [`Application`].
+    type Flags;
+
+    /// The graphics backend to use to draw the windows of the
+    /// [`Application`].
+    type Renderer: crate::Renderer;
+
+    /// The type of __messages__ your [`Application`] will produce.
+    type Message: std::fmt::Debug + Send;
+
+    /// Initializes the [`Application`] with the flags provided to
+    /// [`run`] as part of the [`Settings`].
+    ///
+    /// Here is where you should return the initial state of your app.
+    ///
+    /// Additionally, you can return a [`Command`] if you need to perform some
+    /// async action in the background on startup. This is useful if you want
+    /// to load state from a file, perform an initial HTTP request, etc.
+    fn new(flags: Self::Flags) -> (Self, Command<Self::Message>);
+
+    /// Returns the current title of the window with the given
+    /// [`window::Id`].
+    ///
+    /// This title can be dynamic! The runtime will automatically update the
+    /// title of the window when necessary.
+    fn title(&self, window: window::Id) -> String;
+
+    /// Handles a __message__ and updates the state of the [`Application`].
+    ///
+    /// This is where you define your __update logic__. All the __messages__,
+    /// produced by either user interactions or commands, will be handled by
+    /// this method.
+    ///
+    /// Any [`Command`] returned will be executed immediately in the
+    /// background.
+    fn update(&mut self, message: Self::Message) -> Command<Self::Message>;
+
+    /// Returns the widgets to display in the window with the given
+    /// [`window::Id`].
+    ///
+    /// These widgets can produce __messages__ based on user interaction.
+    fn view(
+        &mut self,
+        window: window::Id,
+    ) -> Element<'_, Self::Message, Self::Renderer>;
+
+    /// Returns the event [`Subscription`] for the current state of the
+    /// application.
+    ///
+    /// A [`Subscription`] will be kept alive as long as you keep returning it,
+    /// and the __messages__ produced will be handled by
+    /// [`update`](#tymethod.update).
+    ///
+    /// By default, this method returns an empty [`Subscription`].
+    fn subscription(&self) -> Subscription<Self::Message> {
+        Subscription::none()
+    }
+
+    /// Returns the keyboard [`Shortcuts`] of the window with the given
+    /// [`window::Id`].
+    ///
+    /// A [`Shortcut`] is triggered when its key is pressed in the window and
+    /// no widget captures the event, producing the message bound to it.
+    ///
+    /// By default, it returns no shortcuts.
+    ///
+    /// [`Shortcut`]: iced_native::keyboard::Shortcut
+    fn shortcuts(&self, _window: window::Id) -> Shortcuts<Self::Message> {
+        Shortcuts::new()
+    }
+
+    /// Returns the current [`Mode`] of the window with the given
+    /// [`window::Id`].
+    ///
+    /// By default, a window is in windowed mode.
+    fn mode(&self, _window: window::Id) -> Mode {
+        Mode::Windowed
+    }
+
+    /// Returns the [`Theme`] of the window with the given [`window::Id`].
+    ///
+    /// The [`Theme`] styles every built-in widget that does not have a
+    /// custom style sheet.
+    ///
+    /// By default, it returns [`Theme::Light`].
+    fn theme(&self, _window: window::Id) -> Theme {
+        Theme::default()
+    }
+
+    /// Returns the duration of the cross-fade played when the [`Theme`] of
+    /// the window with the given [`window::Id`] changes.
+    ///
+    /// By default, it returns `None` and the [`Theme`] changes instantly.
+    fn theme_transition(&self, _window: window::Id) -> Option<Duration> {
+        None
+    }
+
+    /// Returns the background [`Color`] of the window with the given
+    /// [`window::Id`].
+    ///
+    /// By default, it returns the background of its [`Theme`].
+    fn background_color(&self, window: window::Id) -> Color {
+        self.theme(window).palette().background
+    }
+
+    /// Returns the scale factor of the window with the given
+    /// [`window::Id`].
+    ///
+    /// By default, it returns `1.0`.
+    fn scale_factor(&self, _window: window::Id) -> f64 {
+        1.0
+    }
+
+    /// Returns the [`Rotation`] of the contents of the window with the given
+    /// [`window::Id`].
+    ///
+    /// By default, it returns [`Rotation::None`].
+    fn rotation(&self, _window: window::Id) -> Rotation {
+        Rotation::None
+    }
+
+    /// Returns whether the [`Application`] should be terminated.
+    ///
+    /// By default, it returns `false`.
+    fn should_exit(&self) -> bool {
+        false
+    }
+
+    /// Returns a future that runs to completion right before the
+    /// [`Application`] exits.
+    ///
+    /// By default, it returns `None`.
+    fn shutdown(&mut self) -> Option<BoxFuture<()>> {
+        None
+    }
+}
+
+/// An event processed by a running multi-window [`Application`].
+#[derive(Debug)]
+enum Event<Message: 'static> {
+    Winit(winit::event::Event<'static, Message>),
+    WindowCreated(window::Id, winit::window::Window),
+}
+
+/// A request of a running multi-window [`Application`] to its event loop.
+#[derive(Debug)]
+enum Control {
+    ChangeFlow(winit::event_loop::ControlFlow),
+    CreateWindow {
+        id: window::Id,
+        settings: window::Settings,
+        title: String,
+        mode: Mode,
+    },
+}
+
+/// A window of a running multi-window [`Application`].
+struct Window<A: Application, C: Compositor> {
+    raw: Arc<winit::window::Window>,
+    surface: C::Surface,
+    state: State<A>,
+    input: Input,
+    shortcuts: Shortcuts<A::Message>,
+    cache: Cache,
+    viewport_version: usize,
+    last_frame: Option<Instant>,
+    #[cfg(feature = "accesskit")]
+    accessibility: crate::AccessibilityAdapter,
+}
+
+/// Runs a multi-window [`Application`] with an executor, compositor, and the
+/// provided settings.
+///
+/// The [`Settings`] describe the main window of the [`Application`].
+pub fn run<A, E, C>(
+    settings: Settings<A::Flags>,
+    compositor_settings: C::Settings,
+) -> Result<(), Error>
+where
+    A: Application + 'static,
+    E: Executor + 'static,
+    C: Compositor<Renderer = A::Renderer> + 'static,
+{
+    use futures::task;
+    use futures::Future;
+    use winit::event_loop::EventLoop;
+    use winit::platform::run_return::EventLoopExtRunReturn;
+
+    let mut debug = Debug::new();
+    debug.startup_started();
+
+    let mut event_loop = EventLoop::with_user_event();
+    let proxy = event_loop.create_proxy();
+
+    let runtime = {
+        let proxy = Proxy::new(event_loop.create_proxy());
+        let executor = E::with_settings(settings.executor)
+            .map_err(Error::ExecutorCreationFailed)?;
+
+        Runtime::new(executor, proxy)
+    };
+
+    let (application, init_command) = {
+        let flags = settings.flags;
+
+        runtime.enter(|| A::new(flags))
+    };
+
+    let window = settings
+        .window
+        .into_builder(
+            &application.title(window::Id::MAIN),
+            application.mode(window::Id::MAIN),
+            event_loop.primary_monitor(),
+            settings.id,
+        )
+        .build(&event_loop)
+        .map_err(Error::WindowCreationFailed)?;
+
+    let clipboard = Clipboard::connect(&window);
+
+    let (compositor, renderer) = C::new(compositor_settings, Some(&window))?;
+
+    let (mut sender, receiver) = mpsc::unbounded();
+    let (control_sender, control_receiver) = std::sync::mpsc::channel();
+
+    let mut instance = Box::pin(run_instance::<A, E, C>(
+        application,
+        init_command,
+        compositor,
+        renderer,
+        runtime,
+        clipboard,
+        proxy,
+        debug,
+        receiver,
+        control_sender,
+        window,
+        settings.exit_on_close_request,
+        settings.keyboard_zoom,
+        settings.frame_rate_cap,
+    ));
+
+    let mut context = task::Context::from_waker(task::noop_waker_ref());
+
+    event_loop.run_return(move |event, window_target, control_flow| {
+        use winit::event_loop::ControlFlow;
+
+        if let ControlFlow::Exit = control_flow {
+            return;
+        }
+
+        let event = match event {
+            winit::event::Event::WindowEvent {
+                event:
+                    winit::event::WindowEvent::ScaleFactorChanged {
+                        new_inner_size,
+                        ..
+                    },
+                window_id,
+            } => Some(winit::event::Event::WindowEvent {
+                event: winit::event::WindowEvent::Resized(*new_inner_size),
+                window_id,
+            }),
+            _ => event.to_static(),
+        };
+
+        if let Some(event) = event {
+            sender.start_send(Event::Winit(event)).expect("Send event");
+
+            loop {
+                let poll = instance.as_mut().poll(&mut context);
+
+                match poll {
+                    task::Poll::Pending => match control_receiver.try_recv() {
+                        Ok(Control::ChangeFlow(flow)) => {
+                            *control_flow = flow;
+                        }
+                        Ok(Control::CreateWindow {
+                            id,
+                            settings,
+                            title,
+                            mode,
+                        }) => {
+                            let window =
+                                crate::settings::Window::from(settings)
+                                    .into_builder(
+                                        &title,
+                                        mode,
+                                        window_target.primary_monitor(),
+                                        None,
+                                    )
+                                    .build(window_target);
+
+                            match window {
+                                Ok(window) => {
+                                    sender
+                                        .start_send(Event::WindowCreated(
+                                            id, window,
+                                        ))
+                                        .expect("Send event");
+                                }
+                                Err(error) => {
+                                    log::error!(
+                                        "failed to create window {:?}: {}",
+                                        id,
+                                        error
+                                    );
+                                }
+                            }
+                        }
+                        Err(_) => break,
+                    },
+                    task::Poll::Ready(_) => {
+                        *control_flow = ControlFlow::Exit;
+                        break;
+                    }
+                }
+            }
+        }
+    });
+
+    Ok(())
+}
+
+async fn run_instance<A, E, C>(
+    mut application: A,
+    init_command: Command<A::Message>,
+    mut compositor: C,
+    mut renderer: A::Renderer,
+    mut runtime: Runtime<E, Proxy<A::Message>, A::Message>,
+    mut clipboard: Clipboard,
+    mut proxy: winit::event_loop::EventLoopProxy<A::Message>,
+    mut debug: Debug,
+    mut receiver: mpsc::UnboundedReceiver<Event<A::Message>>,
+    control_sender: std::sync::mpsc::Sender<Control>,
+    main_window: winit::window::Window,
+    exit_on_close_request: bool,
+    keyboard_zoom: bool,
+    frame_rate_cap: Option<u16>,
+) where
+    A: Application + 'static,
+    E: Executor + 'static,
+    C: Compositor<Renderer = A::Renderer> + 'static,
+{
+    use iced_futures::futures::stream::StreamExt;
+    use winit::event;
+
+    let mut windows: HashMap<window::Id, Window<A, C>> = HashMap::new();
+    let mut window_ids = HashMap::new();
+
+    let _ = window_ids.insert(main_window.id(), window::Id::MAIN);
+    let _ = windows.insert(
+        window::Id::MAIN,
+        Window::new(
+            &application,
+            window::Id::MAIN,
+            main_window,
+            &mut compositor,
+        ),
+    );
+
+    let mut operations = Vec::new();
+    let mut screenshots = Vec::new();
+    let mut frame_pacing = FramePacing::new(frame_rate_cap);
+
+    run_command(
+        init_command,
+        &application,
+        &mut runtime,
+        &mut clipboard,
+        &mut proxy,
+        &mut operations,
+        &mut screenshots,
+        &mut frame_pacing,
+        &mut windows,
+        &control_sender,
+    );
+    runtime.track(application.subscription());
+
+    run_operations(
+        &mut application,
+        &mut windows,
+        &mut renderer,
+        &mut operations,
+        &mut proxy,
+        &mut debug,
+    );
+
+    let mut messages = Vec::new();
+    let mut redraw_request = None;
+    let mut is_suspended = false;
+
+    debug.startup_finished();
+
+    while let Some(event) = receiver.next().await {
+        match event {
+            Event::WindowCreated(id, raw) => {
+                let _ = window_ids.insert(raw.id(), id);
+                let _ = windows.insert(
+                    id,
+                    Window::new(&application, id, raw, &mut compositor),
+                );
+            }
+            Event::Winit(event::Event::MainEventsCleared) => {
+                let now = Instant::now();
+
+                let is_redraw_due = match redraw_request {
+                    Some(RedrawRequest::NextFrame) => true,
+                    Some(RedrawRequest::At(at)) => at <= now,
+                    None => false,
+                };
+
+                if is_redraw_due {
+                    redraw_request = None;
+
+                    for window in windows.values_mut() {
+                        window.input.push(iced_native::Event::Window(
+                            iced_native::window::Event::RedrawRequested(now),
+                        ));
+                    }
+                }
+
+                for window in windows.values_mut() {
+                    window.input.recognize_gestures(now);
+
+                    #[cfg(feature = "accesskit")]
+                    for event in window.accessibility.events() {
+                        window.input.push(event);
+                    }
+                }
+
+                let has_events = windows.values().any(Window::needs_update);
+
+                if !has_events && messages.is_empty() {
+                    control_sender
+                        .send(Control::ChangeFlow(application::control_flow(
+                            redraw_request,
+                        )))
+                        .expect("Send control flow");
+
+                    continue;
+                }
+
+                let mut is_outdated = false;
+
+                for (id, window) in windows.iter_mut() {
+                    if !window.needs_update() {
+                        continue;
+                    }
+
+                    let mut user_interface = build_user_interface(
+                        &mut application,
+                        *id,
+                        std::mem::take(&mut window.cache),
+                        &mut renderer,
+                        window.state.logical_size(),
+                        &mut debug,
+                    );
+
+                    let mut focus = Vec::new();
+
+                    let (events, request) = window.input.process(
+                        &mut user_interface,
+                        &mut window.state,
+                        &window.raw,
+                        &mut renderer,
+                        &mut clipboard,
+                        &window.shortcuts,
+                        keyboard_zoom,
+                        &mut messages,
+                        &mut focus,
+                        &mut debug,
+                    );
+
+                    redraw_request = match (redraw_request, request) {
+                        (Some(current), Some(new)) => Some(current.min(new)),
+                        (current, new) => current.or(new),
+                    };
+
+                    application::run_operations(
+                        &mut user_interface,
+                        &mut focus,
+                        &mut proxy,
+                    );
+
+                    #[cfg(feature = "accesskit")]
+                    window.accessibility.update(
+                        &mut user_interface,
+                        window.state.title(),
+                        window.state.scale_factor(),
+                    );
+
+                    is_outdated |= user_interface.is_outdated();
+                    window.cache = user_interface.into_cache();
+
+                    for (event, status) in events {
+                        runtime.broadcast((*id, event, status));
+                    }
+
+                    window.raw.request_redraw();
+                }
+
+                if !messages.is_empty() || is_outdated {
+                    for message in messages.drain(..) {
+                        debug.log_message(&message);
+
+                        debug.update_started();
+                        let command =
+                            runtime.enter(|| application.update(message));
+                        debug.update_finished();
+
+                        run_command(
+                            command,
+                            &application,
+                            &mut runtime,
+                            &mut clipboard,
+                            &mut proxy,
+                            &mut operations,
+                            &mut screenshots,
+                            &mut frame_pacing,
+                            &mut windows,
+                            &control_sender,
+                        );
+                    }
+
+                    runtime.track(application.subscription());
+
+                    for (id, window) in windows.iter_mut() {
+                        window.state.synchronize(&application, &window.raw);
+                        window.shortcuts = application.shortcuts(*id);
+                        window.raw.request_redraw();
+                    }
+
+                    run_operations(
+                        &mut application,
+                        &mut windows,
+                        &mut renderer,
+                        &mut operations,
+                        &mut proxy,
+                        &mut debug,
+                    );
+
+                    // Let the new widgets request redraws, so animations
+                    // started by the application get their first frame
+                    for (id, window) in windows.iter_mut() {
+                        let mut user_interface = build_user_interface(
+                            &mut application,
+                            *id,
+                            std::mem::take(&mut window.cache),
+                            &mut renderer,
+                            window.state.logical_size(),
+                            &mut debug,
+                        );
+
+                        let _ = user_interface.update(
+                            &[iced_native::Event::Window(
+                                iced_native::window::Event::RedrawRequested(
+                                    Instant::now(),
+                                ),
+                            )],
+                            window.state.cursor_position(),
+                            &mut renderer,
+                            &mut clipboard,
+                            &mut messages,
+                        );
+
+                        redraw_request = match (
+                            redraw_request,
+                            user_interface.redraw_request(),
+                        ) {
+                            (Some(current), Some(new)) => {
+                                Some(current.min(new))
+                            }
+                            (current, new) => current.or(new),
+                        };
+
+                        #[cfg(feature = "accesskit")]
+                        window.accessibility.update(
+                            &mut user_interface,
+                            window.state.title(),
+                            window.state.scale_factor(),
+                        );
+
+                        window.cache = user_interface.into_cache();
+                    }
+
+                    if application.should_exit() || windows.is_empty() {
+                        break;
+                    }
+                }
+
+                for window in windows.values() {
+                    redraw_request =
+                        match (redraw_request, window.state.redraw_request()) {
+                            (Some(current), Some(new)) => {
+                                Some(current.min(new))
+                            }
+                            (current, new) => current.or(new),
+                        };
+                }
+
+                control_sender
+                    .send(Control::ChangeFlow(application::control_flow(
+                        redraw_request,
+                    )))
+                    .expect("Send control flow");
+            }
+            Event::Winit(event::Event::PlatformSpecific(
+                event::PlatformSpecific::MacOS(event::MacOS::ReceivedUrl(url)),
+            )) => {
+                use iced_native::event;
+
+                if let Some(window) = windows.get_mut(&window::Id::MAIN) {
+                    window.input.push(iced_native::Event::PlatformSpecific(
+                        event::PlatformSpecific::MacOS(
+                            event::MacOS::ReceivedUrl(url),
+                        ),
+                    ));
+                }
+            }
+            Event::Winit(event::Event::UserEvent(message)) => {
+                messages.push(message);
+            }
+            Event::Winit(event::Event::Suspended)
+            | Event::Winit(event::Event::Resumed) => {
+                let was_suspended = is_suspended;
+                is_suspended =
+                    matches!(event, Event::Winit(event::Event::Suspended));
+
+                for window in windows.values_mut() {
+                    // Some platforms, like Android, destroy the native
+                    // windows while the application is suspended, so their
+                    // surfaces must be created again when resumed
+                    if was_suspended && !is_suspended {
+                        let size = window.state.physical_size();
+
+                        window.surface =
+                            compositor.create_surface(window.raw.as_ref());
+                        compositor.configure_surface(
+                            &mut window.surface,
+                            size.width,
+                            size.height,
+                        );
+
+                        window.raw.request_redraw();
+                    }
+
+                    window.input.push(iced_native::Event::Window(
+                        iced_native::window::Event::Occluded(is_suspended),
+                    ));
+                }
+            }
+            Event::Winit(event::Event::RedrawRequested(window_id)) => {
+                if let Some(present_mode) = frame_pacing.take_present_mode() {
+                    compositor.set_present_mode(present_mode);
+
+                    for window in windows.values_mut() {
+                        let size = window.state.physical_size();
+
+                        if size.width > 0 && size.height > 0 {
+                            compositor.configure_surface(
+                                &mut window.surface,
+                                size.width,
+                                size.height,
+                            );
+                        }
+                    }
+                }
+
+                let (id, window) = match window_ids
+                    .get(&window_id)
+                    .and_then(|id| Some((*id, windows.get_mut(id)?)))
+                {
+                    Some(entry) => entry,
+                    None => continue,
+                };
+
+                let physical_size = window.state.physical_size();
+
+                if is_suspended
+                    || physical_size.width == 0
+                    || physical_size.height == 0
+                {
+                    continue;
+                }
+
+                if let Some(next_frame) =
+                    frame_pacing.delay(window.last_frame, Instant::now())
+                {
+                    redraw_request = Some(match redraw_request {
+                        Some(current) => {
+                            current.min(RedrawRequest::At(next_frame))
+                        }
+                        None => RedrawRequest::At(next_frame),
+                    });
+
+                    control_sender
+                        .send(Control::ChangeFlow(application::control_flow(
+                            redraw_request,
+                        )))
+                        .expect("Send control action");
+
+                    continue;
+                }
+
+                debug.render_started();
+
+                let current_viewport_version = window.state.viewport_version();
+
+                if window.viewport_version != current_viewport_version {
+                    compositor.configure_surface(
+                        &mut window.surface,
+                        physical_size.width,
+                        physical_size.height,
+                    );
+
+                    window.viewport_version = current_viewport_version;
+                }
+
+                let mut user_interface = build_user_interface(
+                    &mut application,
+                    id,
+                    std::mem::take(&mut window.cache),
+                    &mut renderer,
+                    window.state.logical_size(),
+                    &mut debug,
+                );
+
+                debug.draw_started();
+                let new_mouse_interaction = user_interface.draw(
+                    &mut renderer,
+                    &renderer::Style::from(window.state.theme()),
+                    window.state.cursor_position(),
+                );
+                debug.draw_finished();
+
+                window.cache = user_interface.into_cache();
+
+                window
+                    .input
+                    .set_mouse_interaction(&window.raw, new_mouse_interaction);
+
+                if screenshots.iter().any(|(target, _)| *target == id) {
+                    let screenshot = window::Screenshot::new(
+                        compositor.screenshot(
+                            &mut renderer,
+                            &mut window.surface,
+                            window.state.viewport(),
+                            window.state.background_color(),
+                            &debug.overlay(),
+                        ),
+                        window.state.viewport().physical_size(),
+                    );
+
+                    let (taken, pending) = screenshots
+                        .drain(..)
+                        .partition(|(target, _)| *target == id);
+
+                    screenshots = pending;
+
+                    for (_, tag) in taken {
+                        proxy
+                            .send_event(tag(screenshot.clone()))
+                            .expect("Send message to event loop");
+                    }
+                }
+
+                debug.present_started();
+                match compositor.present(
+                    &mut renderer,
+                    &mut window.surface,
+                    window.state.viewport(),
+                    window.state.background_color(),
+                    &debug.overlay(),
+                ) {
+                    Ok(()) => {
+                        debug.present_finished();
+                        debug.render_finished();
+
+                        let now = Instant::now();
+                        window.last_frame = Some(now);
+
+                        if frame_pacing.is_continuous(id) {
+                            redraw_request = Some(RedrawRequest::NextFrame);
+
+                            control_sender
+                                .send(Control::ChangeFlow(
+                                    application::control_flow(redraw_request),
+                                ))
+                                .expect("Send control action");
+                        }
+
+                        runtime.broadcast((
+                            id,
+                            iced_native::Event::Window(
+                                iced_native::window::Event::FramePresented(now),
+                            ),
+                            iced_native::event::Status::Ignored,
+                        ));
+                    }
+                    Err(error) => match error {
+                        // This is an unrecoverable error.
+                        SurfaceError::OutOfMemory => {
+                            panic!("{:?}", error);
+                        }
+                        _ => {
+                            debug.present_finished();
+                            debug.render_finished();
+
+                            // Try rendering again next frame.
+                            window.raw.request_redraw();
+                        }
+                    },
+                }
+            }
+            Event::Winit(event::Event::WindowEvent {
+                event: window_event,
+                window_id,
+            }) => {
+                let (id, window) = match window_ids
+                    .get(&window_id)
+                    .and_then(|id| Some((*id, windows.get_mut(id)?)))
+                {
+                    Some(entry) => entry,
+                    None => continue,
+                };
+
+                if application::requests_exit(
+                    &window_event,
+                    window.state.modifiers(),
+                ) && exit_on_close_request
+                {
+                    let _ = windows.remove(&id);
+                    let _ = window_ids.remove(&window_id);
+
+                    if windows.is_empty() {
+                        break;
+                    }
+
+                    continue;
+                }
+
+                #[cfg(feature = "accesskit")]
+                window
+                    .accessibility
+                    .process_event(&window.raw, &window_event);
+
+                window.input.handle_window_event(
+                    &mut window.state,
+                    &window.raw,
+                    &window_event,
+                    &mut debug,
+                );
+            }
+            Event::Winit(event::Event::DeviceEvent {
+                event: winit::event::DeviceEvent::MouseMotion { delta },
+                ..
+            }) => {
+                for window in windows.values_mut() {
+                    window.input.handle_mouse_motion(&window.state, delta);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    if let Some(shutdown) = application.shutdown() {
+        runtime.enter(|| futures::executor::block_on(shutdown));
+    }
+}
+
+impl<A: Application, C: Compositor> Window<A, C> {
+    fn new(
+        application: &A,
+        id: window::Id,
+        raw: winit::window::Window,
+        compositor: &mut C,
+    ) -> Self {
+        let mut surface = compositor.create_surface(&raw);
+        let state = State::new(application, id, &raw);
+
+        let physical_size = state.physical_size();
+
+        compositor.configure_surface(
+            &mut surface,
+            physical_size.width,
+            physical_size.height,
+        );
+
+        raw.request_redraw();
+
+        let shortcuts = application.shortcuts(id);
+        application::report_shortcut_conflicts(&shortcuts);
+
+        let raw = Arc::new(raw);
+
+        Window {
+            #[cfg(feature = "accesskit")]
+            accessibility: crate::AccessibilityAdapter::new(&raw),
+            raw,
+            surface,
+            viewport_version: state.viewport_version(),
+            input: Input::new(&state),
+            state,
+            shortcuts,
+            cache: Cache::default(),
+            last_frame: None,
+        }
+    }
+
+    /// Returns true if the window has events to process, or if an assistive
+    /// technology is waiting for its accessibility tree.
+    fn needs_update(&self) -> bool {
+        #[cfg(feature = "accesskit")]
+        if self.accessibility.is_requested() {
+            return true;
+        }
+
+        !self.input.is_empty()
+    }
+}
+
+/// Builds a [`UserInterface`] for the window with the given [`window::Id`],
+/// logging [`struct@Debug`] information accordingly.
+pub fn build_user_interface<'a, A: Application>(
+    application: &'a mut A,
+    id: window::Id,
+    cache: Cache,
+    renderer: &mut A::Renderer,
+    size: Size,
+    debug: &mut Debug,
+) -> UserInterface<'a, A::Message, A::Renderer> {
+    debug.view_started();
+    let view = application.view(id);
+    debug.view_finished();
+
+    debug.layout_started();
+    let user_interface = UserInterface::build(view, size, cache, renderer);
+    debug.layout_finished();
+
+    user_interface
+}
+
+/// Runs the actions of a [`Command`] produced by a multi-window
+/// [`Application`].
+#[allow(clippy::too_many_arguments)]
+fn run_command<A, C, E>(
+    command: Command<A::Message>,
+    application: &A,
+    runtime: &mut Runtime<E, Proxy<A::Message>, A::Message>,
+    clipboard: &mut Clipboard,
+    proxy: &mut winit::event_loop::EventLoopProxy<A::Message>,
+    operations: &mut Vec<Box<dyn widget::Operation<A::Message>>>,
+    screenshots: &mut Vec<Screenshot<A::Message>>,
+    frame_pacing: &mut FramePacing,
+    windows: &mut HashMap<window::Id, Window<A, C>>,
+    control_sender: &std::sync::mpsc::Sender<Control>,
+) where
+    A: Application + 'static,
+    C: Compositor,
+    E: Executor,
+{
+    use iced_native::command;
+
+    for action in command.actions() {
+        match action {
+            command::Action::Future(future) => {
+                runtime.spawn(future);
+            }
+            command::Action::Background(future) => {
+                runtime.spawn_background(future);
+            }
+            command::Action::Stream(stream) => {
+                runtime.run(stream);
+            }
+            command::Action::Clipboard(action) => {
+                application::run_clipboard_action(action, clipboard, proxy);
+            }
+            command::Action::Window(action) => match action {
+                iced_native::window::Action::Open { id, settings } => {
+                    if windows.contains_key(&id) {
+                        log::warn!("window {:?} is already open", id);
+                        continue;
+                    }
+
+                    control_sender
+                        .send(Control::CreateWindow {
+                            id,
+                            settings,
+                            title: application.title(id),
+                            mode: application.mode(id),
+                        })
+                        .expect("Send control action");
+                }
+                iced_native::window::Action::Close(id) => {
+                    let _ = windows.remove(&id);
+                    frame_pacing.set_continuous_rendering(id, false);
+
+                    screenshots.retain(|(target, _)| *target != id);
+                }
+                iced_native::window::Action::SetPresentMode(present_mode) => {
+                    frame_pacing.set_present_mode(present_mode);
+
+                    for window in windows.values() {
+                        window.raw.request_redraw();
+                    }
+                }
+                iced_native::window::Action::SetFrameRateCap(
+                    frame_rate_cap,
+                ) => {
+                    frame_pacing.set_frame_rate_cap(frame_rate_cap);
+                }
+                iced_native::window::Action::SetContinuousRendering(
+                    id,
+                    is_continuous,
+                ) => {
+                    frame_pacing.set_continuous_rendering(id, is_continuous);
+
+                    if let Some(window) = windows.get(&id) {
+                        window.raw.request_redraw();
+                    }
+                }
+                action => {
+                    if let Some(window) = windows.get_mut(&window::Id::MAIN) {
+                        application::run_window_action(
+                            action,
+                            &window.raw,
+                            window.input.drag_resize(),
+                        );
+                    }
+                }
+            },
+            command::Action::Tray(action) => {
+                application::run_tray_action(action, proxy);
+            }
+            command::Action::FetchMonitors(tag) => {
+                if let Some(window) = windows.values().next() {
+                    proxy
+                        .send_event(tag(application::monitors(&window.raw)))
+                        .expect("Send message to event loop");
+                }
+            }
+            command::Action::FetchColorScheme(tag) => {
+                let color_scheme = windows
+                    .get(&window::Id::MAIN)
+                    .or_else(|| windows.values().next())
+                    .and_then(|window| application::color_scheme(&window.raw));
+
+                proxy
+                    .send_event(tag(color_scheme))
+                    .expect("Send message to event loop");
+            }
+            command::Action::Screenshot(id, tag) => {
+                if windows.contains_key(&id) {
+                    screenshots.push((id, tag));
+                } else {
+                    log::warn!("window {:?} does not exist", id);
+                }
+            }
+            command::Action::StartDrag(data, tag) => {
+                let result = match windows
+                    .get(&window::Id::MAIN)
+                    .or_else(|| windows.values().next())
+                {
+                    Some(window) => application::start_drag(&window.raw, data),
+                    None => Err(dnd::Error::Unsupported),
+                };
+
+                proxy
+                    .send_event(tag(result))
+                    .expect("Send message to event loop");
+            }
+            command::Action::FetchProxy(tag) => {
+                proxy
+                    .send_event(tag(application::command_proxy(proxy)))
+                    .expect("Send message to event loop");
+            }
+            command::Action::Widget(operation) => {
+                operations.push(operation);
+            }
+        }
+    }
+}
+
+/// Applies the queued widget operations to the user interfaces of all the
+/// windows, sending any resulting message to the event loop.
+fn run_operations<A, C>(
+    application: &mut A,
+    windows: &mut HashMap<window::Id, Window<A, C>>,
+    renderer: &mut A::Renderer,
+    operations: &mut Vec<Box<dyn widget::Operation<A::Message>>>,
+    proxy: &mut winit::event_loop::EventLoopProxy<A::Message>,
+    debug: &mut Debug,
+) where
+    A: Application + 'static,
+    C: Compositor,
+{
+    use iced_native::widget::operation;
+
+    for mut operation in operations.drain(..) {
+        loop {
+            for (id, window) in windows.iter_mut() {
+                let mut user_interface = build_user_interface(
+                    application,
+                    *id,
+                    std::mem::take(&mut window.cache),
+                    renderer,
+                    window.state.logical_size(),
+                    debug,
+                );
+
+                user_interface.operate(operation.as_mut());
+
+                window.cache = user_interface.into_cache();
+            }
+
+            match operation.finish() {
+                operation::Outcome::None => break,
+                operation::Outcome::Some(message) => {
+                    proxy
+                        .send_event(message)
+                        .expect("Send message to event loop");
+
+                    break;
+                }
+                operation::Outcome::Chain(next) => {
+                    operation = next;
+                }
+            }
+        }
+    }
+}
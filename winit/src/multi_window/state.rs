@@ -0,0 +1,69 @@
+use crate::application::{Appearance, WindowState};
+use crate::multi_window::Application;
+use crate::window;
+
+use std::marker::PhantomData;
+use std::ops::{Deref, DerefMut};
+use winit::window::Window;
+
+/// The state of a window of a multi-window [`Application`].
+///
+/// It dereferences to the [`WindowState`] of the window.
+#[derive(Debug, Clone)]
+pub struct State<A: Application> {
+    id: window::Id,
+    window: WindowState,
+    application: PhantomData<A>,
+}
+
+impl<A: Application> State<A> {
+    /// Creates a new [`State`] for the window with the given [`window::Id`].
+    pub fn new(application: &A, id: window::Id, window: &Window) -> Self {
+        Self {
+            id,
+            window: WindowState::new(appearance(application, id), window),
+            application: PhantomData,
+        }
+    }
+
+    /// Returns the [`window::Id`] of the [`State`].
+    pub fn id(&self) -> window::Id {
+        self.id
+    }
+
+    /// Synchronizes the [`State`] with its [`Application`] and its respective
+    /// window.
+    ///
+    /// Normally an [`Application`] should be synchronized with the [`State`]
+    /// of all of its windows after calling [`Application::update`].
+    pub fn synchronize(&mut self, application: &A, window: &Window) {
+        self.window
+            .synchronize(appearance(application, self.id), window);
+    }
+}
+
+impl<A: Application> Deref for State<A> {
+    type Target = WindowState;
+
+    fn deref(&self) -> &WindowState {
+        &self.window
+    }
+}
+
+impl<A: Application> DerefMut for State<A> {
+    fn deref_mut(&mut self) -> &mut WindowState {
+        &mut self.window
+    }
+}
+
+fn appearance<A: Application>(application: &A, id: window::Id) -> Appearance {
+    Appearance {
+        title: application.title(id),
+        mode: application.mode(id),
+        theme: application.theme(id),
+        theme_transition: application.theme_transition(id),
+        background_color: application.background_color(id),
+        scale_factor: application.scale_factor(id),
+        rotation: application.rotation(id),
+    }
+}
@@ -0,0 +1,837 @@
+//! Show the menu bar of an application in the menu bar of the operating
+//! system.
+//!
+//! On macOS, the menu bar is set as the main menu of the application. On
+//! Linux, it is exported through the `com.canonical.dbusmenu` D-Bus
+//! interface and registered for the window as its global menu, which is only
+//! possible on X11.
+//!
+//! Where the operating system cannot show the menu bar, it is drawn at the
+//! top of the window instead.
+use crate::application;
+use crate::keyboard::Shortcuts;
+use crate::menu_bar::{self, Menu, MenuBar};
+use crate::text;
+use crate::Element;
+
+use std::sync::{Arc, Mutex};
+
+/// The [`MenuBar`] of an application, shown by the operating system.
+///
+/// The messages of its items are sent to the event loop.
+#[allow(missing_debug_implementations)]
+pub struct NativeMenu<Message> {
+    menu_bar: Arc<Mutex<MenuBar<Message>>>,
+    menus: Vec<Menu<usize>>,
+    platform: platform::Platform,
+    fallback: menu_bar::State,
+}
+
+impl<Message: 'static + std::fmt::Debug + Send> NativeMenu<Message> {
+    /// Shows the given [`MenuBar`] for the given window.
+    pub fn new(
+        menu_bar: MenuBar<Message>,
+        window: &winit::window::Window,
+        proxy: &winit::event_loop::EventLoopProxy<Message>,
+    ) -> Self {
+        let menus = menu_bar.menus().to_vec();
+        let menu_bar = Arc::new(Mutex::new(menu_bar));
+
+        let on_activate: Arc<dyn Fn(usize) + Send + Sync> = {
+            let menu_bar = menu_bar.clone();
+            let proxy = application::command_proxy(proxy);
+
+            Arc::new(move |index| {
+                let message =
+                    menu_bar.lock().expect("Lock menu bar").message(index);
+
+                if let Some(message) = message {
+                    let _ = proxy.send(message);
+                }
+            })
+        };
+
+        let mut platform = platform::Platform::new(window, on_activate);
+
+        if !menus.is_empty() {
+            platform.show(&menus);
+        }
+
+        Self {
+            menu_bar,
+            menus,
+            platform,
+            fallback: menu_bar::State::new(),
+        }
+    }
+
+    /// Replaces the current [`MenuBar`], updating the menu bar of the
+    /// operating system if its menus changed.
+    pub fn update(&mut self, menu_bar: MenuBar<Message>) {
+        if menu_bar.menus() != self.menus.as_slice()
+            || self.platform.is_outdated()
+        {
+            self.menus = menu_bar.menus().to_vec();
+            self.platform.show(&self.menus);
+        }
+
+        *self.menu_bar.lock().expect("Lock menu bar") = menu_bar;
+    }
+
+    /// Returns the [`Shortcuts`] of the items of the current [`MenuBar`].
+    pub fn shortcuts(&self) -> Shortcuts<Message> {
+        self.menu_bar.lock().expect("Lock menu bar").shortcuts()
+    }
+
+    /// Returns the current [`MenuBar`] drawn inside of the window, if the
+    /// operating system cannot show it.
+    pub fn view<'a, Renderer>(
+        &'a mut self,
+    ) -> Option<Element<'a, Message, Renderer>>
+    where
+        Renderer: text::Renderer + 'a,
+    {
+        if self.menus.is_empty() || self.platform.is_native() {
+            return None;
+        }
+
+        let menu_bar = self.menu_bar.lock().expect("Lock menu bar");
+
+        Some(menu_bar.view(&mut self.fallback))
+    }
+}
+
+/// Returns the name of the key of a [`Shortcut`].
+///
+/// [`Shortcut`]: crate::keyboard::Shortcut
+#[cfg(all(
+    feature = "menu_bar",
+    any(target_os = "linux", target_os = "macos")
+))]
+fn key_name(shortcut: &crate::keyboard::Shortcut) -> String {
+    // The key is always the last part of a shortcut, and it is never empty
+    shortcut
+        .to_string()
+        .rsplit('+')
+        .next()
+        .unwrap_or_default()
+        .to_owned()
+}
+
+#[cfg(all(feature = "menu_bar", target_os = "linux"))]
+mod platform {
+    use crate::keyboard::{Modifiers, Shortcut};
+    use crate::menu_bar::{Item, Menu};
+
+    use winit::platform::unix::WindowExtUnix;
+    use zbus::blocking::{connection, Connection};
+    use zbus::names::BusName;
+    use zbus::zvariant::{ObjectPath, OwnedValue, StructureBuilder, Value};
+
+    use std::collections::HashMap;
+    use std::convert::TryFrom;
+    use std::sync::atomic::{self, AtomicBool};
+    use std::sync::{mpsc, Arc};
+    use std::thread;
+
+    /// The object path of the menu bar.
+    const PATH: &str = "/com/canonical/menu/iced";
+
+    /// The well-known name of the registrar of global menus.
+    const REGISTRAR: &str = "com.canonical.AppMenu.Registrar";
+
+    pub struct Platform {
+        updates: Option<mpsc::Sender<Vec<Menu<usize>>>>,
+        is_unavailable: Arc<AtomicBool>,
+    }
+
+    impl Platform {
+        pub fn new(
+            window: &winit::window::Window,
+            on_activate: Arc<dyn Fn(usize) + Send + Sync>,
+        ) -> Self {
+            let is_unavailable = Arc::new(AtomicBool::new(false));
+
+            // Wayland has no global menus that can be registered for a window
+            let window_id = match window.xlib_window() {
+                Some(window_id) => window_id as u32,
+                None => {
+                    is_unavailable.store(true, atomic::Ordering::Relaxed);
+
+                    return Self {
+                        updates: None,
+                        is_unavailable,
+                    };
+                }
+            };
+
+            let (sender, receiver) = mpsc::channel();
+
+            // The session bus is connected to in a background thread, so the
+            // event loop is never blocked
+            let spawned = thread::Builder::new()
+                .name(String::from("iced_winit menu bar"))
+                .spawn({
+                    let is_unavailable = is_unavailable.clone();
+
+                    move || {
+                        if let Err(error) =
+                            run(window_id, on_activate, receiver)
+                        {
+                            log::warn!(
+                                "failed to show the menu bar, drawing it in \
+                                the window instead: {}",
+                                error
+                            );
+
+                            is_unavailable
+                                .store(true, atomic::Ordering::Relaxed);
+                        }
+                    }
+                });
+
+            if let Err(error) = spawned {
+                log::warn!("failed to show the menu bar: {}", error);
+
+                is_unavailable.store(true, atomic::Ordering::Relaxed);
+            }
+
+            Self {
+                updates: Some(sender),
+                is_unavailable,
+            }
+        }
+
+        pub fn show(&mut self, menus: &[Menu<usize>]) {
+            if let Some(updates) = &self.updates {
+                // The thread is gone if the session bus is not available,
+                // which was already logged
+                let _ = updates.send(menus.to_vec());
+            }
+        }
+
+        pub fn is_outdated(&self) -> bool {
+            false
+        }
+
+        pub fn is_native(&self) -> bool {
+            !self.is_unavailable.load(atomic::Ordering::Relaxed)
+        }
+    }
+
+    fn run(
+        window_id: u32,
+        on_activate: Arc<dyn Fn(usize) + Send + Sync>,
+        updates: mpsc::Receiver<Vec<Menu<usize>>>,
+    ) -> zbus::Result<()> {
+        let menu = DbusMenu {
+            entries: entries(&[]),
+            revision: 0,
+            on_activate,
+        };
+
+        let connection = connection::Builder::session()?
+            .serve_at(PATH, menu)?
+            .build()?;
+
+        register(&connection, window_id)?;
+
+        let menu = connection.object_server().interface::<_, DbusMenu>(PATH)?;
+
+        for menus in updates {
+            let revision = {
+                let mut menu = menu.get_mut();
+
+                menu.entries = entries(&menus);
+                menu.revision += 1;
+                menu.revision
+            };
+
+            if let Err(error) = connection.emit_signal(
+                None::<BusName<'_>>,
+                PATH,
+                "com.canonical.dbusmenu",
+                "LayoutUpdated",
+                &(revision, 0),
+            ) {
+                log::warn!("failed to update the menu bar: {}", error);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn register(connection: &Connection, window_id: u32) -> zbus::Result<()> {
+        let _ = connection.call_method(
+            Some(REGISTRAR),
+            "/com/canonical/AppMenu/Registrar",
+            Some(REGISTRAR),
+            "RegisterWindow",
+            &(window_id, ObjectPath::from_static_str_unchecked(PATH)),
+        )?;
+
+        Ok(())
+    }
+
+    /// An entry of a menu, identified by its position in the menu bar.
+    ///
+    /// The first entry is the root of the menu bar.
+    struct Entry {
+        label: String,
+        kind: Kind,
+        index: Option<usize>,
+        shortcut: Option<Shortcut>,
+        children: Vec<i32>,
+    }
+
+    enum Kind {
+        Root,
+        Button,
+        Checkbox(bool),
+        Separator,
+        Submenu,
+    }
+
+    fn entries(menus: &[Menu<usize>]) -> Vec<Entry> {
+        let mut entries = vec![Entry {
+            label: String::new(),
+            kind: Kind::Root,
+            index: None,
+            shortcut: None,
+            children: Vec::new(),
+        }];
+
+        entries[0].children =
+            menus.iter().map(|menu| push(&mut entries, menu)).collect();
+
+        entries
+    }
+
+    fn push(entries: &mut Vec<Entry>, menu: &Menu<usize>) -> i32 {
+        let id = entries.len();
+
+        entries.push(Entry {
+            label: menu.title.clone(),
+            kind: Kind::Submenu,
+            index: None,
+            shortcut: None,
+            children: Vec::new(),
+        });
+
+        let children = menu
+            .items
+            .iter()
+            .map(|item| match item {
+                Item::Button {
+                    label,
+                    on_press,
+                    shortcut,
+                } => {
+                    entries.push(Entry {
+                        label: label.clone(),
+                        kind: Kind::Button,
+                        index: *on_press,
+                        shortcut: *shortcut,
+                        children: Vec::new(),
+                    });
+
+                    entries.len() as i32 - 1
+                }
+                Item::Checkbox {
+                    label,
+                    is_checked,
+                    on_toggle,
+                    shortcut,
+                } => {
+                    entries.push(Entry {
+                        label: label.clone(),
+                        kind: Kind::Checkbox(*is_checked),
+                        index: *on_toggle,
+                        shortcut: *shortcut,
+                        children: Vec::new(),
+                    });
+
+                    entries.len() as i32 - 1
+                }
+                Item::Separator => {
+                    entries.push(Entry {
+                        label: String::new(),
+                        kind: Kind::Separator,
+                        index: None,
+                        shortcut: None,
+                        children: Vec::new(),
+                    });
+
+                    entries.len() as i32 - 1
+                }
+                Item::Submenu(menu) => push(entries, menu),
+            })
+            .collect();
+
+        entries[id].children = children;
+
+        id as i32
+    }
+
+    type Properties = HashMap<String, OwnedValue>;
+
+    /// An entry with its properties and the layouts of its children.
+    type Layout = (i32, Properties, Vec<OwnedValue>);
+
+    struct DbusMenu {
+        entries: Vec<Entry>,
+        revision: u32,
+        on_activate: Arc<dyn Fn(usize) + Send + Sync>,
+    }
+
+    impl DbusMenu {
+        fn properties(&self, id: i32, names: &[String]) -> Option<Properties> {
+            let entry = self.entries.get(usize::try_from(id).ok()?)?;
+            let mut properties = HashMap::new();
+
+            let mut insert = |name: &str, value: Value<'_>| {
+                if names.is_empty() || names.iter().any(|wanted| wanted == name)
+                {
+                    let _ = properties.insert(name.to_owned(), owned(value));
+                }
+            };
+
+            match entry.kind {
+                Kind::Root => {
+                    insert("children-display", Value::from("submenu"));
+                }
+                Kind::Separator => {
+                    insert("type", Value::from("separator"));
+                }
+                Kind::Button | Kind::Checkbox(_) | Kind::Submenu => {
+                    // Underscores mark mnemonics in labels
+                    insert(
+                        "label",
+                        Value::from(entry.label.replace('_', "__")),
+                    );
+                    insert(
+                        "enabled",
+                        Value::from(
+                            matches!(entry.kind, Kind::Submenu)
+                                || entry.index.is_some(),
+                        ),
+                    );
+                }
+            }
+
+            match entry.kind {
+                Kind::Checkbox(is_checked) => {
+                    insert("toggle-type", Value::from("checkmark"));
+                    insert("toggle-state", Value::from(i32::from(is_checked)));
+                }
+                Kind::Submenu => {
+                    insert("children-display", Value::from("submenu"));
+                }
+                _ => {}
+            }
+
+            if let Some(shortcut) = entry.shortcut {
+                insert("shortcut", Value::from(vec![shortcut_keys(shortcut)]));
+            }
+
+            Some(properties)
+        }
+
+        fn layout(
+            &self,
+            id: i32,
+            depth: i32,
+            names: &[String],
+        ) -> Option<Layout> {
+            let properties = self.properties(id, names)?;
+            let entry = &self.entries[id as usize];
+
+            let children = if depth == 0 {
+                Vec::new()
+            } else {
+                entry
+                    .children
+                    .iter()
+                    .filter_map(|child| {
+                        self.layout(*child, (depth - 1).max(-1), names)
+                    })
+                    .map(|(id, properties, children)| {
+                        owned(Value::from(
+                            StructureBuilder::new()
+                                .add_field(id)
+                                .add_field(properties)
+                                .add_field(children)
+                                .build(),
+                        ))
+                    })
+                    .collect()
+            };
+
+            Some((id, properties, children))
+        }
+    }
+
+    #[zbus::interface(name = "com.canonical.dbusmenu")]
+    impl DbusMenu {
+        fn get_layout(
+            &self,
+            parent_id: i32,
+            recursion_depth: i32,
+            property_names: Vec<String>,
+        ) -> zbus::fdo::Result<(u32, Layout)> {
+            let layout = self
+                .layout(parent_id, recursion_depth, &property_names)
+                .ok_or_else(|| {
+                    zbus::fdo::Error::InvalidArgs(String::from(
+                        "unknown parent id",
+                    ))
+                })?;
+
+            Ok((self.revision, layout))
+        }
+
+        fn get_group_properties(
+            &self,
+            ids: Vec<i32>,
+            property_names: Vec<String>,
+        ) -> Vec<(i32, Properties)> {
+            let ids = if ids.is_empty() {
+                (0..self.entries.len() as i32).collect()
+            } else {
+                ids
+            };
+
+            ids.into_iter()
+                .filter_map(|id| {
+                    Some((id, self.properties(id, &property_names)?))
+                })
+                .collect()
+        }
+
+        fn get_property(
+            &self,
+            id: i32,
+            name: String,
+        ) -> zbus::fdo::Result<OwnedValue> {
+            self.properties(id, std::slice::from_ref(&name))
+                .and_then(|mut properties| properties.remove(&name))
+                .ok_or_else(|| {
+                    zbus::fdo::Error::InvalidArgs(format!(
+                        "unknown property {} of item {}",
+                        name, id
+                    ))
+                })
+        }
+
+        fn event(
+            &self,
+            id: i32,
+            event_id: String,
+            _data: OwnedValue,
+            _timestamp: u32,
+        ) {
+            if event_id != "clicked" {
+                return;
+            }
+
+            let index = usize::try_from(id)
+                .ok()
+                .and_then(|id| self.entries.get(id))
+                .and_then(|entry| entry.index);
+
+            if let Some(index) = index {
+                (self.on_activate)(index);
+            }
+        }
+
+        fn event_group(
+            &self,
+            events: Vec<(i32, String, OwnedValue, u32)>,
+        ) -> Vec<i32> {
+            let mut unknown = Vec::new();
+
+            for (id, event_id, data, timestamp) in events {
+                if usize::try_from(id)
+                    .map_or(true, |id| id >= self.entries.len())
+                {
+                    unknown.push(id);
+                } else {
+                    self.event(id, event_id, data, timestamp);
+                }
+            }
+
+            unknown
+        }
+
+        fn about_to_show(&self, _id: i32) -> bool {
+            false
+        }
+
+        fn about_to_show_group(&self, _ids: Vec<i32>) -> (Vec<i32>, Vec<i32>) {
+            (Vec::new(), Vec::new())
+        }
+
+        #[zbus(property)]
+        fn version(&self) -> u32 {
+            3
+        }
+
+        #[zbus(property)]
+        fn text_direction(&self) -> &str {
+            "ltr"
+        }
+
+        #[zbus(property)]
+        fn status(&self) -> &str {
+            "normal"
+        }
+
+        #[zbus(property)]
+        fn icon_theme_path(&self) -> Vec<String> {
+            Vec::new()
+        }
+    }
+
+    fn owned(value: Value<'_>) -> OwnedValue {
+        OwnedValue::try_from(value)
+            .expect("Menu properties have no file descriptors")
+    }
+
+    /// Returns the keys of a [`Shortcut`], as expected by the `shortcut`
+    /// property of `com.canonical.dbusmenu`.
+    fn shortcut_keys(shortcut: Shortcut) -> Vec<String> {
+        let mut keys: Vec<String> = [
+            (Modifiers::CTRL, "Control"),
+            (Modifiers::ALT, "Alt"),
+            (Modifiers::SHIFT, "Shift"),
+            (Modifiers::LOGO, "Super"),
+        ]
+        .iter()
+        .filter(|(modifier, _)| shortcut.modifiers.contains(*modifier))
+        .map(|(_, name)| String::from(*name))
+        .collect();
+
+        let key = super::key_name(&shortcut);
+
+        keys.push(
+            match key.as_str() {
+                "Plus" => "plus",
+                "-" => "minus",
+                "=" => "equal",
+                "," => "comma",
+                "." => "period",
+                "/" => "slash",
+                "\\" => "backslash",
+                ";" => "semicolon",
+                "'" => "apostrophe",
+                "`" => "grave",
+                "[" => "bracketleft",
+                "]" => "bracketright",
+                "Enter" => "Return",
+                "Backspace" => "BackSpace",
+                "PageUp" => "Page_Up",
+                "PageDown" => "Page_Down",
+                key => key,
+            }
+            .to_owned(),
+        );
+
+        keys
+    }
+}
+
+#[cfg(all(feature = "menu_bar", target_os = "macos"))]
+mod platform {
+    use crate::keyboard::{Modifiers, Shortcut};
+    use crate::menu_bar::{Item, Menu};
+    use crate::menu_event::{self, Callback, Owner};
+
+    use muda::accelerator::Accelerator;
+    use muda::{CheckMenuItem, MenuId, MenuItem, PredefinedMenuItem, Submenu};
+
+    use std::sync::atomic::{self, AtomicBool};
+    use std::sync::Arc;
+
+    /// Whether a checkbox was toggled since the menu bar was last shown.
+    ///
+    /// macOS toggles checkboxes on its own, so the menu bar must be shown
+    /// again to match the state of the application.
+    static IS_TOGGLED: AtomicBool = AtomicBool::new(false);
+
+    pub struct Platform {
+        menu: Option<muda::Menu>,
+        on_activate: Arc<dyn Fn(usize) + Send + Sync>,
+    }
+
+    impl Platform {
+        pub fn new(
+            _window: &winit::window::Window,
+            on_activate: Arc<dyn Fn(usize) + Send + Sync>,
+        ) -> Self {
+            Self {
+                menu: None,
+                on_activate,
+            }
+        }
+
+        pub fn show(&mut self, menus: &[Menu<usize>]) {
+            let menu = muda::Menu::new();
+            let mut callbacks = Vec::new();
+
+            for submenu in menus {
+                let result = self
+                    .submenu(submenu, &mut callbacks)
+                    .and_then(|submenu| menu.append(&submenu));
+
+                if let Err(error) = result {
+                    log::warn!("failed to build the menu bar: {}", error);
+                }
+            }
+
+            menu_event::replace(Owner::MenuBar, callbacks);
+            menu.init_for_nsapp();
+
+            // The previous menu is only dropped once it is replaced
+            self.menu = Some(menu);
+        }
+
+        pub fn is_outdated(&self) -> bool {
+            IS_TOGGLED.swap(false, atomic::Ordering::Relaxed)
+        }
+
+        pub fn is_native(&self) -> bool {
+            true
+        }
+
+        fn submenu(
+            &self,
+            menu: &Menu<usize>,
+            callbacks: &mut Vec<(MenuId, Callback)>,
+        ) -> muda::Result<Submenu> {
+            let submenu = Submenu::new(&menu.title, true);
+
+            for item in &menu.items {
+                match item {
+                    Item::Button {
+                        label,
+                        on_press,
+                        shortcut,
+                    } => {
+                        let button = MenuItem::new(
+                            label,
+                            on_press.is_some(),
+                            shortcut.and_then(accelerator),
+                        );
+
+                        if let Some(index) = *on_press {
+                            callbacks.push((
+                                button.id().clone(),
+                                self.callback(index, false),
+                            ));
+                        }
+
+                        submenu.append(&button)?;
+                    }
+                    Item::Checkbox {
+                        label,
+                        is_checked,
+                        on_toggle,
+                        shortcut,
+                    } => {
+                        let checkbox = CheckMenuItem::new(
+                            label,
+                            on_toggle.is_some(),
+                            *is_checked,
+                            shortcut.and_then(accelerator),
+                        );
+
+                        if let Some(index) = *on_toggle {
+                            callbacks.push((
+                                checkbox.id().clone(),
+                                self.callback(index, true),
+                            ));
+                        }
+
+                        submenu.append(&checkbox)?;
+                    }
+                    Item::Separator => {
+                        submenu.append(&PredefinedMenuItem::separator())?;
+                    }
+                    Item::Submenu(menu) => {
+                        submenu.append(&self.submenu(menu, callbacks)?)?;
+                    }
+                }
+            }
+
+            Ok(submenu)
+        }
+
+        fn callback(&self, index: usize, is_checkbox: bool) -> Callback {
+            let on_activate = self.on_activate.clone();
+
+            Arc::new(move || {
+                if is_checkbox {
+                    IS_TOGGLED.store(true, atomic::Ordering::Relaxed);
+                }
+
+                on_activate(index);
+            })
+        }
+    }
+
+    fn accelerator(shortcut: Shortcut) -> Option<Accelerator> {
+        let mut accelerator = String::new();
+
+        for (modifier, name) in [
+            (Modifiers::CTRL, "Ctrl+"),
+            (Modifiers::ALT, "Alt+"),
+            (Modifiers::SHIFT, "Shift+"),
+            (Modifiers::LOGO, "CmdOrCtrl+"),
+        ] {
+            if shortcut.modifiers.contains(modifier) {
+                accelerator.push_str(name);
+            }
+        }
+
+        accelerator.push_str(&super::key_name(&shortcut));
+
+        match accelerator.parse() {
+            Ok(accelerator) => Some(accelerator),
+            Err(error) => {
+                log::warn!("shortcut {} is not shown: {}", shortcut, error);
+                None
+            }
+        }
+    }
+}
+
+#[cfg(not(all(
+    feature = "menu_bar",
+    any(target_os = "linux", target_os = "macos")
+)))]
+mod platform {
+    use crate::menu_bar::Menu;
+
+    use std::sync::Arc;
+
+    pub struct Platform;
+
+    impl Platform {
+        pub fn new(
+            _window: &winit::window::Window,
+            _on_activate: Arc<dyn Fn(usize) + Send + Sync>,
+        ) -> Self {
+            Self
+        }
+
+        pub fn show(&mut self, _menus: &[Menu<usize>]) {}
+
+        pub fn is_outdated(&self) -> bool {
+            false
+        }
+
+        pub fn is_native(&self) -> bool {
+            false
+        }
+    }
+}
@@ -14,6 +14,7 @@ mod platform;
 pub use platform::PlatformSpecific;
 
 use crate::conversion;
+use crate::executor;
 use crate::{Mode, Position};
 use winit::monitor::MonitorHandle;
 use winit::window::WindowBuilder;
@@ -37,7 +38,33 @@ pub struct Settings<Flags> {
 
     /// Whether the [`Application`] should exit when the user requests the
     /// window to close (e.g. the user presses the close button).
+    ///
+    /// When disabled, a [`window::Event::CloseRequested`] is produced instead,
+    /// and the [`Application`] can decide to close the window with
+    /// [`window::close`].
+    ///
+    /// [`window::Event::CloseRequested`]: crate::window::Event::CloseRequested
+    /// [`window::close`]: crate::window::close
     pub exit_on_close_request: bool,
+
+    /// Whether the user can zoom the whole user interface with the keyboard.
+    ///
+    /// When enabled, <kbd>Ctrl</kbd>+<kbd>+</kbd> zooms in,
+    /// <kbd>Ctrl</kbd>+<kbd>-</kbd> zooms out, and <kbd>Ctrl</kbd>+<kbd>0</kbd>
+    /// resets the zoom, as long as no widget captures these presses.
+    pub keyboard_zoom: bool,
+
+    /// The maximum amount of frames presented per second, if any.
+    ///
+    /// It can be changed at runtime with [`window::set_frame_rate_cap`].
+    ///
+    /// [`window::set_frame_rate_cap`]: crate::window::set_frame_rate_cap
+    pub frame_rate_cap: Option<u16>,
+
+    /// The settings of the [`Executor`] of the application.
+    ///
+    /// [`Executor`]: crate::Executor
+    pub executor: executor::Settings,
 }
 
 /// The window settings of an application.
@@ -126,6 +153,9 @@ impl Window {
             use ::winit::platform::unix::WindowBuilderExtUnix;
 
             if let Some(id) = _id {
+                #[cfg(all(target_os = "linux", feature = "launcher_entry"))]
+                crate::launcher_entry::set_desktop_id(id.clone());
+
                 window_builder = window_builder.with_app_id(id);
             }
         }
@@ -163,6 +193,23 @@ impl Window {
     }
 }
 
+impl From<iced_native::window::Settings> for Window {
+    fn from(settings: iced_native::window::Settings) -> Window {
+        Window {
+            size: settings.size,
+            position: settings.position,
+            min_size: settings.min_size,
+            max_size: settings.max_size,
+            resizable: settings.resizable,
+            decorations: settings.decorations,
+            transparent: settings.transparent,
+            always_on_top: settings.always_on_top,
+            icon: None,
+            platform_specific: Default::default(),
+        }
+    }
+}
+
 impl Default for Window {
     fn default() -> Window {
         Window {
@@ -0,0 +1,463 @@
+//! Show an icon with a menu in the system tray.
+//!
+//! On Linux, the tray is exported through the `StatusNotifierItem` D-Bus
+//! interface by a background thread. On Windows and macOS, it is owned by the
+//! thread of the event loop, as the operating system requires.
+//!
+//! The messages of the tray are sent to the event loop by their own closures,
+//! so the backends only deal with `tray::Action<()>`.
+pub use platform::run_action;
+
+/// Decodes the icon of a tray in RGBA format, logging any error.
+#[cfg(any(target_os = "linux", target_os = "windows", target_os = "macos"))]
+fn decode(icon: &iced_native::image::Handle) -> Option<(u32, u32, Vec<u8>)> {
+    match crate::clipboard::decode(icon) {
+        Ok(image) => Some((
+            image.width as u32,
+            image.height as u32,
+            image.bytes.into_owned(),
+        )),
+        Err(error) => {
+            log::warn!("failed to decode the icon of the tray: {}", error);
+            None
+        }
+    }
+}
+
+#[cfg(target_os = "linux")]
+mod platform {
+    use super::decode;
+    use iced_native::{image, tray};
+
+    use ksni::blocking::{Handle, TrayMethods};
+    use ksni::menu::{CheckmarkItem, StandardItem, SubMenu};
+    use ksni::MenuItem;
+
+    use std::sync::{mpsc, OnceLock};
+    use std::thread;
+
+    /// The actions sent to the thread that owns the tray.
+    static ACTIONS: OnceLock<mpsc::Sender<tray::Action<()>>> = OnceLock::new();
+
+    /// Runs the given action in the thread that owns the tray.
+    ///
+    /// The session bus is connected to in a background thread, so the event
+    /// loop is never blocked.
+    pub fn run_action(action: tray::Action<()>) {
+        let actions = ACTIONS.get_or_init(|| {
+            let (sender, receiver) = mpsc::channel();
+
+            let spawned = thread::Builder::new()
+                .name(String::from("iced_winit system tray"))
+                .spawn(move || run(receiver));
+
+            if let Err(error) = spawned {
+                log::warn!("failed to show the tray: {}", error);
+            }
+
+            sender
+        });
+
+        let _ = actions.send(action);
+    }
+
+    fn run(actions: mpsc::Receiver<tray::Action<()>>) {
+        let mut handle: Option<Handle<StatusNotifier>> = None;
+
+        for action in actions {
+            if handle.as_ref().is_some_and(Handle::is_closed) {
+                handle = None;
+            }
+
+            match action {
+                tray::Action::Hide => {
+                    if let Some(handle) = handle.take() {
+                        handle.shutdown().wait();
+                    }
+                }
+                tray::Action::Show(tray) if handle.is_none() => {
+                    match StatusNotifier::new(tray).spawn() {
+                        Ok(spawned) => handle = Some(spawned),
+                        Err(error) => {
+                            log::warn!("failed to show the tray: {}", error)
+                        }
+                    }
+                }
+                action => match &handle {
+                    Some(handle) => {
+                        let _ = handle
+                            .update(move |notifier| notifier.apply(action));
+                    }
+                    None => {
+                        log::warn!(
+                            "{:?} needs a tray to be shown first",
+                            action
+                        );
+                    }
+                },
+            }
+        }
+    }
+
+    struct StatusNotifier {
+        id: String,
+        icon: Vec<ksni::Icon>,
+        tooltip: String,
+        menu: Vec<tray::Item<()>>,
+        on_activate: Option<tray::Message<()>>,
+    }
+
+    impl StatusNotifier {
+        fn new(tray: tray::Tray<()>) -> Self {
+            let id = std::env::current_exe()
+                .ok()
+                .and_then(|executable| {
+                    Some(executable.file_stem()?.to_string_lossy().into_owned())
+                })
+                .unwrap_or_else(|| String::from("iced"));
+
+            Self {
+                id,
+                icon: icon(&tray.icon),
+                tooltip: tray.tooltip,
+                menu: tray.menu,
+                on_activate: tray.on_activate,
+            }
+        }
+
+        fn apply(&mut self, action: tray::Action<()>) {
+            match action {
+                tray::Action::Show(tray) => {
+                    self.icon = icon(&tray.icon);
+                    self.tooltip = tray.tooltip;
+                    self.menu = tray.menu;
+                    self.on_activate = tray.on_activate;
+                }
+                tray::Action::SetIcon(handle) => {
+                    self.icon = icon(&handle);
+                }
+                tray::Action::SetTooltip(tooltip) => {
+                    self.tooltip = tooltip;
+                }
+                tray::Action::SetMenu(menu) => {
+                    self.menu = menu;
+                }
+                tray::Action::Hide => {}
+            }
+        }
+    }
+
+    impl ksni::Tray for StatusNotifier {
+        fn id(&self) -> String {
+            self.id.clone()
+        }
+
+        fn title(&self) -> String {
+            self.tooltip.clone()
+        }
+
+        fn activate(&mut self, _x: i32, _y: i32) {
+            if let Some(on_activate) = &self.on_activate {
+                on_activate.produce();
+            }
+        }
+
+        fn icon_pixmap(&self) -> Vec<ksni::Icon> {
+            self.icon.clone()
+        }
+
+        fn tool_tip(&self) -> ksni::ToolTip {
+            ksni::ToolTip {
+                title: self.tooltip.clone(),
+                ..ksni::ToolTip::default()
+            }
+        }
+
+        fn menu(&self) -> Vec<MenuItem<Self>> {
+            menu(&self.menu)
+        }
+    }
+
+    /// Converts the icon of a tray into the ARGB pixels of the
+    /// `StatusNotifierItem` specification.
+    fn icon(handle: &image::Handle) -> Vec<ksni::Icon> {
+        let (width, height, mut pixels) = match decode(handle) {
+            Some(icon) => icon,
+            None => return Vec::new(),
+        };
+
+        pixels
+            .chunks_exact_mut(4)
+            .for_each(|pixel| pixel.rotate_right(1));
+
+        vec![ksni::Icon {
+            width: width as i32,
+            height: height as i32,
+            data: pixels,
+        }]
+    }
+
+    fn menu(items: &[tray::Item<()>]) -> Vec<MenuItem<StatusNotifier>> {
+        items
+            .iter()
+            .map(|item| match item {
+                tray::Item::Button { label, on_press } => {
+                    let on_press = on_press.clone();
+
+                    StandardItem {
+                        label: label.clone(),
+                        enabled: on_press.is_some(),
+                        activate: Box::new(move |_| {
+                            if let Some(on_press) = &on_press {
+                                on_press.produce();
+                            }
+                        }),
+                        ..StandardItem::default()
+                    }
+                    .into()
+                }
+                tray::Item::Checkbox {
+                    label,
+                    is_checked,
+                    on_toggle,
+                } => {
+                    let on_toggle = on_toggle.clone();
+
+                    CheckmarkItem {
+                        label: label.clone(),
+                        enabled: on_toggle.is_some(),
+                        checked: *is_checked,
+                        activate: Box::new(move |_| {
+                            if let Some(on_toggle) = &on_toggle {
+                                on_toggle.produce();
+                            }
+                        }),
+                        ..CheckmarkItem::default()
+                    }
+                    .into()
+                }
+                tray::Item::Separator => MenuItem::Separator,
+                tray::Item::Submenu { label, items } => SubMenu {
+                    label: label.clone(),
+                    submenu: menu(items),
+                    ..SubMenu::default()
+                }
+                .into(),
+            })
+            .collect()
+    }
+}
+
+#[cfg(any(target_os = "windows", target_os = "macos"))]
+mod platform {
+    use super::decode;
+    use iced_native::{image, tray};
+
+    use crate::menu_event::{self, Callback, Owner};
+
+    use muda::{
+        CheckMenuItem, IsMenuItem, Menu, MenuId, MenuItem, PredefinedMenuItem,
+        Submenu,
+    };
+    use tray_icon::{
+        MouseButton, MouseButtonState, TrayIcon, TrayIconBuilder, TrayIconEvent,
+    };
+
+    use std::cell::RefCell;
+    use std::sync::{Arc, Mutex, Once};
+
+    thread_local! {
+        /// The tray, which must stay in the thread of the event loop.
+        static TRAY: RefCell<Option<TrayIcon>> = RefCell::new(None);
+    }
+
+    /// The message produced when the current tray is clicked.
+    static ON_ACTIVATE: Mutex<Option<tray::Message<()>>> = Mutex::new(None);
+
+    static HANDLERS: Once = Once::new();
+
+    /// Runs the given action on the tray of the thread of the event loop.
+    pub fn run_action(action: tray::Action<()>) {
+        HANDLERS.call_once(|| {
+            TrayIconEvent::set_event_handler(Some(|event| {
+                if let TrayIconEvent::Click {
+                    button: MouseButton::Left,
+                    button_state: MouseButtonState::Up,
+                    ..
+                } = event
+                {
+                    let on_activate =
+                        ON_ACTIVATE.lock().expect("Lock tray activation");
+
+                    if let Some(on_activate) = on_activate.as_ref() {
+                        on_activate.produce();
+                    }
+                }
+            }));
+        });
+
+        TRAY.with(|current| {
+            let mut current = current.borrow_mut();
+
+            match action {
+                tray::Action::Show(tray) => {
+                    // Only one tray is shown at a time
+                    *current = None;
+
+                    let mut builder = TrayIconBuilder::new()
+                        .with_menu(Box::new(menu(&tray.menu)))
+                        .with_tooltip(&tray.tooltip)
+                        .with_menu_on_left_click(tray.on_activate.is_none());
+
+                    if let Some(icon) = icon(&tray.icon) {
+                        builder = builder.with_icon(icon);
+                    }
+
+                    *ON_ACTIVATE.lock().expect("Lock tray activation") =
+                        tray.on_activate;
+
+                    match builder.build() {
+                        Ok(tray) => *current = Some(tray),
+                        Err(error) => {
+                            log::warn!("failed to show the tray: {}", error)
+                        }
+                    }
+                }
+                tray::Action::Hide => {
+                    *current = None;
+                    *ON_ACTIVATE.lock().expect("Lock tray activation") = None;
+                    menu_event::replace(Owner::Tray, Vec::new());
+                }
+                action => match current.as_ref() {
+                    Some(tray) => {
+                        let result = match action {
+                            tray::Action::SetIcon(handle) => {
+                                tray.set_icon(icon(&handle))
+                            }
+                            tray::Action::SetTooltip(tooltip) => {
+                                tray.set_tooltip(Some(tooltip))
+                            }
+                            tray::Action::SetMenu(items) => {
+                                tray.set_menu(Some(Box::new(menu(&items))));
+
+                                Ok(())
+                            }
+                            tray::Action::Show(_) | tray::Action::Hide => {
+                                Ok(())
+                            }
+                        };
+
+                        if let Err(error) = result {
+                            log::warn!("failed to update the tray: {}", error);
+                        }
+                    }
+                    None => {
+                        log::warn!(
+                            "{:?} needs a tray to be shown first",
+                            action
+                        );
+                    }
+                },
+            }
+        });
+    }
+
+    fn icon(handle: &image::Handle) -> Option<tray_icon::Icon> {
+        let (width, height, pixels) = decode(handle)?;
+
+        match tray_icon::Icon::from_rgba(pixels, width, height) {
+            Ok(icon) => Some(icon),
+            Err(error) => {
+                log::warn!("invalid icon for the tray: {}", error);
+                None
+            }
+        }
+    }
+
+    /// Builds a native menu with the given items, replacing the messages of
+    /// the current one.
+    fn menu(items: &[tray::Item<()>]) -> Menu {
+        let menu = Menu::new();
+        let mut messages = Vec::new();
+
+        append(items, &|item| menu.append(item), &mut messages);
+
+        menu_event::replace(Owner::Tray, messages);
+
+        menu
+    }
+
+    fn callback(message: &tray::Message<()>) -> Callback {
+        let message = message.clone();
+
+        Arc::new(move || message.produce())
+    }
+
+    fn append(
+        items: &[tray::Item<()>],
+        push: &dyn Fn(&dyn IsMenuItem) -> muda::Result<()>,
+        messages: &mut Vec<(MenuId, Callback)>,
+    ) {
+        for item in items {
+            let result = match item {
+                tray::Item::Button { label, on_press } => {
+                    let button = MenuItem::new(label, on_press.is_some(), None);
+
+                    if let Some(on_press) = on_press {
+                        messages
+                            .push((button.id().clone(), callback(on_press)));
+                    }
+
+                    push(&button)
+                }
+                tray::Item::Checkbox {
+                    label,
+                    is_checked,
+                    on_toggle,
+                } => {
+                    let checkbox = CheckMenuItem::new(
+                        label,
+                        on_toggle.is_some(),
+                        *is_checked,
+                        None,
+                    );
+
+                    if let Some(on_toggle) = on_toggle {
+                        messages
+                            .push((checkbox.id().clone(), callback(on_toggle)));
+                    }
+
+                    push(&checkbox)
+                }
+                tray::Item::Separator => push(&PredefinedMenuItem::separator()),
+                tray::Item::Submenu { label, items } => {
+                    let submenu = Submenu::new(label, true);
+
+                    append(items, &|item| submenu.append(item), messages);
+
+                    push(&submenu)
+                }
+            };
+
+            if let Err(error) = result {
+                log::warn!("failed to build the menu of the tray: {}", error);
+            }
+        }
+    }
+}
+
+#[cfg(not(any(
+    target_os = "linux",
+    target_os = "windows",
+    target_os = "macos"
+)))]
+mod platform {
+    use iced_native::tray;
+
+    pub fn run_action(action: tray::Action<()>) {
+        log::warn!(
+            "{:?} is only supported on Linux, Windows and macOS",
+            action
+        );
+    }
+}
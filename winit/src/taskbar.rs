@@ -0,0 +1,146 @@
+//! Show the progress of the application in the taskbar of Windows, through
+//! the `ITaskbarList3` COM interface.
+#![allow(unsafe_code)]
+
+use winapi::shared::minwindef::LPVOID;
+use winapi::shared::windef::HWND;
+use winapi::shared::winerror::{FAILED, HRESULT, RPC_E_CHANGED_MODE};
+use winapi::shared::wtypesbase::CLSCTX_INPROC_SERVER;
+use winapi::um::combaseapi::{CoCreateInstance, CoInitializeEx};
+use winapi::um::objbase::COINIT_APARTMENTTHREADED;
+use winapi::um::shobjidl_core::{
+    CLSID_TaskbarList, ITaskbarList3, TBPF_NOPROGRESS, TBPF_NORMAL,
+};
+use winapi::Interface;
+use winit::platform::windows::WindowExtWindows;
+use winit::window::Window;
+
+use std::cell::RefCell;
+use std::ptr;
+
+/// The total of the progress values given to the taskbar.
+const TOTAL: u64 = 10_000;
+
+thread_local! {
+    /// The taskbar of the thread, created the first time it is needed.
+    ///
+    /// COM objects can only be used by the thread that created them, which
+    /// is the one that owns the windows.
+    static TASKBAR: RefCell<Option<Taskbar>> = RefCell::new(None);
+}
+
+/// An owned `ITaskbarList3` instance.
+struct Taskbar(ptr::NonNull<ITaskbarList3>);
+
+impl Taskbar {
+    fn new() -> Result<Self, HRESULT> {
+        let mut taskbar: LPVOID = ptr::null_mut();
+
+        // SAFETY: COM is initialized for the current thread before creating
+        // the instance. Initializing it again with the same model succeeds,
+        // and a different model chosen by the windowing library can be kept.
+        unsafe {
+            let result =
+                CoInitializeEx(ptr::null_mut(), COINIT_APARTMENTTHREADED);
+
+            if FAILED(result) && result != RPC_E_CHANGED_MODE {
+                return Err(result);
+            }
+
+            let result = CoCreateInstance(
+                &CLSID_TaskbarList,
+                ptr::null_mut(),
+                CLSCTX_INPROC_SERVER,
+                &ITaskbarList3::uuidof(),
+                &mut taskbar,
+            );
+
+            if FAILED(result) {
+                return Err(result);
+            }
+        }
+
+        let taskbar = Self(
+            ptr::NonNull::new(taskbar as *mut ITaskbarList3)
+                .expect("Create taskbar instance"),
+        );
+
+        // SAFETY: The instance is valid until it is released on drop.
+        let result = unsafe { taskbar.0.as_ref().HrInit() };
+
+        if FAILED(result) {
+            return Err(result);
+        }
+
+        Ok(taskbar)
+    }
+
+    fn set_progress(
+        &self,
+        window: HWND,
+        progress: Option<f32>,
+    ) -> Result<(), HRESULT> {
+        // SAFETY: The instance is valid until it is released on drop, and
+        // the handle belongs to a window that is still alive.
+        let result = unsafe {
+            let taskbar = self.0.as_ref();
+
+            match progress {
+                Some(progress) => {
+                    let completed = (f64::from(progress.clamp(0.0, 1.0))
+                        * TOTAL as f64)
+                        .round() as u64;
+
+                    let result = taskbar.SetProgressState(window, TBPF_NORMAL);
+
+                    if FAILED(result) {
+                        result
+                    } else {
+                        taskbar.SetProgressValue(window, completed, TOTAL)
+                    }
+                }
+                None => taskbar.SetProgressState(window, TBPF_NOPROGRESS),
+            }
+        };
+
+        if FAILED(result) {
+            Err(result)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl Drop for Taskbar {
+    fn drop(&mut self) {
+        // SAFETY: The instance is owned and released only once.
+        let _ = unsafe { self.0.as_ref().Release() };
+    }
+}
+
+/// Shows the given progress, from `0.0` to `1.0`, in the taskbar button of
+/// the given window, or hides it.
+pub fn set_progress(
+    window: &Window,
+    progress: Option<f32>,
+) -> Result<(), String> {
+    let hwnd = window.hwnd() as HWND;
+
+    TASKBAR.with(|taskbar| {
+        let mut taskbar = taskbar.borrow_mut();
+
+        if taskbar.is_none() {
+            *taskbar = Some(Taskbar::new().map_err(|result| {
+                format!("the taskbar is not available ({:#x})", result)
+            })?);
+        }
+
+        taskbar
+            .as_ref()
+            .expect("Taskbar instance")
+            .set_progress(hwnd, progress)
+            .map_err(|result| {
+                format!("the taskbar rejected the progress ({:#x})", result)
+            })
+    })
+}
@@ -0,0 +1,75 @@
+use crate::theme::Palette;
+use crate::{Color, Theme};
+
+use std::time::{Duration, Instant};
+
+/// A cross-fade from the colors of a previous [`Theme`] to the current one.
+#[derive(Debug, Clone, Copy)]
+pub struct Transition {
+    palette: Palette,
+    background_color: Color,
+    started_at: Instant,
+    duration: Duration,
+}
+
+impl Transition {
+    /// Starts a new [`Transition`] from the given [`Palette`] and background
+    /// [`Color`].
+    pub fn new(
+        palette: Palette,
+        background_color: Color,
+        duration: Duration,
+    ) -> Self {
+        Self {
+            palette,
+            background_color,
+            started_at: Instant::now(),
+            duration,
+        }
+    }
+
+    /// Returns the eased progress of the [`Transition`] at the given
+    /// [`Instant`], or `None` if it has already finished.
+    pub fn progress(&self, now: Instant) -> Option<f32> {
+        let elapsed = now.saturating_duration_since(self.started_at);
+
+        if elapsed >= self.duration {
+            return None;
+        }
+
+        let t = elapsed.as_secs_f32() / self.duration.as_secs_f32();
+
+        Some(t * t * (3.0 - 2.0 * t))
+    }
+
+    /// Returns the [`Theme`] shown at the given [`Instant`] while
+    /// transitioning to the given one.
+    ///
+    /// Only the base [`Palette`] is faded. The overrides of a
+    /// [`Theme::Overridden`] apply for the whole [`Transition`].
+    pub fn theme(&self, to: Theme, now: Instant) -> Theme {
+        let progress = match self.progress(now) {
+            Some(progress) => progress,
+            None => return to,
+        };
+
+        match to {
+            Theme::Overridden(palette, overrides) => Theme::Overridden(
+                self.palette.mix(palette, progress),
+                overrides,
+            ),
+            to => Theme::Custom(self.palette.mix(to.palette(), progress)),
+        }
+    }
+
+    /// Returns the background [`Color`] shown at the given [`Instant`] while
+    /// transitioning to the given one.
+    pub fn background_color(&self, to: Color, now: Instant) -> Color {
+        match self.progress(now) {
+            Some(progress) => {
+                crate::theme::palette::mix(self.background_color, to, progress)
+            }
+            None => to,
+        }
+    }
+}
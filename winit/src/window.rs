@@ -1,8 +1,22 @@
 //! Interact with the window of your application.
 use crate::command::{self, Command};
+use iced_native::dnd;
+use iced_native::event;
+use iced_native::image;
+use iced_native::subscription::{self, Subscription};
 use iced_native::window;
+use iced_native::Hasher;
 
-pub use window::Event;
+use iced_futures::futures::future;
+use iced_futures::futures::StreamExt;
+use iced_futures::BoxStream;
+use std::collections::BTreeMap;
+use std::time::Instant;
+
+pub use window::{
+    ColorScheme, Event, Frame, Id, Level, Monitor, Position, PresentMode,
+    ResizeDirection, Screenshot, Settings, UserAttention,
+};
 
 /// Resizes the window to the given logical dimensions.
 pub fn resize<Message>(width: u32, height: u32) -> Command<Message> {
@@ -16,3 +30,424 @@ pub fn resize<Message>(width: u32, height: u32) -> Command<Message> {
 pub fn move_to<Message>(x: i32, y: i32) -> Command<Message> {
     Command::single(command::Action::Window(window::Action::Move { x, y }))
 }
+
+/// Fetches the monitors connected to the system.
+pub fn monitors<Message>(
+    f: impl Fn(Vec<Monitor>) -> Message + 'static,
+) -> Command<Message> {
+    Command::single(command::Action::FetchMonitors(Box::new(f)))
+}
+
+/// Fetches the [`ColorScheme`] preferred by the user of the operating system.
+///
+/// It produces `None` when the preference cannot be detected. Outside of
+/// Windows, it can only be detected with the `system_theme` feature.
+pub fn color_scheme<Message>(
+    f: impl Fn(Option<ColorScheme>) -> Message + 'static,
+) -> Command<Message> {
+    Command::single(command::Action::FetchColorScheme(Box::new(f)))
+}
+
+/// Captures the contents of the main window.
+///
+/// The [`Screenshot`] is taken the next time the window is presented.
+pub fn screenshot<Message>(
+    f: impl Fn(Screenshot) -> Message + 'static,
+) -> Command<Message> {
+    screenshot_of(Id::MAIN, f)
+}
+
+/// Captures the contents of the window with the given [`Id`].
+///
+/// The [`Screenshot`] is taken the next time the window is presented.
+pub fn screenshot_of<Message>(
+    id: Id,
+    f: impl Fn(Screenshot) -> Message + 'static,
+) -> Command<Message> {
+    Command::single(command::Action::Screenshot(id, Box::new(f)))
+}
+
+/// Returns a [`Subscription`] that produces messages whenever the
+/// [`ColorScheme`] preferred by the user of the operating system changes.
+///
+/// Outside of Windows, it needs the `system_theme` feature, and changes are
+/// only noticed when a window gains focus.
+pub fn color_scheme_changes<Message>(
+    f: fn(ColorScheme) -> Message,
+) -> Subscription<Message>
+where
+    Message: 'static + Send,
+{
+    subscription::events_with(|event, _status| match event {
+        iced_native::Event::Window(Event::ColorSchemeChanged(color_scheme)) => {
+            Some(color_scheme)
+        }
+        _ => None,
+    })
+    .map(f)
+}
+
+/// Returns a [`Subscription`] that produces messages whenever a window gains
+/// or loses focus.
+///
+/// The function receives `true` when the window is focused.
+pub fn focus_changes<Message>(f: fn(bool) -> Message) -> Subscription<Message>
+where
+    Message: 'static + Send,
+{
+    subscription::events_with(|event, _status| match event {
+        iced_native::Event::Window(Event::Focused) => Some(true),
+        iced_native::Event::Window(Event::Unfocused) => Some(false),
+        _ => None,
+    })
+    .map(f)
+}
+
+/// Returns a [`Subscription`] that produces messages whenever a window is
+/// hidden from the user or shown again; that is, when it is minimized,
+/// restored, or occluded.
+///
+/// The function receives `true` when the window becomes visible. This is
+/// useful to pause animations, videos, and expensive subscriptions while
+/// nobody can see them.
+pub fn visibility_changes<Message>(
+    f: fn(bool) -> Message,
+) -> Subscription<Message>
+where
+    Message: 'static + Send,
+{
+    subscription::events_with(|event, _status| match event {
+        iced_native::Event::Window(Event::Minimized) => Some(false),
+        iced_native::Event::Window(Event::Restored) => Some(true),
+        iced_native::Event::Window(Event::Occluded(is_occluded)) => {
+            Some(!is_occluded)
+        }
+        _ => None,
+    })
+    .map(f)
+}
+
+/// Returns a [`Subscription`] that produces a [`Frame`] every time a window
+/// presents a frame on the screen.
+///
+/// A window is redrawn after every update of the application, so the
+/// [`Subscription`] keeps producing a [`Frame`] per refresh of the screen for
+/// as long as it is active. Presentation is synchronized with the display
+/// when vsync is enabled, which makes it a steadier source of time than
+/// `time::every` for custom animations and simulations.
+pub fn frames() -> Subscription<Frame> {
+    Subscription::from_recipe(Frames)
+}
+
+struct Frames;
+
+impl subscription::Recipe<Hasher, (Id, iced_native::Event, event::Status)>
+    for Frames
+{
+    type Output = Frame;
+
+    fn hash(&self, state: &mut Hasher) {
+        use std::hash::Hash;
+
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+    }
+
+    fn stream(
+        self: Box<Self>,
+        event_stream: subscription::EventStream,
+    ) -> BoxStream<Self::Output> {
+        let presentations = event_stream.filter_map(|(id, event, _status)| {
+            future::ready(match event {
+                iced_native::Event::Window(Event::FramePresented(at)) => {
+                    Some((id, at))
+                }
+                _ => None,
+            })
+        });
+
+        let frames = presentations.scan(
+            BTreeMap::<Id, Instant>::new(),
+            |previous, (window, at)| {
+                let delta = previous
+                    .insert(window, at)
+                    .map(|previous| at.saturating_duration_since(previous))
+                    .unwrap_or_default();
+
+                future::ready(Some(Frame { window, at, delta }))
+            },
+        );
+
+        iced_futures::boxed_stream(frames)
+    }
+}
+
+/// Moves the window to the given [`Monitor`], placing it at the given
+/// [`Position`] relative to the monitor.
+pub fn move_to_monitor<Message>(
+    monitor: Monitor,
+    position: Position,
+) -> Command<Message> {
+    Command::single(command::Action::Window(window::Action::MoveToMonitor {
+        monitor,
+        position,
+    }))
+}
+
+/// Centers the window on the given [`Monitor`].
+pub fn center_on_monitor<Message>(monitor: Monitor) -> Command<Message> {
+    move_to_monitor(monitor, Position::Centered)
+}
+
+/// Makes the window take the whole given [`Monitor`] in exclusive
+/// fullscreen.
+///
+/// The video mode of the monitor with its current size and the highest
+/// refresh rate is used.
+pub fn fullscreen_on<Message>(monitor: Monitor) -> Command<Message> {
+    Command::single(command::Action::Window(window::Action::Fullscreen(Some(
+        monitor,
+    ))))
+}
+
+/// Makes the window leave the fullscreen entered with [`fullscreen_on`].
+pub fn exit_fullscreen<Message>() -> Command<Message> {
+    Command::single(command::Action::Window(window::Action::Fullscreen(None)))
+}
+
+/// Sets the minimum logical dimensions of the window.
+///
+/// When `None`, the size of the window is not limited.
+pub fn set_min_size<Message>(size: Option<(u32, u32)>) -> Command<Message> {
+    Command::single(command::Action::Window(window::Action::SetMinSize(size)))
+}
+
+/// Sets the maximum logical dimensions of the window.
+///
+/// When `None`, the size of the window is not limited.
+pub fn set_max_size<Message>(size: Option<(u32, u32)>) -> Command<Message> {
+    Command::single(command::Action::Window(window::Action::SetMaxSize(size)))
+}
+
+/// Sets whether the window can be resized by the user or not.
+pub fn set_resizable<Message>(resizable: bool) -> Command<Message> {
+    Command::single(command::Action::Window(window::Action::SetResizable(
+        resizable,
+    )))
+}
+
+/// Sets whether the window should have a border, a title bar, etc.
+pub fn set_decorations<Message>(decorations: bool) -> Command<Message> {
+    Command::single(command::Action::Window(window::Action::SetDecorations(
+        decorations,
+    )))
+}
+
+/// Sets the stacking [`Level`] of the window.
+///
+/// _**Note:** [`Level::AlwaysOnBottom`] is only supported on X11, with the
+/// `x11` feature. Anywhere else, it is treated like [`Level::Normal`] and a
+/// warning is logged._
+pub fn set_level<Message>(level: Level) -> Command<Message> {
+    Command::single(command::Action::Window(window::Action::SetLevel(level)))
+}
+
+/// Minimizes the window.
+pub fn minimize<Message>() -> Command<Message> {
+    Command::single(command::Action::Window(window::Action::Minimize))
+}
+
+/// Maximizes the window.
+pub fn maximize<Message>() -> Command<Message> {
+    Command::single(command::Action::Window(window::Action::Maximize))
+}
+
+/// Restores the window after it was minimized or maximized.
+pub fn restore<Message>() -> Command<Message> {
+    Command::single(command::Action::Window(window::Action::Restore))
+}
+
+/// Sets the icon of the window, which is usually also shown in the taskbar.
+///
+/// When `None`, the default icon of the platform is used.
+///
+/// _**Note:** Only an [`image::Handle`] created from decoded pixels can be
+/// used as an icon for now. Other handles are ignored and a warning is
+/// logged instead._
+pub fn set_icon<Message>(icon: Option<image::Handle>) -> Command<Message> {
+    Command::single(command::Action::Window(window::Action::SetIcon(icon)))
+}
+
+/// Sets the progress shown in the taskbar or dock entry of the window, from
+/// `0.0` to `1.0`.
+///
+/// When `None`, the progress is hidden.
+///
+/// _**Note:** This is only supported on Windows, where the progress is shown
+/// in the taskbar button of the window, and on Linux with the
+/// `launcher_entry` feature. On Linux, the progress is shown in the launcher
+/// entry of the desktop file named after the [`Settings::id`] of the
+/// application, or after its executable. Docks and task managers that
+/// implement the `com.canonical.Unity.LauncherEntry` interface display it,
+/// like the ones of KDE Plasma and Ubuntu. Anywhere else, a warning is
+/// logged instead._
+///
+/// [`Settings::id`]: crate::Settings::id
+pub fn set_progress<Message>(progress: Option<f32>) -> Command<Message> {
+    Command::single(command::Action::Window(window::Action::SetProgress(
+        progress,
+    )))
+}
+
+/// Changes the [`PresentMode`] of the windows of the application, trading
+/// latency for tearing and power usage.
+///
+/// _**Note:** Only `iced_wgpu` can change the present mode at runtime. Other
+/// renderers ignore this command._
+pub fn set_present_mode<Message>(
+    present_mode: PresentMode,
+) -> Command<Message> {
+    Command::single(command::Action::Window(window::Action::SetPresentMode(
+        present_mode,
+    )))
+}
+
+/// Limits the amount of frames presented per second by the windows of the
+/// application.
+///
+/// When `None`, frames are presented as fast as the [`PresentMode`] allows.
+pub fn set_frame_rate_cap<Message>(
+    frames_per_second: Option<u16>,
+) -> Command<Message> {
+    Command::single(command::Action::Window(window::Action::SetFrameRateCap(
+        frames_per_second,
+    )))
+}
+
+/// Sets whether the window is redrawn continuously, once per frame, instead
+/// of only when an event or a message is processed.
+///
+/// Continuous rendering is useful for applications that animate all the
+/// time, like games or simulations drawn on a `Canvas`. Frames are paced by
+/// the [`PresentMode`] and the frame rate cap of the application.
+pub fn set_continuous_rendering<Message>(
+    is_continuous: bool,
+) -> Command<Message> {
+    set_continuous_rendering_of(Id::MAIN, is_continuous)
+}
+
+/// Sets whether the window with the given [`Id`] is redrawn continuously.
+///
+/// See [`set_continuous_rendering`].
+pub fn set_continuous_rendering_of<Message>(
+    id: Id,
+    is_continuous: bool,
+) -> Command<Message> {
+    Command::single(command::Action::Window(
+        window::Action::SetContinuousRendering(id, is_continuous),
+    ))
+}
+
+/// Shows the software keyboard of the platform.
+///
+/// Text input on mobile devices relies on the software keyboard.
+///
+/// _**Note:** `winit` can only show the software keyboard on Android, so
+/// this command is not available on other platforms. The SDL2 host of
+/// `iced_sdl2` also shows it on iOS._
+#[cfg(target_os = "android")]
+pub fn show_soft_keyboard<Message>() -> Command<Message> {
+    Command::single(command::Action::Window(
+        window::Action::SetSoftKeyboardVisible(true),
+    ))
+}
+
+/// Hides the software keyboard of the platform.
+///
+/// _**Note:** `winit` can only hide the software keyboard on Android, so
+/// this command is not available on other platforms. The SDL2 host of
+/// `iced_sdl2` also hides it on iOS._
+#[cfg(target_os = "android")]
+pub fn hide_soft_keyboard<Message>() -> Command<Message> {
+    Command::single(command::Action::Window(
+        window::Action::SetSoftKeyboardVisible(false),
+    ))
+}
+
+/// Requests the [`UserAttention`] of the user, like flashing the taskbar
+/// entry of the window or bouncing its icon in the dock.
+///
+/// When `None`, a previous request is cancelled.
+pub fn request_attention<Message>(
+    attention: Option<UserAttention>,
+) -> Command<Message> {
+    Command::single(command::Action::Window(window::Action::RequestAttention(
+        attention,
+    )))
+}
+
+/// Starts moving the window with the cursor.
+///
+/// You should produce this [`Command`] as a reaction to the user pressing a
+/// mouse button, while it is still held. A [`WindowHandle`] does this for
+/// you.
+///
+/// [`WindowHandle`]: crate::widget::WindowHandle
+pub fn drag<Message>() -> Command<Message> {
+    Command::single(command::Action::Window(window::Action::Drag))
+}
+
+/// Starts resizing the window with the cursor, from the given
+/// [`ResizeDirection`].
+///
+/// The window follows the cursor until the left mouse button is released,
+/// so the command should be produced while the button is pressed. Windows
+/// that cannot be moved, like the ones on Wayland, can only be resized
+/// through their bottom and right edges.
+pub fn drag_resize<Message>(direction: ResizeDirection) -> Command<Message> {
+    Command::single(command::Action::Window(window::Action::DragResize(
+        direction,
+    )))
+}
+
+/// Starts dragging the given [`dnd::Data`] out of the window, so it can be
+/// dropped into other applications.
+///
+/// You should produce this [`Command`] as a reaction to the user pressing and
+/// moving the cursor, while the mouse button is still held. The resulting
+/// message tells whether the drag could be started.
+///
+/// _**Note:** This is only supported on X11, with the `x11` feature. On
+/// other platforms, [`dnd::Error::Unsupported`] is produced instead._
+pub fn start_drag<Message>(
+    data: dnd::Data,
+    f: impl Fn(Result<(), dnd::Error>) -> Message + 'static,
+) -> Command<Message> {
+    Command::single(command::Action::StartDrag(data, Box::new(f)))
+}
+
+/// Opens a new window with the given [`Id`] and [`Settings`].
+///
+/// _**Note:** Only a [`multi_window::Application`] can open new windows.
+/// Otherwise, the command is ignored and a warning is logged instead._
+///
+/// [`multi_window::Application`]: crate::multi_window::Application
+pub fn open<Message>(id: Id, settings: Settings) -> Command<Message> {
+    Command::single(command::Action::Window(window::Action::Open {
+        id,
+        settings,
+    }))
+}
+
+/// Closes the window with the given [`Id`].
+///
+/// Closing the [`Id::MAIN`] window of a single-window [`Application`] makes it
+/// exit. Only a [`multi_window::Application`] can close its other windows.
+/// Otherwise, the command is ignored and a warning is logged instead.
+///
+/// [`Application`]: crate::Application
+///
+/// [`multi_window::Application`]: crate::multi_window::Application
+pub fn close<Message>(id: Id) -> Command<Message> {
+    Command::single(command::Action::Window(window::Action::Close(id)))
+}
@@ -0,0 +1,126 @@
+//! Talk to the X11 window manager directly, for the features that `winit`
+//! does not expose.
+mod drag;
+
+pub use drag::start_drag;
+
+use winit::platform::unix::WindowExtUnix;
+use winit::window::Window;
+
+use std::sync::OnceLock;
+
+use x11rb::connection::Connection as _;
+use x11rb::protocol::xproto::{
+    Atom, AtomEnum, ClientMessageEvent, ConnectionExt, EventMask,
+};
+use x11rb::rust_connection::RustConnection;
+
+/// The source indication of a request sent by a normal application, as
+/// defined by the Extended Window Manager Hints.
+const SOURCE_APPLICATION: u32 = 1;
+
+/// The connection to the X server, opened the first time a window is kept
+/// below others.
+static CONNECTION: OnceLock<Option<Connection>> = OnceLock::new();
+
+struct Connection {
+    connection: RustConnection,
+    root: u32,
+    wm_state: Atom,
+    below: Atom,
+}
+
+impl Connection {
+    fn open() -> Result<Self, Box<dyn std::error::Error>> {
+        let (connection, screen) = x11rb::connect(None)?;
+        let root = connection.setup().roots[screen].root;
+
+        let wm_state = connection.intern_atom(false, b"_NET_WM_STATE")?;
+        let below = connection.intern_atom(false, b"_NET_WM_STATE_BELOW")?;
+
+        Ok(Self {
+            root,
+            wm_state: wm_state.reply()?.atom,
+            below: below.reply()?.atom,
+            connection,
+        })
+    }
+
+    fn is_below(&self, id: u32) -> Result<bool, Box<dyn std::error::Error>> {
+        let state = self
+            .connection
+            .get_property(false, id, self.wm_state, AtomEnum::ATOM, 0, 32)?
+            .reply()?;
+
+        Ok(state
+            .value32()
+            .is_some_and(|mut atoms| atoms.any(|atom| atom == self.below)))
+    }
+
+    fn set_below(
+        &self,
+        id: u32,
+        is_below: bool,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let event = ClientMessageEvent::new(
+            32,
+            id,
+            self.wm_state,
+            [u32::from(is_below), self.below, 0, SOURCE_APPLICATION, 0],
+        );
+
+        let _ = self.connection.send_event(
+            false,
+            self.root,
+            EventMask::SUBSTRUCTURE_REDIRECT | EventMask::SUBSTRUCTURE_NOTIFY,
+            event,
+        )?;
+
+        self.connection.flush()?;
+
+        Ok(())
+    }
+}
+
+/// Keeps the given window below every other window, or stops doing so.
+///
+/// The window manager is only asked when the state of the window changes.
+///
+/// Returns `Ok(false)` if the window is not an X11 window.
+pub fn set_always_on_bottom(
+    window: &Window,
+    is_below: bool,
+) -> Result<bool, Box<dyn std::error::Error>> {
+    let id = match window.xlib_window() {
+        Some(id) => id as u32,
+        None => return Ok(false),
+    };
+
+    // No window can be below others before the connection is opened
+    let connection = if is_below {
+        CONNECTION.get_or_init(|| match Connection::open() {
+            Ok(connection) => Some(connection),
+            Err(error) => {
+                log::warn!("failed to connect to the X server: {}", error);
+
+                None
+            }
+        })
+    } else {
+        match CONNECTION.get() {
+            Some(connection) => connection,
+            None => return Ok(true),
+        }
+    };
+
+    let connection = match connection {
+        Some(connection) => connection,
+        None => return Ok(true),
+    };
+
+    if connection.is_below(id)? != is_below {
+        connection.set_below(id, is_below)?;
+    }
+
+    Ok(true)
+}
@@ -0,0 +1,543 @@
+//! Drag data out of a window, into other applications, with the XDND
+//! protocol.
+//!
+//! The drag is driven by a background thread with its own connection to the
+//! X server, which follows the cursor until the mouse buttons are released.
+use iced_native::dnd;
+
+use winit::platform::unix::WindowExtUnix;
+use winit::window::Window;
+
+use x11rb::connection::Connection as _;
+use x11rb::protocol::xproto::{
+    self, Atom, AtomEnum, ClientMessageEvent, ConnectionExt, CreateWindowAux,
+    EventMask, KeyButMask, PropMode, SelectionNotifyEvent,
+    SelectionRequestEvent, WindowClass,
+};
+use x11rb::protocol::Event;
+use x11rb::rust_connection::RustConnection;
+use x11rb::wrapper::ConnectionExt as _;
+use x11rb::{COPY_DEPTH_FROM_PARENT, COPY_FROM_PARENT, CURRENT_TIME, NONE};
+
+use std::error::Error;
+use std::os::unix::ffi::OsStrExt;
+use std::path::Path;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// The newest version of the XDND protocol spoken by the drag.
+const VERSION: u32 = 5;
+
+/// The interval between the checks of the position of the cursor.
+const INTERVAL: Duration = Duration::from_millis(16);
+
+/// How long the target of a drop is waited for before giving up.
+const TIMEOUT: Duration = Duration::from_secs(5);
+
+x11rb::atom_manager! {
+    Atoms: AtomsCookie {
+        XdndAware,
+        XdndEnter,
+        XdndPosition,
+        XdndStatus,
+        XdndLeave,
+        XdndDrop,
+        XdndFinished,
+        XdndSelection,
+        XdndActionCopy,
+        TARGETS,
+        UTF8_STRING,
+        TEXT_PLAIN: b"text/plain",
+        TEXT_PLAIN_UTF8: b"text/plain;charset=utf-8",
+        TEXT_URI_LIST: b"text/uri-list",
+        IMAGE_PNG: b"image/png",
+    }
+}
+
+/// Starts dragging the given [`dnd::Data`] out of the window.
+///
+/// Returns `Ok(false)` if the window is not an X11 window.
+pub fn start_drag(
+    window: &Window,
+    data: dnd::Data,
+) -> Result<bool, Box<dyn Error>> {
+    if window.xlib_window().is_none() {
+        return Ok(false);
+    }
+
+    // The data is encoded before dragging, so it is ready as soon as a
+    // target asks for it
+    let contents = Contents::encode(data)?;
+    let drag = Drag::start(contents)?;
+
+    let _ = thread::Builder::new()
+        .name(String::from("iced_winit drag"))
+        .spawn(move || {
+            if let Err(error) = drag.run() {
+                log::warn!("failed to drag data out of the window: {}", error);
+            }
+        })?;
+
+    Ok(true)
+}
+
+/// The dragged data, encoded in the format advertised to targets.
+enum Contents {
+    UriList(Vec<u8>),
+    Text(Vec<u8>),
+    Png(Vec<u8>),
+}
+
+impl Contents {
+    fn encode(data: dnd::Data) -> Result<Self, Box<dyn Error>> {
+        match data {
+            dnd::Data::Files(paths) => {
+                let mut list = String::new();
+
+                for path in paths {
+                    // Targets cannot resolve paths relative to our working
+                    // directory
+                    let path = std::fs::canonicalize(&path).unwrap_or(path);
+
+                    list.push_str(&uri(&path));
+                    list.push_str("\r\n");
+                }
+
+                Ok(Contents::UriList(list.into_bytes()))
+            }
+            dnd::Data::Text(text) => Ok(Contents::Text(text.into_bytes())),
+            dnd::Data::Image {
+                width,
+                height,
+                pixels,
+            } => {
+                let image =
+                    image_rs::RgbaImage::from_raw(width, height, pixels)
+                        .ok_or(
+                            "the pixels do not match the size of the image",
+                        )?;
+
+                let mut png = Vec::new();
+
+                image.write_to(
+                    &mut std::io::Cursor::new(&mut png),
+                    image_rs::ImageFormat::Png,
+                )?;
+
+                Ok(Contents::Png(png))
+            }
+        }
+    }
+
+    fn types(&self, atoms: &Atoms) -> Vec<Atom> {
+        match self {
+            Contents::UriList(_) => vec![atoms.TEXT_URI_LIST],
+            Contents::Text(_) => {
+                vec![atoms.UTF8_STRING, atoms.TEXT_PLAIN_UTF8, atoms.TEXT_PLAIN]
+            }
+            Contents::Png(_) => vec![atoms.IMAGE_PNG],
+        }
+    }
+
+    fn bytes(&self) -> &[u8] {
+        match self {
+            Contents::UriList(bytes)
+            | Contents::Text(bytes)
+            | Contents::Png(bytes) => bytes,
+        }
+    }
+}
+
+/// A window that accepts drops.
+struct Target {
+    window: xproto::Window,
+    position: Option<(i16, i16)>,
+    is_waiting: bool,
+    is_accepted: bool,
+}
+
+struct Drag {
+    connection: RustConnection,
+    root: xproto::Window,
+    source: xproto::Window,
+    atoms: Atoms,
+    types: Vec<Atom>,
+    contents: Contents,
+    target: Option<Target>,
+    is_finished: bool,
+}
+
+impl Drag {
+    fn start(contents: Contents) -> Result<Self, Box<dyn Error>> {
+        let (connection, screen) = x11rb::connect(None)?;
+        let root = connection.setup().roots[screen].root;
+        let atoms = Atoms::new(&connection)?.reply()?;
+
+        // An invisible window owns the dragged data and receives the
+        // messages of the targets
+        let source = connection.generate_id()?;
+
+        let _ = connection.create_window(
+            COPY_DEPTH_FROM_PARENT,
+            source,
+            root,
+            -1,
+            -1,
+            1,
+            1,
+            0,
+            WindowClass::INPUT_ONLY,
+            COPY_FROM_PARENT,
+            &CreateWindowAux::new(),
+        )?;
+
+        let _ = connection.set_selection_owner(
+            source,
+            atoms.XdndSelection,
+            CURRENT_TIME,
+        )?;
+
+        connection.flush()?;
+
+        Ok(Self {
+            types: contents.types(&atoms),
+            connection,
+            root,
+            source,
+            atoms,
+            contents,
+            target: None,
+            is_finished: false,
+        })
+    }
+
+    fn run(mut self) -> Result<(), Box<dyn Error>> {
+        loop {
+            let pointer = self.connection.query_pointer(self.root)?.reply()?;
+            let position = (pointer.root_x, pointer.root_y);
+
+            let found = self.find_target(position)?;
+
+            if self.target.as_ref().map(|target| target.window)
+                != found.map(|(window, _)| window)
+            {
+                self.leave()?;
+
+                if let Some((window, version)) = found {
+                    self.enter(window, version)?;
+                }
+            }
+
+            self.move_to(position)?;
+
+            while let Some(event) = self.connection.poll_for_event()? {
+                self.handle(event)?;
+            }
+
+            let buttons = u16::from(
+                KeyButMask::BUTTON1 | KeyButMask::BUTTON2 | KeyButMask::BUTTON3,
+            );
+
+            if u16::from(pointer.mask) & buttons == 0 {
+                break;
+            }
+
+            thread::sleep(INTERVAL);
+        }
+
+        // The target must answer the last position before the drop
+        let _ = self.wait(|drag| {
+            !drag.target.as_ref().is_some_and(|target| target.is_waiting)
+        })?;
+
+        match &self.target {
+            Some(target) if target.is_accepted && !target.is_waiting => {
+                self.send(
+                    target.window,
+                    self.atoms.XdndDrop,
+                    [self.source, 0, CURRENT_TIME, 0, 0],
+                )?;
+
+                if !self.wait(|drag| drag.is_finished)? {
+                    log::warn!("the drop target did not finish in time");
+                }
+
+                Ok(())
+            }
+            _ => self.leave(),
+        }
+    }
+
+    /// Finds the window under the given position that accepts drops, with
+    /// the version of the XDND protocol it supports.
+    fn find_target(
+        &self,
+        (x, y): (i16, i16),
+    ) -> Result<Option<(xproto::Window, u32)>, Box<dyn Error>> {
+        let mut window = self.root;
+
+        loop {
+            let child = self
+                .connection
+                .translate_coordinates(self.root, window, x, y)?
+                .reply()?
+                .child;
+
+            if child == NONE {
+                return Ok(None);
+            }
+
+            if let Some(version) = self.version(child)? {
+                return Ok(Some((child, version)));
+            }
+
+            window = child;
+        }
+    }
+
+    /// Returns the version of the XDND protocol supported by a window, if
+    /// it accepts drops.
+    fn version(
+        &self,
+        window: xproto::Window,
+    ) -> Result<Option<u32>, Box<dyn Error>> {
+        let aware = self
+            .connection
+            .get_property(
+                false,
+                window,
+                self.atoms.XdndAware,
+                AtomEnum::ATOM,
+                0,
+                1,
+            )?
+            .reply()?;
+
+        // Older versions cannot tell when a drop is finished
+        Ok(aware
+            .value32()
+            .and_then(|mut version| version.next())
+            .filter(|version| *version >= 3))
+    }
+
+    fn enter(
+        &mut self,
+        window: xproto::Window,
+        version: u32,
+    ) -> Result<(), Box<dyn Error>> {
+        // There are never more than three types, so they all fit in the
+        // message
+        let mut data = [self.source, version.min(VERSION) << 24, 0, 0, 0];
+
+        for (slot, type_) in data[2..].iter_mut().zip(&self.types) {
+            *slot = *type_;
+        }
+
+        self.send(window, self.atoms.XdndEnter, data)?;
+
+        self.target = Some(Target {
+            window,
+            position: None,
+            is_waiting: false,
+            is_accepted: false,
+        });
+
+        Ok(())
+    }
+
+    fn move_to(&mut self, position: (i16, i16)) -> Result<(), Box<dyn Error>> {
+        let window = match &self.target {
+            // A new position is only sent once the last one is answered
+            Some(target)
+                if !target.is_waiting && target.position != Some(position) =>
+            {
+                target.window
+            }
+            _ => return Ok(()),
+        };
+
+        let (x, y) = position;
+
+        self.send(
+            window,
+            self.atoms.XdndPosition,
+            [
+                self.source,
+                0,
+                u32::from(x as u16) << 16 | u32::from(y as u16),
+                CURRENT_TIME,
+                self.atoms.XdndActionCopy,
+            ],
+        )?;
+
+        if let Some(target) = &mut self.target {
+            target.position = Some(position);
+            target.is_waiting = true;
+        }
+
+        Ok(())
+    }
+
+    fn leave(&mut self) -> Result<(), Box<dyn Error>> {
+        if let Some(target) = self.target.take() {
+            self.send(
+                target.window,
+                self.atoms.XdndLeave,
+                [self.source, 0, 0, 0, 0],
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// Handles events until the given condition holds, or the target takes
+    /// too long.
+    ///
+    /// Returns whether the condition holds.
+    fn wait(
+        &mut self,
+        condition: impl Fn(&Self) -> bool,
+    ) -> Result<bool, Box<dyn Error>> {
+        let deadline = Instant::now() + TIMEOUT;
+
+        while !condition(self) {
+            if Instant::now() > deadline {
+                return Ok(false);
+            }
+
+            match self.connection.poll_for_event()? {
+                Some(event) => self.handle(event)?,
+                None => thread::sleep(INTERVAL),
+            }
+        }
+
+        Ok(true)
+    }
+
+    fn handle(&mut self, event: Event) -> Result<(), Box<dyn Error>> {
+        match event {
+            Event::ClientMessage(message)
+                if message.type_ == self.atoms.XdndStatus =>
+            {
+                let data = message.data.as_data32();
+
+                if let Some(target) = &mut self.target {
+                    if target.window == data[0] {
+                        target.is_waiting = false;
+                        target.is_accepted = data[1] & 1 == 1;
+                    }
+                }
+            }
+            Event::ClientMessage(message)
+                if message.type_ == self.atoms.XdndFinished =>
+            {
+                self.is_finished = true;
+            }
+            Event::SelectionRequest(request) => self.answer(request)?,
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    /// Sends the dragged data to a target that asks for it.
+    fn answer(
+        &self,
+        request: SelectionRequestEvent,
+    ) -> Result<(), Box<dyn Error>> {
+        // Obsolete clients do not name a property to store the data in
+        let property = if request.property == NONE {
+            request.target
+        } else {
+            request.property
+        };
+
+        let is_answered = if request.selection != self.atoms.XdndSelection {
+            false
+        } else if request.target == self.atoms.TARGETS {
+            let _ = self.connection.change_property32(
+                PropMode::REPLACE,
+                request.requestor,
+                property,
+                AtomEnum::ATOM,
+                &self.types,
+            )?;
+
+            true
+        } else if self.types.contains(&request.target) {
+            let _ = self.connection.change_property8(
+                PropMode::REPLACE,
+                request.requestor,
+                property,
+                request.target,
+                self.contents.bytes(),
+            )?;
+
+            true
+        } else {
+            false
+        };
+
+        let notify = SelectionNotifyEvent {
+            response_type: xproto::SELECTION_NOTIFY_EVENT,
+            sequence: 0,
+            time: request.time,
+            requestor: request.requestor,
+            selection: request.selection,
+            target: request.target,
+            property: if is_answered { property } else { NONE },
+        };
+
+        let _ = self.connection.send_event(
+            false,
+            request.requestor,
+            EventMask::NO_EVENT,
+            notify,
+        )?;
+
+        self.connection.flush()?;
+
+        Ok(())
+    }
+
+    fn send(
+        &self,
+        window: xproto::Window,
+        type_: Atom,
+        data: [u32; 5],
+    ) -> Result<(), Box<dyn Error>> {
+        let message = ClientMessageEvent::new(32, window, type_, data);
+
+        let _ = self.connection.send_event(
+            false,
+            window,
+            EventMask::NO_EVENT,
+            message,
+        )?;
+
+        self.connection.flush()?;
+
+        Ok(())
+    }
+}
+
+/// Returns the `file://` URI of the given absolute path.
+fn uri(path: &Path) -> String {
+    let mut uri = String::from("file://");
+
+    for byte in path.as_os_str().as_bytes() {
+        match byte {
+            b'A'..=b'Z'
+            | b'a'..=b'z'
+            | b'0'..=b'9'
+            | b'/'
+            | b'-'
+            | b'_'
+            | b'.'
+            | b'~' => uri.push(char::from(*byte)),
+            _ => uri.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+
+    uri
+}